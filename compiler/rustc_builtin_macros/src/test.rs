@@ -179,6 +179,10 @@ pub fn expand_test_or_bench(
         )
     };
 
+    // creates ::core::time::Duration::from_secs
+    let duration_from_secs_path =
+        cx.path(sp, cx.std_path(&[sym::time, sym::Duration, sym::from_secs]));
+
     // creates $name: $expr
     let field = |name, expr| cx.field_imm(sp, Ident::from_str_and_span(name, sp), expr);
 
@@ -344,6 +348,22 @@ pub fn expand_test_or_bench(
                                             }
                                         },
                                     ),
+                                    // timeout: Some(Duration::from_secs(...)) | None
+                                    field(
+                                        "timeout",
+                                        if let Some(secs) = test_timeout(cx, &item) {
+                                            cx.expr_some(
+                                                sp,
+                                                cx.expr_call(
+                                                    sp,
+                                                    cx.expr_path(duration_from_secs_path),
+                                                    thin_vec![cx.expr_u64(sp, secs)],
+                                                ),
+                                            )
+                                        } else {
+                                            cx.expr_none(sp)
+                                        },
+                                    ),
                                     // },
                                 ],
                             ),
@@ -499,6 +519,19 @@ fn should_panic(cx: &ExtCtxt<'_>, i: &ast::Item) -> ShouldPanic {
     }
 }
 
+fn test_timeout(cx: &ExtCtxt<'_>, i: &ast::Item) -> Option<u64> {
+    let attr = attr::find_by_name(&i.attrs, sym::test_timeout)?;
+    // Malformed attributes are reported by the attribute checks.
+    let secs = attr.value_str()?;
+    match secs.as_str().parse() {
+        Ok(secs) if secs > 0 => Some(secs),
+        _ => {
+            cx.dcx().emit_err(errors::TestTimeout { span: attr.span });
+            None
+        }
+    }
+}
+
 enum TestType {
     UnitTest,
     IntegrationTest,
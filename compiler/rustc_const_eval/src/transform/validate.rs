@@ -41,65 +41,89 @@ fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
         if matches!(body.source.instance, InstanceDef::Intrinsic(..) | InstanceDef::Virtual(..)) {
             return;
         }
-        let def_id = body.source.def_id();
-        let mir_phase = self.mir_phase;
-        let param_env = match mir_phase.reveal() {
-            Reveal::UserFacing => tcx.param_env(def_id),
-            Reveal::All => tcx.param_env_reveal_all_normalized(def_id),
-        };
-
-        let can_unwind = if mir_phase <= MirPhase::Runtime(RuntimePhase::Initial) {
-            // In this case `AbortUnwindingCalls` haven't yet been executed.
-            true
-        } else if !tcx.def_kind(def_id).is_fn_like() {
-            true
-        } else {
-            let body_ty = tcx.type_of(def_id).skip_binder();
-            let body_abi = match body_ty.kind() {
-                ty::FnDef(..) => body_ty.fn_sig(tcx).abi(),
-                ty::Closure(..) => Abi::RustCall,
-                ty::Coroutine(..) => Abi::Rust,
-                _ => {
-                    span_bug!(body.span, "unexpected body ty: {:?} phase {:?}", body_ty, mir_phase)
-                }
+        for (location, msg) in validate_body(tcx, self.mir_phase, body) {
+            let span = match location {
+                Some(location) => body.source_info(location).span,
+                None => body.span,
             };
+            let location = location.map(|location| format!(" at {location:?}")).unwrap_or_default();
+            // We use `span_delayed_bug` as we might see broken MIR when other errors have already
+            // occurred.
+            tcx.dcx().span_delayed_bug(
+                span,
+                format!(
+                    "broken MIR in {:?} ({}){location}:\n{msg}",
+                    body.source.instance, self.when,
+                ),
+            );
+        }
+    }
+}
 
-            ty::layout::fn_can_unwind(tcx, Some(def_id), body_abi)
-        };
-
-        let mut cfg_checker = CfgChecker {
-            when: &self.when,
-            body,
-            tcx,
-            mir_phase,
-            unwind_edge_count: 0,
-            reachable_blocks: traversal::reachable_as_bitset(body),
-            value_cache: FxHashSet::default(),
-            can_unwind,
+/// Runs all the checks of the [`Validator`] on `body`, and returns the failures instead of
+/// reporting them. A failure without a location applies to the whole body.
+pub fn validate_body<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    mir_phase: MirPhase,
+    body: &Body<'tcx>,
+) -> Vec<(Option<Location>, String)> {
+    let def_id = body.source.def_id();
+    let param_env = match mir_phase.reveal() {
+        Reveal::UserFacing => tcx.param_env(def_id),
+        Reveal::All => tcx.param_env_reveal_all_normalized(def_id),
+    };
+
+    let can_unwind = if mir_phase <= MirPhase::Runtime(RuntimePhase::Initial) {
+        // In this case `AbortUnwindingCalls` haven't yet been executed.
+        true
+    } else if !tcx.def_kind(def_id).is_fn_like() {
+        true
+    } else {
+        let body_ty = tcx.type_of(def_id).skip_binder();
+        let body_abi = match body_ty.kind() {
+            ty::FnDef(..) => body_ty.fn_sig(tcx).abi(),
+            ty::Closure(..) => Abi::RustCall,
+            ty::Coroutine(..) => Abi::Rust,
+            _ => {
+                span_bug!(body.span, "unexpected body ty: {:?} phase {:?}", body_ty, mir_phase)
+            }
         };
-        cfg_checker.visit_body(body);
-        cfg_checker.check_cleanup_control_flow();
 
-        // Also run the TypeChecker.
-        for (location, msg) in validate_types(tcx, self.mir_phase, param_env, body) {
-            cfg_checker.fail(location, msg);
-        }
+        ty::layout::fn_can_unwind(tcx, Some(def_id), body_abi)
+    };
+
+    let mut cfg_checker = CfgChecker {
+        body,
+        tcx,
+        mir_phase,
+        unwind_edge_count: 0,
+        reachable_blocks: traversal::reachable_as_bitset(body),
+        value_cache: FxHashSet::default(),
+        can_unwind,
+        failures: Vec::new(),
+    };
+    cfg_checker.visit_body(body);
+    cfg_checker.check_cleanup_control_flow();
+
+    // Also run the TypeChecker.
+    for (location, msg) in validate_types(tcx, mir_phase, param_env, body) {
+        cfg_checker.fail(location, msg);
+    }
 
-        if let MirPhase::Runtime(_) = body.phase {
-            if let ty::InstanceDef::Item(_) = body.source.instance {
-                if body.has_free_regions() {
-                    cfg_checker.fail(
-                        Location::START,
-                        format!("Free regions in optimized {} MIR", body.phase.name()),
-                    );
-                }
+    if let MirPhase::Runtime(_) = body.phase {
+        if let ty::InstanceDef::Item(_) = body.source.instance {
+            if body.has_free_regions() {
+                cfg_checker.fail(
+                    Location::START,
+                    format!("Free regions in optimized {} MIR", body.phase.name()),
+                );
             }
         }
     }
+    cfg_checker.failures
 }
 
 struct CfgChecker<'a, 'tcx> {
-    when: &'a str,
     body: &'a Body<'tcx>,
     tcx: TyCtxt<'tcx>,
     mir_phase: MirPhase,
@@ -109,24 +133,12 @@ struct CfgChecker<'a, 'tcx> {
     // If `false`, then the MIR must not contain `UnwindAction::Continue` or
     // `TerminatorKind::Resume`.
     can_unwind: bool,
+    failures: Vec<(Option<Location>, String)>,
 }
 
 impl<'a, 'tcx> CfgChecker<'a, 'tcx> {
-    #[track_caller]
-    fn fail(&self, location: Location, msg: impl AsRef<str>) {
-        let span = self.body.source_info(location).span;
-        // We use `span_delayed_bug` as we might see broken MIR when other errors have already
-        // occurred.
-        self.tcx.dcx().span_delayed_bug(
-            span,
-            format!(
-                "broken MIR in {:?} ({}) at {:?}:\n{}",
-                self.body.source.instance,
-                self.when,
-                location,
-                msg.as_ref()
-            ),
-        );
+    fn fail(&mut self, location: Location, msg: impl Into<String>) {
+        self.failures.push((Some(location), msg.into()));
     }
 
     fn check_edge(&mut self, location: Location, bb: BasicBlock, edge_kind: EdgeKind) {
@@ -163,11 +175,12 @@ fn check_edge(&mut self, location: Location, bb: BasicBlock, edge_kind: EdgeKind
         }
     }
 
-    fn check_cleanup_control_flow(&self) {
+    fn check_cleanup_control_flow(&mut self) {
         if self.unwind_edge_count <= 1 {
             return;
         }
-        let doms = self.body.basic_blocks.dominators();
+        let body = self.body;
+        let doms = body.basic_blocks.dominators();
         let mut post_contract_node = FxHashMap::default();
         // Reusing the allocation across invocations of the closure
         let mut dom_path = vec![];
@@ -178,7 +191,7 @@ fn check_cleanup_control_flow(&self) {
                 }
                 let parent = doms.immediate_dominator(bb).unwrap();
                 dom_path.push(bb);
-                if !self.body.basic_blocks[parent].is_cleanup {
+                if !body.basic_blocks[parent].is_cleanup {
                     break bb;
                 }
                 bb = parent;
@@ -189,8 +202,8 @@ fn check_cleanup_control_flow(&self) {
             root
         };
 
-        let mut parent = IndexVec::from_elem(None, &self.body.basic_blocks);
-        for (bb, bb_data) in self.body.basic_blocks.iter_enumerated() {
+        let mut parent = IndexVec::from_elem(None, &body.basic_blocks);
+        for (bb, bb_data) in body.basic_blocks.iter_enumerated() {
             if !bb_data.is_cleanup || !self.reachable_blocks.contains(bb) {
                 continue;
             }
@@ -498,13 +511,7 @@ fn visit_terminator(&mut self, terminator: &Terminator<'tcx>, location: Location
 
     fn visit_source_scope(&mut self, scope: SourceScope) {
         if self.body.source_scopes.get(scope).is_none() {
-            self.tcx.dcx().span_delayed_bug(
-                self.body.span,
-                format!(
-                    "broken MIR in {:?} ({}):\ninvalid source scope {:?}",
-                    self.body.source.instance, self.when, scope,
-                ),
-            );
+            self.failures.push((None, format!("invalid source scope {scope:?}")));
         }
     }
 }
@@ -79,6 +79,19 @@
 //! invocation) and allocate the corresponding strings together with a mapping
 //! for `DepNodeIndex as StringId`.
 //!
+//!
+//! ## Chrome Traces
+//!
+//! With `-Z self-profile-format=chrome-trace`, events are not streamed to
+//! `measureme` but kept in memory, and written in the Chrome trace-event
+//! format once the `SelfProfiler` is dropped, along with a summary printed to
+//! stderr. This format doesn't need any post-processing, at the cost of a
+//! higher memory usage. The strings allocated through the `SelfProfiler` are
+//! mirrored in a table of their own, so that the `event_id`s can be resolved
+//! when the trace is written. This is why all strings, including the ones of
+//! `EventId`s built from a label and arguments, must be allocated through the
+//! `SelfProfiler`, rather than through the underlying `measureme::Profiler`.
+//!
 //! [mm]: https://github.com/rust-lang/measureme/
 
 use crate::fx::FxHashMap;
@@ -95,11 +108,16 @@
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
+use measureme::event_id::SEPARATOR_BYTE;
 pub use measureme::EventId;
-use measureme::{EventIdBuilder, Profiler, SerializableString, StringId};
+use measureme::{Profiler, SerializableString, StringComponent, StringId};
 use parking_lot::RwLock;
 use smallvec::SmallVec;
 
+use self::chrome_trace::ChromeTraceRecorder;
+
+mod chrome_trace;
+
 bitflags::bitflags! {
     #[derive(Clone, Copy)]
     struct EventFilter: u16 {
@@ -156,6 +174,15 @@ pub enum TimePassesFormat {
     Json,
 }
 
+/// Which format to use for `-Z self-profile`
+#[derive(Clone, Copy, PartialEq, Hash, Debug)]
+pub enum SelfProfileFormat {
+    /// Emit the binary format of `measureme`
+    Measureme,
+    /// Emit a Chrome trace, and print a summary to stderr
+    ChromeTrace,
+}
+
 /// A reference to the SelfProfiler. It can be cloned and sent across thread
 /// boundaries at will.
 #[derive(Clone)]
@@ -275,7 +302,7 @@ pub fn generic_activity_with_arg<A>(
         A: Borrow<str> + Into<String>,
     {
         self.exec(EventFilter::GENERIC_ACTIVITIES, |profiler| {
-            let builder = EventIdBuilder::new(&profiler.profiler);
+            let builder = profiler.event_id_builder();
             let event_label = profiler.get_or_alloc_cached_string(event_label);
             let event_id = if profiler.event_filter_mask.contains(EventFilter::FUNCTION_ARGS) {
                 let event_arg = profiler.get_or_alloc_cached_string(event_arg);
@@ -318,7 +345,7 @@ pub fn generic_activity_with_arg_recorder<F>(
     {
         // Ensure this event will only be recorded when self-profiling is turned on.
         self.exec(EventFilter::GENERIC_ACTIVITIES, |profiler| {
-            let builder = EventIdBuilder::new(&profiler.profiler);
+            let builder = profiler.event_id_builder();
             let event_label = profiler.get_or_alloc_cached_string(event_label);
 
             // Ensure the closure to create event arguments will only be called when argument
@@ -357,13 +384,13 @@ pub fn artifact_size<A>(&self, artifact_kind: &str, artifact_name: A, size: u64)
         A: Borrow<str> + Into<String>,
     {
         drop(self.exec(EventFilter::ARTIFACT_SIZES, |profiler| {
-            let builder = EventIdBuilder::new(&profiler.profiler);
+            let builder = profiler.event_id_builder();
             let event_label = profiler.get_or_alloc_cached_string(artifact_kind);
             let event_arg = profiler.get_or_alloc_cached_string(artifact_name);
             let event_id = builder.from_label_and_arg(event_label, event_arg);
             let thread_id = get_thread_id();
 
-            profiler.profiler.record_integer_event(
+            profiler.record_integer_event(
                 profiler.artifact_size_event_kind,
                 event_id,
                 thread_id,
@@ -381,7 +408,7 @@ pub fn generic_activity_with_args(
         event_args: &[String],
     ) -> TimingGuard<'_> {
         self.exec(EventFilter::GENERIC_ACTIVITIES, |profiler| {
-            let builder = EventIdBuilder::new(&profiler.profiler);
+            let builder = profiler.event_id_builder();
             let event_label = profiler.get_or_alloc_cached_string(event_label);
             let event_id = if profiler.event_filter_mask.contains(EventFilter::FUNCTION_ARGS) {
                 let event_args: Vec<_> = event_args
@@ -468,7 +495,7 @@ fn instant_query_event(
         let event_id = StringId::new_virtual(query_invocation_id.0);
         let thread_id = get_thread_id();
         let profiler = self.profiler.as_ref().unwrap();
-        profiler.profiler.record_instant_event(
+        profiler.record_instant_event(
             event_kind(profiler),
             EventId::from_virtual(event_id),
             thread_id,
@@ -531,8 +558,17 @@ pub fn record_arg<A>(&mut self, event_arg: A)
     }
 }
 
+/// Where a `SelfProfiler` records its events.
+enum Recorder {
+    /// Events are streamed to a file in the binary format of `measureme`.
+    Measureme(Profiler),
+    /// Events are kept in memory, and written as a Chrome trace when the
+    /// profiler is dropped.
+    ChromeTrace(ChromeTraceRecorder),
+}
+
 pub struct SelfProfiler {
-    profiler: Profiler,
+    recorder: Recorder,
     event_filter_mask: EventFilter,
 
     string_cache: RwLock<FxHashMap<String, StringId>>,
@@ -552,6 +588,7 @@ pub fn new(
         crate_name: Option<&str>,
         event_filters: Option<&[String]>,
         counter_name: &str,
+        format: SelfProfileFormat,
     ) -> Result<SelfProfiler, Box<dyn Error + Send + Sync>> {
         fs::create_dir_all(output_directory)?;
 
@@ -560,19 +597,47 @@ pub fn new(
         // length can behave as a source of entropy for heap addresses, when
         // ASLR is disabled and the heap is otherwise deterministic.
         let pid: u32 = process::id();
-        let filename = format!("{crate_name}-{pid:07}.rustc_profile");
-        let path = output_directory.join(&filename);
-        let profiler =
-            Profiler::with_counter(&path, measureme::counters::Counter::by_name(counter_name)?)?;
-
-        let query_event_kind = profiler.alloc_string("Query");
-        let generic_activity_event_kind = profiler.alloc_string("GenericActivity");
-        let incremental_load_result_event_kind = profiler.alloc_string("IncrementalLoadResult");
-        let incremental_result_hashing_event_kind =
+        let recorder = match format {
+            SelfProfileFormat::Measureme => {
+                let filename = format!("{crate_name}-{pid:07}.rustc_profile");
+                let path = output_directory.join(&filename);
+                let counter = measureme::counters::Counter::by_name(counter_name)?;
+                Recorder::Measureme(Profiler::with_counter(&path, counter)?)
+            }
+            SelfProfileFormat::ChromeTrace => {
+                if counter_name != "wall-time" {
+                    return Err(format!(
+                        "the `{counter_name}` counter is not supported by the `chrome-trace` format"
+                    )
+                    .into());
+                }
+                let filename = format!("{crate_name}-{pid:07}.trace.json");
+                Recorder::ChromeTrace(ChromeTraceRecorder::new(output_directory.join(&filename)))
+            }
+        };
+
+        let mut profiler = SelfProfiler {
+            recorder,
+            event_filter_mask: EventFilter::empty(),
+            string_cache: RwLock::new(FxHashMap::default()),
+            query_event_kind: StringId::INVALID,
+            generic_activity_event_kind: StringId::INVALID,
+            incremental_load_result_event_kind: StringId::INVALID,
+            incremental_result_hashing_event_kind: StringId::INVALID,
+            query_blocked_event_kind: StringId::INVALID,
+            query_cache_hit_event_kind: StringId::INVALID,
+            artifact_size_event_kind: StringId::INVALID,
+        };
+
+        profiler.query_event_kind = profiler.alloc_string("Query");
+        profiler.generic_activity_event_kind = profiler.alloc_string("GenericActivity");
+        profiler.incremental_load_result_event_kind =
+            profiler.alloc_string("IncrementalLoadResult");
+        profiler.incremental_result_hashing_event_kind =
             profiler.alloc_string("IncrementalResultHashing");
-        let query_blocked_event_kind = profiler.alloc_string("QueryBlocked");
-        let query_cache_hit_event_kind = profiler.alloc_string("QueryCacheHit");
-        let artifact_size_event_kind = profiler.alloc_string("ArtifactSize");
+        profiler.query_blocked_event_kind = profiler.alloc_string("QueryBlocked");
+        profiler.query_cache_hit_event_kind = profiler.alloc_string("QueryCacheHit");
+        profiler.artifact_size_event_kind = profiler.alloc_string("ArtifactSize");
 
         let mut event_filter_mask = EventFilter::empty();
 
@@ -607,24 +672,17 @@ pub fn new(
             event_filter_mask = EventFilter::DEFAULT;
         }
 
-        Ok(SelfProfiler {
-            profiler,
-            event_filter_mask,
-            string_cache: RwLock::new(FxHashMap::default()),
-            query_event_kind,
-            generic_activity_event_kind,
-            incremental_load_result_event_kind,
-            incremental_result_hashing_event_kind,
-            query_blocked_event_kind,
-            query_cache_hit_event_kind,
-            artifact_size_event_kind,
-        })
+        profiler.event_filter_mask = event_filter_mask;
+        Ok(profiler)
     }
 
     /// Allocates a new string in the profiling data. Does not do any caching
     /// or deduplication.
-    pub fn alloc_string<STR: SerializableString + ?Sized>(&self, s: &STR) -> StringId {
-        self.profiler.alloc_string(s)
+    pub fn alloc_string<STR: ProfilerString + ?Sized>(&self, s: &STR) -> StringId {
+        match &self.recorder {
+            Recorder::Measureme(profiler) => profiler.alloc_string(s),
+            Recorder::ChromeTrace(recorder) => recorder.alloc_string(s),
+        }
     }
 
     /// Gets a `StringId` for the given string. This method makes sure that
@@ -650,7 +708,7 @@ pub fn get_or_alloc_cached_string<A>(&self, s: A) -> StringId
         match string_cache.entry(s.into()) {
             Entry::Occupied(e) => *e.get(),
             Entry::Vacant(e) => {
-                let string_id = self.profiler.alloc_string(&e.key()[..]);
+                let string_id = self.alloc_string(&e.key()[..]);
                 *e.insert(string_id)
             }
         }
@@ -658,7 +716,10 @@ pub fn get_or_alloc_cached_string<A>(&self, s: A) -> StringId
 
     pub fn map_query_invocation_id_to_string(&self, from: QueryInvocationId, to: StringId) {
         let from = StringId::new_virtual(from.0);
-        self.profiler.map_virtual_to_concrete_string(from, to);
+        match &self.recorder {
+            Recorder::Measureme(profiler) => profiler.map_virtual_to_concrete_string(from, to),
+            Recorder::ChromeTrace(recorder) => recorder.map_virtual_to_concrete_string(from, to),
+        }
     }
 
     pub fn bulk_map_query_invocation_id_to_single_string<I>(&self, from: I, to: StringId)
@@ -666,7 +727,14 @@ pub fn bulk_map_query_invocation_id_to_single_string<I>(&self, from: I, to: Stri
         I: Iterator<Item = QueryInvocationId> + ExactSizeIterator,
     {
         let from = from.map(|qid| StringId::new_virtual(qid.0));
-        self.profiler.bulk_map_virtual_to_single_concrete_string(from, to);
+        match &self.recorder {
+            Recorder::Measureme(profiler) => {
+                profiler.bulk_map_virtual_to_single_concrete_string(from, to)
+            }
+            Recorder::ChromeTrace(recorder) => {
+                recorder.bulk_map_virtual_to_single_concrete_string(from, to)
+            }
+        }
     }
 
     pub fn query_key_recording_enabled(&self) -> bool {
@@ -674,12 +742,103 @@ pub fn query_key_recording_enabled(&self) -> bool {
     }
 
     pub fn event_id_builder(&self) -> EventIdBuilder<'_> {
-        EventIdBuilder::new(&self.profiler)
+        EventIdBuilder { profiler: self }
+    }
+
+    fn record_instant_event(&self, event_kind: StringId, event_id: EventId, thread_id: u32) {
+        match &self.recorder {
+            Recorder::Measureme(profiler) => {
+                profiler.record_instant_event(event_kind, event_id, thread_id)
+            }
+            Recorder::ChromeTrace(recorder) => {
+                recorder.record_instant_event(event_kind, event_id, thread_id)
+            }
+        }
+    }
+
+    fn record_integer_event(
+        &self,
+        event_kind: StringId,
+        event_id: EventId,
+        thread_id: u32,
+        value: u64,
+    ) {
+        match &self.recorder {
+            Recorder::Measureme(profiler) => {
+                profiler.record_integer_event(event_kind, event_id, thread_id, value)
+            }
+            Recorder::ChromeTrace(recorder) => {
+                recorder.record_integer_event(event_kind, event_id, thread_id, value)
+            }
+        }
+    }
+}
+
+/// A string that can be allocated in the profiling data.
+pub trait ProfilerString: SerializableString {
+    /// Appends the contents of the string to `out`, using `resolve` to append
+    /// the contents of the strings it refers to.
+    fn append_to(&self, out: &mut String, resolve: &impl Fn(StringId, &mut String));
+}
+
+impl ProfilerString for str {
+    fn append_to(&self, out: &mut String, _resolve: &impl Fn(StringId, &mut String)) {
+        out.push_str(self)
+    }
+}
+
+impl ProfilerString for [StringComponent<'_>] {
+    fn append_to(&self, out: &mut String, resolve: &impl Fn(StringId, &mut String)) {
+        for component in self {
+            match *component {
+                StringComponent::Value(s) => out.push_str(s),
+                StringComponent::Ref(string_id) => resolve(string_id, out),
+            }
+        }
     }
 }
 
+/// Builds `EventId`s out of a label and arguments, like the `EventIdBuilder`
+/// of `measureme`, but going through the `SelfProfiler` to allocate strings.
+pub struct EventIdBuilder<'p> {
+    profiler: &'p SelfProfiler,
+}
+
+impl EventIdBuilder<'_> {
+    #[inline]
+    pub fn from_label(&self, label: StringId) -> EventId {
+        EventId::from_label(label)
+    }
+
+    pub fn from_label_and_arg(&self, label: StringId, arg: StringId) -> EventId {
+        EventId::from_label(self.profiler.alloc_string(
+            &[
+                StringComponent::Ref(label),
+                StringComponent::Value(SEPARATOR_BYTE),
+                StringComponent::Ref(arg),
+            ][..],
+        ))
+    }
+
+    pub fn from_label_and_args(&self, label: StringId, args: &[StringId]) -> EventId {
+        let mut components =
+            SmallVec::<[StringComponent<'_>; 7]>::with_capacity(1 + args.len() * 2);
+        components.push(StringComponent::Ref(label));
+        for &arg in args {
+            components.push(StringComponent::Value(SEPARATOR_BYTE));
+            components.push(StringComponent::Ref(arg));
+        }
+        EventId::from_label(self.profiler.alloc_string(&components[..]))
+    }
+}
+
+enum RawTimingGuard<'a> {
+    Measureme(measureme::TimingGuard<'a>),
+    ChromeTrace(chrome_trace::TimingGuard<'a>),
+}
+
 #[must_use]
-pub struct TimingGuard<'a>(Option<measureme::TimingGuard<'a>>);
+pub struct TimingGuard<'a>(Option<RawTimingGuard<'a>>);
 
 impl<'a> TimingGuard<'a> {
     #[inline]
@@ -689,9 +848,14 @@ pub fn start(
         event_id: EventId,
     ) -> TimingGuard<'a> {
         let thread_id = get_thread_id();
-        let raw_profiler = &profiler.profiler;
-        let timing_guard =
-            raw_profiler.start_recording_interval_event(event_kind, event_id, thread_id);
+        let timing_guard = match &profiler.recorder {
+            Recorder::Measureme(profiler) => RawTimingGuard::Measureme(
+                profiler.start_recording_interval_event(event_kind, event_id, thread_id),
+            ),
+            Recorder::ChromeTrace(recorder) => RawTimingGuard::ChromeTrace(
+                recorder.start_recording_interval_event(event_kind, event_id, thread_id),
+            ),
+        };
         TimingGuard(Some(timing_guard))
     }
 
@@ -701,7 +865,14 @@ pub fn finish_with_query_invocation_id(self, query_invocation_id: QueryInvocatio
             outline(|| {
                 let event_id = StringId::new_virtual(query_invocation_id.0);
                 let event_id = EventId::from_virtual(event_id);
-                guard.finish_with_override_event_id(event_id);
+                match guard {
+                    RawTimingGuard::Measureme(guard) => {
+                        guard.finish_with_override_event_id(event_id)
+                    }
+                    RawTimingGuard::ChromeTrace(guard) => {
+                        guard.finish_with_override_event_id(event_id)
+                    }
+                }
             });
         }
     }
@@ -0,0 +1,400 @@
+//! The recorder used by `-Z self-profile-format=chrome-trace`.
+//!
+//! It keeps the events in memory, and writes them in the [trace event format][tef] when it is
+//! dropped, so that they can be loaded straight into Perfetto or `chrome://tracing`. The time spent
+//! in each query and activity is also summarized on stderr.
+//!
+//! [tef]: https://docs.google.com/document/d/1CvAClvFfyA5R-PhYUmn5OOQtYMH4h6I0nSsKchNAySU
+
+use super::{duration_to_secs_str, ProfilerString};
+use crate::fx::{FxHashMap, FxIndexMap};
+
+use std::cmp::Reverse;
+use std::fmt;
+use std::fs;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::{Duration, Instant};
+
+use measureme::event_id::SEPARATOR_BYTE;
+use measureme::{EventId, StringId};
+use parking_lot::{Mutex, RwLock};
+
+/// The `StringId` of the first string allocated by the recorder. Virtual `StringId`s, which
+/// identify query invocations, are all below this value.
+const FIRST_STRING_ID: u32 = 1 << 31;
+
+/// The number of items listed in the summary.
+const SUMMARY_LEN: usize = 30;
+
+pub(super) struct ChromeTraceRecorder {
+    path: PathBuf,
+    start_time: Instant,
+    next_string_id: AtomicU32,
+    /// The contents of every string allocated so far.
+    strings: RwLock<FxHashMap<StringId, Box<str>>>,
+    /// The strings the virtual `StringId`s have been mapped to.
+    virtual_strings: Mutex<FxHashMap<StringId, StringId>>,
+    events: Mutex<Events>,
+}
+
+#[derive(Default)]
+struct Events {
+    events: Vec<Event>,
+    /// The names of the threads that recorded an event.
+    thread_names: FxHashMap<u32, String>,
+}
+
+struct Event {
+    event_kind: StringId,
+    event_id: StringId,
+    thread_id: u32,
+    payload: Payload,
+}
+
+/// The timestamps are in nanoseconds since the creation of the recorder.
+enum Payload {
+    Interval { start: u64, end: u64 },
+    Instant { timestamp: u64 },
+    Integer { timestamp: u64, value: u64 },
+}
+
+impl ChromeTraceRecorder {
+    pub(super) fn new(path: PathBuf) -> ChromeTraceRecorder {
+        ChromeTraceRecorder {
+            path,
+            start_time: Instant::now(),
+            next_string_id: AtomicU32::new(FIRST_STRING_ID),
+            strings: Default::default(),
+            virtual_strings: Default::default(),
+            events: Default::default(),
+        }
+    }
+
+    pub(super) fn alloc_string<STR: ProfilerString + ?Sized>(&self, s: &STR) -> StringId {
+        let mut contents = String::new();
+        {
+            let strings = self.strings.read();
+            s.append_to(&mut contents, &|string_id, out: &mut String| {
+                if let Some(s) = strings.get(&string_id) {
+                    out.push_str(s);
+                }
+            });
+        }
+        let string_id = StringId::new(self.next_string_id.fetch_add(1, Ordering::Relaxed));
+        self.strings.write().insert(string_id, contents.into_boxed_str());
+        string_id
+    }
+
+    pub(super) fn map_virtual_to_concrete_string(&self, from: StringId, to: StringId) {
+        self.virtual_strings.lock().insert(from, to);
+    }
+
+    pub(super) fn bulk_map_virtual_to_single_concrete_string<I>(&self, from: I, to: StringId)
+    where
+        I: Iterator<Item = StringId>,
+    {
+        self.virtual_strings.lock().extend(from.map(|from| (from, to)));
+    }
+
+    pub(super) fn start_recording_interval_event(
+        &self,
+        event_kind: StringId,
+        event_id: EventId,
+        thread_id: u32,
+    ) -> TimingGuard<'_> {
+        TimingGuard {
+            recorder: self,
+            event_kind,
+            event_id: event_id.to_string_id(),
+            thread_id,
+            start: self.timestamp(),
+        }
+    }
+
+    pub(super) fn record_instant_event(
+        &self,
+        event_kind: StringId,
+        event_id: EventId,
+        thread_id: u32,
+    ) {
+        let payload = Payload::Instant { timestamp: self.timestamp() };
+        self.record(Event { event_kind, event_id: event_id.to_string_id(), thread_id, payload });
+    }
+
+    pub(super) fn record_integer_event(
+        &self,
+        event_kind: StringId,
+        event_id: EventId,
+        thread_id: u32,
+        value: u64,
+    ) {
+        let payload = Payload::Integer { timestamp: self.timestamp(), value };
+        self.record(Event { event_kind, event_id: event_id.to_string_id(), thread_id, payload });
+    }
+
+    fn timestamp(&self) -> u64 {
+        self.start_time.elapsed().as_nanos() as u64
+    }
+
+    /// Records an event. This must be called from the thread that recorded the event.
+    fn record(&self, event: Event) {
+        let mut events = self.events.lock();
+        events.thread_names.entry(event.thread_id).or_insert_with(|| {
+            match std::thread::current().name() {
+                Some(name) => name.to_owned(),
+                None => format!("thread {}", event.thread_id),
+            }
+        });
+        events.events.push(event);
+    }
+}
+
+impl Drop for ChromeTraceRecorder {
+    fn drop(&mut self) {
+        let strings = Strings {
+            strings: self.strings.get_mut(),
+            virtual_strings: self.virtual_strings.get_mut(),
+        };
+        let Events { events, thread_names } = self.events.get_mut();
+        if let Err(err) = write_trace(&self.path, &strings, events, thread_names) {
+            eprintln!(
+                "warning: failed to write the self-profile trace to `{}`: {err}",
+                self.path.display()
+            );
+        }
+        print_summary(&strings, events);
+    }
+}
+
+pub(super) struct TimingGuard<'a> {
+    recorder: &'a ChromeTraceRecorder,
+    event_kind: StringId,
+    event_id: StringId,
+    thread_id: u32,
+    start: u64,
+}
+
+impl TimingGuard<'_> {
+    pub(super) fn finish_with_override_event_id(mut self, event_id: EventId) {
+        self.event_id = event_id.to_string_id();
+    }
+}
+
+impl Drop for TimingGuard<'_> {
+    fn drop(&mut self) {
+        let payload = Payload::Interval { start: self.start, end: self.recorder.timestamp() };
+        self.recorder.record(Event {
+            event_kind: self.event_kind,
+            event_id: self.event_id,
+            thread_id: self.thread_id,
+            payload,
+        });
+    }
+}
+
+struct Strings<'a> {
+    strings: &'a FxHashMap<StringId, Box<str>>,
+    virtual_strings: &'a FxHashMap<StringId, StringId>,
+}
+
+impl<'a> Strings<'a> {
+    /// Returns the contents of a string, or `None` if it is unknown, e.g., for the virtual
+    /// `StringId` of a query invocation that has never been mapped.
+    fn get(&self, string_id: StringId) -> Option<&'a str> {
+        let string_id = self.virtual_strings.get(&string_id).copied().unwrap_or(string_id);
+        self.strings.get(&string_id).map(|s| &**s)
+    }
+
+    fn event_kind(&self, event: &Event) -> &'a str {
+        self.get(event.event_kind).unwrap_or("")
+    }
+
+    /// Returns the label and the arguments of an event. Events with an unknown `event_id` are
+    /// labeled with their kind.
+    fn label_and_args(&self, event: &Event) -> (&'a str, impl Iterator<Item = &'a str>) {
+        let event_id = self.get(event.event_id).unwrap_or_else(|| self.event_kind(event));
+        let mut parts = event_id.split(SEPARATOR_BYTE);
+        (parts.next().unwrap(), parts)
+    }
+}
+
+fn write_trace(
+    path: &Path,
+    strings: &Strings<'_>,
+    events: &[Event],
+    thread_names: &FxHashMap<u32, String>,
+) -> io::Result<()> {
+    let pid = process::id();
+    let mut file = BufWriter::new(fs::File::create(path)?);
+    write!(file, r#"{{"displayTimeUnit":"ms","traceEvents":["#)?;
+    let mut separator = "\n";
+
+    let mut threads: Vec<_> = thread_names.iter().collect();
+    threads.sort();
+    for (thread_id, name) in threads {
+        write!(
+            file,
+            r#"{separator}{{"name":"thread_name","ph":"M","pid":{pid},"tid":{thread_id},"#
+        )?;
+        write!(file, r#""args":{{"name":{}}}}}"#, JsonStr(name))?;
+        separator = ",\n";
+    }
+
+    for event in events {
+        let (label, args) = strings.label_and_args(event);
+        write!(
+            file,
+            r#"{separator}{{"name":{},"cat":{},"pid":{pid},"tid":{},"#,
+            JsonStr(label),
+            JsonStr(strings.event_kind(event)),
+            event.thread_id
+        )?;
+        match event.payload {
+            Payload::Interval { start, end } => {
+                write!(file, r#""ph":"X","ts":{},"dur":{}"#, Micros(start), Micros(end - start))?
+            }
+            Payload::Instant { timestamp } | Payload::Integer { timestamp, .. } => {
+                write!(file, r#""ph":"i","s":"t","ts":{}"#, Micros(timestamp))?
+            }
+        }
+        write!(file, r#","args":{{"#)?;
+        let mut arg_separator = "";
+        for (i, arg) in args.enumerate() {
+            write!(file, r#"{arg_separator}"arg{i}":{}"#, JsonStr(arg))?;
+            arg_separator = ",";
+        }
+        if let Payload::Integer { value, .. } = event.payload {
+            write!(file, r#"{arg_separator}"value":{value}"#)?;
+        }
+        write!(file, "}}}}")?;
+        separator = ",\n";
+    }
+
+    writeln!(file, "\n]}}")?;
+    file.flush()
+}
+
+#[derive(Default)]
+struct SummaryRow {
+    count: usize,
+    self_time: u64,
+    total_time: u64,
+}
+
+/// Prints the time spent in each kind of interval event, from the slowest to the fastest.
+fn print_summary(strings: &Strings<'_>, events: &[Event]) {
+    let intervals: Vec<_> = events
+        .iter()
+        .filter_map(|event| match event.payload {
+            Payload::Interval { start, end } => Some((event, start, end)),
+            _ => None,
+        })
+        .collect();
+    let self_times = self_times(
+        &intervals
+            .iter()
+            .map(|&(event, start, end)| (event.thread_id, start, end))
+            .collect::<Vec<_>>(),
+    );
+
+    let mut rows: FxIndexMap<(&str, &str), SummaryRow> = FxIndexMap::default();
+    for (&(event, start, end), self_time) in intervals.iter().zip(self_times) {
+        let (label, _) = strings.label_and_args(event);
+        let row = rows.entry((label, strings.event_kind(event))).or_default();
+        row.count += 1;
+        row.self_time += self_time;
+        row.total_time += end - start;
+    }
+    let total_self_time: u64 = rows.values().map(|row| row.self_time).sum();
+    let mut rows: Vec<_> = rows.into_iter().collect();
+    rows.sort_by_key(|(_, row)| Reverse(row.self_time));
+
+    let secs = |nanos| duration_to_secs_str(Duration::from_nanos(nanos));
+    let shown = &rows[..rows.len().min(SUMMARY_LEN)];
+    let label_width = shown.iter().map(|((label, _), _)| label.len()).max().unwrap_or(0).max(4);
+    let kind_width = shown.iter().map(|((_, kind), _)| kind.len()).max().unwrap_or(0).max(4);
+    eprintln!(
+        "{:label_width$}  {:kind_width$}  {:>9}  {:>6}  {:>8}  {:>10}",
+        "Item", "Kind", "Self time", "%", "Count", "Total time"
+    );
+    for ((label, kind), row) in shown {
+        let percent = if total_self_time == 0 {
+            0.0
+        } else {
+            row.self_time as f64 * 100.0 / total_self_time as f64
+        };
+        eprintln!(
+            "{label:label_width$}  {kind:kind_width$}  {:>9}  {percent:>5.1}%  {:>8}  {:>10}",
+            secs(row.self_time),
+            row.count,
+            secs(row.total_time)
+        );
+    }
+    if rows.len() > shown.len() {
+        eprintln!("... and {} more items", rows.len() - shown.len());
+    }
+}
+
+/// Computes the self time of each `(thread_id, start, end)` interval, i.e., its duration minus
+/// the duration of the intervals directly nested in it on the same thread.
+pub(super) fn self_times(intervals: &[(u32, u64, u64)]) -> Vec<u64> {
+    let mut self_times: Vec<u64> = intervals.iter().map(|&(_, start, end)| end - start).collect();
+
+    // Visit the intervals of each thread in order, with the enclosing intervals first.
+    let mut order: Vec<usize> = (0..intervals.len()).collect();
+    order.sort_by_key(|&i| {
+        let (thread_id, start, end) = intervals[i];
+        (thread_id, start, Reverse(end))
+    });
+
+    let mut stack: Vec<usize> = Vec::new();
+    let mut current_thread = None;
+    for i in order {
+        let (thread_id, start, end) = intervals[i];
+        if current_thread != Some(thread_id) {
+            stack.clear();
+            current_thread = Some(thread_id);
+        }
+        while stack.last().is_some_and(|&parent| intervals[parent].2 <= start) {
+            stack.pop();
+        }
+        if let Some(&parent) = stack.last() {
+            let nested = end.min(intervals[parent].2) - start;
+            self_times[parent] = self_times[parent].saturating_sub(nested);
+        }
+        stack.push(i);
+    }
+    self_times
+}
+
+/// Formats a timestamp or a duration in nanoseconds as microseconds.
+struct Micros(u64);
+
+impl fmt::Display for Micros {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{:03}", self.0 / 1000, self.0 % 1000)
+    }
+}
+
+/// Formats a string as a JSON string literal.
+struct JsonStr<'a>(&'a str);
+
+impl fmt::Display for JsonStr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("\"")?;
+        for c in self.0.chars() {
+            match c {
+                '"' => f.write_str("\\\"")?,
+                '\\' => f.write_str("\\\\")?,
+                '\n' => f.write_str("\\n")?,
+                '\t' => f.write_str("\\t")?,
+                c if c.is_control() => write!(f, "\\u{:04x}", c as u32)?,
+                c => write!(f, "{c}")?,
+            }
+        }
+        f.write_str("\"")
+    }
+}
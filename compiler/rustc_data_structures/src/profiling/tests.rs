@@ -1,4 +1,7 @@
-use super::JsonTimePassesEntry;
+use super::chrome_trace::self_times;
+use super::{JsonTimePassesEntry, SelfProfileFormat, SelfProfiler, SelfProfilerRef};
+use std::fs;
+use std::sync::Arc;
 
 #[test]
 fn with_rss() {
@@ -17,3 +20,39 @@ fn no_rss() {
         r#"{"pass":"typeck","time":56.1,"rss_start":null,"rss_end":null}"#
     )
 }
+
+#[test]
+fn chrome_trace() {
+    let dir = tempfile::tempdir().unwrap();
+    let events = ["default".to_string(), "args".to_string()];
+    let profiler = SelfProfiler::new(
+        dir.path(),
+        Some("test"),
+        Some(&events),
+        "wall-time",
+        SelfProfileFormat::ChromeTrace,
+    )
+    .unwrap();
+    let profiler = SelfProfilerRef::new(Some(Arc::new(profiler)), None);
+    {
+        let _outer = profiler.generic_activity("outer");
+        let _inner =
+            profiler.generic_activity_with_args("inner", &["a\"b".to_string(), "c".into()]);
+    }
+    drop(profiler);
+
+    let path = fs::read_dir(dir.path()).unwrap().next().unwrap().unwrap().path();
+    assert!(path.to_str().unwrap().ends_with(".trace.json"));
+    let trace = fs::read_to_string(path).unwrap();
+    assert!(trace.starts_with(r#"{"displayTimeUnit":"ms","traceEvents":["#));
+    assert!(trace.contains(r#"{"name":"outer","cat":"GenericActivity","#));
+    assert!(trace.contains(r#"{"name":"inner","cat":"GenericActivity","#));
+    assert!(trace.contains(r#""args":{"arg0":"a\"b","arg1":"c"}}"#));
+    assert!(trace.ends_with("\n]}\n"));
+}
+
+#[test]
+fn chrome_trace_self_times() {
+    let intervals = [(1, 0, 10), (1, 2, 5), (1, 3, 4), (2, 0, 10), (1, 6, 8)];
+    assert_eq!(self_times(&intervals), [5, 2, 1, 10, 2]);
+}
@@ -119,6 +119,10 @@ pub struct Diagnostic {
     /// otherwise hash is based on the all the fields
     pub is_lint: bool,
 
+    /// Whether this is a lint warning that a warning baseline can suppress. This includes the
+    /// warnings that are turned into errors through the `warnings` lint.
+    pub is_baseline_warning: bool,
+
     /// With `-Ztrack_diagnostics` enabled,
     /// we print where in rustc this error was emitted.
     pub emitted_at: DiagnosticLocation,
@@ -232,6 +236,7 @@ pub fn new_with_messages(level: Level, messages: Vec<(DiagnosticMessage, Style)>
             args: Default::default(),
             sort_span: DUMMY_SP,
             is_lint: false,
+            is_baseline_warning: false,
             emitted_at: DiagnosticLocation::caller(),
         }
     }
@@ -898,6 +903,11 @@ pub fn is_lint(&mut self) -> &mut Self {
         self
     }
 
+    pub fn is_baseline_warning(&mut self) -> &mut Self {
+        self.is_baseline_warning = true;
+        self
+    }
+
     pub fn code(&mut self, s: DiagnosticId) -> &mut Self {
         self.code = Some(s);
         self
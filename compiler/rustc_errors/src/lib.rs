@@ -59,7 +59,7 @@
 use registry::Registry;
 use rustc_data_structures::fx::{FxHashSet, FxIndexMap, FxIndexSet};
 use rustc_data_structures::stable_hasher::{Hash128, StableHasher};
-use rustc_data_structures::sync::{Lock, Lrc};
+use rustc_data_structures::sync::{DynSend, Lock, Lrc};
 use rustc_data_structures::AtomicRef;
 use rustc_lint_defs::LintExpectationId;
 use rustc_span::source_map::SourceMap;
@@ -86,6 +86,7 @@
 mod lock;
 pub mod markdown;
 pub mod registry;
+pub mod sarif;
 mod snippet;
 mod styled_buffer;
 #[cfg(test)]
@@ -489,8 +490,16 @@ struct DiagCtxtInner {
     /// The file where the ICE information is stored. This allows delayed_span_bug backtraces to be
     /// stored along side the main panic backtrace.
     ice_file: Option<PathBuf>,
+
+    /// Returns whether a lint warning, given the name of its lint and its primary span, is
+    /// covered by the warning baseline of the session, in which case it isn't reported.
+    warning_baseline: Option<Box<WarningBaselineFilter>>,
 }
 
+/// The filter of the lint warnings suppressed by a warning baseline, see
+/// [`DiagCtxt::with_warning_baseline`].
+pub type WarningBaselineFilter = dyn Fn(&str, Span) -> bool + DynSend;
+
 /// A key denoting where from a diagnostic was stashed.
 #[derive(Copy, Clone, PartialEq, Eq, Hash)]
 pub enum StashKey {
@@ -594,6 +603,11 @@ pub fn with_ice_file(mut self, ice_file: PathBuf) -> Self {
         self
     }
 
+    pub fn with_warning_baseline(mut self, filter: Box<WarningBaselineFilter>) -> Self {
+        self.inner.get_mut().warning_baseline = Some(filter);
+        self
+    }
+
     pub fn with_emitter(emitter: Box<DynEmitter>) -> Self {
         Self {
             inner: Lock::new(DiagCtxtInner {
@@ -616,6 +630,7 @@ pub fn with_emitter(emitter: Box<DynEmitter>) -> Self {
                 unstable_expect_diagnostics: Vec::new(),
                 fulfilled_expectations: Default::default(),
                 ice_file: None,
+                warning_baseline: None,
             }),
         }
     }
@@ -1319,6 +1334,17 @@ fn emit_diagnostic(&mut self, mut diagnostic: Diagnostic) -> Option<ErrorGuarant
 
         let mut guaranteed = None;
         (*TRACK_DIAGNOSTIC)(diagnostic, &mut |mut diagnostic| {
+            // The warning baseline is only checked once the diagnostic has been tracked, so that
+            // the warnings replayed from the incremental cache are checked again.
+            if diagnostic.is_baseline_warning
+                && let Some(filter) = &self.warning_baseline
+                && let Some(DiagnosticId::Lint { name, .. }) = &diagnostic.code
+                && let Some(span) = diagnostic.span.primary_span()
+                && filter(name, span)
+            {
+                return;
+            }
+
             if let Some(ref code) = diagnostic.code {
                 self.emitted_diagnostic_codes.insert(code.clone());
             }
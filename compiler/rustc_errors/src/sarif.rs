@@ -0,0 +1,542 @@
+//! A SARIF emitter for errors.
+//!
+//! Unlike the JSON emitter, which writes one message per diagnostic, this emitter
+//! buffers every diagnostic of a compilation session and writes a single
+//! [SARIF 2.1.0] log when it is dropped. Each distinct error code or lint name
+//! becomes a *rule* of the run, and diagnostics become *results* referencing
+//! those rules. Suggestions are mapped to SARIF *fixes*, tagged with their
+//! `Applicability`.
+//!
+//! The format of the SARIF output should be considered *unstable*, beyond what
+//! the SARIF specification itself mandates.
+//!
+//! [SARIF 2.1.0]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+
+use rustc_span::source_map::{FilePathMapping, SourceMap};
+
+use crate::emitter::Emitter;
+use crate::registry::Registry;
+use crate::translation::{to_fluent_args, Translate};
+use crate::{CodeSuggestion, DiagnosticId, FluentBundle, LazyFallbackBundle, Level, SubDiagnostic};
+use rustc_lint_defs::Applicability;
+
+use rustc_data_structures::fx::FxIndexMap;
+use rustc_data_structures::sync::{IntoDynSyncSend, Lrc};
+use rustc_error_messages::FluentArgs;
+use rustc_span::{SourceFile, Span};
+use std::error::Report;
+use std::io::{self, Write};
+
+use serde::Serialize;
+
+#[cfg(test)]
+mod tests;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+const ERROR_CODES_URL: &str = "https://doc.rust-lang.org/error_codes";
+
+pub struct SarifEmitter {
+    dst: IntoDynSyncSend<Box<dyn Write + Send>>,
+    registry: Option<Registry>,
+    sm: Lrc<SourceMap>,
+    fluent_bundle: Option<Lrc<FluentBundle>>,
+    fallback_bundle: LazyFallbackBundle,
+    pretty: bool,
+    /// Whether no log is written if no diagnostic was recorded. This is `true` for the
+    /// emitters used for early diagnostics, which are created several times per session,
+    /// so that they only write a log of their own if they have something to report.
+    skip_empty_log: bool,
+    /// Rules referenced by the results so far, indexed by error code or lint name.
+    rules: FxIndexMap<String, Rule>,
+    results: Vec<SarifResult>,
+}
+
+impl SarifEmitter {
+    pub fn stderr(
+        registry: Option<Registry>,
+        source_map: Lrc<SourceMap>,
+        fluent_bundle: Option<Lrc<FluentBundle>>,
+        fallback_bundle: LazyFallbackBundle,
+        pretty: bool,
+    ) -> SarifEmitter {
+        SarifEmitter::new(
+            Box::new(io::BufWriter::new(io::stderr())),
+            registry,
+            source_map,
+            fluent_bundle,
+            fallback_bundle,
+            pretty,
+        )
+    }
+
+    /// Creates an emitter for early diagnostics, which only writes a log if a diagnostic
+    /// was actually emitted.
+    pub fn basic(pretty: bool, fallback_bundle: LazyFallbackBundle) -> SarifEmitter {
+        let file_path_mapping = FilePathMapping::empty();
+        SarifEmitter::stderr(
+            None,
+            Lrc::new(SourceMap::new(file_path_mapping)),
+            None,
+            fallback_bundle,
+            pretty,
+        )
+        .skip_empty_log(true)
+    }
+
+    pub fn new(
+        dst: Box<dyn Write + Send>,
+        registry: Option<Registry>,
+        source_map: Lrc<SourceMap>,
+        fluent_bundle: Option<Lrc<FluentBundle>>,
+        fallback_bundle: LazyFallbackBundle,
+        pretty: bool,
+    ) -> SarifEmitter {
+        SarifEmitter {
+            dst: IntoDynSyncSend(dst),
+            registry,
+            sm: source_map,
+            fluent_bundle,
+            fallback_bundle,
+            pretty,
+            skip_empty_log: false,
+            rules: Default::default(),
+            results: Vec::new(),
+        }
+    }
+
+    pub fn skip_empty_log(self, skip_empty_log: bool) -> Self {
+        Self { skip_empty_log, ..self }
+    }
+
+    fn write_log(&mut self) -> io::Result<()> {
+        let log = SarifLog {
+            schema: SARIF_SCHEMA,
+            version: SARIF_VERSION,
+            runs: [Run {
+                tool: Tool {
+                    driver: ToolComponent {
+                        name: "rustc",
+                        information_uri: "https://www.rust-lang.org/",
+                        rules: self.rules.values().collect(),
+                    },
+                },
+                column_kind: "unicodeCodePoints",
+                results: &self.results,
+            }],
+        };
+        if self.pretty {
+            serde_json::to_writer_pretty(&mut *self.dst, &log)?
+        } else {
+            serde_json::to_writer(&mut *self.dst, &log)?
+        };
+        self.dst.write_all(b"\n")?;
+        self.dst.flush()
+    }
+
+    /// Returns the index of the rule for `code`, registering it if this is its first use.
+    fn rule_index(&mut self, code: &DiagnosticId) -> usize {
+        let (id, is_error_code) = match code {
+            DiagnosticId::Error(code) => (code, true),
+            DiagnosticId::Lint { name, .. } => (name, false),
+        };
+        if let Some(index) = self.rules.get_index_of(id) {
+            return index;
+        }
+
+        let description = if is_error_code {
+            self.registry.as_ref().and_then(|registry| registry.try_find_description(id).ok())
+        } else {
+            None
+        };
+        let rule = Rule {
+            id: id.clone(),
+            help: description
+                .map(|text| MultiformatMessage { text: text.to_owned(), markdown: Some(text) }),
+            help_uri: is_error_code.then(|| format!("{ERROR_CODES_URL}/{id}.html")),
+        };
+        self.rules.insert_full(id.clone(), rule).0
+    }
+}
+
+impl Drop for SarifEmitter {
+    fn drop(&mut self) {
+        if self.results.is_empty() && self.skip_empty_log {
+            return;
+        }
+        // Don't cause a double panic if the log is written while unwinding from an ICE.
+        if let Err(e) = self.write_log()
+            && !std::thread::panicking()
+        {
+            panic!("failed to print SARIF log: {e:?}");
+        }
+    }
+}
+
+impl Translate for SarifEmitter {
+    fn fluent_bundle(&self) -> Option<&Lrc<FluentBundle>> {
+        self.fluent_bundle.as_ref()
+    }
+
+    fn fallback_fluent_bundle(&self) -> &FluentBundle {
+        &self.fallback_bundle
+    }
+}
+
+impl Emitter for SarifEmitter {
+    fn emit_diagnostic(&mut self, diag: &crate::Diagnostic) {
+        // Failure notes ("For more information about this error, ...") are about the
+        // other diagnostics and carry no information of their own.
+        if diag.level.is_failure_note() {
+            return;
+        }
+        let rule_index = diag.code.as_ref().map(|code| self.rule_index(code));
+        let result = SarifResult::from_errors_diagnostic(diag, rule_index, self);
+        self.results.push(result);
+    }
+
+    fn source_map(&self) -> Option<&Lrc<SourceMap>> {
+        Some(&self.sm)
+    }
+
+    fn should_show_explain(&self) -> bool {
+        // The explanations are available as the help of the rules instead.
+        false
+    }
+}
+
+// The following data types are provided just for serialisation.
+
+#[derive(Serialize)]
+struct SarifLog<'a> {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: [Run<'a>; 1],
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Run<'a> {
+    tool: Tool<'a>,
+    /// Columns are counted in characters, like in the other diagnostic outputs.
+    column_kind: &'static str,
+    results: &'a [SarifResult],
+}
+
+#[derive(Serialize)]
+struct Tool<'a> {
+    driver: ToolComponent<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ToolComponent<'a> {
+    name: &'static str,
+    information_uri: &'static str,
+    rules: Vec<&'a Rule>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Rule {
+    /// The error code (e.g. "E0308") or lint name (e.g. "unused_variables").
+    id: String,
+    /// The long explanation of an error code, as shown by `rustc --explain`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    help: Option<MultiformatMessage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    help_uri: Option<String>,
+}
+
+#[derive(Serialize)]
+struct MultiformatMessage {
+    text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    markdown: Option<&'static str>,
+}
+
+#[derive(Serialize)]
+struct Message {
+    text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_index: Option<usize>,
+    /// "error", "warning", "note" or "none".
+    level: &'static str,
+    message: Message,
+    /// The primary spans of the diagnostic.
+    locations: Vec<Location>,
+    /// The secondary spans of the diagnostic and the spans of its notes and helps.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    related_locations: Vec<Location>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fixes: Vec<Fix>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Location {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<usize>,
+    physical_location: PhysicalLocation,
+    /// The label of the span, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<Message>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PhysicalLocation {
+    artifact_location: ArtifactLocation,
+    region: Region,
+}
+
+#[derive(Serialize)]
+struct ArtifactLocation {
+    uri: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Region {
+    /// 1-based.
+    start_line: usize,
+    /// 1-based, character offset.
+    start_column: usize,
+    end_line: usize,
+    /// 1-based, character offset, exclusive.
+    end_column: usize,
+    byte_offset: u32,
+    byte_length: u32,
+    /// The source text covered by the region.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    snippet: Option<ArtifactContent>,
+}
+
+#[derive(Serialize)]
+struct ArtifactContent {
+    text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Fix {
+    description: Message,
+    artifact_changes: Vec<ArtifactChange>,
+    properties: FixProperties,
+}
+
+#[derive(Serialize)]
+struct FixProperties {
+    applicability: Applicability,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ArtifactChange {
+    artifact_location: ArtifactLocation,
+    replacements: Vec<Replacement>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Replacement {
+    deleted_region: Region,
+    inserted_content: ArtifactContent,
+}
+
+/// Maps a diagnostic level to a SARIF `result.level`.
+fn sarif_level(level: Level) -> &'static str {
+    match level {
+        Level::Bug | Level::DelayedBug(_) | Level::Fatal | Level::Error => "error",
+        Level::ForceWarning(_) | Level::Warning => "warning",
+        Level::Note | Level::OnceNote | Level::Help | Level::OnceHelp | Level::FailureNote => {
+            "note"
+        }
+        Level::Allow | Level::Expect(_) => "none",
+    }
+}
+
+impl SarifResult {
+    fn from_errors_diagnostic(
+        diag: &crate::Diagnostic,
+        rule_index: Option<usize>,
+        se: &SarifEmitter,
+    ) -> SarifResult {
+        let args = to_fluent_args(diag.args());
+        let mut message = se.translate_messages(&diag.messages, &args).into_owned();
+
+        let mut locations = vec![];
+        let mut related_locations = vec![];
+        for span_label in diag.span.span_labels() {
+            let label = span_label
+                .label
+                .as_ref()
+                .map(|m| se.translate_message(m, &args).map_err(Report::new).unwrap())
+                .map(|m| Message { text: m.into_owned() });
+            let Some(physical_location) = PhysicalLocation::from_span(span_label.span, se) else {
+                continue;
+            };
+            if span_label.is_primary {
+                locations.push(Location { id: None, physical_location, message: label });
+            } else {
+                related_locations.push(Location { id: None, physical_location, message: label });
+            }
+        }
+
+        // Notes and helps without a span are folded into the message, the ones with
+        // spans are related locations labelled with their message.
+        for child in &diag.children {
+            let text = SarifResult::sub_diagnostic_text(child, &args, se);
+            let mut physical_locations = child
+                .span
+                .primary_spans()
+                .iter()
+                .filter_map(|&span| PhysicalLocation::from_span(span, se))
+                .peekable();
+            if physical_locations.peek().is_none() {
+                message.push('\n');
+                message.push_str(&text);
+            }
+            related_locations.extend(physical_locations.map(|physical_location| Location {
+                id: None,
+                physical_location,
+                message: Some(Message { text: text.clone() }),
+            }));
+        }
+        for (id, location) in related_locations.iter_mut().enumerate() {
+            location.id = Some(id);
+        }
+
+        let fixes = diag
+            .suggestions
+            .iter()
+            .flatten()
+            .flat_map(|sugg| Fix::from_suggestion(sugg, &args, se))
+            .collect();
+
+        SarifResult {
+            rule_id: diag.code.as_ref().map(|code| match code {
+                DiagnosticId::Error(code) => code.clone(),
+                DiagnosticId::Lint { name, .. } => name.clone(),
+            }),
+            rule_index,
+            level: sarif_level(diag.level),
+            message: Message { text: message },
+            locations,
+            related_locations,
+            fixes,
+        }
+    }
+
+    fn sub_diagnostic_text(
+        diag: &SubDiagnostic,
+        args: &FluentArgs<'_>,
+        se: &SarifEmitter,
+    ) -> String {
+        let translated_message = se.translate_messages(&diag.messages, args);
+        format!("{}: {}", diag.level.to_str(), translated_message)
+    }
+}
+
+impl Fix {
+    /// Creates one fix per alternative substitution of the suggestion.
+    fn from_suggestion(
+        suggestion: &CodeSuggestion,
+        args: &FluentArgs<'_>,
+        se: &SarifEmitter,
+    ) -> Vec<Fix> {
+        let description = se.translate_message(&suggestion.msg, args).map_err(Report::new).unwrap();
+        suggestion
+            .substitutions
+            .iter()
+            .filter_map(|substitution| {
+                // Parts in the same file are grouped into a single artifact change.
+                let mut changes: FxIndexMap<String, Vec<Replacement>> = Default::default();
+                for part in &substitution.parts {
+                    let (uri, deleted_region) = Region::from_span(part.span, se)?;
+                    changes.entry(uri).or_default().push(Replacement {
+                        deleted_region,
+                        inserted_content: ArtifactContent { text: part.snippet.clone() },
+                    });
+                }
+                Some(Fix {
+                    description: Message { text: description.to_string() },
+                    artifact_changes: changes
+                        .into_iter()
+                        .map(|(uri, replacements)| ArtifactChange {
+                            artifact_location: ArtifactLocation { uri },
+                            replacements,
+                        })
+                        .collect(),
+                    properties: FixProperties { applicability: suggestion.applicability },
+                })
+            })
+            .collect()
+    }
+}
+
+impl PhysicalLocation {
+    fn from_span(span: Span, se: &SarifEmitter) -> Option<PhysicalLocation> {
+        let (uri, region) = Region::from_span(span, se)?;
+        Some(PhysicalLocation { artifact_location: ArtifactLocation { uri }, region })
+    }
+}
+
+impl Region {
+    /// Returns the region covered by `span` together with the URI of its file, or
+    /// `None` for dummy spans.
+    fn from_span(span: Span, se: &SarifEmitter) -> Option<(String, Region)> {
+        if span.is_dummy() {
+            return None;
+        }
+        let start = se.sm.lookup_char_pos(span.lo());
+        let end = se.sm.lookup_char_pos(span.hi());
+        let byte_start = start.file.original_relative_byte_pos(span.lo()).0;
+        let byte_end = start.file.original_relative_byte_pos(span.hi()).0;
+        let region = Region {
+            start_line: start.line,
+            start_column: start.col.0 + 1,
+            end_line: end.line,
+            end_column: end.col.0 + 1,
+            byte_offset: byte_start,
+            byte_length: byte_end.saturating_sub(byte_start),
+            snippet: se.sm.span_to_snippet(span).ok().map(|text| ArtifactContent { text }),
+        };
+        Some((artifact_uri(&start.file, se), region))
+    }
+}
+
+/// Converts the (possibly remapped) name of a source file into a URI reference.
+///
+/// Relative paths stay relative, absolute paths become `file` URIs. Characters
+/// that may not appear in a URI are percent-encoded.
+fn artifact_uri(file: &SourceFile, se: &SarifEmitter) -> String {
+    let name = se.sm.filename_for_diagnostics(&file.name).to_string().replace('\\', "/");
+    let prefix = if name.starts_with('/') {
+        "file://"
+    } else if name.as_bytes().get(1) == Some(&b':') {
+        // A Windows path with a drive letter.
+        "file:///"
+    } else {
+        ""
+    };
+
+    let mut uri = String::from(prefix);
+    for byte in name.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' | b':' => {
+                uri.push(byte as char)
+            }
+            _ => uri.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    uri
+}
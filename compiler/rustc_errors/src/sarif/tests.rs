@@ -0,0 +1,138 @@
+use super::*;
+
+use crate::DiagCtxt;
+use rustc_span::BytePos;
+
+use std::path::Path;
+use std::str;
+use std::sync::{Arc, Mutex};
+
+use serde_json::Value;
+
+struct Shared<T> {
+    data: Arc<Mutex<T>>,
+}
+
+impl<T: Write> Write for Shared<T> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.data.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.data.lock().unwrap().flush()
+    }
+}
+
+/// Runs `f` with a `DiagCtxt` using a SARIF emitter over `code`, and returns what the
+/// emitter wrote once the `DiagCtxt` is dropped.
+fn with_sarif_output(code: &str, skip_empty_log: bool, f: impl FnOnce(&DiagCtxt)) -> String {
+    rustc_span::create_default_session_globals_then(|| {
+        let sm = Lrc::new(SourceMap::new(FilePathMapping::empty()));
+        sm.new_source_file(Path::new("test.rs").to_owned().into(), code.to_owned());
+        let fallback_bundle =
+            crate::fallback_fluent_bundle(vec![crate::DEFAULT_LOCALE_RESOURCE], false);
+
+        let output = Arc::new(Mutex::new(Vec::new()));
+        let registry = Registry::new(&[("E0999", "An explanation.\n")]);
+        let se = SarifEmitter::new(
+            Box::new(Shared { data: output.clone() }),
+            Some(registry),
+            sm,
+            None,
+            fallback_bundle,
+            false,
+        )
+        .skip_empty_log(skip_empty_log);
+
+        let dcx = DiagCtxt::with_emitter(Box::new(se));
+        f(&dcx);
+        drop(dcx);
+
+        let bytes = output.lock().unwrap();
+        str::from_utf8(&bytes).unwrap().to_owned()
+    })
+}
+
+/// Runs `f` with a `DiagCtxt` using a SARIF emitter over `code`, and returns the
+/// log written once the `DiagCtxt` is dropped.
+fn with_sarif_log(code: &str, f: impl FnOnce(&DiagCtxt)) -> Value {
+    serde_json::from_str(&with_sarif_output(code, false, f)).unwrap()
+}
+
+#[test]
+fn empty_log() {
+    let log = with_sarif_log("", |_| {});
+    assert_eq!(log["version"], "2.1.0");
+    assert_eq!(log["runs"].as_array().unwrap().len(), 1);
+    assert_eq!(log["runs"][0]["tool"]["driver"]["name"], "rustc");
+    assert_eq!(log["runs"][0]["results"].as_array().unwrap().len(), 0);
+}
+
+#[test]
+fn error_with_code() {
+    let log = with_sarif_log("fn main() { x }", |dcx| {
+        let span = Span::with_root_ctxt(BytePos(12), BytePos(13));
+        dcx.struct_span_err(span, "cannot find value `x`")
+            .with_code(DiagnosticId::Error("E0999".to_owned()))
+            .with_note("a note")
+            .emit();
+    });
+    let run = &log["runs"][0];
+
+    let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+    assert_eq!(rules.len(), 1);
+    assert_eq!(rules[0]["id"], "E0999");
+    assert_eq!(rules[0]["help"]["text"], "An explanation.\n");
+    assert_eq!(rules[0]["helpUri"], "https://doc.rust-lang.org/error_codes/E0999.html");
+
+    let results = run["results"].as_array().unwrap();
+    assert_eq!(results.len(), 1);
+    let result = &results[0];
+    assert_eq!(result["ruleId"], "E0999");
+    assert_eq!(result["ruleIndex"], 0);
+    assert_eq!(result["level"], "error");
+    assert_eq!(result["message"]["text"], "cannot find value `x`\nnote: a note");
+
+    let location = &result["locations"][0]["physicalLocation"];
+    assert_eq!(location["artifactLocation"]["uri"], "test.rs");
+    assert_eq!(location["region"]["startLine"], 1);
+    assert_eq!(location["region"]["startColumn"], 13);
+    assert_eq!(location["region"]["endColumn"], 14);
+    assert_eq!(location["region"]["byteOffset"], 12);
+    assert_eq!(location["region"]["byteLength"], 1);
+    assert_eq!(location["region"]["snippet"]["text"], "x");
+}
+
+#[test]
+fn suggestion_becomes_fix() {
+    let log = with_sarif_log("let x = 1;", |dcx| {
+        let span = Span::with_root_ctxt(BytePos(4), BytePos(5));
+        dcx.struct_span_warn(span, "unused variable: `x`")
+            .with_span_suggestion(span, "prefix it", "_x", Applicability::MachineApplicable)
+            .emit();
+    });
+    let result = &log["runs"][0]["results"][0];
+    assert_eq!(result["level"], "warning");
+
+    let fix = &result["fixes"][0];
+    assert_eq!(fix["description"]["text"], "prefix it");
+    assert_eq!(fix["properties"]["applicability"], "MachineApplicable");
+    let replacement = &fix["artifactChanges"][0]["replacements"][0];
+    assert_eq!(replacement["deletedRegion"]["byteOffset"], 4);
+    assert_eq!(replacement["insertedContent"]["text"], "_x");
+}
+
+#[test]
+fn skip_empty_log() {
+    let output = with_sarif_output("let x = 1;", true, |_| {});
+    assert_eq!(output, "");
+
+    let output = with_sarif_output("let x = 1;", true, |dcx| {
+        dcx.struct_warn("an early warning").emit();
+    });
+    let log: Value = serde_json::from_str(&output).unwrap();
+    let results = log["runs"][0]["results"].as_array().unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0]["level"], "warning");
+    assert_eq!(results[0]["message"]["text"], "an early warning");
+}
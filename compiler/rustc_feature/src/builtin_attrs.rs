@@ -283,6 +283,10 @@ pub struct BuiltinAttribute {
         should_panic, Normal,
         template!(Word, List: r#"expected = "reason""#, NameValueStr: "reason"), FutureWarnFollowing,
     ),
+    gated!(
+        test_timeout, Normal, template!(NameValueStr: "seconds"), ErrorFollowing,
+        experimental!(test_timeout)
+    ),
     // FIXME(Centril): This can be used on stable but shouldn't.
     ungated!(reexport_test_harness_main, CrateLevel, template!(NameValueStr: "name"), ErrorFollowing),
 
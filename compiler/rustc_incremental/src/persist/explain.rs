@@ -0,0 +1,187 @@
+//! Support for `-Zincremental-explain`, which reports why the results of the previous session
+//! could not be reused.
+//!
+//! When a node of the previous dep-graph can't be marked green, the dep-graph records the
+//! dependency that prevented it. Following these dependencies leads to the node that changed
+//! on its own, which is usually an input of the query system: the HIR of an item, the span of an
+//! item, or the SVH of an upstream crate. This module resolves these chains, and writes them to
+//! a JSON file, grouped by the item the invalidated queries are about.
+
+use crate::errors;
+use rustc_data_structures::fx::FxIndexMap;
+use rustc_hir::def_id::{CrateNum, DefId, DefPathHash, CRATE_DEF_INDEX, LOCAL_CRATE};
+use rustc_middle::dep_graph::{
+    dep_kinds, DepContext, DepKind, DepNode, FingerprintStyle, Invalidation,
+};
+use rustc_middle::ty::print::with_no_trimmed_paths;
+use rustc_middle::ty::TyCtxt;
+use serde::Serialize;
+use std::fs;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+/// The queries that compute the HIR of an item, from the HIR of the whole crate.
+const HIR_OWNER_KINDS: &[DepKind] = &[
+    dep_kinds::hir_owner,
+    dep_kinds::hir_owner_nodes,
+    dep_kinds::hir_owner_parent,
+    dep_kinds::hir_attrs,
+    dep_kinds::hir_module_items,
+    dep_kinds::opt_local_def_id_to_hir_id,
+];
+
+#[derive(Serialize)]
+struct ItemReport {
+    /// The path of the item, or `None` for the queries that are not about a single item.
+    item: Option<String>,
+    queries: Vec<QueryReport>,
+}
+
+#[derive(Serialize)]
+struct QueryReport {
+    /// The invalidated dep-node, e.g., `typeck(foo::bar)`.
+    node: String,
+    /// The chain of dependencies that invalidated the node, from its direct dependency to the
+    /// node that changed.
+    chain: Vec<String>,
+    /// The input that changed, as far as it can be determined from the chain.
+    cause: Cause,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum Cause {
+    /// The HIR of an item changed.
+    HirOwner { item: String },
+    /// The span of an item changed, e.g., because of an edit earlier in its source file.
+    SourceFile { file: String, item: String },
+    /// An upstream crate was rebuilt with a different SVH.
+    CrateHash {
+        #[serde(rename = "crate")]
+        krate: String,
+    },
+    /// Any other node, e.g., an `eval_always` query whose result changed.
+    Query { node: String },
+}
+
+/// What the key of a dep-node refers to.
+enum Subject {
+    /// An item of the local crate, or the root of a crate.
+    Def(DefId),
+    /// An item of an upstream crate. These are not resolved, as looking up an item that no
+    /// longer exists in the metadata of its crate is a fatal error.
+    UpstreamItem(CrateNum),
+    /// An item or a crate that no longer exists.
+    Removed,
+}
+
+/// Write the report of the nodes invalidated in this session to `path`.
+pub(crate) fn write_explanation(tcx: TyCtxt<'_>, path: &Path) {
+    if let Err(err) = try_write_explanation(tcx, path) {
+        tcx.dcx().emit_err(errors::WriteNew {
+            name: "incremental explanation",
+            path: path.to_path_buf(),
+            err,
+        });
+    }
+}
+
+fn try_write_explanation(tcx: TyCtxt<'_>, path: &Path) -> io::Result<()> {
+    let mut items: FxIndexMap<Option<String>, Vec<QueryReport>> = FxIndexMap::default();
+    for Invalidation { node, chain } in tcx.dep_graph.invalidations() {
+        let item = subject(tcx, &node).map(|subject| describe_subject(tcx, subject));
+        let report = QueryReport {
+            node: describe_node(tcx, &node),
+            chain: chain.iter().map(|node| describe_node(tcx, node)).collect(),
+            cause: cause(tcx, &chain),
+        };
+        items.entry(item).or_default().push(report);
+    }
+    let report: Vec<_> =
+        items.into_iter().map(|(item, queries)| ItemReport { item, queries }).collect();
+
+    let mut file = BufWriter::new(fs::File::create(path)?);
+    serde_json::to_writer_pretty(&mut file, &report)?;
+    writeln!(file)?;
+    file.flush()
+}
+
+/// Find the input that changed at the end of an invalidation chain.
+fn cause(tcx: TyCtxt<'_>, chain: &[DepNode]) -> Cause {
+    // The HIR of every item depends on the HIR of the whole crate, which is the last node of
+    // the chain. The node that identifies the item is the last one about a single item.
+    let hir_owner = chain.iter().rev().find_map(|node| {
+        if !HIR_OWNER_KINDS.contains(&node.kind) {
+            return None;
+        }
+        subject(tcx, node)
+    });
+    if let Some(subject) = hir_owner {
+        return Cause::HirOwner { item: describe_subject(tcx, subject) };
+    }
+
+    let Some(input) = chain.last() else { bug!("empty invalidation chain") };
+    match subject(tcx, input) {
+        Some(Subject::Def(def_id)) if input.kind == dep_kinds::source_span => {
+            let span = tcx.def_span(def_id);
+            let file = tcx.sess.source_map().span_to_filename(span);
+            Cause::SourceFile {
+                file: file.prefer_local().to_string(),
+                item: describe_subject(tcx, Subject::Def(def_id)),
+            }
+        }
+        Some(subject) if input.kind == dep_kinds::crate_hash => {
+            Cause::CrateHash { krate: describe_subject(tcx, subject) }
+        }
+        _ => Cause::Query { node: describe_node(tcx, input) },
+    }
+}
+
+fn subject(tcx: TyCtxt<'_>, node: &DepNode) -> Option<Subject> {
+    if tcx.fingerprint_style(node.kind) != FingerprintStyle::DefPathHash {
+        return None;
+    }
+    let hash = DefPathHash(node.hash.into());
+    if hash.stable_crate_id() == tcx.stable_crate_id(LOCAL_CRATE) {
+        let index = tcx.def_path_hash_to_def_index_map().get(&hash.local_hash());
+        return Some(match index {
+            Some(index) => Subject::Def(DefId { krate: LOCAL_CRATE, index }),
+            None => Subject::Removed,
+        });
+    }
+
+    let krate =
+        tcx.crates(()).iter().find(|&&krate| tcx.stable_crate_id(krate) == hash.stable_crate_id());
+    Some(match krate {
+        Some(&krate) if tcx.def_path_hash(krate.as_def_id()) == hash => {
+            Subject::Def(krate.as_def_id())
+        }
+        Some(&krate) => Subject::UpstreamItem(krate),
+        None => Subject::Removed,
+    })
+}
+
+fn describe_subject(tcx: TyCtxt<'_>, subject: Subject) -> String {
+    match subject {
+        Subject::Def(def_id) if def_id.index == CRATE_DEF_INDEX => {
+            tcx.crate_name(def_id.krate).to_string()
+        }
+        // Trimmed paths may only be printed when a diagnostic gets emitted, which is usually not
+        // the case when the explanation is written.
+        Subject::Def(def_id) => with_no_trimmed_paths!(tcx.def_path_str(def_id)),
+        Subject::UpstreamItem(krate) => format!("{{item of `{}`}}", tcx.crate_name(krate)),
+        Subject::Removed => "{removed}".to_string(),
+    }
+}
+
+fn describe_node(tcx: TyCtxt<'_>, node: &DepNode) -> String {
+    let name = tcx.dep_kind_info(node.kind).name;
+    match tcx.fingerprint_style(node.kind) {
+        FingerprintStyle::Unit => format!("{name}()"),
+        FingerprintStyle::DefPathHash => {
+            let subject = subject(tcx, node).unwrap();
+            format!("{name}({})", describe_subject(tcx, subject))
+        }
+        FingerprintStyle::HirId | FingerprintStyle::Opaque => format!("{name}(..)"),
+    }
+}
@@ -13,6 +13,7 @@
 
 use super::data::*;
 use super::dirty_clean;
+use super::explain;
 use super::file_format;
 use super::fs::*;
 use super::work_product;
@@ -46,6 +47,9 @@ pub fn save_dep_graph(tcx: TyCtxt<'_>) {
         if sess.opts.unstable_opts.incremental_info {
             tcx.dep_graph.print_incremental_info()
         }
+        if let Some(path) = &sess.opts.unstable_opts.incremental_explain {
+            sess.time("incr_comp_explain", || explain::write_explanation(tcx, path));
+        }
 
         join(
             move || {
@@ -178,5 +182,6 @@ pub(crate) fn build_dep_graph(
         encoder,
         sess.opts.unstable_opts.query_dep_graph,
         sess.opts.unstable_opts.incremental_info,
+        sess.opts.unstable_opts.incremental_explain.is_some(),
     ))
 }
@@ -1,6 +1,9 @@
 interface_cant_emit_mir =
     could not emit MIR: {$error}
 
+interface_cant_emit_type_layout =
+    could not emit type layouts: {$error}
+
 interface_emoji_identifier =
     identifiers cannot contain emoji: `{$ident}`
 
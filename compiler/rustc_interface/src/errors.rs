@@ -65,6 +65,12 @@ pub struct CantEmitMIR {
     pub error: io::Error,
 }
 
+#[derive(Diagnostic)]
+#[diag(interface_cant_emit_type_layout)]
+pub struct CantEmitTypeLayout {
+    pub error: io::Error,
+}
+
 #[derive(Diagnostic)]
 #[diag(interface_rustc_error_fatal)]
 pub struct RustcErrorFatal {
@@ -943,9 +943,32 @@ pub fn start_codegen<'tcx>(
         }
     }
 
+    // This must run after codegen, so that the layouts of all the monomorphized
+    // types have been computed and recorded.
+    if tcx.sess.opts.output_types.contains_key(&OutputType::TypeLayout) {
+        if let Err(error) = emit_type_layouts(tcx) {
+            let dcx = tcx.dcx();
+            dcx.emit_err(errors::CantEmitTypeLayout { error });
+            dcx.abort_if_errors();
+        }
+    }
+
     codegen
 }
 
+fn emit_type_layouts(tcx: TyCtxt<'_>) -> io::Result<()> {
+    let crate_name = tcx.crate_name(LOCAL_CRATE);
+    match tcx.output_filenames(()).path(OutputType::TypeLayout) {
+        OutFileName::Stdout => {
+            tcx.sess.code_stats.write_type_layouts(crate_name, &mut io::stdout().lock())
+        }
+        OutFileName::Real(path) => {
+            let mut f = BufWriter::new(fs::File::create(&path)?);
+            tcx.sess.code_stats.write_type_layouts(crate_name, &mut f)
+        }
+    }
+}
+
 fn get_recursion_limit(krate_attrs: &[ast::Attribute], sess: &Session) -> Limit {
     if let Some(attr) = krate_attrs
         .iter()
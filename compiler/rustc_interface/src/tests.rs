@@ -1,6 +1,6 @@
 #![allow(rustc::bad_opt_access)]
 use crate::interface::parse_cfg;
-use rustc_data_structures::profiling::TimePassesFormat;
+use rustc_data_structures::profiling::{SelfProfileFormat, TimePassesFormat};
 use rustc_errors::{emitter::HumanReadableErrorType, registry, ColorConfig};
 use rustc_session::config::{
     build_configuration, build_session_options, rustc_optgroups, BranchProtection, CFGuard, Cfg,
@@ -672,6 +672,7 @@ macro_rules! untracked {
     untracked!(future_incompat_test, true);
     untracked!(hir_stats, true);
     untracked!(identify_regions, true);
+    untracked!(incremental_explain, Some(PathBuf::from("explain.json")));
     untracked!(incremental_info, true);
     untracked!(incremental_verify_ich, true);
     untracked!(input_stats, true);
@@ -698,6 +699,7 @@ macro_rules! untracked {
     untracked!(query_dep_graph, true);
     untracked!(self_profile, SwitchWithOptPath::Enabled(None));
     untracked!(self_profile_events, Some(vec![String::new()]));
+    untracked!(self_profile_format, SelfProfileFormat::ChromeTrace);
     untracked!(shell_argfiles, true);
     untracked!(span_debug, true);
     untracked!(span_free_formats, true);
@@ -714,7 +716,9 @@ macro_rules! untracked {
     untracked!(unstable_options, true);
     untracked!(validate_mir, true);
     untracked!(verbose_internals, true);
+    untracked!(warning_baseline, Some(PathBuf::from("baseline.json")));
     untracked!(write_long_types_to_disk, false);
+    untracked!(write_warning_baseline, Some(PathBuf::from("baseline.json")));
     // tidy-alphabetical-end
 
     macro_rules! tracked {
@@ -9,8 +9,8 @@
 pub use rustc_query_system::dep_graph::debug::EdgeFilter;
 pub use rustc_query_system::dep_graph::{
     debug::DepNodeFilter, hash_result, DepContext, DepGraphQuery, DepNodeIndex, Deps,
-    SerializedDepGraph, SerializedDepNodeIndex, TaskDepsRef, WorkProduct, WorkProductId,
-    WorkProductMap,
+    FingerprintStyle, Invalidation, SerializedDepGraph, SerializedDepNodeIndex, TaskDepsRef,
+    WorkProduct, WorkProductId, WorkProductMap,
 };
 
 pub use dep_node::{dep_kinds, label_strs, DepKind, DepNode, DepNodeExt};
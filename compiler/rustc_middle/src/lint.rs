@@ -10,7 +10,7 @@
 };
 use rustc_session::Session;
 use rustc_span::hygiene::{ExpnKind, MacroKind};
-use rustc_span::{symbol, DesugaringKind, Span, Symbol, DUMMY_SP};
+use rustc_span::{sym, symbol, DesugaringKind, Span, Symbol, DUMMY_SP};
 
 use crate::ty::TyCtxt;
 
@@ -347,6 +347,20 @@ fn struct_lint_level_impl(
             }
         }
 
+        // Warnings listed in the `-Zwarning-baseline` file are not reported. This includes the
+        // warnings that are turned into errors through the `warnings` lint, e.g., with
+        // `-Dwarnings`, but not the lints that are denied explicitly. The baseline is checked
+        // when the diagnostic is emitted, so that it also applies to the warnings replayed from
+        // the incremental cache.
+        let is_warning = match level {
+            Level::Warn => true,
+            Level::Deny | Level::Forbid => src.name() == sym::warnings,
+            _ => false,
+        };
+        if is_warning {
+            err.is_baseline_warning();
+        }
+
         // Delay evaluating and setting the primary message until after we've
         // suppressed the lint due to macros.
         err.primary_message(msg);
@@ -204,15 +204,34 @@ pub enum MonoItemCollectionMode {
     Lazy,
 }
 
+/// The way in which a mono item uses another one.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UsageKind {
+    /// The used item is called directly.
+    Call,
+    /// The used item is reified into a function pointer, or its address is otherwise taken
+    /// (e.g. through a `sym` operand of inline assembly or a function pointer in a constant).
+    FnPtr,
+    /// The used item is a method of a vtable built by the user.
+    VtableMethod,
+    /// The used item is the drop glue of some type, either called by a `Drop` terminator
+    /// or stored in a vtable.
+    DropGlue,
+    /// The used item is a static.
+    Static,
+    /// Any other use, e.g. a thread-local shim or a default trait method collected eagerly.
+    Other,
+}
+
 pub struct UsageMap<'tcx> {
     // Maps every mono item to the mono items used by it.
-    used_map: FxHashMap<MonoItem<'tcx>, Vec<MonoItem<'tcx>>>,
+    used_map: FxHashMap<MonoItem<'tcx>, Vec<(MonoItem<'tcx>, UsageKind)>>,
 
     // Maps every mono item to the mono items that use it.
     user_map: FxHashMap<MonoItem<'tcx>, Vec<MonoItem<'tcx>>>,
 }
 
-type MonoItems<'tcx> = Vec<Spanned<MonoItem<'tcx>>>;
+type MonoItems<'tcx> = Vec<(Spanned<MonoItem<'tcx>>, UsageKind)>;
 
 impl<'tcx> UsageMap<'tcx> {
     fn new() -> UsageMap<'tcx> {
@@ -222,12 +241,12 @@ fn new() -> UsageMap<'tcx> {
     fn record_used<'a>(
         &mut self,
         user_item: MonoItem<'tcx>,
-        used_items: &'a [Spanned<MonoItem<'tcx>>],
+        used_items: &'a [(Spanned<MonoItem<'tcx>>, UsageKind)],
     ) where
         'tcx: 'a,
     {
-        let used_items: Vec<_> = used_items.iter().map(|item| item.node).collect();
-        for &used_item in used_items.iter() {
+        let used_items: Vec<_> = used_items.iter().map(|(item, kind)| (item.node, *kind)).collect();
+        for &(used_item, _) in used_items.iter() {
             self.user_map.entry(used_item).or_default().push(user_item);
         }
 
@@ -238,13 +257,19 @@ pub fn get_user_items(&self, item: MonoItem<'tcx>) -> &[MonoItem<'tcx>] {
         self.user_map.get(&item).map(|items| items.as_slice()).unwrap_or(&[])
     }
 
+    /// Returns the mono items used by `item`, along with how they are used. An item used in
+    /// several places is listed once per use.
+    pub fn get_used_items(&self, item: MonoItem<'tcx>) -> &[(MonoItem<'tcx>, UsageKind)] {
+        self.used_map.get(&item).map(|items| items.as_slice()).unwrap_or(&[])
+    }
+
     /// Internally iterate over all inlined items used by `item`.
     pub fn for_each_inlined_used_item<F>(&self, tcx: TyCtxt<'tcx>, item: MonoItem<'tcx>, mut f: F)
     where
         F: FnMut(MonoItem<'tcx>),
     {
         let used_items = self.used_map.get(&item).unwrap();
-        for used_item in used_items.iter() {
+        for (used_item, _) in used_items.iter() {
             let is_inlined = used_item.instantiation_mode(tcx) == InstantiationMode::LocalCopy;
             if is_inlined {
                 f(*used_item);
@@ -323,7 +348,7 @@ fn collect_roots(tcx: TyCtxt<'_>, mode: MonoItemCollectionMode) -> Vec<MonoItem<
     // can't actually be used, so we can just skip codegenning them.
     roots
         .into_iter()
-        .filter_map(|Spanned { node: mono_item, .. }| {
+        .filter_map(|(Spanned { node: mono_item, .. }, _)| {
             mono_item.is_instantiable(tcx).then_some(mono_item)
         })
         .collect()
@@ -392,12 +417,15 @@ fn collect_items_rec<'tcx>(
             }
 
             if tcx.needs_thread_local_shim(def_id) {
-                used_items.push(respan(
-                    starting_item.span,
-                    MonoItem::Fn(Instance {
-                        def: InstanceDef::ThreadLocalShim(def_id),
-                        args: GenericArgs::empty(),
-                    }),
+                used_items.push((
+                    respan(
+                        starting_item.span,
+                        MonoItem::Fn(Instance {
+                            def: InstanceDef::ThreadLocalShim(def_id),
+                            args: GenericArgs::empty(),
+                        }),
+                    ),
+                    UsageKind::Other,
                 ));
             }
         }
@@ -440,7 +468,10 @@ fn collect_items_rec<'tcx>(
                             let instance = Instance::mono(tcx, *def_id);
                             if should_codegen_locally(tcx, &instance) {
                                 trace!("collecting static {:?}", def_id);
-                                used_items.push(dummy_spanned(MonoItem::Static(*def_id)));
+                                used_items.push((
+                                    dummy_spanned(MonoItem::Static(*def_id)),
+                                    UsageKind::Static,
+                                ));
                             }
                         }
                         hir::InlineAsmOperand::In { .. }
@@ -471,7 +502,7 @@ fn collect_items_rec<'tcx>(
     }
     usage_map.lock_mut().record_used(starting_item.node, &used_items);
 
-    for used_item in used_items {
+    for (used_item, _) in used_items {
         collect_items_rec(tcx, used_item, visited, recursion_depths, recursion_limit, usage_map);
     }
 
@@ -760,7 +791,10 @@ fn visit_rvalue(&mut self, rvalue: &mir::Rvalue<'tcx>, location: Location) {
                         )
                         .expect("failed to normalize and resolve closure during codegen");
                         if should_codegen_locally(self.tcx, &instance) {
-                            self.output.push(create_fn_mono_item(self.tcx, instance, span));
+                            self.output.push((
+                                create_fn_mono_item(self.tcx, instance, span),
+                                UsageKind::FnPtr,
+                            ));
                         }
                     }
                     _ => bug!(),
@@ -771,7 +805,7 @@ fn visit_rvalue(&mut self, rvalue: &mir::Rvalue<'tcx>, location: Location) {
                 let instance = Instance::mono(self.tcx, def_id);
                 if should_codegen_locally(self.tcx, &instance) {
                     trace!("collecting thread-local static {:?}", def_id);
-                    self.output.push(respan(span, MonoItem::Static(def_id)));
+                    self.output.push((respan(span, MonoItem::Static(def_id)), UsageKind::Static));
                 }
             }
             _ => { /* not interesting */ }
@@ -808,7 +842,7 @@ fn visit_terminator(&mut self, terminator: &mir::Terminator<'tcx>, location: Loc
         let push_mono_lang_item = |this: &mut Self, lang_item: LangItem| {
             let instance = Instance::mono(tcx, tcx.require_lang_item(lang_item, Some(source)));
             if should_codegen_locally(tcx, &instance) {
-                this.output.push(create_fn_mono_item(tcx, instance, source));
+                this.output.push((create_fn_mono_item(tcx, instance, source), UsageKind::Call));
             }
         };
 
@@ -835,7 +869,10 @@ fn visit_terminator(&mut self, terminator: &mir::Terminator<'tcx>, location: Loc
                             let instance = Instance::mono(self.tcx, def_id);
                             if should_codegen_locally(self.tcx, &instance) {
                                 trace!("collecting asm sym static {:?}", def_id);
-                                self.output.push(respan(source, MonoItem::Static(def_id)));
+                                self.output.push((
+                                    respan(source, MonoItem::Static(def_id)),
+                                    UsageKind::Static,
+                                ));
                             }
                         }
                         _ => {}
@@ -934,7 +971,7 @@ fn visit_instance_use<'tcx>(
             let def_id = tcx.lang_items().get(LangItem::PanicNounwind).unwrap();
             let panic_instance = Instance::mono(tcx, def_id);
             if should_codegen_locally(tcx, &panic_instance) {
-                output.push(create_fn_mono_item(tcx, panic_instance, source));
+                output.push((create_fn_mono_item(tcx, panic_instance, source), UsageKind::Call));
             }
         }
     }
@@ -951,7 +988,7 @@ fn visit_instance_use<'tcx>(
         ty::InstanceDef::DropGlue(_, None) => {
             // Don't need to emit noop drop glue if we are calling directly.
             if !is_direct_call {
-                output.push(create_fn_mono_item(tcx, instance, source));
+                output.push((create_fn_mono_item(tcx, instance, source), UsageKind::DropGlue));
             }
         }
         ty::InstanceDef::DropGlue(_, Some(_))
@@ -962,7 +999,14 @@ fn visit_instance_use<'tcx>(
         | ty::InstanceDef::FnPtrShim(..)
         | ty::InstanceDef::CloneShim(..)
         | ty::InstanceDef::FnPtrAddrShim(..) => {
-            output.push(create_fn_mono_item(tcx, instance, source));
+            let kind = if let ty::InstanceDef::DropGlue(..) = instance.def {
+                UsageKind::DropGlue
+            } else if is_direct_call {
+                UsageKind::Call
+            } else {
+                UsageKind::FnPtr
+            };
+            output.push((create_fn_mono_item(tcx, instance, source), kind));
         }
     }
 }
@@ -1162,7 +1206,7 @@ fn create_mono_items_for_vtable_methods<'tcx>(
                         Some(*instance).filter(|instance| should_codegen_locally(tcx, instance))
                     }
                 })
-                .map(|item| create_fn_mono_item(tcx, item, source));
+                .map(|item| (create_fn_mono_item(tcx, item, source), UsageKind::VtableMethod));
             output.extend(methods);
         }
 
@@ -1200,12 +1244,12 @@ fn process_item(&mut self, id: hir::ItemId) {
                     "RootCollector: ItemKind::GlobalAsm({})",
                     self.tcx.def_path_str(id.owner_id)
                 );
-                self.output.push(dummy_spanned(MonoItem::GlobalAsm(id)));
+                self.output.push((dummy_spanned(MonoItem::GlobalAsm(id)), UsageKind::Other));
             }
             DefKind::Static(..) => {
                 let def_id = id.owner_id.to_def_id();
                 debug!("RootCollector: ItemKind::Static({})", self.tcx.def_path_str(def_id));
-                self.output.push(dummy_spanned(MonoItem::Static(def_id)));
+                self.output.push((dummy_spanned(MonoItem::Static(def_id)), UsageKind::Static));
             }
             DefKind::Const => {
                 // const items only generate mono items if they are
@@ -1258,7 +1302,7 @@ fn push_if_root(&mut self, def_id: LocalDefId) {
             debug!("found root");
 
             let instance = Instance::mono(self.tcx, def_id.to_def_id());
-            self.output.push(create_fn_mono_item(self.tcx, instance, DUMMY_SP));
+            self.output.push((create_fn_mono_item(self.tcx, instance, DUMMY_SP), UsageKind::Other));
         }
     }
 
@@ -1296,7 +1340,8 @@ fn push_extra_entry_roots(&mut self) {
         .unwrap()
         .unwrap();
 
-        self.output.push(create_fn_mono_item(self.tcx, start_instance, DUMMY_SP));
+        self.output
+            .push((create_fn_mono_item(self.tcx, start_instance, DUMMY_SP), UsageKind::Other));
     }
 }
 
@@ -1369,7 +1414,7 @@ fn create_mono_items_for_default_impls<'tcx>(
 
         let mono_item = create_fn_mono_item(tcx, instance, DUMMY_SP);
         if mono_item.node.is_instantiable(tcx) && should_codegen_locally(tcx, &instance) {
-            output.push(mono_item);
+            output.push((mono_item, UsageKind::Other));
         }
     }
 }
@@ -1382,7 +1427,7 @@ fn collect_alloc<'tcx>(tcx: TyCtxt<'tcx>, alloc_id: AllocId, output: &mut MonoIt
             let instance = Instance::mono(tcx, def_id);
             if should_codegen_locally(tcx, &instance) {
                 trace!("collecting static {:?}", def_id);
-                output.push(dummy_spanned(MonoItem::Static(def_id)));
+                output.push((dummy_spanned(MonoItem::Static(def_id)), UsageKind::Static));
             }
         }
         GlobalAlloc::Memory(alloc) => {
@@ -1396,7 +1441,7 @@ fn collect_alloc<'tcx>(tcx: TyCtxt<'tcx>, alloc_id: AllocId, output: &mut MonoIt
         GlobalAlloc::Function(fn_instance) => {
             if should_codegen_locally(tcx, &fn_instance) {
                 trace!("collecting {:?} with {:#?}", alloc_id, fn_instance);
-                output.push(create_fn_mono_item(tcx, fn_instance, DUMMY_SP));
+                output.push((create_fn_mono_item(tcx, fn_instance, DUMMY_SP), UsageKind::FnPtr));
             }
         }
         GlobalAlloc::VTable(ty, trait_ref) => {
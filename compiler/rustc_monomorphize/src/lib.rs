@@ -22,6 +22,9 @@
 mod polymorphize;
 mod util;
 
+pub use collector::{UsageKind, UsageMap};
+pub use partitioning::{collect_mono_item_graph, partition_mono_item_graph};
+
 rustc_fluent_macro::fluent_messages! { "../messages.ftl" }
 
 fn custom_coerce_unsize_info<'tcx>(
@@ -1084,8 +1084,8 @@ fn assert_symbols_are_distinct<'a, 'tcx, I>(tcx: TyCtxt<'tcx>, mono_items: I)
     }
 }
 
-fn collect_and_partition_mono_items(tcx: TyCtxt<'_>, (): ()) -> (&DefIdSet, &[CodegenUnit<'_>]) {
-    let collection_mode = match tcx.sess.opts.unstable_opts.print_mono_items {
+fn collection_mode(tcx: TyCtxt<'_>) -> MonoItemCollectionMode {
+    match tcx.sess.opts.unstable_opts.print_mono_items {
         Some(ref s) => {
             let mode = s.to_lowercase();
             let mode = mode.trim();
@@ -1106,22 +1106,46 @@ fn collect_and_partition_mono_items(tcx: TyCtxt<'_>, (): ()) -> (&DefIdSet, &[Co
                 MonoItemCollectionMode::Lazy
             }
         }
-    };
+    }
+}
 
-    let (items, usage_map) = collector::collect_crate_mono_items(tcx, collection_mode);
+/// Runs the mono item collector over the local crate, in the same mode used by
+/// `collect_and_partition_mono_items`, and returns every collected item along with the
+/// usage map describing how they use each other.
+///
+/// This is meant for tools that want to inspect the mono item graph: it does not cache its
+/// result, so codegen should keep going through the `collect_and_partition_mono_items` query.
+pub fn collect_mono_item_graph(tcx: TyCtxt<'_>) -> (FxHashSet<MonoItem<'_>>, UsageMap<'_>) {
+    let (items, usage_map) = collector::collect_crate_mono_items(tcx, collection_mode(tcx));
 
     tcx.dcx().abort_if_errors();
 
+    (items, usage_map)
+}
+
+/// Partitions the items returned by [`collect_mono_item_graph`] into codegen units, the same way
+/// `collect_and_partition_mono_items` does, so that tools don't have to run the collector again.
+pub fn partition_mono_item_graph<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    items: &FxHashSet<MonoItem<'tcx>>,
+    usage_map: &UsageMap<'tcx>,
+) -> &'tcx [CodegenUnit<'tcx>] {
     let (codegen_units, _) = tcx.sess.time("partition_and_assert_distinct_symbols", || {
         sync::join(
             || {
-                let mut codegen_units = partition(tcx, items.iter().copied(), &usage_map);
+                let mut codegen_units = partition(tcx, items.iter().copied(), usage_map);
                 codegen_units[0].make_primary();
                 &*tcx.arena.alloc_from_iter(codegen_units)
             },
             || assert_symbols_are_distinct(tcx, items.iter()),
         )
     });
+    codegen_units
+}
+
+fn collect_and_partition_mono_items(tcx: TyCtxt<'_>, (): ()) -> (&DefIdSet, &[CodegenUnit<'_>]) {
+    let (items, usage_map) = collect_mono_item_graph(tcx);
+    let codegen_units = partition_mono_item_graph(tcx, &items, &usage_map);
 
     if tcx.prof.enabled() {
         // Record CGU size estimates for self-profiling.
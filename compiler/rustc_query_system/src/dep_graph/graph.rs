@@ -100,6 +100,21 @@ pub(crate) struct DepGraphData<D: Deps> {
     /// a particular query result was decoded from disk
     /// (not just marked green)
     debug_loaded_from_disk: Lock<FxHashSet<DepNode>>,
+
+    /// For every node of the previous session that could not be marked green, the dependency
+    /// that prevented it. Only recorded for `-Zincremental-explain`.
+    invalidations: Option<Lock<FxHashMap<SerializedDepNodeIndex, SerializedDepNodeIndex>>>,
+}
+
+/// A node of the previous session that could not be marked green, and thus had to be
+/// recomputed, or was not reused.
+#[derive(Debug)]
+pub struct Invalidation {
+    pub node: DepNode,
+    /// The chain of dependencies that invalidated `node`: its first element is the dependency of
+    /// `node` that couldn't be marked green, and its last element is the node that changed on its
+    /// own, usually an input of the query system.
+    pub chain: Vec<DepNode>,
 }
 
 pub fn hash_result<R>(hcx: &mut StableHashingContext<'_>, result: &R) -> Fingerprint
@@ -119,6 +134,7 @@ pub fn new(
         encoder: FileEncoder,
         record_graph: bool,
         record_stats: bool,
+        record_invalidations: bool,
     ) -> DepGraph<D> {
         let prev_graph_node_count = prev_graph.node_count();
 
@@ -174,6 +190,7 @@ pub fn new(
                 previous: prev_graph,
                 colors,
                 debug_loaded_from_disk: Default::default(),
+                invalidations: record_invalidations.then(Default::default),
             })),
             virtual_dep_node_index: Lrc::new(AtomicU32::new(0)),
         }
@@ -861,7 +878,14 @@ fn try_mark_previous_green<Qcx: QueryContext<Deps = D>>(
         let prev_deps = self.previous.edge_targets_from(prev_dep_node_index);
 
         for dep_dep_node_index in prev_deps {
-            self.try_mark_parent_green(qcx, dep_dep_node_index, dep_node, Some(&frame))?;
+            let marked =
+                self.try_mark_parent_green(qcx, dep_dep_node_index, dep_node, Some(&frame));
+            if marked.is_none() {
+                if let Some(invalidations) = &self.invalidations {
+                    invalidations.lock().insert(prev_dep_node_index, dep_dep_node_index);
+                }
+                return None;
+            }
         }
 
         // If we got here without hitting a `return` that means that all
@@ -982,6 +1006,33 @@ pub fn print_incremental_info(&self) {
         }
     }
 
+    /// Returns the nodes of the previous session that could not be marked green, along with the
+    /// dependencies that invalidated them. This is empty unless the graph was created with
+    /// `record_invalidations`.
+    pub fn invalidations(&self) -> Vec<Invalidation> {
+        let Some(data) = &self.data else { return Vec::new() };
+        let Some(invalidations) = &data.invalidations else { return Vec::new() };
+        let invalidations = invalidations.lock();
+        let mut result: Vec<_> = invalidations
+            .keys()
+            .map(|&index| {
+                let mut chain = Vec::new();
+                let mut cause = invalidations.get(&index);
+                // The previous graph is acyclic, but let's not rely on it for a diagnostic.
+                while let Some(&cause_index) = cause
+                    && chain.len() <= invalidations.len()
+                {
+                    chain.push(data.previous.index_to_node(cause_index));
+                    cause = invalidations.get(&cause_index);
+                }
+                (index, Invalidation { node: data.previous.index_to_node(index), chain })
+            })
+            .collect();
+        // Sort the result to get a deterministic output.
+        result.sort_by_key(|(index, _)| *index);
+        result.into_iter().map(|(_, invalidation)| invalidation).collect()
+    }
+
     pub fn finish_encoding(&self, profiler: &SelfProfilerRef) -> FileEncodeResult {
         if let Some(data) = &self.data {
             data.current.encoder.steal().finish(profiler)
@@ -7,7 +7,9 @@
 
 pub use dep_node::{DepKind, DepKindStruct, DepNode, DepNodeParams, WorkProductId};
 pub(crate) use graph::DepGraphData;
-pub use graph::{hash_result, DepGraph, DepNodeIndex, TaskDepsRef, WorkProduct, WorkProductMap};
+pub use graph::{
+    hash_result, DepGraph, DepNodeIndex, Invalidation, TaskDepsRef, WorkProduct, WorkProductMap,
+};
 pub use query::DepGraphQuery;
 pub use serialized::{SerializedDepGraph, SerializedDepNodeIndex};
 
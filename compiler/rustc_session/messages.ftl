@@ -18,6 +18,8 @@ session_expr_parentheses_needed = parentheses are required to parse this as an e
 
 session_failed_to_create_profiler = failed to create profiler: {$err}
 
+session_failed_to_load_warning_baseline = failed to load the warning baseline `{$path}`: {$err}
+
 session_feature_diagnostic_for_issue =
     see issue #{$n} <https://github.com/rust-lang/rust/issues/{$n}> for more information
 
@@ -114,3 +116,13 @@ session_unleashed_feature_help_unnamed = skipping check that does not even have
 session_unstable_virtual_function_elimination = `-Zvirtual-function-elimination` requires `-Clto`
 
 session_unsupported_dwarf_version = requested DWARF version {$dwarf_version} is greater than 5
+
+session_unused_warning_baseline_entry =
+    the warning baseline expects {$count ->
+        [one] a `{$lint}` warning
+        *[other] {$count} `{$lint}` warnings
+    } in `{$file}` that {$count ->
+        [one] is
+        *[other] are
+    } no longer reported
+    .note = remove the entry from the baseline, or regenerate it with `-Zwrite-warning-baseline`
@@ -3,7 +3,9 @@
 use rustc_span::def_id::DefId;
 use rustc_span::Symbol;
 use rustc_target::abi::{Align, Size};
+use serde::Serialize;
 use std::cmp;
+use std::io::{self, Write};
 
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub struct VariantInfo {
@@ -55,14 +57,49 @@ pub enum DataTypeKind {
     Coroutine,
 }
 
+/// The generic definition a recorded type is an instantiation of.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct InstantiationInfo {
+    /// The path of the struct, enum, union, closure or coroutine definition.
+    pub def_path: String,
+    /// The generic arguments of the definition, excluding lifetimes.
+    pub args: Vec<String>,
+}
+
+/// How the discriminant of an enum or coroutine is stored.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum DiscriminantInfo {
+    /// The discriminant is stored in a dedicated tag field.
+    Tag { offset: u64, size: u64 },
+    /// The discriminant is encoded in the invalid values of a field of the
+    /// `untagged_variant`, starting at `niche_start`.
+    Niche { offset: u64, size: u64, untagged_variant: Symbol, niche_start: u128 },
+}
+
+/// The largest niche of a type, i.e. the field whose invalid values can be used
+/// to store the discriminant of an enum containing the type.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct NicheInfo {
+    pub offset: u64,
+    pub size: u64,
+    /// The (wrapping) range of valid values of the niche.
+    pub valid_range_start: u128,
+    pub valid_range_end: u128,
+    /// The number of invalid values that are still available.
+    pub available: u128,
+}
+
 #[derive(PartialEq, Eq, Hash, Debug)]
 pub struct TypeSizeInfo {
     pub kind: DataTypeKind,
     pub type_description: String,
+    pub instantiation: Option<InstantiationInfo>,
     pub align: u64,
     pub overall_size: u64,
     pub packed: bool,
     pub opt_discr_size: Option<u64>,
+    pub discriminant: Option<DiscriminantInfo>,
+    pub largest_niche: Option<NicheInfo>,
     pub variants: Vec<VariantInfo>,
 }
 
@@ -96,10 +133,13 @@ pub fn record_type_size<S: ToString>(
         &self,
         kind: DataTypeKind,
         type_desc: S,
+        instantiation: Option<InstantiationInfo>,
         align: Align,
         overall_size: Size,
         packed: bool,
         opt_discr_size: Option<Size>,
+        discriminant: Option<DiscriminantInfo>,
+        largest_niche: Option<NicheInfo>,
         mut variants: Vec<VariantInfo>,
     ) {
         // Sort variants so the largest ones are shown first. A stable sort is
@@ -113,10 +153,13 @@ pub fn record_type_size<S: ToString>(
         let info = TypeSizeInfo {
             kind,
             type_description: type_desc.to_string(),
+            instantiation,
             align: align.bytes(),
             overall_size: overall_size.bytes(),
             packed,
             opt_discr_size: opt_discr_size.map(|s| s.bytes()),
+            discriminant,
+            largest_niche,
             variants,
         };
         self.type_sizes.borrow_mut().insert(info);
@@ -228,6 +271,26 @@ pub fn print_type_sizes(&self) {
         }
     }
 
+    /// Writes the recorded type layouts as a JSON report, for `--emit=type-layout`.
+    ///
+    /// Unlike `print_type_sizes`, types are sorted by their description, so that
+    /// the reports of two compilations can be diffed.
+    pub fn write_type_layouts(&self, crate_name: Symbol, w: &mut dyn Write) -> io::Result<()> {
+        let type_sizes = self.type_sizes.borrow();
+        // We will soon sort, so the initial order does not matter.
+        #[allow(rustc::potential_query_instability)]
+        let mut sorted: Vec<_> = type_sizes.iter().collect();
+        sorted.sort_by_key(|info| (&info.type_description, info.overall_size));
+
+        let report = TypeLayoutReport {
+            crate_name: crate_name.as_str(),
+            types: sorted.into_iter().map(TypeLayout::from_type_size_info).collect(),
+        };
+        serde_json::to_writer_pretty(&mut *w, &report)?;
+        w.write_all(b"\n")?;
+        w.flush()
+    }
+
     pub fn print_vtable_sizes(&self, crate_name: Symbol) {
         // We will soon sort, so the initial order does not matter.
         #[allow(rustc::potential_query_instability)]
@@ -257,3 +320,158 @@ pub fn print_vtable_sizes(&self, crate_name: Symbol) {
         }
     }
 }
+
+// The following data types are provided just for serialisation of the
+// `--emit=type-layout` report.
+
+#[derive(Serialize)]
+struct TypeLayoutReport<'a> {
+    crate_name: &'a str,
+    types: Vec<TypeLayout<'a>>,
+}
+
+#[derive(Serialize)]
+struct TypeLayout<'a> {
+    /// The type, as it would be printed by `-Zprint-type-sizes`.
+    #[serde(rename = "type")]
+    ty: &'a str,
+    /// "struct", "union", "enum", "closure" or "coroutine".
+    kind: &'static str,
+    /// The path of the generic definition this type instantiates.
+    def_path: Option<&'a str>,
+    /// The generic arguments of the instantiation, excluding lifetimes.
+    generic_args: &'a [String],
+    size: u64,
+    align: u64,
+    packed: bool,
+    discriminant: Option<DiscriminantLayout<'a>>,
+    largest_niche: Option<NicheLayout>,
+    variants: Vec<VariantLayout<'a>>,
+    /// Padding between the end of the largest variant and the end of the type.
+    end_padding: u64,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "encoding", rename_all = "snake_case")]
+enum DiscriminantLayout<'a> {
+    Tag { offset: u64, size: u64 },
+    Niche { offset: u64, size: u64, untagged_variant: &'a str, niche_start: u128 },
+}
+
+#[derive(Serialize)]
+struct NicheLayout {
+    offset: u64,
+    size: u64,
+    valid_range_start: u128,
+    valid_range_end: u128,
+    available: u128,
+}
+
+#[derive(Serialize)]
+struct VariantLayout<'a> {
+    name: Option<&'a str>,
+    /// The offset of the end of the last field, including the discriminant.
+    size: u64,
+    /// Whether `size` is only a lower bound, for unsized types.
+    is_min_size: bool,
+    align: u64,
+    /// The fields, by increasing offset.
+    fields: Vec<FieldLayout<'a>>,
+}
+
+#[derive(Serialize)]
+struct FieldLayout<'a> {
+    /// "field", "upvar" or "local".
+    kind: &'static str,
+    name: &'a str,
+    offset: u64,
+    size: u64,
+    align: u64,
+    /// Padding between the end of the previous field (or discriminant) and this field.
+    padding_before: u64,
+}
+
+impl<'a> TypeLayout<'a> {
+    fn from_type_size_info(info: &'a TypeSizeInfo) -> TypeLayout<'a> {
+        let discr_size = info.opt_discr_size.unwrap_or(0);
+        let mut max_variant_size = discr_size;
+
+        let variants = info
+            .variants
+            .iter()
+            .map(|variant| {
+                max_variant_size = cmp::max(max_variant_size, variant.size);
+
+                // See `print_type_sizes` for why zero-sized fields go first.
+                let mut fields: Vec<_> = variant.fields.iter().collect();
+                fields.sort_by_key(|f| (f.offset, f.size));
+
+                let mut min_offset = discr_size;
+                let fields = fields
+                    .into_iter()
+                    .map(|field| {
+                        // Fields of unions overlap, they don't have padding in between.
+                        let padding_before = field.offset.saturating_sub(min_offset);
+                        min_offset = cmp::max(min_offset, field.offset + field.size);
+                        FieldLayout {
+                            kind: match field.kind {
+                                FieldKind::AdtField => "field",
+                                FieldKind::Upvar => "upvar",
+                                FieldKind::CoroutineLocal => "local",
+                            },
+                            name: field.name.as_str(),
+                            offset: field.offset,
+                            size: field.size,
+                            align: field.align,
+                            padding_before,
+                        }
+                    })
+                    .collect();
+
+                VariantLayout {
+                    name: variant.name.as_ref().map(|name| name.as_str()),
+                    size: variant.size,
+                    is_min_size: variant.kind == SizeKind::Min,
+                    align: variant.align,
+                    fields,
+                }
+            })
+            .collect();
+
+        TypeLayout {
+            ty: &info.type_description,
+            kind: match info.kind {
+                DataTypeKind::Struct => "struct",
+                DataTypeKind::Union => "union",
+                DataTypeKind::Enum => "enum",
+                DataTypeKind::Closure => "closure",
+                DataTypeKind::Coroutine => "coroutine",
+            },
+            def_path: info.instantiation.as_ref().map(|inst| &*inst.def_path),
+            generic_args: info.instantiation.as_ref().map_or(&[][..], |inst| &inst.args[..]),
+            size: info.overall_size,
+            align: info.align,
+            packed: info.packed,
+            discriminant: info.discriminant.as_ref().map(|discr| match discr {
+                &DiscriminantInfo::Tag { offset, size } => DiscriminantLayout::Tag { offset, size },
+                &DiscriminantInfo::Niche { offset, size, ref untagged_variant, niche_start } => {
+                    DiscriminantLayout::Niche {
+                        offset,
+                        size,
+                        untagged_variant: untagged_variant.as_str(),
+                        niche_start,
+                    }
+                }
+            }),
+            largest_niche: info.largest_niche.map(|niche| NicheLayout {
+                offset: niche.offset,
+                size: niche.size,
+                valid_range_start: niche.valid_range_start,
+                valid_range_end: niche.valid_range_end,
+                available: niche.available,
+            }),
+            variants,
+            end_padding: info.overall_size.saturating_sub(max_variant_size),
+        }
+    }
+}
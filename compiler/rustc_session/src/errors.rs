@@ -148,6 +148,15 @@ pub struct UnsupportedDwarfVersion {
     pub dwarf_version: u32,
 }
 
+#[derive(Diagnostic)]
+#[diag(session_unused_warning_baseline_entry)]
+#[note]
+pub(crate) struct UnusedWarningBaselineEntry {
+    pub lint: String,
+    pub file: String,
+    pub count: usize,
+}
+
 #[derive(Diagnostic)]
 #[diag(session_target_stack_protector_not_supported)]
 pub struct StackProtectorNotSupportedForTarget<'a> {
@@ -468,3 +477,10 @@ pub struct IncompatibleLinkerFlavor {
 pub struct FailedToCreateProfiler {
     pub err: String,
 }
+
+#[derive(Diagnostic)]
+#[diag(session_failed_to_load_warning_baseline)]
+pub(crate) struct FailedToLoadWarningBaseline<'a> {
+    pub path: &'a std::path::Path,
+    pub err: String,
+}
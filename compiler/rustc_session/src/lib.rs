@@ -34,6 +34,7 @@
 pub use session::*;
 
 pub mod output;
+pub mod warning_baseline;
 
 pub use getopts;
 
@@ -4,7 +4,7 @@
 use crate::utils::NativeLib;
 use crate::{lint, EarlyDiagCtxt};
 use rustc_data_structures::fx::FxIndexMap;
-use rustc_data_structures::profiling::TimePassesFormat;
+use rustc_data_structures::profiling::{SelfProfileFormat, TimePassesFormat};
 use rustc_data_structures::stable_hasher::Hash64;
 use rustc_errors::ColorConfig;
 use rustc_errors::{LanguageIdentifier, TerminalUrl};
@@ -376,6 +376,7 @@ mod desc {
     pub const parse_opt_number: &str = parse_number;
     pub const parse_threads: &str = parse_number;
     pub const parse_time_passes_format: &str = "`text` (default) or `json`";
+    pub const parse_self_profile_format: &str = "`measureme` (default) or `chrome-trace`";
     pub const parse_passes: &str = "a space-separated list of passes, or `all`";
     pub const parse_panic_strategy: &str = "either `unwind` or `abort`";
     pub const parse_opt_panic_strategy: &str = parse_panic_strategy;
@@ -880,6 +881,20 @@ pub(crate) fn parse_time_passes_format(slot: &mut TimePassesFormat, v: Option<&s
         }
     }
 
+    pub(crate) fn parse_self_profile_format(slot: &mut SelfProfileFormat, v: Option<&str>) -> bool {
+        match v {
+            Some("measureme") => {
+                *slot = SelfProfileFormat::Measureme;
+                true
+            }
+            Some("chrome-trace") => {
+                *slot = SelfProfileFormat::ChromeTrace;
+                true
+            }
+            _ => false,
+        }
+    }
+
     pub(crate) fn parse_dump_mono_stats(slot: &mut DumpMonoStatsFormat, v: Option<&str>) -> bool {
         match v {
             None => true,
@@ -1628,6 +1643,9 @@ pub(crate) fn parse_function_return(slot: &mut FunctionReturn, v: Option<&str>)
         "display unnamed regions as `'<id>`, using a non-ident unique id (default: no)"),
     ignore_directory_in_diagnostics_source_blocks: Vec<String> = (Vec::new(), parse_string_push, [UNTRACKED],
         "do not display the source code block in diagnostics for files in the directory"),
+    incremental_explain: Option<PathBuf> = (None, parse_opt_pathbuf, [UNTRACKED],
+        "write a JSON report explaining why the results of the previous incremental session \
+        could not be reused to this file"),
     incremental_ignore_spans: bool = (false, parse_bool, [TRACKED],
         "ignore spans during ICH computation -- used for testing (default: no)"),
     incremental_info: bool = (false, parse_bool, [UNTRACKED],
@@ -1842,6 +1860,10 @@ pub(crate) fn parse_function_return(slot: &mut FunctionReturn, v: Option<&str>)
         for example: `-Z self-profile-events=default,query-keys`
         all options: none, all, default, generic-activity, query-provider, query-cache-hit
                      query-blocked, incr-cache-load, incr-result-hashing, query-keys, function-args, args, llvm, artifact-sizes"),
+    self_profile_format: SelfProfileFormat = (SelfProfileFormat::Measureme,
+        parse_self_profile_format, [UNTRACKED],
+        "the format of the self profiler output: `measureme` (default), or `chrome-trace` to write a \
+        Chrome trace and print a summary to stderr"),
     share_generics: Option<bool> = (None, parse_opt_bool, [TRACKED],
         "make the current crate share its generic instantiations"),
     shell_argfiles: bool = (false, parse_bool, [UNTRACKED],
@@ -1975,10 +1997,15 @@ pub(crate) fn parse_function_return(slot: &mut FunctionReturn, v: Option<&str>)
     virtual_function_elimination: bool = (false, parse_bool, [TRACKED],
         "enables dead virtual function elimination optimization. \
         Requires `-Clto[=[fat,yes]]`"),
+    warning_baseline: Option<PathBuf> = (None, parse_opt_pathbuf, [UNTRACKED],
+        "do not report the lint warnings listed in this baseline file, and report the entries \
+        of the file that no longer match any warning"),
     wasi_exec_model: Option<WasiExecModel> = (None, parse_wasi_exec_model, [TRACKED],
         "whether to build a wasi command or reactor"),
     write_long_types_to_disk: bool = (true, parse_bool, [UNTRACKED],
         "whether long type names should be written to files instead of being printed in errors"),
+    write_warning_baseline: Option<PathBuf> = (None, parse_opt_pathbuf, [UNTRACKED],
+        "write the lint warnings of the compilation to this baseline file"),
     // tidy-alphabetical-end
 
     // If you add a new option, please update:
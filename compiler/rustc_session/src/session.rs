@@ -8,10 +8,11 @@
 use crate::errors;
 use crate::parse::{add_feature_diagnostics, ParseSess};
 use crate::search_paths::{PathKind, SearchPath};
+use crate::warning_baseline::{BaselineEntry, WarningBaseline};
 use crate::{filesearch, lint};
 
 use rustc_data_structures::flock;
-use rustc_data_structures::fx::{FxHashMap, FxIndexSet};
+use rustc_data_structures::fx::{FxHashMap, FxHashSet, FxIndexSet};
 use rustc_data_structures::jobserver::{self, Client};
 use rustc_data_structures::profiling::{SelfProfiler, SelfProfilerRef};
 use rustc_data_structures::sync::{AtomicU64, DynSend, DynSync, Lock, Lrc, OneThread};
@@ -19,6 +20,7 @@
 use rustc_errors::emitter::{DynEmitter, HumanEmitter, HumanReadableErrorType};
 use rustc_errors::json::JsonEmitter;
 use rustc_errors::registry::Registry;
+use rustc_errors::sarif::SarifEmitter;
 use rustc_errors::{
     error_code, fallback_fluent_bundle, DiagCtxt, DiagnosticBuilder, DiagnosticId,
     DiagnosticMessage, ErrorGuaranteed, FatalAbort, FluentBundle, IntoDiagnostic,
@@ -158,6 +160,10 @@ pub struct Session {
     /// Data about code being compiled, gathered during compilation.
     pub code_stats: CodeStats,
 
+    /// The lint warnings recorded for `-Zwarning-baseline` and `-Zwrite-warning-baseline`, if
+    /// any of them is specified.
+    warning_baseline: Option<Lrc<WarningBaseline>>,
+
     /// Tracks fuel info if `-zfuel=crate=n` is specified.
     optimization_fuel: Lock<OptimizationFuel>,
 
@@ -287,10 +293,44 @@ fn check_miri_unleashed_features(&self) {
     /// Invoked all the way at the end to finish off diagnostics printing.
     pub fn finish_diagnostics(&self, registry: &Registry) {
         self.check_miri_unleashed_features();
+        self.finish_warning_baseline();
         self.dcx().print_error_count(registry);
         self.emit_future_breakage();
     }
 
+    fn finish_warning_baseline(&self) {
+        let Some(baseline) = &self.warning_baseline else { return };
+        if let Some(path) = &self.opts.unstable_opts.write_warning_baseline {
+            if let Err(err) = baseline.write(path) {
+                self.dcx().emit_err(errors::FileWriteFail { path, err: err.to_string() });
+            }
+        }
+
+        // If compilation failed, some of the warnings may not have been computed.
+        let has_baseline = self.opts.unstable_opts.warning_baseline.is_some();
+        if !has_baseline || self.dcx().has_errors().is_some() {
+            return;
+        }
+        // A baseline can be shared by several crates, so we only report the entries of the files
+        // that belong to the current one.
+        let files: FxHashSet<String> = self
+            .source_map()
+            .files()
+            .iter()
+            .filter(|file| !file.is_imported())
+            .map(|file| file.name.prefer_local().to_string())
+            .collect();
+        for (entry, count) in baseline.unmatched_entries() {
+            if files.contains(&entry.file) {
+                self.dcx().emit_warn(errors::UnusedWarningBaselineEntry {
+                    lint: entry.lint,
+                    file: entry.file,
+                    count,
+                });
+            }
+        }
+    }
+
     fn emit_future_breakage(&self) {
         if !self.opts.json_future_incompat {
             return;
@@ -354,6 +394,7 @@ pub fn good_path_delayed_bug(&self, msg: impl Into<DiagnosticMessage>) {
             || self.opts.unstable_opts.dump_mir.is_some()
             || self.opts.unstable_opts.unpretty.is_some()
             || self.opts.output_types.contains_key(&OutputType::Mir)
+            || self.opts.output_types.contains_key(&OutputType::TypeLayout)
             || std::env::var_os("RUSTC_LOG").is_some()
         {
             return;
@@ -362,6 +403,13 @@ pub fn good_path_delayed_bug(&self, msg: impl Into<DiagnosticMessage>) {
         self.dcx().good_path_delayed_bug(msg)
     }
 
+    /// Whether the layouts of types should be recorded in `code_stats`, for
+    /// `-Zprint-type-sizes` or `--emit=type-layout`.
+    pub fn record_type_layouts(&self) -> bool {
+        self.opts.unstable_opts.print_type_sizes
+            || self.opts.output_types.contains_key(&OutputType::TypeLayout)
+    }
+
     #[inline]
     pub fn dcx(&self) -> &DiagCtxt {
         &self.parse_sess.dcx
@@ -1037,6 +1085,13 @@ fn default_emitter(
                 sopts.unstable_opts.ignore_directory_in_diagnostics_source_blocks.clone(),
             ),
         ),
+        config::ErrorOutputType::Sarif { pretty } => Box::new(SarifEmitter::stderr(
+            Some(registry),
+            source_map,
+            bundle,
+            fallback_bundle,
+            pretty,
+        )),
     }
 }
 
@@ -1122,6 +1177,7 @@ pub fn build_session(
             sopts.crate_name.as_deref(),
             sopts.unstable_opts.self_profile_events.as_deref(),
             &sopts.unstable_opts.self_profile_counter,
+            sopts.unstable_opts.self_profile_format,
         );
         match profiler {
             Ok(profiler) => Some(Arc::new(profiler)),
@@ -1134,6 +1190,22 @@ pub fn build_session(
         None
     };
 
+    let warning_baseline = match &sopts.unstable_opts.warning_baseline {
+        Some(path) => Some(WarningBaseline::load(path).unwrap_or_else(|err| {
+            dcx.emit_fatal(errors::FailedToLoadWarningBaseline { path, err })
+        })),
+        None => sopts.unstable_opts.write_warning_baseline.is_some().then(WarningBaseline::default),
+    }
+    .map(Lrc::new);
+    if let Some(baseline) = &warning_baseline {
+        // The warnings are recorded, and possibly suppressed, as they are emitted.
+        let baseline = Lrc::clone(baseline);
+        let source_map = Lrc::clone(&source_map);
+        dcx = dcx.with_warning_baseline(Box::new(move |lint, span| {
+            baseline.suppress(BaselineEntry::new(&source_map, lint.to_string(), span))
+        }));
+    }
+
     let mut parse_sess = ParseSess::with_dcx(dcx, source_map);
     parse_sess.assume_incomplete_release = sopts.unstable_opts.assume_incomplete_release;
 
@@ -1180,6 +1252,7 @@ pub fn build_session(
         incr_comp_session: OneThread::new(RefCell::new(IncrCompSession::NotInitialized)),
         prof,
         code_stats: Default::default(),
+        warning_baseline,
         optimization_fuel,
         print_fuel,
         jobserver: jobserver::client(),
@@ -1514,6 +1587,9 @@ fn mk_emitter(output: ErrorOutputType) -> Box<DynEmitter> {
             false,
             TerminalUrl::No,
         )),
+        config::ErrorOutputType::Sarif { pretty } => {
+            Box::new(SarifEmitter::basic(pretty, fallback_bundle))
+        }
     };
     emitter
 }
@@ -0,0 +1,116 @@
+//! Support for `-Zwarning-baseline` and `-Zwrite-warning-baseline`.
+//!
+//! A warning baseline lists the lint warnings that are known to exist in a code base, so that
+//! new lints can be enabled without having to fix or `#[allow]` every pre-existing occurrence
+//! first. Each entry identifies a warning by the name of its lint, the file it points to, and a
+//! fingerprint of the snippet of its primary span. The fingerprint ignores whitespace, and
+//! doesn't depend on line numbers, so entries keep matching when unrelated code moves around.
+//!
+//! The warnings that match an entry are not reported, and each entry can only be used once, so
+//! new occurrences of an existing warning still get reported. Entries that don't match any
+//! warning are reported at the end of the compilation, as they can be removed from the baseline.
+//!
+//! The baseline is checked by the `DiagCtxt` when a warning is emitted, rather than when the lint
+//! is raised. Incremental compilation stores the warnings of a query before they go through the
+//! baseline, and replays them through it when the query is reused, so the baseline applies in the
+//! same way to the reused queries, and doesn't need to be part of the incremental state.
+
+use rustc_data_structures::fx::FxHashMap;
+use rustc_data_structures::stable_hasher::{Hash64, StableHasher};
+use rustc_data_structures::sync::Lock;
+use rustc_span::source_map::SourceMap;
+use rustc_span::Span;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::hash::Hash;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+/// A warning, as recorded in a baseline file.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    /// The name of the lint, e.g., `unused_variables` or `clippy::needless_return`.
+    pub lint: String,
+    /// The file the primary span of the warning points to.
+    pub file: String,
+    /// A hash of the snippet of the primary span of the warning.
+    pub fingerprint: String,
+}
+
+impl BaselineEntry {
+    pub fn new(source_map: &SourceMap, lint: String, span: Span) -> BaselineEntry {
+        let file = source_map.span_to_filename(span).prefer_local().to_string();
+        // Spans that don't point to source code, e.g., the ones of imported files without
+        // sources, are only identified by their lint and file.
+        let snippet = source_map.span_to_snippet(span).unwrap_or_default();
+        let mut hasher = StableHasher::new();
+        for word in snippet.split_whitespace() {
+            word.hash(&mut hasher);
+        }
+        let fingerprint: Hash64 = hasher.finish();
+        BaselineEntry { lint, file, fingerprint: format!("{:016x}", fingerprint.as_u64()) }
+    }
+}
+
+/// The state of the warning baseline of a session.
+#[derive(Default)]
+pub struct WarningBaseline {
+    /// The entries of `-Zwarning-baseline` that didn't match a warning yet, with their number of
+    /// occurrences.
+    unmatched: Lock<FxHashMap<BaselineEntry, usize>>,
+    /// Every warning reported during the session, including the suppressed ones.
+    recorded: Lock<Vec<BaselineEntry>>,
+}
+
+impl WarningBaseline {
+    /// Load the baseline file at `path`.
+    pub fn load(path: &Path) -> Result<WarningBaseline, String> {
+        let contents = fs::read_to_string(path).map_err(|err| err.to_string())?;
+        let entries: Vec<BaselineEntry> =
+            serde_json::from_str(&contents).map_err(|err| err.to_string())?;
+        let mut unmatched = FxHashMap::default();
+        for entry in entries {
+            *unmatched.entry(entry).or_default() += 1;
+        }
+        Ok(WarningBaseline { unmatched: Lock::new(unmatched), recorded: Default::default() })
+    }
+
+    /// Record a warning that is about to be reported, and return whether it is covered by the
+    /// baseline, in which case it shouldn't be reported.
+    pub fn suppress(&self, entry: BaselineEntry) -> bool {
+        let suppress = match self.unmatched.lock().get_mut(&entry) {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                true
+            }
+            _ => false,
+        };
+        self.recorded.lock().push(entry);
+        suppress
+    }
+
+    /// The entries that didn't match any warning, with their number of unused occurrences.
+    #[allow(rustc::potential_query_instability)]
+    pub fn unmatched_entries(&self) -> Vec<(BaselineEntry, usize)> {
+        let mut entries: Vec<_> = self
+            .unmatched
+            .lock()
+            .iter()
+            .filter(|(_, &count)| count > 0)
+            .map(|(entry, &count)| (entry.clone(), count))
+            .collect();
+        // Sort the entries to get a deterministic output.
+        entries.sort();
+        entries
+    }
+
+    /// Write the warnings reported during the session to a new baseline file at `path`.
+    pub fn write(&self, path: &Path) -> io::Result<()> {
+        let mut entries = self.recorded.lock().clone();
+        entries.sort();
+        let mut file = BufWriter::new(fs::File::create(path)?);
+        serde_json::to_writer_pretty(&mut file, &entries)?;
+        writeln!(file)?;
+        file.flush()
+    }
+}
@@ -0,0 +1,281 @@
+//! Allow drivers to replace the optimized MIR of the items of the local crate.
+//!
+//! This is useful for tools that instrument the code being compiled, e.g., to collect coverage
+//! information, without having to maintain their own version of the MIR passes.
+
+use crate::rustc_smir::{Stable, Tables};
+use rustc_const_eval::transform::validate::validate_body;
+use rustc_data_structures::fx::FxHashMap;
+use rustc_middle::mir;
+use rustc_middle::ty::TyCtxt;
+use rustc_span::def_id::LocalDefId;
+use stable_mir::mir::{Body, StatementKind, Terminator, TerminatorKind, UnwindAction};
+use stable_mir::CrateItem;
+use std::collections::VecDeque;
+use std::sync::RwLock;
+
+use super::{run_nested, with_tables, RustcInternal};
+
+/// A callback that may replace the optimized MIR of an item.
+///
+/// It receives the item and its optimized body, and returns the body to be used instead, or
+/// `None` to keep the original one.
+pub type BodyOverride = fn(CrateItem, Body) -> Option<Body>;
+
+struct OverrideState {
+    callback: BodyOverride,
+    /// The query override that was installed before ours, if any.
+    previous: Option<fn(&rustc_session::Session, &mut rustc_middle::util::Providers)>,
+    /// The providers we replaced, used to compute the original body.
+    original: Option<rustc_middle::query::Providers>,
+}
+
+static OVERRIDE_STATE: RwLock<Option<OverrideState>> = RwLock::new(None);
+
+/// Install `callback` to replace the optimized MIR of the items of the local crate.
+///
+/// The callback is invoked once per item, when the compiler first computes its optimized MIR. It
+/// runs in a StableMIR context of its own, which may be nested inside the context of the caller,
+/// so it must not use the items from any other context.
+///
+/// Replaced bodies go through the MIR validator. If a body is invalid, an error is reported and
+/// the original body is used instead. The number of arguments of a body cannot be changed, and
+/// the debug information of the new body must refer to its own locals and to the source scopes of
+/// the original body. Bodies that contain inline assembly, coverage statements, or user type
+/// ascriptions cannot be replaced.
+///
+/// This must be called from the `config` callback of the driver. Any query override already
+/// installed in `config` keeps working.
+pub fn override_optimized_mir(config: &mut rustc_interface::Config, callback: BodyOverride) {
+    *OVERRIDE_STATE.write().unwrap() =
+        Some(OverrideState { callback, previous: config.override_queries, original: None });
+    config.override_queries = Some(|session, providers| {
+        let mut state = OVERRIDE_STATE.write().unwrap();
+        let state = state.as_mut().unwrap();
+        if let Some(previous) = state.previous {
+            previous(session, providers);
+        }
+        state.original = Some(providers.queries);
+        providers.optimized_mir = optimized_mir;
+    });
+}
+
+fn optimized_mir(tcx: TyCtxt<'_>, def_id: LocalDefId) -> &mir::Body<'_> {
+    let (callback, original) = {
+        let state = OVERRIDE_STATE.read().unwrap();
+        let state = state.as_ref().unwrap();
+        (state.callback, state.original.unwrap().optimized_mir)
+    };
+    let body = original(tcx, def_id);
+    match override_body(tcx, def_id, body, callback) {
+        Some(new_body) => tcx.arena.alloc(new_body),
+        None => body,
+    }
+}
+
+fn override_body<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    def_id: LocalDefId,
+    body: &mir::Body<'tcx>,
+    callback: BodyOverride,
+) -> Option<mir::Body<'tcx>> {
+    let result = run_nested(tcx, || {
+        let (item, stable_body) =
+            with_tables(|tables| (tables.crate_item(def_id.to_def_id()), body.stable(tables)));
+        let new_body = callback(item, stable_body)?;
+        Some(with_tables(|tables| internal_body(tables, body, &new_body)))
+    })?;
+
+    let errors = match result {
+        Ok(new_body) => {
+            let errors = validate_body(tcx, body.phase, &new_body);
+            if errors.is_empty() {
+                return Some(new_body);
+            }
+            errors
+                .into_iter()
+                .map(|(location, msg)| match location {
+                    Some(location) => (new_body.source_info(location).span, msg),
+                    None => (new_body.span, msg),
+                })
+                .collect()
+        }
+        Err(msg) => vec![(body.span, msg)],
+    };
+    for (span, msg) in errors {
+        tcx.dcx().span_err(
+            span,
+            format!("invalid MIR override for `{}`: {msg}", tcx.def_path_str(def_id)),
+        );
+    }
+    None
+}
+
+/// Build the internal version of `new_body`, which replaces `original`.
+///
+/// StableMIR doesn't expose source scopes, cleanup blocks, or some details of the terminators, so
+/// these are recovered from the original body, or inferred from the new one.
+fn internal_body<'tcx>(
+    tables: &mut Tables<'tcx>,
+    original: &mir::Body<'tcx>,
+    new_body: &Body,
+) -> Result<mir::Body<'tcx>, String> {
+    if new_body.arg_locals().len() != original.arg_count {
+        return Err(format!(
+            "expected {} arguments, but the new body has {}",
+            original.arg_count,
+            new_body.arg_locals().len()
+        ));
+    }
+    for block in &new_body.blocks {
+        for statement in &block.statements {
+            if let StatementKind::AscribeUserType { .. } | StatementKind::Coverage(_) =
+                statement.kind
+            {
+                return Err(format!("unsupported statement `{:?}`", statement.kind));
+            }
+        }
+        if let TerminatorKind::InlineAsm { .. } = block.terminator.kind {
+            return Err("inline assembly is not supported".to_string());
+        }
+    }
+
+    // Reuse the scope of the statements that come from the original body.
+    let mut scopes = FxHashMap::default();
+    for data in original.basic_blocks.iter() {
+        for statement in &data.statements {
+            scopes.entry(statement.source_info.span).or_insert(statement.source_info.scope);
+        }
+        let source_info = data.terminator().source_info;
+        scopes.entry(source_info.span).or_insert(source_info.scope);
+    }
+    let source_info = |span: stable_mir::ty::Span, tables: &mut Tables<'tcx>| {
+        let span = span.internal(tables);
+        let scope = scopes.get(&span).copied().unwrap_or(mir::OUTERMOST_SOURCE_SCOPE);
+        mir::SourceInfo { span, scope }
+    };
+
+    let is_cleanup = cleanup_blocks(new_body);
+    let blocks = new_body
+        .blocks
+        .iter()
+        .zip(is_cleanup)
+        .map(|(block, is_cleanup)| {
+            let statements = block
+                .statements
+                .iter()
+                .map(|statement| mir::Statement {
+                    source_info: source_info(statement.span, tables),
+                    kind: statement.kind.internal(tables),
+                })
+                .collect();
+            let terminator = mir::Terminator {
+                source_info: source_info(block.terminator.span, tables),
+                kind: internal_terminator(tables, &block.terminator, is_cleanup),
+            };
+            mir::BasicBlockData { statements, terminator: Some(terminator), is_cleanup }
+        })
+        .collect();
+
+    let mut body = original.clone();
+    body.local_decls = new_body
+        .local_decls()
+        .map(|(local, decl)| {
+            let ty = decl.ty.internal(tables);
+            let span = decl.span.internal(tables);
+            let mut internal = match original.local_decls.get(mir::Local::from_usize(local)) {
+                Some(original_decl) => original_decl.clone(),
+                None => mir::LocalDecl::new(ty, span),
+            };
+            internal.ty = ty;
+            internal.mutability = decl.mutability.internal(tables);
+            internal.source_info.span = span;
+            internal
+        })
+        .collect();
+    body.var_debug_info = new_body.var_debug_info.internal(tables);
+    *body.basic_blocks_mut() = blocks;
+    Ok(body)
+}
+
+/// Compute which blocks are cleanup blocks, i.e., the ones that are reachable from an unwind
+/// edge.
+fn cleanup_blocks(body: &Body) -> Vec<bool> {
+    let mut is_cleanup = vec![false; body.blocks.len()];
+    let mut queue: VecDeque<_> = body
+        .blocks
+        .iter()
+        .filter_map(|block| match block.terminator.kind.unwind() {
+            Some(UnwindAction::Cleanup(target)) => Some(*target),
+            _ => None,
+        })
+        .collect();
+    while let Some(bb) = queue.pop_front() {
+        // Jumps to blocks that don't exist are reported by the validator.
+        if is_cleanup.get(bb) == Some(&false) {
+            is_cleanup[bb] = true;
+            queue.extend(body.blocks[bb].terminator.successors());
+        }
+    }
+    is_cleanup
+}
+
+fn internal_terminator<'tcx>(
+    tables: &mut Tables<'tcx>,
+    terminator: &Terminator,
+    is_cleanup: bool,
+) -> mir::TerminatorKind<'tcx> {
+    let bb = mir::BasicBlock::from_usize;
+    let terminate_reason = if is_cleanup {
+        mir::UnwindTerminateReason::InCleanup
+    } else {
+        mir::UnwindTerminateReason::Abi
+    };
+    let unwind = |unwind: &UnwindAction| match unwind {
+        UnwindAction::Continue => mir::UnwindAction::Continue,
+        UnwindAction::Unreachable => mir::UnwindAction::Unreachable,
+        UnwindAction::Terminate => mir::UnwindAction::Terminate(terminate_reason),
+        UnwindAction::Cleanup(target) => mir::UnwindAction::Cleanup(bb(*target)),
+    };
+    match &terminator.kind {
+        TerminatorKind::Goto { target } => mir::TerminatorKind::Goto { target: bb(*target) },
+        TerminatorKind::SwitchInt { discr, targets } => mir::TerminatorKind::SwitchInt {
+            discr: discr.internal(tables),
+            targets: mir::SwitchTargets::new(
+                targets.branches().map(|(value, target)| (value, bb(target))),
+                bb(targets.otherwise()),
+            ),
+        },
+        TerminatorKind::Resume => mir::TerminatorKind::UnwindResume,
+        TerminatorKind::Abort => mir::TerminatorKind::UnwindTerminate(terminate_reason),
+        TerminatorKind::Return => mir::TerminatorKind::Return,
+        TerminatorKind::Unreachable => mir::TerminatorKind::Unreachable,
+        TerminatorKind::Drop { place, target, unwind: drop_unwind } => mir::TerminatorKind::Drop {
+            place: place.internal(tables),
+            target: bb(*target),
+            unwind: unwind(drop_unwind),
+            replace: false,
+        },
+        TerminatorKind::Call { func, args, destination, target, unwind: call_unwind } => {
+            mir::TerminatorKind::Call {
+                func: func.internal(tables),
+                args: args.internal(tables),
+                destination: destination.internal(tables),
+                target: target.map(bb),
+                unwind: unwind(call_unwind),
+                call_source: mir::CallSource::Normal,
+                fn_span: terminator.span.internal(tables),
+            }
+        }
+        TerminatorKind::Assert { cond, expected, msg, target, unwind: assert_unwind } => {
+            mir::TerminatorKind::Assert {
+                cond: cond.internal(tables),
+                expected: *expected,
+                msg: Box::new(msg.internal(tables)),
+                target: bb(*target),
+                unwind: unwind(assert_unwind),
+            }
+        }
+        TerminatorKind::InlineAsm { .. } => unreachable!("inline assembly was rejected earlier"),
+    }
+}
@@ -1,7 +1,8 @@
 //! Module containing the translation from stable mir constructs to the rustc counterpart.
 //!
 //! This module will only include a few constructs to allow users to invoke internal rustc APIs
-//! due to incomplete stable coverage.
+//! due to incomplete stable coverage, as well as the MIR constructs needed to turn a body
+//! overridden by the user back into internal MIR.
 
 // Prefer importing stable_mir over internal rustc constructs to make this file more readable.
 use crate::rustc_smir::Tables;
@@ -10,7 +11,13 @@
 use stable_mir::abi::Layout;
 use stable_mir::mir::alloc::AllocId;
 use stable_mir::mir::mono::{Instance, MonoItem, StaticDef};
-use stable_mir::mir::{Mutability, Safety};
+use stable_mir::mir::{
+    AggregateKind, AssertMessage, BinOp, BorrowKind, CastKind, Constant, CoroutineDesugaring,
+    CoroutineKind, CoroutineSource, FakeReadCause, MutBorrowKind, Mutability,
+    NonDivergingIntrinsic, NullOp, Operand, Place, PointerCoercion, ProjectionElem, RetagKind,
+    Rvalue, Safety, SourceInfo, StatementKind, UnOp, VarDebugInfo, VarDebugInfoContents,
+    VarDebugInfoFragment,
+};
 use stable_mir::ty::{
     Abi, AdtDef, Binder, BoundRegionKind, BoundTyKind, BoundVariableKind, ClosureKind, Const,
     DynKind, ExistentialPredicate, ExistentialProjection, ExistentialTraitRef, FloatTy, FnSig,
@@ -506,3 +513,496 @@ fn internal(&self, tables: &mut Tables<'tcx>) -> Self::T {
         self.iter().map(|e| e.internal(tables)).collect()
     }
 }
+
+impl<'tcx> RustcInternal<'tcx> for Place {
+    type T = rustc_middle::mir::Place<'tcx>;
+
+    fn internal(&self, tables: &mut Tables<'tcx>) -> Self::T {
+        let projection: Vec<_> = self.projection.internal(tables);
+        rustc_middle::mir::Place {
+            local: rustc_middle::mir::Local::from_usize(self.local),
+            projection: tables.tcx.mk_place_elems(&projection),
+        }
+    }
+}
+
+impl<'tcx> RustcInternal<'tcx> for ProjectionElem {
+    type T = rustc_middle::mir::PlaceElem<'tcx>;
+
+    fn internal(&self, tables: &mut Tables<'tcx>) -> Self::T {
+        use rustc_middle::mir::ProjectionElem as InternalElem;
+        match self {
+            ProjectionElem::Deref => InternalElem::Deref,
+            ProjectionElem::Field(idx, ty) => InternalElem::Field(
+                rustc_target::abi::FieldIdx::from_usize(*idx),
+                ty.internal(tables),
+            ),
+            ProjectionElem::Index(local) => {
+                InternalElem::Index(rustc_middle::mir::Local::from_usize(*local))
+            }
+            ProjectionElem::ConstantIndex { offset, min_length, from_end } => {
+                InternalElem::ConstantIndex {
+                    offset: *offset,
+                    min_length: *min_length,
+                    from_end: *from_end,
+                }
+            }
+            ProjectionElem::Subslice { from, to, from_end } => {
+                InternalElem::Subslice { from: *from, to: *to, from_end: *from_end }
+            }
+            // The variant name is only used for printing MIR.
+            ProjectionElem::Downcast(idx) => InternalElem::Downcast(None, idx.internal(tables)),
+            ProjectionElem::OpaqueCast(ty) => InternalElem::OpaqueCast(ty.internal(tables)),
+            ProjectionElem::Subtype(ty) => InternalElem::Subtype(ty.internal(tables)),
+        }
+    }
+}
+
+impl<'tcx> RustcInternal<'tcx> for Operand {
+    type T = rustc_middle::mir::Operand<'tcx>;
+
+    fn internal(&self, tables: &mut Tables<'tcx>) -> Self::T {
+        match self {
+            Operand::Copy(place) => rustc_middle::mir::Operand::Copy(place.internal(tables)),
+            Operand::Move(place) => rustc_middle::mir::Operand::Move(place.internal(tables)),
+            Operand::Constant(constant) => {
+                rustc_middle::mir::Operand::Constant(Box::new(constant.internal(tables)))
+            }
+        }
+    }
+}
+
+impl<'tcx> RustcInternal<'tcx> for Constant {
+    type T = rustc_middle::mir::ConstOperand<'tcx>;
+
+    fn internal(&self, tables: &mut Tables<'tcx>) -> Self::T {
+        rustc_middle::mir::ConstOperand {
+            span: self.span.internal(tables),
+            user_ty: self.user_ty.map(rustc_middle::ty::UserTypeAnnotationIndex::from_usize),
+            const_: self.literal.internal(tables),
+        }
+    }
+}
+
+impl<'tcx> RustcInternal<'tcx> for SourceInfo {
+    type T = rustc_middle::mir::SourceInfo;
+
+    fn internal(&self, tables: &mut Tables<'tcx>) -> Self::T {
+        rustc_middle::mir::SourceInfo {
+            span: self.span.internal(tables),
+            scope: rustc_middle::mir::SourceScope::from_u32(self.scope),
+        }
+    }
+}
+
+impl<'tcx> RustcInternal<'tcx> for VarDebugInfo {
+    type T = rustc_middle::mir::VarDebugInfo<'tcx>;
+
+    fn internal(&self, tables: &mut Tables<'tcx>) -> Self::T {
+        rustc_middle::mir::VarDebugInfo {
+            name: Symbol::intern(&self.name),
+            source_info: self.source_info.internal(tables),
+            composite: self
+                .composite
+                .as_ref()
+                .map(|composite| Box::new(composite.internal(tables))),
+            value: self.value.internal(tables),
+            argument_index: self.argument_index,
+        }
+    }
+}
+
+impl<'tcx> RustcInternal<'tcx> for VarDebugInfoFragment {
+    type T = rustc_middle::mir::VarDebugInfoFragment<'tcx>;
+
+    fn internal(&self, tables: &mut Tables<'tcx>) -> Self::T {
+        rustc_middle::mir::VarDebugInfoFragment {
+            ty: self.ty.internal(tables),
+            projection: self.projection.internal(tables),
+        }
+    }
+}
+
+impl<'tcx> RustcInternal<'tcx> for VarDebugInfoContents {
+    type T = rustc_middle::mir::VarDebugInfoContents<'tcx>;
+
+    fn internal(&self, tables: &mut Tables<'tcx>) -> Self::T {
+        match self {
+            VarDebugInfoContents::Place(place) => {
+                rustc_middle::mir::VarDebugInfoContents::Place(place.internal(tables))
+            }
+            VarDebugInfoContents::Const(const_operand) => {
+                rustc_middle::mir::VarDebugInfoContents::Const(rustc_middle::mir::ConstOperand {
+                    span: const_operand.span.internal(tables),
+                    user_ty: const_operand
+                        .user_ty
+                        .map(rustc_middle::ty::UserTypeAnnotationIndex::from_usize),
+                    const_: const_operand.const_.internal(tables),
+                })
+            }
+        }
+    }
+}
+
+impl<'tcx> RustcInternal<'tcx> for Rvalue {
+    type T = rustc_middle::mir::Rvalue<'tcx>;
+
+    fn internal(&self, tables: &mut Tables<'tcx>) -> Self::T {
+        use rustc_middle::mir::Rvalue as InternalRvalue;
+        match self {
+            Rvalue::AddressOf(mutability, place) => {
+                InternalRvalue::AddressOf(mutability.internal(tables), place.internal(tables))
+            }
+            Rvalue::Aggregate(kind, operands) => InternalRvalue::Aggregate(
+                Box::new(kind.internal(tables)),
+                operands.iter().map(|operand| operand.internal(tables)).collect(),
+            ),
+            Rvalue::BinaryOp(op, lhs, rhs) => InternalRvalue::BinaryOp(
+                op.internal(tables),
+                Box::new((lhs.internal(tables), rhs.internal(tables))),
+            ),
+            Rvalue::Cast(kind, operand, ty) => InternalRvalue::Cast(
+                kind.internal(tables),
+                operand.internal(tables),
+                ty.internal(tables),
+            ),
+            Rvalue::CheckedBinaryOp(op, lhs, rhs) => InternalRvalue::CheckedBinaryOp(
+                op.internal(tables),
+                Box::new((lhs.internal(tables), rhs.internal(tables))),
+            ),
+            Rvalue::CopyForDeref(place) => InternalRvalue::CopyForDeref(place.internal(tables)),
+            Rvalue::Discriminant(place) => InternalRvalue::Discriminant(place.internal(tables)),
+            Rvalue::Len(place) => InternalRvalue::Len(place.internal(tables)),
+            Rvalue::Ref(region, kind, place) => InternalRvalue::Ref(
+                region.internal(tables),
+                kind.internal(tables),
+                place.internal(tables),
+            ),
+            Rvalue::Repeat(operand, count) => {
+                InternalRvalue::Repeat(operand.internal(tables), ty_const(count, tables))
+            }
+            Rvalue::ShallowInitBox(operand, ty) => {
+                InternalRvalue::ShallowInitBox(operand.internal(tables), ty.internal(tables))
+            }
+            Rvalue::ThreadLocalRef(item) => InternalRvalue::ThreadLocalRef(item.internal(tables)),
+            Rvalue::NullaryOp(op, ty) => {
+                InternalRvalue::NullaryOp(op.internal(tables), ty.internal(tables))
+            }
+            Rvalue::UnaryOp(op, operand) => {
+                InternalRvalue::UnaryOp(op.internal(tables), operand.internal(tables))
+            }
+            Rvalue::Use(operand) => InternalRvalue::Use(operand.internal(tables)),
+        }
+    }
+}
+
+impl<'tcx> RustcInternal<'tcx> for AggregateKind {
+    type T = rustc_middle::mir::AggregateKind<'tcx>;
+
+    fn internal(&self, tables: &mut Tables<'tcx>) -> Self::T {
+        use rustc_middle::mir::AggregateKind as InternalKind;
+        match self {
+            AggregateKind::Array(ty) => InternalKind::Array(ty.internal(tables)),
+            AggregateKind::Tuple => InternalKind::Tuple,
+            AggregateKind::Adt(def, variant, args, user_ty, active_field) => InternalKind::Adt(
+                def.0.internal(tables),
+                variant.internal(tables),
+                args.internal(tables),
+                user_ty.map(rustc_middle::ty::UserTypeAnnotationIndex::from_usize),
+                active_field.map(rustc_target::abi::FieldIdx::from_usize),
+            ),
+            AggregateKind::Closure(def, args) => {
+                InternalKind::Closure(def.0.internal(tables), args.internal(tables))
+            }
+            AggregateKind::Coroutine(def, args, _movability) => {
+                InternalKind::Coroutine(def.0.internal(tables), args.internal(tables))
+            }
+        }
+    }
+}
+
+impl<'tcx> RustcInternal<'tcx> for BinOp {
+    type T = rustc_middle::mir::BinOp;
+
+    fn internal(&self, _tables: &mut Tables<'tcx>) -> Self::T {
+        use rustc_middle::mir::BinOp as InternalBinOp;
+        match self {
+            BinOp::Add => InternalBinOp::Add,
+            BinOp::AddUnchecked => InternalBinOp::AddUnchecked,
+            BinOp::Sub => InternalBinOp::Sub,
+            BinOp::SubUnchecked => InternalBinOp::SubUnchecked,
+            BinOp::Mul => InternalBinOp::Mul,
+            BinOp::MulUnchecked => InternalBinOp::MulUnchecked,
+            BinOp::Div => InternalBinOp::Div,
+            BinOp::Rem => InternalBinOp::Rem,
+            BinOp::BitXor => InternalBinOp::BitXor,
+            BinOp::BitAnd => InternalBinOp::BitAnd,
+            BinOp::BitOr => InternalBinOp::BitOr,
+            BinOp::Shl => InternalBinOp::Shl,
+            BinOp::ShlUnchecked => InternalBinOp::ShlUnchecked,
+            BinOp::Shr => InternalBinOp::Shr,
+            BinOp::ShrUnchecked => InternalBinOp::ShrUnchecked,
+            BinOp::Eq => InternalBinOp::Eq,
+            BinOp::Lt => InternalBinOp::Lt,
+            BinOp::Le => InternalBinOp::Le,
+            BinOp::Ne => InternalBinOp::Ne,
+            BinOp::Ge => InternalBinOp::Ge,
+            BinOp::Gt => InternalBinOp::Gt,
+            BinOp::Offset => InternalBinOp::Offset,
+        }
+    }
+}
+
+impl<'tcx> RustcInternal<'tcx> for UnOp {
+    type T = rustc_middle::mir::UnOp;
+
+    fn internal(&self, _tables: &mut Tables<'tcx>) -> Self::T {
+        match self {
+            UnOp::Not => rustc_middle::mir::UnOp::Not,
+            UnOp::Neg => rustc_middle::mir::UnOp::Neg,
+        }
+    }
+}
+
+impl<'tcx> RustcInternal<'tcx> for NullOp {
+    type T = rustc_middle::mir::NullOp<'tcx>;
+
+    fn internal(&self, tables: &mut Tables<'tcx>) -> Self::T {
+        match self {
+            NullOp::SizeOf => rustc_middle::mir::NullOp::SizeOf,
+            NullOp::AlignOf => rustc_middle::mir::NullOp::AlignOf,
+            NullOp::OffsetOf(indices) => rustc_middle::mir::NullOp::OffsetOf(
+                tables.tcx.mk_offset_of_from_iter(indices.iter().map(|(variant, field)| {
+                    (variant.internal(tables), rustc_target::abi::FieldIdx::from_usize(*field))
+                })),
+            ),
+        }
+    }
+}
+
+impl<'tcx> RustcInternal<'tcx> for CastKind {
+    type T = rustc_middle::mir::CastKind;
+
+    fn internal(&self, tables: &mut Tables<'tcx>) -> Self::T {
+        use rustc_middle::mir::CastKind as InternalKind;
+        match self {
+            CastKind::PointerExposeAddress => InternalKind::PointerExposeAddress,
+            CastKind::PointerFromExposedAddress => InternalKind::PointerFromExposedAddress,
+            CastKind::PointerCoercion(coercion) => {
+                InternalKind::PointerCoercion(coercion.internal(tables))
+            }
+            CastKind::DynStar => InternalKind::DynStar,
+            CastKind::IntToInt => InternalKind::IntToInt,
+            CastKind::FloatToInt => InternalKind::FloatToInt,
+            CastKind::FloatToFloat => InternalKind::FloatToFloat,
+            CastKind::IntToFloat => InternalKind::IntToFloat,
+            CastKind::PtrToPtr => InternalKind::PtrToPtr,
+            CastKind::FnPtrToPtr => InternalKind::FnPtrToPtr,
+            CastKind::Transmute => InternalKind::Transmute,
+        }
+    }
+}
+
+impl<'tcx> RustcInternal<'tcx> for PointerCoercion {
+    type T = rustc_ty::adjustment::PointerCoercion;
+
+    fn internal(&self, tables: &mut Tables<'tcx>) -> Self::T {
+        use rustc_ty::adjustment::PointerCoercion as InternalCoercion;
+        match self {
+            PointerCoercion::ReifyFnPointer => InternalCoercion::ReifyFnPointer,
+            PointerCoercion::UnsafeFnPointer => InternalCoercion::UnsafeFnPointer,
+            PointerCoercion::ClosureFnPointer(safety) => {
+                InternalCoercion::ClosureFnPointer(safety.internal(tables))
+            }
+            PointerCoercion::MutToConstPointer => InternalCoercion::MutToConstPointer,
+            PointerCoercion::ArrayToPointer => InternalCoercion::ArrayToPointer,
+            PointerCoercion::Unsize => InternalCoercion::Unsize,
+        }
+    }
+}
+
+impl<'tcx> RustcInternal<'tcx> for BorrowKind {
+    type T = rustc_middle::mir::BorrowKind;
+
+    fn internal(&self, tables: &mut Tables<'tcx>) -> Self::T {
+        match self {
+            BorrowKind::Shared => rustc_middle::mir::BorrowKind::Shared,
+            BorrowKind::Fake => rustc_middle::mir::BorrowKind::Fake,
+            BorrowKind::Mut { kind } => {
+                rustc_middle::mir::BorrowKind::Mut { kind: kind.internal(tables) }
+            }
+        }
+    }
+}
+
+impl<'tcx> RustcInternal<'tcx> for MutBorrowKind {
+    type T = rustc_middle::mir::MutBorrowKind;
+
+    fn internal(&self, _tables: &mut Tables<'tcx>) -> Self::T {
+        match self {
+            MutBorrowKind::Default => rustc_middle::mir::MutBorrowKind::Default,
+            MutBorrowKind::TwoPhaseBorrow => rustc_middle::mir::MutBorrowKind::TwoPhaseBorrow,
+            MutBorrowKind::ClosureCapture => rustc_middle::mir::MutBorrowKind::ClosureCapture,
+        }
+    }
+}
+
+impl<'tcx> RustcInternal<'tcx> for StatementKind {
+    type T = rustc_middle::mir::StatementKind<'tcx>;
+
+    fn internal(&self, tables: &mut Tables<'tcx>) -> Self::T {
+        use rustc_middle::mir::StatementKind as InternalKind;
+        match self {
+            StatementKind::Assign(place, rvalue) => {
+                InternalKind::Assign(Box::new((place.internal(tables), rvalue.internal(tables))))
+            }
+            StatementKind::FakeRead(cause, place) => {
+                InternalKind::FakeRead(Box::new((cause.internal(tables), place.internal(tables))))
+            }
+            StatementKind::SetDiscriminant { place, variant_index } => {
+                InternalKind::SetDiscriminant {
+                    place: Box::new(place.internal(tables)),
+                    variant_index: variant_index.internal(tables),
+                }
+            }
+            StatementKind::Deinit(place) => InternalKind::Deinit(Box::new(place.internal(tables))),
+            StatementKind::StorageLive(local) => {
+                InternalKind::StorageLive(rustc_middle::mir::Local::from_usize(*local))
+            }
+            StatementKind::StorageDead(local) => {
+                InternalKind::StorageDead(rustc_middle::mir::Local::from_usize(*local))
+            }
+            StatementKind::Retag(kind, place) => {
+                InternalKind::Retag(kind.internal(tables), Box::new(place.internal(tables)))
+            }
+            StatementKind::PlaceMention(place) => {
+                InternalKind::PlaceMention(Box::new(place.internal(tables)))
+            }
+            StatementKind::Intrinsic(intrinsic) => {
+                InternalKind::Intrinsic(Box::new(intrinsic.internal(tables)))
+            }
+            StatementKind::ConstEvalCounter => InternalKind::ConstEvalCounter,
+            StatementKind::Nop => InternalKind::Nop,
+            // The projections of the user type and the coverage information are not exposed in
+            // StableMIR, so these statements cannot be reconstructed.
+            StatementKind::AscribeUserType { .. } | StatementKind::Coverage(_) => {
+                unimplemented!("Cannot convert `{self:?}` to an internal statement")
+            }
+        }
+    }
+}
+
+impl<'tcx> RustcInternal<'tcx> for FakeReadCause {
+    type T = rustc_middle::mir::FakeReadCause;
+
+    fn internal(&self, _tables: &mut Tables<'tcx>) -> Self::T {
+        use rustc_middle::mir::FakeReadCause as InternalCause;
+        // The closure that contains the pattern is not exposed in StableMIR, and it is only used
+        // for diagnostics.
+        match self {
+            FakeReadCause::ForMatchGuard => InternalCause::ForMatchGuard,
+            FakeReadCause::ForMatchedPlace(_) => InternalCause::ForMatchedPlace(None),
+            FakeReadCause::ForGuardBinding => InternalCause::ForGuardBinding,
+            FakeReadCause::ForLet(_) => InternalCause::ForLet(None),
+            FakeReadCause::ForIndex => InternalCause::ForIndex,
+        }
+    }
+}
+
+impl<'tcx> RustcInternal<'tcx> for RetagKind {
+    type T = rustc_middle::mir::RetagKind;
+
+    fn internal(&self, _tables: &mut Tables<'tcx>) -> Self::T {
+        match self {
+            RetagKind::FnEntry => rustc_middle::mir::RetagKind::FnEntry,
+            RetagKind::TwoPhase => rustc_middle::mir::RetagKind::TwoPhase,
+            RetagKind::Raw => rustc_middle::mir::RetagKind::Raw,
+            RetagKind::Default => rustc_middle::mir::RetagKind::Default,
+        }
+    }
+}
+
+impl<'tcx> RustcInternal<'tcx> for NonDivergingIntrinsic {
+    type T = rustc_middle::mir::NonDivergingIntrinsic<'tcx>;
+
+    fn internal(&self, tables: &mut Tables<'tcx>) -> Self::T {
+        match self {
+            NonDivergingIntrinsic::Assume(operand) => {
+                rustc_middle::mir::NonDivergingIntrinsic::Assume(operand.internal(tables))
+            }
+            NonDivergingIntrinsic::CopyNonOverlapping(copy) => {
+                rustc_middle::mir::NonDivergingIntrinsic::CopyNonOverlapping(
+                    rustc_middle::mir::CopyNonOverlapping {
+                        src: copy.src.internal(tables),
+                        dst: copy.dst.internal(tables),
+                        count: copy.count.internal(tables),
+                    },
+                )
+            }
+        }
+    }
+}
+
+impl<'tcx> RustcInternal<'tcx> for AssertMessage {
+    type T = rustc_middle::mir::AssertMessage<'tcx>;
+
+    fn internal(&self, tables: &mut Tables<'tcx>) -> Self::T {
+        use rustc_middle::mir::AssertKind;
+        match self {
+            AssertMessage::BoundsCheck { len, index } => {
+                AssertKind::BoundsCheck { len: len.internal(tables), index: index.internal(tables) }
+            }
+            AssertMessage::Overflow(op, lhs, rhs) => AssertKind::Overflow(
+                op.internal(tables),
+                lhs.internal(tables),
+                rhs.internal(tables),
+            ),
+            AssertMessage::OverflowNeg(operand) => {
+                AssertKind::OverflowNeg(operand.internal(tables))
+            }
+            AssertMessage::DivisionByZero(operand) => {
+                AssertKind::DivisionByZero(operand.internal(tables))
+            }
+            AssertMessage::RemainderByZero(operand) => {
+                AssertKind::RemainderByZero(operand.internal(tables))
+            }
+            AssertMessage::ResumedAfterReturn(kind) => {
+                AssertKind::ResumedAfterReturn(kind.internal(tables))
+            }
+            AssertMessage::ResumedAfterPanic(kind) => {
+                AssertKind::ResumedAfterPanic(kind.internal(tables))
+            }
+            AssertMessage::MisalignedPointerDereference { required, found } => {
+                AssertKind::MisalignedPointerDereference {
+                    required: required.internal(tables),
+                    found: found.internal(tables),
+                }
+            }
+        }
+    }
+}
+
+impl<'tcx> RustcInternal<'tcx> for CoroutineKind {
+    type T = rustc_hir::CoroutineKind;
+
+    fn internal(&self, tables: &mut Tables<'tcx>) -> Self::T {
+        match self {
+            CoroutineKind::Desugared(desugaring, source) => {
+                let desugaring = match desugaring {
+                    CoroutineDesugaring::Async => rustc_hir::CoroutineDesugaring::Async,
+                    CoroutineDesugaring::Gen => rustc_hir::CoroutineDesugaring::Gen,
+                    CoroutineDesugaring::AsyncGen => rustc_hir::CoroutineDesugaring::AsyncGen,
+                };
+                let source = match source {
+                    CoroutineSource::Block => rustc_hir::CoroutineSource::Block,
+                    CoroutineSource::Closure => rustc_hir::CoroutineSource::Closure,
+                    CoroutineSource::Fn => rustc_hir::CoroutineSource::Fn,
+                };
+                rustc_hir::CoroutineKind::Desugared(desugaring, source)
+            }
+            CoroutineKind::Coroutine(movability) => {
+                rustc_hir::CoroutineKind::Coroutine(movability.internal(tables))
+            }
+        }
+    }
+}
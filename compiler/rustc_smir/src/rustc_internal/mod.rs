@@ -21,9 +21,12 @@
 use std::hash::Hash;
 use std::ops::Index;
 
+mod body_override;
 mod internal;
 pub mod pretty;
 
+pub use body_override::{override_optimized_mir, BodyOverride};
+
 pub fn stable<'tcx, S: Stable<'tcx>>(item: S) -> S::T {
     with_tables(|tables| item.stable(tables))
 }
@@ -173,11 +176,8 @@ pub(crate) fn with_tables<'tcx, R>(f: impl FnOnce(&mut Tables<'tcx>) -> R) -> R
     })
 }
 
-pub fn run<F, T>(tcx: TyCtxt<'_>, f: F) -> Result<T, Error>
-where
-    F: FnOnce() -> T,
-{
-    let tables = TablesWrapper(RefCell::new(Tables {
+fn new_tables(tcx: TyCtxt<'_>) -> TablesWrapper<'_> {
+    TablesWrapper(RefCell::new(Tables {
         tcx,
         def_ids: IndexMap::default(),
         alloc_ids: IndexMap::default(),
@@ -186,10 +186,33 @@ pub fn run<F, T>(tcx: TyCtxt<'_>, f: F) -> Result<T, Error>
         instances: IndexMap::default(),
         constants: IndexMap::default(),
         layouts: IndexMap::default(),
-    }));
+    }))
+}
+
+pub fn run<F, T>(tcx: TyCtxt<'_>, f: F) -> Result<T, Error>
+where
+    F: FnOnce() -> T,
+{
+    let tables = new_tables(tcx);
     stable_mir::compiler_interface::run(&tables, || init(&tables, f))
 }
 
+/// Run `f` with a new StableMIR context, even if one is already active.
+///
+/// This is used when the compiler invokes user code while answering a query, which may happen
+/// while the user is already running StableMIR code, e.g., when retrieving a body that was
+/// overridden. The new context has its own tables, so items must not be exchanged between the two.
+pub(crate) fn run_nested<F, T>(tcx: TyCtxt<'_>, f: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    let tables = new_tables(tcx);
+    stable_mir::compiler_interface::run_nested(&tables, || {
+        let ptr = &tables as *const _ as *const ();
+        TLV.set(&Cell::new(ptr), f)
+    })
+}
+
 #[macro_export]
 macro_rules! run {
     ($args:expr, $callback:expr) => {
@@ -6,6 +6,7 @@
 #![allow(rustc::usage_of_qualified_ty)]
 
 use rustc_abi::HasDataLayout;
+use rustc_data_structures::fx::{FxHashMap, FxIndexSet};
 use rustc_middle::ty;
 use rustc_middle::ty::layout::{
     FnAbiOf, FnAbiOfHelpers, HasParamEnv, HasTyCtxt, LayoutOf, LayoutOfHelpers,
@@ -18,12 +19,12 @@
 use stable_mir::abi::{FnAbi, Layout, LayoutShape};
 use stable_mir::compiler_interface::Context;
 use stable_mir::mir::alloc::GlobalAlloc;
-use stable_mir::mir::mono::{InstanceDef, StaticDef};
+use stable_mir::mir::mono::{CodegenUnit, InstanceDef, MonoItemGraph, MonoItemUse, StaticDef};
 use stable_mir::mir::Body;
 use stable_mir::target::{MachineInfo, MachineSize};
 use stable_mir::ty::{
     AdtDef, AdtKind, Allocation, ClosureDef, ClosureKind, Const, FieldDef, FnDef, GenericArgs,
-    LineInfo, PolyFnSig, RigidTy, Span, Ty, TyKind, VariantDef,
+    LineInfo, PolyFnSig, RigidTy, Span, Ty, TyKind, UintTy, VariantDef,
 };
 use stable_mir::{Crate, CrateItem, CrateNum, DefId, Error, Filename, ItemKind, Symbol};
 use std::cell::RefCell;
@@ -55,6 +56,44 @@ fn all_local_items(&self) -> stable_mir::CrateItems {
         tables.tcx.mir_keys(()).iter().map(|item| tables.crate_item(item.to_def_id())).collect()
     }
 
+    fn mono_item_graph(&self) -> MonoItemGraph {
+        let mut tables = self.0.borrow_mut();
+        let tcx = tables.tcx;
+        let (items, usage_map) = rustc_monomorphize::collect_mono_item_graph(tcx);
+        let codegen_units = rustc_monomorphize::partition_mono_item_graph(tcx, &items, &usage_map);
+
+        // Symbol names are unique, which gives us a deterministic order.
+        let mut items: Vec<_> = items.into_iter().collect();
+        items.sort_by_cached_key(|item| item.symbol_name(tcx).name);
+        let indices: FxHashMap<_, _> =
+            items.iter().enumerate().map(|(index, item)| (*item, index)).collect();
+
+        let mut uses = FxIndexSet::default();
+        for (user, item) in items.iter().enumerate() {
+            for (used_item, kind) in usage_map.get_used_items(*item) {
+                if let Some(&used) = indices.get(used_item) {
+                    uses.insert(MonoItemUse { user, used, kind: kind.stable(&mut *tables) });
+                }
+            }
+        }
+
+        let codegen_units = codegen_units
+            .iter()
+            .map(|cgu| {
+                let mut items: Vec<_> =
+                    cgu.items().keys().filter_map(|item| indices.get(item).copied()).collect();
+                items.sort_unstable();
+                CodegenUnit { name: cgu.name().to_string(), items }
+            })
+            .collect();
+
+        MonoItemGraph {
+            items: items.iter().map(|item| item.stable(&mut *tables)).collect(),
+            uses: uses.into_iter().collect(),
+            codegen_units,
+        }
+    }
+
     fn mir_body(&self, item: stable_mir::DefId) -> stable_mir::mir::Body {
         let mut tables = self.0.borrow_mut();
         let def_id = tables[item];
@@ -297,6 +336,40 @@ fn usize_to_const(&self, val: u64) -> Result<Const, Error> {
             .stable(&mut *tables))
     }
 
+    fn try_new_const_zst(&self, ty: Ty) -> Result<Const, Error> {
+        let mut tables = self.0.borrow_mut();
+        let tcx = tables.tcx;
+        let ty_internal = ty.internal(&mut *tables);
+        let layout = tcx
+            .layout_of(ParamEnv::reveal_all().and(ty_internal))
+            .map_err(|err| Error::new(format!("Cannot create zero-sized constant: {err:?}")))?;
+        if !layout.is_zst() {
+            return Err(Error::new(format!(
+                "Cannot create zero-sized constant for type `{ty_internal}`: type is not zero-sized"
+            )));
+        }
+        Ok(rustc_middle::mir::Const::Val(rustc_middle::mir::ConstValue::ZeroSized, ty_internal)
+            .stable(&mut *tables))
+    }
+
+    fn new_const_bool(&self, value: bool) -> Const {
+        let mut tables = self.0.borrow_mut();
+        rustc_middle::ty::Const::from_bool(tables.tcx, value).stable(&mut *tables)
+    }
+
+    fn try_new_const_uint(&self, value: u128, uint_ty: UintTy) -> Result<Const, Error> {
+        let mut tables = self.0.borrow_mut();
+        let tcx = tables.tcx;
+        let ty = rustc_middle::ty::Ty::new_uint(tcx, uint_ty.internal(&mut *tables));
+        let size = tcx.layout_of(ParamEnv::empty().and(ty)).unwrap().size;
+
+        let scalar = ScalarInt::try_from_uint(value, size).ok_or_else(|| {
+            Error::new(format!("Value overflow: cannot convert `{value}` to `{ty}`."))
+        })?;
+        Ok(rustc_middle::ty::Const::new_value(tcx, ValTree::from_scalar_int(scalar), ty)
+            .stable(&mut *tables))
+    }
+
     fn new_rigid_ty(&self, kind: RigidTy) -> stable_mir::ty::Ty {
         let mut tables = self.0.borrow_mut();
         let internal_kind = kind.internal(&mut *tables);
@@ -744,3 +744,20 @@ fn stable(&self, tables: &mut Tables<'tcx>) -> Self::T {
         }
     }
 }
+
+impl<'tcx> Stable<'tcx> for rustc_monomorphize::UsageKind {
+    type T = stable_mir::mir::mono::UseKind;
+
+    fn stable(&self, _: &mut Tables<'tcx>) -> Self::T {
+        use rustc_monomorphize::UsageKind;
+        use stable_mir::mir::mono::UseKind;
+        match self {
+            UsageKind::Call => UseKind::Call,
+            UsageKind::FnPtr => UseKind::FnPtr,
+            UsageKind::VtableMethod => UseKind::VtableMethod,
+            UsageKind::DropGlue => UseKind::DropGlue,
+            UsageKind::Static => UseKind::Static,
+            UsageKind::Other => UseKind::Other,
+        }
+    }
+}
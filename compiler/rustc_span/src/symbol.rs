@@ -812,6 +812,7 @@
         from_iter_fn,
         from_output,
         from_residual,
+        from_secs,
         from_size_align_unchecked,
         from_str_method,
         from_usize,
@@ -1647,12 +1648,14 @@
         test_case,
         test_removed_feature,
         test_runner,
+        test_timeout,
         test_unstable_lint,
         thread,
         thread_local,
         thread_local_macro,
         thumb2,
         thumb_mode: "thumb-mode",
+        time,
         tmm_reg,
         to_owned_method,
         to_string,
@@ -1787,6 +1790,7 @@
         vtable_align,
         vtable_size,
         warn,
+        warnings,
         wasm_abi,
         wasm_import_module,
         wasm_target_feature,
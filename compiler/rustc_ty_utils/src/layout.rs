@@ -9,6 +9,7 @@
 };
 use rustc_middle::ty::print::with_no_trimmed_paths;
 use rustc_middle::ty::{self, AdtDef, EarlyBinder, GenericArgsRef, Ty, TyCtxt, TypeVisitableExt};
+use rustc_session::code_stats::{DiscriminantInfo, InstantiationInfo, NicheInfo};
 use rustc_session::{DataTypeKind, FieldInfo, FieldKind, SizeKind, VariantInfo};
 use rustc_span::symbol::Symbol;
 use rustc_target::abi::*;
@@ -62,9 +63,9 @@ fn layout_of<'tcx>(
     let layout = layout_of_uncached(&cx, ty)?;
     let layout = TyAndLayout { ty, layout };
 
-    // If we are running with `-Zprint-type-sizes`, maybe record layouts
-    // for dumping later.
-    if cx.tcx.sess.opts.unstable_opts.print_type_sizes {
+    // If we are running with `-Zprint-type-sizes` or `--emit=type-layout`,
+    // maybe record layouts for dumping later.
+    if cx.tcx.sess.record_type_layouts() {
         record_layout_for_printing(&cx, layout);
     }
 
@@ -934,26 +935,54 @@ fn record_layout_for_printing<'tcx>(cx: &LayoutCx<'tcx, TyCtxt<'tcx>>, layout: T
     }
 
     // (delay format until we actually need it)
-    let record = |kind, packed, opt_discr_size, variants| {
+    let instantiation = |def_id: DefId, args: &[ty::GenericArg<'tcx>]| {
+        with_no_trimmed_paths!(InstantiationInfo {
+            def_path: cx.tcx.def_path_str(def_id),
+            args: args
+                .iter()
+                .filter(|arg| !matches!(arg.unpack(), ty::GenericArgKind::Lifetime(_)))
+                .map(|arg| arg.to_string())
+                .collect(),
+        })
+    };
+    let record = |kind, instantiation, packed, opt_discr_size, discriminant, variants| {
         let type_desc = with_no_trimmed_paths!(format!("{}", layout.ty));
+        let largest_niche = layout.largest_niche.map(|niche| NicheInfo {
+            offset: niche.offset.bytes(),
+            size: niche.value.size(cx).bytes(),
+            valid_range_start: niche.valid_range.start,
+            valid_range_end: niche.valid_range.end,
+            available: niche.available(cx),
+        });
         cx.tcx.sess.code_stats.record_type_size(
             kind,
             type_desc,
+            Some(instantiation),
             layout.align.abi,
             layout.size,
             packed,
             opt_discr_size,
+            discriminant,
+            largest_niche,
             variants,
         );
     };
 
     match *layout.ty.kind() {
-        ty::Adt(adt_def, _) => {
+        ty::Adt(adt_def, args) => {
             debug!("print-type-size t: `{:?}` process adt", layout.ty);
             let adt_kind = adt_def.adt_kind();
             let adt_packed = adt_def.repr().pack.is_some();
             let (variant_infos, opt_discr_size) = variant_info_for_adt(cx, layout, adt_def);
-            record(adt_kind.into(), adt_packed, opt_discr_size, variant_infos);
+            let discriminant = discriminant_info(cx, layout, |i| adt_def.variant(i).name);
+            record(
+                adt_kind.into(),
+                instantiation(adt_def.did(), args),
+                adt_packed,
+                opt_discr_size,
+                discriminant,
+                variant_infos,
+            );
         }
 
         ty::Coroutine(def_id, args) => {
@@ -961,12 +990,29 @@ fn record_layout_for_printing<'tcx>(cx: &LayoutCx<'tcx, TyCtxt<'tcx>>, layout: T
             // Coroutines always have a begin/poisoned/end state with additional suspend points
             let (variant_infos, opt_discr_size) =
                 variant_info_for_coroutine(cx, layout, def_id, args);
-            record(DataTypeKind::Coroutine, false, opt_discr_size, variant_infos);
+            let discriminant = discriminant_info(cx, layout, |i| {
+                Symbol::intern(&ty::CoroutineArgs::variant_name(i))
+            });
+            record(
+                DataTypeKind::Coroutine,
+                instantiation(def_id, args.as_coroutine().parent_args()),
+                false,
+                opt_discr_size,
+                discriminant,
+                variant_infos,
+            );
         }
 
-        ty::Closure(..) => {
+        ty::Closure(def_id, args) => {
             debug!("print-type-size t: `{:?}` record closure", layout.ty);
-            record(DataTypeKind::Closure, false, None, vec![]);
+            record(
+                DataTypeKind::Closure,
+                instantiation(def_id, args.as_closure().parent_args()),
+                false,
+                None,
+                None,
+                vec![],
+            );
         }
 
         _ => {
@@ -975,6 +1021,29 @@ fn record_layout_for_printing<'tcx>(cx: &LayoutCx<'tcx, TyCtxt<'tcx>>, layout: T
     };
 }
 
+/// Describes where the discriminant of a multi-variant layout is stored, using
+/// `variant_name` to name the untagged variant of niche-encoded layouts.
+fn discriminant_info<'tcx>(
+    cx: &LayoutCx<'tcx, TyCtxt<'tcx>>,
+    layout: TyAndLayout<'tcx>,
+    variant_name: impl FnOnce(VariantIdx) -> Symbol,
+) -> Option<DiscriminantInfo> {
+    let Variants::Multiple { tag, ref tag_encoding, tag_field, .. } = layout.variants else {
+        return None;
+    };
+    let offset = layout.fields.offset(tag_field).bytes();
+    let size = tag.size(cx).bytes();
+    Some(match *tag_encoding {
+        TagEncoding::Direct => DiscriminantInfo::Tag { offset, size },
+        TagEncoding::Niche { untagged_variant, niche_start, .. } => DiscriminantInfo::Niche {
+            offset,
+            size,
+            untagged_variant: variant_name(untagged_variant),
+            niche_start,
+        },
+    })
+}
+
 fn variant_info_for_adt<'tcx>(
     cx: &LayoutCx<'tcx, TyCtxt<'tcx>>,
     layout: TyAndLayout<'tcx>,
@@ -7,13 +7,13 @@
 
 use crate::abi::{FnAbi, Layout, LayoutShape};
 use crate::mir::alloc::{AllocId, GlobalAlloc};
-use crate::mir::mono::{Instance, InstanceDef, StaticDef};
+use crate::mir::mono::{Instance, InstanceDef, MonoItemGraph, StaticDef};
 use crate::mir::Body;
 use crate::target::MachineInfo;
 use crate::ty::{
     AdtDef, AdtKind, Allocation, ClosureDef, ClosureKind, Const, FieldDef, FnDef, GenericArgs,
     GenericPredicates, Generics, ImplDef, ImplTrait, LineInfo, PolyFnSig, RigidTy, Span, TraitDecl,
-    TraitDef, Ty, TyKind, VariantDef,
+    TraitDef, Ty, TyKind, UintTy, VariantDef,
 };
 use crate::{
     mir, Crate, CrateItem, CrateItems, CrateNum, DefId, Error, Filename, ImplTraitDecls, ItemKind,
@@ -26,6 +26,8 @@ pub trait Context {
     fn entry_fn(&self) -> Option<CrateItem>;
     /// Retrieve all items of the local crate that have a MIR associated with them.
     fn all_local_items(&self) -> CrateItems;
+    /// Collect the mono items of the local crate, their uses and their codegen units.
+    fn mono_item_graph(&self) -> MonoItemGraph;
     /// Retrieve the body of a function.
     /// This function will panic if the body is not available.
     fn mir_body(&self, item: DefId) -> mir::Body;
@@ -97,6 +99,15 @@ pub trait Context {
     /// Create a target usize constant for the given value.
     fn usize_to_const(&self, val: u64) -> Result<Const, Error>;
 
+    /// Create a zero-sized constant of the given type.
+    fn try_new_const_zst(&self, ty: Ty) -> Result<Const, Error>;
+
+    /// Create a boolean constant.
+    fn new_const_bool(&self, value: bool) -> Const;
+
+    /// Create an unsigned integer constant of the given type.
+    fn try_new_const_uint(&self, value: u128, uint_ty: UintTy) -> Result<Const, Error>;
+
     /// Create a new type from the given kind.
     fn new_rigid_ty(&self, kind: RigidTy) -> Ty;
 
@@ -206,6 +217,20 @@ pub fn run<F, T>(context: &dyn Context, f: F) -> Result<T, Error>
     }
 }
 
+/// Same as [run], but it can be called while a StableMIR context is already active, e.g., by a
+/// callback that the compiler invokes while the user code is running.
+///
+/// The outer context is restored once `f` returns. Values that refer to the compiler tables, such
+/// as [Ty] or [DefId], must not be exchanged between the two contexts.
+#[doc(hidden)]
+pub fn run_nested<F, T>(context: &dyn Context, f: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    let ptr: *const () = &context as *const &_ as _;
+    TLV.set(&Cell::new(ptr), f)
+}
+
 /// Execute the given function with access the compiler [Context].
 ///
 /// I.e., This function will load the current context and calls a function with it.
@@ -0,0 +1,312 @@
+//! A self-contained serialization format for StableMIR values.
+//!
+//! Most StableMIR values refer to their contents through handles, such as [Ty] or [DefId], that
+//! are indices into tables owned by the compiler. These handles can be serialized on their own,
+//! but they are meaningless once the compiler is gone.
+//!
+//! An [Export] wraps a value, e.g. a list of bodies, and serializes it together with a table that
+//! resolves every handle the value transitively refers to. Once deserialized, the table can be
+//! queried without a compiler attached:
+//!
+//! ```ignore (requires a compiler and a serde format)
+//! let bodies: Vec<Body> = stable_mir::all_local_items().iter().map(|item| item.body()).collect();
+//! let json = serde_json::to_string(&Export::new(bodies)).unwrap();
+//!
+//! // Later, possibly in a different process.
+//! let export: Export<Vec<Body>> = serde_json::from_str(&json).unwrap();
+//! let ret_ty = export.value[0].ret_local().ty;
+//! println!("{:?}", export.ty_kind(ret_ty));
+//! ```
+//!
+//! Handles are resolved while the value is being serialized, so serializing a newly created
+//! [Export] must happen while the compiler is running. Handles that were already resolved when
+//! the [Export] was deserialized are reused, so a deserialized [Export] can be serialized again
+//! without a compiler. The table is written as a sequence of unknown length, so the serde format
+//! must support that, which is the case of self-describing formats such as JSON.
+
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use scoped_tls::scoped_thread_local;
+use serde::ser::{SerializeSeq, SerializeStruct};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::abi::{Layout, LayoutShape};
+use crate::mir::alloc::{AllocId, GlobalAlloc};
+use crate::mir::mono::InstanceDef;
+use crate::ty::{GenericArgs, IndexedVal, LineInfo, Span, Ty, TyKind};
+use crate::{with, Crate, DefId, Filename, Symbol};
+
+/// A value bundled with the information needed to resolve the handles it contains.
+#[derive(Clone, Debug)]
+pub struct Export<T> {
+    /// The exported value.
+    pub value: T,
+    tables: ExportTables,
+}
+
+/// Information about a definition, as stored in an [Export].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DefInfo {
+    /// The fully qualified name of the definition.
+    pub name: Symbol,
+    /// The trimmed name of the definition.
+    pub trimmed_name: Symbol,
+    /// The crate where the definition is declared.
+    pub krate: Crate,
+}
+
+/// Information about a span, as stored in an [Export].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SpanInfo {
+    pub filename: Filename,
+    pub lines: LineInfo,
+}
+
+/// Information about an instance, as stored in an [Export].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InstanceInfo {
+    /// The specialized name of the instance, e.g., `std::vec::Vec<u8>::new`.
+    pub name: Symbol,
+    /// The mangled name used for calling the instance.
+    pub mangled_name: Symbol,
+    /// The definition this instance was instantiated from.
+    pub def_id: DefId,
+    /// The arguments this instance was instantiated with.
+    pub args: GenericArgs,
+}
+
+impl<T> Export<T> {
+    /// Wrap `value` so that it gets serialized along with the resolution of its handles.
+    pub fn new(value: T) -> Export<T> {
+        Export { value, tables: ExportTables::default() }
+    }
+
+    /// Retrieve the kind of an exported type.
+    pub fn ty_kind(&self, ty: Ty) -> Option<&TyKind> {
+        self.tables.tys.get(&ty)
+    }
+
+    /// Retrieve information about an exported definition.
+    pub fn def_info(&self, def_id: DefId) -> Option<&DefInfo> {
+        self.tables.def_ids.get(&def_id)
+    }
+
+    /// Retrieve an exported global allocation.
+    pub fn global_alloc(&self, alloc_id: AllocId) -> Option<&GlobalAlloc> {
+        self.tables.alloc_ids.get(&alloc_id)
+    }
+
+    /// Retrieve the location of an exported span.
+    pub fn span_info(&self, span: Span) -> Option<&SpanInfo> {
+        self.tables.spans.get(&span)
+    }
+
+    /// Retrieve information about an exported instance.
+    pub fn instance_info(&self, def: InstanceDef) -> Option<&InstanceInfo> {
+        self.tables.instances.get(&def)
+    }
+
+    /// Retrieve the shape of an exported layout.
+    pub fn layout_shape(&self, layout: Layout) -> Option<&LayoutShape> {
+        self.tables.layouts.get(&layout)
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+struct ExportTables {
+    tys: HashMap<Ty, TyKind>,
+    def_ids: HashMap<DefId, DefInfo>,
+    alloc_ids: HashMap<AllocId, GlobalAlloc>,
+    spans: HashMap<Span, SpanInfo>,
+    instances: HashMap<InstanceDef, InstanceInfo>,
+    layouts: HashMap<Layout, LayoutShape>,
+}
+
+/// A handle that needs to be resolved in the table of an [Export].
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum Handle {
+    Ty(Ty),
+    DefId(DefId),
+    AllocId(AllocId),
+    Span(Span),
+    Instance(InstanceDef),
+    Layout(Layout),
+}
+
+/// One entry of the table of an [Export].
+#[derive(Serialize, Deserialize)]
+enum TableEntry {
+    Ty(Ty, TyKind),
+    DefId(DefId, DefInfo),
+    AllocId(AllocId, GlobalAlloc),
+    Span(Span, SpanInfo),
+    Instance(InstanceDef, InstanceInfo),
+    Layout(Layout, LayoutShape),
+}
+
+impl ExportTables {
+    /// Resolve a handle, reusing the tables of a deserialized [Export] when possible.
+    fn resolve(&self, handle: Handle) -> TableEntry {
+        match handle {
+            Handle::Ty(ty) => {
+                let kind = self.tys.get(&ty).cloned().unwrap_or_else(|| ty.kind());
+                TableEntry::Ty(ty, kind)
+            }
+            Handle::DefId(def_id) => {
+                let info = self.def_ids.get(&def_id).cloned().unwrap_or_else(|| {
+                    with(|cx| DefInfo {
+                        name: cx.def_name(def_id, false),
+                        trimmed_name: cx.def_name(def_id, true),
+                        krate: cx.krate(def_id),
+                    })
+                });
+                TableEntry::DefId(def_id, info)
+            }
+            Handle::AllocId(alloc_id) => {
+                let alloc = self.alloc_ids.get(&alloc_id).cloned();
+                TableEntry::AllocId(alloc_id, alloc.unwrap_or_else(|| GlobalAlloc::from(alloc_id)))
+            }
+            Handle::Span(span) => {
+                let info = self.spans.get(&span).cloned().unwrap_or_else(|| SpanInfo {
+                    filename: span.get_filename(),
+                    lines: span.get_lines(),
+                });
+                TableEntry::Span(span, info)
+            }
+            Handle::Instance(def) => {
+                let info = self.instances.get(&def).cloned().unwrap_or_else(|| {
+                    with(|cx| InstanceInfo {
+                        name: cx.instance_name(def, false),
+                        mangled_name: cx.instance_mangled_name(def),
+                        def_id: cx.instance_def_id(def),
+                        args: cx.instance_args(def),
+                    })
+                });
+                TableEntry::Instance(def, info)
+            }
+            Handle::Layout(layout) => {
+                let shape = self.layouts.get(&layout).cloned().unwrap_or_else(|| layout.shape());
+                TableEntry::Layout(layout, shape)
+            }
+        }
+    }
+}
+
+impl FromIterator<TableEntry> for ExportTables {
+    fn from_iter<I: IntoIterator<Item = TableEntry>>(entries: I) -> Self {
+        let mut tables = ExportTables::default();
+        for entry in entries {
+            match entry {
+                TableEntry::Ty(ty, kind) => {
+                    tables.tys.insert(ty, kind);
+                }
+                TableEntry::DefId(def_id, info) => {
+                    tables.def_ids.insert(def_id, info);
+                }
+                TableEntry::AllocId(alloc_id, alloc) => {
+                    tables.alloc_ids.insert(alloc_id, alloc);
+                }
+                TableEntry::Span(span, info) => {
+                    tables.spans.insert(span, info);
+                }
+                TableEntry::Instance(def, info) => {
+                    tables.instances.insert(def, info);
+                }
+                TableEntry::Layout(layout, shape) => {
+                    tables.layouts.insert(layout, shape);
+                }
+            }
+        }
+        tables
+    }
+}
+
+/// Keeps track of the handles serialized so far, and of the ones that still need to be resolved.
+#[derive(Default)]
+struct HandleRecorder {
+    seen: HashSet<Handle>,
+    pending: VecDeque<Handle>,
+}
+
+scoped_thread_local! (static RECORDER: RefCell<HandleRecorder>);
+
+fn record(handle: Handle) {
+    if RECORDER.is_set() {
+        RECORDER.with(|recorder| {
+            let mut recorder = recorder.borrow_mut();
+            if recorder.seen.insert(handle) {
+                recorder.pending.push_back(handle);
+            }
+        })
+    }
+}
+
+macro_rules! serialize_handle {
+    ($($name:ident => $handle:ident),* $(,)?) => {
+        $(
+            impl Serialize for $name {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    record(Handle::$handle(*self));
+                    serializer.serialize_newtype_struct(stringify!($name), &self.to_index())
+                }
+            }
+        )*
+    };
+}
+
+serialize_handle! {
+    Ty => Ty,
+    DefId => DefId,
+    AllocId => AllocId,
+    Span => Span,
+    InstanceDef => Instance,
+    Layout => Layout,
+}
+
+impl<T: Serialize> Serialize for Export<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let recorder = RefCell::new(HandleRecorder::default());
+        RECORDER.set(&recorder, || {
+            let mut export = serializer.serialize_struct("Export", 2)?;
+            export.serialize_field("value", &self.value)?;
+            export.serialize_field(
+                "tables",
+                &PendingEntries { tables: &self.tables, recorder: &recorder },
+            )?;
+            export.end()
+        })
+    }
+}
+
+/// Serializes the resolution of every recorded handle. Resolving an entry may record new
+/// handles, so this keeps going until no handle is left.
+struct PendingEntries<'a> {
+    tables: &'a ExportTables,
+    recorder: &'a RefCell<HandleRecorder>,
+}
+
+impl Serialize for PendingEntries<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(None)?;
+        loop {
+            let Some(handle) = self.recorder.borrow_mut().pending.pop_front() else { break };
+            seq.serialize_element(&self.tables.resolve(handle))?;
+        }
+        seq.end()
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Export<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(rename = "Export")]
+        struct ExportRepr<T> {
+            value: T,
+            tables: Vec<TableEntry>,
+        }
+
+        let ExportRepr { value, tables } = ExportRepr::deserialize(deserializer)?;
+        Ok(Export { value, tables: tables.into_iter().collect() })
+    }
+}
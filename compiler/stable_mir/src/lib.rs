@@ -28,6 +28,7 @@
 pub use crate::crate_def::CrateDef;
 pub use crate::crate_def::DefId;
 pub use crate::error::*;
+use crate::mir::mono::MonoItemGraph;
 use crate::mir::pretty::function_name;
 use crate::mir::Body;
 use crate::mir::Mutability;
@@ -39,6 +40,8 @@
 pub mod compiler_interface;
 #[macro_use]
 pub mod error;
+#[cfg(feature = "serde")]
+pub mod export;
 pub mod mir;
 pub mod target;
 pub mod ty;
@@ -80,6 +83,7 @@ fn to_index(&self) -> usize {
 
 /// Holds information about a crate.
 #[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Crate {
     pub id: CrateNum,
     pub name: Symbol,
@@ -99,6 +103,7 @@ pub fn trait_impls(&self) -> ImplTraitDecls {
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ItemKind {
     Fn,
     Static,
@@ -107,6 +112,7 @@ pub enum ItemKind {
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CtorKind {
     Const,
     Fn,
@@ -177,6 +183,14 @@ pub fn all_local_items() -> CrateItems {
     with(|cx| cx.all_local_items())
 }
 
+/// Retrieve the mono items that the compiler collected for the local crate, how they use each
+/// other, and the codegen units they were partitioned into.
+///
+/// This runs the mono item collector, which is expensive, so the result should be reused.
+pub fn mono_item_graph() -> MonoItemGraph {
+    with(|cx| cx.mono_item_graph())
+}
+
 pub fn all_trait_decls() -> TraitDecls {
     with(|cx| cx.all_trait_decls())
 }
@@ -187,6 +201,7 @@ pub fn all_trait_impls() -> ImplTraitDecls {
 
 /// A type that provides internal information but that can still be used for debug purpose.
 #[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Opaque(String);
 
 impl std::fmt::Display for Opaque {
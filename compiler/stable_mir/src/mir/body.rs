@@ -7,6 +7,7 @@
 use std::io;
 /// The SMIR representation of a single function.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Body {
     pub blocks: Vec<BasicBlock>,
 
@@ -72,6 +73,21 @@ pub fn inner_locals(&self) -> &[LocalDecl] {
         &self.locals[self.arg_count + 1..]
     }
 
+    /// Mutable version of [Body::ret_local], used by the [MutMirVisitor](super::MutMirVisitor).
+    pub(super) fn ret_local_mut(&mut self) -> &mut LocalDecl {
+        &mut self.locals[RETURN_LOCAL]
+    }
+
+    /// Mutable version of [Body::arg_locals], used by the [MutMirVisitor](super::MutMirVisitor).
+    pub(super) fn arg_locals_mut(&mut self) -> &mut [LocalDecl] {
+        &mut self.locals[1..][..self.arg_count]
+    }
+
+    /// Mutable version of [Body::inner_locals], used by the [MutMirVisitor](super::MutMirVisitor).
+    pub(super) fn inner_locals_mut(&mut self) -> &mut [LocalDecl] {
+        &mut self.locals[self.arg_count + 1..]
+    }
+
     /// Convenience function to get all the locals in this function.
     ///
     /// Locals are typically accessed via the more specific methods `ret_local`,
@@ -90,6 +106,47 @@ pub fn local_decls(&self) -> impl Iterator<Item = (Local, &LocalDecl)> {
         self.locals.iter().enumerate()
     }
 
+    /// Declare a new local, and return its index.
+    ///
+    /// The new local is added after all the existing ones, so the existing locals keep their
+    /// index.
+    pub fn new_local(&mut self, ty: Ty, span: Span, mutability: Mutability) -> Local {
+        self.locals.push(LocalDecl { ty, span, mutability });
+        self.locals.len() - 1
+    }
+
+    /// Add a new basic block to the end of this body, and return its index.
+    pub fn push_block(&mut self, block: BasicBlock) -> BasicBlockIdx {
+        self.blocks.push(block);
+        self.blocks.len() - 1
+    }
+
+    /// Insert a statement in the basic block `bb`, before the statement at `index`.
+    ///
+    /// If `index` is the number of statements in the block, the statement is inserted right
+    /// before the terminator.
+    pub fn insert_statement(&mut self, bb: BasicBlockIdx, index: usize, statement: Statement) {
+        self.blocks[bb].statements.insert(index, statement);
+    }
+
+    /// Split the basic block `bb` before the statement at `index`, and return the index of the
+    /// new basic block.
+    ///
+    /// The statements starting at `index`, and the terminator, are moved to the new block, and
+    /// `bb` is terminated with a `Goto` to the new block. To insert a call in the middle of a
+    /// block, replace this `Goto` by a `Call` that returns to the new block.
+    pub fn split_block(&mut self, bb: BasicBlockIdx, index: usize) -> BasicBlockIdx {
+        let new_bb = self.blocks.len();
+        let block = &mut self.blocks[bb];
+        let span = block.terminator.span;
+        let terminator = std::mem::replace(
+            &mut block.terminator,
+            Terminator { kind: TerminatorKind::Goto { target: new_bb }, span },
+        );
+        let statements = block.statements.split_off(index);
+        self.push_block(BasicBlock { statements, terminator })
+    }
+
     pub fn dump<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
         writeln!(w, "{}", function_body(self))?;
         self.blocks
@@ -122,6 +179,7 @@ pub fn spread_arg(&self) -> Option<Local> {
 type LocalDecls = Vec<LocalDecl>;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LocalDecl {
     pub ty: Ty,
     pub span: Span,
@@ -129,12 +187,14 @@ pub struct LocalDecl {
 }
 
 #[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BasicBlock {
     pub statements: Vec<Statement>,
     pub terminator: Terminator,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Terminator {
     pub kind: TerminatorKind,
     pub span: Span,
@@ -149,6 +209,7 @@ pub fn successors(&self) -> Successors {
 pub type Successors = Vec<BasicBlockIdx>;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TerminatorKind {
     Goto {
         target: BasicBlockIdx,
@@ -239,6 +300,7 @@ pub fn unwind(&self) -> Option<&UnwindAction> {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InlineAsmOperand {
     pub in_value: Option<Operand>,
     pub out_place: Option<Place>,
@@ -248,6 +310,7 @@ pub struct InlineAsmOperand {
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum UnwindAction {
     Continue,
     Unreachable,
@@ -256,6 +319,7 @@ pub enum UnwindAction {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AssertMessage {
     BoundsCheck { len: Operand, index: Operand },
     Overflow(BinOp, Operand, Operand),
@@ -325,6 +389,7 @@ pub fn description(&self) -> Result<&'static str, Error> {
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BinOp {
     Add,
     AddUnchecked,
@@ -391,18 +456,21 @@ pub fn ty(&self, lhs_ty: Ty, rhs_ty: Ty) -> Ty {
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum UnOp {
     Not,
     Neg,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CoroutineKind {
     Desugared(CoroutineDesugaring, CoroutineSource),
     Coroutine(Movability),
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CoroutineSource {
     Block,
     Closure,
@@ -410,6 +478,7 @@ pub enum CoroutineSource {
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CoroutineDesugaring {
     Async,
 
@@ -426,6 +495,7 @@ pub enum CoroutineDesugaring {
 
 /// The FakeReadCause describes the type of pattern why a FakeRead statement exists.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FakeReadCause {
     ForMatchGuard,
     ForMatchedPlace(LocalDefId),
@@ -436,6 +506,7 @@ pub enum FakeReadCause {
 
 /// Describes what kind of retag is to be performed
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RetagKind {
     FnEntry,
     TwoPhase,
@@ -444,6 +515,7 @@ pub enum RetagKind {
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Variance {
     Covariant,
     Invariant,
@@ -452,6 +524,7 @@ pub enum Variance {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CopyNonOverlapping {
     pub src: Operand,
     pub dst: Operand,
@@ -459,18 +532,21 @@ pub struct CopyNonOverlapping {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NonDivergingIntrinsic {
     Assume(Operand),
     CopyNonOverlapping(CopyNonOverlapping),
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Statement {
     pub kind: StatementKind,
     pub span: Span,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum StatementKind {
     Assign(Place, Rvalue),
     FakeRead(FakeReadCause, Place),
@@ -488,6 +564,7 @@ pub enum StatementKind {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Rvalue {
     /// Creates a pointer with the indicated mutability to the place.
     ///
@@ -657,6 +734,7 @@ pub fn ty(&self, locals: &[LocalDecl]) -> Result<Ty, Error> {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AggregateKind {
     Array(Ty),
     Tuple,
@@ -667,6 +745,7 @@ pub enum AggregateKind {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Operand {
     Copy(Place),
     Move(Place),
@@ -674,6 +753,7 @@ pub enum Operand {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Place {
     pub local: Local,
     /// projection out of a place (access a field, deref a pointer, etc)
@@ -688,6 +768,7 @@ fn from(local: Local) -> Self {
 
 /// Debug information pertaining to a user variable.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VarDebugInfo {
     /// The variable name.
     pub name: Symbol,
@@ -730,24 +811,28 @@ pub fn constant(&self) -> Option<&ConstOperand> {
 pub type SourceScope = u32;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SourceInfo {
     pub span: Span,
     pub scope: SourceScope,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VarDebugInfoFragment {
     pub ty: Ty,
     pub projection: Vec<ProjectionElem>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum VarDebugInfoContents {
     Place(Place),
     Const(ConstOperand),
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConstOperand {
     pub span: Span,
     pub user_ty: Option<UserTypeAnnotationIndex>,
@@ -760,6 +845,7 @@ pub struct ConstOperand {
 // are of type ProjectionElem<(), ()>). In SMIR we don't need this generality, so we just use
 // ProjectionElem for Places.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ProjectionElem {
     /// Dereference projections (e.g. `*_1`) project to the address referenced by the base place.
     Deref,
@@ -834,6 +920,7 @@ pub enum ProjectionElem {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UserTypeProjection {
     pub base: UserTypeAnnotationIndex,
 
@@ -863,6 +950,7 @@ pub struct UserTypeProjection {
 type UserTypeAnnotationIndex = usize;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Constant {
     pub span: Span,
     pub user_ty: Option<UserTypeAnnotationIndex>,
@@ -871,6 +959,7 @@ pub struct Constant {
 
 /// The possible branch sites of a [TerminatorKind::SwitchInt].
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SwitchTargets {
     /// The conditional branches where the first element represents the value that guards this
     /// branch, and the second element is the branch target.
@@ -908,6 +997,7 @@ pub fn new(branches: Vec<(u128, BasicBlockIdx)>, otherwise: BasicBlockIdx) -> Sw
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BorrowKind {
     /// Data must be immutable and is aliasable.
     Shared,
@@ -937,6 +1027,7 @@ pub fn to_mutable_lossy(self) -> Mutability {
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MutBorrowKind {
     Default,
     TwoPhaseBorrow,
@@ -944,18 +1035,21 @@ pub enum MutBorrowKind {
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Mutability {
     Not,
     Mut,
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Safety {
     Unsafe,
     Normal,
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PointerCoercion {
     /// Go from a fn-item type to a fn-pointer type.
     ReifyFnPointer,
@@ -983,6 +1077,7 @@ pub enum PointerCoercion {
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CastKind {
     PointerExposeAddress,
     PointerFromExposedAddress,
@@ -998,6 +1093,7 @@ pub enum CastKind {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NullOp {
     /// Returns the size of a value of that type.
     SizeOf,
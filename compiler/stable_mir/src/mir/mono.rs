@@ -6,13 +6,90 @@
 use std::fmt::{Debug, Formatter};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MonoItem {
     Fn(Instance),
     Static(StaticDef),
     GlobalAsm(Opaque),
 }
 
+/// The mono items reached by the compiler's mono item collector for the local crate, how they
+/// use each other, and the codegen units they were partitioned into.
+///
+/// Items are referred to by their index in [MonoItemGraph::items].
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MonoItemGraph {
+    /// Every collected mono item, in a deterministic order.
+    pub items: Vec<MonoItem>,
+    /// The uses between mono items. Each `(user, used, kind)` triple is only listed once.
+    pub uses: Vec<MonoItemUse>,
+    /// The codegen units that the mono items were partitioned into.
+    pub codegen_units: Vec<CodegenUnit>,
+}
+
+impl MonoItemGraph {
+    /// Retrieve the uses of other mono items by the item at index `item`.
+    pub fn used_by(&self, item: usize) -> impl Iterator<Item = &MonoItemUse> + '_ {
+        self.uses.iter().filter(move |usage| usage.user == item)
+    }
+
+    /// Retrieve the uses of the item at index `item` by other mono items.
+    pub fn users_of(&self, item: usize) -> impl Iterator<Item = &MonoItemUse> + '_ {
+        self.uses.iter().filter(move |usage| usage.used == item)
+    }
+
+    /// Retrieve the codegen units that contain the item at index `item`.
+    ///
+    /// An item may be part of several codegen units, e.g. if it is inlined in each of them.
+    pub fn codegen_units_of(&self, item: usize) -> impl Iterator<Item = &CodegenUnit> + '_ {
+        self.codegen_units.iter().filter(move |cgu| cgu.items.contains(&item))
+    }
+}
+
+/// An edge of the [MonoItemGraph].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MonoItemUse {
+    /// The index of the item that uses `used`.
+    pub user: usize,
+    /// The index of the used item.
+    pub used: usize,
+    /// How `used` is used by `user`.
+    pub kind: UseKind,
+}
+
+/// The way in which a mono item uses another one.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UseKind {
+    /// The used item is called directly.
+    Call,
+    /// The used item is reified into a function pointer, or its address is otherwise taken.
+    FnPtr,
+    /// The used item is a method of a vtable.
+    VtableMethod,
+    /// The used item is the drop glue of some type, either called by a `Drop` terminator or
+    /// stored in a vtable.
+    DropGlue,
+    /// The used item is a static.
+    Static,
+    /// Any other use, e.g. a thread-local shim.
+    Other,
+}
+
+/// A codegen unit, i.e. a set of mono items that are compiled together.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CodegenUnit {
+    /// The name of this codegen unit.
+    pub name: Symbol,
+    /// The indices of the mono items in this codegen unit.
+    pub items: Vec<usize>,
+}
+
 #[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Instance {
     /// The type of instance.
     pub kind: InstanceKind,
@@ -22,6 +99,7 @@ pub struct Instance {
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum InstanceKind {
     /// A user defined item.
     Item,
@@ -210,6 +288,7 @@ fn from(value: StaticDef) -> Self {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 pub struct InstanceDef(usize);
 
 impl CrateDef for InstanceDef {
@@ -460,6 +460,30 @@ pub const fn new() -> BinaryHeap<T> {
     pub fn with_capacity(capacity: usize) -> BinaryHeap<T> {
         BinaryHeap { data: Vec::with_capacity(capacity) }
     }
+
+    /// Creates an empty `BinaryHeap` with at least the specified capacity,
+    /// returning an error instead of aborting if the allocation fails.
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(fallible_alloc)]
+    /// use std::collections::BinaryHeap;
+    /// let mut heap = BinaryHeap::try_with_capacity(10)?;
+    /// heap.push(4);
+    /// # Ok::<(), std::collections::TryReserveError>(())
+    /// ```
+    #[unstable(feature = "fallible_alloc", issue = "none")]
+    pub fn try_with_capacity(capacity: usize) -> Result<BinaryHeap<T>, TryReserveError> {
+        Ok(BinaryHeap { data: Vec::try_with_capacity(capacity)? })
+    }
 }
 
 impl<T: Ord, A: Allocator> BinaryHeap<T, A> {
@@ -615,6 +639,40 @@ pub fn push(&mut self, item: T) {
         unsafe { self.sift_up(0, old_len) };
     }
 
+    /// Pushes an item onto the binary heap, returning an error instead of
+    /// aborting if the heap has to grow and the allocation fails.
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned and the heap is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(fallible_alloc)]
+    /// use std::collections::BinaryHeap;
+    /// let mut heap = BinaryHeap::new();
+    /// heap.try_push(3)?;
+    /// heap.try_push(5)?;
+    /// heap.try_push(1)?;
+    ///
+    /// assert_eq!(heap.len(), 3);
+    /// assert_eq!(heap.peek(), Some(&5));
+    /// # Ok::<(), std::collections::TryReserveError>(())
+    /// ```
+    #[unstable(feature = "fallible_alloc", issue = "none")]
+    pub fn try_push(&mut self, item: T) -> Result<(), TryReserveError> {
+        let old_len = self.len();
+        self.data.try_push(item)?;
+        // SAFETY: Since we pushed a new item it means that
+        //  old_len = self.len() - 1 < self.len()
+        unsafe { self.sift_up(0, old_len) };
+        Ok(())
+    }
+
     /// Consumes the `BinaryHeap` and returns a vector in sorted
     /// (ascending) order.
     ///
@@ -86,6 +86,9 @@ pub fn bulk_push<I, A: Allocator + Clone>(&mut self, iter: I, length: &mut usize
             // the appended elements even if advancing the iterator panicks.
             *length += 1;
         }
+        // The pairs were pushed without updating the subtree lengths of the
+        // ancestors, so calculate them all at once.
+        self.borrow_mut().recalc_all_subtree_lens();
         self.fix_right_border_of_plentiful();
     }
 }
@@ -10,6 +10,7 @@
 use core::ptr;
 
 use crate::alloc::{Allocator, Global};
+use crate::collections::TryReserveError;
 
 use super::borrow::DormantMutRef;
 use super::dedup_sorted_iter::DedupSortedIter;
@@ -898,6 +899,40 @@ pub fn pop_last(&mut self) -> Option<(K, V)>
         self.last_entry().map(|entry| entry.remove_entry())
     }
 
+    /// Returns the number of elements in the map whose key is less than the
+    /// given key.
+    ///
+    /// This is the index the key has, or would have if inserted, in the
+    /// sorted sequence of keys. It is computed in logarithmic time.
+    ///
+    /// The key may be any borrowed form of the map's key type, but the ordering
+    /// on the borrowed form *must* match the ordering on the key type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(btree_order_statistics)]
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map = BTreeMap::from([(1, "a"), (3, "c"), (5, "e")]);
+    /// assert_eq!(map.rank(&0), 0);
+    /// assert_eq!(map.rank(&3), 1);
+    /// assert_eq!(map.rank(&4), 2);
+    /// assert_eq!(map.rank(&9), 3);
+    /// ```
+    #[unstable(feature = "btree_order_statistics", issue = "none")]
+    pub fn rank<Q: ?Sized>(&self, key: &Q) -> usize
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord,
+    {
+        let Some(root) = &self.root else { return 0 };
+        match root.reborrow().search_tree(key) {
+            Found(handle) => handle.rank(),
+            GoDown(handle) => handle.forget_node_type().rank(),
+        }
+    }
+
     /// Returns `true` if the map contains a value for the specified key.
     ///
     /// The key may be any borrowed form of the map's key type, but the ordering
@@ -1023,6 +1058,46 @@ pub fn try_insert(&mut self, key: K, value: V) -> Result<&mut V, OccupiedError<'
         }
     }
 
+    /// Inserts a key-value pair into the map, returning an error instead of
+    /// aborting if the tree has to grow and the allocation of its nodes fails.
+    ///
+    /// This is the fallible equivalent of [`insert`], and returns the same
+    /// value on success. It's not named `try_insert`, because [`try_insert`]
+    /// refuses to replace an existing value rather than handling allocation
+    /// failures.
+    ///
+    /// All nodes that the insertion may need are allocated before the map is
+    /// changed, so the map is left unchanged if this returns an error.
+    ///
+    /// [`insert`]: BTreeMap::insert
+    /// [`try_insert`]: BTreeMap::try_insert
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fallible_alloc)]
+    /// use std::collections::BTreeMap;
+    ///
+    /// let mut map = BTreeMap::new();
+    /// assert_eq!(map.try_insert_alloc(37, "a")?, None);
+    /// assert_eq!(map.try_insert_alloc(37, "b")?, Some("a"));
+    /// assert_eq!(map[&37], "b");
+    /// # Ok::<(), std::collections::TryReserveError>(())
+    /// ```
+    #[unstable(feature = "fallible_alloc", issue = "none")]
+    pub fn try_insert_alloc(&mut self, key: K, value: V) -> Result<Option<V>, TryReserveError>
+    where
+        K: Ord,
+    {
+        match self.entry(key) {
+            Occupied(mut entry) => Ok(Some(entry.insert(value))),
+            Vacant(entry) => {
+                entry.try_insert(value)?;
+                Ok(None)
+            }
+        }
+    }
+
     /// Removes a key from the map, returning the value at the key if the key
     /// was previously in the map.
     ///
@@ -1343,7 +1418,9 @@ pub fn split_off<Q: ?Sized + Ord>(&mut self, key: &Q) -> Self
 
         let right_root = left_root.split_off(key, (*self.alloc).clone());
 
-        let (new_left_len, right_len) = Root::calc_split_length(total_num, &left_root, &right_root);
+        let (new_left_len, right_len) = (left_root.subtree_len(), right_root.subtree_len());
+        debug_assert_eq!(new_left_len + right_len, total_num);
+        debug_assert_eq!(right_len, right_root.reborrow().calc_length());
         self.length = new_left_len;
 
         BTreeMap {
@@ -1997,6 +2074,30 @@ fn max(mut self) -> Option<(&'a K, &'a V)>
     }
 }
 
+impl<K, V> Range<'_, K, V> {
+    /// Returns the number of elements remaining in the range.
+    ///
+    /// Unlike `count`, this doesn't visit the elements, and takes logarithmic
+    /// time in the size of the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(btree_order_statistics)]
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map: BTreeMap<i32, i32> = (0..100).map(|i| (i, i)).collect();
+    /// let mut range = map.range(10..50);
+    /// assert_eq!(range.len(), 40);
+    /// range.next();
+    /// assert_eq!(range.len(), 39);
+    /// ```
+    #[unstable(feature = "btree_order_statistics", issue = "none")]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
 #[stable(feature = "default_iters", since = "1.70.0")]
 impl<K, V> Default for Range<'_, K, V> {
     /// Creates an empty `btree_map::Range`.
@@ -2215,6 +2316,27 @@ fn next_back(&mut self) -> Option<(&'a K, &'a mut V)> {
 #[stable(feature = "fused", since = "1.26.0")]
 impl<K, V> FusedIterator for RangeMut<'_, K, V> {}
 
+impl<K, V> RangeMut<'_, K, V> {
+    /// Returns the number of elements remaining in the range.
+    ///
+    /// Unlike `count`, this doesn't visit the elements, and takes logarithmic
+    /// time in the size of the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(btree_order_statistics)]
+    /// use std::collections::BTreeMap;
+    ///
+    /// let mut map: BTreeMap<i32, i32> = (0..100).map(|i| (i, i)).collect();
+    /// assert_eq!(map.range_mut(90..).len(), 10);
+    /// ```
+    #[unstable(feature = "btree_order_statistics", issue = "none")]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<K: Ord, V> FromIterator<(K, V)> for BTreeMap<K, V> {
     fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> BTreeMap<K, V> {
@@ -2521,6 +2643,64 @@ pub const fn is_empty(&self) -> bool {
         self.len() == 0
     }
 
+    /// Returns the key-value pair preceded by `n` other pairs in the map,
+    /// that is the pair with the `n`-th smallest key, counting from zero.
+    ///
+    /// Returns `None` if the map has no more than `n` elements. The pair is
+    /// found in logarithmic time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(btree_order_statistics)]
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map = BTreeMap::from([(1, "a"), (3, "c"), (5, "e")]);
+    /// assert_eq!(map.get_nth(0), Some((&1, &"a")));
+    /// assert_eq!(map.get_nth(2), Some((&5, &"e")));
+    /// assert_eq!(map.get_nth(3), None);
+    /// ```
+    #[unstable(feature = "btree_order_statistics", issue = "none")]
+    pub fn get_nth(&self, n: usize) -> Option<(&K, &V)> {
+        let root_node = self.root.as_ref()?.reborrow();
+        root_node.find_nth(n).map(Handle::into_kv)
+    }
+
+    /// Removes and returns the key-value pair preceded by `n` other pairs in
+    /// the map, that is the pair with the `n`-th smallest key, counting from
+    /// zero.
+    ///
+    /// Returns `None` and leaves the map unchanged if the map has no more
+    /// than `n` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(btree_order_statistics)]
+    /// use std::collections::BTreeMap;
+    ///
+    /// let mut map = BTreeMap::from([(1, "a"), (3, "c"), (5, "e")]);
+    /// assert_eq!(map.remove_nth(1), Some((3, "c")));
+    /// assert_eq!(map.remove_nth(2), None);
+    /// assert_eq!(map.len(), 2);
+    /// ```
+    #[unstable(feature = "btree_order_statistics", issue = "none")]
+    pub fn remove_nth(&mut self, n: usize) -> Option<(K, V)> {
+        let (map, dormant_map) = DormantMutRef::new(self);
+        let alloc = (*map.alloc).clone();
+        let kv = map.root.as_mut()?.borrow_mut().find_nth(n)?;
+        let mut emptied_internal_root = false;
+        let (old_kv, _) = kv.remove_kv_tracking(|| emptied_internal_root = true, alloc.clone());
+        // SAFETY: we consumed the intermediate root borrow, `kv`.
+        let map = unsafe { dormant_map.awaken() };
+        map.length -= 1;
+        if emptied_internal_root {
+            let root = map.root.as_mut().unwrap();
+            root.pop_internal_level(alloc);
+        }
+        Some(old_kv)
+    }
+
     /// Returns a [`Cursor`] pointing at the first element that is above the
     /// given bound.
     ///
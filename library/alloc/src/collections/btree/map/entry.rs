@@ -3,9 +3,10 @@
 use core::mem;
 
 use crate::alloc::{Allocator, Global};
+use crate::collections::TryReserveError;
 
 use super::super::borrow::DormantMutRef;
-use super::super::node::{marker, Handle, NodeRef};
+use super::super::node::{marker, Handle, NodeRef, NodeReserve};
 use super::BTreeMap;
 
 use Entry::*;
@@ -347,27 +348,61 @@ pub fn into_key(self) -> K {
     /// assert_eq!(map["poneyland"], 37);
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
-    pub fn insert(mut self, value: V) -> &'a mut V {
+    pub fn insert(self, value: V) -> &'a mut V {
+        let alloc = self.alloc.clone();
+        self.insert_in(value, alloc)
+    }
+
+    /// Sets the value of the entry with the `VacantEntry`'s key, and returns a
+    /// mutable reference to it, or an error if the tree has to grow and the
+    /// allocation of its nodes fails.
+    ///
+    /// All nodes that the insertion may need are allocated before the map is
+    /// changed, so the map is left unchanged if this returns an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fallible_alloc)]
+    /// use std::collections::BTreeMap;
+    /// use std::collections::btree_map::Entry;
+    ///
+    /// let mut map: BTreeMap<&str, u32> = BTreeMap::new();
+    ///
+    /// if let Entry::Vacant(o) = map.entry("poneyland") {
+    ///     o.try_insert(37)?;
+    /// }
+    /// assert_eq!(map["poneyland"], 37);
+    /// # Ok::<(), std::collections::TryReserveError>(())
+    /// ```
+    #[unstable(feature = "fallible_alloc", issue = "none")]
+    pub fn try_insert(self, value: V) -> Result<&'a mut V, TryReserveError> {
+        let edge = self.handle.as_ref().map(|handle| handle.reborrow());
+        let reserve = NodeReserve::try_new(edge, self.alloc.clone())?;
+        Ok(self.insert_in(value, &reserve))
+    }
+
+    /// Inserts the value, allocating any new nodes with `alloc`.
+    fn insert_in<B: Allocator + Clone>(mut self, value: V, alloc: B) -> &'a mut V {
         let out_ptr = match self.handle {
             None => {
                 // SAFETY: There is no tree yet so no reference to it exists.
                 let map = unsafe { self.dormant_map.awaken() };
-                let mut root = NodeRef::new_leaf(self.alloc.clone());
+                let mut root = NodeRef::new_leaf(alloc);
                 let val_ptr = root.borrow_mut().push(self.key, value) as *mut V;
                 map.root = Some(root.forget_type());
                 map.length = 1;
                 val_ptr
             }
             Some(handle) => {
-                let new_handle =
-                    handle.insert_recursing(self.key, value, self.alloc.clone(), |ins| {
-                        drop(ins.left);
-                        // SAFETY: Pushing a new root node doesn't invalidate
-                        // handles to existing nodes.
-                        let map = unsafe { self.dormant_map.reborrow() };
-                        let root = map.root.as_mut().unwrap(); // same as ins.left
-                        root.push_internal_level(self.alloc).push(ins.kv.0, ins.kv.1, ins.right)
-                    });
+                let new_handle = handle.insert_recursing(self.key, value, alloc.clone(), |ins| {
+                    drop(ins.left);
+                    // SAFETY: Pushing a new root node doesn't invalidate
+                    // handles to existing nodes.
+                    let map = unsafe { self.dormant_map.reborrow() };
+                    let root = map.root.as_mut().unwrap(); // same as ins.left
+                    root.push_internal_level(alloc).push(ins.kv.0, ins.kv.1, ins.right)
+                });
 
                 // Get the pointer to the value
                 let val_ptr = new_handle.into_val_mut();
@@ -36,7 +36,7 @@ fn test_all_refs<'a, T: 'a>(dummy: &mut T, iter: impl Iterator<Item = &'a mut T>
     }
 }
 
-impl<K, V> BTreeMap<K, V> {
+impl<K, V, A: Allocator + Clone> BTreeMap<K, V, A> {
     // Panics if the map (or the code navigating it) is corrupted.
     fn check_invariants(&self) {
         if let Some(root) = &self.root {
@@ -50,6 +50,9 @@ fn check_invariants(&self) {
             // Check consistency of `length` with what navigation code encounters.
             assert_eq!(self.length, root_node.calc_length());
 
+            // Check the subtree lengths that order statistics rely on.
+            assert_eq!(self.length, root_node.assert_subtree_lens());
+
             // Lastly, check the invariant causing the least harm.
             root_node.assert_min_len(if root_node.height() > 0 { 1 } else { 0 });
         } else {
@@ -101,7 +104,9 @@ fn assert_strictly_ascending(&self)
             }
         }
     }
+}
 
+impl<K, V> BTreeMap<K, V> {
     // Transform the tree to minimize wasted space, obtaining fewer nodes that
     // are mostly filled up to their capacity. The same compact tree could have
     // been obtained by inserting keys in a shrewd order.
@@ -1786,6 +1791,8 @@ fn map<K>(mut map: BTreeMap<K, ()>) {
         let _ = map.keys();
         let _ = map.values();
         let _ = map.values_mut();
+        let _ = map.get_nth(0);
+        let _ = map.remove_nth(0);
         if true {
             let _ = map.into_values();
         } else if true {
@@ -1981,6 +1988,79 @@ fn test_pop_first_last() {
     assert_eq!(map.pop_last(), None);
 }
 
+#[test]
+fn test_rank_get_nth() {
+    let map = BTreeMap::<i32, i32>::new();
+    assert_eq!(map.rank(&0), 0);
+    assert_eq!(map.get_nth(0), None);
+
+    let size = MIN_INSERTS_HEIGHT_2;
+    let mut map = BTreeMap::new();
+    for i in 0..size as i32 {
+        map.insert(i * 2, i);
+    }
+    assert_eq!(map.height(), Some(2));
+    for i in 0..size {
+        let key = i as i32 * 2;
+        assert_eq!(map.rank(&key), i);
+        assert_eq!(map.rank(&(key + 1)), i + 1);
+        assert_eq!(map.get_nth(i), Some((&key, &(i as i32))));
+    }
+    assert_eq!(map.rank(&-1), 0);
+    assert_eq!(map.get_nth(size), None);
+}
+
+#[test]
+fn test_remove_nth() {
+    let mut map = BTreeMap::new();
+    assert_eq!(map.remove_nth(0), None);
+
+    let mut data = rand_data(MIN_INSERTS_HEIGHT_2);
+    map.extend(data.iter().copied());
+    assert_eq!(map.height(), Some(2));
+    data.sort();
+    let mut rng = DeterministicRng::new();
+    while !data.is_empty() {
+        assert_eq!(map.remove_nth(data.len()), None);
+        let n = rng.next() as usize % data.len();
+        let (k, v) = data.remove(n);
+        assert_eq!(map.remove_nth(n), Some((k, v)));
+        map.check();
+    }
+    assert!(map.is_empty());
+}
+
+#[test]
+fn test_range_len() {
+    let size = MIN_INSERTS_HEIGHT_2 as i32;
+    let mut map: BTreeMap<_, _> = (0..size).map(|i| (i, i)).collect();
+    assert_eq!(map.range(..).len(), size as usize);
+    assert_eq!(map.range(..0).len(), 0);
+    assert_eq!(map.range(size..).len(), 0);
+    for i in 0..=size {
+        for j in i..=size {
+            assert_eq!(map.range(i..j).len(), (j - i) as usize);
+        }
+    }
+
+    let mut range = map.range(1..size - 1);
+    let mut expected = size as usize - 2;
+    while range.next().is_some() {
+        expected -= 1;
+        assert_eq!(range.len(), expected);
+        if range.next_back().is_some() {
+            expected -= 1;
+        }
+        assert_eq!(range.len(), expected);
+    }
+    assert_eq!(expected, 0);
+
+    let mut range = map.range_mut(size / 2..);
+    assert_eq!(range.len(), (size - size / 2) as usize);
+    range.next_back();
+    assert_eq!(range.len(), (size - size / 2 - 1) as usize);
+}
+
 #[test]
 fn test_get_key_value() {
     let mut map = BTreeMap::new();
@@ -2046,6 +2126,67 @@ fn test_try_insert() {
     assert_eq!(err.value, 200);
 }
 
+#[test]
+fn test_try_insert_alloc() {
+    use crate::alloc::{AllocError, Layout};
+    use core::cell::Cell;
+    use core::ptr::NonNull;
+
+    // An allocator that fails once it used up its fuel, and counts the
+    // allocations that are still alive.
+    struct BoundedAlloc {
+        fuel: Cell<usize>,
+        live: Cell<usize>,
+    }
+    unsafe impl Allocator for BoundedAlloc {
+        fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            if self.fuel.get() == 0 {
+                return Err(AllocError);
+            }
+            self.fuel.set(self.fuel.get() - 1);
+            self.live.set(self.live.get() + 1);
+            Global.allocate(layout)
+        }
+        unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+            self.live.set(self.live.get() - 1);
+            unsafe { Global.deallocate(ptr, layout) }
+        }
+    }
+
+    let alloc = BoundedAlloc { fuel: Cell::new(0), live: Cell::new(0) };
+    let mut map = BTreeMap::new_in(&alloc);
+    for i in 0..MIN_INSERTS_HEIGHT_2 {
+        // Give the insertion more and more fuel until it succeeds, making sure
+        // that every failed attempt leaves the map as it was.
+        let mut fuel = 0;
+        loop {
+            alloc.fuel.set(fuel);
+            let live = alloc.live.get();
+            match map.try_insert_alloc(i, i) {
+                Ok(old) => {
+                    assert_eq!(old, None);
+                    break;
+                }
+                Err(_) => {
+                    assert_eq!(alloc.live.get(), live);
+                    assert_eq!(map.len(), i);
+                    map.check();
+                    fuel += 1;
+                }
+            }
+        }
+        // A split adds at most one node per level and a new root.
+        assert!(fuel <= map.height().unwrap() + 1);
+    }
+    assert_eq!(map.height(), Some(2));
+    map.check();
+
+    alloc.fuel.set(0);
+    assert_eq!(map.try_insert_alloc(0, 1), Ok(Some(0)));
+    drop(map);
+    assert_eq!(alloc.live.get(), 0);
+}
+
 macro_rules! create_append_test {
     ($name:ident, $len:expr) => {
         #[test]
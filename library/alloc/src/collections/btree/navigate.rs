@@ -41,6 +41,16 @@ pub fn reborrow(&self) -> LeafRange<marker::Immut<'_>, K, V> {
             back: self.back.as_ref().map(|b| b.reborrow()),
         }
     }
+
+    /// Counts the key-value pairs in the range, without visiting them.
+    pub fn len(&self) -> usize {
+        match self.reborrow() {
+            LeafRange { front: Some(front), back: Some(back) } => {
+                back.forget_node_type().rank() - front.forget_node_type().rank()
+            }
+            _ => 0,
+        }
+    }
 }
 
 impl<'a, K, V> LeafRange<marker::Immut<'a>, K, V> {
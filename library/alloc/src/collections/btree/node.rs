@@ -31,13 +31,15 @@
 //   since leaf edges are empty and need no data representation. In an internal node,
 //   an edge both identifies a position and contains a pointer to a child node.
 
+use core::cell::Cell;
 use core::marker::PhantomData;
 use core::mem::{self, MaybeUninit};
 use core::ptr::{self, NonNull};
 use core::slice::SliceIndex;
 
-use crate::alloc::{Allocator, Layout};
+use crate::alloc::{AllocError, Allocator, Layout};
 use crate::boxed::Box;
+use crate::collections::{TryReserveError, TryReserveErrorKind};
 
 const B: usize = 6;
 pub const CAPACITY: usize = 2 * B - 1;
@@ -97,6 +99,9 @@ fn new<A: Allocator + Clone>(alloc: A) -> Box<Self, A> {
 struct InternalNode<K, V> {
     data: LeafNode<K, V>,
 
+    /// The number of keys and values stored in this node and all its descendants.
+    subtree_len: usize,
+
     /// The pointers to the children of this node. `len + 1` of these are considered
     /// initialized and valid, except that near the end, while the tree is held
     /// through borrow type `Dying`, some of these pointers are dangling.
@@ -113,8 +118,10 @@ impl<K, V> InternalNode<K, V> {
     unsafe fn new<A: Allocator + Clone>(alloc: A) -> Box<Self, A> {
         unsafe {
             let mut node = Box::<Self, _>::new_uninit_in(alloc);
-            // We only need to initialize the data; the edges are MaybeUninit.
+            // We only need to initialize the data and the subtree length;
+            // the edges are MaybeUninit.
             LeafNode::init(ptr::addr_of_mut!((*node.as_mut_ptr()).data));
+            ptr::addr_of_mut!((*node.as_mut_ptr()).subtree_len).write(0);
             node.assume_init()
         }
     }
@@ -239,6 +246,7 @@ unsafe fn from_new_internal<A: Allocator + Clone>(
         let node = NonNull::from(Box::leak(internal)).cast();
         let mut this = NodeRef { height, node, _marker: PhantomData };
         this.borrow_mut().correct_all_childrens_parent_links();
+        this.borrow_mut().recalc_subtree_len();
         this
     }
 }
@@ -280,6 +288,18 @@ pub fn len(&self) -> usize {
         unsafe { usize::from((*Self::as_leaf_ptr(self)).len) }
     }
 
+    /// Finds the number of keys or values in the subtree rooted at this node,
+    /// which is the length of the node itself if it's a leaf.
+    pub fn subtree_len(&self) -> usize {
+        if self.height == 0 {
+            self.len()
+        } else {
+            // SAFETY: the node is internal. As in `len`, we only access the
+            // `subtree_len` field.
+            unsafe { (*(Self::as_leaf_ptr(self) as *const InternalNode<K, V>)).subtree_len }
+        }
+    }
+
     /// Returns the number of levels that the node and leaves are apart. Zero
     /// height means the node is a leaf itself. If you picture trees with the
     /// root on top, the number says at which elevation the node appears.
@@ -557,6 +577,60 @@ fn correct_all_childrens_parent_links(&mut self) {
         let len = self.len();
         unsafe { self.correct_childrens_parent_links(0..=len) };
     }
+
+    /// Sets the subtree length of the node, without invalidating other
+    /// references to the node.
+    fn set_subtree_len(&mut self, subtree_len: usize) {
+        let internal = NodeRef::as_internal_ptr(self);
+        unsafe { (*internal).subtree_len = subtree_len };
+    }
+
+    /// Recalculates the subtree length of the node from its length and the
+    /// subtree lengths of its children, which must be correct.
+    fn recalc_subtree_len(&mut self) {
+        let len = self.len();
+        let mut subtree_len = len;
+        for i in 0..=len {
+            subtree_len += unsafe { Handle::new_edge(self.reborrow(), i) }.descend().subtree_len();
+        }
+        self.set_subtree_len(subtree_len);
+    }
+}
+
+impl<'a, K: 'a, V: 'a> NodeRef<marker::Mut<'a>, K, V, marker::LeafOrInternal> {
+    /// Adds `delta` to the subtree lengths of all ancestors of the node, after
+    /// key-value pairs were added to or removed from its subtree.
+    pub fn add_to_ancestor_subtree_lens(self, delta: isize) {
+        let mut node = self;
+        while let Ok(parent) = node.ascend() {
+            let mut parent = parent.into_node();
+            let subtree_len = parent.subtree_len().wrapping_add_signed(delta);
+            parent.set_subtree_len(subtree_len);
+            node = parent.forget_type();
+        }
+    }
+
+    /// Recalculates the subtree lengths of all ancestors of the node, from the
+    /// bottom up, after key-value pairs or edges were moved around between them.
+    pub fn recalc_ancestor_subtree_lens(self) {
+        let mut node = self;
+        while let Ok(parent) = node.ascend() {
+            let mut parent = parent.into_node();
+            parent.recalc_subtree_len();
+            node = parent.forget_type();
+        }
+    }
+
+    /// Recalculates the subtree lengths of the node and all its descendants.
+    pub fn recalc_all_subtree_lens(&mut self) {
+        if let ForceResult::Internal(mut internal) = unsafe { self.reborrow_mut() }.force() {
+            for i in 0..=internal.len() {
+                let mut child = unsafe { Handle::new_edge(internal.reborrow_mut(), i) }.descend();
+                child.recalc_all_subtree_lens();
+            }
+            internal.recalc_subtree_len();
+        }
+    }
 }
 
 impl<'a, K: 'a, V: 'a> NodeRef<marker::Mut<'a>, K, V, marker::LeafOrInternal> {
@@ -667,6 +741,8 @@ impl<'a, K: 'a, V: 'a> NodeRef<marker::Mut<'a>, K, V, marker::Internal> {
     pub fn push(&mut self, key: K, val: V, edge: Root<K, V>) {
         assert!(edge.height == self.height - 1);
 
+        let subtree_len = self.subtree_len() + 1 + edge.subtree_len();
+        self.set_subtree_len(subtree_len);
         let len = self.len_mut();
         let idx = usize::from(*len);
         assert!(idx < CAPACITY);
@@ -973,6 +1049,7 @@ fn insert_fit(&mut self, key: K, val: V, edge: Root<K, V>) {
             *self.node.len_mut() = new_len as u16;
 
             self.node.correct_childrens_parent_links(self.idx + 1..new_len + 1);
+            self.node.recalc_subtree_len();
         }
     }
 
@@ -1018,12 +1095,16 @@ impl<'a, K: 'a, V: 'a> Handle<NodeRef<marker::Mut<'a>, K, V, marker::Leaf>, mark
     /// The returned pointer points to the inserted value, which in the case of `SplitResult`
     /// is in the `left` or `right` tree.
     pub fn insert_recursing<A: Allocator + Clone>(
-        self,
+        mut self,
         key: K,
         value: V,
         alloc: A,
         split_root: impl FnOnce(SplitResult<'a, K, V, marker::LeafOrInternal>),
     ) -> Handle<NodeRef<marker::Mut<'a>, K, V, marker::Leaf>, marker::KV> {
+        // Account for the new pair in the ancestors up front. The nodes split along
+        // the way recalculate their subtree lengths from their children instead.
+        unsafe { self.reborrow_mut() }.into_node().forget_type().add_to_ancestor_subtree_lens(1);
+
         let (mut split, handle) = match self.insert(key, value, alloc.clone()) {
             // SAFETY: we have finished splitting and can now re-awaken the
             // handle to the inserted element.
@@ -1052,6 +1133,100 @@ pub fn insert_recursing<A: Allocator + Clone>(
     }
 }
 
+/// An allocator that hands out the nodes an insertion may need, which were all
+/// allocated in advance. Passing it to `insert_recursing` makes sure that the
+/// insertion can't run out of memory after it started splitting nodes.
+pub struct NodeReserve<K, V, A: Allocator> {
+    alloc: A,
+    leaf: Cell<Option<NonNull<u8>>>,
+    // A list of internal nodes, linked through a pointer at the start of each node.
+    internal: Cell<Option<NonNull<u8>>>,
+    _marker: PhantomData<(K, V)>,
+}
+
+impl<K, V, A: Allocator> NodeReserve<K, V, A> {
+    /// Allocates the nodes needed to insert a key-value pair at `edge`, or to
+    /// create the root if the tree is empty and there's no edge.
+    pub fn try_new(
+        edge: Option<Handle<NodeRef<marker::Immut<'_>, K, V, marker::Leaf>, marker::Edge>>,
+        alloc: A,
+    ) -> Result<Self, TryReserveError> {
+        let reserve = NodeReserve {
+            alloc,
+            leaf: Cell::new(None),
+            internal: Cell::new(None),
+            _marker: PhantomData,
+        };
+        let Some(edge) = edge else {
+            reserve.leaf.set(Some(reserve.try_allocate(Layout::new::<LeafNode<K, V>>())?));
+            return Ok(reserve);
+        };
+
+        // A full leaf is split into a new leaf. The split propagates up through
+        // the full ancestors, which are split into new internal nodes, and if it
+        // reaches the root, a new internal root is pushed on top of it.
+        let mut node = edge.into_node().forget_type();
+        if node.len() < CAPACITY {
+            return Ok(reserve);
+        }
+        reserve.leaf.set(Some(reserve.try_allocate(Layout::new::<LeafNode<K, V>>())?));
+        loop {
+            let parent = node.ascend().ok().map(|parent| parent.into_node());
+            if parent.is_some_and(|parent| parent.len() < CAPACITY) {
+                return Ok(reserve);
+            }
+            let internal = reserve.try_allocate(Layout::new::<InternalNode<K, V>>())?;
+            unsafe {
+                internal.cast::<Option<NonNull<u8>>>().as_ptr().write(reserve.internal.get())
+            };
+            reserve.internal.set(Some(internal));
+            match parent {
+                Some(parent) => node = parent.forget_type(),
+                None => return Ok(reserve),
+            }
+        }
+    }
+
+    fn try_allocate(&self, layout: Layout) -> Result<NonNull<u8>, TryReserveError> {
+        match self.alloc.allocate(layout) {
+            Ok(ptr) => Ok(ptr.cast()),
+            Err(_) => Err(TryReserveErrorKind::AllocError { layout, non_exhaustive: () }.into()),
+        }
+    }
+}
+
+unsafe impl<K, V, A: Allocator> Allocator for NodeReserve<K, V, A> {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        let ptr = if layout == Layout::new::<LeafNode<K, V>>() {
+            self.leaf.take()
+        } else {
+            debug_assert_eq!(layout, Layout::new::<InternalNode<K, V>>());
+            let ptr = self.internal.get();
+            if let Some(ptr) = ptr {
+                self.internal.set(unsafe { ptr.cast::<Option<NonNull<u8>>>().as_ptr().read() });
+            }
+            ptr
+        };
+        ptr.map(|ptr| NonNull::slice_from_raw_parts(ptr, layout.size())).ok_or(AllocError)
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        unsafe { self.alloc.deallocate(ptr, layout) }
+    }
+}
+
+impl<K, V, A: Allocator> Drop for NodeReserve<K, V, A> {
+    fn drop(&mut self) {
+        if let Some(leaf) = self.leaf.take() {
+            unsafe { self.alloc.deallocate(leaf, Layout::new::<LeafNode<K, V>>()) };
+        }
+        while let Ok(internal) = self.allocate(Layout::new::<InternalNode<K, V>>()) {
+            let internal = internal.cast::<u8>();
+            unsafe { self.alloc.deallocate(internal, Layout::new::<InternalNode<K, V>>()) };
+        }
+    }
+}
+
 impl<BorrowType: marker::BorrowType, K, V>
     Handle<NodeRef<BorrowType, K, V, marker::Internal>, marker::Edge>
 {
@@ -1244,6 +1419,7 @@ pub fn split<A: Allocator + Clone>(
                 &mut new_node.edges[..new_len + 1],
             );
 
+            self.node.recalc_subtree_len();
             let height = self.node.height;
             let right = NodeRef::from_new_internal(new_node, height);
 
@@ -1389,6 +1565,7 @@ fn do_merge<
                 );
 
                 left_node.correct_childrens_parent_links(old_left_len + 1..new_left_len + 1);
+                left_node.recalc_subtree_len();
 
                 alloc.deallocate(right_node.node.cast(), Layout::new::<InternalNode<K, V>>());
             } else {
@@ -1524,6 +1701,8 @@ pub fn bulk_steal_left(&mut self, count: usize) {
                     );
 
                     right.correct_childrens_parent_links(0..new_right_len + 1);
+                    left.recalc_subtree_len();
+                    right.recalc_subtree_len();
                 }
                 (ForceResult::Leaf(_), ForceResult::Leaf(_)) => {}
                 _ => unreachable!(),
@@ -1588,6 +1767,8 @@ pub fn bulk_steal_right(&mut self, count: usize) {
 
                     left.correct_childrens_parent_links(old_left_len + 1..new_left_len + 1);
                     right.correct_childrens_parent_links(0..new_right_len + 1);
+                    left.recalc_subtree_len();
+                    right.recalc_subtree_len();
                 }
                 (ForceResult::Leaf(_), ForceResult::Leaf(_)) => {}
                 _ => unreachable!(),
@@ -1620,6 +1801,14 @@ pub fn forget_node_type(
     }
 }
 
+impl<BorrowType, K, V> Handle<NodeRef<BorrowType, K, V, marker::Internal>, marker::KV> {
+    pub fn forget_node_type(
+        self,
+    ) -> Handle<NodeRef<BorrowType, K, V, marker::LeafOrInternal>, marker::KV> {
+        unsafe { Handle::new_kv(self.node.forget_type(), self.idx) }
+    }
+}
+
 impl<BorrowType, K, V, Type> Handle<NodeRef<BorrowType, K, V, marker::LeafOrInternal>, Type> {
     /// Checks whether the underlying node is an `Internal` node or a `Leaf` node.
     pub fn force(
@@ -1651,7 +1840,8 @@ pub unsafe fn cast_to_leaf_unchecked(
 
 impl<'a, K, V> Handle<NodeRef<marker::Mut<'a>, K, V, marker::LeafOrInternal>, marker::Edge> {
     /// Move the suffix after `self` from one node to another one. `right` must be empty.
-    /// The first edge of `right` remains unchanged.
+    /// The first edge of `right` remains unchanged. The subtree lengths of both nodes
+    /// are left for the caller to recalculate.
     pub fn move_suffix(
         &mut self,
         right: &mut NodeRef<marker::Mut<'a>, K, V, marker::LeafOrInternal>,
@@ -17,6 +17,23 @@ pub fn assert_back_pointers(self) {
         }
     }
 
+    // Asserts that every internal node knows the number of key-value pairs in
+    // its subtree, and returns the number of pairs in the tree.
+    pub fn assert_subtree_lens(self) -> usize {
+        let subtree_len = match self.force() {
+            ForceResult::Leaf(leaf) => leaf.len(),
+            ForceResult::Internal(node) => {
+                let children = (0..=node.len()).map(|idx| {
+                    let edge = unsafe { Handle::new_edge(node, idx) };
+                    edge.descend().assert_subtree_lens()
+                });
+                node.len() + children.sum::<usize>()
+            }
+        };
+        assert_eq!(self.subtree_len(), subtree_len);
+        subtree_len
+    }
+
     // Renders a multi-line display of the keys in order and in tree hierarchy,
     // picturing the tree growing sideways from its root on the left to its
     // leaves on the right.
@@ -98,6 +115,6 @@ fn test_partial_eq() {
 fn test_sizes() {
     assert_eq!(core::mem::size_of::<LeafNode<(), ()>>(), 16);
     assert_eq!(core::mem::size_of::<LeafNode<i64, i64>>(), 16 + CAPACITY * 2 * 8);
-    assert_eq!(core::mem::size_of::<InternalNode<(), ()>>(), 16 + (CAPACITY + 1) * 8);
-    assert_eq!(core::mem::size_of::<InternalNode<i64, i64>>(), 16 + (CAPACITY * 3 + 1) * 8);
+    assert_eq!(core::mem::size_of::<InternalNode<(), ()>>(), 16 + (CAPACITY + 2) * 8);
+    assert_eq!(core::mem::size_of::<InternalNode<i64, i64>>(), 16 + (CAPACITY * 3 + 2) * 8);
 }
@@ -0,0 +1,78 @@
+use super::node::{marker, ForceResult::*, Handle, NodeRef};
+
+impl<BorrowType: marker::BorrowType, K, V> NodeRef<BorrowType, K, V, marker::LeafOrInternal> {
+    /// Finds the key-value pair preceded by `n` other pairs in the (sub)tree,
+    /// using the subtree lengths to descend directly towards it.
+    /// Returns `None` if the (sub)tree holds no more than `n` pairs.
+    pub fn find_nth(
+        self,
+        mut n: usize,
+    ) -> Option<Handle<NodeRef<BorrowType, K, V, marker::LeafOrInternal>, marker::KV>> {
+        if n >= self.subtree_len() {
+            return None;
+        }
+        let mut node = self;
+        loop {
+            let internal = match node.force() {
+                Leaf(leaf) => return Some(unsafe { Handle::new_kv(leaf, n) }.forget_node_type()),
+                Internal(internal) => internal,
+            };
+            let mut idx = 0;
+            loop {
+                let child = unsafe { Handle::new_edge(internal.reborrow(), idx) }.descend();
+                let child_len = child.subtree_len();
+                if n < child_len {
+                    node = unsafe { Handle::new_edge(internal, idx) }.descend();
+                    break;
+                }
+                n -= child_len;
+                if n == 0 {
+                    return Some(unsafe { Handle::new_kv(internal, idx) }.forget_node_type());
+                }
+                n -= 1;
+                idx += 1;
+            }
+        }
+    }
+}
+
+impl<'a, K: 'a, V: 'a>
+    Handle<NodeRef<marker::Immut<'a>, K, V, marker::LeafOrInternal>, marker::Edge>
+{
+    /// Counts the key-value pairs to the left of the edge in the subtree of its node.
+    fn subtree_len_before(self) -> usize {
+        let idx = self.idx();
+        match self.into_node().force() {
+            Leaf(_) => idx,
+            Internal(internal) => {
+                let children = (0..idx).map(|i| unsafe { Handle::new_edge(internal, i) }.descend());
+                idx + children.map(|child| child.subtree_len()).sum::<usize>()
+            }
+        }
+    }
+}
+
+impl<BorrowType: marker::BorrowType, K, V>
+    Handle<NodeRef<BorrowType, K, V, marker::LeafOrInternal>, marker::Edge>
+{
+    /// Counts the key-value pairs to the left of the edge in the entire tree,
+    /// by ascending to the root.
+    pub fn rank(self) -> usize {
+        let mut rank = self.reborrow().subtree_len_before();
+        let mut node = self.into_node();
+        while let Ok(parent) = node.ascend() {
+            rank += parent.reborrow().forget_node_type().subtree_len_before();
+            node = parent.into_node().forget_type();
+        }
+        rank
+    }
+}
+
+impl<BorrowType: marker::BorrowType, K, V>
+    Handle<NodeRef<BorrowType, K, V, marker::LeafOrInternal>, marker::KV>
+{
+    /// Counts the key-value pairs to the left of the pair in the entire tree.
+    pub fn rank(self) -> usize {
+        self.right_edge().rank() - 1
+    }
+}
@@ -21,10 +21,13 @@ pub fn remove_kv_tracking<F: FnOnce(), A: Allocator + Clone>(
 
 impl<'a, K: 'a, V: 'a> Handle<NodeRef<marker::Mut<'a>, K, V, marker::Leaf>, marker::KV> {
     fn remove_leaf_kv<F: FnOnce(), A: Allocator + Clone>(
-        self,
+        mut self,
         handle_emptied_internal_root: F,
         alloc: A,
     ) -> ((K, V), Handle<NodeRef<marker::Mut<'a>, K, V, marker::Leaf>, marker::Edge>) {
+        // Account for the removed pair in the ancestors before rebalancing, which
+        // only moves pairs around within their subtrees.
+        unsafe { self.reborrow_mut() }.into_node().forget_type().add_to_ancestor_subtree_lens(-1);
         let (old_kv, mut pos) = self.remove();
         let len = pos.reborrow().into_node().len();
         if len < MIN_LEN {
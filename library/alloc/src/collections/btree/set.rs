@@ -14,6 +14,7 @@
 use super::Recover;
 
 use crate::alloc::{Allocator, Global};
+use crate::collections::TryReserveError;
 
 /// An ordered set based on a B-Tree.
 ///
@@ -871,6 +872,37 @@ pub fn pop_last(&mut self) -> Option<T>
         self.map.pop_last().map(|kv| kv.0)
     }
 
+    /// Returns the number of elements in the set that are less than the given
+    /// value.
+    ///
+    /// This is the index the value has, or would have if inserted, in the
+    /// sorted sequence of elements. It is computed in logarithmic time.
+    ///
+    /// The value may be any borrowed form of the set's element type,
+    /// but the ordering on the borrowed form *must* match the
+    /// ordering on the element type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(btree_order_statistics)]
+    /// use std::collections::BTreeSet;
+    ///
+    /// let set = BTreeSet::from([1, 3, 5]);
+    /// assert_eq!(set.rank(&0), 0);
+    /// assert_eq!(set.rank(&3), 1);
+    /// assert_eq!(set.rank(&4), 2);
+    /// assert_eq!(set.rank(&9), 3);
+    /// ```
+    #[unstable(feature = "btree_order_statistics", issue = "none")]
+    pub fn rank<Q: ?Sized>(&self, value: &Q) -> usize
+    where
+        T: Borrow<Q> + Ord,
+        Q: Ord,
+    {
+        self.map.rank(value)
+    }
+
     /// Adds a value to the set.
     ///
     /// Returns whether the value was newly inserted. That is:
@@ -903,6 +935,35 @@ pub fn insert(&mut self, value: T) -> bool
         self.map.insert(value, SetValZST::default()).is_none()
     }
 
+    /// Adds a value to the set, returning an error instead of aborting if the
+    /// tree has to grow and the allocation of its nodes fails.
+    ///
+    /// This is the fallible equivalent of [`insert`], and returns the same
+    /// value on success. The set is left unchanged if this returns an error.
+    ///
+    /// [`insert`]: BTreeSet::insert
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fallible_alloc)]
+    /// use std::collections::BTreeSet;
+    ///
+    /// let mut set = BTreeSet::new();
+    ///
+    /// assert_eq!(set.try_insert_alloc(2)?, true);
+    /// assert_eq!(set.try_insert_alloc(2)?, false);
+    /// assert_eq!(set.len(), 1);
+    /// # Ok::<(), std::collections::TryReserveError>(())
+    /// ```
+    #[unstable(feature = "fallible_alloc", issue = "none")]
+    pub fn try_insert_alloc(&mut self, value: T) -> Result<bool, TryReserveError>
+    where
+        T: Ord,
+    {
+        Ok(self.map.try_insert_alloc(value, SetValZST::default())?.is_none())
+    }
+
     /// Adds a value to the set, replacing the existing element, if any, that is
     /// equal to the value. Returns the replaced element.
     ///
@@ -1178,6 +1239,50 @@ pub const fn len(&self) -> usize {
     pub const fn is_empty(&self) -> bool {
         self.len() == 0
     }
+
+    /// Returns the element preceded by `n` other elements in the set, that is
+    /// the `n`-th smallest element, counting from zero.
+    ///
+    /// Returns `None` if the set has no more than `n` elements. The element
+    /// is found in logarithmic time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(btree_order_statistics)]
+    /// use std::collections::BTreeSet;
+    ///
+    /// let set = BTreeSet::from([1, 3, 5]);
+    /// assert_eq!(set.get_nth(0), Some(&1));
+    /// assert_eq!(set.get_nth(2), Some(&5));
+    /// assert_eq!(set.get_nth(3), None);
+    /// ```
+    #[unstable(feature = "btree_order_statistics", issue = "none")]
+    pub fn get_nth(&self, n: usize) -> Option<&T> {
+        self.map.get_nth(n).map(|(k, _)| k)
+    }
+
+    /// Removes and returns the element preceded by `n` other elements in the
+    /// set, that is the `n`-th smallest element, counting from zero.
+    ///
+    /// Returns `None` and leaves the set unchanged if the set has no more
+    /// than `n` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(btree_order_statistics)]
+    /// use std::collections::BTreeSet;
+    ///
+    /// let mut set = BTreeSet::from([1, 3, 5]);
+    /// assert_eq!(set.remove_nth(1), Some(3));
+    /// assert_eq!(set.remove_nth(2), None);
+    /// assert_eq!(set.len(), 2);
+    /// ```
+    #[unstable(feature = "btree_order_statistics", issue = "none")]
+    pub fn remove_nth(&mut self, n: usize) -> Option<T> {
+        self.map.remove_nth(n).map(|kv| kv.0)
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -1607,6 +1712,30 @@ fn next_back(&mut self) -> Option<&'a T> {
 #[stable(feature = "fused", since = "1.26.0")]
 impl<T> FusedIterator for Range<'_, T> {}
 
+impl<T> Range<'_, T> {
+    /// Returns the number of elements remaining in the range.
+    ///
+    /// Unlike `count`, this doesn't visit the elements, and takes logarithmic
+    /// time in the size of the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(btree_order_statistics)]
+    /// use std::collections::BTreeSet;
+    ///
+    /// let set: BTreeSet<i32> = (0..100).collect();
+    /// let mut range = set.range(10..50);
+    /// assert_eq!(range.len(), 40);
+    /// range.next_back();
+    /// assert_eq!(range.len(), 39);
+    /// ```
+    #[unstable(feature = "btree_order_statistics", issue = "none")]
+    pub fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
 #[stable(feature = "default_iters", since = "1.70.0")]
 impl<T> Default for Range<'_, T> {
     /// Creates an empty `btree_set::Range`.
@@ -701,6 +701,8 @@ fn set<K>(mut set: BTreeSet<K>) {
         let _ = set.len();
         set.clear();
         let _ = set.iter();
+        let _ = set.get_nth(0);
+        let _ = set.remove_nth(0);
         let _ = set.into_iter();
     }
 
@@ -778,6 +780,32 @@ fn test_first_last() {
     assert_eq!(a.pop_last(), None);
 }
 
+#[test]
+fn test_rank_nth() {
+    let mut a = BTreeSet::from([2, 4, 6, 8]);
+    assert_eq!(a.rank(&1), 0);
+    assert_eq!(a.rank(&4), 1);
+    assert_eq!(a.rank(&5), 2);
+    assert_eq!(a.rank(&9), 4);
+    assert_eq!(a.get_nth(1), Some(&4));
+    assert_eq!(a.get_nth(4), None);
+    assert_eq!(a.range(3..8).len(), 2);
+    assert_eq!(a.remove_nth(4), None);
+    assert_eq!(a.remove_nth(1), Some(4));
+    assert_eq!(a.get_nth(1), Some(&6));
+    assert_eq!(a.rank(&6), 1);
+    assert_eq!(a.len(), 3);
+}
+
+#[test]
+fn test_try_insert_alloc() {
+    let mut a = BTreeSet::new();
+    assert_eq!(a.try_insert_alloc(2), Ok(true));
+    assert_eq!(a.try_insert_alloc(1), Ok(true));
+    assert_eq!(a.try_insert_alloc(2), Ok(false));
+    assert!(a.iter().eq(&[1, 2]));
+}
+
 // Unlike the function with the same name in map/tests, returns no values.
 // Which also means it returns different predetermined pseudo-random keys,
 // and the test cases using this function explore slightly different trees.
@@ -4,26 +4,6 @@
 use core::borrow::Borrow;
 
 impl<K, V> Root<K, V> {
-    /// Calculates the length of both trees that result from splitting up
-    /// a given number of distinct key-value pairs.
-    pub fn calc_split_length(
-        total_num: usize,
-        root_a: &Root<K, V>,
-        root_b: &Root<K, V>,
-    ) -> (usize, usize) {
-        let (length_a, length_b);
-        if root_a.height() < root_b.height() {
-            length_a = root_a.reborrow().calc_length();
-            length_b = total_num - length_a;
-            debug_assert_eq!(length_b, root_b.reborrow().calc_length());
-        } else {
-            length_b = root_b.reborrow().calc_length();
-            length_a = total_num - length_b;
-            debug_assert_eq!(length_a, root_a.reborrow().calc_length());
-        }
-        (length_a, length_b)
-    }
-
     /// Split off a tree with key-value pairs at and after the given key.
     /// The result is meaningful only if the tree is ordered by key,
     /// and if the ordering of `Q` corresponds to that of `K`.
@@ -52,7 +32,14 @@ pub fn split_off<Q: ?Sized + Ord, A: Allocator + Clone>(&mut self, key: &Q, allo
                     left_node = edge.descend();
                     right_node = node.first_edge().descend();
                 }
-                (Leaf(_), Leaf(_)) => break,
+                (Leaf(edge), Leaf(node)) => {
+                    // Pairs and edges moved between the nodes along the search
+                    // path at every level, so their subtree lengths are only
+                    // known now.
+                    edge.into_node().forget_type().recalc_ancestor_subtree_lens();
+                    node.forget_type().recalc_ancestor_subtree_lens();
+                    break;
+                }
                 _ => unreachable!(),
             }
         }
@@ -559,6 +559,32 @@ pub const fn new() -> VecDeque<T> {
     pub fn with_capacity(capacity: usize) -> VecDeque<T> {
         Self::with_capacity_in(capacity, Global)
     }
+
+    /// Creates an empty deque with space for at least `capacity` elements,
+    /// returning an error instead of aborting if the allocation fails.
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fallible_alloc)]
+    /// use std::collections::VecDeque;
+    ///
+    /// let deque: VecDeque<u32> = VecDeque::try_with_capacity(10)?;
+    /// assert!(deque.capacity() >= 10);
+    /// # Ok::<(), std::collections::TryReserveError>(())
+    /// ```
+    #[inline]
+    #[unstable(feature = "fallible_alloc", issue = "none")]
+    pub fn try_with_capacity(capacity: usize) -> Result<VecDeque<T>, TryReserveError> {
+        let mut deque = VecDeque::new();
+        deque.try_reserve_exact(capacity)?;
+        Ok(deque)
+    }
 }
 
 impl<T, A: Allocator> VecDeque<T, A> {
@@ -1663,6 +1689,64 @@ pub fn push_back(&mut self, value: T) {
         self.len += 1;
     }
 
+    /// Prepends an element to the deque, returning an error instead of
+    /// aborting if the deque has to grow and the allocation fails.
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned and the deque is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fallible_alloc)]
+    /// use std::collections::VecDeque;
+    ///
+    /// let mut d = VecDeque::new();
+    /// d.try_push_front(1)?;
+    /// d.try_push_front(2)?;
+    /// assert_eq!(d.front(), Some(&2));
+    /// # Ok::<(), std::collections::TryReserveError>(())
+    /// ```
+    #[unstable(feature = "fallible_alloc", issue = "none")]
+    pub fn try_push_front(&mut self, value: T) -> Result<(), TryReserveError> {
+        // This only reallocates if the deque is full, in which case `push_front`
+        // won't have to grow it any more.
+        self.try_reserve(1)?;
+        self.push_front(value);
+        Ok(())
+    }
+
+    /// Appends an element to the back of the deque, returning an error
+    /// instead of aborting if the deque has to grow and the allocation fails.
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned and the deque is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fallible_alloc)]
+    /// use std::collections::VecDeque;
+    ///
+    /// let mut buf = VecDeque::new();
+    /// buf.try_push_back(1)?;
+    /// buf.try_push_back(3)?;
+    /// assert_eq!(3, *buf.back().unwrap());
+    /// # Ok::<(), std::collections::TryReserveError>(())
+    /// ```
+    #[unstable(feature = "fallible_alloc", issue = "none")]
+    pub fn try_push_back(&mut self, value: T) -> Result<(), TryReserveError> {
+        // This only reallocates if the deque is full, in which case `push_back`
+        // won't have to grow it any more.
+        self.try_reserve(1)?;
+        self.push_back(value);
+        Ok(())
+    }
+
     #[inline]
     fn is_contiguous(&self) -> bool {
         // Do the calculation like this to avoid overflowing if len + head > usize::MAX
@@ -67,6 +67,8 @@
 pub use core::slice::{RSplit, RSplitMut};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::slice::{RSplitN, RSplitNMut, SplitN, SplitNMut};
+#[unstable(feature = "slice_search", issue = "none")]
+pub use core::slice::{RSplitNSubslice, RSplitSubslice, SplitNSubslice, SplitSubslice};
 #[stable(feature = "split_inclusive", since = "1.51.0")]
 pub use core::slice::{SplitInclusive, SplitInclusiveMut};
 
@@ -544,6 +546,71 @@ pub fn repeat(&self, n: usize) -> Vec<T>
         buf
     }
 
+    /// Replaces all non-overlapping occurrences of `from` with `to`.
+    ///
+    /// `replace_subslice` creates a new [`Vec`], and copies the data from this
+    /// slice into it. While doing so, it replaces the occurrences of `from`
+    /// that [`split_subslice`] would find.
+    ///
+    /// [`split_subslice`]: slice::split_subslice
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_search)]
+    /// let v = b"GET /a%20b HTTP/1.1";
+    /// assert_eq!(v.replace_subslice(b"%20", b" "), b"GET /a b HTTP/1.1");
+    ///
+    /// // An empty pattern matches between every element.
+    /// assert_eq!([1, 2].replace_subslice(&[], &[0]), [0, 1, 0, 2, 0]);
+    /// ```
+    #[rustc_allow_incoherent_impl]
+    #[cfg(not(no_global_oom_handling))]
+    #[unstable(feature = "slice_search", issue = "none")]
+    #[must_use = "this returns the replaced slice as a new vector, \
+                  without modifying the original"]
+    pub fn replace_subslice(&self, from: &[T], to: &[T]) -> Vec<T>
+    where
+        T: Clone + PartialEq,
+    {
+        self.replacen_subslice(from, to, usize::MAX)
+    }
+
+    /// Replaces the first `count` non-overlapping occurrences of `from` with
+    /// `to`.
+    ///
+    /// `replacen_subslice` creates a new [`Vec`], and copies the data from
+    /// this slice into it. While doing so, it replaces at most `count`
+    /// occurrences of `from`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_search)]
+    /// let v = b"a, b, c";
+    /// assert_eq!(v.replacen_subslice(b", ", b";", 1), b"a;b, c");
+    /// assert_eq!(v.replacen_subslice(b", ", b";", 0), v);
+    /// ```
+    #[rustc_allow_incoherent_impl]
+    #[cfg(not(no_global_oom_handling))]
+    #[unstable(feature = "slice_search", issue = "none")]
+    #[must_use = "this returns the replaced slice as a new vector, \
+                  without modifying the original"]
+    pub fn replacen_subslice(&self, from: &[T], to: &[T], count: usize) -> Vec<T>
+    where
+        T: Clone + PartialEq,
+    {
+        let mut result = Vec::with_capacity(self.len());
+        let mut parts = self.splitn_subslice(count.saturating_add(1), from);
+        // The slice always has at least one part.
+        result.extend_from_slice(parts.next().unwrap());
+        for part in parts {
+            result.extend_from_slice(to);
+            result.extend_from_slice(part);
+        }
+        result
+    }
+
     /// Flattens a slice of `T` into a single value `Self::Output`.
     ///
     /// # Examples
@@ -24,6 +24,8 @@
 pub use core::str::pattern;
 #[stable(feature = "encode_utf16", since = "1.8.0")]
 pub use core::str::EncodeUtf16;
+#[unstable(feature = "str_graphemes", issue = "none")]
+pub use core::str::Graphemes;
 #[stable(feature = "split_ascii_whitespace", since = "1.34.0")]
 pub use core::str::SplitAsciiWhitespace;
 #[stable(feature = "split_inclusive", since = "1.51.0")]
@@ -481,6 +483,111 @@ pub fn to_uppercase(&self) -> String {
         s
     }
 
+    /// Returns the Normalization Form D of this string slice, as a new [`String`].
+    ///
+    /// In Normalization Form D, all characters are decomposed into their
+    /// canonical decompositions, with combining marks in canonical order, as
+    /// described in [Unicode Standard Annex #15]. Canonically equivalent strings,
+    /// such as a precomposed letter and the same letter followed by a combining
+    /// accent, have the same Normalization Form D.
+    ///
+    /// [Unicode Standard Annex #15]: https://www.unicode.org/reports/tr15/
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_normalization)]
+    /// let s = "caf\u{e9}";
+    ///
+    /// assert_eq!("cafe\u{301}", s.to_nfd());
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    #[rustc_allow_incoherent_impl]
+    #[must_use = "this returns the normalized string as a new String, \
+                  without modifying the original"]
+    #[unstable(feature = "str_normalization", issue = "none")]
+    pub fn to_nfd(&self) -> String {
+        normalize(self, false, false)
+    }
+
+    /// Returns the Normalization Form C of this string slice, as a new [`String`].
+    ///
+    /// Normalization Form C is obtained by recomposing the Normalization Form D
+    /// of the string (see [`to_nfd`]), so that characters which have
+    /// precomposed forms are mostly represented by them.
+    ///
+    /// [`to_nfd`]: str::to_nfd
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_normalization)]
+    /// let s = "cafe\u{301}";
+    ///
+    /// assert_eq!("caf\u{e9}", s.to_nfc());
+    /// assert_eq!(s.to_nfc(), s.to_nfd().to_nfc());
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    #[rustc_allow_incoherent_impl]
+    #[must_use = "this returns the normalized string as a new String, \
+                  without modifying the original"]
+    #[unstable(feature = "str_normalization", issue = "none")]
+    pub fn to_nfc(&self) -> String {
+        normalize(self, false, true)
+    }
+
+    /// Returns the Normalization Form KD of this string slice, as a new [`String`].
+    ///
+    /// This is like [`to_nfd`], but also applies the compatibility
+    /// decompositions, which replace characters such as ligatures, circled
+    /// digits or full-width forms by their plain equivalents. Unlike the
+    /// canonical ones, these decompositions may lose information.
+    ///
+    /// [`to_nfd`]: str::to_nfd
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_normalization)]
+    /// let s = "\u{fb01}nal \u{2461}";
+    ///
+    /// assert_eq!("final 2", s.to_nfkd());
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    #[rustc_allow_incoherent_impl]
+    #[must_use = "this returns the normalized string as a new String, \
+                  without modifying the original"]
+    #[unstable(feature = "str_normalization", issue = "none")]
+    pub fn to_nfkd(&self) -> String {
+        normalize(self, true, false)
+    }
+
+    /// Returns the Normalization Form KC of this string slice, as a new [`String`].
+    ///
+    /// This is like [`to_nfc`], but also applies the compatibility
+    /// decompositions before recomposing the string (see [`to_nfkd`]).
+    ///
+    /// [`to_nfc`]: str::to_nfc
+    /// [`to_nfkd`]: str::to_nfkd
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_normalization)]
+    /// // Half-width katakana, with a separate voiced sound mark
+    /// let s = "\u{ff76}\u{ff9e}";
+    ///
+    /// assert_eq!("\u{30ac}", s.to_nfkc());
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    #[rustc_allow_incoherent_impl]
+    #[must_use = "this returns the normalized string as a new String, \
+                  without modifying the original"]
+    #[unstable(feature = "str_normalization", issue = "none")]
+    pub fn to_nfkc(&self) -> String {
+        normalize(self, true, true)
+    }
+
     /// Converts a [`Box<str>`] into a [`String`] without copying or allocating.
     ///
     /// # Examples
@@ -611,6 +718,59 @@ pub unsafe fn from_boxed_utf8_unchecked(v: Box<[u8]>) -> Box<str> {
     unsafe { Box::from_raw(Box::into_raw(v) as *mut str) }
 }
 
+/// Normalizes the string as described in Unicode Standard Annex #15: decomposes
+/// it, using the compatibility decompositions if `compatible` is true, puts the
+/// combining marks into canonical order and recomposes it if `compose` is true.
+#[cfg(not(test))]
+#[cfg(not(no_global_oom_handling))]
+fn normalize(s: &str, compatible: bool, compose: bool) -> String {
+    use core::unicode::normalization::{canonical_combining_class, decompose};
+
+    // ASCII characters are left unchanged by all the normalization forms.
+    if s.is_ascii() {
+        return String::from(s);
+    }
+
+    let mut decomposed: Vec<(char, u8)> = Vec::with_capacity(s.len());
+    for c in s.chars() {
+        decompose(c, compatible, |c| {
+            let class = canonical_combining_class(c);
+            // Sort each run of non-starters by their combining class, keeping
+            // the marks with the same class in order.
+            let mut i = decomposed.len();
+            while class != 0 && i > 0 && decomposed[i - 1].1 > class {
+                i -= 1;
+            }
+            decomposed.insert(i, (c, class));
+        });
+    }
+    if !compose {
+        return decomposed.into_iter().map(|(c, _)| c).collect();
+    }
+
+    // Each character is combined with the last starter if it isn't blocked
+    // from it, that is if there is no character between them with a combining
+    // class of zero or not lower than its own.
+    let mut composed: Vec<(char, u8)> = Vec::with_capacity(decomposed.len());
+    let mut starter = None;
+    for (c, class) in decomposed {
+        if let Some(i) = starter {
+            let blocked = composed.len() - 1 != i && composed[composed.len() - 1].1 >= class;
+            if !blocked {
+                if let Some(composite) = core::unicode::normalization::compose(composed[i].0, c) {
+                    composed[i].0 = composite;
+                    continue;
+                }
+            }
+        }
+        if class == 0 {
+            starter = Some(composed.len());
+        }
+        composed.push((c, class));
+    }
+    composed.into_iter().map(|(c, _)| c).collect()
+}
+
 /// Converts the bytes while the bytes are still ascii.
 /// For better average performance, this happens in chunks of `2*size_of::<usize>()`.
 /// Returns a vec with the converted bytes.
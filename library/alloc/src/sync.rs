@@ -1160,6 +1160,67 @@ pub fn new_zeroed_slice(len: usize) -> Arc<[mem::MaybeUninit<T>]> {
             ))
         }
     }
+
+    /// Constructs a new atomically reference-counted slice with uninitialized contents, returning
+    /// an error if the allocation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(new_uninit, allocator_api)]
+    ///
+    /// use std::sync::Arc;
+    ///
+    /// let mut values = Arc::<[u32]>::try_new_uninit_slice(3)?;
+    ///
+    /// // Deferred initialization:
+    /// let data = Arc::get_mut(&mut values).unwrap();
+    /// data[0].write(1);
+    /// data[1].write(2);
+    /// data[2].write(3);
+    ///
+    /// let values = unsafe { values.assume_init() };
+    ///
+    /// assert_eq!(*values, [1, 2, 3]);
+    /// # Ok::<(), std::alloc::AllocError>(())
+    /// ```
+    #[unstable(feature = "allocator_api", issue = "32838")]
+    // #[unstable(feature = "new_uninit", issue = "63291")]
+    pub fn try_new_uninit_slice(len: usize) -> Result<Arc<[mem::MaybeUninit<T>]>, AllocError> {
+        unsafe {
+            Ok(Arc::from_ptr(Arc::try_allocate_for_slice(len, |layout| Global.allocate(layout))?))
+        }
+    }
+
+    /// Constructs a new atomically reference-counted slice with uninitialized contents, with the
+    /// memory being filled with `0` bytes, returning an error if the allocation fails.
+    ///
+    /// See [`MaybeUninit::zeroed`][zeroed] for examples of correct and
+    /// incorrect usage of this method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(new_uninit, allocator_api)]
+    ///
+    /// use std::sync::Arc;
+    ///
+    /// let values = Arc::<[u32]>::try_new_zeroed_slice(3)?;
+    /// let values = unsafe { values.assume_init() };
+    ///
+    /// assert_eq!(*values, [0, 0, 0]);
+    /// # Ok::<(), std::alloc::AllocError>(())
+    /// ```
+    ///
+    /// [zeroed]: mem::MaybeUninit::zeroed
+    #[unstable(feature = "allocator_api", issue = "32838")]
+    // #[unstable(feature = "new_uninit", issue = "63291")]
+    pub fn try_new_zeroed_slice(len: usize) -> Result<Arc<[mem::MaybeUninit<T>]>, AllocError> {
+        unsafe {
+            let ptr = Arc::try_allocate_for_slice(len, |layout| Global.allocate_zeroed(layout))?;
+            Ok(Arc::from_ptr(ptr))
+        }
+    }
 }
 
 impl<T, A: Allocator> Arc<[T], A> {
@@ -1236,6 +1297,83 @@ pub fn new_zeroed_slice_in(len: usize, alloc: A) -> Arc<[mem::MaybeUninit<T>], A
             )
         }
     }
+
+    /// Constructs a new atomically reference-counted slice with uninitialized contents in the
+    /// provided allocator, returning an error if the allocation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(new_uninit)]
+    /// #![feature(get_mut_unchecked)]
+    /// #![feature(allocator_api)]
+    ///
+    /// use std::sync::Arc;
+    /// use std::alloc::System;
+    ///
+    /// let mut values = Arc::<[u32], _>::try_new_uninit_slice_in(3, System)?;
+    ///
+    /// let values = unsafe {
+    ///     // Deferred initialization:
+    ///     Arc::get_mut_unchecked(&mut values)[0].as_mut_ptr().write(1);
+    ///     Arc::get_mut_unchecked(&mut values)[1].as_mut_ptr().write(2);
+    ///     Arc::get_mut_unchecked(&mut values)[2].as_mut_ptr().write(3);
+    ///
+    ///     values.assume_init()
+    /// };
+    ///
+    /// assert_eq!(*values, [1, 2, 3]);
+    /// # Ok::<(), std::alloc::AllocError>(())
+    /// ```
+    #[unstable(feature = "allocator_api", issue = "32838")]
+    // #[unstable(feature = "new_uninit", issue = "63291")]
+    #[inline]
+    pub fn try_new_uninit_slice_in(
+        len: usize,
+        alloc: A,
+    ) -> Result<Arc<[mem::MaybeUninit<T>], A>, AllocError> {
+        unsafe {
+            let ptr = Arc::try_allocate_for_slice(len, |layout| alloc.allocate(layout))?;
+            Ok(Arc::from_ptr_in(ptr, alloc))
+        }
+    }
+
+    /// Constructs a new atomically reference-counted slice with uninitialized contents in the
+    /// provided allocator, with the memory being filled with `0` bytes, returning an error if
+    /// the allocation fails.
+    ///
+    /// See [`MaybeUninit::zeroed`][zeroed] for examples of correct and
+    /// incorrect usage of this method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(new_uninit)]
+    /// #![feature(allocator_api)]
+    ///
+    /// use std::sync::Arc;
+    /// use std::alloc::System;
+    ///
+    /// let values = Arc::<[u32], _>::try_new_zeroed_slice_in(3, System)?;
+    /// let values = unsafe { values.assume_init() };
+    ///
+    /// assert_eq!(*values, [0, 0, 0]);
+    /// # Ok::<(), std::alloc::AllocError>(())
+    /// ```
+    ///
+    /// [zeroed]: mem::MaybeUninit::zeroed
+    #[unstable(feature = "allocator_api", issue = "32838")]
+    // #[unstable(feature = "new_uninit", issue = "63291")]
+    #[inline]
+    pub fn try_new_zeroed_slice_in(
+        len: usize,
+        alloc: A,
+    ) -> Result<Arc<[mem::MaybeUninit<T>], A>, AllocError> {
+        unsafe {
+            let ptr = Arc::try_allocate_for_slice(len, |layout| alloc.allocate_zeroed(layout))?;
+            Ok(Arc::from_ptr_in(ptr, alloc))
+        }
+    }
 }
 
 impl<T, A: Allocator> Arc<mem::MaybeUninit<T>, A> {
@@ -1890,6 +2028,21 @@ unsafe fn allocate_for_slice(len: usize) -> *mut ArcInner<[T]> {
         }
     }
 
+    /// Allocates an `ArcInner<[T]>` with the given length, returning an error
+    /// if the length overflows or the allocation fails.
+    #[inline]
+    unsafe fn try_allocate_for_slice(
+        len: usize,
+        allocate: impl FnOnce(Layout) -> Result<NonNull<[u8]>, AllocError>,
+    ) -> Result<*mut ArcInner<[T]>, AllocError> {
+        let value_layout = Layout::array::<T>(len).map_err(|_| AllocError)?;
+        unsafe {
+            Self::try_allocate_for_layout(value_layout, allocate, |mem| {
+                ptr::slice_from_raw_parts_mut(mem.cast::<T>(), len) as *mut ArcInner<[T]>
+            })
+        }
+    }
+
     /// Copy elements from slice into newly allocated `Arc<[T]>`
     ///
     /// Unsafe because the caller must either take ownership or bind `T: Copy`.
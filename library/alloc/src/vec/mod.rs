@@ -481,6 +481,33 @@ pub fn with_capacity(capacity: usize) -> Self {
         Self::with_capacity_in(capacity, Global)
     }
 
+    /// Constructs a new, empty `Vec<T>` with at least the specified capacity,
+    /// returning an error instead of aborting if the allocation fails.
+    ///
+    /// This is the fallible equivalent of [`Vec::with_capacity`].
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fallible_alloc)]
+    ///
+    /// let mut vec = Vec::<u32>::try_with_capacity(10)?;
+    /// assert!(vec.capacity() >= 10);
+    ///
+    /// assert!(Vec::<u32>::try_with_capacity(usize::MAX).is_err());
+    /// # Ok::<(), std::collections::TryReserveError>(())
+    /// ```
+    #[inline]
+    #[unstable(feature = "fallible_alloc", issue = "none")]
+    pub fn try_with_capacity(capacity: usize) -> Result<Self, TryReserveError> {
+        Self::try_with_capacity_in(capacity, Global)
+    }
+
     /// Creates a `Vec<T>` directly from a pointer, a capacity, and a length.
     ///
     /// # Safety
@@ -672,6 +699,36 @@ pub fn with_capacity_in(capacity: usize, alloc: A) -> Self {
         Vec { buf: RawVec::with_capacity_in(capacity, alloc), len: 0 }
     }
 
+    /// Constructs a new, empty `Vec<T, A>` with at least the specified capacity
+    /// with the provided allocator, returning an error instead of aborting if
+    /// the allocation fails.
+    ///
+    /// This is the fallible equivalent of [`Vec::with_capacity_in`].
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(allocator_api, fallible_alloc)]
+    ///
+    /// use std::alloc::System;
+    ///
+    /// let vec = Vec::<u32, _>::try_with_capacity_in(10, System)?;
+    /// assert!(vec.capacity() >= 10);
+    /// # Ok::<(), std::collections::TryReserveError>(())
+    /// ```
+    #[inline]
+    #[unstable(feature = "fallible_alloc", issue = "none")]
+    pub fn try_with_capacity_in(capacity: usize, alloc: A) -> Result<Self, TryReserveError> {
+        let mut vec = Vec::new_in(alloc);
+        vec.try_reserve_exact(capacity)?;
+        Ok(vec)
+    }
+
     /// Creates a `Vec<T, A>` directly from a pointer, a capacity, a length,
     /// and an allocator.
     ///
@@ -1490,6 +1547,51 @@ fn assert_failed(index: usize, len: usize) -> ! {
     #[cfg(not(no_global_oom_handling))]
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn insert(&mut self, index: usize, element: T) {
+        // space for the new element
+        if self.len == self.buf.capacity() {
+            self.reserve(1);
+        }
+        self.insert_within_capacity(index, element);
+    }
+
+    /// Inserts an element at position `index` within the vector, shifting all
+    /// elements after it to the right, returning an error instead of aborting
+    /// if the vector has to grow and the allocation fails.
+    ///
+    /// This is the fallible equivalent of [`Vec::insert`].
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned and the vector is left unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fallible_alloc)]
+    ///
+    /// let mut vec = vec![1, 2, 3];
+    /// vec.try_insert(1, 4)?;
+    /// assert_eq!(vec, [1, 4, 2, 3]);
+    /// # Ok::<(), std::collections::TryReserveError>(())
+    /// ```
+    #[unstable(feature = "fallible_alloc", issue = "none")]
+    pub fn try_insert(&mut self, index: usize, element: T) -> Result<(), TryReserveError> {
+        // space for the new element
+        if self.len == self.buf.capacity() {
+            self.try_reserve(1)?;
+        }
+        self.insert_within_capacity(index, element);
+        Ok(())
+    }
+
+    /// Inserts an element at position `index`, for which the vector must
+    /// already have spare capacity.
+    fn insert_within_capacity(&mut self, index: usize, element: T) {
         #[cold]
         #[cfg_attr(not(feature = "panic_immediate_abort"), inline(never))]
         #[track_caller]
@@ -1498,11 +1600,7 @@ fn assert_failed(index: usize, len: usize) -> ! {
         }
 
         let len = self.len();
-
-        // space for the new element
-        if len == self.buf.capacity() {
-            self.reserve(1);
-        }
+        debug_assert!(len < self.buf.capacity());
 
         unsafe {
             // infallible
@@ -1970,6 +2068,43 @@ pub fn push_within_capacity(&mut self, value: T) -> Result<(), T> {
         Ok(())
     }
 
+    /// Appends an element to the back of a collection, returning an error
+    /// instead of aborting if the vector has to grow and the allocation fails.
+    ///
+    /// This is the fallible equivalent of [`Vec::push`]. Unlike
+    /// [`push_within_capacity`], it still grows the vector when it's full.
+    ///
+    /// [`push_within_capacity`]: Vec::push_within_capacity
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned and the vector is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fallible_alloc)]
+    ///
+    /// let mut vec = vec![1, 2];
+    /// vec.try_push(3)?;
+    /// assert_eq!(vec, [1, 2, 3]);
+    /// # Ok::<(), std::collections::TryReserveError>(())
+    /// ```
+    #[inline]
+    #[unstable(feature = "fallible_alloc", issue = "none")]
+    pub fn try_push(&mut self, value: T) -> Result<(), TryReserveError> {
+        if self.len == self.buf.capacity() {
+            self.try_reserve(1)?;
+        }
+        unsafe {
+            let end = self.as_mut_ptr().add(self.len);
+            ptr::write(end, value);
+            self.len += 1;
+        }
+        Ok(())
+    }
+
     /// Removes the last element from a vector and returns it, or [`None`] if it
     /// is empty.
     ///
@@ -2479,6 +2614,37 @@ pub fn extend_from_slice(&mut self, other: &[T]) {
         self.spec_extend(other.iter())
     }
 
+    /// Clones and appends all elements in a slice to the `Vec`, returning an
+    /// error instead of aborting if the vector has to grow and the allocation
+    /// fails.
+    ///
+    /// This is the fallible equivalent of [`Vec::extend_from_slice`].
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned and the vector is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fallible_alloc)]
+    ///
+    /// let mut vec = vec![1];
+    /// vec.try_extend_from_slice(&[2, 3, 4])?;
+    /// assert_eq!(vec, [1, 2, 3, 4]);
+    /// # Ok::<(), std::collections::TryReserveError>(())
+    /// ```
+    #[unstable(feature = "fallible_alloc", issue = "none")]
+    pub fn try_extend_from_slice(&mut self, other: &[T]) -> Result<(), TryReserveError> {
+        self.try_reserve(other.len())?;
+        for element in other {
+            // The capacity was reserved above, so this can't fail.
+            let _ = self.push_within_capacity(element.clone());
+        }
+        Ok(())
+    }
+
     /// Copies elements from `src` range to the end of the vector.
     ///
     /// # Panics
@@ -2614,6 +2780,80 @@ impl<T: PartialEq, A: Allocator> Vec<T, A> {
     pub fn dedup(&mut self) {
         self.dedup_by(|a, b| a == b)
     }
+
+    /// Replaces all non-overlapping occurrences of `from` in the vector with
+    /// `to`.
+    ///
+    /// This replaces the same occurrences as [`slice::replace_subslice`], but
+    /// does so in place: the vector only reallocates if `to` is longer than
+    /// `from` and the vector doesn't have enough spare capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_search)]
+    /// let mut v = b"a\r\nb\r\nc".to_vec();
+    ///
+    /// v.replace_subslice_in_place(b"\r\n", b"\n");
+    /// assert_eq!(v, b"a\nb\nc");
+    ///
+    /// v.replace_subslice_in_place(b"\n", b"\r\n");
+    /// assert_eq!(v, b"a\r\nb\r\nc");
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    #[unstable(feature = "slice_search", issue = "none")]
+    pub fn replace_subslice_in_place(&mut self, from: &[T], to: &[T])
+    where
+        T: Copy,
+    {
+        if from == to {
+            return;
+        }
+
+        if to.len() <= from.len() {
+            // Move the parts between the occurrences to the front, so that
+            // they are never overwritten before they were searched and moved.
+            let (mut read, mut write) = (0, 0);
+            while let Some(i) = self[read..].find_subslice(from) {
+                self.copy_within(read..read + i, write);
+                write += i;
+                self[write..write + to.len()].copy_from_slice(to);
+                write += to.len();
+                read += i + from.len();
+            }
+            let len = self.len();
+            self.copy_within(read..len, write);
+            self.truncate(write + len - read);
+        } else {
+            // Find all occurrences first, then grow the vector and move the
+            // parts between them to the back, starting with the last one.
+            let mut occurrences = Vec::new();
+            let mut end = 0;
+            for part in self.split_subslice(from) {
+                end += part.len();
+                occurrences.push(end);
+                end += from.len();
+            }
+            // The last part isn't followed by an occurrence.
+            occurrences.pop();
+
+            let old_len = self.len();
+            let additional =
+                (to.len() - from.len()).checked_mul(occurrences.len()).expect("capacity overflow");
+            self.resize(old_len.checked_add(additional).expect("capacity overflow"), to[0]);
+
+            let (mut read, mut write) = (old_len, self.len());
+            for &start in occurrences.iter().rev() {
+                let part = start + from.len()..read;
+                write -= part.len();
+                self.copy_within(part, write);
+                write -= to.len();
+                self[write..write + to.len()].copy_from_slice(to);
+                read = start;
+            }
+            debug_assert_eq!(read, write);
+        }
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -1218,6 +1218,20 @@ fn test_ends_with() {
     assert!(b"foobar".ends_with(empty));
 }
 
+#[test]
+fn test_replace_subslice() {
+    assert_eq!(b"a, b, c".replace_subslice(b", ", b","), b"a,b,c");
+    assert_eq!(b"a, b, c".replace_subslice(b", ", b" and "), b"a and b and c");
+    assert_eq!(b"a, b, c".replace_subslice(b"; ", b","), b"a, b, c");
+    assert_eq!(b"aaaaa".replace_subslice(b"aa", b"b"), b"bba");
+    assert_eq!(b"ab".replace_subslice(b"", b"-"), b"-a-b-");
+    assert_eq!([1, 0, 0, 2].replace_subslice(&[0, 0], &[]), [1, 2]);
+
+    assert_eq!(b"a, b, c".replacen_subslice(b", ", b",", 1), b"a,b, c");
+    assert_eq!(b"a, b, c".replacen_subslice(b", ", b",", 0), b"a, b, c");
+    assert_eq!(b"ab".replacen_subslice(b"", b"-", 2), b"-a-b");
+}
+
 #[test]
 fn test_mut_split_iterator() {
     let mut xs = [0, 1, 0, 2, 3, 0, 0, 4, 5, 0];
@@ -1869,6 +1869,131 @@ fn to_uppercase() {
     assert_eq!("aéǅßﬁᾀ".to_uppercase(), "AÉǄSSFIἈΙ");
 }
 
+#[test]
+fn graphemes() {
+    fn check(s: &str, expected: &[&str]) {
+        assert_eq!(s.graphemes().collect::<Vec<_>>(), expected);
+        let mut rev = s.graphemes().rev().collect::<Vec<_>>();
+        rev.reverse();
+        assert_eq!(rev, expected);
+    }
+
+    check("", &[]);
+    check("abc", &["a", "b", "c"]);
+    check("a\r\nb\n\r", &["a", "\r\n", "b", "\n", "\r"]);
+    // Combining marks and spacing marks
+    check("e\u{301}\u{323}x", &["e\u{301}\u{323}", "x"]);
+    check("\u{915}\u{93f}", &["\u{915}\u{93f}"]);
+    check("\u{301}a", &["\u{301}", "a"]);
+    check("\n\u{301}", &["\n", "\u{301}"]);
+    // Prepend
+    check("\u{600}1", &["\u{600}1"]);
+    // Hangul syllables, precomposed and as sequences of jamos
+    check("\u{d55c}\u{ae00}", &["\u{d55c}", "\u{ae00}"]);
+    check("\u{1112}\u{1161}\u{11ab}\u{1100}", &["\u{1112}\u{1161}\u{11ab}", "\u{1100}"]);
+    check("\u{ac00}\u{11a8}\u{ac01}\u{1161}", &["\u{ac00}\u{11a8}", "\u{ac01}", "\u{1161}"]);
+    // Emoji sequences
+    check(
+        "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}",
+        &["\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}"],
+    );
+    check("\u{1f44d}\u{1f3fd}\u{2764}\u{fe0f}", &["\u{1f44d}\u{1f3fd}", "\u{2764}\u{fe0f}"]);
+    check("\u{2764}\u{fe0f}\u{200d}\u{1f525}", &["\u{2764}\u{fe0f}\u{200d}\u{1f525}"]);
+    check("a\u{200d}\u{1f525}", &["a\u{200d}", "\u{1f525}"]);
+    // Regional indicators pair up
+    check("\u{1f1eb}\u{1f1f7}\u{1f1e9}", &["\u{1f1eb}\u{1f1f7}", "\u{1f1e9}"]);
+    check(
+        "\u{1f1eb}\u{1f1f7}\u{1f1e9}\u{1f1ea}\u{1f1ee}",
+        &["\u{1f1eb}\u{1f1f7}", "\u{1f1e9}\u{1f1ea}", "\u{1f1ee}"],
+    );
+    check("\u{1f1eb}\u{600}\u{1f1f7}", &["\u{1f1eb}", "\u{600}\u{1f1f7}"]);
+    check(
+        "\u{1f1eb}\u{1f1f7}\u{1f1e9}\u{301}\u{1f1ea}",
+        &["\u{1f1eb}\u{1f1f7}", "\u{1f1e9}\u{301}", "\u{1f1ea}"],
+    );
+    // A long run of regional indicators is only counted once from the back.
+    let flags = "\u{1f1eb}\u{1f1f7}".repeat(10_000) + "\u{1f1e9}";
+    let mut graphemes = flags.graphemes();
+    assert_eq!(graphemes.next_back(), Some("\u{1f1e9}"));
+    assert!(graphemes.rev().all(|flag| flag == "\u{1f1eb}\u{1f1f7}"));
+
+    // Both ends can be iterated at the same time.
+    let mut graphemes = "ab\u{1f1eb}\u{1f1f7}\u{1f1e9}\u{1f1ea}c\u{301}".graphemes();
+    assert_eq!(graphemes.next(), Some("a"));
+    assert_eq!(graphemes.next_back(), Some("c\u{301}"));
+    assert_eq!(graphemes.next_back(), Some("\u{1f1e9}\u{1f1ea}"));
+    assert_eq!(graphemes.next(), Some("b"));
+    assert_eq!(graphemes.next(), Some("\u{1f1eb}\u{1f1f7}"));
+    assert_eq!(graphemes.next(), None);
+    assert_eq!(graphemes.next_back(), None);
+}
+
+#[test]
+fn normalization() {
+    // (source, NFC, NFD, NFKC, NFKD)
+    let cases = [
+        ("", "", "", "", ""),
+        ("abc", "abc", "abc", "abc", "abc"),
+        ("\u{e9}", "\u{e9}", "e\u{301}", "\u{e9}", "e\u{301}"),
+        ("e\u{301}", "\u{e9}", "e\u{301}", "\u{e9}", "e\u{301}"),
+        // Combining marks are put into canonical order.
+        (
+            "\u{1e0b}\u{323}",
+            "\u{1e0d}\u{307}",
+            "d\u{323}\u{307}",
+            "\u{1e0d}\u{307}",
+            "d\u{323}\u{307}",
+        ),
+        (
+            "q\u{307}\u{323}",
+            "q\u{323}\u{307}",
+            "q\u{323}\u{307}",
+            "q\u{323}\u{307}",
+            "q\u{323}\u{307}",
+        ),
+        // Singletons and composition exclusions aren't recomposed.
+        ("\u{212b}", "\u{c5}", "A\u{30a}", "\u{c5}", "A\u{30a}"),
+        ("\u{958}", "\u{915}\u{93c}", "\u{915}\u{93c}", "\u{915}\u{93c}", "\u{915}\u{93c}"),
+        // Hangul syllables
+        (
+            "\u{d55c}",
+            "\u{d55c}",
+            "\u{1112}\u{1161}\u{11ab}",
+            "\u{d55c}",
+            "\u{1112}\u{1161}\u{11ab}",
+        ),
+        (
+            "\u{1100}\u{1161}\u{11a8}",
+            "\u{ac01}",
+            "\u{1100}\u{1161}\u{11a8}",
+            "\u{ac01}",
+            "\u{1100}\u{1161}\u{11a8}",
+        ),
+        // Compatibility decompositions
+        ("\u{fb01}", "\u{fb01}", "\u{fb01}", "fi", "fi"),
+        (
+            "\u{1e9b}\u{323}",
+            "\u{1e9b}\u{323}",
+            "\u{17f}\u{323}\u{307}",
+            "\u{1e69}",
+            "s\u{323}\u{307}",
+        ),
+        (
+            "\u{ff76}\u{ff9e}",
+            "\u{ff76}\u{ff9e}",
+            "\u{ff76}\u{ff9e}",
+            "\u{30ac}",
+            "\u{30ab}\u{3099}",
+        ),
+    ];
+    for (source, nfc, nfd, nfkc, nfkd) in cases {
+        assert_eq!(source.to_nfc(), nfc, "{source:?}");
+        assert_eq!(source.to_nfd(), nfd, "{source:?}");
+        assert_eq!(source.to_nfkc(), nfkc, "{source:?}");
+        assert_eq!(source.to_nfkd(), nfkd, "{source:?}");
+    }
+}
+
 #[test]
 fn test_into_string() {
     // The only way to acquire a Box<str> in the first place is through a String, so just
@@ -855,6 +855,21 @@ fn test_try_reserve_exact() {
     }
 }
 
+#[test]
+fn test_try_push() {
+    let mut s = String::try_with_capacity(1).unwrap();
+    assert!(s.capacity() >= 1);
+    s.try_push('a').unwrap();
+    s.try_push('é').unwrap();
+    s.try_push_str("bc").unwrap();
+    assert_eq!(s, "aébc");
+
+    assert_matches!(
+        String::try_with_capacity(usize::MAX).map_err(|e| e.kind()),
+        Err(CapacityOverflow)
+    );
+}
+
 #[test]
 fn test_from_char() {
     assert_eq!(String::from('a'), 'a'.to_string());
@@ -405,6 +405,28 @@ fn test_retain_maybeuninits() {
     assert_eq!(vec, [2, 4]);
 }
 
+#[test]
+fn test_replace_subslice_in_place() {
+    fn case(v: &[u8], from: &[u8], to: &[u8]) {
+        let mut vec = v.to_vec();
+        vec.replace_subslice_in_place(from, to);
+        assert_eq!(vec, v.replace_subslice(from, to));
+    }
+    for v in [&b""[..], b"a", b"aa", b"aaa", b"abcab", b"ab, cd, , ef"] {
+        for from in [&b""[..], b"a", b"aa", b"ab", b", ", b"x"] {
+            for to in [&b""[..], b"-", b"--", b"---"] {
+                case(v, from, to);
+            }
+        }
+    }
+
+    let mut v = vec![1, 0, 2, 0, 3];
+    v.replace_subslice_in_place(&[0], &[4, 4]);
+    assert_eq!(v, [1, 4, 4, 2, 4, 4, 3]);
+    v.replace_subslice_in_place(&[4, 4], &[]);
+    assert_eq!(v, [1, 2, 3]);
+}
+
 #[test]
 fn test_dedup() {
     fn case(a: Vec<i32>, b: Vec<i32>) {
@@ -1864,6 +1886,32 @@ fn test_try_reserve_exact() {
     }
 }
 
+#[test]
+fn test_try_push_insert_extend() {
+    let mut vec: Vec<u32> = Vec::try_with_capacity(2).unwrap();
+    assert!(vec.capacity() >= 2);
+    vec.try_push(1).unwrap();
+    vec.try_push(3).unwrap();
+    vec.try_push(4).unwrap();
+    vec.try_insert(1, 2).unwrap();
+    vec.try_insert(4, 5).unwrap();
+    vec.try_extend_from_slice(&[6, 7]).unwrap();
+    assert_eq!(vec, [1, 2, 3, 4, 5, 6, 7]);
+
+    assert_matches!(
+        Vec::<u32>::try_with_capacity(usize::MAX).map_err(|e| e.kind()),
+        Err(CapacityOverflow)
+    );
+
+    // Failing to grow leaves the vector unchanged.
+    let mut zsts = vec![(); 0];
+    unsafe { zsts.set_len(usize::MAX) };
+    assert_matches!(zsts.try_push(()).map_err(|e| e.kind()), Err(CapacityOverflow));
+    assert_matches!(zsts.try_insert(0, ()).map_err(|e| e.kind()), Err(CapacityOverflow));
+    assert_matches!(zsts.try_extend_from_slice(&[()]).map_err(|e| e.kind()), Err(CapacityOverflow));
+    assert_eq!(zsts.len(), usize::MAX);
+}
+
 #[test]
 fn test_stable_pointers() {
     /// Pull an element from the iterator, then drop it.
@@ -1368,6 +1368,22 @@ fn test_try_reserve_exact() {
     }
 }
 
+#[test]
+fn test_try_push() {
+    let mut d: VecDeque<u32> = VecDeque::try_with_capacity(1).unwrap();
+    assert!(d.capacity() >= 1);
+    for i in 0..10 {
+        d.try_push_back(i).unwrap();
+        d.try_push_front(i).unwrap();
+    }
+    assert!(d.iter().copied().eq((0..10).rev().chain(0..10)));
+
+    assert_matches!(
+        VecDeque::<u32>::try_with_capacity(usize::MAX).map_err(|e| e.kind()),
+        Err(CapacityOverflow)
+    );
+}
+
 #[test]
 fn test_rotate_nop() {
     let mut v: VecDeque<_> = (0..10).collect();
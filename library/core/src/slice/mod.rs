@@ -38,6 +38,7 @@
 mod iter;
 mod raw;
 mod rotate;
+mod search;
 mod select;
 mod specialize;
 
@@ -95,6 +96,11 @@
 #[stable(feature = "inherent_ascii_escape", since = "1.60.0")]
 pub use ascii::EscapeAscii;
 
+#[unstable(feature = "slice_search", issue = "none")]
+pub use search::{RSplitNSubslice, RSplitSubslice, SplitNSubslice, SplitSubslice};
+
+use search::SubsliceSearcher;
+
 /// Calculates the direction and split point of a one-sided range.
 ///
 /// This is a helper function for `take` and `take_mut` that returns
@@ -2703,6 +2709,324 @@ pub fn strip_suffix<P: SlicePattern<Item = T> + ?Sized>(&self, suffix: &P) -> Op
         None
     }
 
+    /// Returns the index of the first occurrence of `needle` in the slice.
+    ///
+    /// Returns [`None`] if `needle` doesn't occur in the slice. An empty
+    /// `needle` occurs at the start of every slice.
+    ///
+    /// Byte slices are searched in linear time with the same algorithm as
+    /// [`str::find`], slices of other types in *O*(*n* \* *m*) time, where
+    /// *m* is the length of `needle`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_search)]
+    /// let v = b"GET /index.html HTTP/1.1";
+    /// assert_eq!(v.find_subslice(b" "), Some(3));
+    /// assert_eq!(v.find_subslice(b"HTTP"), Some(16));
+    /// assert_eq!(v.find_subslice(b"POST"), None);
+    ///
+    /// let v = [10, 40, 30, 40, 30];
+    /// assert_eq!(v.find_subslice(&[40, 30]), Some(1));
+    /// ```
+    #[unstable(feature = "slice_search", issue = "none")]
+    #[must_use]
+    pub fn find_subslice(&self, needle: &[T]) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        SubsliceSearcher::new(self, needle).next_match().map(|(start, _)| start)
+    }
+
+    /// Returns the index of the last occurrence of `needle` in the slice.
+    ///
+    /// Returns [`None`] if `needle` doesn't occur in the slice. An empty
+    /// `needle` occurs at the end of every slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_search)]
+    /// let v = b"a/b/c";
+    /// assert_eq!(v.rfind_subslice(b"/"), Some(3));
+    /// assert_eq!(v.rfind_subslice(b"//"), None);
+    ///
+    /// let v = [10, 40, 30, 40, 30];
+    /// assert_eq!(v.rfind_subslice(&[40, 30]), Some(3));
+    /// ```
+    #[unstable(feature = "slice_search", issue = "none")]
+    #[must_use]
+    pub fn rfind_subslice(&self, needle: &[T]) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        SubsliceSearcher::new(self, needle).next_match_back().map(|(start, _)| start)
+    }
+
+    /// Returns `true` if `needle` occurs in the slice.
+    ///
+    /// This is the subslice counterpart of [`contains`], which looks for a
+    /// single element.
+    ///
+    /// [`contains`]: slice::contains
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_search)]
+    /// let v = b"Content-Type: text/plain";
+    /// assert!(v.contains_subslice(b"text"));
+    /// assert!(!v.contains_subslice(b"html"));
+    /// assert!(v.contains_subslice(b""));
+    /// ```
+    #[unstable(feature = "slice_search", issue = "none")]
+    #[must_use]
+    pub fn contains_subslice(&self, needle: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        self.find_subslice(needle).is_some()
+    }
+
+    /// Returns an iterator over the parts of the slice separated by the
+    /// non-overlapping occurrences of `separator`, which are not included.
+    ///
+    /// If `separator` occurs at the start or the end of the slice, the
+    /// iterator returns an empty slice first or last. An empty `separator`
+    /// separates every element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_search)]
+    /// let v = b"a\r\nb\r\n\r\nc";
+    /// let mut iter = v.split_subslice(b"\r\n");
+    ///
+    /// assert_eq!(iter.next().unwrap(), b"a");
+    /// assert_eq!(iter.next().unwrap(), b"b");
+    /// assert_eq!(iter.next().unwrap(), b"");
+    /// assert_eq!(iter.next().unwrap(), b"c");
+    /// assert!(iter.next().is_none());
+    /// ```
+    ///
+    /// An empty separator:
+    ///
+    /// ```
+    /// #![feature(slice_search)]
+    /// let v = [10, 40];
+    /// let parts: Vec<&[i32]> = v.split_subslice(&[]).collect();
+    /// assert_eq!(parts, [&[][..], &[10], &[40], &[]]);
+    /// ```
+    #[unstable(feature = "slice_search", issue = "none")]
+    #[inline]
+    pub fn split_subslice<'b>(&self, separator: &'b [T]) -> SplitSubslice<'_, 'b, T>
+    where
+        T: PartialEq,
+    {
+        SplitSubslice::new(self, separator)
+    }
+
+    /// Returns an iterator over the parts of the slice separated by the
+    /// non-overlapping occurrences of `separator`, starting at the end of the
+    /// slice and working backwards.
+    ///
+    /// If `separator` can overlap with itself, the occurrences found from the
+    /// back may differ from the ones found by [`split_subslice`].
+    ///
+    /// [`split_subslice`]: slice::split_subslice
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_search)]
+    /// let v = b"usr::local::bin";
+    /// let mut iter = v.rsplit_subslice(b"::");
+    ///
+    /// assert_eq!(iter.next().unwrap(), b"bin");
+    /// assert_eq!(iter.next().unwrap(), b"local");
+    /// assert_eq!(iter.next().unwrap(), b"usr");
+    /// assert!(iter.next().is_none());
+    /// ```
+    #[unstable(feature = "slice_search", issue = "none")]
+    #[inline]
+    pub fn rsplit_subslice<'b>(&self, separator: &'b [T]) -> RSplitSubslice<'_, 'b, T>
+    where
+        T: PartialEq,
+    {
+        RSplitSubslice::new(self, separator)
+    }
+
+    /// Returns an iterator over the parts of the slice separated by the
+    /// non-overlapping occurrences of `separator`, limited to returning at
+    /// most `n` parts.
+    ///
+    /// The last part returned, if any, contains the remainder of the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_search)]
+    /// let v = b"Host: example.com: 8080";
+    /// let mut iter = v.splitn_subslice(2, b": ");
+    ///
+    /// assert_eq!(iter.next().unwrap(), b"Host");
+    /// assert_eq!(iter.next().unwrap(), b"example.com: 8080");
+    /// assert!(iter.next().is_none());
+    /// ```
+    #[unstable(feature = "slice_search", issue = "none")]
+    #[inline]
+    pub fn splitn_subslice<'b>(&self, n: usize, separator: &'b [T]) -> SplitNSubslice<'_, 'b, T>
+    where
+        T: PartialEq,
+    {
+        SplitNSubslice::new(self, separator, n)
+    }
+
+    /// Returns an iterator over the parts of the slice separated by the
+    /// non-overlapping occurrences of `separator`, limited to returning at
+    /// most `n` parts, starting at the end of the slice and working
+    /// backwards.
+    ///
+    /// The last part returned, if any, contains the remainder of the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_search)]
+    /// let v = b"archive.tar.gz";
+    /// let mut iter = v.rsplitn_subslice(2, b".");
+    ///
+    /// assert_eq!(iter.next().unwrap(), b"gz");
+    /// assert_eq!(iter.next().unwrap(), b"archive.tar");
+    /// assert!(iter.next().is_none());
+    /// ```
+    #[unstable(feature = "slice_search", issue = "none")]
+    #[inline]
+    pub fn rsplitn_subslice<'b>(&self, n: usize, separator: &'b [T]) -> RSplitNSubslice<'_, 'b, T>
+    where
+        T: PartialEq,
+    {
+        RSplitNSubslice::new(self, separator, n)
+    }
+
+    /// Splits the slice on the first occurrence of `separator`.
+    ///
+    /// Returns the parts before and after the separator, or [`None`] if
+    /// `separator` doesn't occur in the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_search)]
+    /// let v = b"Accept: text/html";
+    /// assert_eq!(v.split_once_subslice(b": "), Some((&b"Accept"[..], &b"text/html"[..])));
+    /// assert_eq!(v.split_once_subslice(b"; "), None);
+    /// ```
+    #[unstable(feature = "slice_search", issue = "none")]
+    #[inline]
+    pub fn split_once_subslice(&self, separator: &[T]) -> Option<(&[T], &[T])>
+    where
+        T: PartialEq,
+    {
+        let start = self.find_subslice(separator)?;
+        Some((&self[..start], &self[start + separator.len()..]))
+    }
+
+    /// Splits the slice on the last occurrence of `separator`.
+    ///
+    /// Returns the parts before and after the separator, or [`None`] if
+    /// `separator` doesn't occur in the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_search)]
+    /// let v = b"archive.tar.gz";
+    /// assert_eq!(v.rsplit_once_subslice(b"."), Some((&b"archive.tar"[..], &b"gz"[..])));
+    /// assert_eq!(v.rsplit_once_subslice(b"/"), None);
+    /// ```
+    #[unstable(feature = "slice_search", issue = "none")]
+    #[inline]
+    pub fn rsplit_once_subslice(&self, separator: &[T]) -> Option<(&[T], &[T])>
+    where
+        T: PartialEq,
+    {
+        let start = self.rfind_subslice(separator)?;
+        Some((&self[..start], &self[start + separator.len()..]))
+    }
+
+    /// Returns a subslice with all prefixes that are equal to `needle`
+    /// repeatedly removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_search)]
+    /// let v = b"\r\n\r\nbody\r\n";
+    /// assert_eq!(v.trim_start_matches_subslice(b"\r\n"), b"body\r\n");
+    /// assert_eq!(v.trim_start_matches_subslice(b"\n"), v);
+    /// ```
+    #[unstable(feature = "slice_search", issue = "none")]
+    #[must_use = "returns the subslice without modifying the original"]
+    pub fn trim_start_matches_subslice(&self, needle: &[T]) -> &[T]
+    where
+        T: PartialEq,
+    {
+        let mut trimmed = self;
+        if !needle.is_empty() {
+            while let Some(rest) = trimmed.strip_prefix(needle) {
+                trimmed = rest;
+            }
+        }
+        trimmed
+    }
+
+    /// Returns a subslice with all suffixes that are equal to `needle`
+    /// repeatedly removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_search)]
+    /// let v = b"\r\nbody\r\n\r\n";
+    /// assert_eq!(v.trim_end_matches_subslice(b"\r\n"), b"\r\nbody");
+    /// assert_eq!(v.trim_end_matches_subslice(b"\r"), v);
+    /// ```
+    #[unstable(feature = "slice_search", issue = "none")]
+    #[must_use = "returns the subslice without modifying the original"]
+    pub fn trim_end_matches_subslice(&self, needle: &[T]) -> &[T]
+    where
+        T: PartialEq,
+    {
+        let mut trimmed = self;
+        if !needle.is_empty() {
+            while let Some(rest) = trimmed.strip_suffix(needle) {
+                trimmed = rest;
+            }
+        }
+        trimmed
+    }
+
+    /// Returns a subslice with all prefixes and suffixes that are equal to
+    /// `needle` repeatedly removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_search)]
+    /// let v = [0, 0, 1, 0, 2, 0, 0, 0];
+    /// assert_eq!(v.trim_matches_subslice(&[0, 0]), &[1, 0, 2, 0]);
+    /// ```
+    #[unstable(feature = "slice_search", issue = "none")]
+    #[must_use = "returns the subslice without modifying the original"]
+    pub fn trim_matches_subslice(&self, needle: &[T]) -> &[T]
+    where
+        T: PartialEq,
+    {
+        self.trim_start_matches_subslice(needle).trim_end_matches_subslice(needle)
+    }
+
     /// Binary searches this slice for a given element.
     /// If the slice is not sorted, the returned result is unspecified and
     /// meaningless.
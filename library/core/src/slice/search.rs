@@ -0,0 +1,355 @@
+//! Searching for subslices of a slice.
+//!
+//! Byte slices are searched with the Two-Way algorithm that is also used to
+//! search for substrings, slices of other types with a naive search.
+
+use crate::fmt;
+use crate::iter::FusedIterator;
+use crate::str::pattern::TwoWaySearcher;
+
+/// Finds the non-overlapping occurrences of a needle in a haystack.
+///
+/// The matches are found either from the front or from the back, and both
+/// directions are independent of each other: if the needle can overlap with
+/// itself, they don't necessarily find the same matches.
+pub(super) struct SubsliceSearcher<'a, 'b, T> {
+    haystack: &'a [T],
+    needle: &'b [T],
+    searcher: SearcherImpl<'a, 'b>,
+}
+
+#[derive(Clone, Debug)]
+enum SearcherImpl<'a, 'b> {
+    /// The empty needle matches at every position, including the end.
+    Empty {
+        position: Option<usize>,
+        end: Option<usize>,
+    },
+    TwoWay {
+        searcher: TwoWaySearcher,
+        haystack: &'a [u8],
+        needle: &'b [u8],
+    },
+    Naive {
+        position: usize,
+        end: usize,
+    },
+}
+
+/// Gives the searcher access to byte slices, which it can search more efficiently.
+trait SpecSubsliceSearch: Sized {
+    fn as_bytes(slice: &[Self]) -> Option<&[u8]>;
+}
+
+impl<T: PartialEq> SpecSubsliceSearch for T {
+    #[inline]
+    default fn as_bytes(_: &[T]) -> Option<&[u8]> {
+        None
+    }
+}
+
+impl SpecSubsliceSearch for u8 {
+    #[inline]
+    fn as_bytes(slice: &[u8]) -> Option<&[u8]> {
+        Some(slice)
+    }
+}
+
+impl<'a, 'b, T: PartialEq> SubsliceSearcher<'a, 'b, T> {
+    pub(super) fn new(haystack: &'a [T], needle: &'b [T]) -> Self {
+        let searcher = if needle.is_empty() {
+            SearcherImpl::Empty { position: Some(0), end: Some(haystack.len()) }
+        } else if let (Some(haystack), Some(needle)) = (T::as_bytes(haystack), T::as_bytes(needle))
+        {
+            let searcher = TwoWaySearcher::new(needle, haystack.len());
+            SearcherImpl::TwoWay { searcher, haystack, needle }
+        } else {
+            SearcherImpl::Naive { position: 0, end: haystack.len() }
+        };
+        SubsliceSearcher { haystack, needle, searcher }
+    }
+
+    /// Returns the start and end of the next match from the front.
+    pub(super) fn next_match(&mut self) -> Option<(usize, usize)> {
+        match self.searcher {
+            SearcherImpl::Empty { ref mut position, .. } => {
+                let pos = (*position)?;
+                *position = if pos < self.haystack.len() { Some(pos + 1) } else { None };
+                Some((pos, pos))
+            }
+            SearcherImpl::TwoWay { ref mut searcher, haystack, needle } => {
+                searcher.next_match(haystack, needle)
+            }
+            SearcherImpl::Naive { ref mut position, .. } => {
+                let len = self.needle.len();
+                let mut windows = self.haystack[*position..].windows(len);
+                match windows.position(|window| window == self.needle) {
+                    Some(i) => {
+                        let start = *position + i;
+                        *position = start + len;
+                        Some((start, start + len))
+                    }
+                    None => {
+                        *position = self.haystack.len();
+                        None
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the start and end of the next match from the back.
+    pub(super) fn next_match_back(&mut self) -> Option<(usize, usize)> {
+        match self.searcher {
+            SearcherImpl::Empty { ref mut end, .. } => {
+                let pos = (*end)?;
+                *end = pos.checked_sub(1);
+                Some((pos, pos))
+            }
+            SearcherImpl::TwoWay { ref mut searcher, haystack, needle } => {
+                searcher.next_match_back(haystack, needle)
+            }
+            SearcherImpl::Naive { ref mut end, .. } => {
+                let len = self.needle.len();
+                let mut windows = self.haystack[..*end].windows(len);
+                match windows.rposition(|window| window == self.needle) {
+                    Some(start) => {
+                        *end = start;
+                        Some((start, start + len))
+                    }
+                    None => {
+                        *end = 0;
+                        None
+                    }
+                }
+            }
+        }
+    }
+}
+
+// FIXME(#26925) Remove in favor of `#[derive(Clone)]`
+impl<T> Clone for SubsliceSearcher<'_, '_, T> {
+    fn clone(&self) -> Self {
+        SubsliceSearcher {
+            haystack: self.haystack,
+            needle: self.needle,
+            searcher: self.searcher.clone(),
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for SubsliceSearcher<'_, '_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SubsliceSearcher")
+            .field("haystack", &self.haystack)
+            .field("needle", &self.needle)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The state shared by the iterators over the parts of a slice separated by
+/// a subslice.
+struct SplitInternal<'a, 'b, T> {
+    start: usize,
+    end: usize,
+    searcher: SubsliceSearcher<'a, 'b, T>,
+    finished: bool,
+}
+
+impl<'a, 'b, T: PartialEq> SplitInternal<'a, 'b, T> {
+    fn new(slice: &'a [T], separator: &'b [T]) -> Self {
+        let searcher = SubsliceSearcher::new(slice, separator);
+        SplitInternal { start: 0, end: slice.len(), searcher, finished: false }
+    }
+
+    #[inline]
+    fn finish(&mut self) -> Option<&'a [T]> {
+        if self.finished {
+            return None;
+        }
+        self.finished = true;
+        Some(&self.searcher.haystack[self.start..self.end])
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a [T]> {
+        if self.finished {
+            return None;
+        }
+        match self.searcher.next_match() {
+            Some((a, b)) => {
+                let part = &self.searcher.haystack[self.start..a];
+                self.start = b;
+                Some(part)
+            }
+            None => self.finish(),
+        }
+    }
+
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a [T]> {
+        if self.finished {
+            return None;
+        }
+        match self.searcher.next_match_back() {
+            Some((a, b)) => {
+                let part = &self.searcher.haystack[b..self.end];
+                self.end = a;
+                Some(part)
+            }
+            None => self.finish(),
+        }
+    }
+}
+
+// FIXME(#26925) Remove in favor of `#[derive(Clone)]`
+impl<T> Clone for SplitInternal<'_, '_, T> {
+    fn clone(&self) -> Self {
+        SplitInternal {
+            start: self.start,
+            end: self.end,
+            searcher: self.searcher.clone(),
+            finished: self.finished,
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for SplitInternal<'_, '_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SplitInternal")
+            .field("start", &self.start)
+            .field("end", &self.end)
+            .field("searcher", &self.searcher)
+            .field("finished", &self.finished)
+            .finish()
+    }
+}
+
+macro_rules! split_subslice_iterators {
+    ($(
+        $(#[$attr:meta])*
+        struct $name:ident { $($count:ident)? } => $next:ident;
+    )*) => {$(
+        $(#[$attr])*
+        #[unstable(feature = "slice_search", issue = "none")]
+        #[must_use = "iterators are lazy and do nothing unless consumed"]
+        pub struct $name<'a, 'b, T: 'a> {
+            inner: SplitInternal<'a, 'b, T>,
+            $($count: usize,)?
+        }
+
+        impl<'a, 'b, T: PartialEq> $name<'a, 'b, T> {
+            #[inline]
+            pub(super) fn new(slice: &'a [T], separator: &'b [T], $($count: usize)?) -> Self {
+                $name { inner: SplitInternal::new(slice, separator), $($count)? }
+            }
+        }
+
+        #[unstable(feature = "slice_search", issue = "none")]
+        impl<'a, 'b, T: PartialEq> Iterator for $name<'a, 'b, T> {
+            type Item = &'a [T];
+
+            #[inline]
+            fn next(&mut self) -> Option<&'a [T]> {
+                $(
+                    match self.$count {
+                        0 => return None,
+                        1 => {
+                            self.$count = 0;
+                            return self.inner.finish();
+                        }
+                        _ => self.$count -= 1,
+                    }
+                )?
+                self.inner.$next()
+            }
+        }
+
+        #[unstable(feature = "slice_search", issue = "none")]
+        impl<T: PartialEq> FusedIterator for $name<'_, '_, T> {}
+
+        // FIXME(#26925) Remove in favor of `#[derive(Clone)]`
+        #[unstable(feature = "slice_search", issue = "none")]
+        impl<T> Clone for $name<'_, '_, T> {
+            fn clone(&self) -> Self {
+                $name { inner: self.inner.clone(), $($count: self.$count)? }
+            }
+        }
+
+        #[unstable(feature = "slice_search", issue = "none")]
+        impl<T: fmt::Debug> fmt::Debug for $name<'_, '_, T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_struct(stringify!($name)).field("inner", &self.inner).finish()
+            }
+        }
+    )*};
+}
+
+split_subslice_iterators! {
+    /// An iterator over the parts of a slice separated by a subslice.
+    ///
+    /// This struct is created by the [`split_subslice`] method on [slices].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #![feature(slice_search)]
+    /// let slice = b"key: value";
+    /// let iter = slice.split_subslice(b": ");
+    /// ```
+    ///
+    /// [`split_subslice`]: slice::split_subslice
+    /// [slices]: slice
+    struct SplitSubslice {} => next;
+
+    /// An iterator over the parts of a slice separated by a subslice, starting
+    /// from the end of the slice.
+    ///
+    /// This struct is created by the [`rsplit_subslice`] method on [slices].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #![feature(slice_search)]
+    /// let slice = b"key: value";
+    /// let iter = slice.rsplit_subslice(b": ");
+    /// ```
+    ///
+    /// [`rsplit_subslice`]: slice::rsplit_subslice
+    /// [slices]: slice
+    struct RSplitSubslice {} => next_back;
+
+    /// An iterator over the parts of a slice separated by a subslice, limited
+    /// to a given number of parts.
+    ///
+    /// This struct is created by the [`splitn_subslice`] method on [slices].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #![feature(slice_search)]
+    /// let slice = b"a, b, c";
+    /// let iter = slice.splitn_subslice(2, b", ");
+    /// ```
+    ///
+    /// [`splitn_subslice`]: slice::splitn_subslice
+    /// [slices]: slice
+    struct SplitNSubslice { count } => next;
+
+    /// An iterator over the parts of a slice separated by a subslice, limited
+    /// to a given number of parts, starting from the end of the slice.
+    ///
+    /// This struct is created by the [`rsplitn_subslice`] method on [slices].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #![feature(slice_search)]
+    /// let slice = b"a, b, c";
+    /// let iter = slice.rsplitn_subslice(2, b", ");
+    /// ```
+    ///
+    /// [`rsplitn_subslice`]: slice::rsplitn_subslice
+    /// [slices]: slice
+    struct RSplitNSubslice { count } => next_back;
+}
@@ -0,0 +1,133 @@
+//! Iteration over the extended grapheme clusters of a string, as described in
+//! [Unicode Standard Annex #29](https://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundaries).
+
+use crate::iter::FusedIterator;
+use crate::unicode::{Extended_Pictographic, GraphemeClusterBreak, Grapheme_Cluster_Break};
+
+/// An iterator over the extended grapheme clusters of a string slice.
+///
+/// This struct is created by the [`graphemes`] method on [`str`].
+/// See its documentation for more.
+///
+/// [`graphemes`]: str::graphemes
+#[derive(Clone, Debug)]
+#[unstable(feature = "str_graphemes", issue = "none")]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Graphemes<'a> {
+    remainder: &'a str,
+    /// Whether the regional indicators at the end of `remainder`, if any, are known to form
+    /// pairs, so that [`next_back`](DoubleEndedIterator::next_back) doesn't count them again.
+    back_ri_paired: bool,
+}
+
+impl<'a> Graphemes<'a> {
+    #[inline]
+    pub(super) fn new(s: &'a str) -> Self {
+        Graphemes { remainder: s, back_ri_paired: false }
+    }
+}
+
+/// Returns whether there is a grapheme cluster boundary between the end of
+/// `before` and `after`.
+///
+/// Some rules look back at several characters, so `before` has to start at a
+/// boundary.
+fn is_boundary(before: &str, after: char) -> bool {
+    use GraphemeClusterBreak::*;
+
+    let mut chars = before.chars();
+    let Some(prev) = chars.next_back() else { return true };
+    if prev.is_ascii() && after.is_ascii() {
+        // Among ASCII characters, only CR LF forms a cluster of several characters.
+        return !(prev == '\r' && after == '\n');
+    }
+    match (Grapheme_Cluster_Break(prev), Grapheme_Cluster_Break(after)) {
+        // GB3, GB4 and GB5
+        (Cr, Lf) => false,
+        (Control | Cr | Lf, _) | (_, Control | Cr | Lf) => true,
+        // GB6, GB7 and GB8: Hangul syllable sequences
+        (L, L | V | Lv | Lvt) | (Lv | V, V | T) | (Lvt | T, T) => false,
+        // GB9, GB9a and GB9b
+        (_, Extend | Zwj | SpacingMark) | (Prepend, _) => false,
+        // GB11: emoji ZWJ sequences
+        (Zwj, _) if Extended_Pictographic(after) => !chars
+            .rev()
+            .find(|&c| Grapheme_Cluster_Break(c) != Extend)
+            .is_some_and(Extended_Pictographic),
+        // GB12 and GB13: regional indicators form pairs
+        (RegionalIndicator, RegionalIndicator) => {
+            let preceding =
+                chars.rev().take_while(|&c| Grapheme_Cluster_Break(c) == RegionalIndicator);
+            preceding.count() % 2 == 1
+        }
+        // GB999
+        _ => true,
+    }
+}
+
+#[unstable(feature = "str_graphemes", issue = "none")]
+impl<'a> Iterator for Graphemes<'a> {
+    type Item = &'a str;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a str> {
+        let s = self.remainder;
+        let mut chars = s.char_indices();
+        chars.next()?;
+        let end = chars.find(|&(i, c)| is_boundary(&s[..i], c)).map_or(s.len(), |(i, _)| i);
+        let (grapheme, remainder) = s.split_at(end);
+        self.remainder = remainder;
+        Some(grapheme)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // The whole string may be a single cluster, or every byte may be one.
+        (usize::from(!self.remainder.is_empty()), Some(self.remainder.len()))
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<&'a str> {
+        self.next_back()
+    }
+}
+
+#[unstable(feature = "str_graphemes", issue = "none")]
+impl<'a> DoubleEndedIterator for Graphemes<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a str> {
+        let is_ri = |c| Grapheme_Cluster_Break(c) == GraphemeClusterBreak::RegionalIndicator;
+
+        let s = self.remainder;
+        let mut chars = s.char_indices().rev();
+        let (mut start, mut c) = chars.next()?;
+        let mut known_boundary = false;
+        if is_ri(c) {
+            // Regional indicators pair up from the start of their run, so its length tells
+            // whether the last one is paired.
+            let paired =
+                self.back_ri_paired || s.chars().rev().take_while(|&c| is_ri(c)).count() % 2 == 0;
+            if paired {
+                (start, c) = chars.next().unwrap();
+            }
+            // The regional indicators before form pairs of their own.
+            known_boundary = chars.clone().next().is_some_and(|(_, prev)| is_ri(prev));
+        }
+
+        // The other rules only look back within the cluster. There is a boundary at the start
+        // of `s`, so this stops there at the latest.
+        while !known_boundary && !is_boundary(&s[..start], c) {
+            (start, c) = chars.next().unwrap();
+        }
+        // A cluster that starts with a regional indicator starts with a pair, or with the last
+        // one of an odd run, so the regional indicators left before it pair up. This way, a run
+        // is only counted once.
+        self.back_ri_paired = is_ri(c);
+        let (remainder, grapheme) = s.split_at(start);
+        self.remainder = remainder;
+        Some(grapheme)
+    }
+}
+
+#[unstable(feature = "str_graphemes", issue = "none")]
+impl FusedIterator for Graphemes<'_> {}
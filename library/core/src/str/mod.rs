@@ -9,6 +9,7 @@
 mod converts;
 mod count;
 mod error;
+mod graphemes;
 mod iter;
 mod traits;
 mod validations;
@@ -70,6 +71,9 @@
 #[stable(feature = "split_inclusive", since = "1.51.0")]
 pub use iter::SplitInclusive;
 
+#[unstable(feature = "str_graphemes", issue = "none")]
+pub use graphemes::Graphemes;
+
 #[unstable(feature = "str_internals", issue = "none")]
 pub use validations::{next_code_point, utf8_char_width};
 
@@ -826,6 +830,34 @@ pub fn char_indices(&self) -> CharIndices<'_> {
         CharIndices { front_offset: 0, iter: self.chars() }
     }
 
+    /// Returns an iterator over the extended grapheme clusters of a string slice.
+    ///
+    /// A grapheme cluster is closer to what a user perceives as a character
+    /// than a [`char`]: for example, a letter followed by combining accents,
+    /// a Hangul syllable spelled with jamos or an emoji sequence are each a
+    /// single cluster made of several [`char`]s. The boundaries between the
+    /// clusters are the default ones defined by [Unicode Standard Annex #29].
+    ///
+    /// [Unicode Standard Annex #29]: https://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundaries
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_graphemes)]
+    /// let s = "cafe\u{301} \u{1F1EB}\u{1F1F7}\r\n";
+    ///
+    /// let graphemes: Vec<&str> = s.graphemes().collect();
+    /// assert_eq!(graphemes, ["c", "a", "f", "e\u{301}", " ", "\u{1F1EB}\u{1F1F7}", "\r\n"]);
+    ///
+    /// // The clusters can also be iterated from the end.
+    /// assert_eq!(s.graphemes().rev().nth(1), Some("\u{1F1EB}\u{1F1F7}"));
+    /// ```
+    #[unstable(feature = "str_graphemes", issue = "none")]
+    #[inline]
+    pub fn graphemes(&self) -> Graphemes<'_> {
+        Graphemes::new(self)
+    }
+
     /// An iterator over the bytes of a string slice.
     ///
     /// As a string slice consists of a sequence of bytes, we can iterate
@@ -1132,22 +1132,7 @@ fn next_match(&mut self) -> Option<(usize, usize)> {
                 }
             },
             StrSearcherImpl::TwoWay(ref mut searcher) => {
-                let is_long = searcher.memory == usize::MAX;
-                // write out `true` and `false` cases to encourage the compiler
-                // to specialize the two cases separately.
-                if is_long {
-                    searcher.next::<MatchOnly>(
-                        self.haystack.as_bytes(),
-                        self.needle.as_bytes(),
-                        true,
-                    )
-                } else {
-                    searcher.next::<MatchOnly>(
-                        self.haystack.as_bytes(),
-                        self.needle.as_bytes(),
-                        false,
-                    )
-                }
+                searcher.next_match(self.haystack.as_bytes(), self.needle.as_bytes())
             }
         }
     }
@@ -1211,29 +1196,17 @@ fn next_match_back(&mut self) -> Option<(usize, usize)> {
                 }
             },
             StrSearcherImpl::TwoWay(ref mut searcher) => {
-                let is_long = searcher.memory == usize::MAX;
-                // write out `true` and `false`, like `next_match`
-                if is_long {
-                    searcher.next_back::<MatchOnly>(
-                        self.haystack.as_bytes(),
-                        self.needle.as_bytes(),
-                        true,
-                    )
-                } else {
-                    searcher.next_back::<MatchOnly>(
-                        self.haystack.as_bytes(),
-                        self.needle.as_bytes(),
-                        false,
-                    )
-                }
+                searcher.next_match_back(self.haystack.as_bytes(), self.needle.as_bytes())
             }
         }
     }
 }
 
 /// The internal state of the two-way substring search algorithm.
+///
+/// It is also used to search for subslices of byte slices.
 #[derive(Clone, Debug)]
-struct TwoWaySearcher {
+pub(crate) struct TwoWaySearcher {
     // constants
     /// critical factorization index
     crit_pos: usize,
@@ -1328,7 +1301,8 @@ struct TwoWaySearcher {
 
 */
 impl TwoWaySearcher {
-    fn new(needle: &[u8], end: usize) -> TwoWaySearcher {
+    /// Creates a searcher for the non-empty `needle` in a haystack of length `end`.
+    pub(crate) fn new(needle: &[u8], end: usize) -> TwoWaySearcher {
         let (crit_pos_false, period_false) = TwoWaySearcher::maximal_suffix(needle, false);
         let (crit_pos_true, period_true) = TwoWaySearcher::maximal_suffix(needle, true);
 
@@ -1396,6 +1370,35 @@ fn new(needle: &[u8], end: usize) -> TwoWaySearcher {
         }
     }
 
+    /// Finds the next non-overlapping match of `needle` in `haystack` from the front.
+    #[inline]
+    pub(crate) fn next_match(&mut self, haystack: &[u8], needle: &[u8]) -> Option<(usize, usize)> {
+        let is_long = self.memory == usize::MAX;
+        // write out `true` and `false` cases to encourage the compiler
+        // to specialize the two cases separately.
+        if is_long {
+            self.next::<MatchOnly>(haystack, needle, true)
+        } else {
+            self.next::<MatchOnly>(haystack, needle, false)
+        }
+    }
+
+    /// Finds the next non-overlapping match of `needle` in `haystack` from the back.
+    #[inline]
+    pub(crate) fn next_match_back(
+        &mut self,
+        haystack: &[u8],
+        needle: &[u8],
+    ) -> Option<(usize, usize)> {
+        let is_long = self.memory == usize::MAX;
+        // write out `true` and `false`, like `next_match`
+        if is_long {
+            self.next_back::<MatchOnly>(haystack, needle, true)
+        } else {
+            self.next_back::<MatchOnly>(haystack, needle, false)
+        }
+    }
+
     #[inline]
     fn byteset_create(bytes: &[u8]) -> u64 {
         bytes.iter().fold(0, |a, &b| (1 << (b & 0x3f)) | a)
@@ -19,11 +19,15 @@
 
 // For use in alloc, not re-exported in std.
 pub use unicode_data::{
-    case_ignorable::lookup as Case_Ignorable, cased::lookup as Cased, conversions,
+    case_ignorable::lookup as Case_Ignorable, cased::lookup as Cased, conversions, normalization,
 };
 
 pub(crate) use unicode_data::alphabetic::lookup as Alphabetic;
 pub(crate) use unicode_data::cc::lookup as Cc;
+pub(crate) use unicode_data::extended_pictographic::lookup as Extended_Pictographic;
+pub(crate) use unicode_data::grapheme_cluster_break::{
+    lookup as Grapheme_Cluster_Break, GraphemeClusterBreak,
+};
 pub(crate) use unicode_data::grapheme_extend::lookup as Grapheme_Extend;
 pub(crate) use unicode_data::lowercase::lookup as Lowercase;
 pub(crate) use unicode_data::n::lookup as N;
@@ -99,6 +99,14 @@ fn skip_search<const SOR: usize, const OFFSETS: usize>(
     offset_idx % 2 == 1
 }
 
+#[inline]
+fn run_search<const N: usize>(needle: u32, runs: &[u32; N]) -> u8 {
+    // The first run starts at zero, so there always is a run that contains the
+    // needle, and `idx` can't be zero.
+    let idx = runs.partition_point(|run| run >> 8 <= needle);
+    runs[idx - 1] as u8
+}
+
 pub const UNICODE_VERSION: (u8, u8, u8) = (15, 0, 0);
 
 #[rustfmt::skip]
@@ -277,6 +285,29 @@ pub fn lookup(c: char) -> bool {
     }
 }
 
+#[rustfmt::skip]
+pub mod extended_pictographic {
+    static SHORT_OFFSET_RUNS: [u32; 15] = [
+        8252, 10494746, 35662132, 174074629, 178270256, 195048087, 203550720, 211939584, 214037499,
+        268563774, 272758352, 276953856, 323091456, 325189630, 328400894,
+    ];
+    static OFFSETS: [u8; 157] = [
+        169, 1, 4, 1, 0, 1, 12, 1, 216, 1, 22, 1, 90, 6, 15, 2, 0, 2, 12, 1, 95, 1, 70, 1, 25, 11,
+        4, 3, 199, 1, 231, 2, 10, 1, 9, 1, 58, 4, 1, 6, 1, 12, 1, 114, 10, 118, 2, 11, 1, 1, 1, 1,
+        6, 1, 3, 1, 6, 1, 10, 2, 15, 1, 2, 1, 4, 1, 1, 1, 4, 3, 1, 1, 11, 5, 45, 3, 9, 1, 14, 1, 14,
+        1, 0, 2, 0, 3, 19, 2, 51, 1, 4, 1, 0, 1, 12, 1, 0, 1, 1, 1, 0, 0, 13, 3, 31, 1, 60, 6, 12,
+        2, 14, 1, 2, 10, 18, 57, 27, 15, 10, 1, 20, 1, 2, 9, 1, 4, 9, 0, 5, 0, 8, 0, 48, 128, 116,
+        12, 85, 43, 12, 4, 56, 8, 10, 6, 40, 8, 30, 82, 12, 47, 1, 10, 1, 0, 0, 0, 0,
+    ];
+    pub fn lookup(c: char) -> bool {
+        super::skip_search(
+            c as u32,
+            &SHORT_OFFSET_RUNS,
+            &OFFSETS,
+        )
+    }
+}
+
 #[rustfmt::skip]
 pub mod grapheme_extend {
     static SHORT_OFFSET_RUNS: [u32; 33] = [
@@ -1381,3 +1412,2924 @@ pub mod conversions {
         ['\u{544}', '\u{53d}', '\u{0}'],
     ];
 }
+
+#[rustfmt::skip]
+pub mod grapheme_cluster_break {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum GraphemeClusterBreak {
+        Other,
+        Cr,
+        Lf,
+        Control,
+        Extend,
+        Zwj,
+        RegionalIndicator,
+        Prepend,
+        SpacingMark,
+        L,
+        V,
+        T,
+        Lv,
+        Lvt,
+    }
+
+    use GraphemeClusterBreak::*;
+
+    pub fn lookup(c: char) -> GraphemeClusterBreak {
+        match c as u32 {
+            c @ 0xAC00..=0xD7A3 => if (c - 0xAC00) % 28 == 0 { Lv } else { Lvt },
+            c => VALUES[super::run_search(c, &GRAPHEME_CLUSTER_BREAK_RUNS) as usize],
+        }
+    }
+
+    static VALUES: [GraphemeClusterBreak; 14] = [
+        Other, Cr, Lf, Control, Extend, Zwj, RegionalIndicator, Prepend, SpacingMark, L, V, T, Lv,
+        Lvt,
+    ];
+
+    static GRAPHEME_CLUSTER_BREAK_RUNS: [u32; 901] = [
+        3, 2562, 2819, 3329, 3587, 8192, 32515, 40960, 44291, 44544, 196612, 225280, 295684,
+        297472, 364804, 376320, 376580, 376832, 377092, 377600, 377860, 378368, 378628, 378880,
+        393223, 394752, 397316, 400128, 400387, 400640, 412420, 417792, 421892, 422144, 448004,
+        449799, 450048, 450308, 451840, 452356, 452864, 453124, 454144, 462599, 462848, 463108,
+        463360, 471044, 477952, 501252, 504064, 518916, 521216, 523524, 523776, 529924, 530944,
+        531204, 533504, 533764, 534528, 534788, 536064, 547076, 547840, 561159, 561664, 563204,
+        565248, 576004, 582151, 582404, 590600, 590848, 604676, 604936, 605188, 605440, 605704,
+        606468, 608520, 609540, 609800, 610304, 610564, 612352, 614916, 615424, 622852, 623112,
+        623616, 637956, 638208, 638468, 638728, 639236, 640256, 640776, 641280, 641800, 642308,
+        642560, 644868, 645120, 647684, 648192, 654852, 655104, 655620, 656136, 656384, 670724,
+        670976, 671240, 672004, 672512, 673540, 674048, 674564, 675328, 676100, 676352, 684036,
+        684544, 685316, 685568, 688388, 688904, 689152, 703492, 703744, 704008, 704772, 706048,
+        706308, 706824, 707072, 707336, 707844, 708096, 713220, 713728, 719364, 720896, 721156,
+        721416, 721920, 736260, 736512, 736772, 737288, 737540, 738560, 739080, 739584, 740104,
+        740612, 740864, 742660, 743424, 745988, 746496, 754180, 754432, 769540, 769800, 770052,
+        770312, 770816, 771592, 772352, 772616, 773380, 773632, 775940, 776192, 786436, 786696,
+        787460, 787712, 801796, 802048, 802308, 803080, 804096, 804356, 805120, 805380, 806400,
+        808196, 808704, 811524, 812032, 819460, 819720, 820224, 834564, 834816, 835080, 835332,
+        835592, 836100, 836360, 836864, 837124, 837384, 837888, 838152, 838660, 839168, 840964,
+        841472, 844292, 844800, 848648, 848896, 851972, 852488, 852992, 867076, 867584, 867844,
+        868104, 868612, 869632, 869896, 870656, 870920, 871684, 871943, 872192, 874244, 874496,
+        877060, 877568, 884996, 885256, 885760, 903684, 903936, 904964, 905224, 905732, 906496,
+        906756, 907008, 907272, 909060, 909312, 913928, 914432, 930052, 930304, 930568, 930820,
+        932608, 935684, 937728, 962820, 963072, 963336, 963588, 965888, 968708, 970496, 989188,
+        989696, 996612, 996864, 997124, 997376, 997636, 997888, 998920, 999424, 1011972, 1015560,
+        1015812, 1017088, 1017348, 1017856, 1019140, 1021952, 1022212, 1031424, 1033732, 1033984,
+        1060100, 1061128, 1061380, 1062912, 1063172, 1063688, 1064196, 1064704, 1070600, 1071108,
+        1071616, 1072644, 1073408, 1077508, 1078528, 1081860, 1082112, 1082376, 1082628, 1083136,
+        1084676, 1084928, 1088772, 1089024, 1114121, 1138698, 1157131, 1179648, 1268996, 1269760,
+        1511940, 1512712, 1512960, 1520132, 1520648, 1520896, 1528324, 1528832, 1536516, 1537024,
+        1553412, 1553928, 1554180, 1555976, 1558020, 1558280, 1558788, 1561600, 1563908, 1564160,
+        1575684, 1576451, 1576708, 1576960, 1606916, 1607424, 1616132, 1616384, 1646596, 1647368,
+        1648388, 1648904, 1649664, 1650696, 1651204, 1651464, 1652996, 1653760, 1709828, 1710344,
+        1710852, 1711104, 1725704, 1725956, 1726216, 1726468, 1728256, 1728516, 1728768, 1729028,
+        1729280, 1729796, 1731848, 1733380, 1735936, 1736452, 1736704, 1748996, 1756928, 1769476,
+        1770504, 1770752, 1782788, 1784584, 1784836, 1785096, 1786372, 1786632, 1787136, 1796868,
+        1799168, 1802244, 1802760, 1803008, 1810696, 1810948, 1811976, 1812484, 1813000, 1813252,
+        1814016, 1828356, 1828616, 1828868, 1829384, 1830148, 1830408, 1830660, 1831432, 1831936,
+        1844232, 1846276, 1848328, 1848836, 1849344, 1888260, 1889024, 1889284, 1892616, 1892868,
+        1894656, 1895684, 1895936, 1897476, 1897728, 1898248, 1898500, 1899008, 1949700, 1966080,
+        2099971, 2100228, 2100485, 2100739, 2101248, 2107395, 2109184, 2121731, 2125824, 2150404,
+        2158848, 2944772, 2945536, 2981636, 2981888, 3006468, 3014656, 3156484, 3158016, 3184900,
+        3185408, 10907396, 10908416, 10908676, 10911232, 10919428, 10919936, 10940420, 10940928,
+        11010564, 11010816, 11011588, 11011840, 11012868, 11013120, 11019016, 11019524, 11020040,
+        11020288, 11021316, 11021568, 11042824, 11043328, 11056136, 11060228, 11060736, 11067396,
+        11072000, 11075332, 11075584, 11085316, 11087360, 11093764, 11096584, 11097088, 11100169,
+        11107584, 11108356, 11109128, 11109376, 11121412, 11121672, 11122180, 11123208, 11123716,
+        11124232, 11124992, 11134212, 11134464, 11151620, 11153160, 11153668, 11154184, 11154692,
+        11155200, 11158276, 11158528, 11160580, 11160840, 11161088, 11172868, 11173120, 11186180,
+        11186432, 11186692, 11187456, 11187972, 11188480, 11189764, 11190272, 11190532, 11190784,
+        11201288, 11201540, 11202056, 11202560, 11203848, 11204100, 11204352, 11264776, 11265284,
+        11265544, 11266052, 11266312, 11266816, 11267080, 11267332, 11267584, 14135306, 14141184,
+        14142219, 14154752, 16457220, 16457472, 16646148, 16650240, 16654340, 16658432, 16711427,
+        16711680, 16752132, 16752640, 16773123, 16776192, 16907524, 16907776, 16965636, 16965888,
+        17004036, 17005312, 17432836, 17433600, 17433860, 17434368, 17435652, 17436672, 17446916,
+        17447680, 17448708, 17448960, 17491204, 17491712, 17638404, 17639424, 17738500, 17739008,
+        17759492, 17760256, 17778180, 17780992, 17793540, 17794560, 17825800, 17826052, 17826312,
+        17826560, 17840132, 17843968, 17854468, 17854720, 17855236, 17855744, 17858308, 17859080,
+        17859328, 17870856, 17871620, 17872648, 17873156, 17873664, 17874183, 17874432, 17875460,
+        17875712, 17878279, 17878528, 17891332, 17892096, 17901316, 17902600, 17902852, 17904896,
+        17909000, 17909504, 17920772, 17921024, 17924100, 17924616, 17924864, 17937160, 17937924,
+        17940232, 17940736, 17940999, 17941504, 17942788, 17943808, 17944072, 17944324, 17944576,
+        17968136, 17968900, 17969672, 17970180, 17970440, 17970692, 17971200, 17972740, 17972992,
+        17973508, 17973760, 18013956, 18014216, 18014980, 18017024, 18022404, 18022920, 18023424,
+        18037508, 18038016, 18038276, 18038536, 18038788, 18039048, 18040064, 18040584, 18041088,
+        18041608, 18042368, 18044676, 18044928, 18047496, 18048000, 18048516, 18050304, 18051076,
+        18052352, 18101512, 18102276, 18104328, 18104836, 18105608, 18105860, 18106112, 18112004,
+        18112256, 18132996, 18133256, 18133764, 18135304, 18135556, 18135816, 18136324, 18136584,
+        18136836, 18137352, 18137604, 18138112, 18198276, 18198536, 18199044, 18200064, 18200584,
+        18201604, 18202120, 18202372, 18202880, 18209796, 18210304, 18231304, 18232068, 18234120,
+        18234628, 18234888, 18235140, 18235648, 18262788, 18263048, 18263300, 18263560, 18264068,
+        18265608, 18265860, 18266112, 18291972, 18292736, 18293252, 18294280, 18294532, 18295808,
+        18361352, 18362116, 18364424, 18364676, 18365184, 18427908, 18428168, 18429440, 18429704,
+        18430208, 18430724, 18431240, 18431492, 18431751, 18432008, 18432263, 18432520, 18432772,
+        18433024, 18469128, 18469892, 18470912, 18471428, 18471944, 18472964, 18473216, 18473992,
+        18474240, 18481412, 18483968, 18494212, 18495752, 18496007, 18496260, 18497280, 18499332,
+        18499584, 18501892, 18503432, 18503940, 18504704, 18514951, 18516484, 18519816, 18520068,
+        18520576, 18624264, 18624516, 18626304, 18626564, 18628104, 18628356, 18628608, 18649604,
+        18655232, 18655496, 18655748, 18657544, 18657796, 18658312, 18658564, 18659072, 18690308,
+        18691840, 18692612, 18692864, 18693124, 18693632, 18693892, 18695687, 18695940, 18696192,
+        18713096, 18714368, 18714628, 18715136, 18715400, 18715908, 18716168, 18716420, 18716672,
+        18805508, 18806024, 18806528, 18808836, 18809351, 18809608, 18809856, 18822152, 18822660,
+        18823936, 18824712, 18825220, 18825480, 18825732, 18825984, 20197379, 20201476, 20201728,
+        20203268, 20207104, 23785476, 23786752, 23801860, 23803648, 24071940, 24072192, 24072456,
+        24086528, 24088324, 24089344, 24110084, 24110336, 24113160, 24113664, 29138180, 29138688,
+        29138947, 29139968, 30343172, 30354944, 30355460, 30361344, 30500100, 30500360, 30500612,
+        30501376, 30502152, 30502404, 30503683, 30505732, 30507776, 30508292, 30510080, 30517764,
+        30518784, 30556676, 30557440, 31064068, 31078144, 31079172, 31091968, 31094020, 31094272,
+        31097860, 31098112, 31103748, 31105024, 31105284, 31109120, 31457284, 31459072, 31459332,
+        31463680, 31464196, 31465984, 31466244, 31466752, 31467012, 31468288, 31493892, 31494144,
+        31535108, 31536896, 31632900, 31633152, 31648772, 31649792, 31779844, 31780864, 32034820,
+        32036608, 32064516, 32066304, 32630278, 32636928, 32766724, 32768000, 234881027, 234889220,
+        234913795, 234946564, 235008003, 235929600,
+    ];
+}
+
+#[rustfmt::skip]
+pub mod normalization {
+    const S_BASE: u32 = 0xAC00;
+    const L_BASE: u32 = 0x1100;
+    const V_BASE: u32 = 0x1161;
+    const T_BASE: u32 = 0x11A7;
+    const L_COUNT: u32 = 19;
+    const V_COUNT: u32 = 21;
+    const T_COUNT: u32 = 28;
+    const N_COUNT: u32 = V_COUNT * T_COUNT;
+    const S_COUNT: u32 = L_COUNT * N_COUNT;
+
+    pub fn canonical_combining_class(c: char) -> u8 {
+        if c.is_ascii() { 0 } else { super::run_search(c as u32, &COMBINING_CLASS_RUNS) }
+    }
+
+    pub fn decompose(c: char, compatible: bool, mut emit: impl FnMut(char)) {
+        if c.is_ascii() {
+            return emit(c);
+        }
+        let s_index = (c as u32).wrapping_sub(S_BASE);
+        if s_index < S_COUNT {
+            // SAFETY: The Hangul syllables decompose into valid chars
+            unsafe {
+                emit(char::from_u32_unchecked(L_BASE + s_index / N_COUNT));
+                emit(char::from_u32_unchecked(V_BASE + (s_index % N_COUNT) / T_COUNT));
+                if s_index % T_COUNT != 0 {
+                    emit(char::from_u32_unchecked(T_BASE + s_index % T_COUNT));
+                }
+            }
+            return;
+        }
+        let mut chars = None;
+        if compatible {
+            chars = decomposition(COMPATIBILITY_TABLE, COMPATIBILITY_CHARS, c);
+        }
+        match chars.or_else(|| decomposition(CANONICAL_TABLE, CANONICAL_CHARS, c)) {
+            Some(chars) => chars.iter().copied().for_each(emit),
+            None => emit(c),
+        }
+    }
+
+    fn decomposition(
+        table: &'static [(char, u32)],
+        chars: &'static [char],
+        c: char,
+    ) -> Option<&'static [char]> {
+        let i = table.binary_search_by(|&(key, _)| key.cmp(&c)).ok()?;
+        let offset = (table[i].1 >> 8) as usize;
+        let len = (table[i].1 & 0xff) as usize;
+        chars.get(offset..offset + len)
+    }
+
+    pub fn compose(a: char, b: char) -> Option<char> {
+        let l_index = (a as u32).wrapping_sub(L_BASE);
+        let v_index = (b as u32).wrapping_sub(V_BASE);
+        if l_index < L_COUNT && v_index < V_COUNT {
+            return char::from_u32(S_BASE + (l_index * V_COUNT + v_index) * T_COUNT);
+        }
+        let s_index = (a as u32).wrapping_sub(S_BASE);
+        let t_index = (b as u32).wrapping_sub(T_BASE);
+        if s_index < S_COUNT && s_index % T_COUNT == 0 && 0 < t_index && t_index < T_COUNT {
+            return char::from_u32(a as u32 + t_index);
+        }
+        COMPOSITION_TABLE
+            .binary_search_by(|&(x, y, _)| (x, y).cmp(&(a, b)))
+            .ok()
+            .map(|i| COMPOSITION_TABLE[i].2)
+    }
+
+    static COMBINING_CLASS_RUNS: [u32; 581] = [
+        0, 196838, 202216, 202460, 203496, 203736, 203996, 205258, 205788, 206794, 207324, 209921,
+        211420, 212454, 214512, 214758, 215004, 215782, 216540, 216832, 217318, 218076, 219110,
+        219368, 219612, 220134, 220393, 220650, 221161, 221418, 221929, 222182, 225280, 295910,
+        296960, 365020, 365286, 366300, 366566, 367326, 367580, 367846, 369372, 370918, 371420,
+        371686, 372190, 372452, 372710, 372746, 373003, 373260, 373517, 373774, 374031, 374288,
+        374545, 374802, 375059, 375572, 375829, 376086, 376320, 376599, 376832, 377112, 377369,
+        377600, 378086, 378332, 378368, 378642, 378880, 397542, 399390, 399647, 399904, 400128,
+        412443, 412700, 412957, 413214, 413471, 413728, 413985, 414242, 414694, 415196, 415718,
+        416988, 417254, 417756, 417792, 421923, 422144, 448230, 449792, 450534, 451548, 451814,
+        451840, 452582, 452864, 453340, 453606, 454108, 454144, 463140, 463360, 471270, 471516,
+        471782, 472284, 472550, 473052, 473830, 474076, 474598, 474844, 475110, 475868, 476134,
+        476380, 476646, 476892, 477158, 477404, 477670, 477952, 519142, 520924, 521190, 521216,
+        523740, 523776, 530150, 530944, 531430, 533504, 533990, 534528, 535014, 536064, 547292,
+        547840, 563430, 563676, 564454, 565248, 576230, 577500, 578790, 582144, 582620, 582886,
+        583388, 583654, 584156, 584422, 585180, 585755, 586012, 586269, 586726, 587484, 587750,
+        588252, 588774, 589824, 605191, 605440, 609545, 609792, 610790, 611036, 611302, 611584,
+        637959, 638208, 642313, 642560, 655078, 655104, 670727, 670976, 675081, 675328, 703495,
+        703744, 707849, 708096, 736263, 736512, 740617, 740864, 773385, 773632, 801799, 802048,
+        806153, 806400, 808276, 808539, 808704, 834567, 834816, 838921, 839168, 867081, 867584,
+        871689, 871936, 903689, 903936, 931943, 932361, 932608, 936043, 936960, 964726, 965129,
+        965376, 968826, 969728, 989404, 989696, 996828, 996864, 997340, 997376, 997848, 997888,
+        1012097, 1012354, 1012480, 1012868, 1012992, 1014402, 1015296, 1015938, 1016064, 1016550,
+        1016841, 1017088, 1017574, 1017856, 1033948, 1033984, 1062663, 1062912, 1063177, 1063680,
+        1084892, 1084928, 1269222, 1269760, 1512457, 1512960, 1520649, 1520896, 1561097, 1561344,
+        1564134, 1564160, 1616356, 1616384, 1653214, 1653478, 1653724, 1653760, 1710054, 1710300,
+        1710336, 1728521, 1728768, 1734118, 1735936, 1736668, 1736704, 1749222, 1750492, 1752038,
+        1752540, 1752576, 1753052, 1753574, 1754076, 1754598, 1755868, 1756134, 1756928, 1782791,
+        1783040, 1786889, 1787136, 1797094, 1797340, 1797606, 1799168, 1813001, 1813504, 1828359,
+        1828608, 1831433, 1831936, 1849095, 1849344, 1888486, 1889024, 1889281, 1889756, 1891046,
+        1891548, 1892582, 1892608, 1892865, 1894656, 1895900, 1895936, 1897702, 1897728, 1898726,
+        1899008, 1949926, 1950428, 1950694, 1952476, 1952742, 1953258, 1953494, 1953756, 1953994,
+        1954278, 1963752, 1964004, 1964508, 1964762, 1965030, 1965289, 1965532, 1965798, 1966044,
+        1966080, 2150630, 2150913, 2151654, 2152449, 2153446, 2153728, 2154982, 2155008, 2155777,
+        2156518, 2156764, 2157030, 2157057, 2157788, 2158822, 2158848, 2944998, 2945536, 2981641,
+        2981888, 3006694, 3014656, 3156698, 3156964, 3157224, 3157470, 3157728, 3158016, 3184904,
+        3185408, 10907622, 10907648, 10908902, 10911232, 10919654, 10919936, 10940646, 10940928,
+        11011593, 11011840, 11021321, 11021568, 11060233, 11060480, 11067622, 11072000, 11086812,
+        11087360, 11096841, 11097088, 11121415, 11121664, 11124745, 11124992, 11186406, 11186432,
+        11186918, 11187420, 11187456, 11188198, 11188480, 11189990, 11190272, 11190758, 11190784,
+        11204105, 11204352, 11267337, 11267584, 16457242, 16457472, 16654566, 16656348, 16658150,
+        16658432, 16907740, 16907776, 16965852, 16965888, 17004262, 17005312, 17436124, 17436160,
+        17436646, 17436672, 17447142, 17447169, 17447644, 17447680, 17448713, 17448960, 17491430,
+        17491676, 17491712, 17638630, 17639424, 17738726, 17739008, 17759708, 17760256, 17778396,
+        17778918, 17779676, 17779942, 17780188, 17780992, 17793766, 17794012, 17794278, 17794524,
+        17794560, 17843721, 17843968, 17854473, 17854720, 17858313, 17858560, 17873161, 17873415,
+        17873664, 17891558, 17892096, 17904393, 17904896, 17920775, 17921024, 17940489, 17940736,
+        17943047, 17943296, 17970441, 17970695, 17970944, 18016519, 18016777, 18017024, 18037511,
+        18038016, 18042121, 18042368, 18048742, 18050304, 18051302, 18052352, 18104841, 18105088,
+        18105863, 18106112, 18112230, 18112256, 18137609, 18137863, 18138112, 18202377, 18202631,
+        18202880, 18235145, 18235392, 18265609, 18265863, 18266112, 18295561, 18295808, 18364681,
+        18364935, 18365184, 18431241, 18431744, 18432775, 18433024, 18472969, 18473216, 18494473,
+        18494720, 18499337, 18499584, 18520329, 18520576, 18628361, 18628608, 18694663, 18694912,
+        18695177, 18695680, 18716425, 18716672, 18825481, 18825984, 23785473, 23786752, 23802086,
+        23803648, 24113158, 24113664, 29138433, 29138688, 30500312, 30500609, 30501376, 30502370,
+        30502616, 30503680, 30505948, 30507776, 30508518, 30509788, 30510080, 30517990, 30518784,
+        30556902, 30557440, 31457510, 31459072, 31459558, 31463680, 31464422, 31465984, 31466470,
+        31466752, 31467238, 31468288, 31494118, 31494144, 31535334, 31536896, 31633126, 31633152,
+        31648998, 31649792, 31780072, 31780572, 31780838, 31780864, 32035036, 32036608, 32064742,
+        32066055, 32066304,
+    ];
+
+    static CANONICAL_TABLE: &[(char, u32)] = &[
+        ('\u{c0}', 2), ('\u{c1}', 514), ('\u{c2}', 1026), ('\u{c3}', 1538), ('\u{c4}', 2050),
+        ('\u{c5}', 2562), ('\u{c7}', 3074), ('\u{c8}', 3586), ('\u{c9}', 4098), ('\u{ca}', 4610),
+        ('\u{cb}', 5122), ('\u{cc}', 5634), ('\u{cd}', 6146), ('\u{ce}', 6658), ('\u{cf}', 7170),
+        ('\u{d1}', 7682), ('\u{d2}', 8194), ('\u{d3}', 8706), ('\u{d4}', 9218), ('\u{d5}', 9730),
+        ('\u{d6}', 10242), ('\u{d9}', 10754), ('\u{da}', 11266), ('\u{db}', 11778),
+        ('\u{dc}', 12290), ('\u{dd}', 12802), ('\u{e0}', 13314), ('\u{e1}', 13826),
+        ('\u{e2}', 14338), ('\u{e3}', 14850), ('\u{e4}', 15362), ('\u{e5}', 15874),
+        ('\u{e7}', 16386), ('\u{e8}', 16898), ('\u{e9}', 17410), ('\u{ea}', 17922),
+        ('\u{eb}', 18434), ('\u{ec}', 18946), ('\u{ed}', 19458), ('\u{ee}', 19970),
+        ('\u{ef}', 20482), ('\u{f1}', 20994), ('\u{f2}', 21506), ('\u{f3}', 22018),
+        ('\u{f4}', 22530), ('\u{f5}', 23042), ('\u{f6}', 23554), ('\u{f9}', 24066),
+        ('\u{fa}', 24578), ('\u{fb}', 25090), ('\u{fc}', 25602), ('\u{fd}', 26114),
+        ('\u{ff}', 26626), ('\u{100}', 27138), ('\u{101}', 27650), ('\u{102}', 28162),
+        ('\u{103}', 28674), ('\u{104}', 29186), ('\u{105}', 29698), ('\u{106}', 30210),
+        ('\u{107}', 30722), ('\u{108}', 31234), ('\u{109}', 31746), ('\u{10a}', 32258),
+        ('\u{10b}', 32770), ('\u{10c}', 33282), ('\u{10d}', 33794), ('\u{10e}', 34306),
+        ('\u{10f}', 34818), ('\u{112}', 35330), ('\u{113}', 35842), ('\u{114}', 36354),
+        ('\u{115}', 36866), ('\u{116}', 37378), ('\u{117}', 37890), ('\u{118}', 38402),
+        ('\u{119}', 38914), ('\u{11a}', 39426), ('\u{11b}', 39938), ('\u{11c}', 40450),
+        ('\u{11d}', 40962), ('\u{11e}', 41474), ('\u{11f}', 41986), ('\u{120}', 42498),
+        ('\u{121}', 43010), ('\u{122}', 43522), ('\u{123}', 44034), ('\u{124}', 44546),
+        ('\u{125}', 45058), ('\u{128}', 45570), ('\u{129}', 46082), ('\u{12a}', 46594),
+        ('\u{12b}', 47106), ('\u{12c}', 47618), ('\u{12d}', 48130), ('\u{12e}', 48642),
+        ('\u{12f}', 49154), ('\u{130}', 49666), ('\u{134}', 50178), ('\u{135}', 50690),
+        ('\u{136}', 51202), ('\u{137}', 51714), ('\u{139}', 52226), ('\u{13a}', 52738),
+        ('\u{13b}', 53250), ('\u{13c}', 53762), ('\u{13d}', 54274), ('\u{13e}', 54786),
+        ('\u{143}', 55298), ('\u{144}', 55810), ('\u{145}', 56322), ('\u{146}', 56834),
+        ('\u{147}', 57346), ('\u{148}', 57858), ('\u{14c}', 58370), ('\u{14d}', 58882),
+        ('\u{14e}', 59394), ('\u{14f}', 59906), ('\u{150}', 60418), ('\u{151}', 60930),
+        ('\u{154}', 61442), ('\u{155}', 61954), ('\u{156}', 62466), ('\u{157}', 62978),
+        ('\u{158}', 63490), ('\u{159}', 64002), ('\u{15a}', 64514), ('\u{15b}', 65026),
+        ('\u{15c}', 65538), ('\u{15d}', 66050), ('\u{15e}', 66562), ('\u{15f}', 67074),
+        ('\u{160}', 67586), ('\u{161}', 68098), ('\u{162}', 68610), ('\u{163}', 69122),
+        ('\u{164}', 69634), ('\u{165}', 70146), ('\u{168}', 70658), ('\u{169}', 71170),
+        ('\u{16a}', 71682), ('\u{16b}', 72194), ('\u{16c}', 72706), ('\u{16d}', 73218),
+        ('\u{16e}', 73730), ('\u{16f}', 74242), ('\u{170}', 74754), ('\u{171}', 75266),
+        ('\u{172}', 75778), ('\u{173}', 76290), ('\u{174}', 76802), ('\u{175}', 77314),
+        ('\u{176}', 77826), ('\u{177}', 78338), ('\u{178}', 78850), ('\u{179}', 79362),
+        ('\u{17a}', 79874), ('\u{17b}', 80386), ('\u{17c}', 80898), ('\u{17d}', 81410),
+        ('\u{17e}', 81922), ('\u{1a0}', 82434), ('\u{1a1}', 82946), ('\u{1af}', 83458),
+        ('\u{1b0}', 83970), ('\u{1cd}', 84482), ('\u{1ce}', 84994), ('\u{1cf}', 85506),
+        ('\u{1d0}', 86018), ('\u{1d1}', 86530), ('\u{1d2}', 87042), ('\u{1d3}', 87554),
+        ('\u{1d4}', 88066), ('\u{1d5}', 88579), ('\u{1d6}', 89347), ('\u{1d7}', 90115),
+        ('\u{1d8}', 90883), ('\u{1d9}', 91651), ('\u{1da}', 92419), ('\u{1db}', 93187),
+        ('\u{1dc}', 93955), ('\u{1de}', 94723), ('\u{1df}', 95491), ('\u{1e0}', 96259),
+        ('\u{1e1}', 97027), ('\u{1e2}', 97794), ('\u{1e3}', 98306), ('\u{1e6}', 98818),
+        ('\u{1e7}', 99330), ('\u{1e8}', 99842), ('\u{1e9}', 100354), ('\u{1ea}', 100866),
+        ('\u{1eb}', 101378), ('\u{1ec}', 101891), ('\u{1ed}', 102659), ('\u{1ee}', 103426),
+        ('\u{1ef}', 103938), ('\u{1f0}', 104450), ('\u{1f4}', 104962), ('\u{1f5}', 105474),
+        ('\u{1f8}', 105986), ('\u{1f9}', 106498), ('\u{1fa}', 107011), ('\u{1fb}', 107779),
+        ('\u{1fc}', 108546), ('\u{1fd}', 109058), ('\u{1fe}', 109570), ('\u{1ff}', 110082),
+        ('\u{200}', 110594), ('\u{201}', 111106), ('\u{202}', 111618), ('\u{203}', 112130),
+        ('\u{204}', 112642), ('\u{205}', 113154), ('\u{206}', 113666), ('\u{207}', 114178),
+        ('\u{208}', 114690), ('\u{209}', 115202), ('\u{20a}', 115714), ('\u{20b}', 116226),
+        ('\u{20c}', 116738), ('\u{20d}', 117250), ('\u{20e}', 117762), ('\u{20f}', 118274),
+        ('\u{210}', 118786), ('\u{211}', 119298), ('\u{212}', 119810), ('\u{213}', 120322),
+        ('\u{214}', 120834), ('\u{215}', 121346), ('\u{216}', 121858), ('\u{217}', 122370),
+        ('\u{218}', 122882), ('\u{219}', 123394), ('\u{21a}', 123906), ('\u{21b}', 124418),
+        ('\u{21e}', 124930), ('\u{21f}', 125442), ('\u{226}', 125954), ('\u{227}', 126466),
+        ('\u{228}', 126978), ('\u{229}', 127490), ('\u{22a}', 128003), ('\u{22b}', 128771),
+        ('\u{22c}', 129539), ('\u{22d}', 130307), ('\u{22e}', 131074), ('\u{22f}', 131586),
+        ('\u{230}', 132099), ('\u{231}', 132867), ('\u{232}', 133634), ('\u{233}', 134146),
+        ('\u{340}', 134657), ('\u{341}', 134913), ('\u{343}', 135169), ('\u{344}', 135426),
+        ('\u{374}', 135937), ('\u{37e}', 136193), ('\u{385}', 136450), ('\u{386}', 136962),
+        ('\u{387}', 137473), ('\u{388}', 137730), ('\u{389}', 138242), ('\u{38a}', 138754),
+        ('\u{38c}', 139266), ('\u{38e}', 139778), ('\u{38f}', 140290), ('\u{390}', 140803),
+        ('\u{3aa}', 141570), ('\u{3ab}', 142082), ('\u{3ac}', 142594), ('\u{3ad}', 143106),
+        ('\u{3ae}', 143618), ('\u{3af}', 144130), ('\u{3b0}', 144643), ('\u{3ca}', 145410),
+        ('\u{3cb}', 145922), ('\u{3cc}', 146434), ('\u{3cd}', 146946), ('\u{3ce}', 147458),
+        ('\u{3d3}', 147970), ('\u{3d4}', 148482), ('\u{400}', 148994), ('\u{401}', 149506),
+        ('\u{403}', 150018), ('\u{407}', 150530), ('\u{40c}', 151042), ('\u{40d}', 151554),
+        ('\u{40e}', 152066), ('\u{419}', 152578), ('\u{439}', 153090), ('\u{450}', 153602),
+        ('\u{451}', 154114), ('\u{453}', 154626), ('\u{457}', 155138), ('\u{45c}', 155650),
+        ('\u{45d}', 156162), ('\u{45e}', 156674), ('\u{476}', 157186), ('\u{477}', 157698),
+        ('\u{4c1}', 158210), ('\u{4c2}', 158722), ('\u{4d0}', 159234), ('\u{4d1}', 159746),
+        ('\u{4d2}', 160258), ('\u{4d3}', 160770), ('\u{4d6}', 161282), ('\u{4d7}', 161794),
+        ('\u{4da}', 162306), ('\u{4db}', 162818), ('\u{4dc}', 163330), ('\u{4dd}', 163842),
+        ('\u{4de}', 164354), ('\u{4df}', 164866), ('\u{4e2}', 165378), ('\u{4e3}', 165890),
+        ('\u{4e4}', 166402), ('\u{4e5}', 166914), ('\u{4e6}', 167426), ('\u{4e7}', 167938),
+        ('\u{4ea}', 168450), ('\u{4eb}', 168962), ('\u{4ec}', 169474), ('\u{4ed}', 169986),
+        ('\u{4ee}', 170498), ('\u{4ef}', 171010), ('\u{4f0}', 171522), ('\u{4f1}', 172034),
+        ('\u{4f2}', 172546), ('\u{4f3}', 173058), ('\u{4f4}', 173570), ('\u{4f5}', 174082),
+        ('\u{4f8}', 174594), ('\u{4f9}', 175106), ('\u{622}', 175618), ('\u{623}', 176130),
+        ('\u{624}', 176642), ('\u{625}', 177154), ('\u{626}', 177666), ('\u{6c0}', 178178),
+        ('\u{6c2}', 178690), ('\u{6d3}', 179202), ('\u{929}', 179714), ('\u{931}', 180226),
+        ('\u{934}', 180738), ('\u{958}', 181250), ('\u{959}', 181762), ('\u{95a}', 182274),
+        ('\u{95b}', 182786), ('\u{95c}', 183298), ('\u{95d}', 183810), ('\u{95e}', 184322),
+        ('\u{95f}', 184834), ('\u{9cb}', 185346), ('\u{9cc}', 185858), ('\u{9dc}', 186370),
+        ('\u{9dd}', 186882), ('\u{9df}', 187394), ('\u{a33}', 187906), ('\u{a36}', 188418),
+        ('\u{a59}', 188930), ('\u{a5a}', 189442), ('\u{a5b}', 189954), ('\u{a5e}', 190466),
+        ('\u{b48}', 190978), ('\u{b4b}', 191490), ('\u{b4c}', 192002), ('\u{b5c}', 192514),
+        ('\u{b5d}', 193026), ('\u{b94}', 193538), ('\u{bca}', 194050), ('\u{bcb}', 194562),
+        ('\u{bcc}', 195074), ('\u{c48}', 195586), ('\u{cc0}', 196098), ('\u{cc7}', 196610),
+        ('\u{cc8}', 197122), ('\u{cca}', 197634), ('\u{ccb}', 198147), ('\u{d4a}', 198914),
+        ('\u{d4b}', 199426), ('\u{d4c}', 199938), ('\u{dda}', 200450), ('\u{ddc}', 200962),
+        ('\u{ddd}', 201475), ('\u{dde}', 202242), ('\u{f43}', 202754), ('\u{f4d}', 203266),
+        ('\u{f52}', 203778), ('\u{f57}', 204290), ('\u{f5c}', 204802), ('\u{f69}', 205314),
+        ('\u{f73}', 205826), ('\u{f75}', 206338), ('\u{f76}', 206850), ('\u{f78}', 207362),
+        ('\u{f81}', 207874), ('\u{f93}', 208386), ('\u{f9d}', 208898), ('\u{fa2}', 209410),
+        ('\u{fa7}', 209922), ('\u{fac}', 210434), ('\u{fb9}', 210946), ('\u{1026}', 211458),
+        ('\u{1b06}', 211970), ('\u{1b08}', 212482), ('\u{1b0a}', 212994), ('\u{1b0c}', 213506),
+        ('\u{1b0e}', 214018), ('\u{1b12}', 214530), ('\u{1b3b}', 215042), ('\u{1b3d}', 215554),
+        ('\u{1b40}', 216066), ('\u{1b41}', 216578), ('\u{1b43}', 217090), ('\u{1e00}', 217602),
+        ('\u{1e01}', 218114), ('\u{1e02}', 218626), ('\u{1e03}', 219138), ('\u{1e04}', 219650),
+        ('\u{1e05}', 220162), ('\u{1e06}', 220674), ('\u{1e07}', 221186), ('\u{1e08}', 221699),
+        ('\u{1e09}', 222467), ('\u{1e0a}', 223234), ('\u{1e0b}', 223746), ('\u{1e0c}', 224258),
+        ('\u{1e0d}', 224770), ('\u{1e0e}', 225282), ('\u{1e0f}', 225794), ('\u{1e10}', 226306),
+        ('\u{1e11}', 226818), ('\u{1e12}', 227330), ('\u{1e13}', 227842), ('\u{1e14}', 228355),
+        ('\u{1e15}', 229123), ('\u{1e16}', 229891), ('\u{1e17}', 230659), ('\u{1e18}', 231426),
+        ('\u{1e19}', 231938), ('\u{1e1a}', 232450), ('\u{1e1b}', 232962), ('\u{1e1c}', 233475),
+        ('\u{1e1d}', 234243), ('\u{1e1e}', 235010), ('\u{1e1f}', 235522), ('\u{1e20}', 236034),
+        ('\u{1e21}', 236546), ('\u{1e22}', 237058), ('\u{1e23}', 237570), ('\u{1e24}', 238082),
+        ('\u{1e25}', 238594), ('\u{1e26}', 239106), ('\u{1e27}', 239618), ('\u{1e28}', 240130),
+        ('\u{1e29}', 240642), ('\u{1e2a}', 241154), ('\u{1e2b}', 241666), ('\u{1e2c}', 242178),
+        ('\u{1e2d}', 242690), ('\u{1e2e}', 243203), ('\u{1e2f}', 243971), ('\u{1e30}', 244738),
+        ('\u{1e31}', 245250), ('\u{1e32}', 245762), ('\u{1e33}', 246274), ('\u{1e34}', 246786),
+        ('\u{1e35}', 247298), ('\u{1e36}', 247810), ('\u{1e37}', 248322), ('\u{1e38}', 248835),
+        ('\u{1e39}', 249603), ('\u{1e3a}', 250370), ('\u{1e3b}', 250882), ('\u{1e3c}', 251394),
+        ('\u{1e3d}', 251906), ('\u{1e3e}', 252418), ('\u{1e3f}', 252930), ('\u{1e40}', 253442),
+        ('\u{1e41}', 253954), ('\u{1e42}', 254466), ('\u{1e43}', 254978), ('\u{1e44}', 255490),
+        ('\u{1e45}', 256002), ('\u{1e46}', 256514), ('\u{1e47}', 257026), ('\u{1e48}', 257538),
+        ('\u{1e49}', 258050), ('\u{1e4a}', 258562), ('\u{1e4b}', 259074), ('\u{1e4c}', 259587),
+        ('\u{1e4d}', 260355), ('\u{1e4e}', 261123), ('\u{1e4f}', 261891), ('\u{1e50}', 262659),
+        ('\u{1e51}', 263427), ('\u{1e52}', 264195), ('\u{1e53}', 264963), ('\u{1e54}', 265730),
+        ('\u{1e55}', 266242), ('\u{1e56}', 266754), ('\u{1e57}', 267266), ('\u{1e58}', 267778),
+        ('\u{1e59}', 268290), ('\u{1e5a}', 268802), ('\u{1e5b}', 269314), ('\u{1e5c}', 269827),
+        ('\u{1e5d}', 270595), ('\u{1e5e}', 271362), ('\u{1e5f}', 271874), ('\u{1e60}', 272386),
+        ('\u{1e61}', 272898), ('\u{1e62}', 273410), ('\u{1e63}', 273922), ('\u{1e64}', 274435),
+        ('\u{1e65}', 275203), ('\u{1e66}', 275971), ('\u{1e67}', 276739), ('\u{1e68}', 277507),
+        ('\u{1e69}', 278275), ('\u{1e6a}', 279042), ('\u{1e6b}', 279554), ('\u{1e6c}', 280066),
+        ('\u{1e6d}', 280578), ('\u{1e6e}', 281090), ('\u{1e6f}', 281602), ('\u{1e70}', 282114),
+        ('\u{1e71}', 282626), ('\u{1e72}', 283138), ('\u{1e73}', 283650), ('\u{1e74}', 284162),
+        ('\u{1e75}', 284674), ('\u{1e76}', 285186), ('\u{1e77}', 285698), ('\u{1e78}', 286211),
+        ('\u{1e79}', 286979), ('\u{1e7a}', 287747), ('\u{1e7b}', 288515), ('\u{1e7c}', 289282),
+        ('\u{1e7d}', 289794), ('\u{1e7e}', 290306), ('\u{1e7f}', 290818), ('\u{1e80}', 291330),
+        ('\u{1e81}', 291842), ('\u{1e82}', 292354), ('\u{1e83}', 292866), ('\u{1e84}', 293378),
+        ('\u{1e85}', 293890), ('\u{1e86}', 294402), ('\u{1e87}', 294914), ('\u{1e88}', 295426),
+        ('\u{1e89}', 295938), ('\u{1e8a}', 296450), ('\u{1e8b}', 296962), ('\u{1e8c}', 297474),
+        ('\u{1e8d}', 297986), ('\u{1e8e}', 298498), ('\u{1e8f}', 299010), ('\u{1e90}', 299522),
+        ('\u{1e91}', 300034), ('\u{1e92}', 300546), ('\u{1e93}', 301058), ('\u{1e94}', 301570),
+        ('\u{1e95}', 302082), ('\u{1e96}', 302594), ('\u{1e97}', 303106), ('\u{1e98}', 303618),
+        ('\u{1e99}', 304130), ('\u{1e9b}', 304642), ('\u{1ea0}', 305154), ('\u{1ea1}', 305666),
+        ('\u{1ea2}', 306178), ('\u{1ea3}', 306690), ('\u{1ea4}', 307203), ('\u{1ea5}', 307971),
+        ('\u{1ea6}', 308739), ('\u{1ea7}', 309507), ('\u{1ea8}', 310275), ('\u{1ea9}', 311043),
+        ('\u{1eaa}', 311811), ('\u{1eab}', 312579), ('\u{1eac}', 313347), ('\u{1ead}', 314115),
+        ('\u{1eae}', 314883), ('\u{1eaf}', 315651), ('\u{1eb0}', 316419), ('\u{1eb1}', 317187),
+        ('\u{1eb2}', 317955), ('\u{1eb3}', 318723), ('\u{1eb4}', 319491), ('\u{1eb5}', 320259),
+        ('\u{1eb6}', 321027), ('\u{1eb7}', 321795), ('\u{1eb8}', 322562), ('\u{1eb9}', 323074),
+        ('\u{1eba}', 323586), ('\u{1ebb}', 324098), ('\u{1ebc}', 324610), ('\u{1ebd}', 325122),
+        ('\u{1ebe}', 325635), ('\u{1ebf}', 326403), ('\u{1ec0}', 327171), ('\u{1ec1}', 327939),
+        ('\u{1ec2}', 328707), ('\u{1ec3}', 329475), ('\u{1ec4}', 330243), ('\u{1ec5}', 331011),
+        ('\u{1ec6}', 331779), ('\u{1ec7}', 332547), ('\u{1ec8}', 333314), ('\u{1ec9}', 333826),
+        ('\u{1eca}', 334338), ('\u{1ecb}', 334850), ('\u{1ecc}', 335362), ('\u{1ecd}', 335874),
+        ('\u{1ece}', 336386), ('\u{1ecf}', 336898), ('\u{1ed0}', 337411), ('\u{1ed1}', 338179),
+        ('\u{1ed2}', 338947), ('\u{1ed3}', 339715), ('\u{1ed4}', 340483), ('\u{1ed5}', 341251),
+        ('\u{1ed6}', 342019), ('\u{1ed7}', 342787), ('\u{1ed8}', 343555), ('\u{1ed9}', 344323),
+        ('\u{1eda}', 345091), ('\u{1edb}', 345859), ('\u{1edc}', 346627), ('\u{1edd}', 347395),
+        ('\u{1ede}', 348163), ('\u{1edf}', 348931), ('\u{1ee0}', 349699), ('\u{1ee1}', 350467),
+        ('\u{1ee2}', 351235), ('\u{1ee3}', 352003), ('\u{1ee4}', 352770), ('\u{1ee5}', 353282),
+        ('\u{1ee6}', 353794), ('\u{1ee7}', 354306), ('\u{1ee8}', 354819), ('\u{1ee9}', 355587),
+        ('\u{1eea}', 356355), ('\u{1eeb}', 357123), ('\u{1eec}', 357891), ('\u{1eed}', 358659),
+        ('\u{1eee}', 359427), ('\u{1eef}', 360195), ('\u{1ef0}', 360963), ('\u{1ef1}', 361731),
+        ('\u{1ef2}', 362498), ('\u{1ef3}', 363010), ('\u{1ef4}', 363522), ('\u{1ef5}', 364034),
+        ('\u{1ef6}', 364546), ('\u{1ef7}', 365058), ('\u{1ef8}', 365570), ('\u{1ef9}', 366082),
+        ('\u{1f00}', 366594), ('\u{1f01}', 367106), ('\u{1f02}', 367619), ('\u{1f03}', 368387),
+        ('\u{1f04}', 369155), ('\u{1f05}', 369923), ('\u{1f06}', 370691), ('\u{1f07}', 371459),
+        ('\u{1f08}', 372226), ('\u{1f09}', 372738), ('\u{1f0a}', 373251), ('\u{1f0b}', 374019),
+        ('\u{1f0c}', 374787), ('\u{1f0d}', 375555), ('\u{1f0e}', 376323), ('\u{1f0f}', 377091),
+        ('\u{1f10}', 377858), ('\u{1f11}', 378370), ('\u{1f12}', 378883), ('\u{1f13}', 379651),
+        ('\u{1f14}', 380419), ('\u{1f15}', 381187), ('\u{1f18}', 381954), ('\u{1f19}', 382466),
+        ('\u{1f1a}', 382979), ('\u{1f1b}', 383747), ('\u{1f1c}', 384515), ('\u{1f1d}', 385283),
+        ('\u{1f20}', 386050), ('\u{1f21}', 386562), ('\u{1f22}', 387075), ('\u{1f23}', 387843),
+        ('\u{1f24}', 388611), ('\u{1f25}', 389379), ('\u{1f26}', 390147), ('\u{1f27}', 390915),
+        ('\u{1f28}', 391682), ('\u{1f29}', 392194), ('\u{1f2a}', 392707), ('\u{1f2b}', 393475),
+        ('\u{1f2c}', 394243), ('\u{1f2d}', 395011), ('\u{1f2e}', 395779), ('\u{1f2f}', 396547),
+        ('\u{1f30}', 397314), ('\u{1f31}', 397826), ('\u{1f32}', 398339), ('\u{1f33}', 399107),
+        ('\u{1f34}', 399875), ('\u{1f35}', 400643), ('\u{1f36}', 401411), ('\u{1f37}', 402179),
+        ('\u{1f38}', 402946), ('\u{1f39}', 403458), ('\u{1f3a}', 403971), ('\u{1f3b}', 404739),
+        ('\u{1f3c}', 405507), ('\u{1f3d}', 406275), ('\u{1f3e}', 407043), ('\u{1f3f}', 407811),
+        ('\u{1f40}', 408578), ('\u{1f41}', 409090), ('\u{1f42}', 409603), ('\u{1f43}', 410371),
+        ('\u{1f44}', 411139), ('\u{1f45}', 411907), ('\u{1f48}', 412674), ('\u{1f49}', 413186),
+        ('\u{1f4a}', 413699), ('\u{1f4b}', 414467), ('\u{1f4c}', 415235), ('\u{1f4d}', 416003),
+        ('\u{1f50}', 416770), ('\u{1f51}', 417282), ('\u{1f52}', 417795), ('\u{1f53}', 418563),
+        ('\u{1f54}', 419331), ('\u{1f55}', 420099), ('\u{1f56}', 420867), ('\u{1f57}', 421635),
+        ('\u{1f59}', 422402), ('\u{1f5b}', 422915), ('\u{1f5d}', 423683), ('\u{1f5f}', 424451),
+        ('\u{1f60}', 425218), ('\u{1f61}', 425730), ('\u{1f62}', 426243), ('\u{1f63}', 427011),
+        ('\u{1f64}', 427779), ('\u{1f65}', 428547), ('\u{1f66}', 429315), ('\u{1f67}', 430083),
+        ('\u{1f68}', 430850), ('\u{1f69}', 431362), ('\u{1f6a}', 431875), ('\u{1f6b}', 432643),
+        ('\u{1f6c}', 433411), ('\u{1f6d}', 434179), ('\u{1f6e}', 434947), ('\u{1f6f}', 435715),
+        ('\u{1f70}', 436482), ('\u{1f71}', 142594), ('\u{1f72}', 436994), ('\u{1f73}', 143106),
+        ('\u{1f74}', 437506), ('\u{1f75}', 143618), ('\u{1f76}', 438018), ('\u{1f77}', 144130),
+        ('\u{1f78}', 438530), ('\u{1f79}', 146434), ('\u{1f7a}', 439042), ('\u{1f7b}', 146946),
+        ('\u{1f7c}', 439554), ('\u{1f7d}', 147458), ('\u{1f80}', 440067), ('\u{1f81}', 440835),
+        ('\u{1f82}', 441604), ('\u{1f83}', 442628), ('\u{1f84}', 443652), ('\u{1f85}', 444676),
+        ('\u{1f86}', 445700), ('\u{1f87}', 446724), ('\u{1f88}', 447747), ('\u{1f89}', 448515),
+        ('\u{1f8a}', 449284), ('\u{1f8b}', 450308), ('\u{1f8c}', 451332), ('\u{1f8d}', 452356),
+        ('\u{1f8e}', 453380), ('\u{1f8f}', 454404), ('\u{1f90}', 455427), ('\u{1f91}', 456195),
+        ('\u{1f92}', 456964), ('\u{1f93}', 457988), ('\u{1f94}', 459012), ('\u{1f95}', 460036),
+        ('\u{1f96}', 461060), ('\u{1f97}', 462084), ('\u{1f98}', 463107), ('\u{1f99}', 463875),
+        ('\u{1f9a}', 464644), ('\u{1f9b}', 465668), ('\u{1f9c}', 466692), ('\u{1f9d}', 467716),
+        ('\u{1f9e}', 468740), ('\u{1f9f}', 469764), ('\u{1fa0}', 470787), ('\u{1fa1}', 471555),
+        ('\u{1fa2}', 472324), ('\u{1fa3}', 473348), ('\u{1fa4}', 474372), ('\u{1fa5}', 475396),
+        ('\u{1fa6}', 476420), ('\u{1fa7}', 477444), ('\u{1fa8}', 478467), ('\u{1fa9}', 479235),
+        ('\u{1faa}', 480004), ('\u{1fab}', 481028), ('\u{1fac}', 482052), ('\u{1fad}', 483076),
+        ('\u{1fae}', 484100), ('\u{1faf}', 485124), ('\u{1fb0}', 486146), ('\u{1fb1}', 486658),
+        ('\u{1fb2}', 487171), ('\u{1fb3}', 487938), ('\u{1fb4}', 488451), ('\u{1fb6}', 489218),
+        ('\u{1fb7}', 489731), ('\u{1fb8}', 490498), ('\u{1fb9}', 491010), ('\u{1fba}', 491522),
+        ('\u{1fbb}', 136962), ('\u{1fbc}', 492034), ('\u{1fbe}', 492545), ('\u{1fc1}', 492802),
+        ('\u{1fc2}', 493315), ('\u{1fc3}', 494082), ('\u{1fc4}', 494595), ('\u{1fc6}', 495362),
+        ('\u{1fc7}', 495875), ('\u{1fc8}', 496642), ('\u{1fc9}', 137730), ('\u{1fca}', 497154),
+        ('\u{1fcb}', 138242), ('\u{1fcc}', 497666), ('\u{1fcd}', 498178), ('\u{1fce}', 498690),
+        ('\u{1fcf}', 499202), ('\u{1fd0}', 499714), ('\u{1fd1}', 500226), ('\u{1fd2}', 500739),
+        ('\u{1fd3}', 140803), ('\u{1fd6}', 501506), ('\u{1fd7}', 502019), ('\u{1fd8}', 502786),
+        ('\u{1fd9}', 503298), ('\u{1fda}', 503810), ('\u{1fdb}', 138754), ('\u{1fdd}', 504322),
+        ('\u{1fde}', 504834), ('\u{1fdf}', 505346), ('\u{1fe0}', 505858), ('\u{1fe1}', 506370),
+        ('\u{1fe2}', 506883), ('\u{1fe3}', 144643), ('\u{1fe4}', 507650), ('\u{1fe5}', 508162),
+        ('\u{1fe6}', 508674), ('\u{1fe7}', 509187), ('\u{1fe8}', 509954), ('\u{1fe9}', 510466),
+        ('\u{1fea}', 510978), ('\u{1feb}', 139778), ('\u{1fec}', 511490), ('\u{1fed}', 512002),
+        ('\u{1fee}', 136450), ('\u{1fef}', 512513), ('\u{1ff2}', 512771), ('\u{1ff3}', 513538),
+        ('\u{1ff4}', 514051), ('\u{1ff6}', 514818), ('\u{1ff7}', 515331), ('\u{1ff8}', 516098),
+        ('\u{1ff9}', 139266), ('\u{1ffa}', 516610), ('\u{1ffb}', 140290), ('\u{1ffc}', 517122),
+        ('\u{1ffd}', 517633), ('\u{2000}', 517889), ('\u{2001}', 518145), ('\u{2126}', 518401),
+        ('\u{212a}', 518657), ('\u{212b}', 2562), ('\u{219a}', 518914), ('\u{219b}', 519426),
+        ('\u{21ae}', 519938), ('\u{21cd}', 520450), ('\u{21ce}', 520962), ('\u{21cf}', 521474),
+        ('\u{2204}', 521986), ('\u{2209}', 522498), ('\u{220c}', 523010), ('\u{2224}', 523522),
+        ('\u{2226}', 524034), ('\u{2241}', 524546), ('\u{2244}', 525058), ('\u{2247}', 525570),
+        ('\u{2249}', 526082), ('\u{2260}', 526594), ('\u{2262}', 527106), ('\u{226d}', 527618),
+        ('\u{226e}', 528130), ('\u{226f}', 528642), ('\u{2270}', 529154), ('\u{2271}', 529666),
+        ('\u{2274}', 530178), ('\u{2275}', 530690), ('\u{2278}', 531202), ('\u{2279}', 531714),
+        ('\u{2280}', 532226), ('\u{2281}', 532738), ('\u{2284}', 533250), ('\u{2285}', 533762),
+        ('\u{2288}', 534274), ('\u{2289}', 534786), ('\u{22ac}', 535298), ('\u{22ad}', 535810),
+        ('\u{22ae}', 536322), ('\u{22af}', 536834), ('\u{22e0}', 537346), ('\u{22e1}', 537858),
+        ('\u{22e2}', 538370), ('\u{22e3}', 538882), ('\u{22ea}', 539394), ('\u{22eb}', 539906),
+        ('\u{22ec}', 540418), ('\u{22ed}', 540930), ('\u{2329}', 541441), ('\u{232a}', 541697),
+        ('\u{2adc}', 541954), ('\u{304c}', 542466), ('\u{304e}', 542978), ('\u{3050}', 543490),
+        ('\u{3052}', 544002), ('\u{3054}', 544514), ('\u{3056}', 545026), ('\u{3058}', 545538),
+        ('\u{305a}', 546050), ('\u{305c}', 546562), ('\u{305e}', 547074), ('\u{3060}', 547586),
+        ('\u{3062}', 548098), ('\u{3065}', 548610), ('\u{3067}', 549122), ('\u{3069}', 549634),
+        ('\u{3070}', 550146), ('\u{3071}', 550658), ('\u{3073}', 551170), ('\u{3074}', 551682),
+        ('\u{3076}', 552194), ('\u{3077}', 552706), ('\u{3079}', 553218), ('\u{307a}', 553730),
+        ('\u{307c}', 554242), ('\u{307d}', 554754), ('\u{3094}', 555266), ('\u{309e}', 555778),
+        ('\u{30ac}', 556290), ('\u{30ae}', 556802), ('\u{30b0}', 557314), ('\u{30b2}', 557826),
+        ('\u{30b4}', 558338), ('\u{30b6}', 558850), ('\u{30b8}', 559362), ('\u{30ba}', 559874),
+        ('\u{30bc}', 560386), ('\u{30be}', 560898), ('\u{30c0}', 561410), ('\u{30c2}', 561922),
+        ('\u{30c5}', 562434), ('\u{30c7}', 562946), ('\u{30c9}', 563458), ('\u{30d0}', 563970),
+        ('\u{30d1}', 564482), ('\u{30d3}', 564994), ('\u{30d4}', 565506), ('\u{30d6}', 566018),
+        ('\u{30d7}', 566530), ('\u{30d9}', 567042), ('\u{30da}', 567554), ('\u{30dc}', 568066),
+        ('\u{30dd}', 568578), ('\u{30f4}', 569090), ('\u{30f7}', 569602), ('\u{30f8}', 570114),
+        ('\u{30f9}', 570626), ('\u{30fa}', 571138), ('\u{30fe}', 571650), ('\u{f900}', 572161),
+        ('\u{f901}', 572417), ('\u{f902}', 572673), ('\u{f903}', 572929), ('\u{f904}', 573185),
+        ('\u{f905}', 573441), ('\u{f906}', 573697), ('\u{f907}', 573953), ('\u{f908}', 573953),
+        ('\u{f909}', 574209), ('\u{f90a}', 574465), ('\u{f90b}', 574721), ('\u{f90c}', 574977),
+        ('\u{f90d}', 575233), ('\u{f90e}', 575489), ('\u{f90f}', 575745), ('\u{f910}', 576001),
+        ('\u{f911}', 576257), ('\u{f912}', 576513), ('\u{f913}', 576769), ('\u{f914}', 577025),
+        ('\u{f915}', 577281), ('\u{f916}', 577537), ('\u{f917}', 577793), ('\u{f918}', 578049),
+        ('\u{f919}', 578305), ('\u{f91a}', 578561), ('\u{f91b}', 578817), ('\u{f91c}', 579073),
+        ('\u{f91d}', 579329), ('\u{f91e}', 579585), ('\u{f91f}', 579841), ('\u{f920}', 580097),
+        ('\u{f921}', 580353), ('\u{f922}', 580609), ('\u{f923}', 580865), ('\u{f924}', 581121),
+        ('\u{f925}', 581377), ('\u{f926}', 581633), ('\u{f927}', 581889), ('\u{f928}', 582145),
+        ('\u{f929}', 582401), ('\u{f92a}', 582657), ('\u{f92b}', 582913), ('\u{f92c}', 583169),
+        ('\u{f92d}', 583425), ('\u{f92e}', 583681), ('\u{f92f}', 583937), ('\u{f930}', 584193),
+        ('\u{f931}', 584449), ('\u{f932}', 584705), ('\u{f933}', 584961), ('\u{f934}', 585217),
+        ('\u{f935}', 585473), ('\u{f936}', 585729), ('\u{f937}', 585985), ('\u{f938}', 586241),
+        ('\u{f939}', 586497), ('\u{f93a}', 586753), ('\u{f93b}', 587009), ('\u{f93c}', 587265),
+        ('\u{f93d}', 587521), ('\u{f93e}', 587777), ('\u{f93f}', 588033), ('\u{f940}', 588289),
+        ('\u{f941}', 588545), ('\u{f942}', 588801), ('\u{f943}', 589057), ('\u{f944}', 589313),
+        ('\u{f945}', 589569), ('\u{f946}', 589825), ('\u{f947}', 590081), ('\u{f948}', 590337),
+        ('\u{f949}', 590593), ('\u{f94a}', 590849), ('\u{f94b}', 591105), ('\u{f94c}', 591361),
+        ('\u{f94d}', 591617), ('\u{f94e}', 591873), ('\u{f94f}', 592129), ('\u{f950}', 592385),
+        ('\u{f951}', 592641), ('\u{f952}', 592897), ('\u{f953}', 593153), ('\u{f954}', 593409),
+        ('\u{f955}', 593665), ('\u{f956}', 593921), ('\u{f957}', 594177), ('\u{f958}', 594433),
+        ('\u{f959}', 594689), ('\u{f95a}', 594945), ('\u{f95b}', 595201), ('\u{f95c}', 577025),
+        ('\u{f95d}', 595457), ('\u{f95e}', 595713), ('\u{f95f}', 595969), ('\u{f960}', 596225),
+        ('\u{f961}', 596481), ('\u{f962}', 596737), ('\u{f963}', 596993), ('\u{f964}', 597249),
+        ('\u{f965}', 597505), ('\u{f966}', 597761), ('\u{f967}', 598017), ('\u{f968}', 598273),
+        ('\u{f969}', 598529), ('\u{f96a}', 598785), ('\u{f96b}', 599041), ('\u{f96c}', 599297),
+        ('\u{f96d}', 599553), ('\u{f96e}', 599809), ('\u{f96f}', 600065), ('\u{f970}', 600321),
+        ('\u{f971}', 600577), ('\u{f972}', 600833), ('\u{f973}', 601089), ('\u{f974}', 601345),
+        ('\u{f975}', 601601), ('\u{f976}', 601857), ('\u{f977}', 602113), ('\u{f978}', 602369),
+        ('\u{f979}', 602625), ('\u{f97a}', 602881), ('\u{f97b}', 603137), ('\u{f97c}', 603393),
+        ('\u{f97d}', 603649), ('\u{f97e}', 603905), ('\u{f97f}', 604161), ('\u{f980}', 604417),
+        ('\u{f981}', 604673), ('\u{f982}', 604929), ('\u{f983}', 605185), ('\u{f984}', 605441),
+        ('\u{f985}', 605697), ('\u{f986}', 605953), ('\u{f987}', 606209), ('\u{f988}', 606465),
+        ('\u{f989}', 606721), ('\u{f98a}', 606977), ('\u{f98b}', 607233), ('\u{f98c}', 607489),
+        ('\u{f98d}', 607745), ('\u{f98e}', 608001), ('\u{f98f}', 608257), ('\u{f990}', 608513),
+        ('\u{f991}', 608769), ('\u{f992}', 609025), ('\u{f993}', 609281), ('\u{f994}', 609537),
+        ('\u{f995}', 609793), ('\u{f996}', 610049), ('\u{f997}', 610305), ('\u{f998}', 610561),
+        ('\u{f999}', 610817), ('\u{f99a}', 611073), ('\u{f99b}', 611329), ('\u{f99c}', 611585),
+        ('\u{f99d}', 611841), ('\u{f99e}', 612097), ('\u{f99f}', 612353), ('\u{f9a0}', 612609),
+        ('\u{f9a1}', 600065), ('\u{f9a2}', 612865), ('\u{f9a3}', 613121), ('\u{f9a4}', 613377),
+        ('\u{f9a5}', 613633), ('\u{f9a6}', 613889), ('\u{f9a7}', 614145), ('\u{f9a8}', 614401),
+        ('\u{f9a9}', 614657), ('\u{f9aa}', 595969), ('\u{f9ab}', 614913), ('\u{f9ac}', 615169),
+        ('\u{f9ad}', 615425), ('\u{f9ae}', 615681), ('\u{f9af}', 615937), ('\u{f9b0}', 616193),
+        ('\u{f9b1}', 616449), ('\u{f9b2}', 616705), ('\u{f9b3}', 616961), ('\u{f9b4}', 617217),
+        ('\u{f9b5}', 617473), ('\u{f9b6}', 617729), ('\u{f9b7}', 617985), ('\u{f9b8}', 618241),
+        ('\u{f9b9}', 618497), ('\u{f9ba}', 618753), ('\u{f9bb}', 619009), ('\u{f9bc}', 619265),
+        ('\u{f9bd}', 619521), ('\u{f9be}', 619777), ('\u{f9bf}', 577025), ('\u{f9c0}', 620033),
+        ('\u{f9c1}', 620289), ('\u{f9c2}', 620545), ('\u{f9c3}', 620801), ('\u{f9c4}', 621057),
+        ('\u{f9c5}', 621313), ('\u{f9c6}', 621569), ('\u{f9c7}', 621825), ('\u{f9c8}', 622081),
+        ('\u{f9c9}', 622337), ('\u{f9ca}', 622593), ('\u{f9cb}', 622849), ('\u{f9cc}', 623105),
+        ('\u{f9cd}', 623361), ('\u{f9ce}', 623617), ('\u{f9cf}', 623873), ('\u{f9d0}', 624129),
+        ('\u{f9d1}', 624385), ('\u{f9d2}', 624641), ('\u{f9d3}', 624897), ('\u{f9d4}', 625153),
+        ('\u{f9d5}', 625409), ('\u{f9d6}', 625665), ('\u{f9d7}', 625921), ('\u{f9d8}', 626177),
+        ('\u{f9d9}', 626433), ('\u{f9da}', 626689), ('\u{f9db}', 596481), ('\u{f9dc}', 626945),
+        ('\u{f9dd}', 627201), ('\u{f9de}', 627457), ('\u{f9df}', 627713), ('\u{f9e0}', 627969),
+        ('\u{f9e1}', 628225), ('\u{f9e2}', 628481), ('\u{f9e3}', 628737), ('\u{f9e4}', 628993),
+        ('\u{f9e5}', 629249), ('\u{f9e6}', 629505), ('\u{f9e7}', 629761), ('\u{f9e8}', 630017),
+        ('\u{f9e9}', 630273), ('\u{f9ea}', 630529), ('\u{f9eb}', 630785), ('\u{f9ec}', 631041),
+        ('\u{f9ed}', 631297), ('\u{f9ee}', 631553), ('\u{f9ef}', 631809), ('\u{f9f0}', 632065),
+        ('\u{f9f1}', 632321), ('\u{f9f2}', 632577), ('\u{f9f3}', 632833), ('\u{f9f4}', 633089),
+        ('\u{f9f5}', 633345), ('\u{f9f6}', 633601), ('\u{f9f7}', 633857), ('\u{f9f8}', 634113),
+        ('\u{f9f9}', 634369), ('\u{f9fa}', 634625), ('\u{f9fb}', 634881), ('\u{f9fc}', 635137),
+        ('\u{f9fd}', 635393), ('\u{f9fe}', 635649), ('\u{f9ff}', 635905), ('\u{fa00}', 636161),
+        ('\u{fa01}', 636417), ('\u{fa02}', 636673), ('\u{fa03}', 636929), ('\u{fa04}', 637185),
+        ('\u{fa05}', 637441), ('\u{fa06}', 637697), ('\u{fa07}', 637953), ('\u{fa08}', 638209),
+        ('\u{fa09}', 638465), ('\u{fa0a}', 638721), ('\u{fa0b}', 638977), ('\u{fa0c}', 639233),
+        ('\u{fa0d}', 639489), ('\u{fa10}', 639745), ('\u{fa12}', 640001), ('\u{fa15}', 640257),
+        ('\u{fa16}', 640513), ('\u{fa17}', 640769), ('\u{fa18}', 641025), ('\u{fa19}', 641281),
+        ('\u{fa1a}', 641537), ('\u{fa1b}', 641793), ('\u{fa1c}', 642049), ('\u{fa1d}', 642305),
+        ('\u{fa1e}', 642561), ('\u{fa20}', 642817), ('\u{fa22}', 643073), ('\u{fa25}', 643329),
+        ('\u{fa26}', 643585), ('\u{fa2a}', 643841), ('\u{fa2b}', 644097), ('\u{fa2c}', 644353),
+        ('\u{fa2d}', 644609), ('\u{fa2e}', 644865), ('\u{fa2f}', 645121), ('\u{fa30}', 645377),
+        ('\u{fa31}', 645633), ('\u{fa32}', 645889), ('\u{fa33}', 646145), ('\u{fa34}', 646401),
+        ('\u{fa35}', 646657), ('\u{fa36}', 646913), ('\u{fa37}', 647169), ('\u{fa38}', 647425),
+        ('\u{fa39}', 647681), ('\u{fa3a}', 647937), ('\u{fa3b}', 648193), ('\u{fa3c}', 648449),
+        ('\u{fa3d}', 648705), ('\u{fa3e}', 648961), ('\u{fa3f}', 649217), ('\u{fa40}', 649473),
+        ('\u{fa41}', 649729), ('\u{fa42}', 649985), ('\u{fa43}', 650241), ('\u{fa44}', 650497),
+        ('\u{fa45}', 650753), ('\u{fa46}', 651009), ('\u{fa47}', 651265), ('\u{fa48}', 651521),
+        ('\u{fa49}', 651777), ('\u{fa4a}', 652033), ('\u{fa4b}', 652289), ('\u{fa4c}', 652545),
+        ('\u{fa4d}', 652801), ('\u{fa4e}', 653057), ('\u{fa4f}', 653313), ('\u{fa50}', 653569),
+        ('\u{fa51}', 653825), ('\u{fa52}', 654081), ('\u{fa53}', 654337), ('\u{fa54}', 654593),
+        ('\u{fa55}', 654849), ('\u{fa56}', 655105), ('\u{fa57}', 610049), ('\u{fa58}', 655361),
+        ('\u{fa59}', 655617), ('\u{fa5a}', 655873), ('\u{fa5b}', 656129), ('\u{fa5c}', 656385),
+        ('\u{fa5d}', 656641), ('\u{fa5e}', 656641), ('\u{fa5f}', 656897), ('\u{fa60}', 657153),
+        ('\u{fa61}', 657409), ('\u{fa62}', 657665), ('\u{fa63}', 657921), ('\u{fa64}', 658177),
+        ('\u{fa65}', 658433), ('\u{fa66}', 658689), ('\u{fa67}', 643329), ('\u{fa68}', 658945),
+        ('\u{fa69}', 659201), ('\u{fa6a}', 659457), ('\u{fa6b}', 659713), ('\u{fa6c}', 659969),
+        ('\u{fa6d}', 660225), ('\u{fa70}', 660481), ('\u{fa71}', 660737), ('\u{fa72}', 660993),
+        ('\u{fa73}', 661249), ('\u{fa74}', 661505), ('\u{fa75}', 661761), ('\u{fa76}', 662017),
+        ('\u{fa77}', 662273), ('\u{fa78}', 646913), ('\u{fa79}', 662529), ('\u{fa7a}', 662785),
+        ('\u{fa7b}', 663041), ('\u{fa7c}', 639745), ('\u{fa7d}', 663297), ('\u{fa7e}', 663553),
+        ('\u{fa7f}', 663809), ('\u{fa80}', 664065), ('\u{fa81}', 664321), ('\u{fa82}', 664577),
+        ('\u{fa83}', 664833), ('\u{fa84}', 665089), ('\u{fa85}', 665345), ('\u{fa86}', 665601),
+        ('\u{fa87}', 665857), ('\u{fa88}', 666113), ('\u{fa89}', 649217), ('\u{fa8a}', 666369),
+        ('\u{fa8b}', 649473), ('\u{fa8c}', 666625), ('\u{fa8d}', 666881), ('\u{fa8e}', 667137),
+        ('\u{fa8f}', 667393), ('\u{fa90}', 667649), ('\u{fa91}', 640001), ('\u{fa92}', 582401),
+        ('\u{fa93}', 667905), ('\u{fa94}', 668161), ('\u{fa95}', 668417), ('\u{fa96}', 600321),
+        ('\u{fa97}', 622593), ('\u{fa98}', 668673), ('\u{fa99}', 668929), ('\u{fa9a}', 651265),
+        ('\u{fa9b}', 669185), ('\u{fa9c}', 651521), ('\u{fa9d}', 669441), ('\u{fa9e}', 669697),
+        ('\u{fa9f}', 669953), ('\u{faa0}', 640513), ('\u{faa1}', 670209), ('\u{faa2}', 670465),
+        ('\u{faa3}', 670721), ('\u{faa4}', 670977), ('\u{faa5}', 671233), ('\u{faa6}', 640769),
+        ('\u{faa7}', 671489), ('\u{faa8}', 671745), ('\u{faa9}', 672001), ('\u{faaa}', 672257),
+        ('\u{faab}', 672513), ('\u{faac}', 672769), ('\u{faad}', 655105), ('\u{faae}', 673025),
+        ('\u{faaf}', 673281), ('\u{fab0}', 610049), ('\u{fab1}', 673537), ('\u{fab2}', 656129),
+        ('\u{fab3}', 673793), ('\u{fab4}', 674049), ('\u{fab5}', 674305), ('\u{fab6}', 674561),
+        ('\u{fab7}', 674817), ('\u{fab8}', 657409), ('\u{fab9}', 675073), ('\u{faba}', 643073),
+        ('\u{fabb}', 675329), ('\u{fabc}', 657665), ('\u{fabd}', 595457), ('\u{fabe}', 675585),
+        ('\u{fabf}', 657921), ('\u{fac0}', 675841), ('\u{fac1}', 658433), ('\u{fac2}', 676097),
+        ('\u{fac3}', 676353), ('\u{fac4}', 676609), ('\u{fac5}', 676865), ('\u{fac6}', 677121),
+        ('\u{fac7}', 658945), ('\u{fac8}', 642049), ('\u{fac9}', 677377), ('\u{faca}', 659201),
+        ('\u{facb}', 677633), ('\u{facc}', 659457), ('\u{facd}', 677889), ('\u{face}', 573953),
+        ('\u{facf}', 678145), ('\u{fad0}', 678401), ('\u{fad1}', 678657), ('\u{fad2}', 678913),
+        ('\u{fad3}', 679169), ('\u{fad4}', 679425), ('\u{fad5}', 679681), ('\u{fad6}', 679937),
+        ('\u{fad7}', 680193), ('\u{fad8}', 680449), ('\u{fad9}', 680705), ('\u{fb1d}', 680962),
+        ('\u{fb1f}', 681474), ('\u{fb2a}', 681986), ('\u{fb2b}', 682498), ('\u{fb2c}', 683011),
+        ('\u{fb2d}', 683779), ('\u{fb2e}', 684546), ('\u{fb2f}', 685058), ('\u{fb30}', 685570),
+        ('\u{fb31}', 686082), ('\u{fb32}', 686594), ('\u{fb33}', 687106), ('\u{fb34}', 687618),
+        ('\u{fb35}', 688130), ('\u{fb36}', 688642), ('\u{fb38}', 689154), ('\u{fb39}', 689666),
+        ('\u{fb3a}', 690178), ('\u{fb3b}', 690690), ('\u{fb3c}', 691202), ('\u{fb3e}', 691714),
+        ('\u{fb40}', 692226), ('\u{fb41}', 692738), ('\u{fb43}', 693250), ('\u{fb44}', 693762),
+        ('\u{fb46}', 694274), ('\u{fb47}', 694786), ('\u{fb48}', 695298), ('\u{fb49}', 695810),
+        ('\u{fb4a}', 696322), ('\u{fb4b}', 696834), ('\u{fb4c}', 697346), ('\u{fb4d}', 697858),
+        ('\u{fb4e}', 698370), ('\u{1109a}', 698882), ('\u{1109c}', 699394), ('\u{110ab}', 699906),
+        ('\u{1112e}', 700418), ('\u{1112f}', 700930), ('\u{1134b}', 701442), ('\u{1134c}', 701954),
+        ('\u{114bb}', 702466), ('\u{114bc}', 702978), ('\u{114be}', 703490), ('\u{115ba}', 704002),
+        ('\u{115bb}', 704514), ('\u{11938}', 705026), ('\u{1d15e}', 705538), ('\u{1d15f}', 706050),
+        ('\u{1d160}', 706563), ('\u{1d161}', 707331), ('\u{1d162}', 708099), ('\u{1d163}', 708867),
+        ('\u{1d164}', 709635), ('\u{1d1bb}', 710402), ('\u{1d1bc}', 710914), ('\u{1d1bd}', 711427),
+        ('\u{1d1be}', 712195), ('\u{1d1bf}', 712963), ('\u{1d1c0}', 713731), ('\u{2f800}', 714497),
+        ('\u{2f801}', 714753), ('\u{2f802}', 715009), ('\u{2f803}', 715265), ('\u{2f804}', 715521),
+        ('\u{2f805}', 645377), ('\u{2f806}', 715777), ('\u{2f807}', 716033), ('\u{2f808}', 716289),
+        ('\u{2f809}', 716545), ('\u{2f80a}', 645633), ('\u{2f80b}', 716801), ('\u{2f80c}', 717057),
+        ('\u{2f80d}', 717313), ('\u{2f80e}', 645889), ('\u{2f80f}', 717569), ('\u{2f810}', 717825),
+        ('\u{2f811}', 718081), ('\u{2f812}', 718337), ('\u{2f813}', 718593), ('\u{2f814}', 718849),
+        ('\u{2f815}', 719105), ('\u{2f816}', 719361), ('\u{2f817}', 719617), ('\u{2f818}', 719873),
+        ('\u{2f819}', 720129), ('\u{2f81a}', 720385), ('\u{2f81b}', 660737), ('\u{2f81c}', 720641),
+        ('\u{2f81d}', 720897), ('\u{2f81e}', 721153), ('\u{2f81f}', 721409), ('\u{2f820}', 721665),
+        ('\u{2f821}', 721921), ('\u{2f822}', 722177), ('\u{2f823}', 722433), ('\u{2f824}', 722689),
+        ('\u{2f825}', 662017), ('\u{2f826}', 646145), ('\u{2f827}', 646401), ('\u{2f828}', 662273),
+        ('\u{2f829}', 722945), ('\u{2f82a}', 723201), ('\u{2f82b}', 596993), ('\u{2f82c}', 723457),
+        ('\u{2f82d}', 646657), ('\u{2f82e}', 723713), ('\u{2f82f}', 723969), ('\u{2f830}', 724225),
+        ('\u{2f831}', 724481), ('\u{2f832}', 724481), ('\u{2f833}', 724481), ('\u{2f834}', 724737),
+        ('\u{2f835}', 724993), ('\u{2f836}', 725249), ('\u{2f837}', 725505), ('\u{2f838}', 725761),
+        ('\u{2f839}', 726017), ('\u{2f83a}', 726273), ('\u{2f83b}', 726529), ('\u{2f83c}', 726785),
+        ('\u{2f83d}', 727041), ('\u{2f83e}', 727297), ('\u{2f83f}', 727553), ('\u{2f840}', 727809),
+        ('\u{2f841}', 728065), ('\u{2f842}', 728321), ('\u{2f843}', 728577), ('\u{2f844}', 728833),
+        ('\u{2f845}', 729089), ('\u{2f846}', 729089), ('\u{2f847}', 662785), ('\u{2f848}', 729345),
+        ('\u{2f849}', 729601), ('\u{2f84a}', 729857), ('\u{2f84b}', 730113), ('\u{2f84c}', 647169),
+        ('\u{2f84d}', 730369), ('\u{2f84e}', 730625), ('\u{2f84f}', 730881), ('\u{2f850}', 636161),
+        ('\u{2f851}', 731137), ('\u{2f852}', 731393), ('\u{2f853}', 731649), ('\u{2f854}', 731905),
+        ('\u{2f855}', 732161), ('\u{2f856}', 732417), ('\u{2f857}', 732673), ('\u{2f858}', 732929),
+        ('\u{2f859}', 733185), ('\u{2f85a}', 733441), ('\u{2f85b}', 733697), ('\u{2f85c}', 733953),
+        ('\u{2f85d}', 734209), ('\u{2f85e}', 734465), ('\u{2f85f}', 734721), ('\u{2f860}', 734977),
+        ('\u{2f861}', 735233), ('\u{2f862}', 735489), ('\u{2f863}', 735745), ('\u{2f864}', 736001),
+        ('\u{2f865}', 736257), ('\u{2f866}', 736513), ('\u{2f867}', 736769), ('\u{2f868}', 737025),
+        ('\u{2f869}', 737281), ('\u{2f86a}', 737537), ('\u{2f86b}', 737537), ('\u{2f86c}', 737793),
+        ('\u{2f86d}', 738049), ('\u{2f86e}', 738305), ('\u{2f86f}', 595969), ('\u{2f870}', 738561),
+        ('\u{2f871}', 738817), ('\u{2f872}', 739073), ('\u{2f873}', 739329), ('\u{2f874}', 739585),
+        ('\u{2f875}', 739841), ('\u{2f876}', 740097), ('\u{2f877}', 740353), ('\u{2f878}', 648449),
+        ('\u{2f879}', 740609), ('\u{2f87a}', 740865), ('\u{2f87b}', 741121), ('\u{2f87c}', 741377),
+        ('\u{2f87d}', 741633), ('\u{2f87e}', 741889), ('\u{2f87f}', 742145), ('\u{2f880}', 742401),
+        ('\u{2f881}', 742657), ('\u{2f882}', 742913), ('\u{2f883}', 743169), ('\u{2f884}', 743425),
+        ('\u{2f885}', 743681), ('\u{2f886}', 743937), ('\u{2f887}', 744193), ('\u{2f888}', 744449),
+        ('\u{2f889}', 744705), ('\u{2f88a}', 744961), ('\u{2f88b}', 745217), ('\u{2f88c}', 745473),
+        ('\u{2f88d}', 745729), ('\u{2f88e}', 582145), ('\u{2f88f}', 745985), ('\u{2f890}', 746241),
+        ('\u{2f891}', 746497), ('\u{2f892}', 746497), ('\u{2f893}', 746753), ('\u{2f894}', 747009),
+        ('\u{2f895}', 747009), ('\u{2f896}', 747265), ('\u{2f897}', 747521), ('\u{2f898}', 747777),
+        ('\u{2f899}', 748033), ('\u{2f89a}', 748289), ('\u{2f89b}', 748545), ('\u{2f89c}', 748801),
+        ('\u{2f89d}', 749057), ('\u{2f89e}', 749313), ('\u{2f89f}', 749569), ('\u{2f8a0}', 749825),
+        ('\u{2f8a1}', 750081), ('\u{2f8a2}', 750337), ('\u{2f8a3}', 648705), ('\u{2f8a4}', 750593),
+        ('\u{2f8a5}', 750849), ('\u{2f8a6}', 751105), ('\u{2f8a7}', 751361), ('\u{2f8a8}', 665857),
+        ('\u{2f8a9}', 751361), ('\u{2f8aa}', 751617), ('\u{2f8ab}', 649217), ('\u{2f8ac}', 751873),
+        ('\u{2f8ad}', 752129), ('\u{2f8ae}', 752385), ('\u{2f8af}', 752641), ('\u{2f8b0}', 649473),
+        ('\u{2f8b1}', 575233), ('\u{2f8b2}', 752897), ('\u{2f8b3}', 753153), ('\u{2f8b4}', 753409),
+        ('\u{2f8b5}', 753665), ('\u{2f8b6}', 753921), ('\u{2f8b7}', 754177), ('\u{2f8b8}', 754433),
+        ('\u{2f8b9}', 754689), ('\u{2f8ba}', 754945), ('\u{2f8bb}', 755201), ('\u{2f8bc}', 755457),
+        ('\u{2f8bd}', 755713), ('\u{2f8be}', 755969), ('\u{2f8bf}', 756225), ('\u{2f8c0}', 756481),
+        ('\u{2f8c1}', 756737), ('\u{2f8c2}', 756993), ('\u{2f8c3}', 757249), ('\u{2f8c4}', 757505),
+        ('\u{2f8c5}', 757761), ('\u{2f8c6}', 758017), ('\u{2f8c7}', 758273), ('\u{2f8c8}', 649729),
+        ('\u{2f8c9}', 758529), ('\u{2f8ca}', 758785), ('\u{2f8cb}', 759041), ('\u{2f8cc}', 759297),
+        ('\u{2f8cd}', 759553), ('\u{2f8ce}', 759809), ('\u{2f8cf}', 650241), ('\u{2f8d0}', 760065),
+        ('\u{2f8d1}', 760321), ('\u{2f8d2}', 760577), ('\u{2f8d3}', 760833), ('\u{2f8d4}', 761089),
+        ('\u{2f8d5}', 761345), ('\u{2f8d6}', 761601), ('\u{2f8d7}', 761857), ('\u{2f8d8}', 582401),
+        ('\u{2f8d9}', 667905), ('\u{2f8da}', 762113), ('\u{2f8db}', 762369), ('\u{2f8dc}', 762625),
+        ('\u{2f8dd}', 762881), ('\u{2f8de}', 763137), ('\u{2f8df}', 763393), ('\u{2f8e0}', 763649),
+        ('\u{2f8e1}', 763905), ('\u{2f8e2}', 650497), ('\u{2f8e3}', 764161), ('\u{2f8e4}', 764417),
+        ('\u{2f8e5}', 764673), ('\u{2f8e6}', 764929), ('\u{2f8e7}', 678913), ('\u{2f8e8}', 765185),
+        ('\u{2f8e9}', 765441), ('\u{2f8ea}', 765697), ('\u{2f8eb}', 765953), ('\u{2f8ec}', 766209),
+        ('\u{2f8ed}', 766465), ('\u{2f8ee}', 766721), ('\u{2f8ef}', 766977), ('\u{2f8f0}', 767233),
+        ('\u{2f8f1}', 767489), ('\u{2f8f2}', 767745), ('\u{2f8f3}', 768001), ('\u{2f8f4}', 768257),
+        ('\u{2f8f5}', 600321), ('\u{2f8f6}', 768513), ('\u{2f8f7}', 768769), ('\u{2f8f8}', 769025),
+        ('\u{2f8f9}', 769281), ('\u{2f8fa}', 769537), ('\u{2f8fb}', 769793), ('\u{2f8fc}', 770049),
+        ('\u{2f8fd}', 770305), ('\u{2f8fe}', 770561), ('\u{2f8ff}', 770817), ('\u{2f900}', 771073),
+        ('\u{2f901}', 650753), ('\u{2f902}', 622593), ('\u{2f903}', 771329), ('\u{2f904}', 771585),
+        ('\u{2f905}', 771841), ('\u{2f906}', 772097), ('\u{2f907}', 772353), ('\u{2f908}', 772609),
+        ('\u{2f909}', 772865), ('\u{2f90a}', 773121), ('\u{2f90b}', 668929), ('\u{2f90c}', 773377),
+        ('\u{2f90d}', 773633), ('\u{2f90e}', 773889), ('\u{2f90f}', 774145), ('\u{2f910}', 774401),
+        ('\u{2f911}', 774657), ('\u{2f912}', 774913), ('\u{2f913}', 775169), ('\u{2f914}', 669185),
+        ('\u{2f915}', 775425), ('\u{2f916}', 775681), ('\u{2f917}', 775937), ('\u{2f918}', 776193),
+        ('\u{2f919}', 776449), ('\u{2f91a}', 776705), ('\u{2f91b}', 776961), ('\u{2f91c}', 777217),
+        ('\u{2f91d}', 777473), ('\u{2f91e}', 777729), ('\u{2f91f}', 777985), ('\u{2f920}', 778241),
+        ('\u{2f921}', 669697), ('\u{2f922}', 778497), ('\u{2f923}', 778753), ('\u{2f924}', 779009),
+        ('\u{2f925}', 779265), ('\u{2f926}', 779521), ('\u{2f927}', 779777), ('\u{2f928}', 780033),
+        ('\u{2f929}', 780289), ('\u{2f92a}', 780545), ('\u{2f92b}', 780801), ('\u{2f92c}', 781057),
+        ('\u{2f92d}', 781057), ('\u{2f92e}', 781313), ('\u{2f92f}', 781569), ('\u{2f930}', 670209),
+        ('\u{2f931}', 781825), ('\u{2f932}', 782081), ('\u{2f933}', 782337), ('\u{2f934}', 782593),
+        ('\u{2f935}', 782849), ('\u{2f936}', 783105), ('\u{2f937}', 783361), ('\u{2f938}', 596737),
+        ('\u{2f939}', 783617), ('\u{2f93a}', 783873), ('\u{2f93b}', 784129), ('\u{2f93c}', 784385),
+        ('\u{2f93d}', 784641), ('\u{2f93e}', 784897), ('\u{2f93f}', 785153), ('\u{2f940}', 671745),
+        ('\u{2f941}', 785409), ('\u{2f942}', 785665), ('\u{2f943}', 785921), ('\u{2f944}', 786177),
+        ('\u{2f945}', 786433), ('\u{2f946}', 786689), ('\u{2f947}', 786689), ('\u{2f948}', 672001),
+        ('\u{2f949}', 679425), ('\u{2f94a}', 786945), ('\u{2f94b}', 787201), ('\u{2f94c}', 787457),
+        ('\u{2f94d}', 787713), ('\u{2f94e}', 787969), ('\u{2f94f}', 587009), ('\u{2f950}', 672513),
+        ('\u{2f951}', 788225), ('\u{2f952}', 788481), ('\u{2f953}', 653569), ('\u{2f954}', 788737),
+        ('\u{2f955}', 788993), ('\u{2f956}', 641793), ('\u{2f957}', 789249), ('\u{2f958}', 789505),
+        ('\u{2f959}', 654593), ('\u{2f95a}', 789761), ('\u{2f95b}', 790017), ('\u{2f95c}', 790273),
+        ('\u{2f95d}', 790529), ('\u{2f95e}', 790529), ('\u{2f95f}', 790785), ('\u{2f960}', 791041),
+        ('\u{2f961}', 791297), ('\u{2f962}', 791553), ('\u{2f963}', 791809), ('\u{2f964}', 792065),
+        ('\u{2f965}', 792321), ('\u{2f966}', 792577), ('\u{2f967}', 792833), ('\u{2f968}', 793089),
+        ('\u{2f969}', 793345), ('\u{2f96a}', 793601), ('\u{2f96b}', 793857), ('\u{2f96c}', 794113),
+        ('\u{2f96d}', 794369), ('\u{2f96e}', 794625), ('\u{2f96f}', 794881), ('\u{2f970}', 795137),
+        ('\u{2f971}', 795393), ('\u{2f972}', 795649), ('\u{2f973}', 795905), ('\u{2f974}', 796161),
+        ('\u{2f975}', 796417), ('\u{2f976}', 796673), ('\u{2f977}', 796929), ('\u{2f978}', 797185),
+        ('\u{2f979}', 797441), ('\u{2f97a}', 656129), ('\u{2f97b}', 797697), ('\u{2f97c}', 797953),
+        ('\u{2f97d}', 798209), ('\u{2f97e}', 798465), ('\u{2f97f}', 798721), ('\u{2f980}', 798977),
+        ('\u{2f981}', 799233), ('\u{2f982}', 799489), ('\u{2f983}', 799745), ('\u{2f984}', 800001),
+        ('\u{2f985}', 800257), ('\u{2f986}', 800513), ('\u{2f987}', 800769), ('\u{2f988}', 801025),
+        ('\u{2f989}', 801281), ('\u{2f98a}', 801537), ('\u{2f98b}', 746753), ('\u{2f98c}', 801793),
+        ('\u{2f98d}', 802049), ('\u{2f98e}', 802305), ('\u{2f98f}', 802561), ('\u{2f990}', 802817),
+        ('\u{2f991}', 803073), ('\u{2f992}', 803329), ('\u{2f993}', 803585), ('\u{2f994}', 803841),
+        ('\u{2f995}', 804097), ('\u{2f996}', 804353), ('\u{2f997}', 804609), ('\u{2f998}', 601345),
+        ('\u{2f999}', 804865), ('\u{2f99a}', 805121), ('\u{2f99b}', 805377), ('\u{2f99c}', 805633),
+        ('\u{2f99d}', 805889), ('\u{2f99e}', 806145), ('\u{2f99f}', 656897), ('\u{2f9a0}', 806401),
+        ('\u{2f9a1}', 806657), ('\u{2f9a2}', 806913), ('\u{2f9a3}', 807169), ('\u{2f9a4}', 807425),
+        ('\u{2f9a5}', 807681), ('\u{2f9a6}', 807937), ('\u{2f9a7}', 808193), ('\u{2f9a8}', 808449),
+        ('\u{2f9a9}', 808705), ('\u{2f9aa}', 808961), ('\u{2f9ab}', 809217), ('\u{2f9ac}', 809473),
+        ('\u{2f9ad}', 809729), ('\u{2f9ae}', 809985), ('\u{2f9af}', 810241), ('\u{2f9b0}', 810497),
+        ('\u{2f9b1}', 810753), ('\u{2f9b2}', 811009), ('\u{2f9b3}', 811265), ('\u{2f9b4}', 585729),
+        ('\u{2f9b5}', 811521), ('\u{2f9b6}', 811777), ('\u{2f9b7}', 812033), ('\u{2f9b8}', 812289),
+        ('\u{2f9b9}', 812545), ('\u{2f9ba}', 812801), ('\u{2f9bb}', 674305), ('\u{2f9bc}', 813057),
+        ('\u{2f9bd}', 813313), ('\u{2f9be}', 813569), ('\u{2f9bf}', 813825), ('\u{2f9c0}', 814081),
+        ('\u{2f9c1}', 814337), ('\u{2f9c2}', 814593), ('\u{2f9c3}', 814849), ('\u{2f9c4}', 815105),
+        ('\u{2f9c5}', 815361), ('\u{2f9c6}', 815617), ('\u{2f9c7}', 815873), ('\u{2f9c8}', 816129),
+        ('\u{2f9c9}', 816385), ('\u{2f9ca}', 816641), ('\u{2f9cb}', 816897), ('\u{2f9cc}', 817153),
+        ('\u{2f9cd}', 817409), ('\u{2f9ce}', 817665), ('\u{2f9cf}', 817921), ('\u{2f9d0}', 675585),
+        ('\u{2f9d1}', 675841), ('\u{2f9d2}', 818177), ('\u{2f9d3}', 818433), ('\u{2f9d4}', 818689),
+        ('\u{2f9d5}', 818945), ('\u{2f9d6}', 819201), ('\u{2f9d7}', 819457), ('\u{2f9d8}', 819713),
+        ('\u{2f9d9}', 819969), ('\u{2f9da}', 820225), ('\u{2f9db}', 820481), ('\u{2f9dc}', 820737),
+        ('\u{2f9dd}', 820993), ('\u{2f9de}', 821249), ('\u{2f9df}', 676097), ('\u{2f9e0}', 821505),
+        ('\u{2f9e1}', 821761), ('\u{2f9e2}', 822017), ('\u{2f9e3}', 822273), ('\u{2f9e4}', 822529),
+        ('\u{2f9e5}', 822785), ('\u{2f9e6}', 823041), ('\u{2f9e7}', 823297), ('\u{2f9e8}', 823553),
+        ('\u{2f9e9}', 823809), ('\u{2f9ea}', 824065), ('\u{2f9eb}', 824321), ('\u{2f9ec}', 824577),
+        ('\u{2f9ed}', 824833), ('\u{2f9ee}', 825089), ('\u{2f9ef}', 825345), ('\u{2f9f0}', 825601),
+        ('\u{2f9f1}', 825857), ('\u{2f9f2}', 826113), ('\u{2f9f3}', 826369), ('\u{2f9f4}', 826625),
+        ('\u{2f9f5}', 826881), ('\u{2f9f6}', 827137), ('\u{2f9f7}', 827393), ('\u{2f9f8}', 827649),
+        ('\u{2f9f9}', 827905), ('\u{2f9fa}', 828161), ('\u{2f9fb}', 828417), ('\u{2f9fc}', 828673),
+        ('\u{2f9fd}', 828929), ('\u{2f9fe}', 677633), ('\u{2f9ff}', 677633), ('\u{2fa00}', 829185),
+        ('\u{2fa01}', 829441), ('\u{2fa02}', 829697), ('\u{2fa03}', 829953), ('\u{2fa04}', 830209),
+        ('\u{2fa05}', 830465), ('\u{2fa06}', 830721), ('\u{2fa07}', 830977), ('\u{2fa08}', 831233),
+        ('\u{2fa09}', 831489), ('\u{2fa0a}', 677889), ('\u{2fa0b}', 831745), ('\u{2fa0c}', 832001),
+        ('\u{2fa0d}', 832257), ('\u{2fa0e}', 832513), ('\u{2fa0f}', 832769), ('\u{2fa10}', 833025),
+        ('\u{2fa11}', 833281), ('\u{2fa12}', 833537), ('\u{2fa13}', 833793), ('\u{2fa14}', 834049),
+        ('\u{2fa15}', 834305), ('\u{2fa16}', 834561), ('\u{2fa17}', 834817), ('\u{2fa18}', 835073),
+        ('\u{2fa19}', 835329), ('\u{2fa1a}', 835585), ('\u{2fa1b}', 835841), ('\u{2fa1c}', 836097),
+        ('\u{2fa1d}', 836353),
+    ];
+
+    static CANONICAL_CHARS: &[char] = &[
+        'A', '\u{300}', 'A', '\u{301}', 'A', '\u{302}', 'A', '\u{303}', 'A', '\u{308}', 'A',
+        '\u{30a}', 'C', '\u{327}', 'E', '\u{300}', 'E', '\u{301}', 'E', '\u{302}', 'E', '\u{308}',
+        'I', '\u{300}', 'I', '\u{301}', 'I', '\u{302}', 'I', '\u{308}', 'N', '\u{303}', 'O',
+        '\u{300}', 'O', '\u{301}', 'O', '\u{302}', 'O', '\u{303}', 'O', '\u{308}', 'U', '\u{300}',
+        'U', '\u{301}', 'U', '\u{302}', 'U', '\u{308}', 'Y', '\u{301}', 'a', '\u{300}', 'a',
+        '\u{301}', 'a', '\u{302}', 'a', '\u{303}', 'a', '\u{308}', 'a', '\u{30a}', 'c', '\u{327}',
+        'e', '\u{300}', 'e', '\u{301}', 'e', '\u{302}', 'e', '\u{308}', 'i', '\u{300}', 'i',
+        '\u{301}', 'i', '\u{302}', 'i', '\u{308}', 'n', '\u{303}', 'o', '\u{300}', 'o', '\u{301}',
+        'o', '\u{302}', 'o', '\u{303}', 'o', '\u{308}', 'u', '\u{300}', 'u', '\u{301}', 'u',
+        '\u{302}', 'u', '\u{308}', 'y', '\u{301}', 'y', '\u{308}', 'A', '\u{304}', 'a', '\u{304}',
+        'A', '\u{306}', 'a', '\u{306}', 'A', '\u{328}', 'a', '\u{328}', 'C', '\u{301}', 'c',
+        '\u{301}', 'C', '\u{302}', 'c', '\u{302}', 'C', '\u{307}', 'c', '\u{307}', 'C', '\u{30c}',
+        'c', '\u{30c}', 'D', '\u{30c}', 'd', '\u{30c}', 'E', '\u{304}', 'e', '\u{304}', 'E',
+        '\u{306}', 'e', '\u{306}', 'E', '\u{307}', 'e', '\u{307}', 'E', '\u{328}', 'e', '\u{328}',
+        'E', '\u{30c}', 'e', '\u{30c}', 'G', '\u{302}', 'g', '\u{302}', 'G', '\u{306}', 'g',
+        '\u{306}', 'G', '\u{307}', 'g', '\u{307}', 'G', '\u{327}', 'g', '\u{327}', 'H', '\u{302}',
+        'h', '\u{302}', 'I', '\u{303}', 'i', '\u{303}', 'I', '\u{304}', 'i', '\u{304}', 'I',
+        '\u{306}', 'i', '\u{306}', 'I', '\u{328}', 'i', '\u{328}', 'I', '\u{307}', 'J', '\u{302}',
+        'j', '\u{302}', 'K', '\u{327}', 'k', '\u{327}', 'L', '\u{301}', 'l', '\u{301}', 'L',
+        '\u{327}', 'l', '\u{327}', 'L', '\u{30c}', 'l', '\u{30c}', 'N', '\u{301}', 'n', '\u{301}',
+        'N', '\u{327}', 'n', '\u{327}', 'N', '\u{30c}', 'n', '\u{30c}', 'O', '\u{304}', 'o',
+        '\u{304}', 'O', '\u{306}', 'o', '\u{306}', 'O', '\u{30b}', 'o', '\u{30b}', 'R', '\u{301}',
+        'r', '\u{301}', 'R', '\u{327}', 'r', '\u{327}', 'R', '\u{30c}', 'r', '\u{30c}', 'S',
+        '\u{301}', 's', '\u{301}', 'S', '\u{302}', 's', '\u{302}', 'S', '\u{327}', 's', '\u{327}',
+        'S', '\u{30c}', 's', '\u{30c}', 'T', '\u{327}', 't', '\u{327}', 'T', '\u{30c}', 't',
+        '\u{30c}', 'U', '\u{303}', 'u', '\u{303}', 'U', '\u{304}', 'u', '\u{304}', 'U', '\u{306}',
+        'u', '\u{306}', 'U', '\u{30a}', 'u', '\u{30a}', 'U', '\u{30b}', 'u', '\u{30b}', 'U',
+        '\u{328}', 'u', '\u{328}', 'W', '\u{302}', 'w', '\u{302}', 'Y', '\u{302}', 'y', '\u{302}',
+        'Y', '\u{308}', 'Z', '\u{301}', 'z', '\u{301}', 'Z', '\u{307}', 'z', '\u{307}', 'Z',
+        '\u{30c}', 'z', '\u{30c}', 'O', '\u{31b}', 'o', '\u{31b}', 'U', '\u{31b}', 'u', '\u{31b}',
+        'A', '\u{30c}', 'a', '\u{30c}', 'I', '\u{30c}', 'i', '\u{30c}', 'O', '\u{30c}', 'o',
+        '\u{30c}', 'U', '\u{30c}', 'u', '\u{30c}', 'U', '\u{308}', '\u{304}', 'u', '\u{308}',
+        '\u{304}', 'U', '\u{308}', '\u{301}', 'u', '\u{308}', '\u{301}', 'U', '\u{308}', '\u{30c}',
+        'u', '\u{308}', '\u{30c}', 'U', '\u{308}', '\u{300}', 'u', '\u{308}', '\u{300}', 'A',
+        '\u{308}', '\u{304}', 'a', '\u{308}', '\u{304}', 'A', '\u{307}', '\u{304}', 'a', '\u{307}',
+        '\u{304}', '\u{c6}', '\u{304}', '\u{e6}', '\u{304}', 'G', '\u{30c}', 'g', '\u{30c}', 'K',
+        '\u{30c}', 'k', '\u{30c}', 'O', '\u{328}', 'o', '\u{328}', 'O', '\u{328}', '\u{304}', 'o',
+        '\u{328}', '\u{304}', '\u{1b7}', '\u{30c}', '\u{292}', '\u{30c}', 'j', '\u{30c}', 'G',
+        '\u{301}', 'g', '\u{301}', 'N', '\u{300}', 'n', '\u{300}', 'A', '\u{30a}', '\u{301}', 'a',
+        '\u{30a}', '\u{301}', '\u{c6}', '\u{301}', '\u{e6}', '\u{301}', '\u{d8}', '\u{301}',
+        '\u{f8}', '\u{301}', 'A', '\u{30f}', 'a', '\u{30f}', 'A', '\u{311}', 'a', '\u{311}', 'E',
+        '\u{30f}', 'e', '\u{30f}', 'E', '\u{311}', 'e', '\u{311}', 'I', '\u{30f}', 'i', '\u{30f}',
+        'I', '\u{311}', 'i', '\u{311}', 'O', '\u{30f}', 'o', '\u{30f}', 'O', '\u{311}', 'o',
+        '\u{311}', 'R', '\u{30f}', 'r', '\u{30f}', 'R', '\u{311}', 'r', '\u{311}', 'U', '\u{30f}',
+        'u', '\u{30f}', 'U', '\u{311}', 'u', '\u{311}', 'S', '\u{326}', 's', '\u{326}', 'T',
+        '\u{326}', 't', '\u{326}', 'H', '\u{30c}', 'h', '\u{30c}', 'A', '\u{307}', 'a', '\u{307}',
+        'E', '\u{327}', 'e', '\u{327}', 'O', '\u{308}', '\u{304}', 'o', '\u{308}', '\u{304}', 'O',
+        '\u{303}', '\u{304}', 'o', '\u{303}', '\u{304}', 'O', '\u{307}', 'o', '\u{307}', 'O',
+        '\u{307}', '\u{304}', 'o', '\u{307}', '\u{304}', 'Y', '\u{304}', 'y', '\u{304}', '\u{300}',
+        '\u{301}', '\u{313}', '\u{308}', '\u{301}', '\u{2b9}', ';', '\u{a8}', '\u{301}', '\u{391}',
+        '\u{301}', '\u{b7}', '\u{395}', '\u{301}', '\u{397}', '\u{301}', '\u{399}', '\u{301}',
+        '\u{39f}', '\u{301}', '\u{3a5}', '\u{301}', '\u{3a9}', '\u{301}', '\u{3b9}', '\u{308}',
+        '\u{301}', '\u{399}', '\u{308}', '\u{3a5}', '\u{308}', '\u{3b1}', '\u{301}', '\u{3b5}',
+        '\u{301}', '\u{3b7}', '\u{301}', '\u{3b9}', '\u{301}', '\u{3c5}', '\u{308}', '\u{301}',
+        '\u{3b9}', '\u{308}', '\u{3c5}', '\u{308}', '\u{3bf}', '\u{301}', '\u{3c5}', '\u{301}',
+        '\u{3c9}', '\u{301}', '\u{3d2}', '\u{301}', '\u{3d2}', '\u{308}', '\u{415}', '\u{300}',
+        '\u{415}', '\u{308}', '\u{413}', '\u{301}', '\u{406}', '\u{308}', '\u{41a}', '\u{301}',
+        '\u{418}', '\u{300}', '\u{423}', '\u{306}', '\u{418}', '\u{306}', '\u{438}', '\u{306}',
+        '\u{435}', '\u{300}', '\u{435}', '\u{308}', '\u{433}', '\u{301}', '\u{456}', '\u{308}',
+        '\u{43a}', '\u{301}', '\u{438}', '\u{300}', '\u{443}', '\u{306}', '\u{474}', '\u{30f}',
+        '\u{475}', '\u{30f}', '\u{416}', '\u{306}', '\u{436}', '\u{306}', '\u{410}', '\u{306}',
+        '\u{430}', '\u{306}', '\u{410}', '\u{308}', '\u{430}', '\u{308}', '\u{415}', '\u{306}',
+        '\u{435}', '\u{306}', '\u{4d8}', '\u{308}', '\u{4d9}', '\u{308}', '\u{416}', '\u{308}',
+        '\u{436}', '\u{308}', '\u{417}', '\u{308}', '\u{437}', '\u{308}', '\u{418}', '\u{304}',
+        '\u{438}', '\u{304}', '\u{418}', '\u{308}', '\u{438}', '\u{308}', '\u{41e}', '\u{308}',
+        '\u{43e}', '\u{308}', '\u{4e8}', '\u{308}', '\u{4e9}', '\u{308}', '\u{42d}', '\u{308}',
+        '\u{44d}', '\u{308}', '\u{423}', '\u{304}', '\u{443}', '\u{304}', '\u{423}', '\u{308}',
+        '\u{443}', '\u{308}', '\u{423}', '\u{30b}', '\u{443}', '\u{30b}', '\u{427}', '\u{308}',
+        '\u{447}', '\u{308}', '\u{42b}', '\u{308}', '\u{44b}', '\u{308}', '\u{627}', '\u{653}',
+        '\u{627}', '\u{654}', '\u{648}', '\u{654}', '\u{627}', '\u{655}', '\u{64a}', '\u{654}',
+        '\u{6d5}', '\u{654}', '\u{6c1}', '\u{654}', '\u{6d2}', '\u{654}', '\u{928}', '\u{93c}',
+        '\u{930}', '\u{93c}', '\u{933}', '\u{93c}', '\u{915}', '\u{93c}', '\u{916}', '\u{93c}',
+        '\u{917}', '\u{93c}', '\u{91c}', '\u{93c}', '\u{921}', '\u{93c}', '\u{922}', '\u{93c}',
+        '\u{92b}', '\u{93c}', '\u{92f}', '\u{93c}', '\u{9c7}', '\u{9be}', '\u{9c7}', '\u{9d7}',
+        '\u{9a1}', '\u{9bc}', '\u{9a2}', '\u{9bc}', '\u{9af}', '\u{9bc}', '\u{a32}', '\u{a3c}',
+        '\u{a38}', '\u{a3c}', '\u{a16}', '\u{a3c}', '\u{a17}', '\u{a3c}', '\u{a1c}', '\u{a3c}',
+        '\u{a2b}', '\u{a3c}', '\u{b47}', '\u{b56}', '\u{b47}', '\u{b3e}', '\u{b47}', '\u{b57}',
+        '\u{b21}', '\u{b3c}', '\u{b22}', '\u{b3c}', '\u{b92}', '\u{bd7}', '\u{bc6}', '\u{bbe}',
+        '\u{bc7}', '\u{bbe}', '\u{bc6}', '\u{bd7}', '\u{c46}', '\u{c56}', '\u{cbf}', '\u{cd5}',
+        '\u{cc6}', '\u{cd5}', '\u{cc6}', '\u{cd6}', '\u{cc6}', '\u{cc2}', '\u{cc6}', '\u{cc2}',
+        '\u{cd5}', '\u{d46}', '\u{d3e}', '\u{d47}', '\u{d3e}', '\u{d46}', '\u{d57}', '\u{dd9}',
+        '\u{dca}', '\u{dd9}', '\u{dcf}', '\u{dd9}', '\u{dcf}', '\u{dca}', '\u{dd9}', '\u{ddf}',
+        '\u{f42}', '\u{fb7}', '\u{f4c}', '\u{fb7}', '\u{f51}', '\u{fb7}', '\u{f56}', '\u{fb7}',
+        '\u{f5b}', '\u{fb7}', '\u{f40}', '\u{fb5}', '\u{f71}', '\u{f72}', '\u{f71}', '\u{f74}',
+        '\u{fb2}', '\u{f80}', '\u{fb3}', '\u{f80}', '\u{f71}', '\u{f80}', '\u{f92}', '\u{fb7}',
+        '\u{f9c}', '\u{fb7}', '\u{fa1}', '\u{fb7}', '\u{fa6}', '\u{fb7}', '\u{fab}', '\u{fb7}',
+        '\u{f90}', '\u{fb5}', '\u{1025}', '\u{102e}', '\u{1b05}', '\u{1b35}', '\u{1b07}',
+        '\u{1b35}', '\u{1b09}', '\u{1b35}', '\u{1b0b}', '\u{1b35}', '\u{1b0d}', '\u{1b35}',
+        '\u{1b11}', '\u{1b35}', '\u{1b3a}', '\u{1b35}', '\u{1b3c}', '\u{1b35}', '\u{1b3e}',
+        '\u{1b35}', '\u{1b3f}', '\u{1b35}', '\u{1b42}', '\u{1b35}', 'A', '\u{325}', 'a', '\u{325}',
+        'B', '\u{307}', 'b', '\u{307}', 'B', '\u{323}', 'b', '\u{323}', 'B', '\u{331}', 'b',
+        '\u{331}', 'C', '\u{327}', '\u{301}', 'c', '\u{327}', '\u{301}', 'D', '\u{307}', 'd',
+        '\u{307}', 'D', '\u{323}', 'd', '\u{323}', 'D', '\u{331}', 'd', '\u{331}', 'D', '\u{327}',
+        'd', '\u{327}', 'D', '\u{32d}', 'd', '\u{32d}', 'E', '\u{304}', '\u{300}', 'e', '\u{304}',
+        '\u{300}', 'E', '\u{304}', '\u{301}', 'e', '\u{304}', '\u{301}', 'E', '\u{32d}', 'e',
+        '\u{32d}', 'E', '\u{330}', 'e', '\u{330}', 'E', '\u{327}', '\u{306}', 'e', '\u{327}',
+        '\u{306}', 'F', '\u{307}', 'f', '\u{307}', 'G', '\u{304}', 'g', '\u{304}', 'H', '\u{307}',
+        'h', '\u{307}', 'H', '\u{323}', 'h', '\u{323}', 'H', '\u{308}', 'h', '\u{308}', 'H',
+        '\u{327}', 'h', '\u{327}', 'H', '\u{32e}', 'h', '\u{32e}', 'I', '\u{330}', 'i', '\u{330}',
+        'I', '\u{308}', '\u{301}', 'i', '\u{308}', '\u{301}', 'K', '\u{301}', 'k', '\u{301}', 'K',
+        '\u{323}', 'k', '\u{323}', 'K', '\u{331}', 'k', '\u{331}', 'L', '\u{323}', 'l', '\u{323}',
+        'L', '\u{323}', '\u{304}', 'l', '\u{323}', '\u{304}', 'L', '\u{331}', 'l', '\u{331}', 'L',
+        '\u{32d}', 'l', '\u{32d}', 'M', '\u{301}', 'm', '\u{301}', 'M', '\u{307}', 'm', '\u{307}',
+        'M', '\u{323}', 'm', '\u{323}', 'N', '\u{307}', 'n', '\u{307}', 'N', '\u{323}', 'n',
+        '\u{323}', 'N', '\u{331}', 'n', '\u{331}', 'N', '\u{32d}', 'n', '\u{32d}', 'O', '\u{303}',
+        '\u{301}', 'o', '\u{303}', '\u{301}', 'O', '\u{303}', '\u{308}', 'o', '\u{303}', '\u{308}',
+        'O', '\u{304}', '\u{300}', 'o', '\u{304}', '\u{300}', 'O', '\u{304}', '\u{301}', 'o',
+        '\u{304}', '\u{301}', 'P', '\u{301}', 'p', '\u{301}', 'P', '\u{307}', 'p', '\u{307}', 'R',
+        '\u{307}', 'r', '\u{307}', 'R', '\u{323}', 'r', '\u{323}', 'R', '\u{323}', '\u{304}', 'r',
+        '\u{323}', '\u{304}', 'R', '\u{331}', 'r', '\u{331}', 'S', '\u{307}', 's', '\u{307}', 'S',
+        '\u{323}', 's', '\u{323}', 'S', '\u{301}', '\u{307}', 's', '\u{301}', '\u{307}', 'S',
+        '\u{30c}', '\u{307}', 's', '\u{30c}', '\u{307}', 'S', '\u{323}', '\u{307}', 's', '\u{323}',
+        '\u{307}', 'T', '\u{307}', 't', '\u{307}', 'T', '\u{323}', 't', '\u{323}', 'T', '\u{331}',
+        't', '\u{331}', 'T', '\u{32d}', 't', '\u{32d}', 'U', '\u{324}', 'u', '\u{324}', 'U',
+        '\u{330}', 'u', '\u{330}', 'U', '\u{32d}', 'u', '\u{32d}', 'U', '\u{303}', '\u{301}', 'u',
+        '\u{303}', '\u{301}', 'U', '\u{304}', '\u{308}', 'u', '\u{304}', '\u{308}', 'V', '\u{303}',
+        'v', '\u{303}', 'V', '\u{323}', 'v', '\u{323}', 'W', '\u{300}', 'w', '\u{300}', 'W',
+        '\u{301}', 'w', '\u{301}', 'W', '\u{308}', 'w', '\u{308}', 'W', '\u{307}', 'w', '\u{307}',
+        'W', '\u{323}', 'w', '\u{323}', 'X', '\u{307}', 'x', '\u{307}', 'X', '\u{308}', 'x',
+        '\u{308}', 'Y', '\u{307}', 'y', '\u{307}', 'Z', '\u{302}', 'z', '\u{302}', 'Z', '\u{323}',
+        'z', '\u{323}', 'Z', '\u{331}', 'z', '\u{331}', 'h', '\u{331}', 't', '\u{308}', 'w',
+        '\u{30a}', 'y', '\u{30a}', '\u{17f}', '\u{307}', 'A', '\u{323}', 'a', '\u{323}', 'A',
+        '\u{309}', 'a', '\u{309}', 'A', '\u{302}', '\u{301}', 'a', '\u{302}', '\u{301}', 'A',
+        '\u{302}', '\u{300}', 'a', '\u{302}', '\u{300}', 'A', '\u{302}', '\u{309}', 'a', '\u{302}',
+        '\u{309}', 'A', '\u{302}', '\u{303}', 'a', '\u{302}', '\u{303}', 'A', '\u{323}', '\u{302}',
+        'a', '\u{323}', '\u{302}', 'A', '\u{306}', '\u{301}', 'a', '\u{306}', '\u{301}', 'A',
+        '\u{306}', '\u{300}', 'a', '\u{306}', '\u{300}', 'A', '\u{306}', '\u{309}', 'a', '\u{306}',
+        '\u{309}', 'A', '\u{306}', '\u{303}', 'a', '\u{306}', '\u{303}', 'A', '\u{323}', '\u{306}',
+        'a', '\u{323}', '\u{306}', 'E', '\u{323}', 'e', '\u{323}', 'E', '\u{309}', 'e', '\u{309}',
+        'E', '\u{303}', 'e', '\u{303}', 'E', '\u{302}', '\u{301}', 'e', '\u{302}', '\u{301}', 'E',
+        '\u{302}', '\u{300}', 'e', '\u{302}', '\u{300}', 'E', '\u{302}', '\u{309}', 'e', '\u{302}',
+        '\u{309}', 'E', '\u{302}', '\u{303}', 'e', '\u{302}', '\u{303}', 'E', '\u{323}', '\u{302}',
+        'e', '\u{323}', '\u{302}', 'I', '\u{309}', 'i', '\u{309}', 'I', '\u{323}', 'i', '\u{323}',
+        'O', '\u{323}', 'o', '\u{323}', 'O', '\u{309}', 'o', '\u{309}', 'O', '\u{302}', '\u{301}',
+        'o', '\u{302}', '\u{301}', 'O', '\u{302}', '\u{300}', 'o', '\u{302}', '\u{300}', 'O',
+        '\u{302}', '\u{309}', 'o', '\u{302}', '\u{309}', 'O', '\u{302}', '\u{303}', 'o', '\u{302}',
+        '\u{303}', 'O', '\u{323}', '\u{302}', 'o', '\u{323}', '\u{302}', 'O', '\u{31b}', '\u{301}',
+        'o', '\u{31b}', '\u{301}', 'O', '\u{31b}', '\u{300}', 'o', '\u{31b}', '\u{300}', 'O',
+        '\u{31b}', '\u{309}', 'o', '\u{31b}', '\u{309}', 'O', '\u{31b}', '\u{303}', 'o', '\u{31b}',
+        '\u{303}', 'O', '\u{31b}', '\u{323}', 'o', '\u{31b}', '\u{323}', 'U', '\u{323}', 'u',
+        '\u{323}', 'U', '\u{309}', 'u', '\u{309}', 'U', '\u{31b}', '\u{301}', 'u', '\u{31b}',
+        '\u{301}', 'U', '\u{31b}', '\u{300}', 'u', '\u{31b}', '\u{300}', 'U', '\u{31b}', '\u{309}',
+        'u', '\u{31b}', '\u{309}', 'U', '\u{31b}', '\u{303}', 'u', '\u{31b}', '\u{303}', 'U',
+        '\u{31b}', '\u{323}', 'u', '\u{31b}', '\u{323}', 'Y', '\u{300}', 'y', '\u{300}', 'Y',
+        '\u{323}', 'y', '\u{323}', 'Y', '\u{309}', 'y', '\u{309}', 'Y', '\u{303}', 'y', '\u{303}',
+        '\u{3b1}', '\u{313}', '\u{3b1}', '\u{314}', '\u{3b1}', '\u{313}', '\u{300}', '\u{3b1}',
+        '\u{314}', '\u{300}', '\u{3b1}', '\u{313}', '\u{301}', '\u{3b1}', '\u{314}', '\u{301}',
+        '\u{3b1}', '\u{313}', '\u{342}', '\u{3b1}', '\u{314}', '\u{342}', '\u{391}', '\u{313}',
+        '\u{391}', '\u{314}', '\u{391}', '\u{313}', '\u{300}', '\u{391}', '\u{314}', '\u{300}',
+        '\u{391}', '\u{313}', '\u{301}', '\u{391}', '\u{314}', '\u{301}', '\u{391}', '\u{313}',
+        '\u{342}', '\u{391}', '\u{314}', '\u{342}', '\u{3b5}', '\u{313}', '\u{3b5}', '\u{314}',
+        '\u{3b5}', '\u{313}', '\u{300}', '\u{3b5}', '\u{314}', '\u{300}', '\u{3b5}', '\u{313}',
+        '\u{301}', '\u{3b5}', '\u{314}', '\u{301}', '\u{395}', '\u{313}', '\u{395}', '\u{314}',
+        '\u{395}', '\u{313}', '\u{300}', '\u{395}', '\u{314}', '\u{300}', '\u{395}', '\u{313}',
+        '\u{301}', '\u{395}', '\u{314}', '\u{301}', '\u{3b7}', '\u{313}', '\u{3b7}', '\u{314}',
+        '\u{3b7}', '\u{313}', '\u{300}', '\u{3b7}', '\u{314}', '\u{300}', '\u{3b7}', '\u{313}',
+        '\u{301}', '\u{3b7}', '\u{314}', '\u{301}', '\u{3b7}', '\u{313}', '\u{342}', '\u{3b7}',
+        '\u{314}', '\u{342}', '\u{397}', '\u{313}', '\u{397}', '\u{314}', '\u{397}', '\u{313}',
+        '\u{300}', '\u{397}', '\u{314}', '\u{300}', '\u{397}', '\u{313}', '\u{301}', '\u{397}',
+        '\u{314}', '\u{301}', '\u{397}', '\u{313}', '\u{342}', '\u{397}', '\u{314}', '\u{342}',
+        '\u{3b9}', '\u{313}', '\u{3b9}', '\u{314}', '\u{3b9}', '\u{313}', '\u{300}', '\u{3b9}',
+        '\u{314}', '\u{300}', '\u{3b9}', '\u{313}', '\u{301}', '\u{3b9}', '\u{314}', '\u{301}',
+        '\u{3b9}', '\u{313}', '\u{342}', '\u{3b9}', '\u{314}', '\u{342}', '\u{399}', '\u{313}',
+        '\u{399}', '\u{314}', '\u{399}', '\u{313}', '\u{300}', '\u{399}', '\u{314}', '\u{300}',
+        '\u{399}', '\u{313}', '\u{301}', '\u{399}', '\u{314}', '\u{301}', '\u{399}', '\u{313}',
+        '\u{342}', '\u{399}', '\u{314}', '\u{342}', '\u{3bf}', '\u{313}', '\u{3bf}', '\u{314}',
+        '\u{3bf}', '\u{313}', '\u{300}', '\u{3bf}', '\u{314}', '\u{300}', '\u{3bf}', '\u{313}',
+        '\u{301}', '\u{3bf}', '\u{314}', '\u{301}', '\u{39f}', '\u{313}', '\u{39f}', '\u{314}',
+        '\u{39f}', '\u{313}', '\u{300}', '\u{39f}', '\u{314}', '\u{300}', '\u{39f}', '\u{313}',
+        '\u{301}', '\u{39f}', '\u{314}', '\u{301}', '\u{3c5}', '\u{313}', '\u{3c5}', '\u{314}',
+        '\u{3c5}', '\u{313}', '\u{300}', '\u{3c5}', '\u{314}', '\u{300}', '\u{3c5}', '\u{313}',
+        '\u{301}', '\u{3c5}', '\u{314}', '\u{301}', '\u{3c5}', '\u{313}', '\u{342}', '\u{3c5}',
+        '\u{314}', '\u{342}', '\u{3a5}', '\u{314}', '\u{3a5}', '\u{314}', '\u{300}', '\u{3a5}',
+        '\u{314}', '\u{301}', '\u{3a5}', '\u{314}', '\u{342}', '\u{3c9}', '\u{313}', '\u{3c9}',
+        '\u{314}', '\u{3c9}', '\u{313}', '\u{300}', '\u{3c9}', '\u{314}', '\u{300}', '\u{3c9}',
+        '\u{313}', '\u{301}', '\u{3c9}', '\u{314}', '\u{301}', '\u{3c9}', '\u{313}', '\u{342}',
+        '\u{3c9}', '\u{314}', '\u{342}', '\u{3a9}', '\u{313}', '\u{3a9}', '\u{314}', '\u{3a9}',
+        '\u{313}', '\u{300}', '\u{3a9}', '\u{314}', '\u{300}', '\u{3a9}', '\u{313}', '\u{301}',
+        '\u{3a9}', '\u{314}', '\u{301}', '\u{3a9}', '\u{313}', '\u{342}', '\u{3a9}', '\u{314}',
+        '\u{342}', '\u{3b1}', '\u{300}', '\u{3b5}', '\u{300}', '\u{3b7}', '\u{300}', '\u{3b9}',
+        '\u{300}', '\u{3bf}', '\u{300}', '\u{3c5}', '\u{300}', '\u{3c9}', '\u{300}', '\u{3b1}',
+        '\u{313}', '\u{345}', '\u{3b1}', '\u{314}', '\u{345}', '\u{3b1}', '\u{313}', '\u{300}',
+        '\u{345}', '\u{3b1}', '\u{314}', '\u{300}', '\u{345}', '\u{3b1}', '\u{313}', '\u{301}',
+        '\u{345}', '\u{3b1}', '\u{314}', '\u{301}', '\u{345}', '\u{3b1}', '\u{313}', '\u{342}',
+        '\u{345}', '\u{3b1}', '\u{314}', '\u{342}', '\u{345}', '\u{391}', '\u{313}', '\u{345}',
+        '\u{391}', '\u{314}', '\u{345}', '\u{391}', '\u{313}', '\u{300}', '\u{345}', '\u{391}',
+        '\u{314}', '\u{300}', '\u{345}', '\u{391}', '\u{313}', '\u{301}', '\u{345}', '\u{391}',
+        '\u{314}', '\u{301}', '\u{345}', '\u{391}', '\u{313}', '\u{342}', '\u{345}', '\u{391}',
+        '\u{314}', '\u{342}', '\u{345}', '\u{3b7}', '\u{313}', '\u{345}', '\u{3b7}', '\u{314}',
+        '\u{345}', '\u{3b7}', '\u{313}', '\u{300}', '\u{345}', '\u{3b7}', '\u{314}', '\u{300}',
+        '\u{345}', '\u{3b7}', '\u{313}', '\u{301}', '\u{345}', '\u{3b7}', '\u{314}', '\u{301}',
+        '\u{345}', '\u{3b7}', '\u{313}', '\u{342}', '\u{345}', '\u{3b7}', '\u{314}', '\u{342}',
+        '\u{345}', '\u{397}', '\u{313}', '\u{345}', '\u{397}', '\u{314}', '\u{345}', '\u{397}',
+        '\u{313}', '\u{300}', '\u{345}', '\u{397}', '\u{314}', '\u{300}', '\u{345}', '\u{397}',
+        '\u{313}', '\u{301}', '\u{345}', '\u{397}', '\u{314}', '\u{301}', '\u{345}', '\u{397}',
+        '\u{313}', '\u{342}', '\u{345}', '\u{397}', '\u{314}', '\u{342}', '\u{345}', '\u{3c9}',
+        '\u{313}', '\u{345}', '\u{3c9}', '\u{314}', '\u{345}', '\u{3c9}', '\u{313}', '\u{300}',
+        '\u{345}', '\u{3c9}', '\u{314}', '\u{300}', '\u{345}', '\u{3c9}', '\u{313}', '\u{301}',
+        '\u{345}', '\u{3c9}', '\u{314}', '\u{301}', '\u{345}', '\u{3c9}', '\u{313}', '\u{342}',
+        '\u{345}', '\u{3c9}', '\u{314}', '\u{342}', '\u{345}', '\u{3a9}', '\u{313}', '\u{345}',
+        '\u{3a9}', '\u{314}', '\u{345}', '\u{3a9}', '\u{313}', '\u{300}', '\u{345}', '\u{3a9}',
+        '\u{314}', '\u{300}', '\u{345}', '\u{3a9}', '\u{313}', '\u{301}', '\u{345}', '\u{3a9}',
+        '\u{314}', '\u{301}', '\u{345}', '\u{3a9}', '\u{313}', '\u{342}', '\u{345}', '\u{3a9}',
+        '\u{314}', '\u{342}', '\u{345}', '\u{3b1}', '\u{306}', '\u{3b1}', '\u{304}', '\u{3b1}',
+        '\u{300}', '\u{345}', '\u{3b1}', '\u{345}', '\u{3b1}', '\u{301}', '\u{345}', '\u{3b1}',
+        '\u{342}', '\u{3b1}', '\u{342}', '\u{345}', '\u{391}', '\u{306}', '\u{391}', '\u{304}',
+        '\u{391}', '\u{300}', '\u{391}', '\u{345}', '\u{3b9}', '\u{a8}', '\u{342}', '\u{3b7}',
+        '\u{300}', '\u{345}', '\u{3b7}', '\u{345}', '\u{3b7}', '\u{301}', '\u{345}', '\u{3b7}',
+        '\u{342}', '\u{3b7}', '\u{342}', '\u{345}', '\u{395}', '\u{300}', '\u{397}', '\u{300}',
+        '\u{397}', '\u{345}', '\u{1fbf}', '\u{300}', '\u{1fbf}', '\u{301}', '\u{1fbf}', '\u{342}',
+        '\u{3b9}', '\u{306}', '\u{3b9}', '\u{304}', '\u{3b9}', '\u{308}', '\u{300}', '\u{3b9}',
+        '\u{342}', '\u{3b9}', '\u{308}', '\u{342}', '\u{399}', '\u{306}', '\u{399}', '\u{304}',
+        '\u{399}', '\u{300}', '\u{1ffe}', '\u{300}', '\u{1ffe}', '\u{301}', '\u{1ffe}', '\u{342}',
+        '\u{3c5}', '\u{306}', '\u{3c5}', '\u{304}', '\u{3c5}', '\u{308}', '\u{300}', '\u{3c1}',
+        '\u{313}', '\u{3c1}', '\u{314}', '\u{3c5}', '\u{342}', '\u{3c5}', '\u{308}', '\u{342}',
+        '\u{3a5}', '\u{306}', '\u{3a5}', '\u{304}', '\u{3a5}', '\u{300}', '\u{3a1}', '\u{314}',
+        '\u{a8}', '\u{300}', '`', '\u{3c9}', '\u{300}', '\u{345}', '\u{3c9}', '\u{345}', '\u{3c9}',
+        '\u{301}', '\u{345}', '\u{3c9}', '\u{342}', '\u{3c9}', '\u{342}', '\u{345}', '\u{39f}',
+        '\u{300}', '\u{3a9}', '\u{300}', '\u{3a9}', '\u{345}', '\u{b4}', '\u{2002}', '\u{2003}',
+        '\u{3a9}', 'K', '\u{2190}', '\u{338}', '\u{2192}', '\u{338}', '\u{2194}', '\u{338}',
+        '\u{21d0}', '\u{338}', '\u{21d4}', '\u{338}', '\u{21d2}', '\u{338}', '\u{2203}', '\u{338}',
+        '\u{2208}', '\u{338}', '\u{220b}', '\u{338}', '\u{2223}', '\u{338}', '\u{2225}', '\u{338}',
+        '\u{223c}', '\u{338}', '\u{2243}', '\u{338}', '\u{2245}', '\u{338}', '\u{2248}', '\u{338}',
+        '=', '\u{338}', '\u{2261}', '\u{338}', '\u{224d}', '\u{338}', '<', '\u{338}', '>',
+        '\u{338}', '\u{2264}', '\u{338}', '\u{2265}', '\u{338}', '\u{2272}', '\u{338}', '\u{2273}',
+        '\u{338}', '\u{2276}', '\u{338}', '\u{2277}', '\u{338}', '\u{227a}', '\u{338}', '\u{227b}',
+        '\u{338}', '\u{2282}', '\u{338}', '\u{2283}', '\u{338}', '\u{2286}', '\u{338}', '\u{2287}',
+        '\u{338}', '\u{22a2}', '\u{338}', '\u{22a8}', '\u{338}', '\u{22a9}', '\u{338}', '\u{22ab}',
+        '\u{338}', '\u{227c}', '\u{338}', '\u{227d}', '\u{338}', '\u{2291}', '\u{338}', '\u{2292}',
+        '\u{338}', '\u{22b2}', '\u{338}', '\u{22b3}', '\u{338}', '\u{22b4}', '\u{338}', '\u{22b5}',
+        '\u{338}', '\u{3008}', '\u{3009}', '\u{2add}', '\u{338}', '\u{304b}', '\u{3099}',
+        '\u{304d}', '\u{3099}', '\u{304f}', '\u{3099}', '\u{3051}', '\u{3099}', '\u{3053}',
+        '\u{3099}', '\u{3055}', '\u{3099}', '\u{3057}', '\u{3099}', '\u{3059}', '\u{3099}',
+        '\u{305b}', '\u{3099}', '\u{305d}', '\u{3099}', '\u{305f}', '\u{3099}', '\u{3061}',
+        '\u{3099}', '\u{3064}', '\u{3099}', '\u{3066}', '\u{3099}', '\u{3068}', '\u{3099}',
+        '\u{306f}', '\u{3099}', '\u{306f}', '\u{309a}', '\u{3072}', '\u{3099}', '\u{3072}',
+        '\u{309a}', '\u{3075}', '\u{3099}', '\u{3075}', '\u{309a}', '\u{3078}', '\u{3099}',
+        '\u{3078}', '\u{309a}', '\u{307b}', '\u{3099}', '\u{307b}', '\u{309a}', '\u{3046}',
+        '\u{3099}', '\u{309d}', '\u{3099}', '\u{30ab}', '\u{3099}', '\u{30ad}', '\u{3099}',
+        '\u{30af}', '\u{3099}', '\u{30b1}', '\u{3099}', '\u{30b3}', '\u{3099}', '\u{30b5}',
+        '\u{3099}', '\u{30b7}', '\u{3099}', '\u{30b9}', '\u{3099}', '\u{30bb}', '\u{3099}',
+        '\u{30bd}', '\u{3099}', '\u{30bf}', '\u{3099}', '\u{30c1}', '\u{3099}', '\u{30c4}',
+        '\u{3099}', '\u{30c6}', '\u{3099}', '\u{30c8}', '\u{3099}', '\u{30cf}', '\u{3099}',
+        '\u{30cf}', '\u{309a}', '\u{30d2}', '\u{3099}', '\u{30d2}', '\u{309a}', '\u{30d5}',
+        '\u{3099}', '\u{30d5}', '\u{309a}', '\u{30d8}', '\u{3099}', '\u{30d8}', '\u{309a}',
+        '\u{30db}', '\u{3099}', '\u{30db}', '\u{309a}', '\u{30a6}', '\u{3099}', '\u{30ef}',
+        '\u{3099}', '\u{30f0}', '\u{3099}', '\u{30f1}', '\u{3099}', '\u{30f2}', '\u{3099}',
+        '\u{30fd}', '\u{3099}', '\u{8c48}', '\u{66f4}', '\u{8eca}', '\u{8cc8}', '\u{6ed1}',
+        '\u{4e32}', '\u{53e5}', '\u{9f9c}', '\u{5951}', '\u{91d1}', '\u{5587}', '\u{5948}',
+        '\u{61f6}', '\u{7669}', '\u{7f85}', '\u{863f}', '\u{87ba}', '\u{88f8}', '\u{908f}',
+        '\u{6a02}', '\u{6d1b}', '\u{70d9}', '\u{73de}', '\u{843d}', '\u{916a}', '\u{99f1}',
+        '\u{4e82}', '\u{5375}', '\u{6b04}', '\u{721b}', '\u{862d}', '\u{9e1e}', '\u{5d50}',
+        '\u{6feb}', '\u{85cd}', '\u{8964}', '\u{62c9}', '\u{81d8}', '\u{881f}', '\u{5eca}',
+        '\u{6717}', '\u{6d6a}', '\u{72fc}', '\u{90ce}', '\u{4f86}', '\u{51b7}', '\u{52de}',
+        '\u{64c4}', '\u{6ad3}', '\u{7210}', '\u{76e7}', '\u{8001}', '\u{8606}', '\u{865c}',
+        '\u{8def}', '\u{9732}', '\u{9b6f}', '\u{9dfa}', '\u{788c}', '\u{797f}', '\u{7da0}',
+        '\u{83c9}', '\u{9304}', '\u{9e7f}', '\u{8ad6}', '\u{58df}', '\u{5f04}', '\u{7c60}',
+        '\u{807e}', '\u{7262}', '\u{78ca}', '\u{8cc2}', '\u{96f7}', '\u{58d8}', '\u{5c62}',
+        '\u{6a13}', '\u{6dda}', '\u{6f0f}', '\u{7d2f}', '\u{7e37}', '\u{964b}', '\u{52d2}',
+        '\u{808b}', '\u{51dc}', '\u{51cc}', '\u{7a1c}', '\u{7dbe}', '\u{83f1}', '\u{9675}',
+        '\u{8b80}', '\u{62cf}', '\u{8afe}', '\u{4e39}', '\u{5be7}', '\u{6012}', '\u{7387}',
+        '\u{7570}', '\u{5317}', '\u{78fb}', '\u{4fbf}', '\u{5fa9}', '\u{4e0d}', '\u{6ccc}',
+        '\u{6578}', '\u{7d22}', '\u{53c3}', '\u{585e}', '\u{7701}', '\u{8449}', '\u{8aaa}',
+        '\u{6bba}', '\u{8fb0}', '\u{6c88}', '\u{62fe}', '\u{82e5}', '\u{63a0}', '\u{7565}',
+        '\u{4eae}', '\u{5169}', '\u{51c9}', '\u{6881}', '\u{7ce7}', '\u{826f}', '\u{8ad2}',
+        '\u{91cf}', '\u{52f5}', '\u{5442}', '\u{5973}', '\u{5eec}', '\u{65c5}', '\u{6ffe}',
+        '\u{792a}', '\u{95ad}', '\u{9a6a}', '\u{9e97}', '\u{9ece}', '\u{529b}', '\u{66c6}',
+        '\u{6b77}', '\u{8f62}', '\u{5e74}', '\u{6190}', '\u{6200}', '\u{649a}', '\u{6f23}',
+        '\u{7149}', '\u{7489}', '\u{79ca}', '\u{7df4}', '\u{806f}', '\u{8f26}', '\u{84ee}',
+        '\u{9023}', '\u{934a}', '\u{5217}', '\u{52a3}', '\u{54bd}', '\u{70c8}', '\u{88c2}',
+        '\u{5ec9}', '\u{5ff5}', '\u{637b}', '\u{6bae}', '\u{7c3e}', '\u{7375}', '\u{4ee4}',
+        '\u{56f9}', '\u{5dba}', '\u{601c}', '\u{73b2}', '\u{7469}', '\u{7f9a}', '\u{8046}',
+        '\u{9234}', '\u{96f6}', '\u{9748}', '\u{9818}', '\u{4f8b}', '\u{79ae}', '\u{91b4}',
+        '\u{96b8}', '\u{60e1}', '\u{4e86}', '\u{50da}', '\u{5bee}', '\u{5c3f}', '\u{6599}',
+        '\u{71ce}', '\u{7642}', '\u{84fc}', '\u{907c}', '\u{9f8d}', '\u{6688}', '\u{962e}',
+        '\u{5289}', '\u{677b}', '\u{67f3}', '\u{6d41}', '\u{6e9c}', '\u{7409}', '\u{7559}',
+        '\u{786b}', '\u{7d10}', '\u{985e}', '\u{516d}', '\u{622e}', '\u{9678}', '\u{502b}',
+        '\u{5d19}', '\u{6dea}', '\u{8f2a}', '\u{5f8b}', '\u{6144}', '\u{6817}', '\u{9686}',
+        '\u{5229}', '\u{540f}', '\u{5c65}', '\u{6613}', '\u{674e}', '\u{68a8}', '\u{6ce5}',
+        '\u{7406}', '\u{75e2}', '\u{7f79}', '\u{88cf}', '\u{88e1}', '\u{91cc}', '\u{96e2}',
+        '\u{533f}', '\u{6eba}', '\u{541d}', '\u{71d0}', '\u{7498}', '\u{85fa}', '\u{96a3}',
+        '\u{9c57}', '\u{9e9f}', '\u{6797}', '\u{6dcb}', '\u{81e8}', '\u{7acb}', '\u{7b20}',
+        '\u{7c92}', '\u{72c0}', '\u{7099}', '\u{8b58}', '\u{4ec0}', '\u{8336}', '\u{523a}',
+        '\u{5207}', '\u{5ea6}', '\u{62d3}', '\u{7cd6}', '\u{5b85}', '\u{6d1e}', '\u{66b4}',
+        '\u{8f3b}', '\u{884c}', '\u{964d}', '\u{898b}', '\u{5ed3}', '\u{5140}', '\u{55c0}',
+        '\u{585a}', '\u{6674}', '\u{51de}', '\u{732a}', '\u{76ca}', '\u{793c}', '\u{795e}',
+        '\u{7965}', '\u{798f}', '\u{9756}', '\u{7cbe}', '\u{7fbd}', '\u{8612}', '\u{8af8}',
+        '\u{9038}', '\u{90fd}', '\u{98ef}', '\u{98fc}', '\u{9928}', '\u{9db4}', '\u{90de}',
+        '\u{96b7}', '\u{4fae}', '\u{50e7}', '\u{514d}', '\u{52c9}', '\u{52e4}', '\u{5351}',
+        '\u{559d}', '\u{5606}', '\u{5668}', '\u{5840}', '\u{58a8}', '\u{5c64}', '\u{5c6e}',
+        '\u{6094}', '\u{6168}', '\u{618e}', '\u{61f2}', '\u{654f}', '\u{65e2}', '\u{6691}',
+        '\u{6885}', '\u{6d77}', '\u{6e1a}', '\u{6f22}', '\u{716e}', '\u{722b}', '\u{7422}',
+        '\u{7891}', '\u{793e}', '\u{7949}', '\u{7948}', '\u{7950}', '\u{7956}', '\u{795d}',
+        '\u{798d}', '\u{798e}', '\u{7a40}', '\u{7a81}', '\u{7bc0}', '\u{7e09}', '\u{7e41}',
+        '\u{7f72}', '\u{8005}', '\u{81ed}', '\u{8279}', '\u{8457}', '\u{8910}', '\u{8996}',
+        '\u{8b01}', '\u{8b39}', '\u{8cd3}', '\u{8d08}', '\u{8fb6}', '\u{96e3}', '\u{97ff}',
+        '\u{983b}', '\u{6075}', '\u{242ee}', '\u{8218}', '\u{4e26}', '\u{51b5}', '\u{5168}',
+        '\u{4f80}', '\u{5145}', '\u{5180}', '\u{52c7}', '\u{52fa}', '\u{5555}', '\u{5599}',
+        '\u{55e2}', '\u{58b3}', '\u{5944}', '\u{5954}', '\u{5a62}', '\u{5b28}', '\u{5ed2}',
+        '\u{5ed9}', '\u{5f69}', '\u{5fad}', '\u{60d8}', '\u{614e}', '\u{6108}', '\u{6160}',
+        '\u{6234}', '\u{63c4}', '\u{641c}', '\u{6452}', '\u{6556}', '\u{671b}', '\u{6756}',
+        '\u{6b79}', '\u{6edb}', '\u{6ecb}', '\u{701e}', '\u{77a7}', '\u{7235}', '\u{72af}',
+        '\u{7471}', '\u{7506}', '\u{753b}', '\u{761d}', '\u{761f}', '\u{76db}', '\u{76f4}',
+        '\u{774a}', '\u{7740}', '\u{78cc}', '\u{7ab1}', '\u{7c7b}', '\u{7d5b}', '\u{7f3e}',
+        '\u{8352}', '\u{83ef}', '\u{8779}', '\u{8941}', '\u{8986}', '\u{8abf}', '\u{8acb}',
+        '\u{8aed}', '\u{8b8a}', '\u{8f38}', '\u{9072}', '\u{9199}', '\u{9276}', '\u{967c}',
+        '\u{97db}', '\u{980b}', '\u{9b12}', '\u{2284a}', '\u{22844}', '\u{233d5}', '\u{3b9d}',
+        '\u{4018}', '\u{4039}', '\u{25249}', '\u{25cd0}', '\u{27ed3}', '\u{9f43}', '\u{9f8e}',
+        '\u{5d9}', '\u{5b4}', '\u{5f2}', '\u{5b7}', '\u{5e9}', '\u{5c1}', '\u{5e9}', '\u{5c2}',
+        '\u{5e9}', '\u{5bc}', '\u{5c1}', '\u{5e9}', '\u{5bc}', '\u{5c2}', '\u{5d0}', '\u{5b7}',
+        '\u{5d0}', '\u{5b8}', '\u{5d0}', '\u{5bc}', '\u{5d1}', '\u{5bc}', '\u{5d2}', '\u{5bc}',
+        '\u{5d3}', '\u{5bc}', '\u{5d4}', '\u{5bc}', '\u{5d5}', '\u{5bc}', '\u{5d6}', '\u{5bc}',
+        '\u{5d8}', '\u{5bc}', '\u{5d9}', '\u{5bc}', '\u{5da}', '\u{5bc}', '\u{5db}', '\u{5bc}',
+        '\u{5dc}', '\u{5bc}', '\u{5de}', '\u{5bc}', '\u{5e0}', '\u{5bc}', '\u{5e1}', '\u{5bc}',
+        '\u{5e3}', '\u{5bc}', '\u{5e4}', '\u{5bc}', '\u{5e6}', '\u{5bc}', '\u{5e7}', '\u{5bc}',
+        '\u{5e8}', '\u{5bc}', '\u{5e9}', '\u{5bc}', '\u{5ea}', '\u{5bc}', '\u{5d5}', '\u{5b9}',
+        '\u{5d1}', '\u{5bf}', '\u{5db}', '\u{5bf}', '\u{5e4}', '\u{5bf}', '\u{11099}', '\u{110ba}',
+        '\u{1109b}', '\u{110ba}', '\u{110a5}', '\u{110ba}', '\u{11131}', '\u{11127}', '\u{11132}',
+        '\u{11127}', '\u{11347}', '\u{1133e}', '\u{11347}', '\u{11357}', '\u{114b9}', '\u{114ba}',
+        '\u{114b9}', '\u{114b0}', '\u{114b9}', '\u{114bd}', '\u{115b8}', '\u{115af}', '\u{115b9}',
+        '\u{115af}', '\u{11935}', '\u{11930}', '\u{1d157}', '\u{1d165}', '\u{1d158}', '\u{1d165}',
+        '\u{1d158}', '\u{1d165}', '\u{1d16e}', '\u{1d158}', '\u{1d165}', '\u{1d16f}', '\u{1d158}',
+        '\u{1d165}', '\u{1d170}', '\u{1d158}', '\u{1d165}', '\u{1d171}', '\u{1d158}', '\u{1d165}',
+        '\u{1d172}', '\u{1d1b9}', '\u{1d165}', '\u{1d1ba}', '\u{1d165}', '\u{1d1b9}', '\u{1d165}',
+        '\u{1d16e}', '\u{1d1ba}', '\u{1d165}', '\u{1d16e}', '\u{1d1b9}', '\u{1d165}', '\u{1d16f}',
+        '\u{1d1ba}', '\u{1d165}', '\u{1d16f}', '\u{4e3d}', '\u{4e38}', '\u{4e41}', '\u{20122}',
+        '\u{4f60}', '\u{4fbb}', '\u{5002}', '\u{507a}', '\u{5099}', '\u{50cf}', '\u{349e}',
+        '\u{2063a}', '\u{5154}', '\u{5164}', '\u{5177}', '\u{2051c}', '\u{34b9}', '\u{5167}',
+        '\u{518d}', '\u{2054b}', '\u{5197}', '\u{51a4}', '\u{4ecc}', '\u{51ac}', '\u{291df}',
+        '\u{51f5}', '\u{5203}', '\u{34df}', '\u{523b}', '\u{5246}', '\u{5272}', '\u{5277}',
+        '\u{3515}', '\u{5305}', '\u{5306}', '\u{5349}', '\u{535a}', '\u{5373}', '\u{537d}',
+        '\u{537f}', '\u{20a2c}', '\u{7070}', '\u{53ca}', '\u{53df}', '\u{20b63}', '\u{53eb}',
+        '\u{53f1}', '\u{5406}', '\u{549e}', '\u{5438}', '\u{5448}', '\u{5468}', '\u{54a2}',
+        '\u{54f6}', '\u{5510}', '\u{5553}', '\u{5563}', '\u{5584}', '\u{55ab}', '\u{55b3}',
+        '\u{55c2}', '\u{5716}', '\u{5717}', '\u{5651}', '\u{5674}', '\u{58ee}', '\u{57ce}',
+        '\u{57f4}', '\u{580d}', '\u{578b}', '\u{5832}', '\u{5831}', '\u{58ac}', '\u{214e4}',
+        '\u{58f2}', '\u{58f7}', '\u{5906}', '\u{591a}', '\u{5922}', '\u{5962}', '\u{216a8}',
+        '\u{216ea}', '\u{59ec}', '\u{5a1b}', '\u{5a27}', '\u{59d8}', '\u{5a66}', '\u{36ee}',
+        '\u{36fc}', '\u{5b08}', '\u{5b3e}', '\u{219c8}', '\u{5bc3}', '\u{5bd8}', '\u{5bf3}',
+        '\u{21b18}', '\u{5bff}', '\u{5c06}', '\u{5f53}', '\u{5c22}', '\u{3781}', '\u{5c60}',
+        '\u{5cc0}', '\u{5c8d}', '\u{21de4}', '\u{5d43}', '\u{21de6}', '\u{5d6e}', '\u{5d6b}',
+        '\u{5d7c}', '\u{5de1}', '\u{5de2}', '\u{382f}', '\u{5dfd}', '\u{5e28}', '\u{5e3d}',
+        '\u{5e69}', '\u{3862}', '\u{22183}', '\u{387c}', '\u{5eb0}', '\u{5eb3}', '\u{5eb6}',
+        '\u{2a392}', '\u{5efe}', '\u{22331}', '\u{8201}', '\u{5f22}', '\u{38c7}', '\u{232b8}',
+        '\u{261da}', '\u{5f62}', '\u{5f6b}', '\u{38e3}', '\u{5f9a}', '\u{5fcd}', '\u{5fd7}',
+        '\u{5ff9}', '\u{6081}', '\u{393a}', '\u{391c}', '\u{226d4}', '\u{60c7}', '\u{6148}',
+        '\u{614c}', '\u{617a}', '\u{61b2}', '\u{61a4}', '\u{61af}', '\u{61de}', '\u{6210}',
+        '\u{621b}', '\u{625d}', '\u{62b1}', '\u{62d4}', '\u{6350}', '\u{22b0c}', '\u{633d}',
+        '\u{62fc}', '\u{6368}', '\u{6383}', '\u{63e4}', '\u{22bf1}', '\u{6422}', '\u{63c5}',
+        '\u{63a9}', '\u{3a2e}', '\u{6469}', '\u{647e}', '\u{649d}', '\u{6477}', '\u{3a6c}',
+        '\u{656c}', '\u{2300a}', '\u{65e3}', '\u{66f8}', '\u{6649}', '\u{3b19}', '\u{3b08}',
+        '\u{3ae4}', '\u{5192}', '\u{5195}', '\u{6700}', '\u{669c}', '\u{80ad}', '\u{43d9}',
+        '\u{6721}', '\u{675e}', '\u{6753}', '\u{233c3}', '\u{3b49}', '\u{67fa}', '\u{6785}',
+        '\u{6852}', '\u{2346d}', '\u{688e}', '\u{681f}', '\u{6914}', '\u{6942}', '\u{69a3}',
+        '\u{69ea}', '\u{6aa8}', '\u{236a3}', '\u{6adb}', '\u{3c18}', '\u{6b21}', '\u{238a7}',
+        '\u{6b54}', '\u{3c4e}', '\u{6b72}', '\u{6b9f}', '\u{6bbb}', '\u{23a8d}', '\u{21d0b}',
+        '\u{23afa}', '\u{6c4e}', '\u{23cbc}', '\u{6cbf}', '\u{6ccd}', '\u{6c67}', '\u{6d16}',
+        '\u{6d3e}', '\u{6d69}', '\u{6d78}', '\u{6d85}', '\u{23d1e}', '\u{6d34}', '\u{6e2f}',
+        '\u{6e6e}', '\u{3d33}', '\u{6ec7}', '\u{23ed1}', '\u{6df9}', '\u{6f6e}', '\u{23f5e}',
+        '\u{23f8e}', '\u{6fc6}', '\u{7039}', '\u{701b}', '\u{3d96}', '\u{704a}', '\u{707d}',
+        '\u{7077}', '\u{70ad}', '\u{20525}', '\u{7145}', '\u{24263}', '\u{719c}', '\u{243ab}',
+        '\u{7228}', '\u{7250}', '\u{24608}', '\u{7280}', '\u{7295}', '\u{24735}', '\u{24814}',
+        '\u{737a}', '\u{738b}', '\u{3eac}', '\u{73a5}', '\u{3eb8}', '\u{7447}', '\u{745c}',
+        '\u{7485}', '\u{74ca}', '\u{3f1b}', '\u{7524}', '\u{24c36}', '\u{753e}', '\u{24c92}',
+        '\u{2219f}', '\u{7610}', '\u{24fa1}', '\u{24fb8}', '\u{25044}', '\u{3ffc}', '\u{4008}',
+        '\u{250f3}', '\u{250f2}', '\u{25119}', '\u{25133}', '\u{771e}', '\u{771f}', '\u{778b}',
+        '\u{4046}', '\u{4096}', '\u{2541d}', '\u{784e}', '\u{40e3}', '\u{25626}', '\u{2569a}',
+        '\u{256c5}', '\u{79eb}', '\u{412f}', '\u{7a4a}', '\u{7a4f}', '\u{2597c}', '\u{25aa7}',
+        '\u{7aee}', '\u{4202}', '\u{25bab}', '\u{7bc6}', '\u{7bc9}', '\u{4227}', '\u{25c80}',
+        '\u{7cd2}', '\u{42a0}', '\u{7ce8}', '\u{7ce3}', '\u{7d00}', '\u{25f86}', '\u{7d63}',
+        '\u{4301}', '\u{7dc7}', '\u{7e02}', '\u{7e45}', '\u{4334}', '\u{26228}', '\u{26247}',
+        '\u{4359}', '\u{262d9}', '\u{7f7a}', '\u{2633e}', '\u{7f95}', '\u{7ffa}', '\u{264da}',
+        '\u{26523}', '\u{8060}', '\u{265a8}', '\u{8070}', '\u{2335f}', '\u{43d5}', '\u{80b2}',
+        '\u{8103}', '\u{440b}', '\u{813e}', '\u{5ab5}', '\u{267a7}', '\u{267b5}', '\u{23393}',
+        '\u{2339c}', '\u{8204}', '\u{8f9e}', '\u{446b}', '\u{8291}', '\u{828b}', '\u{829d}',
+        '\u{52b3}', '\u{82b1}', '\u{82b3}', '\u{82bd}', '\u{82e6}', '\u{26b3c}', '\u{831d}',
+        '\u{8363}', '\u{83ad}', '\u{8323}', '\u{83bd}', '\u{83e7}', '\u{8353}', '\u{83ca}',
+        '\u{83cc}', '\u{83dc}', '\u{26c36}', '\u{26d6b}', '\u{26cd5}', '\u{452b}', '\u{84f1}',
+        '\u{84f3}', '\u{8516}', '\u{273ca}', '\u{8564}', '\u{26f2c}', '\u{455d}', '\u{4561}',
+        '\u{26fb1}', '\u{270d2}', '\u{456b}', '\u{8650}', '\u{8667}', '\u{8669}', '\u{86a9}',
+        '\u{8688}', '\u{870e}', '\u{86e2}', '\u{8728}', '\u{876b}', '\u{8786}', '\u{45d7}',
+        '\u{87e1}', '\u{8801}', '\u{45f9}', '\u{8860}', '\u{8863}', '\u{27667}', '\u{88d7}',
+        '\u{88de}', '\u{4635}', '\u{88fa}', '\u{34bb}', '\u{278ae}', '\u{27966}', '\u{46be}',
+        '\u{46c7}', '\u{8aa0}', '\u{8c55}', '\u{27ca8}', '\u{8cab}', '\u{8cc1}', '\u{8d1b}',
+        '\u{8d77}', '\u{27f2f}', '\u{20804}', '\u{8dcb}', '\u{8dbc}', '\u{8df0}', '\u{208de}',
+        '\u{8ed4}', '\u{285d2}', '\u{285ed}', '\u{9094}', '\u{90f1}', '\u{9111}', '\u{2872e}',
+        '\u{911b}', '\u{9238}', '\u{92d7}', '\u{92d8}', '\u{927c}', '\u{93f9}', '\u{9415}',
+        '\u{28bfa}', '\u{958b}', '\u{4995}', '\u{95b7}', '\u{28d77}', '\u{49e6}', '\u{96c3}',
+        '\u{5db2}', '\u{9723}', '\u{29145}', '\u{2921a}', '\u{4a6e}', '\u{4a76}', '\u{97e0}',
+        '\u{2940a}', '\u{4ab2}', '\u{29496}', '\u{9829}', '\u{295b6}', '\u{98e2}', '\u{4b33}',
+        '\u{9929}', '\u{99a7}', '\u{99c2}', '\u{99fe}', '\u{4bce}', '\u{29b30}', '\u{9c40}',
+        '\u{9cfd}', '\u{4cce}', '\u{4ced}', '\u{9d67}', '\u{2a0ce}', '\u{4cf8}', '\u{2a105}',
+        '\u{2a20e}', '\u{2a291}', '\u{9ebb}', '\u{4d56}', '\u{9ef9}', '\u{9efe}', '\u{9f05}',
+        '\u{9f0f}', '\u{9f16}', '\u{9f3b}', '\u{2a600}',
+    ];
+
+    static COMPATIBILITY_TABLE: &[(char, u32)] = &[
+        ('\u{a0}', 1), ('\u{a8}', 258), ('\u{aa}', 769), ('\u{af}', 1026), ('\u{b2}', 1537),
+        ('\u{b3}', 1793), ('\u{b4}', 2050), ('\u{b5}', 2561), ('\u{b8}', 2818), ('\u{b9}', 3329),
+        ('\u{ba}', 3585), ('\u{bc}', 3843), ('\u{bd}', 4611), ('\u{be}', 5379), ('\u{132}', 6146),
+        ('\u{133}', 6658), ('\u{13f}', 7170), ('\u{140}', 7682), ('\u{149}', 8194),
+        ('\u{17f}', 8705), ('\u{1c4}', 8963), ('\u{1c5}', 9731), ('\u{1c6}', 10499),
+        ('\u{1c7}', 11266), ('\u{1c8}', 11778), ('\u{1c9}', 12290), ('\u{1ca}', 12802),
+        ('\u{1cb}', 13314), ('\u{1cc}', 13826), ('\u{1f1}', 14338), ('\u{1f2}', 14850),
+        ('\u{1f3}', 15362), ('\u{2b0}', 15873), ('\u{2b1}', 16129), ('\u{2b2}', 16385),
+        ('\u{2b3}', 16641), ('\u{2b4}', 16897), ('\u{2b5}', 17153), ('\u{2b6}', 17409),
+        ('\u{2b7}', 17665), ('\u{2b8}', 17921), ('\u{2d8}', 18178), ('\u{2d9}', 18690),
+        ('\u{2da}', 19202), ('\u{2db}', 19714), ('\u{2dc}', 20226), ('\u{2dd}', 20738),
+        ('\u{2e0}', 21249), ('\u{2e1}', 21505), ('\u{2e2}', 8705), ('\u{2e3}', 21761),
+        ('\u{2e4}', 22017), ('\u{37a}', 22274), ('\u{384}', 2050), ('\u{385}', 22787),
+        ('\u{3d0}', 23553), ('\u{3d1}', 23809), ('\u{3d2}', 24065), ('\u{3d3}', 24322),
+        ('\u{3d4}', 24834), ('\u{3d5}', 25345), ('\u{3d6}', 25601), ('\u{3f0}', 25857),
+        ('\u{3f1}', 26113), ('\u{3f2}', 26369), ('\u{3f4}', 26625), ('\u{3f5}', 26881),
+        ('\u{3f9}', 27137), ('\u{587}', 27394), ('\u{675}', 27906), ('\u{676}', 28418),
+        ('\u{677}', 28930), ('\u{678}', 29442), ('\u{e33}', 29954), ('\u{eb3}', 30466),
+        ('\u{edc}', 30978), ('\u{edd}', 31490), ('\u{f0c}', 32001), ('\u{f77}', 32259),
+        ('\u{f79}', 33027), ('\u{10fc}', 33793), ('\u{1d2c}', 34049), ('\u{1d2d}', 34305),
+        ('\u{1d2e}', 34561), ('\u{1d30}', 34817), ('\u{1d31}', 35073), ('\u{1d32}', 35329),
+        ('\u{1d33}', 35585), ('\u{1d34}', 35841), ('\u{1d35}', 36097), ('\u{1d36}', 36353),
+        ('\u{1d37}', 36609), ('\u{1d38}', 36865), ('\u{1d39}', 37121), ('\u{1d3a}', 37377),
+        ('\u{1d3c}', 37633), ('\u{1d3d}', 37889), ('\u{1d3e}', 38145), ('\u{1d3f}', 38401),
+        ('\u{1d40}', 38657), ('\u{1d41}', 38913), ('\u{1d42}', 39169), ('\u{1d43}', 769),
+        ('\u{1d44}', 39425), ('\u{1d45}', 39681), ('\u{1d46}', 39937), ('\u{1d47}', 40193),
+        ('\u{1d48}', 40449), ('\u{1d49}', 40705), ('\u{1d4a}', 40961), ('\u{1d4b}', 41217),
+        ('\u{1d4c}', 41473), ('\u{1d4d}', 41729), ('\u{1d4f}', 41985), ('\u{1d50}', 42241),
+        ('\u{1d51}', 42497), ('\u{1d52}', 3585), ('\u{1d53}', 42753), ('\u{1d54}', 43009),
+        ('\u{1d55}', 43265), ('\u{1d56}', 43521), ('\u{1d57}', 43777), ('\u{1d58}', 44033),
+        ('\u{1d59}', 44289), ('\u{1d5a}', 44545), ('\u{1d5b}', 44801), ('\u{1d5c}', 45057),
+        ('\u{1d5d}', 23553), ('\u{1d5e}', 45313), ('\u{1d5f}', 45569), ('\u{1d60}', 25345),
+        ('\u{1d61}', 45825), ('\u{1d62}', 46081), ('\u{1d63}', 16641), ('\u{1d64}', 44033),
+        ('\u{1d65}', 44801), ('\u{1d66}', 23553), ('\u{1d67}', 45313), ('\u{1d68}', 26113),
+        ('\u{1d69}', 25345), ('\u{1d6a}', 45825), ('\u{1d78}', 46337), ('\u{1d9b}', 46593),
+        ('\u{1d9c}', 46849), ('\u{1d9d}', 47105), ('\u{1d9e}', 47361), ('\u{1d9f}', 41473),
+        ('\u{1da0}', 47617), ('\u{1da1}', 47873), ('\u{1da2}', 48129), ('\u{1da3}', 48385),
+        ('\u{1da4}', 48641), ('\u{1da5}', 48897), ('\u{1da6}', 49153), ('\u{1da7}', 49409),
+        ('\u{1da8}', 49665), ('\u{1da9}', 49921), ('\u{1daa}', 50177), ('\u{1dab}', 50433),
+        ('\u{1dac}', 50689), ('\u{1dad}', 50945), ('\u{1dae}', 51201), ('\u{1daf}', 51457),
+        ('\u{1db0}', 51713), ('\u{1db1}', 51969), ('\u{1db2}', 52225), ('\u{1db3}', 52481),
+        ('\u{1db4}', 52737), ('\u{1db5}', 52993), ('\u{1db6}', 53249), ('\u{1db7}', 53505),
+        ('\u{1db8}', 53761), ('\u{1db9}', 54017), ('\u{1dba}', 54273), ('\u{1dbb}', 54529),
+        ('\u{1dbc}', 54785), ('\u{1dbd}', 55041), ('\u{1dbe}', 55297), ('\u{1dbf}', 23809),
+        ('\u{1e9a}', 55554), ('\u{1e9b}', 56066), ('\u{1fbd}', 56578), ('\u{1fbf}', 56578),
+        ('\u{1fc0}', 57090), ('\u{1fc1}', 57603), ('\u{1fcd}', 58371), ('\u{1fce}', 59139),
+        ('\u{1fcf}', 59907), ('\u{1fdd}', 60675), ('\u{1fde}', 61443), ('\u{1fdf}', 62211),
+        ('\u{1fed}', 62979), ('\u{1fee}', 22787), ('\u{1ffd}', 2050), ('\u{1ffe}', 63746),
+        ('\u{2000}', 1), ('\u{2001}', 1), ('\u{2002}', 1), ('\u{2003}', 1), ('\u{2004}', 1),
+        ('\u{2005}', 1), ('\u{2006}', 1), ('\u{2007}', 1), ('\u{2008}', 1), ('\u{2009}', 1),
+        ('\u{200a}', 1), ('\u{2011}', 64257), ('\u{2017}', 64514), ('\u{2024}', 65025),
+        ('\u{2025}', 65282), ('\u{2026}', 65795), ('\u{202f}', 1), ('\u{2033}', 66562),
+        ('\u{2034}', 67075), ('\u{2036}', 67842), ('\u{2037}', 68355), ('\u{203c}', 69122),
+        ('\u{203e}', 69634), ('\u{2047}', 70146), ('\u{2048}', 70658), ('\u{2049}', 71170),
+        ('\u{2057}', 71684), ('\u{205f}', 1), ('\u{2070}', 72705), ('\u{2071}', 46081),
+        ('\u{2074}', 72961), ('\u{2075}', 73217), ('\u{2076}', 73473), ('\u{2077}', 73729),
+        ('\u{2078}', 73985), ('\u{2079}', 74241), ('\u{207a}', 74497), ('\u{207b}', 74753),
+        ('\u{207c}', 75009), ('\u{207d}', 75265), ('\u{207e}', 75521), ('\u{207f}', 75777),
+        ('\u{2080}', 72705), ('\u{2081}', 3329), ('\u{2082}', 1537), ('\u{2083}', 1793),
+        ('\u{2084}', 72961), ('\u{2085}', 73217), ('\u{2086}', 73473), ('\u{2087}', 73729),
+        ('\u{2088}', 73985), ('\u{2089}', 74241), ('\u{208a}', 74497), ('\u{208b}', 74753),
+        ('\u{208c}', 75009), ('\u{208d}', 75265), ('\u{208e}', 75521), ('\u{2090}', 769),
+        ('\u{2091}', 40705), ('\u{2092}', 3585), ('\u{2093}', 21761), ('\u{2094}', 40961),
+        ('\u{2095}', 15873), ('\u{2096}', 41985), ('\u{2097}', 21505), ('\u{2098}', 42241),
+        ('\u{2099}', 75777), ('\u{209a}', 43521), ('\u{209b}', 8705), ('\u{209c}', 43777),
+        ('\u{20a8}', 76034), ('\u{2100}', 76547), ('\u{2101}', 77315), ('\u{2102}', 78081),
+        ('\u{2103}', 78338), ('\u{2105}', 78851), ('\u{2106}', 79619), ('\u{2107}', 80385),
+        ('\u{2109}', 80642), ('\u{210a}', 41729), ('\u{210b}', 35841), ('\u{210c}', 35841),
+        ('\u{210d}', 35841), ('\u{210e}', 15873), ('\u{210f}', 81153), ('\u{2110}', 36097),
+        ('\u{2111}', 36097), ('\u{2112}', 36865), ('\u{2113}', 21505), ('\u{2115}', 37377),
+        ('\u{2116}', 81410), ('\u{2119}', 38145), ('\u{211a}', 81921), ('\u{211b}', 38401),
+        ('\u{211c}', 38401), ('\u{211d}', 38401), ('\u{2120}', 82178), ('\u{2121}', 82691),
+        ('\u{2122}', 83458), ('\u{2124}', 83969), ('\u{2128}', 83969), ('\u{212c}', 34561),
+        ('\u{212d}', 78081), ('\u{212f}', 40705), ('\u{2130}', 35073), ('\u{2131}', 84225),
+        ('\u{2133}', 37121), ('\u{2134}', 3585), ('\u{2135}', 84481), ('\u{2136}', 84737),
+        ('\u{2137}', 84993), ('\u{2138}', 85249), ('\u{2139}', 46081), ('\u{213b}', 85507),
+        ('\u{213c}', 25601), ('\u{213d}', 45313), ('\u{213e}', 86273), ('\u{213f}', 86529),
+        ('\u{2140}', 86785), ('\u{2145}', 34817), ('\u{2146}', 40449), ('\u{2147}', 40705),
+        ('\u{2148}', 46081), ('\u{2149}', 16385), ('\u{2150}', 87043), ('\u{2151}', 87811),
+        ('\u{2152}', 88580), ('\u{2153}', 89603), ('\u{2154}', 90371), ('\u{2155}', 91139),
+        ('\u{2156}', 91907), ('\u{2157}', 92675), ('\u{2158}', 93443), ('\u{2159}', 94211),
+        ('\u{215a}', 94979), ('\u{215b}', 95747), ('\u{215c}', 96515), ('\u{215d}', 97283),
+        ('\u{215e}', 98051), ('\u{215f}', 98818), ('\u{2160}', 36097), ('\u{2161}', 99330),
+        ('\u{2162}', 99843), ('\u{2163}', 100610), ('\u{2164}', 101121), ('\u{2165}', 101378),
+        ('\u{2166}', 101891), ('\u{2167}', 102660), ('\u{2168}', 103682), ('\u{2169}', 104193),
+        ('\u{216a}', 104450), ('\u{216b}', 104963), ('\u{216c}', 36865), ('\u{216d}', 78081),
+        ('\u{216e}', 34817), ('\u{216f}', 37121), ('\u{2170}', 46081), ('\u{2171}', 105730),
+        ('\u{2172}', 106243), ('\u{2173}', 107010), ('\u{2174}', 44801), ('\u{2175}', 107522),
+        ('\u{2176}', 108035), ('\u{2177}', 108804), ('\u{2178}', 109826), ('\u{2179}', 21761),
+        ('\u{217a}', 110338), ('\u{217b}', 110851), ('\u{217c}', 21505), ('\u{217d}', 46849),
+        ('\u{217e}', 40449), ('\u{217f}', 42241), ('\u{2189}', 111619), ('\u{222c}', 112386),
+        ('\u{222d}', 112899), ('\u{222f}', 113666), ('\u{2230}', 114179), ('\u{2460}', 3329),
+        ('\u{2461}', 1537), ('\u{2462}', 1793), ('\u{2463}', 72961), ('\u{2464}', 73217),
+        ('\u{2465}', 73473), ('\u{2466}', 73729), ('\u{2467}', 73985), ('\u{2468}', 74241),
+        ('\u{2469}', 114946), ('\u{246a}', 115458), ('\u{246b}', 115970), ('\u{246c}', 116482),
+        ('\u{246d}', 116994), ('\u{246e}', 117506), ('\u{246f}', 118018), ('\u{2470}', 118530),
+        ('\u{2471}', 119042), ('\u{2472}', 119554), ('\u{2473}', 120066), ('\u{2474}', 120579),
+        ('\u{2475}', 121347), ('\u{2476}', 122115), ('\u{2477}', 122883), ('\u{2478}', 123651),
+        ('\u{2479}', 124419), ('\u{247a}', 125187), ('\u{247b}', 125955), ('\u{247c}', 126723),
+        ('\u{247d}', 127492), ('\u{247e}', 128516), ('\u{247f}', 129540), ('\u{2480}', 130564),
+        ('\u{2481}', 131588), ('\u{2482}', 132612), ('\u{2483}', 133636), ('\u{2484}', 134660),
+        ('\u{2485}', 135684), ('\u{2486}', 136708), ('\u{2487}', 137732), ('\u{2488}', 138754),
+        ('\u{2489}', 139266), ('\u{248a}', 139778), ('\u{248b}', 140290), ('\u{248c}', 140802),
+        ('\u{248d}', 141314), ('\u{248e}', 141826), ('\u{248f}', 142338), ('\u{2490}', 142850),
+        ('\u{2491}', 143363), ('\u{2492}', 144131), ('\u{2493}', 144899), ('\u{2494}', 145667),
+        ('\u{2495}', 146435), ('\u{2496}', 147203), ('\u{2497}', 147971), ('\u{2498}', 148739),
+        ('\u{2499}', 149507), ('\u{249a}', 150275), ('\u{249b}', 151043), ('\u{249c}', 151811),
+        ('\u{249d}', 152579), ('\u{249e}', 153347), ('\u{249f}', 154115), ('\u{24a0}', 154883),
+        ('\u{24a1}', 155651), ('\u{24a2}', 156419), ('\u{24a3}', 157187), ('\u{24a4}', 157955),
+        ('\u{24a5}', 158723), ('\u{24a6}', 159491), ('\u{24a7}', 160259), ('\u{24a8}', 161027),
+        ('\u{24a9}', 161795), ('\u{24aa}', 162563), ('\u{24ab}', 163331), ('\u{24ac}', 164099),
+        ('\u{24ad}', 164867), ('\u{24ae}', 165635), ('\u{24af}', 166403), ('\u{24b0}', 167171),
+        ('\u{24b1}', 167939), ('\u{24b2}', 168707), ('\u{24b3}', 169475), ('\u{24b4}', 170243),
+        ('\u{24b5}', 171011), ('\u{24b6}', 34049), ('\u{24b7}', 34561), ('\u{24b8}', 78081),
+        ('\u{24b9}', 34817), ('\u{24ba}', 35073), ('\u{24bb}', 84225), ('\u{24bc}', 35585),
+        ('\u{24bd}', 35841), ('\u{24be}', 36097), ('\u{24bf}', 36353), ('\u{24c0}', 36609),
+        ('\u{24c1}', 36865), ('\u{24c2}', 37121), ('\u{24c3}', 37377), ('\u{24c4}', 37633),
+        ('\u{24c5}', 38145), ('\u{24c6}', 81921), ('\u{24c7}', 38401), ('\u{24c8}', 171777),
+        ('\u{24c9}', 38657), ('\u{24ca}', 38913), ('\u{24cb}', 101121), ('\u{24cc}', 39169),
+        ('\u{24cd}', 104193), ('\u{24ce}', 172033), ('\u{24cf}', 83969), ('\u{24d0}', 769),
+        ('\u{24d1}', 40193), ('\u{24d2}', 46849), ('\u{24d3}', 40449), ('\u{24d4}', 40705),
+        ('\u{24d5}', 47617), ('\u{24d6}', 41729), ('\u{24d7}', 15873), ('\u{24d8}', 46081),
+        ('\u{24d9}', 16385), ('\u{24da}', 41985), ('\u{24db}', 21505), ('\u{24dc}', 42241),
+        ('\u{24dd}', 75777), ('\u{24de}', 3585), ('\u{24df}', 43521), ('\u{24e0}', 172289),
+        ('\u{24e1}', 16641), ('\u{24e2}', 8705), ('\u{24e3}', 43777), ('\u{24e4}', 44033),
+        ('\u{24e5}', 44801), ('\u{24e6}', 17665), ('\u{24e7}', 21761), ('\u{24e8}', 17921),
+        ('\u{24e9}', 54529), ('\u{24ea}', 72705), ('\u{2a0c}', 172548), ('\u{2a74}', 173571),
+        ('\u{2a75}', 174338), ('\u{2a76}', 174851), ('\u{2c7c}', 16385), ('\u{2c7d}', 101121),
+        ('\u{2d6f}', 175617), ('\u{2e9f}', 175873), ('\u{2ef3}', 176129), ('\u{2f00}', 176385),
+        ('\u{2f01}', 176641), ('\u{2f02}', 176897), ('\u{2f03}', 177153), ('\u{2f04}', 177409),
+        ('\u{2f05}', 177665), ('\u{2f06}', 177921), ('\u{2f07}', 178177), ('\u{2f08}', 178433),
+        ('\u{2f09}', 178689), ('\u{2f0a}', 178945), ('\u{2f0b}', 179201), ('\u{2f0c}', 179457),
+        ('\u{2f0d}', 179713), ('\u{2f0e}', 179969), ('\u{2f0f}', 180225), ('\u{2f10}', 180481),
+        ('\u{2f11}', 180737), ('\u{2f12}', 180993), ('\u{2f13}', 181249), ('\u{2f14}', 181505),
+        ('\u{2f15}', 181761), ('\u{2f16}', 182017), ('\u{2f17}', 182273), ('\u{2f18}', 182529),
+        ('\u{2f19}', 182785), ('\u{2f1a}', 183041), ('\u{2f1b}', 183297), ('\u{2f1c}', 183553),
+        ('\u{2f1d}', 183809), ('\u{2f1e}', 184065), ('\u{2f1f}', 184321), ('\u{2f20}', 184577),
+        ('\u{2f21}', 184833), ('\u{2f22}', 185089), ('\u{2f23}', 185345), ('\u{2f24}', 185601),
+        ('\u{2f25}', 185857), ('\u{2f26}', 186113), ('\u{2f27}', 186369), ('\u{2f28}', 186625),
+        ('\u{2f29}', 186881), ('\u{2f2a}', 187137), ('\u{2f2b}', 187393), ('\u{2f2c}', 187649),
+        ('\u{2f2d}', 187905), ('\u{2f2e}', 188161), ('\u{2f2f}', 188417), ('\u{2f30}', 188673),
+        ('\u{2f31}', 188929), ('\u{2f32}', 189185), ('\u{2f33}', 189441), ('\u{2f34}', 189697),
+        ('\u{2f35}', 189953), ('\u{2f36}', 190209), ('\u{2f37}', 190465), ('\u{2f38}', 190721),
+        ('\u{2f39}', 190977), ('\u{2f3a}', 191233), ('\u{2f3b}', 191489), ('\u{2f3c}', 191745),
+        ('\u{2f3d}', 192001), ('\u{2f3e}', 192257), ('\u{2f3f}', 192513), ('\u{2f40}', 192769),
+        ('\u{2f41}', 193025), ('\u{2f42}', 193281), ('\u{2f43}', 193537), ('\u{2f44}', 193793),
+        ('\u{2f45}', 194049), ('\u{2f46}', 194305), ('\u{2f47}', 194561), ('\u{2f48}', 194817),
+        ('\u{2f49}', 195073), ('\u{2f4a}', 195329), ('\u{2f4b}', 195585), ('\u{2f4c}', 195841),
+        ('\u{2f4d}', 196097), ('\u{2f4e}', 196353), ('\u{2f4f}', 196609), ('\u{2f50}', 196865),
+        ('\u{2f51}', 197121), ('\u{2f52}', 197377), ('\u{2f53}', 197633), ('\u{2f54}', 197889),
+        ('\u{2f55}', 198145), ('\u{2f56}', 198401), ('\u{2f57}', 198657), ('\u{2f58}', 198913),
+        ('\u{2f59}', 199169), ('\u{2f5a}', 199425), ('\u{2f5b}', 199681), ('\u{2f5c}', 199937),
+        ('\u{2f5d}', 200193), ('\u{2f5e}', 200449), ('\u{2f5f}', 200705), ('\u{2f60}', 200961),
+        ('\u{2f61}', 201217), ('\u{2f62}', 201473), ('\u{2f63}', 201729), ('\u{2f64}', 201985),
+        ('\u{2f65}', 202241), ('\u{2f66}', 202497), ('\u{2f67}', 202753), ('\u{2f68}', 203009),
+        ('\u{2f69}', 203265), ('\u{2f6a}', 203521), ('\u{2f6b}', 203777), ('\u{2f6c}', 204033),
+        ('\u{2f6d}', 204289), ('\u{2f6e}', 204545), ('\u{2f6f}', 204801), ('\u{2f70}', 205057),
+        ('\u{2f71}', 205313), ('\u{2f72}', 205569), ('\u{2f73}', 205825), ('\u{2f74}', 206081),
+        ('\u{2f75}', 206337), ('\u{2f76}', 206593), ('\u{2f77}', 206849), ('\u{2f78}', 207105),
+        ('\u{2f79}', 207361), ('\u{2f7a}', 207617), ('\u{2f7b}', 207873), ('\u{2f7c}', 208129),
+        ('\u{2f7d}', 208385), ('\u{2f7e}', 208641), ('\u{2f7f}', 208897), ('\u{2f80}', 209153),
+        ('\u{2f81}', 209409), ('\u{2f82}', 209665), ('\u{2f83}', 209921), ('\u{2f84}', 210177),
+        ('\u{2f85}', 210433), ('\u{2f86}', 210689), ('\u{2f87}', 210945), ('\u{2f88}', 211201),
+        ('\u{2f89}', 211457), ('\u{2f8a}', 211713), ('\u{2f8b}', 211969), ('\u{2f8c}', 212225),
+        ('\u{2f8d}', 212481), ('\u{2f8e}', 212737), ('\u{2f8f}', 212993), ('\u{2f90}', 213249),
+        ('\u{2f91}', 213505), ('\u{2f92}', 213761), ('\u{2f93}', 214017), ('\u{2f94}', 214273),
+        ('\u{2f95}', 214529), ('\u{2f96}', 214785), ('\u{2f97}', 215041), ('\u{2f98}', 215297),
+        ('\u{2f99}', 215553), ('\u{2f9a}', 215809), ('\u{2f9b}', 216065), ('\u{2f9c}', 216321),
+        ('\u{2f9d}', 216577), ('\u{2f9e}', 216833), ('\u{2f9f}', 217089), ('\u{2fa0}', 217345),
+        ('\u{2fa1}', 217601), ('\u{2fa2}', 217857), ('\u{2fa3}', 218113), ('\u{2fa4}', 218369),
+        ('\u{2fa5}', 218625), ('\u{2fa6}', 218881), ('\u{2fa7}', 219137), ('\u{2fa8}', 219393),
+        ('\u{2fa9}', 219649), ('\u{2faa}', 219905), ('\u{2fab}', 220161), ('\u{2fac}', 220417),
+        ('\u{2fad}', 220673), ('\u{2fae}', 220929), ('\u{2faf}', 221185), ('\u{2fb0}', 221441),
+        ('\u{2fb1}', 221697), ('\u{2fb2}', 221953), ('\u{2fb3}', 222209), ('\u{2fb4}', 222465),
+        ('\u{2fb5}', 222721), ('\u{2fb6}', 222977), ('\u{2fb7}', 223233), ('\u{2fb8}', 223489),
+        ('\u{2fb9}', 223745), ('\u{2fba}', 224001), ('\u{2fbb}', 224257), ('\u{2fbc}', 224513),
+        ('\u{2fbd}', 224769), ('\u{2fbe}', 225025), ('\u{2fbf}', 225281), ('\u{2fc0}', 225537),
+        ('\u{2fc1}', 225793), ('\u{2fc2}', 226049), ('\u{2fc3}', 226305), ('\u{2fc4}', 226561),
+        ('\u{2fc5}', 226817), ('\u{2fc6}', 227073), ('\u{2fc7}', 227329), ('\u{2fc8}', 227585),
+        ('\u{2fc9}', 227841), ('\u{2fca}', 228097), ('\u{2fcb}', 228353), ('\u{2fcc}', 228609),
+        ('\u{2fcd}', 228865), ('\u{2fce}', 229121), ('\u{2fcf}', 229377), ('\u{2fd0}', 229633),
+        ('\u{2fd1}', 229889), ('\u{2fd2}', 230145), ('\u{2fd3}', 230401), ('\u{2fd4}', 230657),
+        ('\u{2fd5}', 230913), ('\u{3000}', 1), ('\u{3036}', 231169), ('\u{3038}', 182273),
+        ('\u{3039}', 231425), ('\u{303a}', 231681), ('\u{309b}', 231938), ('\u{309c}', 232450),
+        ('\u{309f}', 232962), ('\u{30ff}', 233474), ('\u{3131}', 233985), ('\u{3132}', 234241),
+        ('\u{3133}', 234497), ('\u{3134}', 234753), ('\u{3135}', 235009), ('\u{3136}', 235265),
+        ('\u{3137}', 235521), ('\u{3138}', 235777), ('\u{3139}', 236033), ('\u{313a}', 236289),
+        ('\u{313b}', 236545), ('\u{313c}', 236801), ('\u{313d}', 237057), ('\u{313e}', 237313),
+        ('\u{313f}', 237569), ('\u{3140}', 237825), ('\u{3141}', 238081), ('\u{3142}', 238337),
+        ('\u{3143}', 238593), ('\u{3144}', 238849), ('\u{3145}', 239105), ('\u{3146}', 239361),
+        ('\u{3147}', 239617), ('\u{3148}', 239873), ('\u{3149}', 240129), ('\u{314a}', 240385),
+        ('\u{314b}', 240641), ('\u{314c}', 240897), ('\u{314d}', 241153), ('\u{314e}', 241409),
+        ('\u{314f}', 241665), ('\u{3150}', 241921), ('\u{3151}', 242177), ('\u{3152}', 242433),
+        ('\u{3153}', 242689), ('\u{3154}', 242945), ('\u{3155}', 243201), ('\u{3156}', 243457),
+        ('\u{3157}', 243713), ('\u{3158}', 243969), ('\u{3159}', 244225), ('\u{315a}', 244481),
+        ('\u{315b}', 244737), ('\u{315c}', 244993), ('\u{315d}', 245249), ('\u{315e}', 245505),
+        ('\u{315f}', 245761), ('\u{3160}', 246017), ('\u{3161}', 246273), ('\u{3162}', 246529),
+        ('\u{3163}', 246785), ('\u{3164}', 247041), ('\u{3165}', 247297), ('\u{3166}', 247553),
+        ('\u{3167}', 247809), ('\u{3168}', 248065), ('\u{3169}', 248321), ('\u{316a}', 248577),
+        ('\u{316b}', 248833), ('\u{316c}', 249089), ('\u{316d}', 249345), ('\u{316e}', 249601),
+        ('\u{316f}', 249857), ('\u{3170}', 250113), ('\u{3171}', 250369), ('\u{3172}', 250625),
+        ('\u{3173}', 250881), ('\u{3174}', 251137), ('\u{3175}', 251393), ('\u{3176}', 251649),
+        ('\u{3177}', 251905), ('\u{3178}', 252161), ('\u{3179}', 252417), ('\u{317a}', 252673),
+        ('\u{317b}', 252929), ('\u{317c}', 253185), ('\u{317d}', 253441), ('\u{317e}', 253697),
+        ('\u{317f}', 253953), ('\u{3180}', 254209), ('\u{3181}', 254465), ('\u{3182}', 254721),
+        ('\u{3183}', 254977), ('\u{3184}', 255233), ('\u{3185}', 255489), ('\u{3186}', 255745),
+        ('\u{3187}', 256001), ('\u{3188}', 256257), ('\u{3189}', 256513), ('\u{318a}', 256769),
+        ('\u{318b}', 257025), ('\u{318c}', 257281), ('\u{318d}', 257537), ('\u{318e}', 257793),
+        ('\u{3192}', 176385), ('\u{3193}', 177921), ('\u{3194}', 258049), ('\u{3195}', 258305),
+        ('\u{3196}', 258561), ('\u{3197}', 258817), ('\u{3198}', 259073), ('\u{3199}', 259329),
+        ('\u{319a}', 177409), ('\u{319b}', 259585), ('\u{319c}', 259841), ('\u{319d}', 260097),
+        ('\u{319e}', 260353), ('\u{319f}', 178433), ('\u{3200}', 260611), ('\u{3201}', 261379),
+        ('\u{3202}', 262147), ('\u{3203}', 262915), ('\u{3204}', 263683), ('\u{3205}', 264451),
+        ('\u{3206}', 265219), ('\u{3207}', 265987), ('\u{3208}', 266755), ('\u{3209}', 267523),
+        ('\u{320a}', 268291), ('\u{320b}', 269059), ('\u{320c}', 269827), ('\u{320d}', 270595),
+        ('\u{320e}', 271364), ('\u{320f}', 272388), ('\u{3210}', 273412), ('\u{3211}', 274436),
+        ('\u{3212}', 275460), ('\u{3213}', 276484), ('\u{3214}', 277508), ('\u{3215}', 278532),
+        ('\u{3216}', 279556), ('\u{3217}', 280580), ('\u{3218}', 281604), ('\u{3219}', 282628),
+        ('\u{321a}', 283652), ('\u{321b}', 284676), ('\u{321c}', 285700), ('\u{321d}', 286727),
+        ('\u{321e}', 288518), ('\u{3220}', 290051), ('\u{3221}', 290819), ('\u{3222}', 291587),
+        ('\u{3223}', 292355), ('\u{3224}', 293123), ('\u{3225}', 293891), ('\u{3226}', 294659),
+        ('\u{3227}', 295427), ('\u{3228}', 296195), ('\u{3229}', 296963), ('\u{322a}', 297731),
+        ('\u{322b}', 298499), ('\u{322c}', 299267), ('\u{322d}', 300035), ('\u{322e}', 300803),
+        ('\u{322f}', 301571), ('\u{3230}', 302339), ('\u{3231}', 303107), ('\u{3232}', 303875),
+        ('\u{3233}', 304643), ('\u{3234}', 305411), ('\u{3235}', 306179), ('\u{3236}', 306947),
+        ('\u{3237}', 307715), ('\u{3238}', 308483), ('\u{3239}', 309251), ('\u{323a}', 310019),
+        ('\u{323b}', 310787), ('\u{323c}', 311555), ('\u{323d}', 312323), ('\u{323e}', 313091),
+        ('\u{323f}', 313859), ('\u{3240}', 314627), ('\u{3241}', 315395), ('\u{3242}', 316163),
+        ('\u{3243}', 316931), ('\u{3244}', 317697), ('\u{3245}', 317953), ('\u{3246}', 193281),
+        ('\u{3247}', 318209), ('\u{3250}', 318467), ('\u{3251}', 319234), ('\u{3252}', 319746),
+        ('\u{3253}', 320258), ('\u{3254}', 320770), ('\u{3255}', 321282), ('\u{3256}', 321794),
+        ('\u{3257}', 322306), ('\u{3258}', 322818), ('\u{3259}', 323330), ('\u{325a}', 323842),
+        ('\u{325b}', 324354), ('\u{325c}', 324866), ('\u{325d}', 325378), ('\u{325e}', 325890),
+        ('\u{325f}', 326402), ('\u{3260}', 233985), ('\u{3261}', 234753), ('\u{3262}', 235521),
+        ('\u{3263}', 236033), ('\u{3264}', 238081), ('\u{3265}', 238337), ('\u{3266}', 239105),
+        ('\u{3267}', 239617), ('\u{3268}', 239873), ('\u{3269}', 240385), ('\u{326a}', 240641),
+        ('\u{326b}', 240897), ('\u{326c}', 241153), ('\u{326d}', 241409), ('\u{326e}', 326914),
+        ('\u{326f}', 327426), ('\u{3270}', 327938), ('\u{3271}', 328450), ('\u{3272}', 328962),
+        ('\u{3273}', 329474), ('\u{3274}', 329986), ('\u{3275}', 330498), ('\u{3276}', 331010),
+        ('\u{3277}', 331522), ('\u{3278}', 332034), ('\u{3279}', 332546), ('\u{327a}', 333058),
+        ('\u{327b}', 333570), ('\u{327c}', 334085), ('\u{327d}', 335364), ('\u{327e}', 336386),
+        ('\u{3280}', 176385), ('\u{3281}', 177921), ('\u{3282}', 258049), ('\u{3283}', 258305),
+        ('\u{3284}', 336897), ('\u{3285}', 337153), ('\u{3286}', 337409), ('\u{3287}', 179201),
+        ('\u{3288}', 337665), ('\u{3289}', 182273), ('\u{328a}', 195073), ('\u{328b}', 198145),
+        ('\u{328c}', 197889), ('\u{328d}', 195329), ('\u{328e}', 218881), ('\u{328f}', 184321),
+        ('\u{3290}', 194561), ('\u{3291}', 337921), ('\u{3292}', 338177), ('\u{3293}', 338433),
+        ('\u{3294}', 338689), ('\u{3295}', 338945), ('\u{3296}', 339201), ('\u{3297}', 339457),
+        ('\u{3298}', 339713), ('\u{3299}', 339969), ('\u{329a}', 340225), ('\u{329b}', 185857),
+        ('\u{329c}', 340481), ('\u{329d}', 340737), ('\u{329e}', 340993), ('\u{329f}', 341249),
+        ('\u{32a0}', 341505), ('\u{32a1}', 341761), ('\u{32a2}', 342017), ('\u{32a3}', 342273),
+        ('\u{32a4}', 258561), ('\u{32a5}', 258817), ('\u{32a6}', 259073), ('\u{32a7}', 342529),
+        ('\u{32a8}', 342785), ('\u{32a9}', 343041), ('\u{32aa}', 343297), ('\u{32ab}', 343553),
+        ('\u{32ac}', 343809), ('\u{32ad}', 344065), ('\u{32ae}', 344321), ('\u{32af}', 344577),
+        ('\u{32b0}', 344833), ('\u{32b1}', 345090), ('\u{32b2}', 345602), ('\u{32b3}', 346114),
+        ('\u{32b4}', 346626), ('\u{32b5}', 347138), ('\u{32b6}', 347650), ('\u{32b7}', 348162),
+        ('\u{32b8}', 348674), ('\u{32b9}', 349186), ('\u{32ba}', 349698), ('\u{32bb}', 350210),
+        ('\u{32bc}', 350722), ('\u{32bd}', 351234), ('\u{32be}', 351746), ('\u{32bf}', 352258),
+        ('\u{32c0}', 352770), ('\u{32c1}', 353282), ('\u{32c2}', 353794), ('\u{32c3}', 354306),
+        ('\u{32c4}', 354818), ('\u{32c5}', 355330), ('\u{32c6}', 355842), ('\u{32c7}', 356354),
+        ('\u{32c8}', 356866), ('\u{32c9}', 357379), ('\u{32ca}', 358147), ('\u{32cb}', 358915),
+        ('\u{32cc}', 359682), ('\u{32cd}', 360195), ('\u{32ce}', 360962), ('\u{32cf}', 361475),
+        ('\u{32d0}', 362241), ('\u{32d1}', 362497), ('\u{32d2}', 362753), ('\u{32d3}', 363009),
+        ('\u{32d4}', 363265), ('\u{32d5}', 363521), ('\u{32d6}', 363777), ('\u{32d7}', 364033),
+        ('\u{32d8}', 364289), ('\u{32d9}', 364545), ('\u{32da}', 364801), ('\u{32db}', 365057),
+        ('\u{32dc}', 365313), ('\u{32dd}', 365569), ('\u{32de}', 365825), ('\u{32df}', 366081),
+        ('\u{32e0}', 366337), ('\u{32e1}', 366593), ('\u{32e2}', 366849), ('\u{32e3}', 367105),
+        ('\u{32e4}', 367361), ('\u{32e5}', 367617), ('\u{32e6}', 367873), ('\u{32e7}', 368129),
+        ('\u{32e8}', 368385), ('\u{32e9}', 368641), ('\u{32ea}', 368897), ('\u{32eb}', 369153),
+        ('\u{32ec}', 369409), ('\u{32ed}', 369665), ('\u{32ee}', 369921), ('\u{32ef}', 370177),
+        ('\u{32f0}', 370433), ('\u{32f1}', 370689), ('\u{32f2}', 370945), ('\u{32f3}', 371201),
+        ('\u{32f4}', 371457), ('\u{32f5}', 371713), ('\u{32f6}', 371969), ('\u{32f7}', 372225),
+        ('\u{32f8}', 372481), ('\u{32f9}', 372737), ('\u{32fa}', 372993), ('\u{32fb}', 373249),
+        ('\u{32fc}', 373505), ('\u{32fd}', 373761), ('\u{32fe}', 374017), ('\u{32ff}', 374274),
+        ('\u{3300}', 374789), ('\u{3301}', 376068), ('\u{3302}', 377093), ('\u{3303}', 378371),
+        ('\u{3304}', 379141), ('\u{3305}', 380419), ('\u{3306}', 381187), ('\u{3307}', 381958),
+        ('\u{3308}', 383492), ('\u{3309}', 384515), ('\u{330a}', 385283), ('\u{330b}', 386051),
+        ('\u{330c}', 386820), ('\u{330d}', 387844), ('\u{330e}', 388868), ('\u{330f}', 389892),
+        ('\u{3310}', 390916), ('\u{3311}', 391940), ('\u{3312}', 392964), ('\u{3313}', 393990),
+        ('\u{3314}', 395522), ('\u{3315}', 396038), ('\u{3316}', 397574), ('\u{3317}', 399109),
+        ('\u{3318}', 400388), ('\u{3319}', 401414), ('\u{331a}', 402950), ('\u{331b}', 404484),
+        ('\u{331c}', 405507), ('\u{331d}', 406275), ('\u{331e}', 407044), ('\u{331f}', 408068),
+        ('\u{3320}', 409093), ('\u{3321}', 410373), ('\u{3322}', 411651), ('\u{3323}', 412419),
+        ('\u{3324}', 413188), ('\u{3325}', 414211), ('\u{3326}', 414979), ('\u{3327}', 415746),
+        ('\u{3328}', 416258), ('\u{3329}', 416771), ('\u{332a}', 417539), ('\u{332b}', 418310),
+        ('\u{332c}', 419844), ('\u{332d}', 420869), ('\u{332e}', 422150), ('\u{332f}', 423684),
+        ('\u{3330}', 424707), ('\u{3331}', 425475), ('\u{3332}', 426246), ('\u{3333}', 427780),
+        ('\u{3334}', 428806), ('\u{3335}', 430339), ('\u{3336}', 431109), ('\u{3337}', 432387),
+        ('\u{3338}', 433156), ('\u{3339}', 434179), ('\u{333a}', 434948), ('\u{333b}', 435973),
+        ('\u{333c}', 437252), ('\u{333d}', 438277), ('\u{333e}', 439556), ('\u{333f}', 440578),
+        ('\u{3340}', 441093), ('\u{3341}', 442371), ('\u{3342}', 443139), ('\u{3343}', 443908),
+        ('\u{3344}', 444931), ('\u{3345}', 445699), ('\u{3346}', 446467), ('\u{3347}', 447237),
+        ('\u{3348}', 448516), ('\u{3349}', 449538), ('\u{334a}', 450054), ('\u{334b}', 451587),
+        ('\u{334c}', 452357), ('\u{334d}', 453636), ('\u{334e}', 454660), ('\u{334f}', 455683),
+        ('\u{3350}', 456451), ('\u{3351}', 457220), ('\u{3352}', 458242), ('\u{3353}', 458756),
+        ('\u{3354}', 459781), ('\u{3355}', 461058), ('\u{3356}', 461574), ('\u{3357}', 463107),
+        ('\u{3358}', 463874), ('\u{3359}', 464386), ('\u{335a}', 464898), ('\u{335b}', 465410),
+        ('\u{335c}', 465922), ('\u{335d}', 466434), ('\u{335e}', 466946), ('\u{335f}', 467458),
+        ('\u{3360}', 467970), ('\u{3361}', 468482), ('\u{3362}', 468995), ('\u{3363}', 469763),
+        ('\u{3364}', 470531), ('\u{3365}', 471299), ('\u{3366}', 472067), ('\u{3367}', 472835),
+        ('\u{3368}', 473603), ('\u{3369}', 474371), ('\u{336a}', 475139), ('\u{336b}', 475907),
+        ('\u{336c}', 476675), ('\u{336d}', 477443), ('\u{336e}', 478211), ('\u{336f}', 478979),
+        ('\u{3370}', 479747), ('\u{3371}', 480515), ('\u{3372}', 481282), ('\u{3373}', 481794),
+        ('\u{3374}', 482307), ('\u{3375}', 483074), ('\u{3376}', 483586), ('\u{3377}', 484098),
+        ('\u{3378}', 484611), ('\u{3379}', 485379), ('\u{337a}', 486146), ('\u{337b}', 486658),
+        ('\u{337c}', 487170), ('\u{337d}', 487682), ('\u{337e}', 488194), ('\u{337f}', 488708),
+        ('\u{3380}', 489730), ('\u{3381}', 490242), ('\u{3382}', 490754), ('\u{3383}', 491266),
+        ('\u{3384}', 491778), ('\u{3385}', 492290), ('\u{3386}', 492802), ('\u{3387}', 493314),
+        ('\u{3388}', 493827), ('\u{3389}', 494596), ('\u{338a}', 495618), ('\u{338b}', 496130),
+        ('\u{338c}', 496642), ('\u{338d}', 497154), ('\u{338e}', 497666), ('\u{338f}', 498178),
+        ('\u{3390}', 498690), ('\u{3391}', 499203), ('\u{3392}', 499971), ('\u{3393}', 500739),
+        ('\u{3394}', 501507), ('\u{3395}', 502274), ('\u{3396}', 502786), ('\u{3397}', 503298),
+        ('\u{3398}', 503810), ('\u{3399}', 504322), ('\u{339a}', 504834), ('\u{339b}', 505346),
+        ('\u{339c}', 505858), ('\u{339d}', 506370), ('\u{339e}', 506882), ('\u{339f}', 507395),
+        ('\u{33a0}', 508163), ('\u{33a1}', 508930), ('\u{33a2}', 509443), ('\u{33a3}', 510211),
+        ('\u{33a4}', 510979), ('\u{33a5}', 511746), ('\u{33a6}', 512259), ('\u{33a7}', 513027),
+        ('\u{33a8}', 513796), ('\u{33a9}', 514818), ('\u{33aa}', 515331), ('\u{33ab}', 516099),
+        ('\u{33ac}', 516867), ('\u{33ad}', 517635), ('\u{33ae}', 518405), ('\u{33af}', 519686),
+        ('\u{33b0}', 521218), ('\u{33b1}', 521730), ('\u{33b2}', 522242), ('\u{33b3}', 522754),
+        ('\u{33b4}', 523266), ('\u{33b5}', 523778), ('\u{33b6}', 524290), ('\u{33b7}', 524802),
+        ('\u{33b8}', 525314), ('\u{33b9}', 525826), ('\u{33ba}', 526338), ('\u{33bb}', 526850),
+        ('\u{33bc}', 527362), ('\u{33bd}', 527874), ('\u{33be}', 528386), ('\u{33bf}', 528898),
+        ('\u{33c0}', 529410), ('\u{33c1}', 529922), ('\u{33c2}', 530436), ('\u{33c3}', 531458),
+        ('\u{33c4}', 531970), ('\u{33c5}', 532482), ('\u{33c6}', 532996), ('\u{33c7}', 534019),
+        ('\u{33c8}', 534786), ('\u{33c9}', 535298), ('\u{33ca}', 535810), ('\u{33cb}', 536322),
+        ('\u{33cc}', 536834), ('\u{33cd}', 537346), ('\u{33ce}', 537858), ('\u{33cf}', 538370),
+        ('\u{33d0}', 538882), ('\u{33d1}', 539394), ('\u{33d2}', 539907), ('\u{33d3}', 540674),
+        ('\u{33d4}', 541186), ('\u{33d5}', 541699), ('\u{33d6}', 542467), ('\u{33d7}', 543234),
+        ('\u{33d8}', 543748), ('\u{33d9}', 544771), ('\u{33da}', 545538), ('\u{33db}', 546050),
+        ('\u{33dc}', 546562), ('\u{33dd}', 547074), ('\u{33de}', 547587), ('\u{33df}', 548355),
+        ('\u{33e0}', 549122), ('\u{33e1}', 549634), ('\u{33e2}', 550146), ('\u{33e3}', 550658),
+        ('\u{33e4}', 551170), ('\u{33e5}', 551682), ('\u{33e6}', 552194), ('\u{33e7}', 552706),
+        ('\u{33e8}', 553218), ('\u{33e9}', 553731), ('\u{33ea}', 554499), ('\u{33eb}', 555267),
+        ('\u{33ec}', 556035), ('\u{33ed}', 556803), ('\u{33ee}', 557571), ('\u{33ef}', 558339),
+        ('\u{33f0}', 559107), ('\u{33f1}', 559875), ('\u{33f2}', 560643), ('\u{33f3}', 561411),
+        ('\u{33f4}', 562179), ('\u{33f5}', 562947), ('\u{33f6}', 563715), ('\u{33f7}', 564483),
+        ('\u{33f8}', 565251), ('\u{33f9}', 566019), ('\u{33fa}', 566787), ('\u{33fb}', 567555),
+        ('\u{33fc}', 568323), ('\u{33fd}', 569091), ('\u{33fe}', 569859), ('\u{33ff}', 570627),
+        ('\u{a69c}', 571393), ('\u{a69d}', 571649), ('\u{a770}', 571905), ('\u{a7f2}', 78081),
+        ('\u{a7f3}', 84225), ('\u{a7f4}', 81921), ('\u{a7f8}', 572161), ('\u{a7f9}', 572417),
+        ('\u{ab5c}', 572673), ('\u{ab5d}', 572929), ('\u{ab5e}', 573185), ('\u{ab5f}', 573441),
+        ('\u{ab69}', 573697), ('\u{fb00}', 573954), ('\u{fb01}', 574466), ('\u{fb02}', 574978),
+        ('\u{fb03}', 575491), ('\u{fb04}', 576259), ('\u{fb05}', 577026), ('\u{fb06}', 577026),
+        ('\u{fb13}', 577538), ('\u{fb14}', 578050), ('\u{fb15}', 578562), ('\u{fb16}', 579074),
+        ('\u{fb17}', 579586), ('\u{fb20}', 580097), ('\u{fb21}', 84481), ('\u{fb22}', 85249),
+        ('\u{fb23}', 580353), ('\u{fb24}', 580609), ('\u{fb25}', 580865), ('\u{fb26}', 581121),
+        ('\u{fb27}', 581377), ('\u{fb28}', 581633), ('\u{fb29}', 74497), ('\u{fb4f}', 581890),
+        ('\u{fb50}', 582401), ('\u{fb51}', 582401), ('\u{fb52}', 582657), ('\u{fb53}', 582657),
+        ('\u{fb54}', 582657), ('\u{fb55}', 582657), ('\u{fb56}', 582913), ('\u{fb57}', 582913),
+        ('\u{fb58}', 582913), ('\u{fb59}', 582913), ('\u{fb5a}', 583169), ('\u{fb5b}', 583169),
+        ('\u{fb5c}', 583169), ('\u{fb5d}', 583169), ('\u{fb5e}', 583425), ('\u{fb5f}', 583425),
+        ('\u{fb60}', 583425), ('\u{fb61}', 583425), ('\u{fb62}', 583681), ('\u{fb63}', 583681),
+        ('\u{fb64}', 583681), ('\u{fb65}', 583681), ('\u{fb66}', 583937), ('\u{fb67}', 583937),
+        ('\u{fb68}', 583937), ('\u{fb69}', 583937), ('\u{fb6a}', 584193), ('\u{fb6b}', 584193),
+        ('\u{fb6c}', 584193), ('\u{fb6d}', 584193), ('\u{fb6e}', 584449), ('\u{fb6f}', 584449),
+        ('\u{fb70}', 584449), ('\u{fb71}', 584449), ('\u{fb72}', 584705), ('\u{fb73}', 584705),
+        ('\u{fb74}', 584705), ('\u{fb75}', 584705), ('\u{fb76}', 584961), ('\u{fb77}', 584961),
+        ('\u{fb78}', 584961), ('\u{fb79}', 584961), ('\u{fb7a}', 585217), ('\u{fb7b}', 585217),
+        ('\u{fb7c}', 585217), ('\u{fb7d}', 585217), ('\u{fb7e}', 585473), ('\u{fb7f}', 585473),
+        ('\u{fb80}', 585473), ('\u{fb81}', 585473), ('\u{fb82}', 585729), ('\u{fb83}', 585729),
+        ('\u{fb84}', 585985), ('\u{fb85}', 585985), ('\u{fb86}', 586241), ('\u{fb87}', 586241),
+        ('\u{fb88}', 586497), ('\u{fb89}', 586497), ('\u{fb8a}', 586753), ('\u{fb8b}', 586753),
+        ('\u{fb8c}', 587009), ('\u{fb8d}', 587009), ('\u{fb8e}', 587265), ('\u{fb8f}', 587265),
+        ('\u{fb90}', 587265), ('\u{fb91}', 587265), ('\u{fb92}', 587521), ('\u{fb93}', 587521),
+        ('\u{fb94}', 587521), ('\u{fb95}', 587521), ('\u{fb96}', 587777), ('\u{fb97}', 587777),
+        ('\u{fb98}', 587777), ('\u{fb99}', 587777), ('\u{fb9a}', 588033), ('\u{fb9b}', 588033),
+        ('\u{fb9c}', 588033), ('\u{fb9d}', 588033), ('\u{fb9e}', 588289), ('\u{fb9f}', 588289),
+        ('\u{fba0}', 588545), ('\u{fba1}', 588545), ('\u{fba2}', 588545), ('\u{fba3}', 588545),
+        ('\u{fba4}', 588802), ('\u{fba5}', 588802), ('\u{fba6}', 589313), ('\u{fba7}', 589313),
+        ('\u{fba8}', 589313), ('\u{fba9}', 589313), ('\u{fbaa}', 589569), ('\u{fbab}', 589569),
+        ('\u{fbac}', 589569), ('\u{fbad}', 589569), ('\u{fbae}', 589825), ('\u{fbaf}', 589825),
+        ('\u{fbb0}', 590082), ('\u{fbb1}', 590082), ('\u{fbd3}', 590593), ('\u{fbd4}', 590593),
+        ('\u{fbd5}', 590593), ('\u{fbd6}', 590593), ('\u{fbd7}', 590849), ('\u{fbd8}', 590849),
+        ('\u{fbd9}', 591105), ('\u{fbda}', 591105), ('\u{fbdb}', 591361), ('\u{fbdc}', 591361),
+        ('\u{fbdd}', 28930), ('\u{fbde}', 591617), ('\u{fbdf}', 591617), ('\u{fbe0}', 591873),
+        ('\u{fbe1}', 591873), ('\u{fbe2}', 592129), ('\u{fbe3}', 592129), ('\u{fbe4}', 592385),
+        ('\u{fbe5}', 592385), ('\u{fbe6}', 592385), ('\u{fbe7}', 592385), ('\u{fbe8}', 592641),
+        ('\u{fbe9}', 592641), ('\u{fbea}', 592899), ('\u{fbeb}', 592899), ('\u{fbec}', 593667),
+        ('\u{fbed}', 593667), ('\u{fbee}', 594435), ('\u{fbef}', 594435), ('\u{fbf0}', 595203),
+        ('\u{fbf1}', 595203), ('\u{fbf2}', 595971), ('\u{fbf3}', 595971), ('\u{fbf4}', 596739),
+        ('\u{fbf5}', 596739), ('\u{fbf6}', 597507), ('\u{fbf7}', 597507), ('\u{fbf8}', 597507),
+        ('\u{fbf9}', 598275), ('\u{fbfa}', 598275), ('\u{fbfb}', 598275), ('\u{fbfc}', 599041),
+        ('\u{fbfd}', 599041), ('\u{fbfe}', 599041), ('\u{fbff}', 599041), ('\u{fc00}', 599299),
+        ('\u{fc01}', 600067), ('\u{fc02}', 600835), ('\u{fc03}', 598275), ('\u{fc04}', 601603),
+        ('\u{fc05}', 602370), ('\u{fc06}', 602882), ('\u{fc07}', 603394), ('\u{fc08}', 603906),
+        ('\u{fc09}', 604418), ('\u{fc0a}', 604930), ('\u{fc0b}', 605442), ('\u{fc0c}', 605954),
+        ('\u{fc0d}', 606466), ('\u{fc0e}', 606978), ('\u{fc0f}', 607490), ('\u{fc10}', 608002),
+        ('\u{fc11}', 608514), ('\u{fc12}', 609026), ('\u{fc13}', 609538), ('\u{fc14}', 610050),
+        ('\u{fc15}', 610562), ('\u{fc16}', 611074), ('\u{fc17}', 611586), ('\u{fc18}', 612098),
+        ('\u{fc19}', 612610), ('\u{fc1a}', 613122), ('\u{fc1b}', 613634), ('\u{fc1c}', 614146),
+        ('\u{fc1d}', 614658), ('\u{fc1e}', 615170), ('\u{fc1f}', 615682), ('\u{fc20}', 616194),
+        ('\u{fc21}', 616706), ('\u{fc22}', 617218), ('\u{fc23}', 617730), ('\u{fc24}', 618242),
+        ('\u{fc25}', 618754), ('\u{fc26}', 619266), ('\u{fc27}', 619778), ('\u{fc28}', 620290),
+        ('\u{fc29}', 620802), ('\u{fc2a}', 621314), ('\u{fc2b}', 621826), ('\u{fc2c}', 622338),
+        ('\u{fc2d}', 622850), ('\u{fc2e}', 623362), ('\u{fc2f}', 623874), ('\u{fc30}', 624386),
+        ('\u{fc31}', 624898), ('\u{fc32}', 625410), ('\u{fc33}', 625922), ('\u{fc34}', 626434),
+        ('\u{fc35}', 626946), ('\u{fc36}', 627458), ('\u{fc37}', 627970), ('\u{fc38}', 628482),
+        ('\u{fc39}', 628994), ('\u{fc3a}', 629506), ('\u{fc3b}', 630018), ('\u{fc3c}', 630530),
+        ('\u{fc3d}', 631042), ('\u{fc3e}', 631554), ('\u{fc3f}', 632066), ('\u{fc40}', 632578),
+        ('\u{fc41}', 633090), ('\u{fc42}', 633602), ('\u{fc43}', 634114), ('\u{fc44}', 634626),
+        ('\u{fc45}', 635138), ('\u{fc46}', 635650), ('\u{fc47}', 636162), ('\u{fc48}', 636674),
+        ('\u{fc49}', 637186), ('\u{fc4a}', 637698), ('\u{fc4b}', 638210), ('\u{fc4c}', 638722),
+        ('\u{fc4d}', 639234), ('\u{fc4e}', 639746), ('\u{fc4f}', 640258), ('\u{fc50}', 640770),
+        ('\u{fc51}', 641282), ('\u{fc52}', 641794), ('\u{fc53}', 642306), ('\u{fc54}', 642818),
+        ('\u{fc55}', 643330), ('\u{fc56}', 643842), ('\u{fc57}', 644354), ('\u{fc58}', 644866),
+        ('\u{fc59}', 645378), ('\u{fc5a}', 645890), ('\u{fc5b}', 646402), ('\u{fc5c}', 646914),
+        ('\u{fc5d}', 647426), ('\u{fc5e}', 647939), ('\u{fc5f}', 648707), ('\u{fc60}', 649475),
+        ('\u{fc61}', 650243), ('\u{fc62}', 651011), ('\u{fc63}', 651779), ('\u{fc64}', 652547),
+        ('\u{fc65}', 653315), ('\u{fc66}', 600835), ('\u{fc67}', 654083), ('\u{fc68}', 598275),
+        ('\u{fc69}', 601603), ('\u{fc6a}', 654850), ('\u{fc6b}', 655362), ('\u{fc6c}', 603906),
+        ('\u{fc6d}', 655874), ('\u{fc6e}', 604418), ('\u{fc6f}', 604930), ('\u{fc70}', 656386),
+        ('\u{fc71}', 656898), ('\u{fc72}', 606978), ('\u{fc73}', 657410), ('\u{fc74}', 607490),
+        ('\u{fc75}', 608002), ('\u{fc76}', 657922), ('\u{fc77}', 658434), ('\u{fc78}', 609026),
+        ('\u{fc79}', 658946), ('\u{fc7a}', 609538), ('\u{fc7b}', 610050), ('\u{fc7c}', 624898),
+        ('\u{fc7d}', 625410), ('\u{fc7e}', 626946), ('\u{fc7f}', 627458), ('\u{fc80}', 627970),
+        ('\u{fc81}', 630018), ('\u{fc82}', 630530), ('\u{fc83}', 631042), ('\u{fc84}', 631554),
+        ('\u{fc85}', 633602), ('\u{fc86}', 634114), ('\u{fc87}', 634626), ('\u{fc88}', 659458),
+        ('\u{fc89}', 636674), ('\u{fc8a}', 659970), ('\u{fc8b}', 660482), ('\u{fc8c}', 639746),
+        ('\u{fc8d}', 660994), ('\u{fc8e}', 640258), ('\u{fc8f}', 640770), ('\u{fc90}', 647426),
+        ('\u{fc91}', 661506), ('\u{fc92}', 662018), ('\u{fc93}', 644866), ('\u{fc94}', 662530),
+        ('\u{fc95}', 645378), ('\u{fc96}', 645890), ('\u{fc97}', 599299), ('\u{fc98}', 600067),
+        ('\u{fc99}', 663043), ('\u{fc9a}', 600835), ('\u{fc9b}', 663811), ('\u{fc9c}', 602370),
+        ('\u{fc9d}', 602882), ('\u{fc9e}', 603394), ('\u{fc9f}', 603906), ('\u{fca0}', 664578),
+        ('\u{fca1}', 605442), ('\u{fca2}', 605954), ('\u{fca3}', 606466), ('\u{fca4}', 606978),
+        ('\u{fca5}', 665090), ('\u{fca6}', 609026), ('\u{fca7}', 610562), ('\u{fca8}', 611074),
+        ('\u{fca9}', 611586), ('\u{fcaa}', 612098), ('\u{fcab}', 612610), ('\u{fcac}', 613634),
+        ('\u{fcad}', 614146), ('\u{fcae}', 614658), ('\u{fcaf}', 615170), ('\u{fcb0}', 615682),
+        ('\u{fcb1}', 616194), ('\u{fcb2}', 665602), ('\u{fcb3}', 616706), ('\u{fcb4}', 617218),
+        ('\u{fcb5}', 617730), ('\u{fcb6}', 618242), ('\u{fcb7}', 618754), ('\u{fcb8}', 619266),
+        ('\u{fcb9}', 620290), ('\u{fcba}', 620802), ('\u{fcbb}', 621314), ('\u{fcbc}', 621826),
+        ('\u{fcbd}', 622338), ('\u{fcbe}', 622850), ('\u{fcbf}', 623362), ('\u{fcc0}', 623874),
+        ('\u{fcc1}', 624386), ('\u{fcc2}', 625922), ('\u{fcc3}', 626434), ('\u{fcc4}', 628482),
+        ('\u{fcc5}', 628994), ('\u{fcc6}', 629506), ('\u{fcc7}', 630018), ('\u{fcc8}', 630530),
+        ('\u{fcc9}', 632066), ('\u{fcca}', 632578), ('\u{fccb}', 633090), ('\u{fccc}', 633602),
+        ('\u{fccd}', 666114), ('\u{fcce}', 635138), ('\u{fccf}', 635650), ('\u{fcd0}', 636162),
+        ('\u{fcd1}', 636674), ('\u{fcd2}', 638210), ('\u{fcd3}', 638722), ('\u{fcd4}', 639234),
+        ('\u{fcd5}', 639746), ('\u{fcd6}', 666626), ('\u{fcd7}', 641282), ('\u{fcd8}', 641794),
+        ('\u{fcd9}', 667138), ('\u{fcda}', 643330), ('\u{fcdb}', 643842), ('\u{fcdc}', 644354),
+        ('\u{fcdd}', 644866), ('\u{fcde}', 667650), ('\u{fcdf}', 600835), ('\u{fce0}', 663811),
+        ('\u{fce1}', 603906), ('\u{fce2}', 664578), ('\u{fce3}', 606978), ('\u{fce4}', 665090),
+        ('\u{fce5}', 609026), ('\u{fce6}', 668162), ('\u{fce7}', 615682), ('\u{fce8}', 668674),
+        ('\u{fce9}', 669186), ('\u{fcea}', 669698), ('\u{fceb}', 630018), ('\u{fcec}', 630530),
+        ('\u{fced}', 633602), ('\u{fcee}', 639746), ('\u{fcef}', 666626), ('\u{fcf0}', 644866),
+        ('\u{fcf1}', 667650), ('\u{fcf2}', 670211), ('\u{fcf3}', 670979), ('\u{fcf4}', 671747),
+        ('\u{fcf5}', 672514), ('\u{fcf6}', 673026), ('\u{fcf7}', 673538), ('\u{fcf8}', 674050),
+        ('\u{fcf9}', 674562), ('\u{fcfa}', 675074), ('\u{fcfb}', 675586), ('\u{fcfc}', 676098),
+        ('\u{fcfd}', 676610), ('\u{fcfe}', 677122), ('\u{fcff}', 677634), ('\u{fd00}', 678146),
+        ('\u{fd01}', 678658), ('\u{fd02}', 679170), ('\u{fd03}', 679682), ('\u{fd04}', 680194),
+        ('\u{fd05}', 680706), ('\u{fd06}', 681218), ('\u{fd07}', 681730), ('\u{fd08}', 682242),
+        ('\u{fd09}', 682754), ('\u{fd0a}', 683266), ('\u{fd0b}', 683778), ('\u{fd0c}', 669186),
+        ('\u{fd0d}', 684290), ('\u{fd0e}', 684802), ('\u{fd0f}', 685314), ('\u{fd10}', 685826),
+        ('\u{fd11}', 672514), ('\u{fd12}', 673026), ('\u{fd13}', 673538), ('\u{fd14}', 674050),
+        ('\u{fd15}', 674562), ('\u{fd16}', 675074), ('\u{fd17}', 675586), ('\u{fd18}', 676098),
+        ('\u{fd19}', 676610), ('\u{fd1a}', 677122), ('\u{fd1b}', 677634), ('\u{fd1c}', 678146),
+        ('\u{fd1d}', 678658), ('\u{fd1e}', 679170), ('\u{fd1f}', 679682), ('\u{fd20}', 680194),
+        ('\u{fd21}', 680706), ('\u{fd22}', 681218), ('\u{fd23}', 681730), ('\u{fd24}', 682242),
+        ('\u{fd25}', 682754), ('\u{fd26}', 683266), ('\u{fd27}', 683778), ('\u{fd28}', 669186),
+        ('\u{fd29}', 684290), ('\u{fd2a}', 684802), ('\u{fd2b}', 685314), ('\u{fd2c}', 685826),
+        ('\u{fd2d}', 682754), ('\u{fd2e}', 683266), ('\u{fd2f}', 683778), ('\u{fd30}', 669186),
+        ('\u{fd31}', 668674), ('\u{fd32}', 669698), ('\u{fd33}', 619778), ('\u{fd34}', 614146),
+        ('\u{fd35}', 614658), ('\u{fd36}', 615170), ('\u{fd37}', 682754), ('\u{fd38}', 683266),
+        ('\u{fd39}', 683778), ('\u{fd3a}', 619778), ('\u{fd3b}', 620290), ('\u{fd3c}', 686338),
+        ('\u{fd3d}', 686338), ('\u{fd50}', 686851), ('\u{fd51}', 687619), ('\u{fd52}', 687619),
+        ('\u{fd53}', 688387), ('\u{fd54}', 689155), ('\u{fd55}', 689923), ('\u{fd56}', 690691),
+        ('\u{fd57}', 691459), ('\u{fd58}', 692227), ('\u{fd59}', 692227), ('\u{fd5a}', 692995),
+        ('\u{fd5b}', 693763), ('\u{fd5c}', 694531), ('\u{fd5d}', 695299), ('\u{fd5e}', 696067),
+        ('\u{fd5f}', 696835), ('\u{fd60}', 696835), ('\u{fd61}', 697603), ('\u{fd62}', 698371),
+        ('\u{fd63}', 698371), ('\u{fd64}', 699139), ('\u{fd65}', 699139), ('\u{fd66}', 699907),
+        ('\u{fd67}', 700675), ('\u{fd68}', 700675), ('\u{fd69}', 701443), ('\u{fd6a}', 702211),
+        ('\u{fd6b}', 702211), ('\u{fd6c}', 702979), ('\u{fd6d}', 702979), ('\u{fd6e}', 703747),
+        ('\u{fd6f}', 704515), ('\u{fd70}', 704515), ('\u{fd71}', 705283), ('\u{fd72}', 705283),
+        ('\u{fd73}', 706051), ('\u{fd74}', 706819), ('\u{fd75}', 707587), ('\u{fd76}', 708355),
+        ('\u{fd77}', 708355), ('\u{fd78}', 709123), ('\u{fd79}', 709891), ('\u{fd7a}', 710659),
+        ('\u{fd7b}', 711427), ('\u{fd7c}', 712195), ('\u{fd7d}', 712195), ('\u{fd7e}', 712963),
+        ('\u{fd7f}', 713731), ('\u{fd80}', 714499), ('\u{fd81}', 715267), ('\u{fd82}', 716035),
+        ('\u{fd83}', 716803), ('\u{fd84}', 716803), ('\u{fd85}', 717571), ('\u{fd86}', 717571),
+        ('\u{fd87}', 718339), ('\u{fd88}', 718339), ('\u{fd89}', 719107), ('\u{fd8a}', 719875),
+        ('\u{fd8b}', 720643), ('\u{fd8c}', 721411), ('\u{fd8d}', 722179), ('\u{fd8e}', 722947),
+        ('\u{fd8f}', 723715), ('\u{fd92}', 724483), ('\u{fd93}', 725251), ('\u{fd94}', 726019),
+        ('\u{fd95}', 726787), ('\u{fd96}', 727555), ('\u{fd97}', 728323), ('\u{fd98}', 728323),
+        ('\u{fd99}', 729091), ('\u{fd9a}', 729859), ('\u{fd9b}', 730627), ('\u{fd9c}', 731395),
+        ('\u{fd9d}', 731395), ('\u{fd9e}', 732163), ('\u{fd9f}', 732931), ('\u{fda0}', 733699),
+        ('\u{fda1}', 734467), ('\u{fda2}', 735235), ('\u{fda3}', 736003), ('\u{fda4}', 736771),
+        ('\u{fda5}', 737539), ('\u{fda6}', 738307), ('\u{fda7}', 739075), ('\u{fda8}', 739843),
+        ('\u{fda9}', 740611), ('\u{fdaa}', 741379), ('\u{fdab}', 742147), ('\u{fdac}', 742915),
+        ('\u{fdad}', 743683), ('\u{fdae}', 744451), ('\u{fdaf}', 745219), ('\u{fdb0}', 745987),
+        ('\u{fdb1}', 746755), ('\u{fdb2}', 747523), ('\u{fdb3}', 748291), ('\u{fdb4}', 712963),
+        ('\u{fdb5}', 714499), ('\u{fdb6}', 749059), ('\u{fdb7}', 749827), ('\u{fdb8}', 750595),
+        ('\u{fdb9}', 751363), ('\u{fdba}', 752131), ('\u{fdbb}', 752899), ('\u{fdbc}', 752131),
+        ('\u{fdbd}', 750595), ('\u{fdbe}', 753667), ('\u{fdbf}', 754435), ('\u{fdc0}', 755203),
+        ('\u{fdc1}', 755971), ('\u{fdc2}', 756739), ('\u{fdc3}', 752899), ('\u{fdc4}', 707587),
+        ('\u{fdc5}', 699907), ('\u{fdc6}', 757507), ('\u{fdc7}', 758275), ('\u{fdf0}', 759043),
+        ('\u{fdf1}', 759811), ('\u{fdf2}', 760580), ('\u{fdf3}', 761604), ('\u{fdf4}', 762628),
+        ('\u{fdf5}', 763652), ('\u{fdf6}', 764676), ('\u{fdf7}', 765700), ('\u{fdf8}', 766724),
+        ('\u{fdf9}', 767747), ('\u{fdfa}', 768530), ('\u{fdfb}', 773128), ('\u{fdfc}', 775172),
+        ('\u{fe10}', 776193), ('\u{fe11}', 776449), ('\u{fe12}', 776705), ('\u{fe13}', 776961),
+        ('\u{fe14}', 777217), ('\u{fe15}', 777473), ('\u{fe16}', 777729), ('\u{fe17}', 777985),
+        ('\u{fe18}', 778241), ('\u{fe19}', 65795), ('\u{fe30}', 65282), ('\u{fe31}', 778497),
+        ('\u{fe32}', 778753), ('\u{fe33}', 779009), ('\u{fe34}', 779009), ('\u{fe35}', 75265),
+        ('\u{fe36}', 75521), ('\u{fe37}', 779265), ('\u{fe38}', 779521), ('\u{fe39}', 779777),
+        ('\u{fe3a}', 780033), ('\u{fe3b}', 780289), ('\u{fe3c}', 780545), ('\u{fe3d}', 780801),
+        ('\u{fe3e}', 781057), ('\u{fe3f}', 781313), ('\u{fe40}', 781569), ('\u{fe41}', 781825),
+        ('\u{fe42}', 782081), ('\u{fe43}', 782337), ('\u{fe44}', 782593), ('\u{fe47}', 782849),
+        ('\u{fe48}', 783105), ('\u{fe49}', 69634), ('\u{fe4a}', 69634), ('\u{fe4b}', 69634),
+        ('\u{fe4c}', 69634), ('\u{fe4d}', 779009), ('\u{fe4e}', 779009), ('\u{fe4f}', 779009),
+        ('\u{fe50}', 776193), ('\u{fe51}', 776449), ('\u{fe52}', 65025), ('\u{fe54}', 777217),
+        ('\u{fe55}', 776961), ('\u{fe56}', 777729), ('\u{fe57}', 777473), ('\u{fe58}', 778497),
+        ('\u{fe59}', 75265), ('\u{fe5a}', 75521), ('\u{fe5b}', 779265), ('\u{fe5c}', 779521),
+        ('\u{fe5d}', 779777), ('\u{fe5e}', 780033), ('\u{fe5f}', 783361), ('\u{fe60}', 783617),
+        ('\u{fe61}', 783873), ('\u{fe62}', 74497), ('\u{fe63}', 784129), ('\u{fe64}', 784385),
+        ('\u{fe65}', 784641), ('\u{fe66}', 75009), ('\u{fe68}', 784897), ('\u{fe69}', 785153),
+        ('\u{fe6a}', 785409), ('\u{fe6b}', 785665), ('\u{fe70}', 785922), ('\u{fe71}', 786434),
+        ('\u{fe72}', 786946), ('\u{fe74}', 787458), ('\u{fe76}', 787970), ('\u{fe77}', 788482),
+        ('\u{fe78}', 788994), ('\u{fe79}', 789506), ('\u{fe7a}', 790018), ('\u{fe7b}', 790530),
+        ('\u{fe7c}', 791042), ('\u{fe7d}', 791554), ('\u{fe7e}', 792066), ('\u{fe7f}', 792578),
+        ('\u{fe80}', 793089), ('\u{fe81}', 793346), ('\u{fe82}', 793346), ('\u{fe83}', 793858),
+        ('\u{fe84}', 793858), ('\u{fe85}', 794370), ('\u{fe86}', 794370), ('\u{fe87}', 794882),
+        ('\u{fe88}', 794882), ('\u{fe89}', 795394), ('\u{fe8a}', 795394), ('\u{fe8b}', 795394),
+        ('\u{fe8c}', 795394), ('\u{fe8d}', 795905), ('\u{fe8e}', 795905), ('\u{fe8f}', 796161),
+        ('\u{fe90}', 796161), ('\u{fe91}', 796161), ('\u{fe92}', 796161), ('\u{fe93}', 796417),
+        ('\u{fe94}', 796417), ('\u{fe95}', 796673), ('\u{fe96}', 796673), ('\u{fe97}', 796673),
+        ('\u{fe98}', 796673), ('\u{fe99}', 796929), ('\u{fe9a}', 796929), ('\u{fe9b}', 796929),
+        ('\u{fe9c}', 796929), ('\u{fe9d}', 797185), ('\u{fe9e}', 797185), ('\u{fe9f}', 797185),
+        ('\u{fea0}', 797185), ('\u{fea1}', 797441), ('\u{fea2}', 797441), ('\u{fea3}', 797441),
+        ('\u{fea4}', 797441), ('\u{fea5}', 797697), ('\u{fea6}', 797697), ('\u{fea7}', 797697),
+        ('\u{fea8}', 797697), ('\u{fea9}', 797953), ('\u{feaa}', 797953), ('\u{feab}', 798209),
+        ('\u{feac}', 798209), ('\u{fead}', 798465), ('\u{feae}', 798465), ('\u{feaf}', 798721),
+        ('\u{feb0}', 798721), ('\u{feb1}', 798977), ('\u{feb2}', 798977), ('\u{feb3}', 798977),
+        ('\u{feb4}', 798977), ('\u{feb5}', 799233), ('\u{feb6}', 799233), ('\u{feb7}', 799233),
+        ('\u{feb8}', 799233), ('\u{feb9}', 799489), ('\u{feba}', 799489), ('\u{febb}', 799489),
+        ('\u{febc}', 799489), ('\u{febd}', 799745), ('\u{febe}', 799745), ('\u{febf}', 799745),
+        ('\u{fec0}', 799745), ('\u{fec1}', 800001), ('\u{fec2}', 800001), ('\u{fec3}', 800001),
+        ('\u{fec4}', 800001), ('\u{fec5}', 800257), ('\u{fec6}', 800257), ('\u{fec7}', 800257),
+        ('\u{fec8}', 800257), ('\u{fec9}', 800513), ('\u{feca}', 800513), ('\u{fecb}', 800513),
+        ('\u{fecc}', 800513), ('\u{fecd}', 800769), ('\u{fece}', 800769), ('\u{fecf}', 800769),
+        ('\u{fed0}', 800769), ('\u{fed1}', 801025), ('\u{fed2}', 801025), ('\u{fed3}', 801025),
+        ('\u{fed4}', 801025), ('\u{fed5}', 801281), ('\u{fed6}', 801281), ('\u{fed7}', 801281),
+        ('\u{fed8}', 801281), ('\u{fed9}', 801537), ('\u{feda}', 801537), ('\u{fedb}', 801537),
+        ('\u{fedc}', 801537), ('\u{fedd}', 801793), ('\u{fede}', 801793), ('\u{fedf}', 801793),
+        ('\u{fee0}', 801793), ('\u{fee1}', 802049), ('\u{fee2}', 802049), ('\u{fee3}', 802049),
+        ('\u{fee4}', 802049), ('\u{fee5}', 802305), ('\u{fee6}', 802305), ('\u{fee7}', 802305),
+        ('\u{fee8}', 802305), ('\u{fee9}', 802561), ('\u{feea}', 802561), ('\u{feeb}', 802561),
+        ('\u{feec}', 802561), ('\u{feed}', 802817), ('\u{feee}', 802817), ('\u{feef}', 592641),
+        ('\u{fef0}', 592641), ('\u{fef1}', 803073), ('\u{fef2}', 803073), ('\u{fef3}', 803073),
+        ('\u{fef4}', 803073), ('\u{fef5}', 803331), ('\u{fef6}', 803331), ('\u{fef7}', 804099),
+        ('\u{fef8}', 804099), ('\u{fef9}', 804867), ('\u{fefa}', 804867), ('\u{fefb}', 805634),
+        ('\u{fefc}', 805634), ('\u{ff01}', 777473), ('\u{ff02}', 806145), ('\u{ff03}', 783361),
+        ('\u{ff04}', 785153), ('\u{ff05}', 785409), ('\u{ff06}', 783617), ('\u{ff07}', 806401),
+        ('\u{ff08}', 75265), ('\u{ff09}', 75521), ('\u{ff0a}', 783873), ('\u{ff0b}', 74497),
+        ('\u{ff0c}', 776193), ('\u{ff0d}', 784129), ('\u{ff0e}', 65025), ('\u{ff0f}', 806657),
+        ('\u{ff10}', 72705), ('\u{ff11}', 3329), ('\u{ff12}', 1537), ('\u{ff13}', 1793),
+        ('\u{ff14}', 72961), ('\u{ff15}', 73217), ('\u{ff16}', 73473), ('\u{ff17}', 73729),
+        ('\u{ff18}', 73985), ('\u{ff19}', 74241), ('\u{ff1a}', 776961), ('\u{ff1b}', 777217),
+        ('\u{ff1c}', 784385), ('\u{ff1d}', 75009), ('\u{ff1e}', 784641), ('\u{ff1f}', 777729),
+        ('\u{ff20}', 785665), ('\u{ff21}', 34049), ('\u{ff22}', 34561), ('\u{ff23}', 78081),
+        ('\u{ff24}', 34817), ('\u{ff25}', 35073), ('\u{ff26}', 84225), ('\u{ff27}', 35585),
+        ('\u{ff28}', 35841), ('\u{ff29}', 36097), ('\u{ff2a}', 36353), ('\u{ff2b}', 36609),
+        ('\u{ff2c}', 36865), ('\u{ff2d}', 37121), ('\u{ff2e}', 37377), ('\u{ff2f}', 37633),
+        ('\u{ff30}', 38145), ('\u{ff31}', 81921), ('\u{ff32}', 38401), ('\u{ff33}', 171777),
+        ('\u{ff34}', 38657), ('\u{ff35}', 38913), ('\u{ff36}', 101121), ('\u{ff37}', 39169),
+        ('\u{ff38}', 104193), ('\u{ff39}', 172033), ('\u{ff3a}', 83969), ('\u{ff3b}', 782849),
+        ('\u{ff3c}', 784897), ('\u{ff3d}', 783105), ('\u{ff3e}', 806913), ('\u{ff3f}', 779009),
+        ('\u{ff40}', 807169), ('\u{ff41}', 769), ('\u{ff42}', 40193), ('\u{ff43}', 46849),
+        ('\u{ff44}', 40449), ('\u{ff45}', 40705), ('\u{ff46}', 47617), ('\u{ff47}', 41729),
+        ('\u{ff48}', 15873), ('\u{ff49}', 46081), ('\u{ff4a}', 16385), ('\u{ff4b}', 41985),
+        ('\u{ff4c}', 21505), ('\u{ff4d}', 42241), ('\u{ff4e}', 75777), ('\u{ff4f}', 3585),
+        ('\u{ff50}', 43521), ('\u{ff51}', 172289), ('\u{ff52}', 16641), ('\u{ff53}', 8705),
+        ('\u{ff54}', 43777), ('\u{ff55}', 44033), ('\u{ff56}', 44801), ('\u{ff57}', 17665),
+        ('\u{ff58}', 21761), ('\u{ff59}', 17921), ('\u{ff5a}', 54529), ('\u{ff5b}', 779265),
+        ('\u{ff5c}', 807425), ('\u{ff5d}', 779521), ('\u{ff5e}', 807681), ('\u{ff5f}', 807937),
+        ('\u{ff60}', 808193), ('\u{ff61}', 776705), ('\u{ff62}', 781825), ('\u{ff63}', 782081),
+        ('\u{ff64}', 776449), ('\u{ff65}', 808449), ('\u{ff66}', 374017), ('\u{ff67}', 808705),
+        ('\u{ff68}', 808961), ('\u{ff69}', 809217), ('\u{ff6a}', 809473), ('\u{ff6b}', 809729),
+        ('\u{ff6c}', 809985), ('\u{ff6d}', 810241), ('\u{ff6e}', 810497), ('\u{ff6f}', 810753),
+        ('\u{ff70}', 811009), ('\u{ff71}', 362241), ('\u{ff72}', 362497), ('\u{ff73}', 362753),
+        ('\u{ff74}', 363009), ('\u{ff75}', 363265), ('\u{ff76}', 363521), ('\u{ff77}', 363777),
+        ('\u{ff78}', 364033), ('\u{ff79}', 364289), ('\u{ff7a}', 364545), ('\u{ff7b}', 364801),
+        ('\u{ff7c}', 365057), ('\u{ff7d}', 365313), ('\u{ff7e}', 365569), ('\u{ff7f}', 365825),
+        ('\u{ff80}', 366081), ('\u{ff81}', 366337), ('\u{ff82}', 366593), ('\u{ff83}', 366849),
+        ('\u{ff84}', 367105), ('\u{ff85}', 367361), ('\u{ff86}', 367617), ('\u{ff87}', 367873),
+        ('\u{ff88}', 368129), ('\u{ff89}', 368385), ('\u{ff8a}', 368641), ('\u{ff8b}', 368897),
+        ('\u{ff8c}', 369153), ('\u{ff8d}', 369409), ('\u{ff8e}', 369665), ('\u{ff8f}', 369921),
+        ('\u{ff90}', 370177), ('\u{ff91}', 370433), ('\u{ff92}', 370689), ('\u{ff93}', 370945),
+        ('\u{ff94}', 371201), ('\u{ff95}', 371457), ('\u{ff96}', 371713), ('\u{ff97}', 371969),
+        ('\u{ff98}', 372225), ('\u{ff99}', 372481), ('\u{ff9a}', 372737), ('\u{ff9b}', 372993),
+        ('\u{ff9c}', 373249), ('\u{ff9d}', 811265), ('\u{ff9e}', 811521), ('\u{ff9f}', 811777),
+        ('\u{ffa0}', 247041), ('\u{ffa1}', 233985), ('\u{ffa2}', 234241), ('\u{ffa3}', 234497),
+        ('\u{ffa4}', 234753), ('\u{ffa5}', 235009), ('\u{ffa6}', 235265), ('\u{ffa7}', 235521),
+        ('\u{ffa8}', 235777), ('\u{ffa9}', 236033), ('\u{ffaa}', 236289), ('\u{ffab}', 236545),
+        ('\u{ffac}', 236801), ('\u{ffad}', 237057), ('\u{ffae}', 237313), ('\u{ffaf}', 237569),
+        ('\u{ffb0}', 237825), ('\u{ffb1}', 238081), ('\u{ffb2}', 238337), ('\u{ffb3}', 238593),
+        ('\u{ffb4}', 238849), ('\u{ffb5}', 239105), ('\u{ffb6}', 239361), ('\u{ffb7}', 239617),
+        ('\u{ffb8}', 239873), ('\u{ffb9}', 240129), ('\u{ffba}', 240385), ('\u{ffbb}', 240641),
+        ('\u{ffbc}', 240897), ('\u{ffbd}', 241153), ('\u{ffbe}', 241409), ('\u{ffc2}', 241665),
+        ('\u{ffc3}', 241921), ('\u{ffc4}', 242177), ('\u{ffc5}', 242433), ('\u{ffc6}', 242689),
+        ('\u{ffc7}', 242945), ('\u{ffca}', 243201), ('\u{ffcb}', 243457), ('\u{ffcc}', 243713),
+        ('\u{ffcd}', 243969), ('\u{ffce}', 244225), ('\u{ffcf}', 244481), ('\u{ffd2}', 244737),
+        ('\u{ffd3}', 244993), ('\u{ffd4}', 245249), ('\u{ffd5}', 245505), ('\u{ffd6}', 245761),
+        ('\u{ffd7}', 246017), ('\u{ffda}', 246273), ('\u{ffdb}', 246529), ('\u{ffdc}', 246785),
+        ('\u{ffe0}', 812033), ('\u{ffe1}', 812289), ('\u{ffe2}', 812545), ('\u{ffe3}', 1026),
+        ('\u{ffe4}', 812801), ('\u{ffe5}', 813057), ('\u{ffe6}', 813313), ('\u{ffe8}', 813569),
+        ('\u{ffe9}', 813825), ('\u{ffea}', 814081), ('\u{ffeb}', 814337), ('\u{ffec}', 814593),
+        ('\u{ffed}', 814849), ('\u{ffee}', 815105), ('\u{10781}', 815361), ('\u{10782}', 815617),
+        ('\u{10783}', 815873), ('\u{10784}', 816129), ('\u{10785}', 816385), ('\u{10787}', 816641),
+        ('\u{10788}', 816897), ('\u{10789}', 817153), ('\u{1078a}', 817409), ('\u{1078b}', 817665),
+        ('\u{1078c}', 817921), ('\u{1078d}', 818177), ('\u{1078e}', 818433), ('\u{1078f}', 818689),
+        ('\u{10790}', 818945), ('\u{10791}', 819201), ('\u{10792}', 819457), ('\u{10793}', 819713),
+        ('\u{10794}', 819969), ('\u{10795}', 81153), ('\u{10796}', 820225), ('\u{10797}', 820481),
+        ('\u{10798}', 820737), ('\u{10799}', 820993), ('\u{1079a}', 821249), ('\u{1079b}', 821505),
+        ('\u{1079c}', 821761), ('\u{1079d}', 822017), ('\u{1079e}', 822273), ('\u{1079f}', 822529),
+        ('\u{107a0}', 822785), ('\u{107a1}', 823041), ('\u{107a2}', 823297), ('\u{107a3}', 823553),
+        ('\u{107a4}', 823809), ('\u{107a5}', 172289), ('\u{107a6}', 824065), ('\u{107a7}', 824321),
+        ('\u{107a8}', 824577), ('\u{107a9}', 824833), ('\u{107aa}', 825089), ('\u{107ab}', 825345),
+        ('\u{107ac}', 825601), ('\u{107ad}', 825857), ('\u{107ae}', 826113), ('\u{107af}', 826369),
+        ('\u{107b0}', 826625), ('\u{107b2}', 826881), ('\u{107b3}', 827137), ('\u{107b4}', 827393),
+        ('\u{107b5}', 827649), ('\u{107b6}', 827905), ('\u{107b7}', 828161), ('\u{107b8}', 828417),
+        ('\u{107b9}', 828673), ('\u{107ba}', 828929), ('\u{1d400}', 34049), ('\u{1d401}', 34561),
+        ('\u{1d402}', 78081), ('\u{1d403}', 34817), ('\u{1d404}', 35073), ('\u{1d405}', 84225),
+        ('\u{1d406}', 35585), ('\u{1d407}', 35841), ('\u{1d408}', 36097), ('\u{1d409}', 36353),
+        ('\u{1d40a}', 36609), ('\u{1d40b}', 36865), ('\u{1d40c}', 37121), ('\u{1d40d}', 37377),
+        ('\u{1d40e}', 37633), ('\u{1d40f}', 38145), ('\u{1d410}', 81921), ('\u{1d411}', 38401),
+        ('\u{1d412}', 171777), ('\u{1d413}', 38657), ('\u{1d414}', 38913), ('\u{1d415}', 101121),
+        ('\u{1d416}', 39169), ('\u{1d417}', 104193), ('\u{1d418}', 172033), ('\u{1d419}', 83969),
+        ('\u{1d41a}', 769), ('\u{1d41b}', 40193), ('\u{1d41c}', 46849), ('\u{1d41d}', 40449),
+        ('\u{1d41e}', 40705), ('\u{1d41f}', 47617), ('\u{1d420}', 41729), ('\u{1d421}', 15873),
+        ('\u{1d422}', 46081), ('\u{1d423}', 16385), ('\u{1d424}', 41985), ('\u{1d425}', 21505),
+        ('\u{1d426}', 42241), ('\u{1d427}', 75777), ('\u{1d428}', 3585), ('\u{1d429}', 43521),
+        ('\u{1d42a}', 172289), ('\u{1d42b}', 16641), ('\u{1d42c}', 8705), ('\u{1d42d}', 43777),
+        ('\u{1d42e}', 44033), ('\u{1d42f}', 44801), ('\u{1d430}', 17665), ('\u{1d431}', 21761),
+        ('\u{1d432}', 17921), ('\u{1d433}', 54529), ('\u{1d434}', 34049), ('\u{1d435}', 34561),
+        ('\u{1d436}', 78081), ('\u{1d437}', 34817), ('\u{1d438}', 35073), ('\u{1d439}', 84225),
+        ('\u{1d43a}', 35585), ('\u{1d43b}', 35841), ('\u{1d43c}', 36097), ('\u{1d43d}', 36353),
+        ('\u{1d43e}', 36609), ('\u{1d43f}', 36865), ('\u{1d440}', 37121), ('\u{1d441}', 37377),
+        ('\u{1d442}', 37633), ('\u{1d443}', 38145), ('\u{1d444}', 81921), ('\u{1d445}', 38401),
+        ('\u{1d446}', 171777), ('\u{1d447}', 38657), ('\u{1d448}', 38913), ('\u{1d449}', 101121),
+        ('\u{1d44a}', 39169), ('\u{1d44b}', 104193), ('\u{1d44c}', 172033), ('\u{1d44d}', 83969),
+        ('\u{1d44e}', 769), ('\u{1d44f}', 40193), ('\u{1d450}', 46849), ('\u{1d451}', 40449),
+        ('\u{1d452}', 40705), ('\u{1d453}', 47617), ('\u{1d454}', 41729), ('\u{1d456}', 46081),
+        ('\u{1d457}', 16385), ('\u{1d458}', 41985), ('\u{1d459}', 21505), ('\u{1d45a}', 42241),
+        ('\u{1d45b}', 75777), ('\u{1d45c}', 3585), ('\u{1d45d}', 43521), ('\u{1d45e}', 172289),
+        ('\u{1d45f}', 16641), ('\u{1d460}', 8705), ('\u{1d461}', 43777), ('\u{1d462}', 44033),
+        ('\u{1d463}', 44801), ('\u{1d464}', 17665), ('\u{1d465}', 21761), ('\u{1d466}', 17921),
+        ('\u{1d467}', 54529), ('\u{1d468}', 34049), ('\u{1d469}', 34561), ('\u{1d46a}', 78081),
+        ('\u{1d46b}', 34817), ('\u{1d46c}', 35073), ('\u{1d46d}', 84225), ('\u{1d46e}', 35585),
+        ('\u{1d46f}', 35841), ('\u{1d470}', 36097), ('\u{1d471}', 36353), ('\u{1d472}', 36609),
+        ('\u{1d473}', 36865), ('\u{1d474}', 37121), ('\u{1d475}', 37377), ('\u{1d476}', 37633),
+        ('\u{1d477}', 38145), ('\u{1d478}', 81921), ('\u{1d479}', 38401), ('\u{1d47a}', 171777),
+        ('\u{1d47b}', 38657), ('\u{1d47c}', 38913), ('\u{1d47d}', 101121), ('\u{1d47e}', 39169),
+        ('\u{1d47f}', 104193), ('\u{1d480}', 172033), ('\u{1d481}', 83969), ('\u{1d482}', 769),
+        ('\u{1d483}', 40193), ('\u{1d484}', 46849), ('\u{1d485}', 40449), ('\u{1d486}', 40705),
+        ('\u{1d487}', 47617), ('\u{1d488}', 41729), ('\u{1d489}', 15873), ('\u{1d48a}', 46081),
+        ('\u{1d48b}', 16385), ('\u{1d48c}', 41985), ('\u{1d48d}', 21505), ('\u{1d48e}', 42241),
+        ('\u{1d48f}', 75777), ('\u{1d490}', 3585), ('\u{1d491}', 43521), ('\u{1d492}', 172289),
+        ('\u{1d493}', 16641), ('\u{1d494}', 8705), ('\u{1d495}', 43777), ('\u{1d496}', 44033),
+        ('\u{1d497}', 44801), ('\u{1d498}', 17665), ('\u{1d499}', 21761), ('\u{1d49a}', 17921),
+        ('\u{1d49b}', 54529), ('\u{1d49c}', 34049), ('\u{1d49e}', 78081), ('\u{1d49f}', 34817),
+        ('\u{1d4a2}', 35585), ('\u{1d4a5}', 36353), ('\u{1d4a6}', 36609), ('\u{1d4a9}', 37377),
+        ('\u{1d4aa}', 37633), ('\u{1d4ab}', 38145), ('\u{1d4ac}', 81921), ('\u{1d4ae}', 171777),
+        ('\u{1d4af}', 38657), ('\u{1d4b0}', 38913), ('\u{1d4b1}', 101121), ('\u{1d4b2}', 39169),
+        ('\u{1d4b3}', 104193), ('\u{1d4b4}', 172033), ('\u{1d4b5}', 83969), ('\u{1d4b6}', 769),
+        ('\u{1d4b7}', 40193), ('\u{1d4b8}', 46849), ('\u{1d4b9}', 40449), ('\u{1d4bb}', 47617),
+        ('\u{1d4bd}', 15873), ('\u{1d4be}', 46081), ('\u{1d4bf}', 16385), ('\u{1d4c0}', 41985),
+        ('\u{1d4c1}', 21505), ('\u{1d4c2}', 42241), ('\u{1d4c3}', 75777), ('\u{1d4c5}', 43521),
+        ('\u{1d4c6}', 172289), ('\u{1d4c7}', 16641), ('\u{1d4c8}', 8705), ('\u{1d4c9}', 43777),
+        ('\u{1d4ca}', 44033), ('\u{1d4cb}', 44801), ('\u{1d4cc}', 17665), ('\u{1d4cd}', 21761),
+        ('\u{1d4ce}', 17921), ('\u{1d4cf}', 54529), ('\u{1d4d0}', 34049), ('\u{1d4d1}', 34561),
+        ('\u{1d4d2}', 78081), ('\u{1d4d3}', 34817), ('\u{1d4d4}', 35073), ('\u{1d4d5}', 84225),
+        ('\u{1d4d6}', 35585), ('\u{1d4d7}', 35841), ('\u{1d4d8}', 36097), ('\u{1d4d9}', 36353),
+        ('\u{1d4da}', 36609), ('\u{1d4db}', 36865), ('\u{1d4dc}', 37121), ('\u{1d4dd}', 37377),
+        ('\u{1d4de}', 37633), ('\u{1d4df}', 38145), ('\u{1d4e0}', 81921), ('\u{1d4e1}', 38401),
+        ('\u{1d4e2}', 171777), ('\u{1d4e3}', 38657), ('\u{1d4e4}', 38913), ('\u{1d4e5}', 101121),
+        ('\u{1d4e6}', 39169), ('\u{1d4e7}', 104193), ('\u{1d4e8}', 172033), ('\u{1d4e9}', 83969),
+        ('\u{1d4ea}', 769), ('\u{1d4eb}', 40193), ('\u{1d4ec}', 46849), ('\u{1d4ed}', 40449),
+        ('\u{1d4ee}', 40705), ('\u{1d4ef}', 47617), ('\u{1d4f0}', 41729), ('\u{1d4f1}', 15873),
+        ('\u{1d4f2}', 46081), ('\u{1d4f3}', 16385), ('\u{1d4f4}', 41985), ('\u{1d4f5}', 21505),
+        ('\u{1d4f6}', 42241), ('\u{1d4f7}', 75777), ('\u{1d4f8}', 3585), ('\u{1d4f9}', 43521),
+        ('\u{1d4fa}', 172289), ('\u{1d4fb}', 16641), ('\u{1d4fc}', 8705), ('\u{1d4fd}', 43777),
+        ('\u{1d4fe}', 44033), ('\u{1d4ff}', 44801), ('\u{1d500}', 17665), ('\u{1d501}', 21761),
+        ('\u{1d502}', 17921), ('\u{1d503}', 54529), ('\u{1d504}', 34049), ('\u{1d505}', 34561),
+        ('\u{1d507}', 34817), ('\u{1d508}', 35073), ('\u{1d509}', 84225), ('\u{1d50a}', 35585),
+        ('\u{1d50d}', 36353), ('\u{1d50e}', 36609), ('\u{1d50f}', 36865), ('\u{1d510}', 37121),
+        ('\u{1d511}', 37377), ('\u{1d512}', 37633), ('\u{1d513}', 38145), ('\u{1d514}', 81921),
+        ('\u{1d516}', 171777), ('\u{1d517}', 38657), ('\u{1d518}', 38913), ('\u{1d519}', 101121),
+        ('\u{1d51a}', 39169), ('\u{1d51b}', 104193), ('\u{1d51c}', 172033), ('\u{1d51e}', 769),
+        ('\u{1d51f}', 40193), ('\u{1d520}', 46849), ('\u{1d521}', 40449), ('\u{1d522}', 40705),
+        ('\u{1d523}', 47617), ('\u{1d524}', 41729), ('\u{1d525}', 15873), ('\u{1d526}', 46081),
+        ('\u{1d527}', 16385), ('\u{1d528}', 41985), ('\u{1d529}', 21505), ('\u{1d52a}', 42241),
+        ('\u{1d52b}', 75777), ('\u{1d52c}', 3585), ('\u{1d52d}', 43521), ('\u{1d52e}', 172289),
+        ('\u{1d52f}', 16641), ('\u{1d530}', 8705), ('\u{1d531}', 43777), ('\u{1d532}', 44033),
+        ('\u{1d533}', 44801), ('\u{1d534}', 17665), ('\u{1d535}', 21761), ('\u{1d536}', 17921),
+        ('\u{1d537}', 54529), ('\u{1d538}', 34049), ('\u{1d539}', 34561), ('\u{1d53b}', 34817),
+        ('\u{1d53c}', 35073), ('\u{1d53d}', 84225), ('\u{1d53e}', 35585), ('\u{1d540}', 36097),
+        ('\u{1d541}', 36353), ('\u{1d542}', 36609), ('\u{1d543}', 36865), ('\u{1d544}', 37121),
+        ('\u{1d546}', 37633), ('\u{1d54a}', 171777), ('\u{1d54b}', 38657), ('\u{1d54c}', 38913),
+        ('\u{1d54d}', 101121), ('\u{1d54e}', 39169), ('\u{1d54f}', 104193), ('\u{1d550}', 172033),
+        ('\u{1d552}', 769), ('\u{1d553}', 40193), ('\u{1d554}', 46849), ('\u{1d555}', 40449),
+        ('\u{1d556}', 40705), ('\u{1d557}', 47617), ('\u{1d558}', 41729), ('\u{1d559}', 15873),
+        ('\u{1d55a}', 46081), ('\u{1d55b}', 16385), ('\u{1d55c}', 41985), ('\u{1d55d}', 21505),
+        ('\u{1d55e}', 42241), ('\u{1d55f}', 75777), ('\u{1d560}', 3585), ('\u{1d561}', 43521),
+        ('\u{1d562}', 172289), ('\u{1d563}', 16641), ('\u{1d564}', 8705), ('\u{1d565}', 43777),
+        ('\u{1d566}', 44033), ('\u{1d567}', 44801), ('\u{1d568}', 17665), ('\u{1d569}', 21761),
+        ('\u{1d56a}', 17921), ('\u{1d56b}', 54529), ('\u{1d56c}', 34049), ('\u{1d56d}', 34561),
+        ('\u{1d56e}', 78081), ('\u{1d56f}', 34817), ('\u{1d570}', 35073), ('\u{1d571}', 84225),
+        ('\u{1d572}', 35585), ('\u{1d573}', 35841), ('\u{1d574}', 36097), ('\u{1d575}', 36353),
+        ('\u{1d576}', 36609), ('\u{1d577}', 36865), ('\u{1d578}', 37121), ('\u{1d579}', 37377),
+        ('\u{1d57a}', 37633), ('\u{1d57b}', 38145), ('\u{1d57c}', 81921), ('\u{1d57d}', 38401),
+        ('\u{1d57e}', 171777), ('\u{1d57f}', 38657), ('\u{1d580}', 38913), ('\u{1d581}', 101121),
+        ('\u{1d582}', 39169), ('\u{1d583}', 104193), ('\u{1d584}', 172033), ('\u{1d585}', 83969),
+        ('\u{1d586}', 769), ('\u{1d587}', 40193), ('\u{1d588}', 46849), ('\u{1d589}', 40449),
+        ('\u{1d58a}', 40705), ('\u{1d58b}', 47617), ('\u{1d58c}', 41729), ('\u{1d58d}', 15873),
+        ('\u{1d58e}', 46081), ('\u{1d58f}', 16385), ('\u{1d590}', 41985), ('\u{1d591}', 21505),
+        ('\u{1d592}', 42241), ('\u{1d593}', 75777), ('\u{1d594}', 3585), ('\u{1d595}', 43521),
+        ('\u{1d596}', 172289), ('\u{1d597}', 16641), ('\u{1d598}', 8705), ('\u{1d599}', 43777),
+        ('\u{1d59a}', 44033), ('\u{1d59b}', 44801), ('\u{1d59c}', 17665), ('\u{1d59d}', 21761),
+        ('\u{1d59e}', 17921), ('\u{1d59f}', 54529), ('\u{1d5a0}', 34049), ('\u{1d5a1}', 34561),
+        ('\u{1d5a2}', 78081), ('\u{1d5a3}', 34817), ('\u{1d5a4}', 35073), ('\u{1d5a5}', 84225),
+        ('\u{1d5a6}', 35585), ('\u{1d5a7}', 35841), ('\u{1d5a8}', 36097), ('\u{1d5a9}', 36353),
+        ('\u{1d5aa}', 36609), ('\u{1d5ab}', 36865), ('\u{1d5ac}', 37121), ('\u{1d5ad}', 37377),
+        ('\u{1d5ae}', 37633), ('\u{1d5af}', 38145), ('\u{1d5b0}', 81921), ('\u{1d5b1}', 38401),
+        ('\u{1d5b2}', 171777), ('\u{1d5b3}', 38657), ('\u{1d5b4}', 38913), ('\u{1d5b5}', 101121),
+        ('\u{1d5b6}', 39169), ('\u{1d5b7}', 104193), ('\u{1d5b8}', 172033), ('\u{1d5b9}', 83969),
+        ('\u{1d5ba}', 769), ('\u{1d5bb}', 40193), ('\u{1d5bc}', 46849), ('\u{1d5bd}', 40449),
+        ('\u{1d5be}', 40705), ('\u{1d5bf}', 47617), ('\u{1d5c0}', 41729), ('\u{1d5c1}', 15873),
+        ('\u{1d5c2}', 46081), ('\u{1d5c3}', 16385), ('\u{1d5c4}', 41985), ('\u{1d5c5}', 21505),
+        ('\u{1d5c6}', 42241), ('\u{1d5c7}', 75777), ('\u{1d5c8}', 3585), ('\u{1d5c9}', 43521),
+        ('\u{1d5ca}', 172289), ('\u{1d5cb}', 16641), ('\u{1d5cc}', 8705), ('\u{1d5cd}', 43777),
+        ('\u{1d5ce}', 44033), ('\u{1d5cf}', 44801), ('\u{1d5d0}', 17665), ('\u{1d5d1}', 21761),
+        ('\u{1d5d2}', 17921), ('\u{1d5d3}', 54529), ('\u{1d5d4}', 34049), ('\u{1d5d5}', 34561),
+        ('\u{1d5d6}', 78081), ('\u{1d5d7}', 34817), ('\u{1d5d8}', 35073), ('\u{1d5d9}', 84225),
+        ('\u{1d5da}', 35585), ('\u{1d5db}', 35841), ('\u{1d5dc}', 36097), ('\u{1d5dd}', 36353),
+        ('\u{1d5de}', 36609), ('\u{1d5df}', 36865), ('\u{1d5e0}', 37121), ('\u{1d5e1}', 37377),
+        ('\u{1d5e2}', 37633), ('\u{1d5e3}', 38145), ('\u{1d5e4}', 81921), ('\u{1d5e5}', 38401),
+        ('\u{1d5e6}', 171777), ('\u{1d5e7}', 38657), ('\u{1d5e8}', 38913), ('\u{1d5e9}', 101121),
+        ('\u{1d5ea}', 39169), ('\u{1d5eb}', 104193), ('\u{1d5ec}', 172033), ('\u{1d5ed}', 83969),
+        ('\u{1d5ee}', 769), ('\u{1d5ef}', 40193), ('\u{1d5f0}', 46849), ('\u{1d5f1}', 40449),
+        ('\u{1d5f2}', 40705), ('\u{1d5f3}', 47617), ('\u{1d5f4}', 41729), ('\u{1d5f5}', 15873),
+        ('\u{1d5f6}', 46081), ('\u{1d5f7}', 16385), ('\u{1d5f8}', 41985), ('\u{1d5f9}', 21505),
+        ('\u{1d5fa}', 42241), ('\u{1d5fb}', 75777), ('\u{1d5fc}', 3585), ('\u{1d5fd}', 43521),
+        ('\u{1d5fe}', 172289), ('\u{1d5ff}', 16641), ('\u{1d600}', 8705), ('\u{1d601}', 43777),
+        ('\u{1d602}', 44033), ('\u{1d603}', 44801), ('\u{1d604}', 17665), ('\u{1d605}', 21761),
+        ('\u{1d606}', 17921), ('\u{1d607}', 54529), ('\u{1d608}', 34049), ('\u{1d609}', 34561),
+        ('\u{1d60a}', 78081), ('\u{1d60b}', 34817), ('\u{1d60c}', 35073), ('\u{1d60d}', 84225),
+        ('\u{1d60e}', 35585), ('\u{1d60f}', 35841), ('\u{1d610}', 36097), ('\u{1d611}', 36353),
+        ('\u{1d612}', 36609), ('\u{1d613}', 36865), ('\u{1d614}', 37121), ('\u{1d615}', 37377),
+        ('\u{1d616}', 37633), ('\u{1d617}', 38145), ('\u{1d618}', 81921), ('\u{1d619}', 38401),
+        ('\u{1d61a}', 171777), ('\u{1d61b}', 38657), ('\u{1d61c}', 38913), ('\u{1d61d}', 101121),
+        ('\u{1d61e}', 39169), ('\u{1d61f}', 104193), ('\u{1d620}', 172033), ('\u{1d621}', 83969),
+        ('\u{1d622}', 769), ('\u{1d623}', 40193), ('\u{1d624}', 46849), ('\u{1d625}', 40449),
+        ('\u{1d626}', 40705), ('\u{1d627}', 47617), ('\u{1d628}', 41729), ('\u{1d629}', 15873),
+        ('\u{1d62a}', 46081), ('\u{1d62b}', 16385), ('\u{1d62c}', 41985), ('\u{1d62d}', 21505),
+        ('\u{1d62e}', 42241), ('\u{1d62f}', 75777), ('\u{1d630}', 3585), ('\u{1d631}', 43521),
+        ('\u{1d632}', 172289), ('\u{1d633}', 16641), ('\u{1d634}', 8705), ('\u{1d635}', 43777),
+        ('\u{1d636}', 44033), ('\u{1d637}', 44801), ('\u{1d638}', 17665), ('\u{1d639}', 21761),
+        ('\u{1d63a}', 17921), ('\u{1d63b}', 54529), ('\u{1d63c}', 34049), ('\u{1d63d}', 34561),
+        ('\u{1d63e}', 78081), ('\u{1d63f}', 34817), ('\u{1d640}', 35073), ('\u{1d641}', 84225),
+        ('\u{1d642}', 35585), ('\u{1d643}', 35841), ('\u{1d644}', 36097), ('\u{1d645}', 36353),
+        ('\u{1d646}', 36609), ('\u{1d647}', 36865), ('\u{1d648}', 37121), ('\u{1d649}', 37377),
+        ('\u{1d64a}', 37633), ('\u{1d64b}', 38145), ('\u{1d64c}', 81921), ('\u{1d64d}', 38401),
+        ('\u{1d64e}', 171777), ('\u{1d64f}', 38657), ('\u{1d650}', 38913), ('\u{1d651}', 101121),
+        ('\u{1d652}', 39169), ('\u{1d653}', 104193), ('\u{1d654}', 172033), ('\u{1d655}', 83969),
+        ('\u{1d656}', 769), ('\u{1d657}', 40193), ('\u{1d658}', 46849), ('\u{1d659}', 40449),
+        ('\u{1d65a}', 40705), ('\u{1d65b}', 47617), ('\u{1d65c}', 41729), ('\u{1d65d}', 15873),
+        ('\u{1d65e}', 46081), ('\u{1d65f}', 16385), ('\u{1d660}', 41985), ('\u{1d661}', 21505),
+        ('\u{1d662}', 42241), ('\u{1d663}', 75777), ('\u{1d664}', 3585), ('\u{1d665}', 43521),
+        ('\u{1d666}', 172289), ('\u{1d667}', 16641), ('\u{1d668}', 8705), ('\u{1d669}', 43777),
+        ('\u{1d66a}', 44033), ('\u{1d66b}', 44801), ('\u{1d66c}', 17665), ('\u{1d66d}', 21761),
+        ('\u{1d66e}', 17921), ('\u{1d66f}', 54529), ('\u{1d670}', 34049), ('\u{1d671}', 34561),
+        ('\u{1d672}', 78081), ('\u{1d673}', 34817), ('\u{1d674}', 35073), ('\u{1d675}', 84225),
+        ('\u{1d676}', 35585), ('\u{1d677}', 35841), ('\u{1d678}', 36097), ('\u{1d679}', 36353),
+        ('\u{1d67a}', 36609), ('\u{1d67b}', 36865), ('\u{1d67c}', 37121), ('\u{1d67d}', 37377),
+        ('\u{1d67e}', 37633), ('\u{1d67f}', 38145), ('\u{1d680}', 81921), ('\u{1d681}', 38401),
+        ('\u{1d682}', 171777), ('\u{1d683}', 38657), ('\u{1d684}', 38913), ('\u{1d685}', 101121),
+        ('\u{1d686}', 39169), ('\u{1d687}', 104193), ('\u{1d688}', 172033), ('\u{1d689}', 83969),
+        ('\u{1d68a}', 769), ('\u{1d68b}', 40193), ('\u{1d68c}', 46849), ('\u{1d68d}', 40449),
+        ('\u{1d68e}', 40705), ('\u{1d68f}', 47617), ('\u{1d690}', 41729), ('\u{1d691}', 15873),
+        ('\u{1d692}', 46081), ('\u{1d693}', 16385), ('\u{1d694}', 41985), ('\u{1d695}', 21505),
+        ('\u{1d696}', 42241), ('\u{1d697}', 75777), ('\u{1d698}', 3585), ('\u{1d699}', 43521),
+        ('\u{1d69a}', 172289), ('\u{1d69b}', 16641), ('\u{1d69c}', 8705), ('\u{1d69d}', 43777),
+        ('\u{1d69e}', 44033), ('\u{1d69f}', 44801), ('\u{1d6a0}', 17665), ('\u{1d6a1}', 21761),
+        ('\u{1d6a2}', 17921), ('\u{1d6a3}', 54529), ('\u{1d6a4}', 829185), ('\u{1d6a5}', 829441),
+        ('\u{1d6a8}', 829697), ('\u{1d6a9}', 829953), ('\u{1d6aa}', 86273), ('\u{1d6ab}', 830209),
+        ('\u{1d6ac}', 830465), ('\u{1d6ad}', 830721), ('\u{1d6ae}', 830977), ('\u{1d6af}', 26625),
+        ('\u{1d6b0}', 831233), ('\u{1d6b1}', 831489), ('\u{1d6b2}', 831745), ('\u{1d6b3}', 832001),
+        ('\u{1d6b4}', 832257), ('\u{1d6b5}', 832513), ('\u{1d6b6}', 832769), ('\u{1d6b7}', 86529),
+        ('\u{1d6b8}', 833025), ('\u{1d6b9}', 26625), ('\u{1d6ba}', 27137), ('\u{1d6bb}', 833281),
+        ('\u{1d6bc}', 24065), ('\u{1d6bd}', 833537), ('\u{1d6be}', 833793), ('\u{1d6bf}', 834049),
+        ('\u{1d6c0}', 834305), ('\u{1d6c1}', 834561), ('\u{1d6c2}', 834817), ('\u{1d6c3}', 23553),
+        ('\u{1d6c4}', 45313), ('\u{1d6c5}', 45569), ('\u{1d6c6}', 26881), ('\u{1d6c7}', 835073),
+        ('\u{1d6c8}', 835329), ('\u{1d6c9}', 23809), ('\u{1d6ca}', 835585), ('\u{1d6cb}', 25857),
+        ('\u{1d6cc}', 835841), ('\u{1d6cd}', 2561), ('\u{1d6ce}', 836097), ('\u{1d6cf}', 836353),
+        ('\u{1d6d0}', 836609), ('\u{1d6d1}', 25601), ('\u{1d6d2}', 26113), ('\u{1d6d3}', 26369),
+        ('\u{1d6d4}', 836865), ('\u{1d6d5}', 837121), ('\u{1d6d6}', 837377), ('\u{1d6d7}', 25345),
+        ('\u{1d6d8}', 45825), ('\u{1d6d9}', 837633), ('\u{1d6da}', 837889), ('\u{1d6db}', 838145),
+        ('\u{1d6dc}', 26881), ('\u{1d6dd}', 23809), ('\u{1d6de}', 25857), ('\u{1d6df}', 25345),
+        ('\u{1d6e0}', 26113), ('\u{1d6e1}', 25601), ('\u{1d6e2}', 829697), ('\u{1d6e3}', 829953),
+        ('\u{1d6e4}', 86273), ('\u{1d6e5}', 830209), ('\u{1d6e6}', 830465), ('\u{1d6e7}', 830721),
+        ('\u{1d6e8}', 830977), ('\u{1d6e9}', 26625), ('\u{1d6ea}', 831233), ('\u{1d6eb}', 831489),
+        ('\u{1d6ec}', 831745), ('\u{1d6ed}', 832001), ('\u{1d6ee}', 832257), ('\u{1d6ef}', 832513),
+        ('\u{1d6f0}', 832769), ('\u{1d6f1}', 86529), ('\u{1d6f2}', 833025), ('\u{1d6f3}', 26625),
+        ('\u{1d6f4}', 27137), ('\u{1d6f5}', 833281), ('\u{1d6f6}', 24065), ('\u{1d6f7}', 833537),
+        ('\u{1d6f8}', 833793), ('\u{1d6f9}', 834049), ('\u{1d6fa}', 834305), ('\u{1d6fb}', 834561),
+        ('\u{1d6fc}', 834817), ('\u{1d6fd}', 23553), ('\u{1d6fe}', 45313), ('\u{1d6ff}', 45569),
+        ('\u{1d700}', 26881), ('\u{1d701}', 835073), ('\u{1d702}', 835329), ('\u{1d703}', 23809),
+        ('\u{1d704}', 835585), ('\u{1d705}', 25857), ('\u{1d706}', 835841), ('\u{1d707}', 2561),
+        ('\u{1d708}', 836097), ('\u{1d709}', 836353), ('\u{1d70a}', 836609), ('\u{1d70b}', 25601),
+        ('\u{1d70c}', 26113), ('\u{1d70d}', 26369), ('\u{1d70e}', 836865), ('\u{1d70f}', 837121),
+        ('\u{1d710}', 837377), ('\u{1d711}', 25345), ('\u{1d712}', 45825), ('\u{1d713}', 837633),
+        ('\u{1d714}', 837889), ('\u{1d715}', 838145), ('\u{1d716}', 26881), ('\u{1d717}', 23809),
+        ('\u{1d718}', 25857), ('\u{1d719}', 25345), ('\u{1d71a}', 26113), ('\u{1d71b}', 25601),
+        ('\u{1d71c}', 829697), ('\u{1d71d}', 829953), ('\u{1d71e}', 86273), ('\u{1d71f}', 830209),
+        ('\u{1d720}', 830465), ('\u{1d721}', 830721), ('\u{1d722}', 830977), ('\u{1d723}', 26625),
+        ('\u{1d724}', 831233), ('\u{1d725}', 831489), ('\u{1d726}', 831745), ('\u{1d727}', 832001),
+        ('\u{1d728}', 832257), ('\u{1d729}', 832513), ('\u{1d72a}', 832769), ('\u{1d72b}', 86529),
+        ('\u{1d72c}', 833025), ('\u{1d72d}', 26625), ('\u{1d72e}', 27137), ('\u{1d72f}', 833281),
+        ('\u{1d730}', 24065), ('\u{1d731}', 833537), ('\u{1d732}', 833793), ('\u{1d733}', 834049),
+        ('\u{1d734}', 834305), ('\u{1d735}', 834561), ('\u{1d736}', 834817), ('\u{1d737}', 23553),
+        ('\u{1d738}', 45313), ('\u{1d739}', 45569), ('\u{1d73a}', 26881), ('\u{1d73b}', 835073),
+        ('\u{1d73c}', 835329), ('\u{1d73d}', 23809), ('\u{1d73e}', 835585), ('\u{1d73f}', 25857),
+        ('\u{1d740}', 835841), ('\u{1d741}', 2561), ('\u{1d742}', 836097), ('\u{1d743}', 836353),
+        ('\u{1d744}', 836609), ('\u{1d745}', 25601), ('\u{1d746}', 26113), ('\u{1d747}', 26369),
+        ('\u{1d748}', 836865), ('\u{1d749}', 837121), ('\u{1d74a}', 837377), ('\u{1d74b}', 25345),
+        ('\u{1d74c}', 45825), ('\u{1d74d}', 837633), ('\u{1d74e}', 837889), ('\u{1d74f}', 838145),
+        ('\u{1d750}', 26881), ('\u{1d751}', 23809), ('\u{1d752}', 25857), ('\u{1d753}', 25345),
+        ('\u{1d754}', 26113), ('\u{1d755}', 25601), ('\u{1d756}', 829697), ('\u{1d757}', 829953),
+        ('\u{1d758}', 86273), ('\u{1d759}', 830209), ('\u{1d75a}', 830465), ('\u{1d75b}', 830721),
+        ('\u{1d75c}', 830977), ('\u{1d75d}', 26625), ('\u{1d75e}', 831233), ('\u{1d75f}', 831489),
+        ('\u{1d760}', 831745), ('\u{1d761}', 832001), ('\u{1d762}', 832257), ('\u{1d763}', 832513),
+        ('\u{1d764}', 832769), ('\u{1d765}', 86529), ('\u{1d766}', 833025), ('\u{1d767}', 26625),
+        ('\u{1d768}', 27137), ('\u{1d769}', 833281), ('\u{1d76a}', 24065), ('\u{1d76b}', 833537),
+        ('\u{1d76c}', 833793), ('\u{1d76d}', 834049), ('\u{1d76e}', 834305), ('\u{1d76f}', 834561),
+        ('\u{1d770}', 834817), ('\u{1d771}', 23553), ('\u{1d772}', 45313), ('\u{1d773}', 45569),
+        ('\u{1d774}', 26881), ('\u{1d775}', 835073), ('\u{1d776}', 835329), ('\u{1d777}', 23809),
+        ('\u{1d778}', 835585), ('\u{1d779}', 25857), ('\u{1d77a}', 835841), ('\u{1d77b}', 2561),
+        ('\u{1d77c}', 836097), ('\u{1d77d}', 836353), ('\u{1d77e}', 836609), ('\u{1d77f}', 25601),
+        ('\u{1d780}', 26113), ('\u{1d781}', 26369), ('\u{1d782}', 836865), ('\u{1d783}', 837121),
+        ('\u{1d784}', 837377), ('\u{1d785}', 25345), ('\u{1d786}', 45825), ('\u{1d787}', 837633),
+        ('\u{1d788}', 837889), ('\u{1d789}', 838145), ('\u{1d78a}', 26881), ('\u{1d78b}', 23809),
+        ('\u{1d78c}', 25857), ('\u{1d78d}', 25345), ('\u{1d78e}', 26113), ('\u{1d78f}', 25601),
+        ('\u{1d790}', 829697), ('\u{1d791}', 829953), ('\u{1d792}', 86273), ('\u{1d793}', 830209),
+        ('\u{1d794}', 830465), ('\u{1d795}', 830721), ('\u{1d796}', 830977), ('\u{1d797}', 26625),
+        ('\u{1d798}', 831233), ('\u{1d799}', 831489), ('\u{1d79a}', 831745), ('\u{1d79b}', 832001),
+        ('\u{1d79c}', 832257), ('\u{1d79d}', 832513), ('\u{1d79e}', 832769), ('\u{1d79f}', 86529),
+        ('\u{1d7a0}', 833025), ('\u{1d7a1}', 26625), ('\u{1d7a2}', 27137), ('\u{1d7a3}', 833281),
+        ('\u{1d7a4}', 24065), ('\u{1d7a5}', 833537), ('\u{1d7a6}', 833793), ('\u{1d7a7}', 834049),
+        ('\u{1d7a8}', 834305), ('\u{1d7a9}', 834561), ('\u{1d7aa}', 834817), ('\u{1d7ab}', 23553),
+        ('\u{1d7ac}', 45313), ('\u{1d7ad}', 45569), ('\u{1d7ae}', 26881), ('\u{1d7af}', 835073),
+        ('\u{1d7b0}', 835329), ('\u{1d7b1}', 23809), ('\u{1d7b2}', 835585), ('\u{1d7b3}', 25857),
+        ('\u{1d7b4}', 835841), ('\u{1d7b5}', 2561), ('\u{1d7b6}', 836097), ('\u{1d7b7}', 836353),
+        ('\u{1d7b8}', 836609), ('\u{1d7b9}', 25601), ('\u{1d7ba}', 26113), ('\u{1d7bb}', 26369),
+        ('\u{1d7bc}', 836865), ('\u{1d7bd}', 837121), ('\u{1d7be}', 837377), ('\u{1d7bf}', 25345),
+        ('\u{1d7c0}', 45825), ('\u{1d7c1}', 837633), ('\u{1d7c2}', 837889), ('\u{1d7c3}', 838145),
+        ('\u{1d7c4}', 26881), ('\u{1d7c5}', 23809), ('\u{1d7c6}', 25857), ('\u{1d7c7}', 25345),
+        ('\u{1d7c8}', 26113), ('\u{1d7c9}', 25601), ('\u{1d7ca}', 838401), ('\u{1d7cb}', 838657),
+        ('\u{1d7ce}', 72705), ('\u{1d7cf}', 3329), ('\u{1d7d0}', 1537), ('\u{1d7d1}', 1793),
+        ('\u{1d7d2}', 72961), ('\u{1d7d3}', 73217), ('\u{1d7d4}', 73473), ('\u{1d7d5}', 73729),
+        ('\u{1d7d6}', 73985), ('\u{1d7d7}', 74241), ('\u{1d7d8}', 72705), ('\u{1d7d9}', 3329),
+        ('\u{1d7da}', 1537), ('\u{1d7db}', 1793), ('\u{1d7dc}', 72961), ('\u{1d7dd}', 73217),
+        ('\u{1d7de}', 73473), ('\u{1d7df}', 73729), ('\u{1d7e0}', 73985), ('\u{1d7e1}', 74241),
+        ('\u{1d7e2}', 72705), ('\u{1d7e3}', 3329), ('\u{1d7e4}', 1537), ('\u{1d7e5}', 1793),
+        ('\u{1d7e6}', 72961), ('\u{1d7e7}', 73217), ('\u{1d7e8}', 73473), ('\u{1d7e9}', 73729),
+        ('\u{1d7ea}', 73985), ('\u{1d7eb}', 74241), ('\u{1d7ec}', 72705), ('\u{1d7ed}', 3329),
+        ('\u{1d7ee}', 1537), ('\u{1d7ef}', 1793), ('\u{1d7f0}', 72961), ('\u{1d7f1}', 73217),
+        ('\u{1d7f2}', 73473), ('\u{1d7f3}', 73729), ('\u{1d7f4}', 73985), ('\u{1d7f5}', 74241),
+        ('\u{1d7f6}', 72705), ('\u{1d7f7}', 3329), ('\u{1d7f8}', 1537), ('\u{1d7f9}', 1793),
+        ('\u{1d7fa}', 72961), ('\u{1d7fb}', 73217), ('\u{1d7fc}', 73473), ('\u{1d7fd}', 73729),
+        ('\u{1d7fe}', 73985), ('\u{1d7ff}', 74241), ('\u{1e030}', 838913), ('\u{1e031}', 839169),
+        ('\u{1e032}', 839425), ('\u{1e033}', 839681), ('\u{1e034}', 839937), ('\u{1e035}', 840193),
+        ('\u{1e036}', 840449), ('\u{1e037}', 840705), ('\u{1e038}', 840961), ('\u{1e039}', 841217),
+        ('\u{1e03a}', 841473), ('\u{1e03b}', 841729), ('\u{1e03c}', 841985), ('\u{1e03d}', 842241),
+        ('\u{1e03e}', 842497), ('\u{1e03f}', 842753), ('\u{1e040}', 843009), ('\u{1e041}', 843265),
+        ('\u{1e042}', 843521), ('\u{1e043}', 843777), ('\u{1e044}', 844033), ('\u{1e045}', 844289),
+        ('\u{1e046}', 844545), ('\u{1e047}', 844801), ('\u{1e048}', 845057), ('\u{1e049}', 845313),
+        ('\u{1e04a}', 845569), ('\u{1e04b}', 845825), ('\u{1e04c}', 846081), ('\u{1e04d}', 846337),
+        ('\u{1e04e}', 846593), ('\u{1e04f}', 846849), ('\u{1e050}', 847105), ('\u{1e051}', 838913),
+        ('\u{1e052}', 839169), ('\u{1e053}', 839425), ('\u{1e054}', 839681), ('\u{1e055}', 839937),
+        ('\u{1e056}', 840193), ('\u{1e057}', 840449), ('\u{1e058}', 840705), ('\u{1e059}', 840961),
+        ('\u{1e05a}', 841217), ('\u{1e05b}', 841473), ('\u{1e05c}', 841985), ('\u{1e05d}', 842241),
+        ('\u{1e05e}', 842753), ('\u{1e05f}', 843265), ('\u{1e060}', 843521), ('\u{1e061}', 843777),
+        ('\u{1e062}', 844033), ('\u{1e063}', 844289), ('\u{1e064}', 844545), ('\u{1e065}', 571393),
+        ('\u{1e066}', 844801), ('\u{1e067}', 847361), ('\u{1e068}', 846081), ('\u{1e069}', 847617),
+        ('\u{1e06a}', 847873), ('\u{1e06b}', 848129), ('\u{1e06c}', 848385), ('\u{1e06d}', 848641),
+        ('\u{1ee00}', 795905), ('\u{1ee01}', 796161), ('\u{1ee02}', 797185), ('\u{1ee03}', 797953),
+        ('\u{1ee05}', 802817), ('\u{1ee06}', 798721), ('\u{1ee07}', 797441), ('\u{1ee08}', 800001),
+        ('\u{1ee09}', 803073), ('\u{1ee0a}', 801537), ('\u{1ee0b}', 801793), ('\u{1ee0c}', 802049),
+        ('\u{1ee0d}', 802305), ('\u{1ee0e}', 798977), ('\u{1ee0f}', 800513), ('\u{1ee10}', 801025),
+        ('\u{1ee11}', 799489), ('\u{1ee12}', 801281), ('\u{1ee13}', 798465), ('\u{1ee14}', 799233),
+        ('\u{1ee15}', 796673), ('\u{1ee16}', 796929), ('\u{1ee17}', 797697), ('\u{1ee18}', 798209),
+        ('\u{1ee19}', 799745), ('\u{1ee1a}', 800257), ('\u{1ee1b}', 800769), ('\u{1ee1c}', 848897),
+        ('\u{1ee1d}', 588289), ('\u{1ee1e}', 849153), ('\u{1ee1f}', 849409), ('\u{1ee21}', 796161),
+        ('\u{1ee22}', 797185), ('\u{1ee24}', 802561), ('\u{1ee27}', 797441), ('\u{1ee29}', 803073),
+        ('\u{1ee2a}', 801537), ('\u{1ee2b}', 801793), ('\u{1ee2c}', 802049), ('\u{1ee2d}', 802305),
+        ('\u{1ee2e}', 798977), ('\u{1ee2f}', 800513), ('\u{1ee30}', 801025), ('\u{1ee31}', 799489),
+        ('\u{1ee32}', 801281), ('\u{1ee34}', 799233), ('\u{1ee35}', 796673), ('\u{1ee36}', 796929),
+        ('\u{1ee37}', 797697), ('\u{1ee39}', 799745), ('\u{1ee3b}', 800769), ('\u{1ee42}', 797185),
+        ('\u{1ee47}', 797441), ('\u{1ee49}', 803073), ('\u{1ee4b}', 801793), ('\u{1ee4d}', 802305),
+        ('\u{1ee4e}', 798977), ('\u{1ee4f}', 800513), ('\u{1ee51}', 799489), ('\u{1ee52}', 801281),
+        ('\u{1ee54}', 799233), ('\u{1ee57}', 797697), ('\u{1ee59}', 799745), ('\u{1ee5b}', 800769),
+        ('\u{1ee5d}', 588289), ('\u{1ee5f}', 849409), ('\u{1ee61}', 796161), ('\u{1ee62}', 797185),
+        ('\u{1ee64}', 802561), ('\u{1ee67}', 797441), ('\u{1ee68}', 800001), ('\u{1ee69}', 803073),
+        ('\u{1ee6a}', 801537), ('\u{1ee6c}', 802049), ('\u{1ee6d}', 802305), ('\u{1ee6e}', 798977),
+        ('\u{1ee6f}', 800513), ('\u{1ee70}', 801025), ('\u{1ee71}', 799489), ('\u{1ee72}', 801281),
+        ('\u{1ee74}', 799233), ('\u{1ee75}', 796673), ('\u{1ee76}', 796929), ('\u{1ee77}', 797697),
+        ('\u{1ee79}', 799745), ('\u{1ee7a}', 800257), ('\u{1ee7b}', 800769), ('\u{1ee7c}', 848897),
+        ('\u{1ee7e}', 849153), ('\u{1ee80}', 795905), ('\u{1ee81}', 796161), ('\u{1ee82}', 797185),
+        ('\u{1ee83}', 797953), ('\u{1ee84}', 802561), ('\u{1ee85}', 802817), ('\u{1ee86}', 798721),
+        ('\u{1ee87}', 797441), ('\u{1ee88}', 800001), ('\u{1ee89}', 803073), ('\u{1ee8b}', 801793),
+        ('\u{1ee8c}', 802049), ('\u{1ee8d}', 802305), ('\u{1ee8e}', 798977), ('\u{1ee8f}', 800513),
+        ('\u{1ee90}', 801025), ('\u{1ee91}', 799489), ('\u{1ee92}', 801281), ('\u{1ee93}', 798465),
+        ('\u{1ee94}', 799233), ('\u{1ee95}', 796673), ('\u{1ee96}', 796929), ('\u{1ee97}', 797697),
+        ('\u{1ee98}', 798209), ('\u{1ee99}', 799745), ('\u{1ee9a}', 800257), ('\u{1ee9b}', 800769),
+        ('\u{1eea1}', 796161), ('\u{1eea2}', 797185), ('\u{1eea3}', 797953), ('\u{1eea5}', 802817),
+        ('\u{1eea6}', 798721), ('\u{1eea7}', 797441), ('\u{1eea8}', 800001), ('\u{1eea9}', 803073),
+        ('\u{1eeab}', 801793), ('\u{1eeac}', 802049), ('\u{1eead}', 802305), ('\u{1eeae}', 798977),
+        ('\u{1eeaf}', 800513), ('\u{1eeb0}', 801025), ('\u{1eeb1}', 799489), ('\u{1eeb2}', 801281),
+        ('\u{1eeb3}', 798465), ('\u{1eeb4}', 799233), ('\u{1eeb5}', 796673), ('\u{1eeb6}', 796929),
+        ('\u{1eeb7}', 797697), ('\u{1eeb8}', 798209), ('\u{1eeb9}', 799745), ('\u{1eeba}', 800257),
+        ('\u{1eebb}', 800769), ('\u{1f100}', 849666), ('\u{1f101}', 850178), ('\u{1f102}', 850690),
+        ('\u{1f103}', 851202), ('\u{1f104}', 851714), ('\u{1f105}', 852226), ('\u{1f106}', 852738),
+        ('\u{1f107}', 853250), ('\u{1f108}', 853762), ('\u{1f109}', 854274), ('\u{1f10a}', 854786),
+        ('\u{1f110}', 855299), ('\u{1f111}', 856067), ('\u{1f112}', 856835), ('\u{1f113}', 857603),
+        ('\u{1f114}', 858371), ('\u{1f115}', 859139), ('\u{1f116}', 859907), ('\u{1f117}', 860675),
+        ('\u{1f118}', 861443), ('\u{1f119}', 862211), ('\u{1f11a}', 862979), ('\u{1f11b}', 863747),
+        ('\u{1f11c}', 864515), ('\u{1f11d}', 865283), ('\u{1f11e}', 866051), ('\u{1f11f}', 866819),
+        ('\u{1f120}', 867587), ('\u{1f121}', 868355), ('\u{1f122}', 869123), ('\u{1f123}', 869891),
+        ('\u{1f124}', 870659), ('\u{1f125}', 871427), ('\u{1f126}', 872195), ('\u{1f127}', 872963),
+        ('\u{1f128}', 873731), ('\u{1f129}', 874499), ('\u{1f12a}', 875267), ('\u{1f12b}', 78081),
+        ('\u{1f12c}', 38401), ('\u{1f12d}', 876034), ('\u{1f12e}', 876546), ('\u{1f130}', 34049),
+        ('\u{1f131}', 34561), ('\u{1f132}', 78081), ('\u{1f133}', 34817), ('\u{1f134}', 35073),
+        ('\u{1f135}', 84225), ('\u{1f136}', 35585), ('\u{1f137}', 35841), ('\u{1f138}', 36097),
+        ('\u{1f139}', 36353), ('\u{1f13a}', 36609), ('\u{1f13b}', 36865), ('\u{1f13c}', 37121),
+        ('\u{1f13d}', 37377), ('\u{1f13e}', 37633), ('\u{1f13f}', 38145), ('\u{1f140}', 81921),
+        ('\u{1f141}', 38401), ('\u{1f142}', 171777), ('\u{1f143}', 38657), ('\u{1f144}', 38913),
+        ('\u{1f145}', 101121), ('\u{1f146}', 39169), ('\u{1f147}', 104193), ('\u{1f148}', 172033),
+        ('\u{1f149}', 83969), ('\u{1f14a}', 877058), ('\u{1f14b}', 525826), ('\u{1f14c}', 877570),
+        ('\u{1f14d}', 878082), ('\u{1f14e}', 878595), ('\u{1f14f}', 879362), ('\u{1f16a}', 879874),
+        ('\u{1f16b}', 880386), ('\u{1f16c}', 880898), ('\u{1f190}', 881410), ('\u{1f200}', 881922),
+        ('\u{1f201}', 882434), ('\u{1f202}', 364801), ('\u{1f210}', 192513), ('\u{1f211}', 882945),
+        ('\u{1f212}', 883201), ('\u{1f213}', 883458), ('\u{1f214}', 177921), ('\u{1f215}', 883969),
+        ('\u{1f216}', 884225), ('\u{1f217}', 260097), ('\u{1f218}', 884481), ('\u{1f219}', 884737),
+        ('\u{1f21a}', 884993), ('\u{1f21b}', 885249), ('\u{1f21c}', 885505), ('\u{1f21d}', 885761),
+        ('\u{1f21e}', 886017), ('\u{1f21f}', 886273), ('\u{1f220}', 886529), ('\u{1f221}', 886785),
+        ('\u{1f222}', 201729), ('\u{1f223}', 887041), ('\u{1f224}', 887297), ('\u{1f225}', 887553),
+        ('\u{1f226}', 887809), ('\u{1f227}', 888065), ('\u{1f228}', 888321), ('\u{1f229}', 176385),
+        ('\u{1f22a}', 258049), ('\u{1f22b}', 888577), ('\u{1f22c}', 342529), ('\u{1f22d}', 258817),
+        ('\u{1f22e}', 342785), ('\u{1f22f}', 888833), ('\u{1f230}', 216065), ('\u{1f231}', 889089),
+        ('\u{1f232}', 889345), ('\u{1f233}', 889601), ('\u{1f234}', 889857), ('\u{1f235}', 890113),
+        ('\u{1f236}', 338177), ('\u{1f237}', 195073), ('\u{1f238}', 890369), ('\u{1f239}', 890625),
+        ('\u{1f23a}', 890881), ('\u{1f23b}', 891137), ('\u{1f240}', 891395), ('\u{1f241}', 892163),
+        ('\u{1f242}', 892931), ('\u{1f243}', 893699), ('\u{1f244}', 894467), ('\u{1f245}', 895235),
+        ('\u{1f246}', 896003), ('\u{1f247}', 896771), ('\u{1f248}', 897539), ('\u{1f250}', 898305),
+        ('\u{1f251}', 898561), ('\u{1fbf0}', 72705), ('\u{1fbf1}', 3329), ('\u{1fbf2}', 1537),
+        ('\u{1fbf3}', 1793), ('\u{1fbf4}', 72961), ('\u{1fbf5}', 73217), ('\u{1fbf6}', 73473),
+        ('\u{1fbf7}', 73729), ('\u{1fbf8}', 73985), ('\u{1fbf9}', 74241),
+    ];
+
+    static COMPATIBILITY_CHARS: &[char] = &[
+        ' ', ' ', '\u{308}', 'a', ' ', '\u{304}', '2', '3', ' ', '\u{301}', '\u{3bc}', ' ',
+        '\u{327}', '1', 'o', '1', '\u{2044}', '4', '1', '\u{2044}', '2', '3', '\u{2044}', '4', 'I',
+        'J', 'i', 'j', 'L', '\u{b7}', 'l', '\u{b7}', '\u{2bc}', 'n', 's', 'D', 'Z', '\u{30c}', 'D',
+        'z', '\u{30c}', 'd', 'z', '\u{30c}', 'L', 'J', 'L', 'j', 'l', 'j', 'N', 'J', 'N', 'j', 'n',
+        'j', 'D', 'Z', 'D', 'z', 'd', 'z', 'h', '\u{266}', 'j', 'r', '\u{279}', '\u{27b}',
+        '\u{281}', 'w', 'y', ' ', '\u{306}', ' ', '\u{307}', ' ', '\u{30a}', ' ', '\u{328}', ' ',
+        '\u{303}', ' ', '\u{30b}', '\u{263}', 'l', 'x', '\u{295}', ' ', '\u{345}', ' ', '\u{308}',
+        '\u{301}', '\u{3b2}', '\u{3b8}', '\u{3a5}', '\u{3a5}', '\u{301}', '\u{3a5}', '\u{308}',
+        '\u{3c6}', '\u{3c0}', '\u{3ba}', '\u{3c1}', '\u{3c2}', '\u{398}', '\u{3b5}', '\u{3a3}',
+        '\u{565}', '\u{582}', '\u{627}', '\u{674}', '\u{648}', '\u{674}', '\u{6c7}', '\u{674}',
+        '\u{64a}', '\u{674}', '\u{e4d}', '\u{e32}', '\u{ecd}', '\u{eb2}', '\u{eab}', '\u{e99}',
+        '\u{eab}', '\u{ea1}', '\u{f0b}', '\u{fb2}', '\u{f71}', '\u{f80}', '\u{fb3}', '\u{f71}',
+        '\u{f80}', '\u{10dc}', 'A', '\u{c6}', 'B', 'D', 'E', '\u{18e}', 'G', 'H', 'I', 'J', 'K',
+        'L', 'M', 'N', 'O', '\u{222}', 'P', 'R', 'T', 'U', 'W', '\u{250}', '\u{251}', '\u{1d02}',
+        'b', 'd', 'e', '\u{259}', '\u{25b}', '\u{25c}', 'g', 'k', 'm', '\u{14b}', '\u{254}',
+        '\u{1d16}', '\u{1d17}', 'p', 't', 'u', '\u{1d1d}', '\u{26f}', 'v', '\u{1d25}', '\u{3b3}',
+        '\u{3b4}', '\u{3c7}', 'i', '\u{43d}', '\u{252}', 'c', '\u{255}', '\u{f0}', 'f', '\u{25f}',
+        '\u{261}', '\u{265}', '\u{268}', '\u{269}', '\u{26a}', '\u{1d7b}', '\u{29d}', '\u{26d}',
+        '\u{1d85}', '\u{29f}', '\u{271}', '\u{270}', '\u{272}', '\u{273}', '\u{274}', '\u{275}',
+        '\u{278}', '\u{282}', '\u{283}', '\u{1ab}', '\u{289}', '\u{28a}', '\u{1d1c}', '\u{28b}',
+        '\u{28c}', 'z', '\u{290}', '\u{291}', '\u{292}', 'a', '\u{2be}', 's', '\u{307}', ' ',
+        '\u{313}', ' ', '\u{342}', ' ', '\u{308}', '\u{342}', ' ', '\u{313}', '\u{300}', ' ',
+        '\u{313}', '\u{301}', ' ', '\u{313}', '\u{342}', ' ', '\u{314}', '\u{300}', ' ', '\u{314}',
+        '\u{301}', ' ', '\u{314}', '\u{342}', ' ', '\u{308}', '\u{300}', ' ', '\u{314}', '\u{2010}',
+        ' ', '\u{333}', '.', '.', '.', '.', '.', '.', '\u{2032}', '\u{2032}', '\u{2032}',
+        '\u{2032}', '\u{2032}', '\u{2035}', '\u{2035}', '\u{2035}', '\u{2035}', '\u{2035}', '!',
+        '!', ' ', '\u{305}', '?', '?', '?', '!', '!', '?', '\u{2032}', '\u{2032}', '\u{2032}',
+        '\u{2032}', '0', '4', '5', '6', '7', '8', '9', '+', '\u{2212}', '=', '(', ')', 'n', 'R',
+        's', 'a', '/', 'c', 'a', '/', 's', 'C', '\u{b0}', 'C', 'c', '/', 'o', 'c', '/', 'u',
+        '\u{190}', '\u{b0}', 'F', '\u{127}', 'N', 'o', 'Q', 'S', 'M', 'T', 'E', 'L', 'T', 'M', 'Z',
+        'F', '\u{5d0}', '\u{5d1}', '\u{5d2}', '\u{5d3}', 'F', 'A', 'X', '\u{393}', '\u{3a0}',
+        '\u{2211}', '1', '\u{2044}', '7', '1', '\u{2044}', '9', '1', '\u{2044}', '1', '0', '1',
+        '\u{2044}', '3', '2', '\u{2044}', '3', '1', '\u{2044}', '5', '2', '\u{2044}', '5', '3',
+        '\u{2044}', '5', '4', '\u{2044}', '5', '1', '\u{2044}', '6', '5', '\u{2044}', '6', '1',
+        '\u{2044}', '8', '3', '\u{2044}', '8', '5', '\u{2044}', '8', '7', '\u{2044}', '8', '1',
+        '\u{2044}', 'I', 'I', 'I', 'I', 'I', 'I', 'V', 'V', 'V', 'I', 'V', 'I', 'I', 'V', 'I', 'I',
+        'I', 'I', 'X', 'X', 'X', 'I', 'X', 'I', 'I', 'i', 'i', 'i', 'i', 'i', 'i', 'v', 'v', 'i',
+        'v', 'i', 'i', 'v', 'i', 'i', 'i', 'i', 'x', 'x', 'i', 'x', 'i', 'i', '0', '\u{2044}', '3',
+        '\u{222b}', '\u{222b}', '\u{222b}', '\u{222b}', '\u{222b}', '\u{222e}', '\u{222e}',
+        '\u{222e}', '\u{222e}', '\u{222e}', '1', '0', '1', '1', '1', '2', '1', '3', '1', '4', '1',
+        '5', '1', '6', '1', '7', '1', '8', '1', '9', '2', '0', '(', '1', ')', '(', '2', ')', '(',
+        '3', ')', '(', '4', ')', '(', '5', ')', '(', '6', ')', '(', '7', ')', '(', '8', ')', '(',
+        '9', ')', '(', '1', '0', ')', '(', '1', '1', ')', '(', '1', '2', ')', '(', '1', '3', ')',
+        '(', '1', '4', ')', '(', '1', '5', ')', '(', '1', '6', ')', '(', '1', '7', ')', '(', '1',
+        '8', ')', '(', '1', '9', ')', '(', '2', '0', ')', '1', '.', '2', '.', '3', '.', '4', '.',
+        '5', '.', '6', '.', '7', '.', '8', '.', '9', '.', '1', '0', '.', '1', '1', '.', '1', '2',
+        '.', '1', '3', '.', '1', '4', '.', '1', '5', '.', '1', '6', '.', '1', '7', '.', '1', '8',
+        '.', '1', '9', '.', '2', '0', '.', '(', 'a', ')', '(', 'b', ')', '(', 'c', ')', '(', 'd',
+        ')', '(', 'e', ')', '(', 'f', ')', '(', 'g', ')', '(', 'h', ')', '(', 'i', ')', '(', 'j',
+        ')', '(', 'k', ')', '(', 'l', ')', '(', 'm', ')', '(', 'n', ')', '(', 'o', ')', '(', 'p',
+        ')', '(', 'q', ')', '(', 'r', ')', '(', 's', ')', '(', 't', ')', '(', 'u', ')', '(', 'v',
+        ')', '(', 'w', ')', '(', 'x', ')', '(', 'y', ')', '(', 'z', ')', 'S', 'Y', 'q', '\u{222b}',
+        '\u{222b}', '\u{222b}', '\u{222b}', ':', ':', '=', '=', '=', '=', '=', '=', '\u{2d61}',
+        '\u{6bcd}', '\u{9f9f}', '\u{4e00}', '\u{4e28}', '\u{4e36}', '\u{4e3f}', '\u{4e59}',
+        '\u{4e85}', '\u{4e8c}', '\u{4ea0}', '\u{4eba}', '\u{513f}', '\u{5165}', '\u{516b}',
+        '\u{5182}', '\u{5196}', '\u{51ab}', '\u{51e0}', '\u{51f5}', '\u{5200}', '\u{529b}',
+        '\u{52f9}', '\u{5315}', '\u{531a}', '\u{5338}', '\u{5341}', '\u{535c}', '\u{5369}',
+        '\u{5382}', '\u{53b6}', '\u{53c8}', '\u{53e3}', '\u{56d7}', '\u{571f}', '\u{58eb}',
+        '\u{5902}', '\u{590a}', '\u{5915}', '\u{5927}', '\u{5973}', '\u{5b50}', '\u{5b80}',
+        '\u{5bf8}', '\u{5c0f}', '\u{5c22}', '\u{5c38}', '\u{5c6e}', '\u{5c71}', '\u{5ddb}',
+        '\u{5de5}', '\u{5df1}', '\u{5dfe}', '\u{5e72}', '\u{5e7a}', '\u{5e7f}', '\u{5ef4}',
+        '\u{5efe}', '\u{5f0b}', '\u{5f13}', '\u{5f50}', '\u{5f61}', '\u{5f73}', '\u{5fc3}',
+        '\u{6208}', '\u{6236}', '\u{624b}', '\u{652f}', '\u{6534}', '\u{6587}', '\u{6597}',
+        '\u{65a4}', '\u{65b9}', '\u{65e0}', '\u{65e5}', '\u{66f0}', '\u{6708}', '\u{6728}',
+        '\u{6b20}', '\u{6b62}', '\u{6b79}', '\u{6bb3}', '\u{6bcb}', '\u{6bd4}', '\u{6bdb}',
+        '\u{6c0f}', '\u{6c14}', '\u{6c34}', '\u{706b}', '\u{722a}', '\u{7236}', '\u{723b}',
+        '\u{723f}', '\u{7247}', '\u{7259}', '\u{725b}', '\u{72ac}', '\u{7384}', '\u{7389}',
+        '\u{74dc}', '\u{74e6}', '\u{7518}', '\u{751f}', '\u{7528}', '\u{7530}', '\u{758b}',
+        '\u{7592}', '\u{7676}', '\u{767d}', '\u{76ae}', '\u{76bf}', '\u{76ee}', '\u{77db}',
+        '\u{77e2}', '\u{77f3}', '\u{793a}', '\u{79b8}', '\u{79be}', '\u{7a74}', '\u{7acb}',
+        '\u{7af9}', '\u{7c73}', '\u{7cf8}', '\u{7f36}', '\u{7f51}', '\u{7f8a}', '\u{7fbd}',
+        '\u{8001}', '\u{800c}', '\u{8012}', '\u{8033}', '\u{807f}', '\u{8089}', '\u{81e3}',
+        '\u{81ea}', '\u{81f3}', '\u{81fc}', '\u{820c}', '\u{821b}', '\u{821f}', '\u{826e}',
+        '\u{8272}', '\u{8278}', '\u{864d}', '\u{866b}', '\u{8840}', '\u{884c}', '\u{8863}',
+        '\u{897e}', '\u{898b}', '\u{89d2}', '\u{8a00}', '\u{8c37}', '\u{8c46}', '\u{8c55}',
+        '\u{8c78}', '\u{8c9d}', '\u{8d64}', '\u{8d70}', '\u{8db3}', '\u{8eab}', '\u{8eca}',
+        '\u{8f9b}', '\u{8fb0}', '\u{8fb5}', '\u{9091}', '\u{9149}', '\u{91c6}', '\u{91cc}',
+        '\u{91d1}', '\u{9577}', '\u{9580}', '\u{961c}', '\u{96b6}', '\u{96b9}', '\u{96e8}',
+        '\u{9751}', '\u{975e}', '\u{9762}', '\u{9769}', '\u{97cb}', '\u{97ed}', '\u{97f3}',
+        '\u{9801}', '\u{98a8}', '\u{98db}', '\u{98df}', '\u{9996}', '\u{9999}', '\u{99ac}',
+        '\u{9aa8}', '\u{9ad8}', '\u{9adf}', '\u{9b25}', '\u{9b2f}', '\u{9b32}', '\u{9b3c}',
+        '\u{9b5a}', '\u{9ce5}', '\u{9e75}', '\u{9e7f}', '\u{9ea5}', '\u{9ebb}', '\u{9ec3}',
+        '\u{9ecd}', '\u{9ed1}', '\u{9ef9}', '\u{9efd}', '\u{9f0e}', '\u{9f13}', '\u{9f20}',
+        '\u{9f3b}', '\u{9f4a}', '\u{9f52}', '\u{9f8d}', '\u{9f9c}', '\u{9fa0}', '\u{3012}',
+        '\u{5344}', '\u{5345}', ' ', '\u{3099}', ' ', '\u{309a}', '\u{3088}', '\u{308a}',
+        '\u{30b3}', '\u{30c8}', '\u{1100}', '\u{1101}', '\u{11aa}', '\u{1102}', '\u{11ac}',
+        '\u{11ad}', '\u{1103}', '\u{1104}', '\u{1105}', '\u{11b0}', '\u{11b1}', '\u{11b2}',
+        '\u{11b3}', '\u{11b4}', '\u{11b5}', '\u{111a}', '\u{1106}', '\u{1107}', '\u{1108}',
+        '\u{1121}', '\u{1109}', '\u{110a}', '\u{110b}', '\u{110c}', '\u{110d}', '\u{110e}',
+        '\u{110f}', '\u{1110}', '\u{1111}', '\u{1112}', '\u{1161}', '\u{1162}', '\u{1163}',
+        '\u{1164}', '\u{1165}', '\u{1166}', '\u{1167}', '\u{1168}', '\u{1169}', '\u{116a}',
+        '\u{116b}', '\u{116c}', '\u{116d}', '\u{116e}', '\u{116f}', '\u{1170}', '\u{1171}',
+        '\u{1172}', '\u{1173}', '\u{1174}', '\u{1175}', '\u{1160}', '\u{1114}', '\u{1115}',
+        '\u{11c7}', '\u{11c8}', '\u{11cc}', '\u{11ce}', '\u{11d3}', '\u{11d7}', '\u{11d9}',
+        '\u{111c}', '\u{11dd}', '\u{11df}', '\u{111d}', '\u{111e}', '\u{1120}', '\u{1122}',
+        '\u{1123}', '\u{1127}', '\u{1129}', '\u{112b}', '\u{112c}', '\u{112d}', '\u{112e}',
+        '\u{112f}', '\u{1132}', '\u{1136}', '\u{1140}', '\u{1147}', '\u{114c}', '\u{11f1}',
+        '\u{11f2}', '\u{1157}', '\u{1158}', '\u{1159}', '\u{1184}', '\u{1185}', '\u{1188}',
+        '\u{1191}', '\u{1192}', '\u{1194}', '\u{119e}', '\u{11a1}', '\u{4e09}', '\u{56db}',
+        '\u{4e0a}', '\u{4e2d}', '\u{4e0b}', '\u{7532}', '\u{4e19}', '\u{4e01}', '\u{5929}',
+        '\u{5730}', '(', '\u{1100}', ')', '(', '\u{1102}', ')', '(', '\u{1103}', ')', '(',
+        '\u{1105}', ')', '(', '\u{1106}', ')', '(', '\u{1107}', ')', '(', '\u{1109}', ')', '(',
+        '\u{110b}', ')', '(', '\u{110c}', ')', '(', '\u{110e}', ')', '(', '\u{110f}', ')', '(',
+        '\u{1110}', ')', '(', '\u{1111}', ')', '(', '\u{1112}', ')', '(', '\u{1100}', '\u{1161}',
+        ')', '(', '\u{1102}', '\u{1161}', ')', '(', '\u{1103}', '\u{1161}', ')', '(', '\u{1105}',
+        '\u{1161}', ')', '(', '\u{1106}', '\u{1161}', ')', '(', '\u{1107}', '\u{1161}', ')', '(',
+        '\u{1109}', '\u{1161}', ')', '(', '\u{110b}', '\u{1161}', ')', '(', '\u{110c}', '\u{1161}',
+        ')', '(', '\u{110e}', '\u{1161}', ')', '(', '\u{110f}', '\u{1161}', ')', '(', '\u{1110}',
+        '\u{1161}', ')', '(', '\u{1111}', '\u{1161}', ')', '(', '\u{1112}', '\u{1161}', ')', '(',
+        '\u{110c}', '\u{116e}', ')', '(', '\u{110b}', '\u{1169}', '\u{110c}', '\u{1165}',
+        '\u{11ab}', ')', '(', '\u{110b}', '\u{1169}', '\u{1112}', '\u{116e}', ')', '(', '\u{4e00}',
+        ')', '(', '\u{4e8c}', ')', '(', '\u{4e09}', ')', '(', '\u{56db}', ')', '(', '\u{4e94}', ')',
+        '(', '\u{516d}', ')', '(', '\u{4e03}', ')', '(', '\u{516b}', ')', '(', '\u{4e5d}', ')', '(',
+        '\u{5341}', ')', '(', '\u{6708}', ')', '(', '\u{706b}', ')', '(', '\u{6c34}', ')', '(',
+        '\u{6728}', ')', '(', '\u{91d1}', ')', '(', '\u{571f}', ')', '(', '\u{65e5}', ')', '(',
+        '\u{682a}', ')', '(', '\u{6709}', ')', '(', '\u{793e}', ')', '(', '\u{540d}', ')', '(',
+        '\u{7279}', ')', '(', '\u{8ca1}', ')', '(', '\u{795d}', ')', '(', '\u{52b4}', ')', '(',
+        '\u{4ee3}', ')', '(', '\u{547c}', ')', '(', '\u{5b66}', ')', '(', '\u{76e3}', ')', '(',
+        '\u{4f01}', ')', '(', '\u{8cc7}', ')', '(', '\u{5354}', ')', '(', '\u{796d}', ')', '(',
+        '\u{4f11}', ')', '(', '\u{81ea}', ')', '(', '\u{81f3}', ')', '\u{554f}', '\u{5e7c}',
+        '\u{7b8f}', 'P', 'T', 'E', '2', '1', '2', '2', '2', '3', '2', '4', '2', '5', '2', '6', '2',
+        '7', '2', '8', '2', '9', '3', '0', '3', '1', '3', '2', '3', '3', '3', '4', '3', '5',
+        '\u{1100}', '\u{1161}', '\u{1102}', '\u{1161}', '\u{1103}', '\u{1161}', '\u{1105}',
+        '\u{1161}', '\u{1106}', '\u{1161}', '\u{1107}', '\u{1161}', '\u{1109}', '\u{1161}',
+        '\u{110b}', '\u{1161}', '\u{110c}', '\u{1161}', '\u{110e}', '\u{1161}', '\u{110f}',
+        '\u{1161}', '\u{1110}', '\u{1161}', '\u{1111}', '\u{1161}', '\u{1112}', '\u{1161}',
+        '\u{110e}', '\u{1161}', '\u{11b7}', '\u{1100}', '\u{1169}', '\u{110c}', '\u{116e}',
+        '\u{110b}', '\u{1174}', '\u{110b}', '\u{116e}', '\u{4e94}', '\u{516d}', '\u{4e03}',
+        '\u{4e5d}', '\u{682a}', '\u{6709}', '\u{793e}', '\u{540d}', '\u{7279}', '\u{8ca1}',
+        '\u{795d}', '\u{52b4}', '\u{79d8}', '\u{7537}', '\u{9069}', '\u{512a}', '\u{5370}',
+        '\u{6ce8}', '\u{9805}', '\u{4f11}', '\u{5199}', '\u{6b63}', '\u{5de6}', '\u{53f3}',
+        '\u{533b}', '\u{5b97}', '\u{5b66}', '\u{76e3}', '\u{4f01}', '\u{8cc7}', '\u{5354}',
+        '\u{591c}', '3', '6', '3', '7', '3', '8', '3', '9', '4', '0', '4', '1', '4', '2', '4', '3',
+        '4', '4', '4', '5', '4', '6', '4', '7', '4', '8', '4', '9', '5', '0', '1', '\u{6708}', '2',
+        '\u{6708}', '3', '\u{6708}', '4', '\u{6708}', '5', '\u{6708}', '6', '\u{6708}', '7',
+        '\u{6708}', '8', '\u{6708}', '9', '\u{6708}', '1', '0', '\u{6708}', '1', '1', '\u{6708}',
+        '1', '2', '\u{6708}', 'H', 'g', 'e', 'r', 'g', 'e', 'V', 'L', 'T', 'D', '\u{30a2}',
+        '\u{30a4}', '\u{30a6}', '\u{30a8}', '\u{30aa}', '\u{30ab}', '\u{30ad}', '\u{30af}',
+        '\u{30b1}', '\u{30b3}', '\u{30b5}', '\u{30b7}', '\u{30b9}', '\u{30bb}', '\u{30bd}',
+        '\u{30bf}', '\u{30c1}', '\u{30c4}', '\u{30c6}', '\u{30c8}', '\u{30ca}', '\u{30cb}',
+        '\u{30cc}', '\u{30cd}', '\u{30ce}', '\u{30cf}', '\u{30d2}', '\u{30d5}', '\u{30d8}',
+        '\u{30db}', '\u{30de}', '\u{30df}', '\u{30e0}', '\u{30e1}', '\u{30e2}', '\u{30e4}',
+        '\u{30e6}', '\u{30e8}', '\u{30e9}', '\u{30ea}', '\u{30eb}', '\u{30ec}', '\u{30ed}',
+        '\u{30ef}', '\u{30f0}', '\u{30f1}', '\u{30f2}', '\u{4ee4}', '\u{548c}', '\u{30a2}',
+        '\u{30cf}', '\u{309a}', '\u{30fc}', '\u{30c8}', '\u{30a2}', '\u{30eb}', '\u{30d5}',
+        '\u{30a1}', '\u{30a2}', '\u{30f3}', '\u{30d8}', '\u{309a}', '\u{30a2}', '\u{30a2}',
+        '\u{30fc}', '\u{30eb}', '\u{30a4}', '\u{30cb}', '\u{30f3}', '\u{30af}', '\u{3099}',
+        '\u{30a4}', '\u{30f3}', '\u{30c1}', '\u{30a6}', '\u{30a9}', '\u{30f3}', '\u{30a8}',
+        '\u{30b9}', '\u{30af}', '\u{30fc}', '\u{30c8}', '\u{3099}', '\u{30a8}', '\u{30fc}',
+        '\u{30ab}', '\u{30fc}', '\u{30aa}', '\u{30f3}', '\u{30b9}', '\u{30aa}', '\u{30fc}',
+        '\u{30e0}', '\u{30ab}', '\u{30a4}', '\u{30ea}', '\u{30ab}', '\u{30e9}', '\u{30c3}',
+        '\u{30c8}', '\u{30ab}', '\u{30ed}', '\u{30ea}', '\u{30fc}', '\u{30ab}', '\u{3099}',
+        '\u{30ed}', '\u{30f3}', '\u{30ab}', '\u{3099}', '\u{30f3}', '\u{30de}', '\u{30ad}',
+        '\u{3099}', '\u{30ab}', '\u{3099}', '\u{30ad}', '\u{3099}', '\u{30cb}', '\u{30fc}',
+        '\u{30ad}', '\u{30e5}', '\u{30ea}', '\u{30fc}', '\u{30ad}', '\u{3099}', '\u{30eb}',
+        '\u{30bf}', '\u{3099}', '\u{30fc}', '\u{30ad}', '\u{30ed}', '\u{30ad}', '\u{30ed}',
+        '\u{30af}', '\u{3099}', '\u{30e9}', '\u{30e0}', '\u{30ad}', '\u{30ed}', '\u{30e1}',
+        '\u{30fc}', '\u{30c8}', '\u{30eb}', '\u{30ad}', '\u{30ed}', '\u{30ef}', '\u{30c3}',
+        '\u{30c8}', '\u{30af}', '\u{3099}', '\u{30e9}', '\u{30e0}', '\u{30af}', '\u{3099}',
+        '\u{30e9}', '\u{30e0}', '\u{30c8}', '\u{30f3}', '\u{30af}', '\u{30eb}', '\u{30bb}',
+        '\u{3099}', '\u{30a4}', '\u{30ed}', '\u{30af}', '\u{30ed}', '\u{30fc}', '\u{30cd}',
+        '\u{30b1}', '\u{30fc}', '\u{30b9}', '\u{30b3}', '\u{30eb}', '\u{30ca}', '\u{30b3}',
+        '\u{30fc}', '\u{30db}', '\u{309a}', '\u{30b5}', '\u{30a4}', '\u{30af}', '\u{30eb}',
+        '\u{30b5}', '\u{30f3}', '\u{30c1}', '\u{30fc}', '\u{30e0}', '\u{30b7}', '\u{30ea}',
+        '\u{30f3}', '\u{30af}', '\u{3099}', '\u{30bb}', '\u{30f3}', '\u{30c1}', '\u{30bb}',
+        '\u{30f3}', '\u{30c8}', '\u{30bf}', '\u{3099}', '\u{30fc}', '\u{30b9}', '\u{30c6}',
+        '\u{3099}', '\u{30b7}', '\u{30c8}', '\u{3099}', '\u{30eb}', '\u{30c8}', '\u{30f3}',
+        '\u{30ca}', '\u{30ce}', '\u{30ce}', '\u{30c3}', '\u{30c8}', '\u{30cf}', '\u{30a4}',
+        '\u{30c4}', '\u{30cf}', '\u{309a}', '\u{30fc}', '\u{30bb}', '\u{30f3}', '\u{30c8}',
+        '\u{30cf}', '\u{309a}', '\u{30fc}', '\u{30c4}', '\u{30cf}', '\u{3099}', '\u{30fc}',
+        '\u{30ec}', '\u{30eb}', '\u{30d2}', '\u{309a}', '\u{30a2}', '\u{30b9}', '\u{30c8}',
+        '\u{30eb}', '\u{30d2}', '\u{309a}', '\u{30af}', '\u{30eb}', '\u{30d2}', '\u{309a}',
+        '\u{30b3}', '\u{30d2}', '\u{3099}', '\u{30eb}', '\u{30d5}', '\u{30a1}', '\u{30e9}',
+        '\u{30c3}', '\u{30c8}', '\u{3099}', '\u{30d5}', '\u{30a3}', '\u{30fc}', '\u{30c8}',
+        '\u{30d5}', '\u{3099}', '\u{30c3}', '\u{30b7}', '\u{30a7}', '\u{30eb}', '\u{30d5}',
+        '\u{30e9}', '\u{30f3}', '\u{30d8}', '\u{30af}', '\u{30bf}', '\u{30fc}', '\u{30eb}',
+        '\u{30d8}', '\u{309a}', '\u{30bd}', '\u{30d8}', '\u{309a}', '\u{30cb}', '\u{30d2}',
+        '\u{30d8}', '\u{30eb}', '\u{30c4}', '\u{30d8}', '\u{309a}', '\u{30f3}', '\u{30b9}',
+        '\u{30d8}', '\u{309a}', '\u{30fc}', '\u{30b7}', '\u{3099}', '\u{30d8}', '\u{3099}',
+        '\u{30fc}', '\u{30bf}', '\u{30db}', '\u{309a}', '\u{30a4}', '\u{30f3}', '\u{30c8}',
+        '\u{30db}', '\u{3099}', '\u{30eb}', '\u{30c8}', '\u{30db}', '\u{30f3}', '\u{30db}',
+        '\u{309a}', '\u{30f3}', '\u{30c8}', '\u{3099}', '\u{30db}', '\u{30fc}', '\u{30eb}',
+        '\u{30db}', '\u{30fc}', '\u{30f3}', '\u{30de}', '\u{30a4}', '\u{30af}', '\u{30ed}',
+        '\u{30de}', '\u{30a4}', '\u{30eb}', '\u{30de}', '\u{30c3}', '\u{30cf}', '\u{30de}',
+        '\u{30eb}', '\u{30af}', '\u{30de}', '\u{30f3}', '\u{30b7}', '\u{30e7}', '\u{30f3}',
+        '\u{30df}', '\u{30af}', '\u{30ed}', '\u{30f3}', '\u{30df}', '\u{30ea}', '\u{30df}',
+        '\u{30ea}', '\u{30cf}', '\u{3099}', '\u{30fc}', '\u{30eb}', '\u{30e1}', '\u{30ab}',
+        '\u{3099}', '\u{30e1}', '\u{30ab}', '\u{3099}', '\u{30c8}', '\u{30f3}', '\u{30e1}',
+        '\u{30fc}', '\u{30c8}', '\u{30eb}', '\u{30e4}', '\u{30fc}', '\u{30c8}', '\u{3099}',
+        '\u{30e4}', '\u{30fc}', '\u{30eb}', '\u{30e6}', '\u{30a2}', '\u{30f3}', '\u{30ea}',
+        '\u{30c3}', '\u{30c8}', '\u{30eb}', '\u{30ea}', '\u{30e9}', '\u{30eb}', '\u{30d2}',
+        '\u{309a}', '\u{30fc}', '\u{30eb}', '\u{30fc}', '\u{30d5}', '\u{3099}', '\u{30eb}',
+        '\u{30ec}', '\u{30e0}', '\u{30ec}', '\u{30f3}', '\u{30c8}', '\u{30b1}', '\u{3099}',
+        '\u{30f3}', '\u{30ef}', '\u{30c3}', '\u{30c8}', '0', '\u{70b9}', '1', '\u{70b9}', '2',
+        '\u{70b9}', '3', '\u{70b9}', '4', '\u{70b9}', '5', '\u{70b9}', '6', '\u{70b9}', '7',
+        '\u{70b9}', '8', '\u{70b9}', '9', '\u{70b9}', '1', '0', '\u{70b9}', '1', '1', '\u{70b9}',
+        '1', '2', '\u{70b9}', '1', '3', '\u{70b9}', '1', '4', '\u{70b9}', '1', '5', '\u{70b9}', '1',
+        '6', '\u{70b9}', '1', '7', '\u{70b9}', '1', '8', '\u{70b9}', '1', '9', '\u{70b9}', '2', '0',
+        '\u{70b9}', '2', '1', '\u{70b9}', '2', '2', '\u{70b9}', '2', '3', '\u{70b9}', '2', '4',
+        '\u{70b9}', 'h', 'P', 'a', 'd', 'a', 'A', 'U', 'b', 'a', 'r', 'o', 'V', 'p', 'c', 'd', 'm',
+        'd', 'm', '2', 'd', 'm', '3', 'I', 'U', '\u{5e73}', '\u{6210}', '\u{662d}', '\u{548c}',
+        '\u{5927}', '\u{6b63}', '\u{660e}', '\u{6cbb}', '\u{682a}', '\u{5f0f}', '\u{4f1a}',
+        '\u{793e}', 'p', 'A', 'n', 'A', '\u{3bc}', 'A', 'm', 'A', 'k', 'A', 'K', 'B', 'M', 'B', 'G',
+        'B', 'c', 'a', 'l', 'k', 'c', 'a', 'l', 'p', 'F', 'n', 'F', '\u{3bc}', 'F', '\u{3bc}', 'g',
+        'm', 'g', 'k', 'g', 'H', 'z', 'k', 'H', 'z', 'M', 'H', 'z', 'G', 'H', 'z', 'T', 'H', 'z',
+        '\u{3bc}', 'l', 'm', 'l', 'd', 'l', 'k', 'l', 'f', 'm', 'n', 'm', '\u{3bc}', 'm', 'm', 'm',
+        'c', 'm', 'k', 'm', 'm', 'm', '2', 'c', 'm', '2', 'm', '2', 'k', 'm', '2', 'm', 'm', '3',
+        'c', 'm', '3', 'm', '3', 'k', 'm', '3', 'm', '\u{2215}', 's', 'm', '\u{2215}', 's', '2',
+        'P', 'a', 'k', 'P', 'a', 'M', 'P', 'a', 'G', 'P', 'a', 'r', 'a', 'd', 'r', 'a', 'd',
+        '\u{2215}', 's', 'r', 'a', 'd', '\u{2215}', 's', '2', 'p', 's', 'n', 's', '\u{3bc}', 's',
+        'm', 's', 'p', 'V', 'n', 'V', '\u{3bc}', 'V', 'm', 'V', 'k', 'V', 'M', 'V', 'p', 'W', 'n',
+        'W', '\u{3bc}', 'W', 'm', 'W', 'k', 'W', 'M', 'W', 'k', '\u{3a9}', 'M', '\u{3a9}', 'a', '.',
+        'm', '.', 'B', 'q', 'c', 'c', 'c', 'd', 'C', '\u{2215}', 'k', 'g', 'C', 'o', '.', 'd', 'B',
+        'G', 'y', 'h', 'a', 'H', 'P', 'i', 'n', 'K', 'K', 'K', 'M', 'k', 't', 'l', 'm', 'l', 'n',
+        'l', 'o', 'g', 'l', 'x', 'm', 'b', 'm', 'i', 'l', 'm', 'o', 'l', 'P', 'H', 'p', '.', 'm',
+        '.', 'P', 'P', 'M', 'P', 'R', 's', 'r', 'S', 'v', 'W', 'b', 'V', '\u{2215}', 'm', 'A',
+        '\u{2215}', 'm', '1', '\u{65e5}', '2', '\u{65e5}', '3', '\u{65e5}', '4', '\u{65e5}', '5',
+        '\u{65e5}', '6', '\u{65e5}', '7', '\u{65e5}', '8', '\u{65e5}', '9', '\u{65e5}', '1', '0',
+        '\u{65e5}', '1', '1', '\u{65e5}', '1', '2', '\u{65e5}', '1', '3', '\u{65e5}', '1', '4',
+        '\u{65e5}', '1', '5', '\u{65e5}', '1', '6', '\u{65e5}', '1', '7', '\u{65e5}', '1', '8',
+        '\u{65e5}', '1', '9', '\u{65e5}', '2', '0', '\u{65e5}', '2', '1', '\u{65e5}', '2', '2',
+        '\u{65e5}', '2', '3', '\u{65e5}', '2', '4', '\u{65e5}', '2', '5', '\u{65e5}', '2', '6',
+        '\u{65e5}', '2', '7', '\u{65e5}', '2', '8', '\u{65e5}', '2', '9', '\u{65e5}', '3', '0',
+        '\u{65e5}', '3', '1', '\u{65e5}', 'g', 'a', 'l', '\u{44a}', '\u{44c}', '\u{a76f}',
+        '\u{126}', '\u{153}', '\u{a727}', '\u{ab37}', '\u{26b}', '\u{ab52}', '\u{28d}', 'f', 'f',
+        'f', 'i', 'f', 'l', 'f', 'f', 'i', 'f', 'f', 'l', 's', 't', '\u{574}', '\u{576}', '\u{574}',
+        '\u{565}', '\u{574}', '\u{56b}', '\u{57e}', '\u{576}', '\u{574}', '\u{56d}', '\u{5e2}',
+        '\u{5d4}', '\u{5db}', '\u{5dc}', '\u{5dd}', '\u{5e8}', '\u{5ea}', '\u{5d0}', '\u{5dc}',
+        '\u{671}', '\u{67b}', '\u{67e}', '\u{680}', '\u{67a}', '\u{67f}', '\u{679}', '\u{6a4}',
+        '\u{6a6}', '\u{684}', '\u{683}', '\u{686}', '\u{687}', '\u{68d}', '\u{68c}', '\u{68e}',
+        '\u{688}', '\u{698}', '\u{691}', '\u{6a9}', '\u{6af}', '\u{6b3}', '\u{6b1}', '\u{6ba}',
+        '\u{6bb}', '\u{6d5}', '\u{654}', '\u{6c1}', '\u{6be}', '\u{6d2}', '\u{6d2}', '\u{654}',
+        '\u{6ad}', '\u{6c7}', '\u{6c6}', '\u{6c8}', '\u{6cb}', '\u{6c5}', '\u{6c9}', '\u{6d0}',
+        '\u{649}', '\u{64a}', '\u{654}', '\u{627}', '\u{64a}', '\u{654}', '\u{6d5}', '\u{64a}',
+        '\u{654}', '\u{648}', '\u{64a}', '\u{654}', '\u{6c7}', '\u{64a}', '\u{654}', '\u{6c6}',
+        '\u{64a}', '\u{654}', '\u{6c8}', '\u{64a}', '\u{654}', '\u{6d0}', '\u{64a}', '\u{654}',
+        '\u{649}', '\u{6cc}', '\u{64a}', '\u{654}', '\u{62c}', '\u{64a}', '\u{654}', '\u{62d}',
+        '\u{64a}', '\u{654}', '\u{645}', '\u{64a}', '\u{654}', '\u{64a}', '\u{628}', '\u{62c}',
+        '\u{628}', '\u{62d}', '\u{628}', '\u{62e}', '\u{628}', '\u{645}', '\u{628}', '\u{649}',
+        '\u{628}', '\u{64a}', '\u{62a}', '\u{62c}', '\u{62a}', '\u{62d}', '\u{62a}', '\u{62e}',
+        '\u{62a}', '\u{645}', '\u{62a}', '\u{649}', '\u{62a}', '\u{64a}', '\u{62b}', '\u{62c}',
+        '\u{62b}', '\u{645}', '\u{62b}', '\u{649}', '\u{62b}', '\u{64a}', '\u{62c}', '\u{62d}',
+        '\u{62c}', '\u{645}', '\u{62d}', '\u{62c}', '\u{62d}', '\u{645}', '\u{62e}', '\u{62c}',
+        '\u{62e}', '\u{62d}', '\u{62e}', '\u{645}', '\u{633}', '\u{62c}', '\u{633}', '\u{62d}',
+        '\u{633}', '\u{62e}', '\u{633}', '\u{645}', '\u{635}', '\u{62d}', '\u{635}', '\u{645}',
+        '\u{636}', '\u{62c}', '\u{636}', '\u{62d}', '\u{636}', '\u{62e}', '\u{636}', '\u{645}',
+        '\u{637}', '\u{62d}', '\u{637}', '\u{645}', '\u{638}', '\u{645}', '\u{639}', '\u{62c}',
+        '\u{639}', '\u{645}', '\u{63a}', '\u{62c}', '\u{63a}', '\u{645}', '\u{641}', '\u{62c}',
+        '\u{641}', '\u{62d}', '\u{641}', '\u{62e}', '\u{641}', '\u{645}', '\u{641}', '\u{649}',
+        '\u{641}', '\u{64a}', '\u{642}', '\u{62d}', '\u{642}', '\u{645}', '\u{642}', '\u{649}',
+        '\u{642}', '\u{64a}', '\u{643}', '\u{627}', '\u{643}', '\u{62c}', '\u{643}', '\u{62d}',
+        '\u{643}', '\u{62e}', '\u{643}', '\u{644}', '\u{643}', '\u{645}', '\u{643}', '\u{649}',
+        '\u{643}', '\u{64a}', '\u{644}', '\u{62c}', '\u{644}', '\u{62d}', '\u{644}', '\u{62e}',
+        '\u{644}', '\u{645}', '\u{644}', '\u{649}', '\u{644}', '\u{64a}', '\u{645}', '\u{62c}',
+        '\u{645}', '\u{62d}', '\u{645}', '\u{62e}', '\u{645}', '\u{645}', '\u{645}', '\u{649}',
+        '\u{645}', '\u{64a}', '\u{646}', '\u{62c}', '\u{646}', '\u{62d}', '\u{646}', '\u{62e}',
+        '\u{646}', '\u{645}', '\u{646}', '\u{649}', '\u{646}', '\u{64a}', '\u{647}', '\u{62c}',
+        '\u{647}', '\u{645}', '\u{647}', '\u{649}', '\u{647}', '\u{64a}', '\u{64a}', '\u{62c}',
+        '\u{64a}', '\u{62d}', '\u{64a}', '\u{62e}', '\u{64a}', '\u{645}', '\u{64a}', '\u{649}',
+        '\u{64a}', '\u{64a}', '\u{630}', '\u{670}', '\u{631}', '\u{670}', '\u{649}', '\u{670}', ' ',
+        '\u{64c}', '\u{651}', ' ', '\u{64d}', '\u{651}', ' ', '\u{64e}', '\u{651}', ' ', '\u{64f}',
+        '\u{651}', ' ', '\u{650}', '\u{651}', ' ', '\u{651}', '\u{670}', '\u{64a}', '\u{654}',
+        '\u{631}', '\u{64a}', '\u{654}', '\u{632}', '\u{64a}', '\u{654}', '\u{646}', '\u{628}',
+        '\u{631}', '\u{628}', '\u{632}', '\u{628}', '\u{646}', '\u{62a}', '\u{631}', '\u{62a}',
+        '\u{632}', '\u{62a}', '\u{646}', '\u{62b}', '\u{631}', '\u{62b}', '\u{632}', '\u{62b}',
+        '\u{646}', '\u{645}', '\u{627}', '\u{646}', '\u{631}', '\u{646}', '\u{632}', '\u{646}',
+        '\u{646}', '\u{64a}', '\u{631}', '\u{64a}', '\u{632}', '\u{64a}', '\u{646}', '\u{64a}',
+        '\u{654}', '\u{62e}', '\u{64a}', '\u{654}', '\u{647}', '\u{628}', '\u{647}', '\u{62a}',
+        '\u{647}', '\u{635}', '\u{62e}', '\u{644}', '\u{647}', '\u{646}', '\u{647}', '\u{647}',
+        '\u{670}', '\u{64a}', '\u{647}', '\u{62b}', '\u{647}', '\u{633}', '\u{647}', '\u{634}',
+        '\u{645}', '\u{634}', '\u{647}', '\u{640}', '\u{64e}', '\u{651}', '\u{640}', '\u{64f}',
+        '\u{651}', '\u{640}', '\u{650}', '\u{651}', '\u{637}', '\u{649}', '\u{637}', '\u{64a}',
+        '\u{639}', '\u{649}', '\u{639}', '\u{64a}', '\u{63a}', '\u{649}', '\u{63a}', '\u{64a}',
+        '\u{633}', '\u{649}', '\u{633}', '\u{64a}', '\u{634}', '\u{649}', '\u{634}', '\u{64a}',
+        '\u{62d}', '\u{649}', '\u{62d}', '\u{64a}', '\u{62c}', '\u{649}', '\u{62c}', '\u{64a}',
+        '\u{62e}', '\u{649}', '\u{62e}', '\u{64a}', '\u{635}', '\u{649}', '\u{635}', '\u{64a}',
+        '\u{636}', '\u{649}', '\u{636}', '\u{64a}', '\u{634}', '\u{62c}', '\u{634}', '\u{62d}',
+        '\u{634}', '\u{62e}', '\u{634}', '\u{631}', '\u{633}', '\u{631}', '\u{635}', '\u{631}',
+        '\u{636}', '\u{631}', '\u{627}', '\u{64b}', '\u{62a}', '\u{62c}', '\u{645}', '\u{62a}',
+        '\u{62d}', '\u{62c}', '\u{62a}', '\u{62d}', '\u{645}', '\u{62a}', '\u{62e}', '\u{645}',
+        '\u{62a}', '\u{645}', '\u{62c}', '\u{62a}', '\u{645}', '\u{62d}', '\u{62a}', '\u{645}',
+        '\u{62e}', '\u{62c}', '\u{645}', '\u{62d}', '\u{62d}', '\u{645}', '\u{64a}', '\u{62d}',
+        '\u{645}', '\u{649}', '\u{633}', '\u{62d}', '\u{62c}', '\u{633}', '\u{62c}', '\u{62d}',
+        '\u{633}', '\u{62c}', '\u{649}', '\u{633}', '\u{645}', '\u{62d}', '\u{633}', '\u{645}',
+        '\u{62c}', '\u{633}', '\u{645}', '\u{645}', '\u{635}', '\u{62d}', '\u{62d}', '\u{635}',
+        '\u{645}', '\u{645}', '\u{634}', '\u{62d}', '\u{645}', '\u{634}', '\u{62c}', '\u{64a}',
+        '\u{634}', '\u{645}', '\u{62e}', '\u{634}', '\u{645}', '\u{645}', '\u{636}', '\u{62d}',
+        '\u{649}', '\u{636}', '\u{62e}', '\u{645}', '\u{637}', '\u{645}', '\u{62d}', '\u{637}',
+        '\u{645}', '\u{645}', '\u{637}', '\u{645}', '\u{64a}', '\u{639}', '\u{62c}', '\u{645}',
+        '\u{639}', '\u{645}', '\u{645}', '\u{639}', '\u{645}', '\u{649}', '\u{63a}', '\u{645}',
+        '\u{645}', '\u{63a}', '\u{645}', '\u{64a}', '\u{63a}', '\u{645}', '\u{649}', '\u{641}',
+        '\u{62e}', '\u{645}', '\u{642}', '\u{645}', '\u{62d}', '\u{642}', '\u{645}', '\u{645}',
+        '\u{644}', '\u{62d}', '\u{645}', '\u{644}', '\u{62d}', '\u{64a}', '\u{644}', '\u{62d}',
+        '\u{649}', '\u{644}', '\u{62c}', '\u{62c}', '\u{644}', '\u{62e}', '\u{645}', '\u{644}',
+        '\u{645}', '\u{62d}', '\u{645}', '\u{62d}', '\u{62c}', '\u{645}', '\u{62d}', '\u{645}',
+        '\u{645}', '\u{62d}', '\u{64a}', '\u{645}', '\u{62c}', '\u{62d}', '\u{645}', '\u{62c}',
+        '\u{645}', '\u{645}', '\u{62e}', '\u{62c}', '\u{645}', '\u{62e}', '\u{645}', '\u{645}',
+        '\u{62c}', '\u{62e}', '\u{647}', '\u{645}', '\u{62c}', '\u{647}', '\u{645}', '\u{645}',
+        '\u{646}', '\u{62d}', '\u{645}', '\u{646}', '\u{62d}', '\u{649}', '\u{646}', '\u{62c}',
+        '\u{645}', '\u{646}', '\u{62c}', '\u{649}', '\u{646}', '\u{645}', '\u{64a}', '\u{646}',
+        '\u{645}', '\u{649}', '\u{64a}', '\u{645}', '\u{645}', '\u{628}', '\u{62e}', '\u{64a}',
+        '\u{62a}', '\u{62c}', '\u{64a}', '\u{62a}', '\u{62c}', '\u{649}', '\u{62a}', '\u{62e}',
+        '\u{64a}', '\u{62a}', '\u{62e}', '\u{649}', '\u{62a}', '\u{645}', '\u{64a}', '\u{62a}',
+        '\u{645}', '\u{649}', '\u{62c}', '\u{645}', '\u{64a}', '\u{62c}', '\u{62d}', '\u{649}',
+        '\u{62c}', '\u{645}', '\u{649}', '\u{633}', '\u{62e}', '\u{649}', '\u{635}', '\u{62d}',
+        '\u{64a}', '\u{634}', '\u{62d}', '\u{64a}', '\u{636}', '\u{62d}', '\u{64a}', '\u{644}',
+        '\u{62c}', '\u{64a}', '\u{644}', '\u{645}', '\u{64a}', '\u{64a}', '\u{62d}', '\u{64a}',
+        '\u{64a}', '\u{62c}', '\u{64a}', '\u{64a}', '\u{645}', '\u{64a}', '\u{645}', '\u{645}',
+        '\u{64a}', '\u{642}', '\u{645}', '\u{64a}', '\u{646}', '\u{62d}', '\u{64a}', '\u{639}',
+        '\u{645}', '\u{64a}', '\u{643}', '\u{645}', '\u{64a}', '\u{646}', '\u{62c}', '\u{62d}',
+        '\u{645}', '\u{62e}', '\u{64a}', '\u{644}', '\u{62c}', '\u{645}', '\u{643}', '\u{645}',
+        '\u{645}', '\u{62c}', '\u{62d}', '\u{64a}', '\u{62d}', '\u{62c}', '\u{64a}', '\u{645}',
+        '\u{62c}', '\u{64a}', '\u{641}', '\u{645}', '\u{64a}', '\u{628}', '\u{62d}', '\u{64a}',
+        '\u{633}', '\u{62e}', '\u{64a}', '\u{646}', '\u{62c}', '\u{64a}', '\u{635}', '\u{644}',
+        '\u{6d2}', '\u{642}', '\u{644}', '\u{6d2}', '\u{627}', '\u{644}', '\u{644}', '\u{647}',
+        '\u{627}', '\u{643}', '\u{628}', '\u{631}', '\u{645}', '\u{62d}', '\u{645}', '\u{62f}',
+        '\u{635}', '\u{644}', '\u{639}', '\u{645}', '\u{631}', '\u{633}', '\u{648}', '\u{644}',
+        '\u{639}', '\u{644}', '\u{64a}', '\u{647}', '\u{648}', '\u{633}', '\u{644}', '\u{645}',
+        '\u{635}', '\u{644}', '\u{649}', '\u{635}', '\u{644}', '\u{649}', ' ', '\u{627}', '\u{644}',
+        '\u{644}', '\u{647}', ' ', '\u{639}', '\u{644}', '\u{64a}', '\u{647}', ' ', '\u{648}',
+        '\u{633}', '\u{644}', '\u{645}', '\u{62c}', '\u{644}', ' ', '\u{62c}', '\u{644}', '\u{627}',
+        '\u{644}', '\u{647}', '\u{631}', '\u{6cc}', '\u{627}', '\u{644}', ',', '\u{3001}',
+        '\u{3002}', ':', ';', '!', '?', '\u{3016}', '\u{3017}', '\u{2014}', '\u{2013}', '_', '{',
+        '}', '\u{3014}', '\u{3015}', '\u{3010}', '\u{3011}', '\u{300a}', '\u{300b}', '\u{3008}',
+        '\u{3009}', '\u{300c}', '\u{300d}', '\u{300e}', '\u{300f}', '[', ']', '#', '&', '*', '-',
+        '<', '>', '\\', '$', '%', '@', ' ', '\u{64b}', '\u{640}', '\u{64b}', ' ', '\u{64c}', ' ',
+        '\u{64d}', ' ', '\u{64e}', '\u{640}', '\u{64e}', ' ', '\u{64f}', '\u{640}', '\u{64f}', ' ',
+        '\u{650}', '\u{640}', '\u{650}', ' ', '\u{651}', '\u{640}', '\u{651}', ' ', '\u{652}',
+        '\u{640}', '\u{652}', '\u{621}', '\u{627}', '\u{653}', '\u{627}', '\u{654}', '\u{648}',
+        '\u{654}', '\u{627}', '\u{655}', '\u{64a}', '\u{654}', '\u{627}', '\u{628}', '\u{629}',
+        '\u{62a}', '\u{62b}', '\u{62c}', '\u{62d}', '\u{62e}', '\u{62f}', '\u{630}', '\u{631}',
+        '\u{632}', '\u{633}', '\u{634}', '\u{635}', '\u{636}', '\u{637}', '\u{638}', '\u{639}',
+        '\u{63a}', '\u{641}', '\u{642}', '\u{643}', '\u{644}', '\u{645}', '\u{646}', '\u{647}',
+        '\u{648}', '\u{64a}', '\u{644}', '\u{627}', '\u{653}', '\u{644}', '\u{627}', '\u{654}',
+        '\u{644}', '\u{627}', '\u{655}', '\u{644}', '\u{627}', '\"', '\'', '/', '^', '`', '|', '~',
+        '\u{2985}', '\u{2986}', '\u{30fb}', '\u{30a1}', '\u{30a3}', '\u{30a5}', '\u{30a7}',
+        '\u{30a9}', '\u{30e3}', '\u{30e5}', '\u{30e7}', '\u{30c3}', '\u{30fc}', '\u{30f3}',
+        '\u{3099}', '\u{309a}', '\u{a2}', '\u{a3}', '\u{ac}', '\u{a6}', '\u{a5}', '\u{20a9}',
+        '\u{2502}', '\u{2190}', '\u{2191}', '\u{2192}', '\u{2193}', '\u{25a0}', '\u{25cb}',
+        '\u{2d0}', '\u{2d1}', '\u{e6}', '\u{299}', '\u{253}', '\u{2a3}', '\u{ab66}', '\u{2a5}',
+        '\u{2a4}', '\u{256}', '\u{257}', '\u{1d91}', '\u{258}', '\u{25e}', '\u{2a9}', '\u{264}',
+        '\u{262}', '\u{260}', '\u{29b}', '\u{29c}', '\u{267}', '\u{284}', '\u{2aa}', '\u{2ab}',
+        '\u{26c}', '\u{1df04}', '\u{a78e}', '\u{26e}', '\u{1df05}', '\u{28e}', '\u{1df06}',
+        '\u{f8}', '\u{276}', '\u{277}', '\u{27a}', '\u{1df08}', '\u{27d}', '\u{27e}', '\u{280}',
+        '\u{2a8}', '\u{2a6}', '\u{ab67}', '\u{2a7}', '\u{288}', '\u{2c71}', '\u{28f}', '\u{2a1}',
+        '\u{2a2}', '\u{298}', '\u{1c0}', '\u{1c1}', '\u{1c2}', '\u{1df0a}', '\u{1df1e}', '\u{131}',
+        '\u{237}', '\u{391}', '\u{392}', '\u{394}', '\u{395}', '\u{396}', '\u{397}', '\u{399}',
+        '\u{39a}', '\u{39b}', '\u{39c}', '\u{39d}', '\u{39e}', '\u{39f}', '\u{3a1}', '\u{3a4}',
+        '\u{3a6}', '\u{3a7}', '\u{3a8}', '\u{3a9}', '\u{2207}', '\u{3b1}', '\u{3b6}', '\u{3b7}',
+        '\u{3b9}', '\u{3bb}', '\u{3bd}', '\u{3be}', '\u{3bf}', '\u{3c3}', '\u{3c4}', '\u{3c5}',
+        '\u{3c8}', '\u{3c9}', '\u{2202}', '\u{3dc}', '\u{3dd}', '\u{430}', '\u{431}', '\u{432}',
+        '\u{433}', '\u{434}', '\u{435}', '\u{436}', '\u{437}', '\u{438}', '\u{43a}', '\u{43b}',
+        '\u{43c}', '\u{43e}', '\u{43f}', '\u{440}', '\u{441}', '\u{442}', '\u{443}', '\u{444}',
+        '\u{445}', '\u{446}', '\u{447}', '\u{448}', '\u{44b}', '\u{44d}', '\u{44e}', '\u{a689}',
+        '\u{4d9}', '\u{456}', '\u{458}', '\u{4e9}', '\u{4af}', '\u{4cf}', '\u{491}', '\u{455}',
+        '\u{45f}', '\u{4ab}', '\u{a651}', '\u{4b1}', '\u{66e}', '\u{6a1}', '\u{66f}', '0', '.', '0',
+        ',', '1', ',', '2', ',', '3', ',', '4', ',', '5', ',', '6', ',', '7', ',', '8', ',', '9',
+        ',', '(', 'A', ')', '(', 'B', ')', '(', 'C', ')', '(', 'D', ')', '(', 'E', ')', '(', 'F',
+        ')', '(', 'G', ')', '(', 'H', ')', '(', 'I', ')', '(', 'J', ')', '(', 'K', ')', '(', 'L',
+        ')', '(', 'M', ')', '(', 'N', ')', '(', 'O', ')', '(', 'P', ')', '(', 'Q', ')', '(', 'R',
+        ')', '(', 'S', ')', '(', 'T', ')', '(', 'U', ')', '(', 'V', ')', '(', 'W', ')', '(', 'X',
+        ')', '(', 'Y', ')', '(', 'Z', ')', '\u{3014}', 'S', '\u{3015}', 'C', 'D', 'W', 'Z', 'H',
+        'V', 'S', 'D', 'S', 'S', 'P', 'P', 'V', 'W', 'C', 'M', 'C', 'M', 'D', 'M', 'R', 'D', 'J',
+        '\u{307b}', '\u{304b}', '\u{30b3}', '\u{30b3}', '\u{5b57}', '\u{53cc}', '\u{30c6}',
+        '\u{3099}', '\u{591a}', '\u{89e3}', '\u{4ea4}', '\u{6620}', '\u{7121}', '\u{6599}',
+        '\u{524d}', '\u{5f8c}', '\u{518d}', '\u{65b0}', '\u{521d}', '\u{7d42}', '\u{8ca9}',
+        '\u{58f0}', '\u{5439}', '\u{6f14}', '\u{6295}', '\u{6355}', '\u{904a}', '\u{6307}',
+        '\u{6253}', '\u{7981}', '\u{7a7a}', '\u{5408}', '\u{6e80}', '\u{7533}', '\u{5272}',
+        '\u{55b6}', '\u{914d}', '\u{3014}', '\u{672c}', '\u{3015}', '\u{3014}', '\u{4e09}',
+        '\u{3015}', '\u{3014}', '\u{4e8c}', '\u{3015}', '\u{3014}', '\u{5b89}', '\u{3015}',
+        '\u{3014}', '\u{70b9}', '\u{3015}', '\u{3014}', '\u{6253}', '\u{3015}', '\u{3014}',
+        '\u{76d7}', '\u{3015}', '\u{3014}', '\u{52dd}', '\u{3015}', '\u{3014}', '\u{6557}',
+        '\u{3015}', '\u{5f97}', '\u{53ef}',
+    ];
+
+    static COMPOSITION_TABLE: &[(char, char, char)] = &[
+        ('<', '\u{338}', '\u{226e}'), ('=', '\u{338}', '\u{2260}'), ('>', '\u{338}', '\u{226f}'),
+        ('A', '\u{300}', '\u{c0}'), ('A', '\u{301}', '\u{c1}'), ('A', '\u{302}', '\u{c2}'),
+        ('A', '\u{303}', '\u{c3}'), ('A', '\u{304}', '\u{100}'), ('A', '\u{306}', '\u{102}'),
+        ('A', '\u{307}', '\u{226}'), ('A', '\u{308}', '\u{c4}'), ('A', '\u{309}', '\u{1ea2}'),
+        ('A', '\u{30a}', '\u{c5}'), ('A', '\u{30c}', '\u{1cd}'), ('A', '\u{30f}', '\u{200}'),
+        ('A', '\u{311}', '\u{202}'), ('A', '\u{323}', '\u{1ea0}'), ('A', '\u{325}', '\u{1e00}'),
+        ('A', '\u{328}', '\u{104}'), ('B', '\u{307}', '\u{1e02}'), ('B', '\u{323}', '\u{1e04}'),
+        ('B', '\u{331}', '\u{1e06}'), ('C', '\u{301}', '\u{106}'), ('C', '\u{302}', '\u{108}'),
+        ('C', '\u{307}', '\u{10a}'), ('C', '\u{30c}', '\u{10c}'), ('C', '\u{327}', '\u{c7}'),
+        ('D', '\u{307}', '\u{1e0a}'), ('D', '\u{30c}', '\u{10e}'), ('D', '\u{323}', '\u{1e0c}'),
+        ('D', '\u{327}', '\u{1e10}'), ('D', '\u{32d}', '\u{1e12}'), ('D', '\u{331}', '\u{1e0e}'),
+        ('E', '\u{300}', '\u{c8}'), ('E', '\u{301}', '\u{c9}'), ('E', '\u{302}', '\u{ca}'),
+        ('E', '\u{303}', '\u{1ebc}'), ('E', '\u{304}', '\u{112}'), ('E', '\u{306}', '\u{114}'),
+        ('E', '\u{307}', '\u{116}'), ('E', '\u{308}', '\u{cb}'), ('E', '\u{309}', '\u{1eba}'),
+        ('E', '\u{30c}', '\u{11a}'), ('E', '\u{30f}', '\u{204}'), ('E', '\u{311}', '\u{206}'),
+        ('E', '\u{323}', '\u{1eb8}'), ('E', '\u{327}', '\u{228}'), ('E', '\u{328}', '\u{118}'),
+        ('E', '\u{32d}', '\u{1e18}'), ('E', '\u{330}', '\u{1e1a}'), ('F', '\u{307}', '\u{1e1e}'),
+        ('G', '\u{301}', '\u{1f4}'), ('G', '\u{302}', '\u{11c}'), ('G', '\u{304}', '\u{1e20}'),
+        ('G', '\u{306}', '\u{11e}'), ('G', '\u{307}', '\u{120}'), ('G', '\u{30c}', '\u{1e6}'),
+        ('G', '\u{327}', '\u{122}'), ('H', '\u{302}', '\u{124}'), ('H', '\u{307}', '\u{1e22}'),
+        ('H', '\u{308}', '\u{1e26}'), ('H', '\u{30c}', '\u{21e}'), ('H', '\u{323}', '\u{1e24}'),
+        ('H', '\u{327}', '\u{1e28}'), ('H', '\u{32e}', '\u{1e2a}'), ('I', '\u{300}', '\u{cc}'),
+        ('I', '\u{301}', '\u{cd}'), ('I', '\u{302}', '\u{ce}'), ('I', '\u{303}', '\u{128}'),
+        ('I', '\u{304}', '\u{12a}'), ('I', '\u{306}', '\u{12c}'), ('I', '\u{307}', '\u{130}'),
+        ('I', '\u{308}', '\u{cf}'), ('I', '\u{309}', '\u{1ec8}'), ('I', '\u{30c}', '\u{1cf}'),
+        ('I', '\u{30f}', '\u{208}'), ('I', '\u{311}', '\u{20a}'), ('I', '\u{323}', '\u{1eca}'),
+        ('I', '\u{328}', '\u{12e}'), ('I', '\u{330}', '\u{1e2c}'), ('J', '\u{302}', '\u{134}'),
+        ('K', '\u{301}', '\u{1e30}'), ('K', '\u{30c}', '\u{1e8}'), ('K', '\u{323}', '\u{1e32}'),
+        ('K', '\u{327}', '\u{136}'), ('K', '\u{331}', '\u{1e34}'), ('L', '\u{301}', '\u{139}'),
+        ('L', '\u{30c}', '\u{13d}'), ('L', '\u{323}', '\u{1e36}'), ('L', '\u{327}', '\u{13b}'),
+        ('L', '\u{32d}', '\u{1e3c}'), ('L', '\u{331}', '\u{1e3a}'), ('M', '\u{301}', '\u{1e3e}'),
+        ('M', '\u{307}', '\u{1e40}'), ('M', '\u{323}', '\u{1e42}'), ('N', '\u{300}', '\u{1f8}'),
+        ('N', '\u{301}', '\u{143}'), ('N', '\u{303}', '\u{d1}'), ('N', '\u{307}', '\u{1e44}'),
+        ('N', '\u{30c}', '\u{147}'), ('N', '\u{323}', '\u{1e46}'), ('N', '\u{327}', '\u{145}'),
+        ('N', '\u{32d}', '\u{1e4a}'), ('N', '\u{331}', '\u{1e48}'), ('O', '\u{300}', '\u{d2}'),
+        ('O', '\u{301}', '\u{d3}'), ('O', '\u{302}', '\u{d4}'), ('O', '\u{303}', '\u{d5}'),
+        ('O', '\u{304}', '\u{14c}'), ('O', '\u{306}', '\u{14e}'), ('O', '\u{307}', '\u{22e}'),
+        ('O', '\u{308}', '\u{d6}'), ('O', '\u{309}', '\u{1ece}'), ('O', '\u{30b}', '\u{150}'),
+        ('O', '\u{30c}', '\u{1d1}'), ('O', '\u{30f}', '\u{20c}'), ('O', '\u{311}', '\u{20e}'),
+        ('O', '\u{31b}', '\u{1a0}'), ('O', '\u{323}', '\u{1ecc}'), ('O', '\u{328}', '\u{1ea}'),
+        ('P', '\u{301}', '\u{1e54}'), ('P', '\u{307}', '\u{1e56}'), ('R', '\u{301}', '\u{154}'),
+        ('R', '\u{307}', '\u{1e58}'), ('R', '\u{30c}', '\u{158}'), ('R', '\u{30f}', '\u{210}'),
+        ('R', '\u{311}', '\u{212}'), ('R', '\u{323}', '\u{1e5a}'), ('R', '\u{327}', '\u{156}'),
+        ('R', '\u{331}', '\u{1e5e}'), ('S', '\u{301}', '\u{15a}'), ('S', '\u{302}', '\u{15c}'),
+        ('S', '\u{307}', '\u{1e60}'), ('S', '\u{30c}', '\u{160}'), ('S', '\u{323}', '\u{1e62}'),
+        ('S', '\u{326}', '\u{218}'), ('S', '\u{327}', '\u{15e}'), ('T', '\u{307}', '\u{1e6a}'),
+        ('T', '\u{30c}', '\u{164}'), ('T', '\u{323}', '\u{1e6c}'), ('T', '\u{326}', '\u{21a}'),
+        ('T', '\u{327}', '\u{162}'), ('T', '\u{32d}', '\u{1e70}'), ('T', '\u{331}', '\u{1e6e}'),
+        ('U', '\u{300}', '\u{d9}'), ('U', '\u{301}', '\u{da}'), ('U', '\u{302}', '\u{db}'),
+        ('U', '\u{303}', '\u{168}'), ('U', '\u{304}', '\u{16a}'), ('U', '\u{306}', '\u{16c}'),
+        ('U', '\u{308}', '\u{dc}'), ('U', '\u{309}', '\u{1ee6}'), ('U', '\u{30a}', '\u{16e}'),
+        ('U', '\u{30b}', '\u{170}'), ('U', '\u{30c}', '\u{1d3}'), ('U', '\u{30f}', '\u{214}'),
+        ('U', '\u{311}', '\u{216}'), ('U', '\u{31b}', '\u{1af}'), ('U', '\u{323}', '\u{1ee4}'),
+        ('U', '\u{324}', '\u{1e72}'), ('U', '\u{328}', '\u{172}'), ('U', '\u{32d}', '\u{1e76}'),
+        ('U', '\u{330}', '\u{1e74}'), ('V', '\u{303}', '\u{1e7c}'), ('V', '\u{323}', '\u{1e7e}'),
+        ('W', '\u{300}', '\u{1e80}'), ('W', '\u{301}', '\u{1e82}'), ('W', '\u{302}', '\u{174}'),
+        ('W', '\u{307}', '\u{1e86}'), ('W', '\u{308}', '\u{1e84}'), ('W', '\u{323}', '\u{1e88}'),
+        ('X', '\u{307}', '\u{1e8a}'), ('X', '\u{308}', '\u{1e8c}'), ('Y', '\u{300}', '\u{1ef2}'),
+        ('Y', '\u{301}', '\u{dd}'), ('Y', '\u{302}', '\u{176}'), ('Y', '\u{303}', '\u{1ef8}'),
+        ('Y', '\u{304}', '\u{232}'), ('Y', '\u{307}', '\u{1e8e}'), ('Y', '\u{308}', '\u{178}'),
+        ('Y', '\u{309}', '\u{1ef6}'), ('Y', '\u{323}', '\u{1ef4}'), ('Z', '\u{301}', '\u{179}'),
+        ('Z', '\u{302}', '\u{1e90}'), ('Z', '\u{307}', '\u{17b}'), ('Z', '\u{30c}', '\u{17d}'),
+        ('Z', '\u{323}', '\u{1e92}'), ('Z', '\u{331}', '\u{1e94}'), ('a', '\u{300}', '\u{e0}'),
+        ('a', '\u{301}', '\u{e1}'), ('a', '\u{302}', '\u{e2}'), ('a', '\u{303}', '\u{e3}'),
+        ('a', '\u{304}', '\u{101}'), ('a', '\u{306}', '\u{103}'), ('a', '\u{307}', '\u{227}'),
+        ('a', '\u{308}', '\u{e4}'), ('a', '\u{309}', '\u{1ea3}'), ('a', '\u{30a}', '\u{e5}'),
+        ('a', '\u{30c}', '\u{1ce}'), ('a', '\u{30f}', '\u{201}'), ('a', '\u{311}', '\u{203}'),
+        ('a', '\u{323}', '\u{1ea1}'), ('a', '\u{325}', '\u{1e01}'), ('a', '\u{328}', '\u{105}'),
+        ('b', '\u{307}', '\u{1e03}'), ('b', '\u{323}', '\u{1e05}'), ('b', '\u{331}', '\u{1e07}'),
+        ('c', '\u{301}', '\u{107}'), ('c', '\u{302}', '\u{109}'), ('c', '\u{307}', '\u{10b}'),
+        ('c', '\u{30c}', '\u{10d}'), ('c', '\u{327}', '\u{e7}'), ('d', '\u{307}', '\u{1e0b}'),
+        ('d', '\u{30c}', '\u{10f}'), ('d', '\u{323}', '\u{1e0d}'), ('d', '\u{327}', '\u{1e11}'),
+        ('d', '\u{32d}', '\u{1e13}'), ('d', '\u{331}', '\u{1e0f}'), ('e', '\u{300}', '\u{e8}'),
+        ('e', '\u{301}', '\u{e9}'), ('e', '\u{302}', '\u{ea}'), ('e', '\u{303}', '\u{1ebd}'),
+        ('e', '\u{304}', '\u{113}'), ('e', '\u{306}', '\u{115}'), ('e', '\u{307}', '\u{117}'),
+        ('e', '\u{308}', '\u{eb}'), ('e', '\u{309}', '\u{1ebb}'), ('e', '\u{30c}', '\u{11b}'),
+        ('e', '\u{30f}', '\u{205}'), ('e', '\u{311}', '\u{207}'), ('e', '\u{323}', '\u{1eb9}'),
+        ('e', '\u{327}', '\u{229}'), ('e', '\u{328}', '\u{119}'), ('e', '\u{32d}', '\u{1e19}'),
+        ('e', '\u{330}', '\u{1e1b}'), ('f', '\u{307}', '\u{1e1f}'), ('g', '\u{301}', '\u{1f5}'),
+        ('g', '\u{302}', '\u{11d}'), ('g', '\u{304}', '\u{1e21}'), ('g', '\u{306}', '\u{11f}'),
+        ('g', '\u{307}', '\u{121}'), ('g', '\u{30c}', '\u{1e7}'), ('g', '\u{327}', '\u{123}'),
+        ('h', '\u{302}', '\u{125}'), ('h', '\u{307}', '\u{1e23}'), ('h', '\u{308}', '\u{1e27}'),
+        ('h', '\u{30c}', '\u{21f}'), ('h', '\u{323}', '\u{1e25}'), ('h', '\u{327}', '\u{1e29}'),
+        ('h', '\u{32e}', '\u{1e2b}'), ('h', '\u{331}', '\u{1e96}'), ('i', '\u{300}', '\u{ec}'),
+        ('i', '\u{301}', '\u{ed}'), ('i', '\u{302}', '\u{ee}'), ('i', '\u{303}', '\u{129}'),
+        ('i', '\u{304}', '\u{12b}'), ('i', '\u{306}', '\u{12d}'), ('i', '\u{308}', '\u{ef}'),
+        ('i', '\u{309}', '\u{1ec9}'), ('i', '\u{30c}', '\u{1d0}'), ('i', '\u{30f}', '\u{209}'),
+        ('i', '\u{311}', '\u{20b}'), ('i', '\u{323}', '\u{1ecb}'), ('i', '\u{328}', '\u{12f}'),
+        ('i', '\u{330}', '\u{1e2d}'), ('j', '\u{302}', '\u{135}'), ('j', '\u{30c}', '\u{1f0}'),
+        ('k', '\u{301}', '\u{1e31}'), ('k', '\u{30c}', '\u{1e9}'), ('k', '\u{323}', '\u{1e33}'),
+        ('k', '\u{327}', '\u{137}'), ('k', '\u{331}', '\u{1e35}'), ('l', '\u{301}', '\u{13a}'),
+        ('l', '\u{30c}', '\u{13e}'), ('l', '\u{323}', '\u{1e37}'), ('l', '\u{327}', '\u{13c}'),
+        ('l', '\u{32d}', '\u{1e3d}'), ('l', '\u{331}', '\u{1e3b}'), ('m', '\u{301}', '\u{1e3f}'),
+        ('m', '\u{307}', '\u{1e41}'), ('m', '\u{323}', '\u{1e43}'), ('n', '\u{300}', '\u{1f9}'),
+        ('n', '\u{301}', '\u{144}'), ('n', '\u{303}', '\u{f1}'), ('n', '\u{307}', '\u{1e45}'),
+        ('n', '\u{30c}', '\u{148}'), ('n', '\u{323}', '\u{1e47}'), ('n', '\u{327}', '\u{146}'),
+        ('n', '\u{32d}', '\u{1e4b}'), ('n', '\u{331}', '\u{1e49}'), ('o', '\u{300}', '\u{f2}'),
+        ('o', '\u{301}', '\u{f3}'), ('o', '\u{302}', '\u{f4}'), ('o', '\u{303}', '\u{f5}'),
+        ('o', '\u{304}', '\u{14d}'), ('o', '\u{306}', '\u{14f}'), ('o', '\u{307}', '\u{22f}'),
+        ('o', '\u{308}', '\u{f6}'), ('o', '\u{309}', '\u{1ecf}'), ('o', '\u{30b}', '\u{151}'),
+        ('o', '\u{30c}', '\u{1d2}'), ('o', '\u{30f}', '\u{20d}'), ('o', '\u{311}', '\u{20f}'),
+        ('o', '\u{31b}', '\u{1a1}'), ('o', '\u{323}', '\u{1ecd}'), ('o', '\u{328}', '\u{1eb}'),
+        ('p', '\u{301}', '\u{1e55}'), ('p', '\u{307}', '\u{1e57}'), ('r', '\u{301}', '\u{155}'),
+        ('r', '\u{307}', '\u{1e59}'), ('r', '\u{30c}', '\u{159}'), ('r', '\u{30f}', '\u{211}'),
+        ('r', '\u{311}', '\u{213}'), ('r', '\u{323}', '\u{1e5b}'), ('r', '\u{327}', '\u{157}'),
+        ('r', '\u{331}', '\u{1e5f}'), ('s', '\u{301}', '\u{15b}'), ('s', '\u{302}', '\u{15d}'),
+        ('s', '\u{307}', '\u{1e61}'), ('s', '\u{30c}', '\u{161}'), ('s', '\u{323}', '\u{1e63}'),
+        ('s', '\u{326}', '\u{219}'), ('s', '\u{327}', '\u{15f}'), ('t', '\u{307}', '\u{1e6b}'),
+        ('t', '\u{308}', '\u{1e97}'), ('t', '\u{30c}', '\u{165}'), ('t', '\u{323}', '\u{1e6d}'),
+        ('t', '\u{326}', '\u{21b}'), ('t', '\u{327}', '\u{163}'), ('t', '\u{32d}', '\u{1e71}'),
+        ('t', '\u{331}', '\u{1e6f}'), ('u', '\u{300}', '\u{f9}'), ('u', '\u{301}', '\u{fa}'),
+        ('u', '\u{302}', '\u{fb}'), ('u', '\u{303}', '\u{169}'), ('u', '\u{304}', '\u{16b}'),
+        ('u', '\u{306}', '\u{16d}'), ('u', '\u{308}', '\u{fc}'), ('u', '\u{309}', '\u{1ee7}'),
+        ('u', '\u{30a}', '\u{16f}'), ('u', '\u{30b}', '\u{171}'), ('u', '\u{30c}', '\u{1d4}'),
+        ('u', '\u{30f}', '\u{215}'), ('u', '\u{311}', '\u{217}'), ('u', '\u{31b}', '\u{1b0}'),
+        ('u', '\u{323}', '\u{1ee5}'), ('u', '\u{324}', '\u{1e73}'), ('u', '\u{328}', '\u{173}'),
+        ('u', '\u{32d}', '\u{1e77}'), ('u', '\u{330}', '\u{1e75}'), ('v', '\u{303}', '\u{1e7d}'),
+        ('v', '\u{323}', '\u{1e7f}'), ('w', '\u{300}', '\u{1e81}'), ('w', '\u{301}', '\u{1e83}'),
+        ('w', '\u{302}', '\u{175}'), ('w', '\u{307}', '\u{1e87}'), ('w', '\u{308}', '\u{1e85}'),
+        ('w', '\u{30a}', '\u{1e98}'), ('w', '\u{323}', '\u{1e89}'), ('x', '\u{307}', '\u{1e8b}'),
+        ('x', '\u{308}', '\u{1e8d}'), ('y', '\u{300}', '\u{1ef3}'), ('y', '\u{301}', '\u{fd}'),
+        ('y', '\u{302}', '\u{177}'), ('y', '\u{303}', '\u{1ef9}'), ('y', '\u{304}', '\u{233}'),
+        ('y', '\u{307}', '\u{1e8f}'), ('y', '\u{308}', '\u{ff}'), ('y', '\u{309}', '\u{1ef7}'),
+        ('y', '\u{30a}', '\u{1e99}'), ('y', '\u{323}', '\u{1ef5}'), ('z', '\u{301}', '\u{17a}'),
+        ('z', '\u{302}', '\u{1e91}'), ('z', '\u{307}', '\u{17c}'), ('z', '\u{30c}', '\u{17e}'),
+        ('z', '\u{323}', '\u{1e93}'), ('z', '\u{331}', '\u{1e95}'),
+        ('\u{a8}', '\u{300}', '\u{1fed}'), ('\u{a8}', '\u{301}', '\u{385}'),
+        ('\u{a8}', '\u{342}', '\u{1fc1}'), ('\u{c2}', '\u{300}', '\u{1ea6}'),
+        ('\u{c2}', '\u{301}', '\u{1ea4}'), ('\u{c2}', '\u{303}', '\u{1eaa}'),
+        ('\u{c2}', '\u{309}', '\u{1ea8}'), ('\u{c4}', '\u{304}', '\u{1de}'),
+        ('\u{c5}', '\u{301}', '\u{1fa}'), ('\u{c6}', '\u{301}', '\u{1fc}'),
+        ('\u{c6}', '\u{304}', '\u{1e2}'), ('\u{c7}', '\u{301}', '\u{1e08}'),
+        ('\u{ca}', '\u{300}', '\u{1ec0}'), ('\u{ca}', '\u{301}', '\u{1ebe}'),
+        ('\u{ca}', '\u{303}', '\u{1ec4}'), ('\u{ca}', '\u{309}', '\u{1ec2}'),
+        ('\u{cf}', '\u{301}', '\u{1e2e}'), ('\u{d4}', '\u{300}', '\u{1ed2}'),
+        ('\u{d4}', '\u{301}', '\u{1ed0}'), ('\u{d4}', '\u{303}', '\u{1ed6}'),
+        ('\u{d4}', '\u{309}', '\u{1ed4}'), ('\u{d5}', '\u{301}', '\u{1e4c}'),
+        ('\u{d5}', '\u{304}', '\u{22c}'), ('\u{d5}', '\u{308}', '\u{1e4e}'),
+        ('\u{d6}', '\u{304}', '\u{22a}'), ('\u{d8}', '\u{301}', '\u{1fe}'),
+        ('\u{dc}', '\u{300}', '\u{1db}'), ('\u{dc}', '\u{301}', '\u{1d7}'),
+        ('\u{dc}', '\u{304}', '\u{1d5}'), ('\u{dc}', '\u{30c}', '\u{1d9}'),
+        ('\u{e2}', '\u{300}', '\u{1ea7}'), ('\u{e2}', '\u{301}', '\u{1ea5}'),
+        ('\u{e2}', '\u{303}', '\u{1eab}'), ('\u{e2}', '\u{309}', '\u{1ea9}'),
+        ('\u{e4}', '\u{304}', '\u{1df}'), ('\u{e5}', '\u{301}', '\u{1fb}'),
+        ('\u{e6}', '\u{301}', '\u{1fd}'), ('\u{e6}', '\u{304}', '\u{1e3}'),
+        ('\u{e7}', '\u{301}', '\u{1e09}'), ('\u{ea}', '\u{300}', '\u{1ec1}'),
+        ('\u{ea}', '\u{301}', '\u{1ebf}'), ('\u{ea}', '\u{303}', '\u{1ec5}'),
+        ('\u{ea}', '\u{309}', '\u{1ec3}'), ('\u{ef}', '\u{301}', '\u{1e2f}'),
+        ('\u{f4}', '\u{300}', '\u{1ed3}'), ('\u{f4}', '\u{301}', '\u{1ed1}'),
+        ('\u{f4}', '\u{303}', '\u{1ed7}'), ('\u{f4}', '\u{309}', '\u{1ed5}'),
+        ('\u{f5}', '\u{301}', '\u{1e4d}'), ('\u{f5}', '\u{304}', '\u{22d}'),
+        ('\u{f5}', '\u{308}', '\u{1e4f}'), ('\u{f6}', '\u{304}', '\u{22b}'),
+        ('\u{f8}', '\u{301}', '\u{1ff}'), ('\u{fc}', '\u{300}', '\u{1dc}'),
+        ('\u{fc}', '\u{301}', '\u{1d8}'), ('\u{fc}', '\u{304}', '\u{1d6}'),
+        ('\u{fc}', '\u{30c}', '\u{1da}'), ('\u{102}', '\u{300}', '\u{1eb0}'),
+        ('\u{102}', '\u{301}', '\u{1eae}'), ('\u{102}', '\u{303}', '\u{1eb4}'),
+        ('\u{102}', '\u{309}', '\u{1eb2}'), ('\u{103}', '\u{300}', '\u{1eb1}'),
+        ('\u{103}', '\u{301}', '\u{1eaf}'), ('\u{103}', '\u{303}', '\u{1eb5}'),
+        ('\u{103}', '\u{309}', '\u{1eb3}'), ('\u{112}', '\u{300}', '\u{1e14}'),
+        ('\u{112}', '\u{301}', '\u{1e16}'), ('\u{113}', '\u{300}', '\u{1e15}'),
+        ('\u{113}', '\u{301}', '\u{1e17}'), ('\u{14c}', '\u{300}', '\u{1e50}'),
+        ('\u{14c}', '\u{301}', '\u{1e52}'), ('\u{14d}', '\u{300}', '\u{1e51}'),
+        ('\u{14d}', '\u{301}', '\u{1e53}'), ('\u{15a}', '\u{307}', '\u{1e64}'),
+        ('\u{15b}', '\u{307}', '\u{1e65}'), ('\u{160}', '\u{307}', '\u{1e66}'),
+        ('\u{161}', '\u{307}', '\u{1e67}'), ('\u{168}', '\u{301}', '\u{1e78}'),
+        ('\u{169}', '\u{301}', '\u{1e79}'), ('\u{16a}', '\u{308}', '\u{1e7a}'),
+        ('\u{16b}', '\u{308}', '\u{1e7b}'), ('\u{17f}', '\u{307}', '\u{1e9b}'),
+        ('\u{1a0}', '\u{300}', '\u{1edc}'), ('\u{1a0}', '\u{301}', '\u{1eda}'),
+        ('\u{1a0}', '\u{303}', '\u{1ee0}'), ('\u{1a0}', '\u{309}', '\u{1ede}'),
+        ('\u{1a0}', '\u{323}', '\u{1ee2}'), ('\u{1a1}', '\u{300}', '\u{1edd}'),
+        ('\u{1a1}', '\u{301}', '\u{1edb}'), ('\u{1a1}', '\u{303}', '\u{1ee1}'),
+        ('\u{1a1}', '\u{309}', '\u{1edf}'), ('\u{1a1}', '\u{323}', '\u{1ee3}'),
+        ('\u{1af}', '\u{300}', '\u{1eea}'), ('\u{1af}', '\u{301}', '\u{1ee8}'),
+        ('\u{1af}', '\u{303}', '\u{1eee}'), ('\u{1af}', '\u{309}', '\u{1eec}'),
+        ('\u{1af}', '\u{323}', '\u{1ef0}'), ('\u{1b0}', '\u{300}', '\u{1eeb}'),
+        ('\u{1b0}', '\u{301}', '\u{1ee9}'), ('\u{1b0}', '\u{303}', '\u{1eef}'),
+        ('\u{1b0}', '\u{309}', '\u{1eed}'), ('\u{1b0}', '\u{323}', '\u{1ef1}'),
+        ('\u{1b7}', '\u{30c}', '\u{1ee}'), ('\u{1ea}', '\u{304}', '\u{1ec}'),
+        ('\u{1eb}', '\u{304}', '\u{1ed}'), ('\u{226}', '\u{304}', '\u{1e0}'),
+        ('\u{227}', '\u{304}', '\u{1e1}'), ('\u{228}', '\u{306}', '\u{1e1c}'),
+        ('\u{229}', '\u{306}', '\u{1e1d}'), ('\u{22e}', '\u{304}', '\u{230}'),
+        ('\u{22f}', '\u{304}', '\u{231}'), ('\u{292}', '\u{30c}', '\u{1ef}'),
+        ('\u{391}', '\u{300}', '\u{1fba}'), ('\u{391}', '\u{301}', '\u{386}'),
+        ('\u{391}', '\u{304}', '\u{1fb9}'), ('\u{391}', '\u{306}', '\u{1fb8}'),
+        ('\u{391}', '\u{313}', '\u{1f08}'), ('\u{391}', '\u{314}', '\u{1f09}'),
+        ('\u{391}', '\u{345}', '\u{1fbc}'), ('\u{395}', '\u{300}', '\u{1fc8}'),
+        ('\u{395}', '\u{301}', '\u{388}'), ('\u{395}', '\u{313}', '\u{1f18}'),
+        ('\u{395}', '\u{314}', '\u{1f19}'), ('\u{397}', '\u{300}', '\u{1fca}'),
+        ('\u{397}', '\u{301}', '\u{389}'), ('\u{397}', '\u{313}', '\u{1f28}'),
+        ('\u{397}', '\u{314}', '\u{1f29}'), ('\u{397}', '\u{345}', '\u{1fcc}'),
+        ('\u{399}', '\u{300}', '\u{1fda}'), ('\u{399}', '\u{301}', '\u{38a}'),
+        ('\u{399}', '\u{304}', '\u{1fd9}'), ('\u{399}', '\u{306}', '\u{1fd8}'),
+        ('\u{399}', '\u{308}', '\u{3aa}'), ('\u{399}', '\u{313}', '\u{1f38}'),
+        ('\u{399}', '\u{314}', '\u{1f39}'), ('\u{39f}', '\u{300}', '\u{1ff8}'),
+        ('\u{39f}', '\u{301}', '\u{38c}'), ('\u{39f}', '\u{313}', '\u{1f48}'),
+        ('\u{39f}', '\u{314}', '\u{1f49}'), ('\u{3a1}', '\u{314}', '\u{1fec}'),
+        ('\u{3a5}', '\u{300}', '\u{1fea}'), ('\u{3a5}', '\u{301}', '\u{38e}'),
+        ('\u{3a5}', '\u{304}', '\u{1fe9}'), ('\u{3a5}', '\u{306}', '\u{1fe8}'),
+        ('\u{3a5}', '\u{308}', '\u{3ab}'), ('\u{3a5}', '\u{314}', '\u{1f59}'),
+        ('\u{3a9}', '\u{300}', '\u{1ffa}'), ('\u{3a9}', '\u{301}', '\u{38f}'),
+        ('\u{3a9}', '\u{313}', '\u{1f68}'), ('\u{3a9}', '\u{314}', '\u{1f69}'),
+        ('\u{3a9}', '\u{345}', '\u{1ffc}'), ('\u{3ac}', '\u{345}', '\u{1fb4}'),
+        ('\u{3ae}', '\u{345}', '\u{1fc4}'), ('\u{3b1}', '\u{300}', '\u{1f70}'),
+        ('\u{3b1}', '\u{301}', '\u{3ac}'), ('\u{3b1}', '\u{304}', '\u{1fb1}'),
+        ('\u{3b1}', '\u{306}', '\u{1fb0}'), ('\u{3b1}', '\u{313}', '\u{1f00}'),
+        ('\u{3b1}', '\u{314}', '\u{1f01}'), ('\u{3b1}', '\u{342}', '\u{1fb6}'),
+        ('\u{3b1}', '\u{345}', '\u{1fb3}'), ('\u{3b5}', '\u{300}', '\u{1f72}'),
+        ('\u{3b5}', '\u{301}', '\u{3ad}'), ('\u{3b5}', '\u{313}', '\u{1f10}'),
+        ('\u{3b5}', '\u{314}', '\u{1f11}'), ('\u{3b7}', '\u{300}', '\u{1f74}'),
+        ('\u{3b7}', '\u{301}', '\u{3ae}'), ('\u{3b7}', '\u{313}', '\u{1f20}'),
+        ('\u{3b7}', '\u{314}', '\u{1f21}'), ('\u{3b7}', '\u{342}', '\u{1fc6}'),
+        ('\u{3b7}', '\u{345}', '\u{1fc3}'), ('\u{3b9}', '\u{300}', '\u{1f76}'),
+        ('\u{3b9}', '\u{301}', '\u{3af}'), ('\u{3b9}', '\u{304}', '\u{1fd1}'),
+        ('\u{3b9}', '\u{306}', '\u{1fd0}'), ('\u{3b9}', '\u{308}', '\u{3ca}'),
+        ('\u{3b9}', '\u{313}', '\u{1f30}'), ('\u{3b9}', '\u{314}', '\u{1f31}'),
+        ('\u{3b9}', '\u{342}', '\u{1fd6}'), ('\u{3bf}', '\u{300}', '\u{1f78}'),
+        ('\u{3bf}', '\u{301}', '\u{3cc}'), ('\u{3bf}', '\u{313}', '\u{1f40}'),
+        ('\u{3bf}', '\u{314}', '\u{1f41}'), ('\u{3c1}', '\u{313}', '\u{1fe4}'),
+        ('\u{3c1}', '\u{314}', '\u{1fe5}'), ('\u{3c5}', '\u{300}', '\u{1f7a}'),
+        ('\u{3c5}', '\u{301}', '\u{3cd}'), ('\u{3c5}', '\u{304}', '\u{1fe1}'),
+        ('\u{3c5}', '\u{306}', '\u{1fe0}'), ('\u{3c5}', '\u{308}', '\u{3cb}'),
+        ('\u{3c5}', '\u{313}', '\u{1f50}'), ('\u{3c5}', '\u{314}', '\u{1f51}'),
+        ('\u{3c5}', '\u{342}', '\u{1fe6}'), ('\u{3c9}', '\u{300}', '\u{1f7c}'),
+        ('\u{3c9}', '\u{301}', '\u{3ce}'), ('\u{3c9}', '\u{313}', '\u{1f60}'),
+        ('\u{3c9}', '\u{314}', '\u{1f61}'), ('\u{3c9}', '\u{342}', '\u{1ff6}'),
+        ('\u{3c9}', '\u{345}', '\u{1ff3}'), ('\u{3ca}', '\u{300}', '\u{1fd2}'),
+        ('\u{3ca}', '\u{301}', '\u{390}'), ('\u{3ca}', '\u{342}', '\u{1fd7}'),
+        ('\u{3cb}', '\u{300}', '\u{1fe2}'), ('\u{3cb}', '\u{301}', '\u{3b0}'),
+        ('\u{3cb}', '\u{342}', '\u{1fe7}'), ('\u{3ce}', '\u{345}', '\u{1ff4}'),
+        ('\u{3d2}', '\u{301}', '\u{3d3}'), ('\u{3d2}', '\u{308}', '\u{3d4}'),
+        ('\u{406}', '\u{308}', '\u{407}'), ('\u{410}', '\u{306}', '\u{4d0}'),
+        ('\u{410}', '\u{308}', '\u{4d2}'), ('\u{413}', '\u{301}', '\u{403}'),
+        ('\u{415}', '\u{300}', '\u{400}'), ('\u{415}', '\u{306}', '\u{4d6}'),
+        ('\u{415}', '\u{308}', '\u{401}'), ('\u{416}', '\u{306}', '\u{4c1}'),
+        ('\u{416}', '\u{308}', '\u{4dc}'), ('\u{417}', '\u{308}', '\u{4de}'),
+        ('\u{418}', '\u{300}', '\u{40d}'), ('\u{418}', '\u{304}', '\u{4e2}'),
+        ('\u{418}', '\u{306}', '\u{419}'), ('\u{418}', '\u{308}', '\u{4e4}'),
+        ('\u{41a}', '\u{301}', '\u{40c}'), ('\u{41e}', '\u{308}', '\u{4e6}'),
+        ('\u{423}', '\u{304}', '\u{4ee}'), ('\u{423}', '\u{306}', '\u{40e}'),
+        ('\u{423}', '\u{308}', '\u{4f0}'), ('\u{423}', '\u{30b}', '\u{4f2}'),
+        ('\u{427}', '\u{308}', '\u{4f4}'), ('\u{42b}', '\u{308}', '\u{4f8}'),
+        ('\u{42d}', '\u{308}', '\u{4ec}'), ('\u{430}', '\u{306}', '\u{4d1}'),
+        ('\u{430}', '\u{308}', '\u{4d3}'), ('\u{433}', '\u{301}', '\u{453}'),
+        ('\u{435}', '\u{300}', '\u{450}'), ('\u{435}', '\u{306}', '\u{4d7}'),
+        ('\u{435}', '\u{308}', '\u{451}'), ('\u{436}', '\u{306}', '\u{4c2}'),
+        ('\u{436}', '\u{308}', '\u{4dd}'), ('\u{437}', '\u{308}', '\u{4df}'),
+        ('\u{438}', '\u{300}', '\u{45d}'), ('\u{438}', '\u{304}', '\u{4e3}'),
+        ('\u{438}', '\u{306}', '\u{439}'), ('\u{438}', '\u{308}', '\u{4e5}'),
+        ('\u{43a}', '\u{301}', '\u{45c}'), ('\u{43e}', '\u{308}', '\u{4e7}'),
+        ('\u{443}', '\u{304}', '\u{4ef}'), ('\u{443}', '\u{306}', '\u{45e}'),
+        ('\u{443}', '\u{308}', '\u{4f1}'), ('\u{443}', '\u{30b}', '\u{4f3}'),
+        ('\u{447}', '\u{308}', '\u{4f5}'), ('\u{44b}', '\u{308}', '\u{4f9}'),
+        ('\u{44d}', '\u{308}', '\u{4ed}'), ('\u{456}', '\u{308}', '\u{457}'),
+        ('\u{474}', '\u{30f}', '\u{476}'), ('\u{475}', '\u{30f}', '\u{477}'),
+        ('\u{4d8}', '\u{308}', '\u{4da}'), ('\u{4d9}', '\u{308}', '\u{4db}'),
+        ('\u{4e8}', '\u{308}', '\u{4ea}'), ('\u{4e9}', '\u{308}', '\u{4eb}'),
+        ('\u{627}', '\u{653}', '\u{622}'), ('\u{627}', '\u{654}', '\u{623}'),
+        ('\u{627}', '\u{655}', '\u{625}'), ('\u{648}', '\u{654}', '\u{624}'),
+        ('\u{64a}', '\u{654}', '\u{626}'), ('\u{6c1}', '\u{654}', '\u{6c2}'),
+        ('\u{6d2}', '\u{654}', '\u{6d3}'), ('\u{6d5}', '\u{654}', '\u{6c0}'),
+        ('\u{928}', '\u{93c}', '\u{929}'), ('\u{930}', '\u{93c}', '\u{931}'),
+        ('\u{933}', '\u{93c}', '\u{934}'), ('\u{9c7}', '\u{9be}', '\u{9cb}'),
+        ('\u{9c7}', '\u{9d7}', '\u{9cc}'), ('\u{b47}', '\u{b3e}', '\u{b4b}'),
+        ('\u{b47}', '\u{b56}', '\u{b48}'), ('\u{b47}', '\u{b57}', '\u{b4c}'),
+        ('\u{b92}', '\u{bd7}', '\u{b94}'), ('\u{bc6}', '\u{bbe}', '\u{bca}'),
+        ('\u{bc6}', '\u{bd7}', '\u{bcc}'), ('\u{bc7}', '\u{bbe}', '\u{bcb}'),
+        ('\u{c46}', '\u{c56}', '\u{c48}'), ('\u{cbf}', '\u{cd5}', '\u{cc0}'),
+        ('\u{cc6}', '\u{cc2}', '\u{cca}'), ('\u{cc6}', '\u{cd5}', '\u{cc7}'),
+        ('\u{cc6}', '\u{cd6}', '\u{cc8}'), ('\u{cca}', '\u{cd5}', '\u{ccb}'),
+        ('\u{d46}', '\u{d3e}', '\u{d4a}'), ('\u{d46}', '\u{d57}', '\u{d4c}'),
+        ('\u{d47}', '\u{d3e}', '\u{d4b}'), ('\u{dd9}', '\u{dca}', '\u{dda}'),
+        ('\u{dd9}', '\u{dcf}', '\u{ddc}'), ('\u{dd9}', '\u{ddf}', '\u{dde}'),
+        ('\u{ddc}', '\u{dca}', '\u{ddd}'), ('\u{1025}', '\u{102e}', '\u{1026}'),
+        ('\u{1b05}', '\u{1b35}', '\u{1b06}'), ('\u{1b07}', '\u{1b35}', '\u{1b08}'),
+        ('\u{1b09}', '\u{1b35}', '\u{1b0a}'), ('\u{1b0b}', '\u{1b35}', '\u{1b0c}'),
+        ('\u{1b0d}', '\u{1b35}', '\u{1b0e}'), ('\u{1b11}', '\u{1b35}', '\u{1b12}'),
+        ('\u{1b3a}', '\u{1b35}', '\u{1b3b}'), ('\u{1b3c}', '\u{1b35}', '\u{1b3d}'),
+        ('\u{1b3e}', '\u{1b35}', '\u{1b40}'), ('\u{1b3f}', '\u{1b35}', '\u{1b41}'),
+        ('\u{1b42}', '\u{1b35}', '\u{1b43}'), ('\u{1e36}', '\u{304}', '\u{1e38}'),
+        ('\u{1e37}', '\u{304}', '\u{1e39}'), ('\u{1e5a}', '\u{304}', '\u{1e5c}'),
+        ('\u{1e5b}', '\u{304}', '\u{1e5d}'), ('\u{1e62}', '\u{307}', '\u{1e68}'),
+        ('\u{1e63}', '\u{307}', '\u{1e69}'), ('\u{1ea0}', '\u{302}', '\u{1eac}'),
+        ('\u{1ea0}', '\u{306}', '\u{1eb6}'), ('\u{1ea1}', '\u{302}', '\u{1ead}'),
+        ('\u{1ea1}', '\u{306}', '\u{1eb7}'), ('\u{1eb8}', '\u{302}', '\u{1ec6}'),
+        ('\u{1eb9}', '\u{302}', '\u{1ec7}'), ('\u{1ecc}', '\u{302}', '\u{1ed8}'),
+        ('\u{1ecd}', '\u{302}', '\u{1ed9}'), ('\u{1f00}', '\u{300}', '\u{1f02}'),
+        ('\u{1f00}', '\u{301}', '\u{1f04}'), ('\u{1f00}', '\u{342}', '\u{1f06}'),
+        ('\u{1f00}', '\u{345}', '\u{1f80}'), ('\u{1f01}', '\u{300}', '\u{1f03}'),
+        ('\u{1f01}', '\u{301}', '\u{1f05}'), ('\u{1f01}', '\u{342}', '\u{1f07}'),
+        ('\u{1f01}', '\u{345}', '\u{1f81}'), ('\u{1f02}', '\u{345}', '\u{1f82}'),
+        ('\u{1f03}', '\u{345}', '\u{1f83}'), ('\u{1f04}', '\u{345}', '\u{1f84}'),
+        ('\u{1f05}', '\u{345}', '\u{1f85}'), ('\u{1f06}', '\u{345}', '\u{1f86}'),
+        ('\u{1f07}', '\u{345}', '\u{1f87}'), ('\u{1f08}', '\u{300}', '\u{1f0a}'),
+        ('\u{1f08}', '\u{301}', '\u{1f0c}'), ('\u{1f08}', '\u{342}', '\u{1f0e}'),
+        ('\u{1f08}', '\u{345}', '\u{1f88}'), ('\u{1f09}', '\u{300}', '\u{1f0b}'),
+        ('\u{1f09}', '\u{301}', '\u{1f0d}'), ('\u{1f09}', '\u{342}', '\u{1f0f}'),
+        ('\u{1f09}', '\u{345}', '\u{1f89}'), ('\u{1f0a}', '\u{345}', '\u{1f8a}'),
+        ('\u{1f0b}', '\u{345}', '\u{1f8b}'), ('\u{1f0c}', '\u{345}', '\u{1f8c}'),
+        ('\u{1f0d}', '\u{345}', '\u{1f8d}'), ('\u{1f0e}', '\u{345}', '\u{1f8e}'),
+        ('\u{1f0f}', '\u{345}', '\u{1f8f}'), ('\u{1f10}', '\u{300}', '\u{1f12}'),
+        ('\u{1f10}', '\u{301}', '\u{1f14}'), ('\u{1f11}', '\u{300}', '\u{1f13}'),
+        ('\u{1f11}', '\u{301}', '\u{1f15}'), ('\u{1f18}', '\u{300}', '\u{1f1a}'),
+        ('\u{1f18}', '\u{301}', '\u{1f1c}'), ('\u{1f19}', '\u{300}', '\u{1f1b}'),
+        ('\u{1f19}', '\u{301}', '\u{1f1d}'), ('\u{1f20}', '\u{300}', '\u{1f22}'),
+        ('\u{1f20}', '\u{301}', '\u{1f24}'), ('\u{1f20}', '\u{342}', '\u{1f26}'),
+        ('\u{1f20}', '\u{345}', '\u{1f90}'), ('\u{1f21}', '\u{300}', '\u{1f23}'),
+        ('\u{1f21}', '\u{301}', '\u{1f25}'), ('\u{1f21}', '\u{342}', '\u{1f27}'),
+        ('\u{1f21}', '\u{345}', '\u{1f91}'), ('\u{1f22}', '\u{345}', '\u{1f92}'),
+        ('\u{1f23}', '\u{345}', '\u{1f93}'), ('\u{1f24}', '\u{345}', '\u{1f94}'),
+        ('\u{1f25}', '\u{345}', '\u{1f95}'), ('\u{1f26}', '\u{345}', '\u{1f96}'),
+        ('\u{1f27}', '\u{345}', '\u{1f97}'), ('\u{1f28}', '\u{300}', '\u{1f2a}'),
+        ('\u{1f28}', '\u{301}', '\u{1f2c}'), ('\u{1f28}', '\u{342}', '\u{1f2e}'),
+        ('\u{1f28}', '\u{345}', '\u{1f98}'), ('\u{1f29}', '\u{300}', '\u{1f2b}'),
+        ('\u{1f29}', '\u{301}', '\u{1f2d}'), ('\u{1f29}', '\u{342}', '\u{1f2f}'),
+        ('\u{1f29}', '\u{345}', '\u{1f99}'), ('\u{1f2a}', '\u{345}', '\u{1f9a}'),
+        ('\u{1f2b}', '\u{345}', '\u{1f9b}'), ('\u{1f2c}', '\u{345}', '\u{1f9c}'),
+        ('\u{1f2d}', '\u{345}', '\u{1f9d}'), ('\u{1f2e}', '\u{345}', '\u{1f9e}'),
+        ('\u{1f2f}', '\u{345}', '\u{1f9f}'), ('\u{1f30}', '\u{300}', '\u{1f32}'),
+        ('\u{1f30}', '\u{301}', '\u{1f34}'), ('\u{1f30}', '\u{342}', '\u{1f36}'),
+        ('\u{1f31}', '\u{300}', '\u{1f33}'), ('\u{1f31}', '\u{301}', '\u{1f35}'),
+        ('\u{1f31}', '\u{342}', '\u{1f37}'), ('\u{1f38}', '\u{300}', '\u{1f3a}'),
+        ('\u{1f38}', '\u{301}', '\u{1f3c}'), ('\u{1f38}', '\u{342}', '\u{1f3e}'),
+        ('\u{1f39}', '\u{300}', '\u{1f3b}'), ('\u{1f39}', '\u{301}', '\u{1f3d}'),
+        ('\u{1f39}', '\u{342}', '\u{1f3f}'), ('\u{1f40}', '\u{300}', '\u{1f42}'),
+        ('\u{1f40}', '\u{301}', '\u{1f44}'), ('\u{1f41}', '\u{300}', '\u{1f43}'),
+        ('\u{1f41}', '\u{301}', '\u{1f45}'), ('\u{1f48}', '\u{300}', '\u{1f4a}'),
+        ('\u{1f48}', '\u{301}', '\u{1f4c}'), ('\u{1f49}', '\u{300}', '\u{1f4b}'),
+        ('\u{1f49}', '\u{301}', '\u{1f4d}'), ('\u{1f50}', '\u{300}', '\u{1f52}'),
+        ('\u{1f50}', '\u{301}', '\u{1f54}'), ('\u{1f50}', '\u{342}', '\u{1f56}'),
+        ('\u{1f51}', '\u{300}', '\u{1f53}'), ('\u{1f51}', '\u{301}', '\u{1f55}'),
+        ('\u{1f51}', '\u{342}', '\u{1f57}'), ('\u{1f59}', '\u{300}', '\u{1f5b}'),
+        ('\u{1f59}', '\u{301}', '\u{1f5d}'), ('\u{1f59}', '\u{342}', '\u{1f5f}'),
+        ('\u{1f60}', '\u{300}', '\u{1f62}'), ('\u{1f60}', '\u{301}', '\u{1f64}'),
+        ('\u{1f60}', '\u{342}', '\u{1f66}'), ('\u{1f60}', '\u{345}', '\u{1fa0}'),
+        ('\u{1f61}', '\u{300}', '\u{1f63}'), ('\u{1f61}', '\u{301}', '\u{1f65}'),
+        ('\u{1f61}', '\u{342}', '\u{1f67}'), ('\u{1f61}', '\u{345}', '\u{1fa1}'),
+        ('\u{1f62}', '\u{345}', '\u{1fa2}'), ('\u{1f63}', '\u{345}', '\u{1fa3}'),
+        ('\u{1f64}', '\u{345}', '\u{1fa4}'), ('\u{1f65}', '\u{345}', '\u{1fa5}'),
+        ('\u{1f66}', '\u{345}', '\u{1fa6}'), ('\u{1f67}', '\u{345}', '\u{1fa7}'),
+        ('\u{1f68}', '\u{300}', '\u{1f6a}'), ('\u{1f68}', '\u{301}', '\u{1f6c}'),
+        ('\u{1f68}', '\u{342}', '\u{1f6e}'), ('\u{1f68}', '\u{345}', '\u{1fa8}'),
+        ('\u{1f69}', '\u{300}', '\u{1f6b}'), ('\u{1f69}', '\u{301}', '\u{1f6d}'),
+        ('\u{1f69}', '\u{342}', '\u{1f6f}'), ('\u{1f69}', '\u{345}', '\u{1fa9}'),
+        ('\u{1f6a}', '\u{345}', '\u{1faa}'), ('\u{1f6b}', '\u{345}', '\u{1fab}'),
+        ('\u{1f6c}', '\u{345}', '\u{1fac}'), ('\u{1f6d}', '\u{345}', '\u{1fad}'),
+        ('\u{1f6e}', '\u{345}', '\u{1fae}'), ('\u{1f6f}', '\u{345}', '\u{1faf}'),
+        ('\u{1f70}', '\u{345}', '\u{1fb2}'), ('\u{1f74}', '\u{345}', '\u{1fc2}'),
+        ('\u{1f7c}', '\u{345}', '\u{1ff2}'), ('\u{1fb6}', '\u{345}', '\u{1fb7}'),
+        ('\u{1fbf}', '\u{300}', '\u{1fcd}'), ('\u{1fbf}', '\u{301}', '\u{1fce}'),
+        ('\u{1fbf}', '\u{342}', '\u{1fcf}'), ('\u{1fc6}', '\u{345}', '\u{1fc7}'),
+        ('\u{1ff6}', '\u{345}', '\u{1ff7}'), ('\u{1ffe}', '\u{300}', '\u{1fdd}'),
+        ('\u{1ffe}', '\u{301}', '\u{1fde}'), ('\u{1ffe}', '\u{342}', '\u{1fdf}'),
+        ('\u{2190}', '\u{338}', '\u{219a}'), ('\u{2192}', '\u{338}', '\u{219b}'),
+        ('\u{2194}', '\u{338}', '\u{21ae}'), ('\u{21d0}', '\u{338}', '\u{21cd}'),
+        ('\u{21d2}', '\u{338}', '\u{21cf}'), ('\u{21d4}', '\u{338}', '\u{21ce}'),
+        ('\u{2203}', '\u{338}', '\u{2204}'), ('\u{2208}', '\u{338}', '\u{2209}'),
+        ('\u{220b}', '\u{338}', '\u{220c}'), ('\u{2223}', '\u{338}', '\u{2224}'),
+        ('\u{2225}', '\u{338}', '\u{2226}'), ('\u{223c}', '\u{338}', '\u{2241}'),
+        ('\u{2243}', '\u{338}', '\u{2244}'), ('\u{2245}', '\u{338}', '\u{2247}'),
+        ('\u{2248}', '\u{338}', '\u{2249}'), ('\u{224d}', '\u{338}', '\u{226d}'),
+        ('\u{2261}', '\u{338}', '\u{2262}'), ('\u{2264}', '\u{338}', '\u{2270}'),
+        ('\u{2265}', '\u{338}', '\u{2271}'), ('\u{2272}', '\u{338}', '\u{2274}'),
+        ('\u{2273}', '\u{338}', '\u{2275}'), ('\u{2276}', '\u{338}', '\u{2278}'),
+        ('\u{2277}', '\u{338}', '\u{2279}'), ('\u{227a}', '\u{338}', '\u{2280}'),
+        ('\u{227b}', '\u{338}', '\u{2281}'), ('\u{227c}', '\u{338}', '\u{22e0}'),
+        ('\u{227d}', '\u{338}', '\u{22e1}'), ('\u{2282}', '\u{338}', '\u{2284}'),
+        ('\u{2283}', '\u{338}', '\u{2285}'), ('\u{2286}', '\u{338}', '\u{2288}'),
+        ('\u{2287}', '\u{338}', '\u{2289}'), ('\u{2291}', '\u{338}', '\u{22e2}'),
+        ('\u{2292}', '\u{338}', '\u{22e3}'), ('\u{22a2}', '\u{338}', '\u{22ac}'),
+        ('\u{22a8}', '\u{338}', '\u{22ad}'), ('\u{22a9}', '\u{338}', '\u{22ae}'),
+        ('\u{22ab}', '\u{338}', '\u{22af}'), ('\u{22b2}', '\u{338}', '\u{22ea}'),
+        ('\u{22b3}', '\u{338}', '\u{22eb}'), ('\u{22b4}', '\u{338}', '\u{22ec}'),
+        ('\u{22b5}', '\u{338}', '\u{22ed}'), ('\u{3046}', '\u{3099}', '\u{3094}'),
+        ('\u{304b}', '\u{3099}', '\u{304c}'), ('\u{304d}', '\u{3099}', '\u{304e}'),
+        ('\u{304f}', '\u{3099}', '\u{3050}'), ('\u{3051}', '\u{3099}', '\u{3052}'),
+        ('\u{3053}', '\u{3099}', '\u{3054}'), ('\u{3055}', '\u{3099}', '\u{3056}'),
+        ('\u{3057}', '\u{3099}', '\u{3058}'), ('\u{3059}', '\u{3099}', '\u{305a}'),
+        ('\u{305b}', '\u{3099}', '\u{305c}'), ('\u{305d}', '\u{3099}', '\u{305e}'),
+        ('\u{305f}', '\u{3099}', '\u{3060}'), ('\u{3061}', '\u{3099}', '\u{3062}'),
+        ('\u{3064}', '\u{3099}', '\u{3065}'), ('\u{3066}', '\u{3099}', '\u{3067}'),
+        ('\u{3068}', '\u{3099}', '\u{3069}'), ('\u{306f}', '\u{3099}', '\u{3070}'),
+        ('\u{306f}', '\u{309a}', '\u{3071}'), ('\u{3072}', '\u{3099}', '\u{3073}'),
+        ('\u{3072}', '\u{309a}', '\u{3074}'), ('\u{3075}', '\u{3099}', '\u{3076}'),
+        ('\u{3075}', '\u{309a}', '\u{3077}'), ('\u{3078}', '\u{3099}', '\u{3079}'),
+        ('\u{3078}', '\u{309a}', '\u{307a}'), ('\u{307b}', '\u{3099}', '\u{307c}'),
+        ('\u{307b}', '\u{309a}', '\u{307d}'), ('\u{309d}', '\u{3099}', '\u{309e}'),
+        ('\u{30a6}', '\u{3099}', '\u{30f4}'), ('\u{30ab}', '\u{3099}', '\u{30ac}'),
+        ('\u{30ad}', '\u{3099}', '\u{30ae}'), ('\u{30af}', '\u{3099}', '\u{30b0}'),
+        ('\u{30b1}', '\u{3099}', '\u{30b2}'), ('\u{30b3}', '\u{3099}', '\u{30b4}'),
+        ('\u{30b5}', '\u{3099}', '\u{30b6}'), ('\u{30b7}', '\u{3099}', '\u{30b8}'),
+        ('\u{30b9}', '\u{3099}', '\u{30ba}'), ('\u{30bb}', '\u{3099}', '\u{30bc}'),
+        ('\u{30bd}', '\u{3099}', '\u{30be}'), ('\u{30bf}', '\u{3099}', '\u{30c0}'),
+        ('\u{30c1}', '\u{3099}', '\u{30c2}'), ('\u{30c4}', '\u{3099}', '\u{30c5}'),
+        ('\u{30c6}', '\u{3099}', '\u{30c7}'), ('\u{30c8}', '\u{3099}', '\u{30c9}'),
+        ('\u{30cf}', '\u{3099}', '\u{30d0}'), ('\u{30cf}', '\u{309a}', '\u{30d1}'),
+        ('\u{30d2}', '\u{3099}', '\u{30d3}'), ('\u{30d2}', '\u{309a}', '\u{30d4}'),
+        ('\u{30d5}', '\u{3099}', '\u{30d6}'), ('\u{30d5}', '\u{309a}', '\u{30d7}'),
+        ('\u{30d8}', '\u{3099}', '\u{30d9}'), ('\u{30d8}', '\u{309a}', '\u{30da}'),
+        ('\u{30db}', '\u{3099}', '\u{30dc}'), ('\u{30db}', '\u{309a}', '\u{30dd}'),
+        ('\u{30ef}', '\u{3099}', '\u{30f7}'), ('\u{30f0}', '\u{3099}', '\u{30f8}'),
+        ('\u{30f1}', '\u{3099}', '\u{30f9}'), ('\u{30f2}', '\u{3099}', '\u{30fa}'),
+        ('\u{30fd}', '\u{3099}', '\u{30fe}'), ('\u{11099}', '\u{110ba}', '\u{1109a}'),
+        ('\u{1109b}', '\u{110ba}', '\u{1109c}'), ('\u{110a5}', '\u{110ba}', '\u{110ab}'),
+        ('\u{11131}', '\u{11127}', '\u{1112e}'), ('\u{11132}', '\u{11127}', '\u{1112f}'),
+        ('\u{11347}', '\u{1133e}', '\u{1134b}'), ('\u{11347}', '\u{11357}', '\u{1134c}'),
+        ('\u{114b9}', '\u{114b0}', '\u{114bc}'), ('\u{114b9}', '\u{114ba}', '\u{114bb}'),
+        ('\u{114b9}', '\u{114bd}', '\u{114be}'), ('\u{115b8}', '\u{115af}', '\u{115ba}'),
+        ('\u{115b9}', '\u{115af}', '\u{115bb}'), ('\u{11935}', '\u{11930}', '\u{11938}'),
+    ];
+}
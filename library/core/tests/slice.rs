@@ -2495,6 +2495,89 @@ fn slice_rsplit_once() {
     assert_eq!(v.rsplit_once(|&x| x == 0), None);
 }
 
+#[test]
+fn slice_find_subslice() {
+    let v = b"abaabaab";
+    assert_eq!(v.find_subslice(b"aab"), Some(2));
+    assert_eq!(v.rfind_subslice(b"aab"), Some(5));
+    assert_eq!(v.find_subslice(b"abc"), None);
+    assert_eq!(v.rfind_subslice(b"abc"), None);
+    assert_eq!(v.find_subslice(b""), Some(0));
+    assert_eq!(v.rfind_subslice(b""), Some(8));
+    assert_eq!(b"ab".find_subslice(b"abc"), None);
+    assert!(v.contains_subslice(b"baab"));
+    assert!(!v.contains_subslice(b"bb"));
+
+    // Slices of other types than bytes are searched naively.
+    let v = [1, 2, 1, 1, 2, 1, 1, 2];
+    assert_eq!(v.find_subslice(&[1, 1, 2]), Some(2));
+    assert_eq!(v.rfind_subslice(&[1, 1, 2]), Some(5));
+    assert_eq!(v.find_subslice(&[3]), None);
+    assert_eq!(v.rfind_subslice(&[]), Some(8));
+}
+
+#[test]
+fn slice_split_subslice() {
+    fn split<'a>(v: &'a [u8], sep: &[u8]) -> Vec<&'a [u8]> {
+        v.split_subslice(sep).collect()
+    }
+    fn rsplit<'a>(v: &'a [u8], sep: &[u8]) -> Vec<&'a [u8]> {
+        v.rsplit_subslice(sep).collect()
+    }
+
+    assert_eq!(split(b"a, b,, c", b", "), [&b"a"[..], b"b,", b"c"]);
+    assert_eq!(rsplit(b"a, b,, c", b", "), [&b"c"[..], b"b,", b"a"]);
+    assert_eq!(split(b", a, ", b", "), [&b""[..], b"a", b""]);
+    assert_eq!(split(b"", b", "), [&b""[..]]);
+    assert_eq!(split(b"ab", b""), [&b""[..], b"a", b"b", b""]);
+    assert_eq!(rsplit(b"ab", b""), [&b""[..], b"b", b"a", b""]);
+    assert_eq!(split(b"aaaaa", b"aa"), [&b""[..], b"", b"a"]);
+
+    // An overlapping separator is matched differently from each end.
+    assert_eq!(split(b"baaab", b"aa"), [&b"b"[..], b"ab"]);
+    assert_eq!(rsplit(b"baaab", b"aa"), [&b"b"[..], b"ba"]);
+
+    let v = [1, 0, 0, 2, 0, 3];
+    let parts: Vec<_> = v.split_subslice(&[0, 0]).collect();
+    assert_eq!(parts, [&[1][..], &[2, 0, 3]]);
+    let parts: Vec<_> = v.rsplit_subslice(&[0]).collect();
+    assert_eq!(parts, [&[3][..], &[2], &[], &[1]]);
+}
+
+#[test]
+fn slice_splitn_subslice() {
+    let v = b"a::b::c";
+    let parts: Vec<_> = v.splitn_subslice(2, b"::").collect();
+    assert_eq!(parts, [&b"a"[..], b"b::c"]);
+    let parts: Vec<_> = v.rsplitn_subslice(2, b"::").collect();
+    assert_eq!(parts, [&b"c"[..], b"a::b"]);
+    let parts: Vec<_> = v.splitn_subslice(5, b"::").collect();
+    assert_eq!(parts, [&b"a"[..], b"b", b"c"]);
+    assert_eq!(v.splitn_subslice(1, b"::").collect::<Vec<_>>(), [v]);
+    assert_eq!(v.rsplitn_subslice(0, b"::").next(), None);
+}
+
+#[test]
+fn slice_split_once_subslice() {
+    let v = b"key=value=1";
+    assert_eq!(v.split_once_subslice(b"="), Some((&b"key"[..], &b"value=1"[..])));
+    assert_eq!(v.rsplit_once_subslice(b"="), Some((&b"key=value"[..], &b"1"[..])));
+    assert_eq!(v.split_once_subslice(b"key"), Some((&b""[..], &b"=value=1"[..])));
+    assert_eq!(v.split_once_subslice(b":"), None);
+    assert_eq!(v.rsplit_once_subslice(b":"), None);
+}
+
+#[test]
+fn slice_trim_matches_subslice() {
+    let v = b"ababxabab";
+    assert_eq!(v.trim_start_matches_subslice(b"ab"), b"xabab");
+    assert_eq!(v.trim_end_matches_subslice(b"ab"), b"ababx");
+    assert_eq!(v.trim_matches_subslice(b"ab"), b"x");
+    assert_eq!(v.trim_matches_subslice(b"ba"), &v[..]);
+    assert_eq!(v.trim_matches_subslice(b""), &v[..]);
+    assert_eq!(b"abab".trim_matches_subslice(b"ab"), b"");
+}
+
 macro_rules! take_tests {
     (slice: &[], $($tts:tt)*) => {
         take_tests!(ty: &[()], slice: &[], $($tts)*);
@@ -93,9 +93,10 @@
 use crate::ffi::c_void;
 use crate::fmt;
 use crate::panic::UnwindSafe;
+use crate::path::PathBuf;
 use crate::sync::atomic::{AtomicUsize, Ordering::Relaxed};
 use crate::sync::LazyLock;
-use crate::sys_common::backtrace::{lock, output_filename, set_image_base};
+use crate::sys_common::backtrace::{filename_path, lock, output_filename, set_image_base};
 
 /// A captured OS thread stack backtrace.
 ///
@@ -159,7 +160,12 @@ enum RawFrame {
     Fake,
 }
 
-struct BacktraceSymbol {
+/// A symbol of a [`BacktraceFrame`], resolved from its instruction pointer.
+///
+/// A frame may have several symbols when functions were inlined into it, in
+/// which case the innermost function comes first.
+#[unstable(feature = "backtrace_frames", issue = "79676")]
+pub struct BacktraceSymbol {
     name: Option<Vec<u8>>,
     filename: Option<BytesOrWide>,
     lineno: Option<u32>,
@@ -207,6 +213,7 @@ fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+#[unstable(feature = "backtrace_frames", issue = "79676")]
 impl fmt::Debug for BacktraceSymbol {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         // FIXME: improve formatting: https://github.com/rust-lang/rust/issues/65280
@@ -233,14 +240,20 @@ fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+impl BytesOrWide {
+    fn as_bytes_or_wide(&self) -> BytesOrWideString<'_> {
+        match self {
+            BytesOrWide::Bytes(w) => BytesOrWideString::Bytes(w),
+            BytesOrWide::Wide(w) => BytesOrWideString::Wide(w),
+        }
+    }
+}
+
 impl fmt::Debug for BytesOrWide {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         output_filename(
             fmt,
-            match self {
-                BytesOrWide::Bytes(w) => BytesOrWideString::Bytes(w),
-                BytesOrWide::Wide(w) => BytesOrWideString::Wide(w),
-            },
+            self.as_bytes_or_wide(),
             backtrace_rs::PrintFmt::Short,
             crate::env::current_dir().as_ref().ok(),
         )
@@ -293,7 +306,7 @@ pub fn capture() -> Backtrace {
         if !Backtrace::enabled() {
             return Backtrace { inner: Inner::Disabled };
         }
-        Backtrace::create(Backtrace::capture as usize)
+        Backtrace::create(Backtrace::capture as usize, None)
     }
 
     /// Forcibly captures a full backtrace, regardless of environment variable
@@ -309,7 +322,73 @@ pub fn capture() -> Backtrace {
     #[stable(feature = "backtrace", since = "1.65.0")]
     #[inline(never)] // want to make sure there's a frame here to remove
     pub fn force_capture() -> Backtrace {
-        Backtrace::create(Backtrace::force_capture as usize)
+        Backtrace::create(Backtrace::force_capture as usize, None)
+    }
+
+    /// Captures a stack backtrace of the current thread, starting `depth`
+    /// frames above the caller.
+    ///
+    /// With a `depth` of 0, the first frame of the backtrace is the function
+    /// calling this one, with a `depth` of 1 it's the caller of that function,
+    /// and so on. This is useful to leave out the frames of error handling or
+    /// reporting functions. Unlike the frames that [`Backtrace::capture`]
+    /// hides from the short format, the skipped frames are also left out of
+    /// [`Backtrace::frames`] and of the full format.
+    ///
+    /// The frames are counted from the frame of this function, so if it can't
+    /// be found in the backtrace, which may happen on platforms where the
+    /// unwinder reports imprecise addresses, `depth` is ignored and none of
+    /// the frames are skipped.
+    ///
+    /// Like [`Backtrace::capture`], this captures a backtrace only if enabled
+    /// by the `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE` environment variables.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(backtrace_frames)]
+    /// use std::backtrace::Backtrace;
+    ///
+    /// fn report(message: &str) {
+    ///     // Start the backtrace at the caller of `report`.
+    ///     let backtrace = Backtrace::capture_from(1);
+    ///     eprintln!("{message}\n{backtrace}");
+    /// }
+    ///
+    /// report("something went wrong");
+    /// ```
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    #[inline(never)] // want to make sure there's a frame here to remove
+    pub fn capture_from(depth: usize) -> Backtrace {
+        if !Backtrace::enabled() {
+            return Backtrace { inner: Inner::Disabled };
+        }
+        Backtrace::create(Backtrace::capture_from as usize, Some(depth))
+    }
+
+    /// Forcibly captures a stack backtrace of the current thread, starting
+    /// `depth` frames above the caller, regardless of environment variable
+    /// configuration.
+    ///
+    /// See [`Backtrace::capture_from`] for the meaning of `depth`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(backtrace_frames)]
+    /// use std::backtrace::Backtrace;
+    ///
+    /// let backtrace = Backtrace::force_capture_from(0);
+    /// for frame in backtrace.frames() {
+    ///     for symbol in frame.symbols() {
+    ///         println!("{:?} at {:?}:{:?}", symbol.name(), symbol.filename(), symbol.lineno());
+    ///     }
+    /// }
+    /// ```
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    #[inline(never)] // want to make sure there's a frame here to remove
+    pub fn force_capture_from(depth: usize) -> Backtrace {
+        Backtrace::create(Backtrace::force_capture_from as usize, Some(depth))
     }
 
     /// Forcibly captures a disabled backtrace, regardless of environment
@@ -321,8 +400,10 @@ pub const fn disabled() -> Backtrace {
     }
 
     // Capture a backtrace which start just before the function addressed by
-    // `ip`
-    fn create(ip: usize) -> Backtrace {
+    // `ip`. If `skip` is set, that many more frames are skipped, and the
+    // frames before the start are dropped. If the function addressed by `ip`
+    // isn't found, `skip` is ignored and all the frames are kept.
+    fn create(ip: usize, skip: Option<usize>) -> Backtrace {
         let _lock = lock();
         let mut frames = Vec::new();
         let mut actual_start = None;
@@ -346,6 +427,10 @@ fn create(ip: usize) -> Backtrace {
         let inner = if frames.is_empty() {
             Inner::Unsupported
         } else {
+            if let (Some(skip), Some(start)) = (skip, actual_start) {
+                frames.drain(..start.saturating_add(skip).min(frames.len()));
+                actual_start = Some(0);
+            }
             Inner::Captured(LazyLock::new(lazy_resolve(Capture {
                 actual_start: actual_start.unwrap_or(0),
                 frames,
@@ -378,6 +463,85 @@ pub fn frames(&'a self) -> &'a [BacktraceFrame] {
     }
 }
 
+impl BacktraceFrame {
+    /// Returns the instruction pointer of this frame.
+    ///
+    /// This is usually the address of the next instruction to run in the
+    /// frame, which is the return address of the call for all frames but the
+    /// first.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn ip(&self) -> *mut c_void {
+        self.frame.ip()
+    }
+
+    /// Returns the starting address of the function of this frame.
+    ///
+    /// This may not be available on all platforms, in which case the
+    /// instruction pointer is returned instead.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn symbol_address(&self) -> *mut c_void {
+        self.frame.symbol_address()
+    }
+
+    /// Returns the base address of the module, that is the executable or
+    /// shared library, containing the function of this frame.
+    ///
+    /// Returns `None` if it isn't known, which is currently the case on most
+    /// platforms but Windows.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn module_base_address(&self) -> Option<*mut c_void> {
+        self.frame.module_base_address()
+    }
+
+    /// Returns the symbols of this frame.
+    ///
+    /// This is empty if the symbols couldn't be resolved, for instance
+    /// because the debug information is missing, and may contain several
+    /// symbols if functions were inlined into the frame.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn symbols(&self) -> &[BacktraceSymbol] {
+        &self.symbols
+    }
+}
+
+impl BacktraceSymbol {
+    /// Returns the demangled name of the function of this symbol, without the
+    /// hash that the legacy mangling scheme adds to it.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn name(&self) -> Option<String> {
+        self.name.as_ref().map(|b| format!("{:#}", backtrace_rs::SymbolName::new(b)))
+    }
+
+    /// Returns the path of the source file containing this symbol.
+    ///
+    /// Returns `None` if it isn't known or can't be represented on this
+    /// platform.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn filename(&self) -> Option<PathBuf> {
+        self.filename.as_ref().and_then(|b| filename_path(b.as_bytes_or_wide())).map(Into::into)
+    }
+
+    /// Returns the line number of this symbol in its source file.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn lineno(&self) -> Option<u32> {
+        self.lineno
+    }
+
+    /// Returns the column number of this symbol in its source file.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn colno(&self) -> Option<u32> {
+        self.colno
+    }
+}
+
 #[stable(feature = "backtrace", since = "1.65.0")]
 impl fmt::Display for Backtrace {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -413,10 +577,7 @@ fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
                     f.frame().print_raw_with_column(
                         frame.frame.ip(),
                         symbol.name.as_ref().map(|b| backtrace_rs::SymbolName::new(b)),
-                        symbol.filename.as_ref().map(|b| match b {
-                            BytesOrWide::Bytes(w) => BytesOrWideString::Bytes(w),
-                            BytesOrWide::Wide(w) => BytesOrWideString::Wide(w),
-                        }),
+                        symbol.filename.as_ref().map(BytesOrWide::as_bytes_or_wide),
                         symbol.lineno,
                         symbol.colno,
                     )?;
@@ -470,4 +631,20 @@ fn ip(&self) -> *mut c_void {
             RawFrame::Fake => crate::ptr::invalid_mut(1),
         }
     }
+
+    fn symbol_address(&self) -> *mut c_void {
+        match self {
+            RawFrame::Actual(frame) => frame.symbol_address(),
+            #[cfg(test)]
+            RawFrame::Fake => crate::ptr::invalid_mut(1),
+        }
+    }
+
+    fn module_base_address(&self) -> Option<*mut c_void> {
+        match self {
+            RawFrame::Actual(frame) => frame.module_base_address(),
+            #[cfg(test)]
+            RawFrame::Fake => None,
+        }
+    }
 }
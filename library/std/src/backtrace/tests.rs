@@ -93,6 +93,46 @@ fn test_frames() {
     assert!(iter.all(|(f, e)| format!("{f:#?}") == *e));
 }
 
+#[test]
+fn test_symbol_accessors() {
+    let frames = generate_fake_frames();
+
+    let symbol = &frames[0].symbols()[0];
+    assert_eq!(symbol.name().as_deref(), Some("std::backtrace::Backtrace::create"));
+    assert_eq!(symbol.filename(), Some(PathBuf::from("rust/backtrace.rs")));
+    assert_eq!(symbol.lineno(), Some(100));
+    assert_eq!(symbol.colno(), None);
+
+    let symbol = &frames[1].symbols()[0];
+    assert_eq!(symbol.name().as_deref(), Some("__rust_maybe_catch_panic"));
+    assert_eq!(symbol.filename(), None);
+
+    let names: Vec<_> = frames[2].symbols().iter().map(|s| s.name().unwrap()).collect();
+    assert_eq!(names, ["std::rt::lang_start_internal", "std::rt::lang_start"]);
+    assert_eq!(frames[2].symbols()[0].colno(), Some(5));
+}
+
+#[test]
+#[cfg_attr(any(miri, target_os = "emscripten"), ignore)]
+fn test_force_capture_from() {
+    #[inline(never)]
+    fn capture(depth: usize) -> Backtrace {
+        Backtrace::force_capture_from(depth)
+    }
+
+    let outer = capture(1);
+    let inner = capture(0);
+    if inner.status() != BacktraceStatus::Captured {
+        return;
+    }
+
+    // The first frame of `inner` is in `capture`, and the next one is in this
+    // test, like the first frame of `outer`.
+    assert_eq!(inner.frames().len(), outer.frames().len() + 1);
+    assert_eq!(inner.frames()[1].symbol_address(), outer.frames()[0].symbol_address());
+    assert!(Backtrace::force_capture_from(usize::MAX).frames().is_empty());
+}
+
 #[test]
 fn backtrace_unwind_safe() {
     fn assert_unwind_safe<T: UnwindSafe + RefUnwindSafe>() {}
@@ -18,6 +18,7 @@
 use crate::sealed::Sealed;
 use crate::sync::Arc;
 use crate::sys::fs as fs_imp;
+use crate::sys_common::fs::ResolveOptions;
 use crate::sys_common::{AsInner, AsInnerMut, FromInner, IntoInner};
 use crate::time::SystemTime;
 
@@ -229,6 +230,142 @@ pub struct DirBuilder {
     recursive: bool,
 }
 
+/// A handle to an open directory, which resolves paths relative to it.
+///
+/// Unlike paths joined to the path of a directory, the paths relative to a `Dir` keep referring
+/// to the same directory when it is renamed, or when one of its ancestors is replaced by a
+/// symlink. This avoids time-of-check to time-of-use races when working with the files of a
+/// directory that can be modified concurrently. The [`DirOptions`] used to open the directory
+/// can additionally prevent the paths from following symlinks, or from escaping the directory.
+///
+/// The directory is closed when the handle is dropped.
+///
+/// # Platform-specific behavior
+///
+/// This type is currently only supported on Unix platforms that provide the `openat` family of
+/// functions. On other platforms, opening a directory returns an error of kind
+/// [`ErrorKind::Unsupported`]. Note that this [may change in the future][changes].
+///
+/// [`ErrorKind::Unsupported`]: io::ErrorKind::Unsupported
+/// [changes]: io#platform-specific-behavior
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(fs_dir)]
+/// use std::fs::{Dir, File};
+/// use std::io::Write;
+///
+/// fn main() -> std::io::Result<()> {
+///     let dir = Dir::open("data")?;
+///     dir.create_dir("logs")?;
+///     let mut log = dir.open_file("logs/today.txt", File::options().create(true).append(true))?;
+///     writeln!(log, "started")?;
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "fs_dir", issue = "none")]
+pub struct Dir {
+    inner: fs_imp::DirHandle,
+}
+
+/// Options which can be used to configure how a [`Dir`] is opened, and how the paths relative
+/// to it are resolved.
+///
+/// By default, the paths relative to a directory are resolved like the paths passed to the
+/// functions of this module: they can follow symlinks, be absolute, and contain `..` components
+/// that leave the directory.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(fs_dir)]
+/// use std::fs::DirOptions;
+///
+/// fn main() -> std::io::Result<()> {
+///     let root = DirOptions::new().beneath(true).open("/srv/files")?;
+///     let metadata = root.metadata("user/report.pdf")?;
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "fs_dir", issue = "none")]
+#[derive(Clone, Debug)]
+pub struct DirOptions {
+    resolve: ResolveOptions,
+}
+
+/// An iterator over the entries of a directory and of its subdirectories, recursively.
+///
+/// This struct is created by [`walk_dir`] and [`WalkDirOptions::walk`]. The entries of the
+/// starting directory have a depth of 1, their own entries a depth of 2, and so on. The starting
+/// directory itself is not returned.
+///
+/// By default, the entries of a subdirectory are returned right after the subdirectory itself,
+/// in the order returned by [`read_dir`], and symlinks are not followed.
+///
+/// # Errors
+///
+/// An error reading a directory or one of its entries is returned by the iterator, which then
+/// carries on with the next entries. The error of a subdirectory that can't be read is returned
+/// right after this subdirectory, or right before it when [its contents come first].
+///
+/// [its contents come first]: WalkDirOptions::contents_first
+#[unstable(feature = "fs_walk_dir", issue = "none")]
+#[derive(Debug)]
+pub struct WalkDir {
+    options: WalkDirOptions,
+    /// The directories being read, the innermost one last.
+    stack: Vec<WalkLevel>,
+    /// The path of the directory that was just returned, whose entries are returned next.
+    descend: Option<PathBuf>,
+    /// A directory that is returned right after the error of reading it, and its depth.
+    deferred: Option<(DirEntry, usize)>,
+    /// The depth of the last returned entry.
+    depth: usize,
+}
+
+/// A directory being read by [`WalkDir`].
+#[derive(Debug)]
+struct WalkLevel {
+    entries: WalkEntries,
+    /// The entry of the directory, which is returned after its contents when they come first.
+    dir: Option<DirEntry>,
+    /// The canonical path of the directory, to detect symlink loops.
+    canonical: Option<PathBuf>,
+}
+
+#[derive(Debug)]
+enum WalkEntries {
+    Unsorted(ReadDir),
+    Sorted(crate::vec::IntoIter<io::Result<DirEntry>>),
+    Skipped,
+}
+
+/// Options which can be used to configure how a directory is walked by [`WalkDir`].
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(fs_walk_dir)]
+/// use std::fs::WalkDirOptions;
+///
+/// fn main() -> std::io::Result<()> {
+///     for entry in WalkDirOptions::new().max_depth(2).sort_by_file_name(true).walk("src")? {
+///         println!("{}", entry?.path().display());
+///     }
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "fs_walk_dir", issue = "none")]
+#[derive(Clone, Debug)]
+pub struct WalkDirOptions {
+    min_depth: usize,
+    max_depth: usize,
+    follow_symlinks: bool,
+    sort_by_file_name: bool,
+    contents_first: bool,
+}
+
 /// Read the entire contents of a file into a bytes vector.
 ///
 /// This is a convenience function for using [`File::open`] and [`read_to_end`]
@@ -708,6 +845,225 @@ pub fn set_times(&self, times: FileTimes) -> io::Result<()> {
     pub fn set_modified(&self, time: SystemTime) -> io::Result<()> {
         self.set_times(FileTimes::new().set_modified(time))
     }
+
+    /// Acquires an exclusive advisory lock on the file, blocking until it can be acquired.
+    ///
+    /// At most one handle can hold an exclusive lock on a file, and no handle can hold a shared
+    /// lock on it at the same time. The lock belongs to this handle: it is released by
+    /// [`unlock`], or when the handle is dropped, including the handles duplicated from it with
+    /// [`try_clone`], which share its lock.
+    ///
+    /// Advisory locks only exclude the other locks: they don't prevent the file from being read
+    /// or written through a handle that doesn't take them. Acquiring a lock that this handle
+    /// already holds, or converting a shared lock to an exclusive one, is not guaranteed to be
+    /// atomic, and may deadlock on some platforms.
+    ///
+    /// [`unlock`]: File::unlock
+    /// [`try_clone`]: File::try_clone
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `flock` function on Unix with the `LOCK_EX`
+    /// flag, and the `LockFileEx` function on Windows with the `LOCKFILE_EXCLUSIVE_LOCK` flag.
+    /// Note that this [may change in the future][changes].
+    ///
+    /// [changes]: io#platform-specific-behavior
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error of kind [`ErrorKind::Unsupported`] on the platforms that
+    /// don't support file locking.
+    ///
+    /// [`ErrorKind::Unsupported`]: io::ErrorKind::Unsupported
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    /// use std::fs::File;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let f = File::create("foo.txt")?;
+    ///     f.lock()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "none")]
+    #[doc(alias = "flock")]
+    #[doc(alias = "LockFileEx")]
+    pub fn lock(&self) -> io::Result<()> {
+        self.inner.lock()
+    }
+
+    /// Acquires a shared advisory lock on the file, blocking until it can be acquired.
+    ///
+    /// Any number of handles can hold a shared lock on a file, as long as no handle holds an
+    /// exclusive lock on it. See [`lock`] for how locks are released.
+    ///
+    /// [`lock`]: File::lock
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `flock` function on Unix with the `LOCK_SH`
+    /// flag, and the `LockFileEx` function on Windows. Note that this
+    /// [may change in the future][changes].
+    ///
+    /// [changes]: io#platform-specific-behavior
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error of kind [`ErrorKind::Unsupported`] on the platforms that
+    /// don't support file locking.
+    ///
+    /// [`ErrorKind::Unsupported`]: io::ErrorKind::Unsupported
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    /// use std::fs::File;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let f = File::open("foo.txt")?;
+    ///     f.lock_shared()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "none")]
+    #[doc(alias = "flock")]
+    #[doc(alias = "LockFileEx")]
+    pub fn lock_shared(&self) -> io::Result<()> {
+        self.inner.lock_shared()
+    }
+
+    /// Tries to acquire an exclusive advisory lock on the file, without blocking.
+    ///
+    /// See [`lock`] for the semantics of the lock.
+    ///
+    /// [`lock`]: File::lock
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `flock` function on Unix with the `LOCK_EX`
+    /// and `LOCK_NB` flags, and the `LockFileEx` function on Windows with the
+    /// `LOCKFILE_EXCLUSIVE_LOCK` and `LOCKFILE_FAIL_IMMEDIATELY` flags. Note that this
+    /// [may change in the future][changes].
+    ///
+    /// [changes]: io#platform-specific-behavior
+    ///
+    /// # Errors
+    ///
+    /// If another handle holds a lock on the file, this function returns an error of kind
+    /// [`ErrorKind::WouldBlock`]. It returns an error of kind [`ErrorKind::Unsupported`] on the
+    /// platforms that don't support file locking.
+    ///
+    /// [`ErrorKind::WouldBlock`]: io::ErrorKind::WouldBlock
+    /// [`ErrorKind::Unsupported`]: io::ErrorKind::Unsupported
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    /// use std::fs::File;
+    /// use std::io::ErrorKind;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let f = File::create("foo.txt")?;
+    ///     match f.try_lock() {
+    ///         Ok(()) => println!("locked"),
+    ///         Err(e) if e.kind() == ErrorKind::WouldBlock => println!("already locked"),
+    ///         Err(e) => return Err(e),
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "none")]
+    #[doc(alias = "flock")]
+    #[doc(alias = "LockFileEx")]
+    pub fn try_lock(&self) -> io::Result<()> {
+        self.inner.try_lock()
+    }
+
+    /// Tries to acquire a shared advisory lock on the file, without blocking.
+    ///
+    /// See [`lock_shared`] for the semantics of the lock.
+    ///
+    /// [`lock_shared`]: File::lock_shared
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `flock` function on Unix with the `LOCK_SH`
+    /// and `LOCK_NB` flags, and the `LockFileEx` function on Windows with the
+    /// `LOCKFILE_FAIL_IMMEDIATELY` flag. Note that this [may change in the future][changes].
+    ///
+    /// [changes]: io#platform-specific-behavior
+    ///
+    /// # Errors
+    ///
+    /// If another handle holds an exclusive lock on the file, this function returns an error of
+    /// kind [`ErrorKind::WouldBlock`]. It returns an error of kind [`ErrorKind::Unsupported`] on
+    /// the platforms that don't support file locking.
+    ///
+    /// [`ErrorKind::WouldBlock`]: io::ErrorKind::WouldBlock
+    /// [`ErrorKind::Unsupported`]: io::ErrorKind::Unsupported
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    /// use std::fs::File;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let f = File::open("foo.txt")?;
+    ///     f.try_lock_shared()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "none")]
+    #[doc(alias = "flock")]
+    #[doc(alias = "LockFileEx")]
+    pub fn try_lock_shared(&self) -> io::Result<()> {
+        self.inner.try_lock_shared()
+    }
+
+    /// Releases the lock held by this handle on the file, if any.
+    ///
+    /// Dropping the handle also releases its lock, so this is only needed to release it earlier.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `flock` function on Unix with the `LOCK_UN`
+    /// flag, and the `UnlockFile` function on Windows. Note that this
+    /// [may change in the future][changes].
+    ///
+    /// [changes]: io#platform-specific-behavior
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error of kind [`ErrorKind::Unsupported`] on the platforms that
+    /// don't support file locking.
+    ///
+    /// [`ErrorKind::Unsupported`]: io::ErrorKind::Unsupported
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    /// use std::fs::File;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let f = File::open("foo.txt")?;
+    ///     f.lock()?;
+    ///     f.unlock()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "none")]
+    #[doc(alias = "flock")]
+    #[doc(alias = "UnlockFile")]
+    pub fn unlock(&self) -> io::Result<()> {
+        self.inner.unlock()
+    }
 }
 
 // In addition to the `impl`s here, `File` also has `impl`s for
@@ -2452,6 +2808,37 @@ pub fn read_dir<P: AsRef<Path>>(path: P) -> io::Result<ReadDir> {
     fs_imp::readdir(path.as_ref()).map(ReadDir)
 }
 
+/// Returns an iterator over the entries of a directory and of its subdirectories, recursively.
+///
+/// This is a convenience function for [`WalkDirOptions::walk`] with the default options: the
+/// entries of each subdirectory are returned right after the subdirectory, in the order returned
+/// by [`read_dir`], and symlinks to directories are not followed. See [`WalkDir`] for more.
+///
+/// # Errors
+///
+/// This function will return an error in the same situations as [`read_dir`]. The errors that
+/// happen while walking the subdirectories are returned by the iterator instead.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(fs_walk_dir)]
+/// use std::fs;
+///
+/// fn main() -> std::io::Result<()> {
+///     let mut size = 0;
+///     for entry in fs::walk_dir("target")? {
+///         size += entry?.metadata()?.len();
+///     }
+///     println!("{size} bytes");
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "fs_walk_dir", issue = "none")]
+pub fn walk_dir<P: AsRef<Path>>(path: P) -> io::Result<WalkDir> {
+    WalkDirOptions::new().walk(path)
+}
+
 /// Changes the permissions found on a file or a directory.
 ///
 /// # Platform-specific behavior
@@ -2586,6 +2973,695 @@ fn as_inner_mut(&mut self) -> &mut fs_imp::DirBuilder {
     }
 }
 
+impl Dir {
+    /// Opens the directory at `path`, with the default [`DirOptions`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `path` does not exist, is not a directory, or
+    /// cannot be read.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(fs_dir)]
+    /// use std::fs::Dir;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let dir = Dir::open("foo")?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "fs_dir", issue = "none")]
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Dir> {
+        DirOptions::new().open(path)
+    }
+
+    /// Opens the file at `path`, relative to this directory, with the given options.
+    ///
+    /// See [`OpenOptions::open`] for the errors this function can return.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(fs_dir)]
+    /// use std::fs::{Dir, File};
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let dir = Dir::open("foo")?;
+    ///     let file = dir.open_file("bar.txt", File::options().read(true))?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "fs_dir", issue = "none")]
+    #[doc(alias = "openat")]
+    pub fn open_file<P: AsRef<Path>>(&self, path: P, options: &OpenOptions) -> io::Result<File> {
+        self.inner.open_file(path.as_ref(), &options.0).map(|inner| File { inner })
+    }
+
+    /// Opens the directory at `path`, relative to this directory.
+    ///
+    /// The new handle resolves paths with the same [`DirOptions`] as this one.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(fs_dir)]
+    /// use std::fs::Dir;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let dir = Dir::open("foo")?;
+    ///     let subdir = dir.open_dir("bar")?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "fs_dir", issue = "none")]
+    #[doc(alias = "openat")]
+    pub fn open_dir<P: AsRef<Path>>(&self, path: P) -> io::Result<Dir> {
+        self.inner.open_dir(path.as_ref()).map(|inner| Dir { inner })
+    }
+
+    /// Creates a new, empty directory at `path`, relative to this directory.
+    ///
+    /// See [`create_dir`] for the errors this function can return.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(fs_dir)]
+    /// use std::fs::Dir;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let dir = Dir::open("foo")?;
+    ///     dir.create_dir("bar")?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "fs_dir", issue = "none")]
+    #[doc(alias = "mkdirat")]
+    pub fn create_dir<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        self.inner.create_dir(path.as_ref())
+    }
+
+    /// Removes the empty directory at `path`, relative to this directory.
+    ///
+    /// See [`remove_dir`] for the errors this function can return.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(fs_dir)]
+    /// use std::fs::Dir;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let dir = Dir::open("foo")?;
+    ///     dir.remove_dir("bar")?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "fs_dir", issue = "none")]
+    #[doc(alias = "unlinkat")]
+    pub fn remove_dir<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        self.inner.remove_dir(path.as_ref())
+    }
+
+    /// Removes the file at `path`, relative to this directory.
+    ///
+    /// See [`remove_file`] for the errors this function can return.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(fs_dir)]
+    /// use std::fs::Dir;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let dir = Dir::open("foo")?;
+    ///     dir.remove_file("bar.txt")?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "fs_dir", issue = "none")]
+    #[doc(alias = "unlinkat")]
+    pub fn remove_file<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        self.inner.remove_file(path.as_ref())
+    }
+
+    /// Queries the metadata of the file at `path`, relative to this directory.
+    ///
+    /// This function follows symlinks, unless the directory was opened with
+    /// [`DirOptions::follow_symlinks`] set to `false`, in which case it returns the metadata of
+    /// a symlink in the last component of `path`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(fs_dir)]
+    /// use std::fs::Dir;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let dir = Dir::open("foo")?;
+    ///     let len = dir.metadata("bar.txt")?.len();
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "fs_dir", issue = "none")]
+    #[doc(alias = "fstatat")]
+    pub fn metadata<P: AsRef<Path>>(&self, path: P) -> io::Result<Metadata> {
+        self.inner.metadata(path.as_ref()).map(Metadata)
+    }
+
+    /// Queries the metadata of the file at `path`, relative to this directory, without
+    /// following a symlink in its last component.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(fs_dir)]
+    /// use std::fs::Dir;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let dir = Dir::open("foo")?;
+    ///     let is_symlink = dir.symlink_metadata("bar")?.is_symlink();
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "fs_dir", issue = "none")]
+    #[doc(alias = "fstatat")]
+    pub fn symlink_metadata<P: AsRef<Path>>(&self, path: P) -> io::Result<Metadata> {
+        self.inner.symlink_metadata(path.as_ref()).map(Metadata)
+    }
+
+    /// Renames the file or directory at `from`, relative to this directory, to `to`, relative to
+    /// `to_dir`, replacing the original file if `to` already exists.
+    ///
+    /// See [`rename`] for the errors this function can return.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(fs_dir)]
+    /// use std::fs::Dir;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let incoming = Dir::open("incoming")?;
+    ///     let done = Dir::open("done")?;
+    ///     incoming.rename("a.txt", &done, "a.txt")?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "fs_dir", issue = "none")]
+    #[doc(alias = "renameat")]
+    pub fn rename<P: AsRef<Path>, Q: AsRef<Path>>(
+        &self,
+        from: P,
+        to_dir: &Dir,
+        to: Q,
+    ) -> io::Result<()> {
+        self.inner.rename(from.as_ref(), &to_dir.inner, to.as_ref())
+    }
+
+    /// Returns an iterator over the entries of this directory.
+    ///
+    /// The paths of the entries are joined to the path the directory was opened with, which may
+    /// no longer refer to it.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(fs_dir)]
+    /// use std::fs::Dir;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let dir = Dir::open("foo")?;
+    ///     for entry in dir.read_dir()? {
+    ///         println!("{:?}", entry?.file_name());
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "fs_dir", issue = "none")]
+    #[doc(alias = "fdopendir")]
+    pub fn read_dir(&self) -> io::Result<ReadDir> {
+        self.inner.read_dir().map(ReadDir)
+    }
+}
+
+#[unstable(feature = "fs_dir", issue = "none")]
+impl fmt::Debug for Dir {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+impl DirOptions {
+    /// Creates a blank new set of options, which follow symlinks and don't restrict the paths
+    /// to the directory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fs_dir)]
+    /// use std::fs::DirOptions;
+    ///
+    /// let options = DirOptions::new();
+    /// ```
+    #[unstable(feature = "fs_dir", issue = "none")]
+    #[must_use]
+    pub fn new() -> DirOptions {
+        DirOptions { resolve: ResolveOptions { follow_symlinks: true, beneath: false } }
+    }
+
+    /// Sets whether symlinks are followed while resolving the paths relative to the directory.
+    ///
+    /// When set to `false`, paths that go through a symlink are rejected. A symlink in the last
+    /// component of a path is not followed either: the functions that operate on the symlink
+    /// itself, like [`Dir::symlink_metadata`], [`Dir::remove_file`] or [`Dir::rename`], still
+    /// work, and the other ones return an error.
+    ///
+    /// This option defaults to `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fs_dir)]
+    /// use std::fs::DirOptions;
+    ///
+    /// let mut options = DirOptions::new();
+    /// options.follow_symlinks(false);
+    /// ```
+    #[unstable(feature = "fs_dir", issue = "none")]
+    pub fn follow_symlinks(&mut self, follow: bool) -> &mut Self {
+        self.resolve.follow_symlinks = follow;
+        self
+    }
+
+    /// Sets whether the paths relative to the directory must stay within it.
+    ///
+    /// When set to `true`, absolute paths, `..` components that would leave the directory, and
+    /// symlinks whose target is outside of the directory are rejected with an error of kind
+    /// [`ErrorKind::PermissionDenied`]. The symlinks that stay within the directory are still
+    /// followed, unless [`follow_symlinks`] is set to `false`.
+    ///
+    /// This option defaults to `false`.
+    ///
+    /// [`ErrorKind::PermissionDenied`]: io::ErrorKind::PermissionDenied
+    /// [`follow_symlinks`]: DirOptions::follow_symlinks
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fs_dir)]
+    /// use std::fs::DirOptions;
+    ///
+    /// let mut options = DirOptions::new();
+    /// options.beneath(true);
+    /// ```
+    #[unstable(feature = "fs_dir", issue = "none")]
+    pub fn beneath(&mut self, beneath: bool) -> &mut Self {
+        self.resolve.beneath = beneath;
+        self
+    }
+
+    /// Opens the directory at `path` with the options specified by `self`.
+    ///
+    /// The options only apply to the paths relative to the directory: `path` itself is resolved
+    /// like the paths passed to the other functions of this module.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `path` does not exist, is not a directory, or
+    /// cannot be read.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(fs_dir)]
+    /// use std::fs::DirOptions;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let dir = DirOptions::new().follow_symlinks(false).open("foo")?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "fs_dir", issue = "none")]
+    pub fn open<P: AsRef<Path>>(&self, path: P) -> io::Result<Dir> {
+        fs_imp::DirHandle::open(path.as_ref(), self.resolve).map(|inner| Dir { inner })
+    }
+}
+
+impl WalkDir {
+    /// Returns the depth of the last entry returned by the iterator.
+    ///
+    /// The entries of the starting directory have a depth of 1, and the entries of a
+    /// subdirectory have a depth one more than the subdirectory.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(fs_walk_dir)]
+    /// use std::fs;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut walk = fs::walk_dir("src")?;
+    ///     while let Some(entry) = walk.next() {
+    ///         let indent = "  ".repeat(walk.depth() - 1);
+    ///         println!("{indent}{}", entry?.file_name().to_string_lossy());
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    #[must_use]
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Skips the contents of the directory that was just returned by the iterator.
+    ///
+    /// If the last returned entry is not a directory about to be walked, because it is not a
+    /// directory, or because the [maximum depth] is reached or [its contents came first], the
+    /// remaining entries of the directory that contains it are skipped instead.
+    ///
+    /// [maximum depth]: WalkDirOptions::max_depth
+    /// [its contents came first]: WalkDirOptions::contents_first
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(fs_walk_dir)]
+    /// use std::fs;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut walk = fs::walk_dir(".")?;
+    ///     while let Some(entry) = walk.next() {
+    ///         let entry = entry?;
+    ///         if entry.file_name() == ".git" {
+    ///             walk.skip_current_dir();
+    ///             continue;
+    ///         }
+    ///         println!("{}", entry.path().display());
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn skip_current_dir(&mut self) {
+        if self.descend.take().is_none() {
+            if let Some(level) = self.stack.last_mut() {
+                level.entries = WalkEntries::Skipped;
+            }
+        }
+    }
+
+    /// Opens the directory at `path` for reading.
+    fn open_level(&self, path: &Path) -> io::Result<WalkLevel> {
+        let canonical = if self.options.follow_symlinks {
+            let canonical = canonicalize(path)?;
+            if self.stack.iter().any(|level| level.canonical.as_ref() == Some(&canonical)) {
+                return Err(io::const_io_error!(
+                    io::ErrorKind::FilesystemLoop,
+                    "the directory is one of its own ancestors",
+                ));
+            }
+            Some(canonical)
+        } else {
+            None
+        };
+
+        let entries = read_dir(path)?;
+        let entries = if self.options.sort_by_file_name {
+            let mut entries: Vec<_> = entries.collect();
+            // The errors come first, as there is no name to sort them by.
+            entries.sort_by_cached_key(|entry| entry.as_ref().ok().map(DirEntry::file_name));
+            WalkEntries::Sorted(entries.into_iter())
+        } else {
+            WalkEntries::Unsorted(entries)
+        };
+        Ok(WalkLevel { entries, dir: None, canonical })
+    }
+
+    /// Returns whether the entry is a directory to walk.
+    fn is_dir(&self, entry: &DirEntry) -> io::Result<bool> {
+        let file_type = entry.file_type()?;
+        if file_type.is_symlink() && self.options.follow_symlinks {
+            // A broken symlink is returned like any other file.
+            Ok(metadata(entry.path()).is_ok_and(|metadata| metadata.is_dir()))
+        } else {
+            Ok(file_type.is_dir())
+        }
+    }
+}
+
+#[unstable(feature = "fs_walk_dir", issue = "none")]
+impl Iterator for WalkDir {
+    type Item = io::Result<DirEntry>;
+
+    fn next(&mut self) -> Option<io::Result<DirEntry>> {
+        if let Some((entry, depth)) = self.deferred.take() {
+            self.depth = depth;
+            return Some(Ok(entry));
+        }
+        if let Some(path) = self.descend.take() {
+            match self.open_level(&path) {
+                Ok(level) => self.stack.push(level),
+                Err(err) => return Some(Err(err)),
+            }
+        }
+
+        loop {
+            let depth = self.stack.len();
+            let level = self.stack.last_mut()?;
+            let next = match &mut level.entries {
+                WalkEntries::Unsorted(entries) => entries.next(),
+                WalkEntries::Sorted(entries) => entries.next(),
+                WalkEntries::Skipped => None,
+            };
+            let entry = match next {
+                Some(Ok(entry)) => entry,
+                Some(Err(err)) => return Some(Err(err)),
+                None => {
+                    // The directory is done, return it if its contents come first.
+                    if let Some(dir) = self.stack.pop().unwrap().dir {
+                        self.depth = depth - 1;
+                        return Some(Ok(dir));
+                    }
+                    continue;
+                }
+            };
+
+            let is_dir = match self.is_dir(&entry) {
+                Ok(is_dir) => is_dir,
+                Err(err) => return Some(Err(err)),
+            };
+            let returned = depth >= self.options.min_depth;
+            if is_dir && depth < self.options.max_depth {
+                if self.options.contents_first || !returned {
+                    match self.open_level(&entry.path()) {
+                        Ok(mut level) => {
+                            level.dir = returned.then_some(entry);
+                            self.stack.push(level);
+                        }
+                        Err(err) => {
+                            if returned {
+                                self.deferred = Some((entry, depth));
+                            }
+                            return Some(Err(err));
+                        }
+                    }
+                    continue;
+                }
+                self.descend = Some(entry.path());
+            } else if !returned {
+                continue;
+            }
+            self.depth = depth;
+            return Some(Ok(entry));
+        }
+    }
+}
+
+impl WalkDirOptions {
+    /// Creates a blank new set of options, which walk all the subdirectories in the order
+    /// returned by [`read_dir`], without following symlinks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fs_walk_dir)]
+    /// use std::fs::WalkDirOptions;
+    ///
+    /// let options = WalkDirOptions::new();
+    /// ```
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    #[must_use]
+    pub fn new() -> WalkDirOptions {
+        WalkDirOptions {
+            min_depth: 1,
+            max_depth: usize::MAX,
+            follow_symlinks: false,
+            sort_by_file_name: false,
+            contents_first: false,
+        }
+    }
+
+    /// Sets the minimum depth of the entries that are returned.
+    ///
+    /// The entries that are less deep are not returned, but the subdirectories among them are
+    /// still walked. The entries of the starting directory have a depth of 1, which is the
+    /// default. The starting directory itself is never returned, so a minimum depth of 0 is the
+    /// same as 1.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(fs_walk_dir)]
+    /// use std::fs::WalkDirOptions;
+    ///
+    /// // Only return what's inside the subdirectories of `crates`.
+    /// let walk = WalkDirOptions::new().min_depth(2).walk("crates");
+    /// ```
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn min_depth(&mut self, depth: usize) -> &mut Self {
+        self.min_depth = depth.max(1);
+        self
+    }
+
+    /// Sets the maximum depth of the entries that are returned.
+    ///
+    /// The subdirectories at the maximum depth are returned, but not walked. By default, there
+    /// is no maximum depth, and a depth of 1 returns the same entries as [`read_dir`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(fs_walk_dir)]
+    /// use std::fs::WalkDirOptions;
+    ///
+    /// // Return the entries of `crates`, and of its subdirectories.
+    /// let walk = WalkDirOptions::new().max_depth(2).walk("crates");
+    /// ```
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn max_depth(&mut self, depth: usize) -> &mut Self {
+        self.max_depth = depth;
+        self
+    }
+
+    /// Sets whether the symlinks to directories are walked like directories.
+    ///
+    /// The symlinks themselves are still returned, and their [`DirEntry::file_type`] still
+    /// reports a symlink. When symlinks are followed, a symlink to one of the directories being
+    /// walked is not walked again, and returns an error of kind
+    /// [`io::ErrorKind::FilesystemLoop`] instead.
+    ///
+    /// This option defaults to `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(fs_walk_dir)]
+    /// use std::fs::WalkDirOptions;
+    ///
+    /// let walk = WalkDirOptions::new().follow_symlinks(true).walk("node_modules");
+    /// ```
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn follow_symlinks(&mut self, follow: bool) -> &mut Self {
+        self.follow_symlinks = follow;
+        self
+    }
+
+    /// Sets whether the entries of each directory are sorted by their file name.
+    ///
+    /// Otherwise, the entries are returned in the order of [`read_dir`], which depends on the
+    /// platform and the filesystem. Sorting reads all the entries of a directory before
+    /// returning the first one.
+    ///
+    /// This option defaults to `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(fs_walk_dir)]
+    /// use std::fs::WalkDirOptions;
+    ///
+    /// let walk = WalkDirOptions::new().sort_by_file_name(true).walk("tests");
+    /// ```
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn sort_by_file_name(&mut self, sort: bool) -> &mut Self {
+        self.sort_by_file_name = sort;
+        self
+    }
+
+    /// Sets whether the entries of a subdirectory are returned before the subdirectory itself.
+    ///
+    /// This is useful to remove a directory tree, as a directory is only returned once it may be
+    /// empty. This option defaults to `false`, in which case a subdirectory is returned before
+    /// its entries.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(fs_walk_dir)]
+    /// use std::fs::{self, WalkDirOptions};
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     for entry in WalkDirOptions::new().contents_first(true).walk("build")? {
+    ///         let entry = entry?;
+    ///         if entry.file_type()?.is_dir() {
+    ///             fs::remove_dir(entry.path())?;
+    ///         } else {
+    ///             fs::remove_file(entry.path())?;
+    ///         }
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn contents_first(&mut self, contents_first: bool) -> &mut Self {
+        self.contents_first = contents_first;
+        self
+    }
+
+    /// Starts walking the directory at `path` with these options.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error in the same situations as [`read_dir`], or if
+    /// symlinks are followed and `path` can't be canonicalized. The errors that happen while
+    /// walking the subdirectories are returned by the iterator instead.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(fs_walk_dir)]
+    /// use std::fs::WalkDirOptions;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let walk = WalkDirOptions::new().max_depth(3).walk("assets")?;
+    ///     for entry in walk.filter_map(Result::ok) {
+    ///         println!("{}", entry.path().display());
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn walk<P: AsRef<Path>>(&self, path: P) -> io::Result<WalkDir> {
+        let mut walk = WalkDir {
+            options: self.clone(),
+            stack: Vec::new(),
+            descend: None,
+            deferred: None,
+            depth: 0,
+        };
+        let mut level = walk.open_level(path.as_ref())?;
+        if self.max_depth == 0 {
+            level.entries = WalkEntries::Skipped;
+        }
+        walk.stack.push(level);
+        Ok(walk)
+    }
+}
+
 /// Returns `Ok(true)` if the path points at an existing entity.
 ///
 /// This function will traverse symbolic links to query information about the
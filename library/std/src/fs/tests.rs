@@ -1,8 +1,8 @@
 use crate::io::prelude::*;
 
 use crate::env;
-use crate::fs::{self, File, FileTimes, OpenOptions};
-use crate::io::{BorrowedBuf, ErrorKind, SeekFrom};
+use crate::fs::{self, File, FileTimes, OpenOptions, WalkDirOptions};
+use crate::io::{self, BorrowedBuf, ErrorKind, SeekFrom};
 use crate::mem::MaybeUninit;
 use crate::path::Path;
 use crate::str;
@@ -15,6 +15,8 @@
 
 #[cfg(target_os = "macos")]
 use crate::ffi::{c_char, c_int};
+#[cfg(all(unix, not(miri)))]
+use crate::fs::{Dir, DirOptions};
 #[cfg(unix)]
 use crate::os::unix::fs::symlink as symlink_dir;
 #[cfg(unix)]
@@ -1820,3 +1822,219 @@ fn test_hidden_file_truncation() {
     let metadata = file.metadata().unwrap();
     assert_eq!(metadata.len(), 0);
 }
+
+#[test]
+#[cfg(any(
+    windows,
+    target_os = "android",
+    target_os = "freebsd",
+    target_os = "linux",
+    target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd",
+))]
+fn file_lock() {
+    let tmpdir = tmpdir();
+    let filename = &tmpdir.join("file_lock.txt");
+    let f1 = check!(File::create(filename));
+    let f2 = check!(File::open(filename));
+
+    // An exclusive lock excludes every other lock.
+    check!(f1.lock());
+    assert_eq!(f2.try_lock().unwrap_err().kind(), ErrorKind::WouldBlock);
+    assert_eq!(f2.try_lock_shared().unwrap_err().kind(), ErrorKind::WouldBlock);
+    check!(f1.unlock());
+    check!(f2.try_lock());
+    check!(f2.unlock());
+
+    // Shared locks only exclude exclusive locks.
+    check!(f1.lock_shared());
+    check!(f2.try_lock_shared());
+    assert_eq!(f1.try_lock().unwrap_err().kind(), ErrorKind::WouldBlock);
+    check!(f2.unlock());
+    check!(f1.unlock());
+
+    // Dropping a handle releases its lock.
+    check!(f1.try_lock());
+    drop(f1);
+    check!(f2.try_lock());
+}
+
+#[test]
+#[cfg(all(unix, not(miri)))]
+fn dir_handle() {
+    let tmpdir = tmpdir();
+    let dir = check!(Dir::open(tmpdir.path()));
+    check!(dir.create_dir("sub"));
+    let mut f = check!(dir.open_file("sub/a.txt", File::options().write(true).create_new(true)));
+    check!(f.write_all(b"hello"));
+    drop(f);
+    assert_eq!(check!(dir.metadata("sub/a.txt")).len(), 5);
+
+    // The paths of a directory handle keep referring to it when it's renamed.
+    let sub = check!(dir.open_dir("sub"));
+    check!(fs::rename(tmpdir.join("sub"), tmpdir.join("moved")));
+    let mut contents = String::new();
+    let mut f = check!(sub.open_file("a.txt", File::options().read(true)));
+    check!(f.read_to_string(&mut contents));
+    assert_eq!(contents, "hello");
+
+    check!(sub.rename("a.txt", &dir, "b.txt"));
+    let names: Vec<_> = check!(dir.read_dir()).map(|entry| check!(entry).file_name()).collect();
+    assert_eq!(names.len(), 2);
+    assert!(names.iter().any(|name| name == "moved") && names.iter().any(|name| name == "b.txt"));
+
+    check!(dir.remove_file("b.txt"));
+    check!(dir.remove_dir("moved"));
+    assert_eq!(dir.metadata("b.txt").unwrap_err().kind(), ErrorKind::NotFound);
+}
+
+#[test]
+#[cfg(all(unix, not(miri)))]
+fn dir_handle_resolve_options() {
+    let tmpdir = tmpdir();
+    check!(fs::create_dir(tmpdir.join("root")));
+    check!(fs::create_dir(tmpdir.join("root/sub")));
+    check!(fs::write(tmpdir.join("root/sub/a.txt"), b"a"));
+    check!(fs::write(tmpdir.join("outside.txt"), b"outside"));
+    check!(symlink_dir("sub", tmpdir.join("root/inside")));
+    check!(symlink_file("../outside.txt", tmpdir.join("root/escape")));
+    check!(symlink_file("sub/a.txt", tmpdir.join("root/link.txt")));
+
+    let root = tmpdir.join("root");
+    let dir = check!(Dir::open(&root));
+    assert_eq!(check!(dir.metadata("../outside.txt")).len(), 7);
+    assert_eq!(check!(dir.metadata("escape")).len(), 7);
+    assert_eq!(check!(dir.metadata("inside/a.txt")).len(), 1);
+
+    let beneath = check!(DirOptions::new().beneath(true).open(&root));
+    assert_eq!(check!(beneath.metadata("inside/a.txt")).len(), 1);
+    assert_eq!(check!(beneath.metadata("sub/../link.txt")).len(), 1);
+    for path in ["../outside.txt", "escape", "sub/../../outside.txt"] {
+        let err = beneath.metadata(path).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied, "{path}");
+    }
+    assert_eq!(
+        beneath.metadata(tmpdir.join("outside.txt")).unwrap_err().kind(),
+        ErrorKind::PermissionDenied
+    );
+    // Directories opened from a handle keep its options.
+    let sub = check!(beneath.open_dir("sub"));
+    assert_eq!(sub.metadata("../sub/a.txt").unwrap_err().kind(), ErrorKind::PermissionDenied);
+
+    let nofollow = check!(DirOptions::new().follow_symlinks(false).open(&root));
+    assert!(nofollow.metadata("inside/a.txt").is_err());
+    assert!(nofollow.open_file("link.txt", File::options().read(true)).is_err());
+    assert!(check!(nofollow.metadata("link.txt")).is_symlink());
+    assert_eq!(check!(nofollow.metadata("sub/a.txt")).len(), 1);
+    check!(nofollow.remove_file("link.txt"));
+}
+
+/// Returns the paths relative to `root` and the depths of the entries of `walk`.
+fn walked(root: &Path, walk: io::Result<fs::WalkDir>) -> Vec<(String, usize)> {
+    let mut walk = check!(walk);
+    let mut entries = Vec::new();
+    while let Some(entry) = walk.next() {
+        let path = check!(entry).path();
+        let path = path.strip_prefix(root).unwrap().to_str().unwrap().replace('\\', "/");
+        entries.push((path, walk.depth()));
+    }
+    entries
+}
+
+#[test]
+fn walk_dir() {
+    let tmpdir = tmpdir();
+    let root = tmpdir.join("root");
+    check!(fs::create_dir_all(root.join("a/b")));
+    check!(fs::create_dir_all(root.join("c")));
+    check!(File::create(root.join("a/b/file.txt")));
+    check!(File::create(root.join("a/file.txt")));
+    check!(File::create(root.join("file.txt")));
+
+    let mut unsorted = walked(&root, fs::walk_dir(&root));
+    unsorted.sort();
+    let entries = walked(&root, WalkDirOptions::new().sort_by_file_name(true).walk(&root));
+    assert_eq!(unsorted, {
+        let mut entries = entries.clone();
+        entries.sort();
+        entries
+    });
+    assert_eq!(
+        entries,
+        [("a", 1), ("a/b", 2), ("a/b/file.txt", 3), ("a/file.txt", 2), ("c", 1), ("file.txt", 1)]
+            .map(|(path, depth)| (path.to_string(), depth))
+    );
+
+    let mut options = WalkDirOptions::new();
+    options.sort_by_file_name(true).contents_first(true);
+    assert_eq!(
+        walked(&root, options.walk(&root)),
+        [("a/b/file.txt", 3), ("a/b", 2), ("a/file.txt", 2), ("a", 1), ("c", 1), ("file.txt", 1)]
+            .map(|(path, depth)| (path.to_string(), depth))
+    );
+
+    options.min_depth(2).max_depth(2);
+    assert_eq!(
+        walked(&root, options.walk(&root)),
+        [("a/b", 2), ("a/file.txt", 2)].map(|(path, depth)| (path.to_string(), depth))
+    );
+    assert!(walked(&root, options.max_depth(0).walk(&root)).is_empty());
+
+    // The starting directory is never returned, so a minimum depth of 0 is the same as 1.
+    let top_level = walked(&root, options.min_depth(1).max_depth(1).walk(&root));
+    assert_eq!(
+        top_level,
+        [("a", 1), ("c", 1), ("file.txt", 1)].map(|(path, depth)| (path.to_string(), depth))
+    );
+    assert_eq!(walked(&root, options.min_depth(0).walk(&root)), top_level);
+
+    // Skip the contents of `a`.
+    let mut walk = check!(WalkDirOptions::new().sort_by_file_name(true).walk(&root));
+    assert_eq!(check!(walk.next().unwrap()).file_name(), "a");
+    walk.skip_current_dir();
+    assert_eq!(check!(walk.next().unwrap()).file_name(), "c");
+    assert_eq!(check!(walk.next().unwrap()).file_name(), "file.txt");
+    assert!(walk.next().is_none());
+
+    assert_eq!(fs::walk_dir(root.join("missing")).unwrap_err().kind(), ErrorKind::NotFound);
+}
+
+#[test]
+fn walk_dir_symlinks() {
+    let tmpdir = tmpdir();
+    if !got_symlink_permission(&tmpdir) {
+        return;
+    };
+    let root = tmpdir.join("root");
+    check!(fs::create_dir_all(root.join("dir")));
+    check!(File::create(root.join("dir/file.txt")));
+    check!(symlink_dir(&root, root.join("dir/loop")));
+    check!(symlink_dir(root.join("dir"), root.join("link")));
+
+    let mut options = WalkDirOptions::new();
+    options.sort_by_file_name(true);
+    assert_eq!(
+        walked(&root, options.walk(&root)),
+        [("dir", 1), ("dir/file.txt", 2), ("dir/loop", 2), ("link", 1)]
+            .map(|(path, depth)| (path.to_string(), depth))
+    );
+
+    // The loop is reported, and the walk carries on.
+    let mut walk = check!(options.follow_symlinks(true).walk(&root));
+    let mut entries = Vec::new();
+    let mut errors = Vec::new();
+    for entry in walk.by_ref() {
+        match entry {
+            Ok(entry) => entries.push(entry.path().strip_prefix(&root).unwrap().to_path_buf()),
+            Err(err) => errors.push(err.kind()),
+        }
+    }
+    assert_eq!(errors, [ErrorKind::FilesystemLoop, ErrorKind::FilesystemLoop]);
+    let expected: Vec<_> =
+        ["dir", "dir/file.txt", "dir/loop", "link", "link/file.txt", "link/loop"]
+            .into_iter()
+            .map(|path| Path::new(path).to_path_buf())
+            .collect();
+    assert_eq!(entries, expected);
+}
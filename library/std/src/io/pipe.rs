@@ -0,0 +1,221 @@
+use crate::fmt;
+use crate::io::{self, BorrowedCursor, IoSlice, IoSliceMut, Read, Write};
+use crate::sys::anonymous_pipe::{pipe as pipe_inner, AnonPipe};
+
+#[cfg(test)]
+mod tests;
+
+/// Creates an anonymous pipe, returning its read and write ends.
+///
+/// The bytes written to the [`PipeWriter`] can be read from the [`PipeReader`],
+/// in order. Reading blocks until data is written, and returns 0 once every
+/// [`PipeWriter`] for the pipe has been dropped, as with [`try_clone`]d
+/// writers, or writers inherited by a child process. Writing blocks while the
+/// pipe is full, and fails with [`BrokenPipe`] once every [`PipeReader`] has
+/// been dropped.
+///
+/// Both ends can be converted into a [`Stdio`] to set up the standard streams
+/// of a child process, so that a pipeline of processes can be built, or the
+/// output of several processes can be merged into a single pipe.
+///
+/// [`try_clone`]: PipeWriter::try_clone
+/// [`BrokenPipe`]: io::ErrorKind::BrokenPipe
+/// [`Stdio`]: crate::process::Stdio
+///
+/// # Platform-specific behavior
+///
+/// This function currently corresponds to the `pipe2` function with
+/// `O_CLOEXEC`, or `pipe` on Unix platforms that lack it, and to the
+/// `CreatePipe` function on Windows. The ends of the pipe are not inherited
+/// by child processes, unless they are passed as standard streams.
+/// Note that this [may change in the future][changes].
+///
+/// [changes]: io#platform-specific-behavior
+///
+/// # Examples
+///
+/// Merging the output of two processes, and reading it once both exited:
+///
+/// ```no_run
+/// #![feature(anonymous_pipe)]
+/// use std::io::{self, Read};
+/// use std::process::Command;
+///
+/// let (mut reader, writer) = io::pipe()?;
+///
+/// let mut first = Command::new("echo").arg("hello").stdout(writer.try_clone()?).spawn()?;
+/// let mut second = Command::new("echo").arg("world").stdout(writer).spawn()?;
+///
+/// // Read until the children have exited, as they hold the last writers. The
+/// // `Command`s have been dropped already, or they would keep it open too.
+/// let mut output = String::new();
+/// reader.read_to_string(&mut output)?;
+/// first.wait()?;
+/// second.wait()?;
+/// # Ok::<_, io::Error>(())
+/// ```
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+#[inline]
+pub fn pipe() -> io::Result<(PipeReader, PipeWriter)> {
+    pipe_inner().map(|(reader, writer)| (PipeReader(reader), PipeWriter(writer)))
+}
+
+/// The read end of an anonymous pipe, created with [`pipe`].
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+pub struct PipeReader(pub(crate) AnonPipe);
+
+/// The write end of an anonymous pipe, created with [`pipe`].
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+pub struct PipeWriter(pub(crate) AnonPipe);
+
+impl PipeReader {
+    /// Creates a new `PipeReader` that reads from the same pipe.
+    ///
+    /// Both readers share the data of the pipe: each byte is read by only one
+    /// of them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(anonymous_pipe)]
+    /// use std::io::{self, Read, Write};
+    ///
+    /// let (reader, mut writer) = io::pipe()?;
+    /// let mut other = reader.try_clone()?;
+    /// drop(reader);
+    ///
+    /// writer.write_all(b"hello")?;
+    /// drop(writer);
+    ///
+    /// let mut data = String::new();
+    /// other.read_to_string(&mut data)?;
+    /// assert_eq!(data, "hello");
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    #[unstable(feature = "anonymous_pipe", issue = "none")]
+    pub fn try_clone(&self) -> io::Result<Self> {
+        self.0.try_clone().map(Self)
+    }
+}
+
+impl PipeWriter {
+    /// Creates a new `PipeWriter` that writes to the same pipe.
+    ///
+    /// The pipe reaches its end only once all the writers have been dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(anonymous_pipe)]
+    /// use std::io;
+    /// use std::process::Command;
+    ///
+    /// let (reader, writer) = io::pipe()?;
+    /// // Both the standard output and error of the child are written to the pipe.
+    /// let child = Command::new("ls")
+    ///     .stdout(writer.try_clone()?)
+    ///     .stderr(writer)
+    ///     .spawn()?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    #[unstable(feature = "anonymous_pipe", issue = "none")]
+    pub fn try_clone(&self) -> io::Result<Self> {
+        self.0.try_clone().map(Self)
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl fmt::Debug for PipeReader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PipeReader").finish_non_exhaustive()
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl fmt::Debug for PipeWriter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PipeWriter").finish_non_exhaustive()
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl Read for &PipeReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.read(buf)
+    }
+
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        self.0.read_vectored(bufs)
+    }
+
+    fn read_buf(&mut self, cursor: BorrowedCursor<'_>) -> io::Result<()> {
+        self.0.read_buf(cursor)
+    }
+
+    #[inline]
+    fn is_read_vectored(&self) -> bool {
+        self.0.is_read_vectored()
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl Read for PipeReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        (&*self).read(buf)
+    }
+
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        (&*self).read_vectored(bufs)
+    }
+
+    fn read_buf(&mut self, cursor: BorrowedCursor<'_>) -> io::Result<()> {
+        (&*self).read_buf(cursor)
+    }
+
+    #[inline]
+    fn is_read_vectored(&self) -> bool {
+        self.0.is_read_vectored()
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl Write for &PipeWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.write(buf)
+    }
+
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        self.0.write_vectored(bufs)
+    }
+
+    #[inline]
+    fn is_write_vectored(&self) -> bool {
+        self.0.is_write_vectored()
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl Write for PipeWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        (&*self).write(buf)
+    }
+
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        (&*self).write_vectored(bufs)
+    }
+
+    #[inline]
+    fn is_write_vectored(&self) -> bool {
+        self.0.is_write_vectored()
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+use super::pipe;
+use crate::io::{ErrorKind, Read, Write};
+use crate::thread;
+
+#[test]
+#[cfg(any(unix, windows))]
+fn pipe_read_write() {
+    let (mut reader, mut writer) = pipe().unwrap();
+    let handle = thread::spawn(move || {
+        writer.write_all(b"hello ").unwrap();
+        // Writing through a clone reaches the same pipe.
+        writer.try_clone().unwrap().write_all(b"world").unwrap();
+    });
+    let mut data = String::new();
+    reader.read_to_string(&mut data).unwrap();
+    handle.join().unwrap();
+    assert_eq!(data, "hello world");
+}
+
+#[test]
+#[cfg(any(unix, windows))]
+fn pipe_ends_closed() {
+    // Reading reaches the end once every writer has been dropped.
+    let (mut reader, writer) = pipe().unwrap();
+    let other = writer.try_clone().unwrap();
+    drop(writer);
+    (&other).write_all(b"x").unwrap();
+    drop(other);
+    let mut data = Vec::new();
+    reader.read_to_end(&mut data).unwrap();
+    assert_eq!(data, b"x");
+
+    // Writing fails once every reader has been dropped.
+    let (reader, mut writer) = pipe().unwrap();
+    drop(reader);
+    assert_eq!(writer.write(b"x").unwrap_err().kind(), ErrorKind::BrokenPipe);
+}
+
+#[test]
+#[cfg(all(unix, not(any(target_os = "vxworks", target_os = "espidf"))))]
+fn pipe_to_child() {
+    use crate::process::Command;
+
+    let (mut reader, writer) = pipe().unwrap();
+    let status = Command::new("echo").arg("hello").stdout(writer.try_clone().unwrap()).status();
+    assert!(status.unwrap().success());
+    let mut child = Command::new("echo").arg("world").stdout(writer).spawn().unwrap();
+    assert!(child.wait().unwrap().success());
+    // The commands were dropped along with their writers, and the children have exited.
+    let mut data = String::new();
+    reader.read_to_string(&mut data).unwrap();
+    assert_eq!(data, "hello\nworld\n");
+}
@@ -8,7 +8,7 @@
 use crate::fmt;
 use crate::io::{self, BorrowedCursor, IoSlice, IoSliceMut};
 use crate::iter::FusedIterator;
-use crate::net::{Shutdown, SocketAddr, ToSocketAddrs};
+use crate::net::{Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr, ToSocketAddrs};
 use crate::sys_common::net as net_imp;
 use crate::sys_common::{AsInner, FromInner, IntoInner};
 use crate::time::Duration;
@@ -1069,3 +1069,464 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.0.fmt(f)
     }
 }
+
+/// A builder for a [`TcpStream`] or a [`TcpListener`], to set the options that
+/// must be set before the socket is bound or connected.
+///
+/// A `TcpSocketBuilder` holds a socket that is not connected or listening
+/// yet, to which options such as [`set_reuseaddr`] or the sizes of the socket
+/// buffers are applied right away. It also gives control over the local
+/// address and the backlog of the socket. [`connect`]ing it turns it into a
+/// [`TcpStream`], and [`listen`]ing on it turns it into a [`TcpListener`].
+///
+/// The socket will be closed when the builder is dropped.
+///
+/// [`connect`]: TcpSocketBuilder::connect
+/// [`listen`]: TcpSocketBuilder::listen
+/// [`set_reuseaddr`]: TcpSocketBuilder::set_reuseaddr
+///
+/// # Examples
+///
+/// Connecting to a server from a specific local address:
+///
+/// ```no_run
+/// #![feature(net_socket_builder)]
+/// use std::net::{SocketAddr, TcpSocketBuilder};
+///
+/// fn main() -> std::io::Result<()> {
+///     let builder = TcpSocketBuilder::new_v4()?;
+///     builder.set_keepalive(true)?;
+///     builder.bind(&SocketAddr::from(([192, 168, 0, 2], 0)))?;
+///     let stream = builder.connect(&SocketAddr::from(([192, 168, 0, 1], 8080)))?;
+///     Ok(())
+/// }
+/// ```
+///
+/// Listening with a larger backlog than [`TcpListener::bind`] uses:
+///
+/// ```no_run
+/// #![feature(net_socket_builder)]
+/// use std::net::{SocketAddr, TcpSocketBuilder};
+///
+/// fn main() -> std::io::Result<()> {
+///     let builder = TcpSocketBuilder::new_v6()?;
+///     builder.set_reuseaddr(true)?;
+///     builder.bind(&"[::1]:8080".parse::<SocketAddr>().unwrap())?;
+///     let listener = builder.listen(1024)?;
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "net_socket_builder", issue = "none")]
+pub struct TcpSocketBuilder(net_imp::TcpSocketBuilder);
+
+impl TcpSocketBuilder {
+    /// Creates a builder for an IPv4 TCP socket.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(net_socket_builder)]
+    /// use std::net::TcpSocketBuilder;
+    ///
+    /// let builder = TcpSocketBuilder::new_v4().expect("couldn't create the socket");
+    /// ```
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn new_v4() -> io::Result<TcpSocketBuilder> {
+        net_imp::TcpSocketBuilder::new(&SocketAddr::from((Ipv4Addr::UNSPECIFIED, 0)))
+            .map(TcpSocketBuilder)
+    }
+
+    /// Creates a builder for an IPv6 TCP socket.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(net_socket_builder)]
+    /// use std::net::TcpSocketBuilder;
+    ///
+    /// let builder = TcpSocketBuilder::new_v6().expect("couldn't create the socket");
+    /// ```
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn new_v6() -> io::Result<TcpSocketBuilder> {
+        net_imp::TcpSocketBuilder::new(&SocketAddr::from((Ipv6Addr::UNSPECIFIED, 0)))
+            .map(TcpSocketBuilder)
+    }
+
+    /// Sets the value of the `SO_REUSEADDR` option on this socket.
+    ///
+    /// On Unix platforms, this allows to bind a socket to an address that is
+    /// still in the `TIME_WAIT` state after a previous socket was closed, which
+    /// [`TcpListener::bind`] always enables. On Windows, this allows to bind a
+    /// socket to an address that is actively in use by another socket.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(net_socket_builder)]
+    /// use std::net::TcpSocketBuilder;
+    ///
+    /// let builder = TcpSocketBuilder::new_v4().expect("couldn't create the socket");
+    /// builder.set_reuseaddr(true).expect("set_reuseaddr call failed");
+    /// ```
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn set_reuseaddr(&self, reuseaddr: bool) -> io::Result<()> {
+        self.0.set_reuseaddr(reuseaddr)
+    }
+
+    /// Gets the value of the `SO_REUSEADDR` option on this socket.
+    ///
+    /// For more information about this option, see [`TcpSocketBuilder::set_reuseaddr`].
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn reuseaddr(&self) -> io::Result<bool> {
+        self.0.reuseaddr()
+    }
+
+    /// Sets the value of the `SO_REUSEPORT` option on this socket.
+    ///
+    /// This allows several sockets to be bound to the same address, provided
+    /// that they all set this option, for instance to spread the incoming
+    /// connections over several listeners.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This option is not available on Windows and some Unix platforms, on
+    /// which this function returns an error of the kind
+    /// [`io::ErrorKind::Unsupported`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(net_socket_builder)]
+    /// use std::net::TcpSocketBuilder;
+    ///
+    /// let builder = TcpSocketBuilder::new_v4().expect("couldn't create the socket");
+    /// builder.set_reuseport(true).expect("set_reuseport call failed");
+    /// ```
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn set_reuseport(&self, reuseport: bool) -> io::Result<()> {
+        self.0.set_reuseport(reuseport)
+    }
+
+    /// Gets the value of the `SO_REUSEPORT` option on this socket.
+    ///
+    /// For more information about this option, see [`TcpSocketBuilder::set_reuseport`].
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn reuseport(&self) -> io::Result<bool> {
+        self.0.reuseport()
+    }
+
+    /// Sets the value of the `SO_KEEPALIVE` option on this socket.
+    ///
+    /// If set, keepalive probes are sent once the connection has been idle for
+    /// some time, to detect a peer that went away. The operating system
+    /// configures the timing of the probes, unless it is set with
+    /// [`TcpSocketBuilder::set_keepalive_idle`],
+    /// [`TcpSocketBuilder::set_keepalive_interval`] and
+    /// [`TcpSocketBuilder::set_keepalive_retries`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(net_socket_builder)]
+    /// use std::net::TcpSocketBuilder;
+    ///
+    /// let builder = TcpSocketBuilder::new_v4().expect("couldn't create the socket");
+    /// builder.set_keepalive(true).expect("set_keepalive call failed");
+    /// ```
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn set_keepalive(&self, keepalive: bool) -> io::Result<()> {
+        self.0.set_keepalive(keepalive)
+    }
+
+    /// Gets the value of the `SO_KEEPALIVE` option on this socket.
+    ///
+    /// For more information about this option, see [`TcpSocketBuilder::set_keepalive`].
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn keepalive(&self) -> io::Result<bool> {
+        self.0.keepalive()
+    }
+
+    /// Sets how long the connection must be idle before the first keepalive
+    /// probe is sent.
+    ///
+    /// This sets the `TCP_KEEPIDLE` option on this socket, or `TCP_KEEPALIVE`
+    /// on Apple platforms. The time is rounded down to whole seconds, and an
+    /// error of the kind [`io::ErrorKind::InvalidInput`] is returned if it is
+    /// shorter than a second. It only applies if [`set_keepalive`] enables
+    /// keepalive probes.
+    ///
+    /// [`set_keepalive`]: TcpSocketBuilder::set_keepalive
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This option is only available on Linux, Android, Fuchsia, Hurd, the
+    /// BSDs except OpenBSD, illumos, Solaris, Apple platforms, and Windows 10
+    /// version 1709 and later. On other platforms, this function returns an
+    /// error of the kind [`io::ErrorKind::Unsupported`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(net_socket_builder)]
+    /// use std::net::TcpSocketBuilder;
+    /// use std::time::Duration;
+    ///
+    /// let builder = TcpSocketBuilder::new_v4().expect("couldn't create the socket");
+    /// builder.set_keepalive(true).expect("set_keepalive call failed");
+    /// builder
+    ///     .set_keepalive_idle(Duration::from_secs(60))
+    ///     .expect("set_keepalive_idle call failed");
+    /// ```
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn set_keepalive_idle(&self, idle: Duration) -> io::Result<()> {
+        self.0.set_keepalive_idle(idle)
+    }
+
+    /// Gets how long the connection must be idle before the first keepalive
+    /// probe is sent.
+    ///
+    /// For more information about this option, see
+    /// [`TcpSocketBuilder::set_keepalive_idle`].
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn keepalive_idle(&self) -> io::Result<Duration> {
+        self.0.keepalive_idle()
+    }
+
+    /// Sets the time between two keepalive probes that the peer doesn't
+    /// acknowledge.
+    ///
+    /// This sets the `TCP_KEEPINTVL` option on this socket. The time is rounded
+    /// down to whole seconds, and an error of the kind
+    /// [`io::ErrorKind::InvalidInput`] is returned if it is shorter than a
+    /// second.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This option is available on the same platforms as
+    /// [`TcpSocketBuilder::set_keepalive_idle`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(net_socket_builder)]
+    /// use std::net::TcpSocketBuilder;
+    /// use std::time::Duration;
+    ///
+    /// let builder = TcpSocketBuilder::new_v4().expect("couldn't create the socket");
+    /// builder.set_keepalive(true).expect("set_keepalive call failed");
+    /// builder
+    ///     .set_keepalive_interval(Duration::from_secs(10))
+    ///     .expect("set_keepalive_interval call failed");
+    /// ```
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn set_keepalive_interval(&self, interval: Duration) -> io::Result<()> {
+        self.0.set_keepalive_interval(interval)
+    }
+
+    /// Gets the time between two keepalive probes that the peer doesn't
+    /// acknowledge.
+    ///
+    /// For more information about this option, see
+    /// [`TcpSocketBuilder::set_keepalive_interval`].
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn keepalive_interval(&self) -> io::Result<Duration> {
+        self.0.keepalive_interval()
+    }
+
+    /// Sets the number of keepalive probes that the peer may leave
+    /// unacknowledged before the connection is dropped.
+    ///
+    /// This sets the `TCP_KEEPCNT` option on this socket.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This option is available on the same platforms as
+    /// [`TcpSocketBuilder::set_keepalive_idle`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(net_socket_builder)]
+    /// use std::net::TcpSocketBuilder;
+    ///
+    /// let builder = TcpSocketBuilder::new_v4().expect("couldn't create the socket");
+    /// builder.set_keepalive(true).expect("set_keepalive call failed");
+    /// builder.set_keepalive_retries(5).expect("set_keepalive_retries call failed");
+    /// ```
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn set_keepalive_retries(&self, retries: u32) -> io::Result<()> {
+        self.0.set_keepalive_retries(retries)
+    }
+
+    /// Gets the number of keepalive probes that the peer may leave
+    /// unacknowledged before the connection is dropped.
+    ///
+    /// For more information about this option, see
+    /// [`TcpSocketBuilder::set_keepalive_retries`].
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn keepalive_retries(&self) -> io::Result<u32> {
+        self.0.keepalive_retries()
+    }
+
+    /// Sets the value of the `SO_SNDBUF` option on this socket.
+    ///
+    /// This is the size of the buffer that holds the data written to the
+    /// socket until the peer acknowledges it. The operating system may round
+    /// the size, or clamp it to its limits.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// On Linux, the kernel doubles the requested size to make room for its
+    /// bookkeeping, and [`TcpSocketBuilder::send_buffer_size`] returns the doubled
+    /// size.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(net_socket_builder)]
+    /// use std::net::TcpSocketBuilder;
+    ///
+    /// let builder = TcpSocketBuilder::new_v4().expect("couldn't create the socket");
+    /// builder.set_send_buffer_size(1 << 20).expect("set_send_buffer_size call failed");
+    /// ```
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn set_send_buffer_size(&self, size: u32) -> io::Result<()> {
+        self.0.set_send_buffer_size(size)
+    }
+
+    /// Gets the value of the `SO_SNDBUF` option on this socket.
+    ///
+    /// For more information about this option, see
+    /// [`TcpSocketBuilder::set_send_buffer_size`].
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn send_buffer_size(&self) -> io::Result<u32> {
+        self.0.send_buffer_size()
+    }
+
+    /// Sets the value of the `SO_RCVBUF` option on this socket.
+    ///
+    /// This is the size of the buffer that holds the received data until it
+    /// is read from the socket, which bounds the window advertised to the
+    /// peer. It should be set before connecting or listening, as the window
+    /// scale of a connection is negotiated when it's established.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// On Linux, the kernel doubles the requested size to make room for its
+    /// bookkeeping, and [`TcpSocketBuilder::recv_buffer_size`] returns the doubled
+    /// size.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(net_socket_builder)]
+    /// use std::net::TcpSocketBuilder;
+    ///
+    /// let builder = TcpSocketBuilder::new_v4().expect("couldn't create the socket");
+    /// builder.set_recv_buffer_size(1 << 20).expect("set_recv_buffer_size call failed");
+    /// ```
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn set_recv_buffer_size(&self, size: u32) -> io::Result<()> {
+        self.0.set_recv_buffer_size(size)
+    }
+
+    /// Gets the value of the `SO_RCVBUF` option on this socket.
+    ///
+    /// For more information about this option, see
+    /// [`TcpSocketBuilder::set_recv_buffer_size`].
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn recv_buffer_size(&self) -> io::Result<u32> {
+        self.0.recv_buffer_size()
+    }
+
+    /// Binds this socket to the specified local address.
+    ///
+    /// This sets the source address of the connection made by
+    /// [`TcpSocketBuilder::connect`], or the address the listener created by
+    /// [`TcpSocketBuilder::listen`] accepts connections on. Binding with a port number
+    /// of 0 will request that the OS assigns a port to this socket, which can
+    /// be queried with [`TcpSocketBuilder::local_addr`].
+    ///
+    /// The address must belong to the same family as the socket.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(net_socket_builder)]
+    /// use std::net::{SocketAddr, TcpSocketBuilder};
+    ///
+    /// let builder = TcpSocketBuilder::new_v4().expect("couldn't create the socket");
+    /// builder.bind(&SocketAddr::from(([127, 0, 0, 1], 0))).expect("couldn't bind the socket");
+    /// ```
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn bind(&self, addr: &SocketAddr) -> io::Result<()> {
+        self.0.bind(addr)
+    }
+
+    /// Returns the local socket address this socket is bound to.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(net_socket_builder)]
+    /// use std::net::{SocketAddr, TcpSocketBuilder};
+    ///
+    /// let builder = TcpSocketBuilder::new_v4().expect("couldn't create the socket");
+    /// builder.bind(&SocketAddr::from(([127, 0, 0, 1], 0))).expect("couldn't bind the socket");
+    /// println!("bound to port {}", builder.local_addr().unwrap().port());
+    /// ```
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.0.socket_addr()
+    }
+
+    /// Opens a TCP connection to a remote host from this socket.
+    ///
+    /// Unlike [`TcpStream::connect`], this takes a single [`SocketAddr`], as
+    /// the socket can only be connected once, to an address of its family. If
+    /// the socket isn't bound yet, the OS picks its local address.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(net_socket_builder)]
+    /// use std::net::{SocketAddr, TcpSocketBuilder};
+    ///
+    /// let builder = TcpSocketBuilder::new_v4().expect("couldn't create the socket");
+    /// let stream = builder
+    ///     .connect(&SocketAddr::from(([127, 0, 0, 1], 8080)))
+    ///     .expect("couldn't connect to the server");
+    /// ```
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn connect(self, addr: &SocketAddr) -> io::Result<TcpStream> {
+        self.0.connect(addr).map(TcpStream)
+    }
+
+    /// Starts listening for connections on this socket.
+    ///
+    /// `backlog` is the maximum number of pending connections that have not
+    /// been accepted yet; the OS may use a lower or higher limit. The socket
+    /// should be bound with [`TcpSocketBuilder::bind`] first.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(net_socket_builder)]
+    /// use std::net::{SocketAddr, TcpSocketBuilder};
+    ///
+    /// let builder = TcpSocketBuilder::new_v4().expect("couldn't create the socket");
+    /// builder.bind(&SocketAddr::from(([127, 0, 0, 1], 8080))).expect("couldn't bind the socket");
+    /// let listener = builder.listen(16).expect("couldn't listen on the socket");
+    /// ```
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn listen(self, backlog: u32) -> io::Result<TcpListener> {
+        self.0.listen(backlog).map(TcpListener)
+    }
+}
+
+#[unstable(feature = "net_socket_builder", issue = "none")]
+impl fmt::Debug for TcpSocketBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
@@ -914,3 +914,93 @@ fn connect_timeout_valid() {
     let addr = listener.local_addr().unwrap();
     TcpStream::connect_timeout(&addr, Duration::from_secs(2)).unwrap();
 }
+
+fn tcp_socket_builder(addr: SocketAddr) -> crate::io::Result<TcpSocketBuilder> {
+    if addr.is_ipv4() {
+        TcpSocketBuilder::new_v4()
+    } else {
+        TcpSocketBuilder::new_v6()
+    }
+}
+
+#[test]
+#[cfg_attr(target_env = "sgx", ignore)]
+fn tcp_socket_listen_connect() {
+    each_ip(&mut |addr| {
+        let builder = t!(tcp_socket_builder(addr));
+        t!(builder.set_reuseaddr(true));
+        assert_eq!(true, t!(builder.reuseaddr()));
+        t!(builder.bind(&addr));
+        assert_eq!(addr, t!(builder.local_addr()));
+        let listener = t!(builder.listen(16));
+        assert_eq!(addr, t!(listener.local_addr()));
+
+        let builder = t!(tcp_socket_builder(addr));
+        t!(builder.set_keepalive(true));
+        assert_eq!(true, t!(builder.keepalive()));
+        t!(builder.bind(&SocketAddr::new(addr.ip(), 0)));
+        let local = t!(builder.local_addr());
+        let mut stream = t!(builder.connect(&addr));
+        assert_eq!(local, t!(stream.local_addr()));
+
+        let (mut accepted, peer) = t!(listener.accept());
+        assert_eq!(local, peer);
+        t!(stream.write_all(b"hello"));
+        let mut buf = [0; 5];
+        t!(accepted.read_exact(&mut buf));
+        assert_eq!(b"hello", &buf);
+    })
+}
+
+#[test]
+#[cfg_attr(target_env = "sgx", ignore)]
+fn tcp_socket_buffer_size() {
+    let builder = t!(TcpSocketBuilder::new_v4());
+    t!(builder.set_send_buffer_size(64 * 1024));
+    assert!(t!(builder.send_buffer_size()) >= 64 * 1024);
+    t!(builder.set_recv_buffer_size(64 * 1024));
+    assert!(t!(builder.recv_buffer_size()) >= 64 * 1024);
+}
+
+#[test]
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn tcp_socket_reuseport() {
+    let addr = next_test_ip4();
+    let listeners: Vec<_> = (0..2)
+        .map(|_| {
+            let builder = t!(TcpSocketBuilder::new_v4());
+            t!(builder.set_reuseport(true));
+            assert_eq!(true, t!(builder.reuseport()));
+            t!(builder.bind(&addr));
+            t!(builder.listen(16))
+        })
+        .collect();
+
+    // Without the option, the address is in use.
+    let builder = t!(TcpSocketBuilder::new_v4());
+    assert_eq!(builder.bind(&addr).unwrap_err().kind(), ErrorKind::AddrInUse);
+    drop(listeners);
+}
+
+#[test]
+#[cfg(windows)]
+fn tcp_socket_reuseport_unsupported() {
+    let builder = t!(TcpSocketBuilder::new_v4());
+    assert_eq!(builder.set_reuseport(true).unwrap_err().kind(), ErrorKind::Unsupported);
+}
+
+#[test]
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn tcp_socket_keepalive_parameters() {
+    let builder = t!(TcpSocketBuilder::new_v4());
+    t!(builder.set_keepalive(true));
+    t!(builder.set_keepalive_idle(Duration::from_millis(60_500)));
+    assert_eq!(Duration::from_secs(60), t!(builder.keepalive_idle()));
+    t!(builder.set_keepalive_interval(Duration::from_secs(10)));
+    assert_eq!(Duration::from_secs(10), t!(builder.keepalive_interval()));
+    t!(builder.set_keepalive_retries(3));
+    assert_eq!(3, t!(builder.keepalive_retries()));
+
+    let err = builder.set_keepalive_idle(Duration::from_millis(500)).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+}
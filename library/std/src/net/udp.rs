@@ -840,3 +840,232 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.0.fmt(f)
     }
 }
+
+/// A builder for a [`UdpSocket`], to set the options that must be set before
+/// the socket is bound.
+///
+/// A `UdpSocketBuilder` holds a socket that is not bound yet, to which options
+/// such as [`set_reuseaddr`] are applied right away. [`bind`]ing it turns it
+/// into a [`UdpSocket`].
+///
+/// [`bind`]: UdpSocketBuilder::bind
+/// [`set_reuseaddr`]: UdpSocketBuilder::set_reuseaddr
+///
+/// # Examples
+///
+/// Binding several sockets to the same multicast address:
+///
+/// ```no_run
+/// #![feature(net_socket_builder)]
+/// use std::net::{Ipv4Addr, SocketAddr, UdpSocketBuilder};
+///
+/// fn main() -> std::io::Result<()> {
+///     let addr = SocketAddr::from(([0, 0, 0, 0], 5353));
+///     let mut sockets = Vec::new();
+///     for _ in 0..2 {
+///         let builder = UdpSocketBuilder::new_v4()?;
+///         builder.set_reuseaddr(true)?;
+///         let socket = builder.bind(&addr)?;
+///         socket.join_multicast_v4(&Ipv4Addr::new(224, 0, 0, 251), &Ipv4Addr::UNSPECIFIED)?;
+///         sockets.push(socket);
+///     }
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "net_socket_builder", issue = "none")]
+pub struct UdpSocketBuilder(net_imp::UdpSocketBuilder);
+
+impl UdpSocketBuilder {
+    /// Creates a builder for an IPv4 UDP socket.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(net_socket_builder)]
+    /// use std::net::UdpSocketBuilder;
+    ///
+    /// let builder = UdpSocketBuilder::new_v4().expect("couldn't create the socket");
+    /// ```
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn new_v4() -> io::Result<UdpSocketBuilder> {
+        net_imp::UdpSocketBuilder::new(&SocketAddr::from((Ipv4Addr::UNSPECIFIED, 0)))
+            .map(UdpSocketBuilder)
+    }
+
+    /// Creates a builder for an IPv6 UDP socket.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(net_socket_builder)]
+    /// use std::net::UdpSocketBuilder;
+    ///
+    /// let builder = UdpSocketBuilder::new_v6().expect("couldn't create the socket");
+    /// ```
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn new_v6() -> io::Result<UdpSocketBuilder> {
+        net_imp::UdpSocketBuilder::new(&SocketAddr::from((Ipv6Addr::UNSPECIFIED, 0)))
+            .map(UdpSocketBuilder)
+    }
+
+    /// Sets the value of the `SO_REUSEADDR` option on the socket.
+    ///
+    /// This allows several sockets to be bound to the same address, provided
+    /// that they all set this option. On Unix platforms, this is typically
+    /// used to receive multicast datagrams in several sockets.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(net_socket_builder)]
+    /// use std::net::UdpSocketBuilder;
+    ///
+    /// let builder = UdpSocketBuilder::new_v4().expect("couldn't create the socket");
+    /// builder.set_reuseaddr(true).expect("set_reuseaddr call failed");
+    /// ```
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn set_reuseaddr(&self, reuseaddr: bool) -> io::Result<()> {
+        self.0.set_reuseaddr(reuseaddr)
+    }
+
+    /// Gets the value of the `SO_REUSEADDR` option on the socket.
+    ///
+    /// For more information about this option, see
+    /// [`UdpSocketBuilder::set_reuseaddr`].
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn reuseaddr(&self) -> io::Result<bool> {
+        self.0.reuseaddr()
+    }
+
+    /// Sets the value of the `SO_REUSEPORT` option on the socket.
+    ///
+    /// This allows several sockets to be bound to the same address, provided
+    /// that they all set this option. On Linux, the unicast datagrams are then
+    /// spread over the sockets.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This option is not available on Windows and some Unix platforms, on
+    /// which this function returns an error of the kind
+    /// [`io::ErrorKind::Unsupported`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(net_socket_builder)]
+    /// use std::net::UdpSocketBuilder;
+    ///
+    /// let builder = UdpSocketBuilder::new_v4().expect("couldn't create the socket");
+    /// builder.set_reuseport(true).expect("set_reuseport call failed");
+    /// ```
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn set_reuseport(&self, reuseport: bool) -> io::Result<()> {
+        self.0.set_reuseport(reuseport)
+    }
+
+    /// Gets the value of the `SO_REUSEPORT` option on the socket.
+    ///
+    /// For more information about this option, see
+    /// [`UdpSocketBuilder::set_reuseport`].
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn reuseport(&self) -> io::Result<bool> {
+        self.0.reuseport()
+    }
+
+    /// Sets the value of the `SO_SNDBUF` option on the socket.
+    ///
+    /// The operating system may round the size, or clamp it to its limits.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// On Linux, the kernel doubles the requested size to make room for its
+    /// bookkeeping, and [`UdpSocketBuilder::send_buffer_size`] returns the
+    /// doubled size.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(net_socket_builder)]
+    /// use std::net::UdpSocketBuilder;
+    ///
+    /// let builder = UdpSocketBuilder::new_v4().expect("couldn't create the socket");
+    /// builder.set_send_buffer_size(1 << 20).expect("set_send_buffer_size call failed");
+    /// ```
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn set_send_buffer_size(&self, size: u32) -> io::Result<()> {
+        self.0.set_send_buffer_size(size)
+    }
+
+    /// Gets the value of the `SO_SNDBUF` option on the socket.
+    ///
+    /// For more information about this option, see
+    /// [`UdpSocketBuilder::set_send_buffer_size`].
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn send_buffer_size(&self) -> io::Result<u32> {
+        self.0.send_buffer_size()
+    }
+
+    /// Sets the value of the `SO_RCVBUF` option on the socket.
+    ///
+    /// This is the size of the buffer that holds the received datagrams until
+    /// they are read from the socket; datagrams that don't fit are dropped.
+    /// The operating system may round the size, or clamp it to its limits.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// On Linux, the kernel doubles the requested size to make room for its
+    /// bookkeeping, and [`UdpSocketBuilder::recv_buffer_size`] returns the
+    /// doubled size.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(net_socket_builder)]
+    /// use std::net::UdpSocketBuilder;
+    ///
+    /// let builder = UdpSocketBuilder::new_v4().expect("couldn't create the socket");
+    /// builder.set_recv_buffer_size(1 << 20).expect("set_recv_buffer_size call failed");
+    /// ```
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn set_recv_buffer_size(&self, size: u32) -> io::Result<()> {
+        self.0.set_recv_buffer_size(size)
+    }
+
+    /// Gets the value of the `SO_RCVBUF` option on the socket.
+    ///
+    /// For more information about this option, see
+    /// [`UdpSocketBuilder::set_recv_buffer_size`].
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn recv_buffer_size(&self) -> io::Result<u32> {
+        self.0.recv_buffer_size()
+    }
+
+    /// Binds the socket to the specified address, and returns it as a
+    /// [`UdpSocket`].
+    ///
+    /// The address must belong to the same family as the socket. Binding with
+    /// a port number of 0 will request that the OS assigns a port to the
+    /// socket, which can be queried with [`UdpSocket::local_addr`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(net_socket_builder)]
+    /// use std::net::{SocketAddr, UdpSocketBuilder};
+    ///
+    /// let builder = UdpSocketBuilder::new_v4().expect("couldn't create the socket");
+    /// let socket = builder.bind(&SocketAddr::from(([127, 0, 0, 1], 34254)))
+    ///     .expect("couldn't bind to address");
+    /// ```
+    #[unstable(feature = "net_socket_builder", issue = "none")]
+    pub fn bind(self, addr: &SocketAddr) -> io::Result<UdpSocket> {
+        self.0.bind(addr).map(UdpSocket)
+    }
+}
+
+#[unstable(feature = "net_socket_builder", issue = "none")]
+impl fmt::Debug for UdpSocketBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
@@ -365,3 +365,29 @@ fn set_nonblocking() {
         }
     })
 }
+
+#[test]
+fn builder_bind() {
+    each_ip(&mut |addr1, addr2| {
+        let builder = if addr1.is_ipv4() {
+            t!(UdpSocketBuilder::new_v4())
+        } else {
+            t!(UdpSocketBuilder::new_v6())
+        };
+        t!(builder.set_reuseaddr(true));
+        assert_eq!(true, t!(builder.reuseaddr()));
+        t!(builder.set_recv_buffer_size(64 * 1024));
+        assert!(t!(builder.recv_buffer_size()) >= 64 * 1024);
+        t!(builder.set_send_buffer_size(64 * 1024));
+        assert!(t!(builder.send_buffer_size()) >= 64 * 1024);
+        let server = t!(builder.bind(&addr1));
+        assert_eq!(addr1, t!(server.local_addr()));
+
+        let client = t!(UdpSocket::bind(&addr2));
+        t!(client.send_to(b"hello", &addr1));
+        let mut buf = [0; 5];
+        let (len, src) = t!(server.recv_from(&mut buf));
+        assert_eq!((5, addr2), (len, src));
+        assert_eq!(b"hello", &buf);
+    })
+}
@@ -0,0 +1,178 @@
+//! Linux-specific extensions to primitives in the [`std::thread`] module.
+//!
+//! [`std::thread`]: crate::thread
+
+#![unstable(feature = "linux_thread_sched", issue = "none")]
+
+use crate::io;
+use crate::sealed::Sealed;
+use crate::sys_common::AsInnerMut;
+use crate::thread::Builder;
+
+/// A scheduling policy of a thread, as described in [`sched(7)`].
+///
+/// [`sched(7)`]: https://man7.org/linux/man-pages/man7/sched.7.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SchedPolicy {
+    /// The default time-sharing policy, `SCHED_OTHER`.
+    Other,
+    /// The time-sharing policy for CPU-intensive threads, `SCHED_BATCH`.
+    Batch,
+    /// The policy for threads that only run when the CPU would be idle otherwise,
+    /// `SCHED_IDLE`.
+    Idle,
+    /// The first-in, first-out real-time policy, `SCHED_FIFO`.
+    Fifo,
+    /// The round-robin real-time policy, `SCHED_RR`.
+    RoundRobin,
+}
+
+impl SchedPolicy {
+    fn as_raw(self) -> i32 {
+        match self {
+            SchedPolicy::Other => libc::SCHED_OTHER,
+            SchedPolicy::Batch => libc::SCHED_BATCH,
+            SchedPolicy::Idle => libc::SCHED_IDLE,
+            SchedPolicy::Fifo => libc::SCHED_FIFO,
+            SchedPolicy::RoundRobin => libc::SCHED_RR,
+        }
+    }
+
+    fn from_raw(policy: i32) -> io::Result<SchedPolicy> {
+        // The flag that resets the policy of the children is reported along with the policy.
+        const SCHED_RESET_ON_FORK: i32 = 0x40000000;
+        match policy & !SCHED_RESET_ON_FORK {
+            libc::SCHED_OTHER => Ok(SchedPolicy::Other),
+            libc::SCHED_BATCH => Ok(SchedPolicy::Batch),
+            libc::SCHED_IDLE => Ok(SchedPolicy::Idle),
+            libc::SCHED_FIFO => Ok(SchedPolicy::Fifo),
+            libc::SCHED_RR => Ok(SchedPolicy::RoundRobin),
+            _ => Err(io::const_io_error!(
+                io::ErrorKind::InvalidData,
+                "the thread has an unknown scheduling policy",
+            )),
+        }
+    }
+}
+
+/// Linux-specific extensions to [`thread::Builder`].
+///
+/// The scheduling settings are applied by the new thread before it runs any
+/// code, and [`spawn`] waits until it did so that it can return an error if
+/// they can't be applied.
+///
+/// [`thread::Builder`]: Builder
+/// [`spawn`]: Builder::spawn
+pub trait BuilderExt: Sealed {
+    /// Sets the scheduling policy of the new thread.
+    ///
+    /// Unless a priority is set with [`sched_priority`], the thread runs with
+    /// the lowest priority of the policy, which is 0 for the time-sharing
+    /// policies and 1 for the real-time ones.
+    ///
+    /// [`sched_priority`]: BuilderExt::sched_priority
+    ///
+    /// # Errors
+    ///
+    /// [`spawn`] fails with an error of the kind [`PermissionDenied`] if the
+    /// process isn't allowed to use a real-time policy, which requires the
+    /// `CAP_SYS_NICE` capability or a large enough `RLIMIT_RTPRIO` limit.
+    ///
+    /// [`spawn`]: Builder::spawn
+    /// [`PermissionDenied`]: io::ErrorKind::PermissionDenied
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(linux_thread_sched)]
+    /// use std::os::linux::thread::{BuilderExt, SchedPolicy};
+    /// use std::thread;
+    ///
+    /// let handler = thread::Builder::new()
+    ///     .sched_policy(SchedPolicy::Fifo)
+    ///     .sched_priority(50)
+    ///     .spawn(|| {
+    ///         // latency-sensitive code
+    ///     })
+    ///     .unwrap();
+    ///
+    /// handler.join().unwrap();
+    /// ```
+    fn sched_policy(self, policy: SchedPolicy) -> Self;
+
+    /// Sets the static scheduling priority of the new thread.
+    ///
+    /// The priority must be 0 for the time-sharing policies, and range from 1
+    /// to 99 for the real-time ones. Unless a policy is set with
+    /// [`sched_policy`], the policy of the spawning thread is used.
+    ///
+    /// [`sched_policy`]: BuilderExt::sched_policy
+    fn sched_priority(self, priority: i32) -> Self;
+}
+
+impl BuilderExt for Builder {
+    fn sched_policy(mut self, policy: SchedPolicy) -> Builder {
+        self.as_inner_mut().sched_policy = Some(policy.as_raw());
+        self
+    }
+
+    fn sched_priority(mut self, priority: i32) -> Builder {
+        self.as_inner_mut().sched_priority = Some(priority);
+        self
+    }
+}
+
+/// Returns the indices of the CPUs the current thread may run on.
+///
+/// This corresponds to the `sched_getaffinity` function.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(linux_thread_sched, thread_affinity)]
+/// use std::os::linux::thread;
+///
+/// // Pin a thread to the first CPU the current thread may run on.
+/// let cpus = thread::affinity()?;
+/// let handler = std::thread::Builder::new()
+///     .affinity(&cpus[..1])
+///     .spawn(|| thread::affinity().unwrap())?;
+///
+/// assert_eq!(handler.join().unwrap(), &cpus[..1]);
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn affinity() -> io::Result<Vec<usize>> {
+    crate::sys::thread::current_affinity()
+}
+
+/// Returns the scheduling policy of the current thread.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(linux_thread_sched)]
+/// use std::os::linux::thread;
+///
+/// println!("scheduling policy: {:?}", thread::sched_policy()?);
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn sched_policy() -> io::Result<SchedPolicy> {
+    SchedPolicy::from_raw(crate::sys::thread::current_sched()?.0)
+}
+
+/// Returns the static scheduling priority of the current thread, which is 0
+/// unless it uses a real-time policy.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(linux_thread_sched)]
+/// use std::os::linux::thread;
+///
+/// println!("scheduling priority: {}", thread::sched_priority()?);
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn sched_priority() -> io::Result<i32> {
+    crate::sys::thread::current_sched().map(|(_, priority)| priority)
+}
@@ -187,6 +187,38 @@ fn arg0<S>(&mut self, arg: S) -> &mut process::Command
     /// ```
     #[stable(feature = "process_set_process_group", since = "1.64.0")]
     fn process_group(&mut self, pgroup: i32) -> &mut process::Command;
+
+    /// Sets whether the child process starts a new session, with a `setsid`
+    /// call in the child process.
+    ///
+    /// The child leads both the new session and a new process group, whose ID
+    /// is the child's process ID, and it has no controlling terminal. This
+    /// detaches it from the terminal further than [`process_group`] does: it
+    /// doesn't receive the signals of the terminal, such as SIGHUP when the
+    /// terminal is closed.
+    ///
+    /// This can't be combined with a [`process_group`] other than 0, as a
+    /// session leader can't join another process group.
+    ///
+    /// [`process_group`]: Self::process_group
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(process_setsid)]
+    /// use std::process::Command;
+    /// use std::os::unix::process::CommandExt;
+    ///
+    /// Command::new("sleep")
+    ///     .arg("10")
+    ///     .setsid(true)
+    ///     .spawn()?
+    ///     .wait()?;
+    /// #
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    #[unstable(feature = "process_setsid", issue = "none")]
+    fn setsid(&mut self, setsid: bool) -> &mut process::Command;
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -232,6 +264,94 @@ fn process_group(&mut self, pgroup: i32) -> &mut process::Command {
         self.as_inner_mut().pgroup(pgroup);
         self
     }
+
+    fn setsid(&mut self, setsid: bool) -> &mut process::Command {
+        self.as_inner_mut().setsid(setsid);
+        self
+    }
+}
+
+/// Unix-specific extensions to [`process::Child`].
+///
+/// This trait is sealed: it cannot be implemented outside the standard library.
+/// This is so that future additional methods are not breaking changes.
+#[unstable(feature = "unix_child_signal", issue = "none")]
+pub trait ChildExt: Sealed {
+    /// Sends the signal `signal` to the child.
+    ///
+    /// Like [`Child::kill`], this does nothing and returns `Ok(())` if the
+    /// child has been waited on already, as its process ID may have been
+    /// reused by another process since.
+    ///
+    /// [`Child::kill`]: process::Child::kill
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_child_signal)]
+    /// use std::process::Command;
+    /// use std::os::unix::process::ChildExt;
+    ///
+    /// let mut child = Command::new("sleep").arg("60").spawn()?;
+    /// // Send SIGTERM.
+    /// child.signal(15)?;
+    /// child.wait()?;
+    /// #
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    #[unstable(feature = "unix_child_signal", issue = "none")]
+    fn signal(&self, signal: i32) -> io::Result<()>;
+
+    /// Sends the signal `signal` to the process group led by the child, as
+    /// with `killpg`.
+    ///
+    /// The child leads a process group if it was spawned with
+    /// [`process_group(0)`] or [`setsid(true)`]. The processes it spawns join
+    /// its group unless they create their own, so this reaches the whole tree
+    /// of processes under the child. The group outlives the child as long as
+    /// it has members, so it can still be signalled after the child has been
+    /// waited on.
+    ///
+    /// [`process_group(0)`]: CommandExt::process_group
+    /// [`setsid(true)`]: CommandExt::setsid
+    ///
+    /// # Errors
+    ///
+    /// This fails if there is no process group led by the child, in particular
+    /// if the child joined another process group, or if all the members of the
+    /// group have exited already.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_child_signal)]
+    /// use std::process::Command;
+    /// use std::os::unix::process::{ChildExt, CommandExt};
+    ///
+    /// let mut child = Command::new("sh")
+    ///     .arg("-c")
+    ///     .arg("sleep 60 & sleep 60")
+    ///     .process_group(0)
+    ///     .spawn()?;
+    /// // Send SIGKILL to both `sleep` processes, along with the shell.
+    /// child.signal_group(9)?;
+    /// child.wait()?;
+    /// #
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    #[unstable(feature = "unix_child_signal", issue = "none")]
+    fn signal_group(&self, signal: i32) -> io::Result<()>;
+}
+
+#[unstable(feature = "unix_child_signal", issue = "none")]
+impl ChildExt for process::Child {
+    fn signal(&self, signal: i32) -> io::Result<()> {
+        self.as_inner().send_signal(signal)
+    }
+
+    fn signal_group(&self, signal: i32) -> io::Result<()> {
+        self.as_inner().send_signal_group(signal)
+    }
 }
 
 /// Unix-specific extensions to [`process::ExitStatus`] and
@@ -111,14 +111,18 @@
 use crate::fmt;
 use crate::fs;
 use crate::io::{self, BorrowedCursor, IoSlice, IoSliceMut};
+use crate::mem;
 use crate::num::NonZeroI32;
+use crate::ops;
 use crate::path::Path;
+use crate::ptr;
 use crate::str;
 use crate::sys::pipe::{read2, AnonPipe};
 use crate::sys::process as imp;
 #[stable(feature = "command_access", since = "1.57.0")]
 pub use crate::sys_common::process::CommandEnvs;
 use crate::sys_common::{AsInner, AsInnerMut, FromInner, IntoInner};
+use crate::time::{Duration, Instant};
 
 /// Representation of a running or exited child process.
 ///
@@ -130,7 +134,7 @@
 /// There is no implementation of [`Drop`] for child processes,
 /// so if you do not ensure the `Child` has exited then it will continue to
 /// run, even after the `Child` handle to the child process has gone out of
-/// scope.
+/// scope, unless the child was spawned with [`Command::kill_on_drop`].
 ///
 /// Calling [`wait`] (or other functions that wrap around it) will make
 /// the parent process wait until the child has actually exited before
@@ -166,7 +170,7 @@
 /// [`wait`]: Child::wait
 #[stable(feature = "process", since = "1.0.0")]
 pub struct Child {
-    pub(crate) handle: imp::Process,
+    pub(crate) handle: ChildHandle,
 
     /// The handle for writing to the child's standard input (stdin), if it
     /// has been captured. You might find it helpful to do
@@ -219,7 +223,7 @@ fn as_inner(&self) -> &imp::Process {
 impl FromInner<(imp::Process, imp::StdioPipes)> for Child {
     fn from_inner((handle, io): (imp::Process, imp::StdioPipes)) -> Child {
         Child {
-            handle,
+            handle: ChildHandle { inner: handle, kill_on_drop: false },
             stdin: io.stdin.map(ChildStdin::from_inner),
             stdout: io.stdout.map(ChildStdout::from_inner),
             stderr: io.stderr.map(ChildStderr::from_inner),
@@ -229,7 +233,51 @@ fn from_inner((handle, io): (imp::Process, imp::StdioPipes)) -> Child {
 
 impl IntoInner<imp::Process> for Child {
     fn into_inner(self) -> imp::Process {
-        self.handle
+        self.handle.into_inner()
+    }
+}
+
+/// The handle to the child process in a [`Child`], which kills and reaps the
+/// process when it's dropped if it was spawned with [`Command::kill_on_drop`].
+///
+/// `Child` itself can't implement [`Drop`], as its pipes are public fields
+/// that may be moved out of it.
+pub(crate) struct ChildHandle {
+    inner: imp::Process,
+    kill_on_drop: bool,
+}
+
+impl ChildHandle {
+    fn into_inner(self) -> imp::Process {
+        // The process is handed over to the caller, so it must not be killed.
+        let this = mem::ManuallyDrop::new(self);
+        unsafe { ptr::read(&this.inner) }
+    }
+}
+
+impl ops::Deref for ChildHandle {
+    type Target = imp::Process;
+
+    #[inline]
+    fn deref(&self) -> &imp::Process {
+        &self.inner
+    }
+}
+
+impl ops::DerefMut for ChildHandle {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut imp::Process {
+        &mut self.inner
+    }
+}
+
+impl Drop for ChildHandle {
+    fn drop(&mut self) {
+        if self.kill_on_drop && matches!(self.inner.try_wait(), Ok(None)) {
+            // Errors are ignored, as there is nothing left to do about them.
+            let _ = self.inner.kill();
+            let _ = self.inner.wait();
+        }
     }
 }
 
@@ -529,6 +577,7 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 #[cfg_attr(not(test), rustc_diagnostic_item = "Command")]
 pub struct Command {
     inner: imp::Command,
+    kill_on_drop: bool,
 }
 
 /// Allows extension traits within `std`.
@@ -580,7 +629,7 @@ impl Command {
     /// ```
     #[stable(feature = "process", since = "1.0.0")]
     pub fn new<S: AsRef<OsStr>>(program: S) -> Command {
-        Command { inner: imp::Command::new(program.as_ref()) }
+        Command { inner: imp::Command::new(program.as_ref()), kill_on_drop: false }
     }
 
     /// Adds an argument to pass to the program.
@@ -928,6 +977,41 @@ pub fn stderr<T: Into<Stdio>>(&mut self, cfg: T) -> &mut Command {
         self
     }
 
+    /// Configures whether the child process is killed when its [`Child`]
+    /// handle is dropped.
+    ///
+    /// By default, dropping a [`Child`] lets the child process run on. With
+    /// `kill_on_drop(true)`, dropping the [`Child`] of a process that hasn't
+    /// exited yet kills it as with [`Child::kill`], and waits for it to exit so
+    /// that it doesn't linger as a zombie. Converting the [`Child`] into a raw
+    /// handle to the process disables this.
+    ///
+    /// Only the child process itself is killed, not the processes it spawned in
+    /// turn. On Unix, those can be reached by placing the child in a new process
+    /// group with `CommandExt::process_group(0)` or `CommandExt::setsid(true)`,
+    /// and signalling the group with `ChildExt::signal_group`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(process_kill_on_drop)]
+    /// use std::process::Command;
+    ///
+    /// let child = Command::new("sleep")
+    ///     .arg("60")
+    ///     .kill_on_drop(true)
+    ///     .spawn()
+    ///     .expect("sleep command failed to start");
+    ///
+    /// // `sleep` is killed here.
+    /// drop(child);
+    /// ```
+    #[unstable(feature = "process_kill_on_drop", issue = "none")]
+    pub fn kill_on_drop(&mut self, kill_on_drop: bool) -> &mut Command {
+        self.kill_on_drop = kill_on_drop;
+        self
+    }
+
     /// Executes the command as a child process, returning a handle to it.
     ///
     /// By default, stdin, stdout and stderr are inherited from the parent.
@@ -945,7 +1029,9 @@ pub fn stderr<T: Into<Stdio>>(&mut self, cfg: T) -> &mut Command {
     /// ```
     #[stable(feature = "process", since = "1.0.0")]
     pub fn spawn(&mut self) -> io::Result<Child> {
-        self.inner.spawn(imp::Stdio::Inherit, true).map(Child::from_inner)
+        let mut child = self.inner.spawn(imp::Stdio::Inherit, true).map(Child::from_inner)?;
+        child.handle.kill_on_drop = self.kill_on_drop;
+        Ok(child)
     }
 
     /// Executes the command as a child process, waiting for it to finish and
@@ -2110,6 +2196,85 @@ pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
         Ok(self.handle.try_wait()?.map(ExitStatus))
     }
 
+    /// Waits for the child to exit for at most `timeout`.
+    ///
+    /// If the child has exited, then `Ok(Some(status))` is returned. If it's
+    /// still running once `timeout` has elapsed, then `Ok(None)` is returned
+    /// and the child keeps running. If an error occurs, then that error is
+    /// returned.
+    ///
+    /// Like [`wait`], the stdin handle to the child process, if any, is closed
+    /// before waiting.
+    ///
+    /// [`wait`]: Self::wait
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently waits on a pidfd on Linux, and on the process
+    /// handle on Windows. On other platforms it polls the child with
+    /// [`try_wait`], so it may return somewhat after the child has exited.
+    /// Note that this [may change in the future][changes].
+    ///
+    /// [`try_wait`]: Self::try_wait
+    /// [changes]: io#platform-specific-behavior
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(child_wait_timeout)]
+    /// use std::process::Command;
+    /// use std::time::Duration;
+    ///
+    /// let mut child = Command::new("sleep").arg("60").spawn()?;
+    ///
+    /// if child.wait_timeout(Duration::from_secs(1))?.is_none() {
+    ///     println!("sleep is taking too long, killing it");
+    ///     child.kill()?;
+    ///     child.wait()?;
+    /// }
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    #[unstable(feature = "child_wait_timeout", issue = "none")]
+    pub fn wait_timeout(&mut self, timeout: Duration) -> io::Result<Option<ExitStatus>> {
+        drop(self.stdin.take());
+        Ok(self.handle.wait_timeout(timeout)?.map(ExitStatus))
+    }
+
+    /// Waits for the child to exit until `deadline` at most.
+    ///
+    /// This is like [`wait_timeout`], but with an absolute deadline, which
+    /// makes it easier to share a time budget between several waits. If the
+    /// deadline has passed already, this only checks whether the child has
+    /// exited, like [`try_wait`] but closing stdin.
+    ///
+    /// [`wait_timeout`]: Self::wait_timeout
+    /// [`try_wait`]: Self::try_wait
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(child_wait_timeout)]
+    /// use std::process::Command;
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let deadline = Instant::now() + Duration::from_secs(10);
+    /// let mut children = vec![
+    ///     Command::new("sleep").arg("1").spawn()?,
+    ///     Command::new("sleep").arg("2").spawn()?,
+    /// ];
+    ///
+    /// for child in &mut children {
+    ///     if child.wait_deadline(deadline)?.is_none() {
+    ///         child.kill()?;
+    ///     }
+    /// }
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    #[unstable(feature = "child_wait_timeout", issue = "none")]
+    pub fn wait_deadline(&mut self, deadline: Instant) -> io::Result<Option<ExitStatus>> {
+        self.wait_timeout(deadline.saturating_duration_since(Instant::now()))
+    }
+
     /// Simultaneously waits for the child to exit and collect all remaining
     /// output on the stdout/stderr handles, returning an `Output`
     /// instance.
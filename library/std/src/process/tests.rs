@@ -727,3 +727,113 @@ fn terminate_exited_process() {
     assert!(p.kill().is_ok());
     assert!(p.kill().is_ok());
 }
+
+#[test]
+#[cfg(unix)]
+#[cfg_attr(target_os = "vxworks", ignore)]
+fn wait_timeout() {
+    use crate::time::{Duration, Instant};
+
+    let mut p = shell_cmd().arg("-c").arg("sleep 60").spawn().unwrap();
+    let start = Instant::now();
+    assert!(p.wait_timeout(Duration::from_millis(100)).unwrap().is_none());
+    assert!(start.elapsed() >= Duration::from_millis(100));
+    assert!(p.wait_deadline(start).unwrap().is_none());
+
+    p.kill().unwrap();
+    let status = p.wait_timeout(Duration::from_secs(60)).unwrap().unwrap();
+    assert!(!status.success());
+    assert_eq!(p.wait_deadline(start).unwrap(), Some(status));
+}
+
+#[test]
+#[cfg_attr(target_os = "vxworks", ignore)]
+fn wait_timeout_closes_stdin() {
+    use crate::time::Duration;
+
+    // The child exits once its stdin is closed.
+    let mut p = if cfg!(target_os = "windows") {
+        Command::new("findstr").arg("x").stdin(Stdio::piped()).spawn().unwrap()
+    } else {
+        shell_cmd().arg("-c").arg("cat").stdin(Stdio::piped()).spawn().unwrap()
+    };
+    assert!(p.wait_timeout(Duration::from_secs(60)).unwrap().is_some());
+    assert!(p.stdin.is_none());
+}
+
+#[test]
+#[cfg(unix)]
+#[cfg_attr(target_os = "vxworks", ignore)]
+fn kill_on_drop() {
+    // `exec` makes the child hold the pipe itself, so that it's closed once the child is killed.
+    let mut cmd = shell_cmd();
+    cmd.arg("-c").arg("exec sleep 60").stdout(Stdio::piped()).kill_on_drop(true);
+    #[cfg(target_os = "linux")]
+    crate::os::linux::process::CommandExt::create_pidfd(&mut cmd, true);
+    let mut p = cmd.spawn().unwrap();
+    let mut stdout = p.stdout.take().unwrap();
+    // Unlike the ID of the child, which may be reused once it's reaped, a pidfd refers to the
+    // child for good.
+    #[cfg(target_os = "linux")]
+    let pidfd = {
+        use crate::os::fd::AsFd;
+        use crate::os::linux::process::ChildExt;
+        p.pidfd().ok().map(|pidfd| pidfd.as_fd().try_clone_to_owned().unwrap())
+    };
+    drop(p);
+
+    // The child was killed, which closed its end of the pipe.
+    let mut output = String::new();
+    stdout.read_to_string(&mut output).unwrap();
+    assert_eq!(output, "");
+
+    // The child was reaped, so there is nothing left to wait for.
+    #[cfg(target_os = "linux")]
+    if let Some(pidfd) = pidfd {
+        use crate::os::fd::AsRawFd;
+        let mut info: libc::siginfo_t = unsafe { crate::mem::zeroed() };
+        let flags = libc::WEXITED | libc::WNOHANG;
+        let ret = unsafe { libc::waitid(libc::P_PIDFD, pidfd.as_raw_fd() as _, &mut info, flags) };
+        assert_eq!(ret, -1);
+        assert_eq!(crate::io::Error::last_os_error().raw_os_error(), Some(libc::ECHILD));
+    }
+
+    // Dropping a child that was waited on already does nothing.
+    let mut p = shell_cmd().arg("-c").arg("true").kill_on_drop(true).spawn().unwrap();
+    p.wait().unwrap();
+    drop(p);
+}
+
+#[test]
+#[cfg(unix)]
+#[cfg_attr(target_os = "vxworks", ignore)]
+fn signal_group() {
+    use crate::io::BufReader;
+    use crate::os::unix::process::{ChildExt, CommandExt, ExitStatusExt};
+
+    for setsid in [false, true] {
+        // The background `sleep` keeps stdout open until it's killed along with the shell.
+        let mut cmd = shell_cmd();
+        cmd.arg("-c").arg("sleep 60 & echo started; wait").stdout(Stdio::piped());
+        if setsid {
+            cmd.setsid(true);
+        } else {
+            cmd.process_group(0);
+        }
+        let mut p = cmd.spawn().unwrap();
+        let mut stdout = BufReader::new(p.stdout.take().unwrap());
+        let mut line = String::new();
+        stdout.read_line(&mut line).unwrap();
+        assert_eq!(line, "started\n");
+
+        p.signal_group(libc::SIGKILL).unwrap();
+        stdout.read_to_string(&mut line).unwrap();
+        assert_eq!(p.wait().unwrap().signal(), Some(libc::SIGKILL));
+    }
+
+    // A child in our own process group doesn't lead a group.
+    let mut p = shell_cmd().arg("-c").arg("sleep 60").spawn().unwrap();
+    assert!(p.signal_group(libc::SIGKILL).is_err());
+    p.signal(libc::SIGKILL).unwrap();
+    assert_eq!(p.wait().unwrap().signal(), Some(libc::SIGKILL));
+}
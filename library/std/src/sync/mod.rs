@@ -179,6 +179,8 @@
 pub use self::lazy_lock::LazyLock;
 #[stable(feature = "once_cell", since = "1.70.0")]
 pub use self::once_lock::OnceLock;
+#[unstable(feature = "semaphore", issue = "none")]
+pub use self::semaphore::{Semaphore, SemaphorePermit};
 
 pub(crate) use self::remutex::{ReentrantMutex, ReentrantMutexGuard};
 
@@ -194,3 +196,4 @@
 mod poison;
 mod remutex;
 mod rwlock;
+mod semaphore;
@@ -483,6 +483,86 @@ pub enum TrySendError<T> {
     Disconnected(#[stable(feature = "rust1", since = "1.0.0")] T),
 }
 
+/// An error returned from [`Select::try_select`] and [`Select::try_ready`]
+/// when none of the operations is ready.
+#[unstable(feature = "mpsc_select", issue = "none")]
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct TrySelectError;
+
+/// An error returned from [`Select::select_timeout`], [`Select::ready_timeout`]
+/// and their deadline variants when none of the operations became ready in time.
+#[unstable(feature = "mpsc_select", issue = "none")]
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct SelectTimeoutError;
+
+/// A set of send and receive operations to wait on at once.
+///
+/// Receive operations are added with [`recv`] and send operations on bounded
+/// channels with [`send`], each returning the index of the operation. Then one
+/// of the operations that is ready is picked with [`select`] and its variants,
+/// and has to be completed with [`SelectedOperation::recv`] or
+/// [`SelectedOperation::send`]. If several operations are ready at the same
+/// time, one of them is picked at random.
+///
+/// Alternatively, [`ready`] and its variants only return the index of an
+/// operation that is ready, which is then performed on its channel as usual,
+/// for example with [`try_recv`]. By the time it is performed, another thread
+/// may have made the operation block again.
+///
+/// An operation on a disconnected channel is always ready, and completes with
+/// an error.
+///
+/// [`recv`]: Select::recv
+/// [`send`]: Select::send
+/// [`select`]: Select::select
+/// [`ready`]: Select::ready
+/// [`try_recv`]: Receiver::try_recv
+///
+/// # Examples
+///
+/// ```
+/// #![feature(mpsc_select)]
+/// use std::sync::mpsc::{channel, Select};
+/// use std::thread;
+///
+/// let (tx1, rx1) = channel();
+/// let (tx2, rx2) = channel();
+///
+/// thread::spawn(move || tx1.send(10).unwrap());
+/// thread::spawn(move || tx2.send(20).unwrap());
+///
+/// let mut sel = Select::new();
+/// let oper1 = sel.recv(&rx1);
+/// let oper2 = sel.recv(&rx2);
+///
+/// // Both operations will eventually be ready, but only one is picked.
+/// let oper = sel.select();
+/// match oper.index() {
+///     i if i == oper1 => assert_eq!(oper.recv(&rx1), Ok(10)),
+///     i if i == oper2 => assert_eq!(oper.recv(&rx2), Ok(20)),
+///     _ => unreachable!(),
+/// }
+/// ```
+#[unstable(feature = "mpsc_select", issue = "none")]
+pub struct Select<'a> {
+    inner: mpmc::Select<'a>,
+}
+
+/// An operation picked by a [`Select`], which has to be completed.
+///
+/// The operation is completed with [`recv`] or [`send`], passing the same
+/// receiver or sender that was added to the [`Select`]. Dropping the operation
+/// without completing it panics, as the other side of a channel may be waiting
+/// for it.
+///
+/// [`recv`]: SelectedOperation::recv
+/// [`send`]: SelectedOperation::send
+#[unstable(feature = "mpsc_select", issue = "none")]
+#[must_use = "the selected operation has to be completed"]
+pub struct SelectedOperation<'a> {
+    inner: mpmc::SelectedOperation<'a>,
+}
+
 /// Creates a new asynchronous channel, returning the sender/receiver halves.
 /// All data sent on the [`Sender`] will become available on the [`Receiver`] in
 /// the same order as it was sent, and no [`send`] will block the calling thread
@@ -1040,6 +1120,385 @@ pub fn try_iter(&self) -> TryIter<'_, T> {
     }
 }
 
+impl<'a> Select<'a> {
+    /// Creates an empty set of operations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(mpsc_select)]
+    /// use std::sync::mpsc::Select;
+    ///
+    /// let mut sel = Select::new();
+    /// assert!(sel.try_select().is_err());
+    /// ```
+    #[unstable(feature = "mpsc_select", issue = "none")]
+    #[must_use]
+    pub fn new() -> Select<'a> {
+        Select { inner: mpmc::Select::new() }
+    }
+
+    /// Adds a receive operation on `r`, and returns its index.
+    ///
+    /// The indices are assigned in order, starting from zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(mpsc_select)]
+    /// use std::sync::mpsc::{channel, Select};
+    ///
+    /// let (tx, rx) = channel();
+    /// tx.send(1).unwrap();
+    ///
+    /// let mut sel = Select::new();
+    /// let index = sel.recv(&rx);
+    ///
+    /// let oper = sel.select();
+    /// assert_eq!(oper.index(), index);
+    /// assert_eq!(oper.recv(&rx), Ok(1));
+    /// ```
+    #[unstable(feature = "mpsc_select", issue = "none")]
+    pub fn recv<T>(&mut self, r: &'a Receiver<T>) -> usize {
+        self.inner.recv(&r.inner)
+    }
+
+    /// Adds a send operation on `s`, and returns its index.
+    ///
+    /// The indices are assigned in order, starting from zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(mpsc_select)]
+    /// use std::sync::mpsc::{sync_channel, Select};
+    ///
+    /// let (tx, rx) = sync_channel(1);
+    ///
+    /// let mut sel = Select::new();
+    /// let index = sel.send(&tx);
+    ///
+    /// let oper = sel.select();
+    /// assert_eq!(oper.index(), index);
+    /// oper.send(&tx, 1).unwrap();
+    /// assert_eq!(rx.recv(), Ok(1));
+    /// ```
+    #[unstable(feature = "mpsc_select", issue = "none")]
+    pub fn send<T>(&mut self, s: &'a SyncSender<T>) -> usize {
+        self.inner.send(&s.inner)
+    }
+
+    /// Removes the operation with the given index.
+    ///
+    /// The indices of the other operations are left unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no operation was added with this index, or if it was removed
+    /// already.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(mpsc_select)]
+    /// use std::sync::mpsc::{channel, Select};
+    ///
+    /// let (tx1, rx1) = channel::<i32>();
+    /// let (tx2, rx2) = channel();
+    /// drop(tx1);
+    /// tx2.send(2).unwrap();
+    ///
+    /// let mut sel = Select::new();
+    /// let oper1 = sel.recv(&rx1);
+    /// let oper2 = sel.recv(&rx2);
+    ///
+    /// // The disconnected channel would always be ready.
+    /// sel.remove(oper1);
+    ///
+    /// let oper = sel.select();
+    /// assert_eq!(oper.index(), oper2);
+    /// assert_eq!(oper.recv(&rx2), Ok(2));
+    /// ```
+    #[unstable(feature = "mpsc_select", issue = "none")]
+    pub fn remove(&mut self, index: usize) {
+        self.inner.remove(index)
+    }
+
+    /// Picks one of the operations that is ready, without blocking.
+    ///
+    /// Returns an error if none of the operations is ready.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(mpsc_select)]
+    /// use std::sync::mpsc::{channel, Select};
+    ///
+    /// let (tx, rx) = channel();
+    ///
+    /// let mut sel = Select::new();
+    /// sel.recv(&rx);
+    /// assert!(sel.try_select().is_err());
+    ///
+    /// tx.send(1).unwrap();
+    /// assert_eq!(sel.try_select().unwrap().recv(&rx), Ok(1));
+    /// ```
+    #[unstable(feature = "mpsc_select", issue = "none")]
+    pub fn try_select(&mut self) -> Result<SelectedOperation<'a>, TrySelectError> {
+        self.inner.try_select().map(|inner| SelectedOperation { inner })
+    }
+
+    /// Blocks the current thread until one of the operations is ready, and
+    /// picks it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no operations have been added, as this would block forever.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(mpsc_select)]
+    /// use std::sync::mpsc::{channel, sync_channel, Select};
+    /// use std::thread;
+    ///
+    /// let (tx, rx) = sync_channel(0);
+    /// let (_keep, idle) = channel::<i32>();
+    ///
+    /// thread::spawn(move || tx.send(1).unwrap());
+    ///
+    /// let mut sel = Select::new();
+    /// sel.recv(&idle);
+    /// let index = sel.recv(&rx);
+    ///
+    /// let oper = sel.select();
+    /// assert_eq!(oper.index(), index);
+    /// assert_eq!(oper.recv(&rx), Ok(1));
+    /// ```
+    #[unstable(feature = "mpsc_select", issue = "none")]
+    pub fn select(&mut self) -> SelectedOperation<'a> {
+        SelectedOperation { inner: self.inner.select() }
+    }
+
+    /// Blocks the current thread until one of the operations is ready, and
+    /// picks it, or until `timeout` elapses.
+    ///
+    /// If no operations have been added, this waits for the whole `timeout`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(mpsc_select)]
+    /// use std::sync::mpsc::{channel, Select, SelectTimeoutError};
+    /// use std::time::Duration;
+    ///
+    /// let (_tx, rx) = channel::<i32>();
+    ///
+    /// let mut sel = Select::new();
+    /// sel.recv(&rx);
+    /// assert_eq!(
+    ///     sel.select_timeout(Duration::from_millis(10)).map(|oper| oper.index()),
+    ///     Err(SelectTimeoutError),
+    /// );
+    /// ```
+    #[unstable(feature = "mpsc_select", issue = "none")]
+    pub fn select_timeout(
+        &mut self,
+        timeout: Duration,
+    ) -> Result<SelectedOperation<'a>, SelectTimeoutError> {
+        self.inner.select_timeout(timeout).map(|inner| SelectedOperation { inner })
+    }
+
+    /// Blocks the current thread until one of the operations is ready, and
+    /// picks it, or until `deadline` is reached.
+    ///
+    /// If no operations have been added, this waits until the `deadline`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(mpsc_select)]
+    /// use std::sync::mpsc::{channel, Select};
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let (tx, rx) = channel();
+    /// tx.send(1).unwrap();
+    ///
+    /// let mut sel = Select::new();
+    /// sel.recv(&rx);
+    /// let oper = sel.select_deadline(Instant::now() + Duration::from_secs(1)).unwrap();
+    /// assert_eq!(oper.recv(&rx), Ok(1));
+    /// ```
+    #[unstable(feature = "mpsc_select", issue = "none")]
+    pub fn select_deadline(
+        &mut self,
+        deadline: Instant,
+    ) -> Result<SelectedOperation<'a>, SelectTimeoutError> {
+        self.inner.select_deadline(deadline).map(|inner| SelectedOperation { inner })
+    }
+
+    /// Returns the index of one of the operations that is ready, without
+    /// blocking.
+    ///
+    /// Returns an error if none of the operations is ready.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(mpsc_select)]
+    /// use std::sync::mpsc::{channel, Select, TrySelectError};
+    ///
+    /// let (tx, rx) = channel();
+    ///
+    /// let mut sel = Select::new();
+    /// let index = sel.recv(&rx);
+    /// assert_eq!(sel.try_ready(), Err(TrySelectError));
+    ///
+    /// tx.send(1).unwrap();
+    /// assert_eq!(sel.try_ready(), Ok(index));
+    /// assert_eq!(rx.try_recv(), Ok(1));
+    /// ```
+    #[unstable(feature = "mpsc_select", issue = "none")]
+    pub fn try_ready(&mut self) -> Result<usize, TrySelectError> {
+        self.inner.try_ready()
+    }
+
+    /// Blocks the current thread until one of the operations is ready, and
+    /// returns its index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no operations have been added, as this would block forever.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(mpsc_select)]
+    /// use std::sync::mpsc::{channel, Select};
+    /// use std::thread;
+    ///
+    /// let (tx, rx) = channel();
+    /// thread::spawn(move || tx.send(1).unwrap());
+    ///
+    /// let mut sel = Select::new();
+    /// let index = sel.recv(&rx);
+    /// assert_eq!(sel.ready(), index);
+    /// assert_eq!(rx.try_recv(), Ok(1));
+    /// ```
+    #[unstable(feature = "mpsc_select", issue = "none")]
+    pub fn ready(&mut self) -> usize {
+        self.inner.ready()
+    }
+
+    /// Blocks the current thread until one of the operations is ready, and
+    /// returns its index, or until `timeout` elapses.
+    ///
+    /// If no operations have been added, this waits for the whole `timeout`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(mpsc_select)]
+    /// use std::sync::mpsc::{channel, Select, SelectTimeoutError};
+    /// use std::time::Duration;
+    ///
+    /// let (_tx, rx) = channel::<i32>();
+    ///
+    /// let mut sel = Select::new();
+    /// sel.recv(&rx);
+    /// assert_eq!(sel.ready_timeout(Duration::from_millis(10)), Err(SelectTimeoutError));
+    /// ```
+    #[unstable(feature = "mpsc_select", issue = "none")]
+    pub fn ready_timeout(&mut self, timeout: Duration) -> Result<usize, SelectTimeoutError> {
+        self.inner.ready_timeout(timeout)
+    }
+
+    /// Blocks the current thread until one of the operations is ready, and
+    /// returns its index, or until `deadline` is reached.
+    ///
+    /// If no operations have been added, this waits until the `deadline`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(mpsc_select)]
+    /// use std::sync::mpsc::{sync_channel, Select};
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let (tx, _rx) = sync_channel::<i32>(1);
+    ///
+    /// let mut sel = Select::new();
+    /// let index = sel.send(&tx);
+    /// assert_eq!(sel.ready_deadline(Instant::now() + Duration::from_secs(1)), Ok(index));
+    /// ```
+    #[unstable(feature = "mpsc_select", issue = "none")]
+    pub fn ready_deadline(&mut self, deadline: Instant) -> Result<usize, SelectTimeoutError> {
+        self.inner.ready_deadline(deadline)
+    }
+}
+
+#[unstable(feature = "mpsc_select", issue = "none")]
+impl<'a> Clone for Select<'a> {
+    fn clone(&self) -> Select<'a> {
+        Select { inner: self.inner.clone() }
+    }
+}
+
+#[unstable(feature = "mpsc_select", issue = "none")]
+impl<'a> Default for Select<'a> {
+    fn default() -> Select<'a> {
+        Select::new()
+    }
+}
+
+#[unstable(feature = "mpsc_select", issue = "none")]
+impl fmt::Debug for Select<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+impl SelectedOperation<'_> {
+    /// Returns the index of the operation, as returned when it was added to
+    /// the [`Select`].
+    #[unstable(feature = "mpsc_select", issue = "none")]
+    #[must_use]
+    pub fn index(&self) -> usize {
+        self.inner.index()
+    }
+
+    /// Completes the receive operation on `r`.
+    ///
+    /// Returns an error if the channel is empty and disconnected.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `r` is not the receiver that was added for this operation.
+    #[unstable(feature = "mpsc_select", issue = "none")]
+    pub fn recv<T>(self, r: &Receiver<T>) -> Result<T, RecvError> {
+        self.inner.recv(&r.inner)
+    }
+
+    /// Completes the send operation on `s`, sending `msg`.
+    ///
+    /// Returns an error containing `msg` if the channel is disconnected.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `s` is not the sender that was added for this operation.
+    #[unstable(feature = "mpsc_select", issue = "none")]
+    pub fn send<T>(self, s: &SyncSender<T>, msg: T) -> Result<(), SendError<T>> {
+        self.inner.send(&s.inner, msg)
+    }
+}
+
+#[unstable(feature = "mpsc_select", issue = "none")]
+impl fmt::Debug for SelectedOperation<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<'a, T> Iterator for Iter<'a, T> {
     type Item = T;
@@ -1244,3 +1703,23 @@ fn from(err: RecvError) -> RecvTimeoutError {
         }
     }
 }
+
+#[unstable(feature = "mpsc_select", issue = "none")]
+impl fmt::Display for TrySelectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        "all operations would block".fmt(f)
+    }
+}
+
+#[unstable(feature = "mpsc_select", issue = "none")]
+impl error::Error for TrySelectError {}
+
+#[unstable(feature = "mpsc_select", issue = "none")]
+impl fmt::Display for SelectTimeoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        "timed out waiting on select".fmt(f)
+    }
+}
+
+#[unstable(feature = "mpsc_select", issue = "none")]
+impl error::Error for SelectTimeoutError {}
@@ -668,3 +668,67 @@ fn drop_unreceived() {
     assert!(weak.upgrade().is_none());
     drop(tx);
 }
+
+#[test]
+fn select_send() {
+    let (tx1, rx1) = sync_channel::<i32>(1);
+    let (tx2, rx2) = sync_channel::<i32>(1);
+    tx1.send(0).unwrap();
+
+    let mut sel = Select::new();
+    let oper1 = sel.send(&tx1);
+    let oper2 = sel.send(&tx2);
+
+    // Only the second channel has room for a message.
+    let oper = sel.select();
+    assert_eq!(oper.index(), oper2);
+    oper.send(&tx2, 2).unwrap();
+    assert_eq!(sel.try_ready(), Err(TrySelectError));
+
+    let t = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(10));
+        assert_eq!(rx1.recv(), Ok(0));
+        rx1
+    });
+    let oper = sel.select();
+    assert_eq!(oper.index(), oper1);
+    oper.send(&tx1, 1).unwrap();
+    let rx1 = t.join().unwrap();
+
+    assert_eq!(rx1.recv(), Ok(1));
+    assert_eq!(rx2.recv(), Ok(2));
+}
+
+#[test]
+fn select_rendezvous() {
+    let (tx1, rx1) = sync_channel::<i32>(0);
+    let (tx2, rx2) = sync_channel::<i32>(0);
+
+    let t = thread::spawn(move || {
+        let mut sel = Select::new();
+        let recv = sel.recv(&rx1);
+        let send = sel.send(&tx2);
+        for _ in 0..2 {
+            let oper = sel.select();
+            match oper.index() {
+                i if i == recv => assert_eq!(oper.recv(&rx1), Ok(1)),
+                i if i == send => oper.send(&tx2, 2).unwrap(),
+                _ => unreachable!(),
+            }
+        }
+    });
+
+    tx1.send(1).unwrap();
+    assert_eq!(rx2.recv(), Ok(2));
+    t.join().unwrap();
+}
+
+#[test]
+fn select_rendezvous_disconnected() {
+    let (tx, rx) = sync_channel::<i32>(0);
+    drop(rx);
+
+    let mut sel = Select::new();
+    sel.send(&tx);
+    assert_eq!(sel.select_timeout(Duration::from_secs(1)).unwrap().send(&tx, 1), Err(SendError(1)));
+}
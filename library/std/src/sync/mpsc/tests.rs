@@ -720,3 +720,125 @@ fn issue_39364() {
     let _tx = t.join().unwrap(); // delay dropping until end of test
     let _ = rx.recv_timeout(Duration::from_millis(500));
 }
+
+#[test]
+fn select_recv() {
+    let (tx1, rx1) = channel::<i32>();
+    let (tx2, rx2) = channel::<i32>();
+
+    let mut sel = Select::new();
+    let oper1 = sel.recv(&rx1);
+    let oper2 = sel.recv(&rx2);
+    assert_eq!((oper1, oper2), (0, 1));
+    assert!(sel.try_select().is_err());
+
+    tx2.send(2).unwrap();
+    let oper = sel.select();
+    assert_eq!(oper.index(), oper2);
+    assert_eq!(oper.recv(&rx2), Ok(2));
+
+    let t = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(10));
+        tx1.send(1).unwrap();
+    });
+    let oper = sel.select();
+    assert_eq!(oper.index(), oper1);
+    assert_eq!(oper.recv(&rx1), Ok(1));
+    t.join().unwrap();
+
+    // A disconnected channel is always ready.
+    let oper = sel.select();
+    assert_eq!(oper.index(), oper1);
+    assert_eq!(oper.recv(&rx1), Err(RecvError));
+}
+
+#[test]
+fn select_timeout() {
+    let (_tx, rx) = channel::<i32>();
+
+    let mut sel = Select::new();
+    sel.recv(&rx);
+    assert_eq!(
+        sel.select_timeout(Duration::from_millis(10)).map(|o| o.index()),
+        Err(SelectTimeoutError)
+    );
+    assert_eq!(sel.ready_timeout(Duration::from_millis(10)), Err(SelectTimeoutError));
+    assert_eq!(sel.try_ready(), Err(TrySelectError));
+
+    // Without any operation, this waits for the timeout.
+    let mut sel = Select::new();
+    assert!(sel.select_timeout(Duration::from_millis(10)).is_err());
+    assert!(sel.ready_deadline(Instant::now() + Duration::from_millis(10)).is_err());
+}
+
+#[test]
+fn select_ready_remove() {
+    let (tx1, rx1) = channel::<i32>();
+    let (tx2, rx2) = channel::<i32>();
+    drop(tx1);
+
+    let mut sel = Select::new();
+    let oper1 = sel.recv(&rx1);
+    let oper2 = sel.recv(&rx2);
+    assert_eq!(sel.ready(), oper1);
+
+    sel.remove(oper1);
+    assert_eq!(sel.try_ready(), Err(TrySelectError));
+
+    let t = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(10));
+        tx2.send(2).unwrap();
+    });
+    assert_eq!(sel.ready(), oper2);
+    assert_eq!(rx2.try_recv(), Ok(2));
+    t.join().unwrap();
+}
+
+#[test]
+#[should_panic(expected = "passed a receiver that wasn't selected")]
+fn select_wrong_receiver() {
+    let (tx1, rx1) = channel::<i32>();
+    let (_tx2, rx2) = channel::<i32>();
+    tx1.send(1).unwrap();
+
+    let mut sel = Select::new();
+    sel.recv(&rx1);
+    let _ = sel.select().recv(&rx2);
+}
+
+#[test]
+fn select_stress() {
+    const AMT: usize = 10000;
+    let (tx1, rx1) = channel::<usize>();
+    let (tx2, rx2) = channel::<usize>();
+
+    let t1 = thread::spawn(move || {
+        for i in 0..AMT {
+            tx1.send(i).unwrap();
+        }
+    });
+    let t2 = thread::spawn(move || {
+        for i in 0..AMT {
+            tx2.send(i).unwrap();
+        }
+    });
+
+    let mut next = [0, 0];
+    let mut sel = Select::new();
+    let opers = [sel.recv(&rx1), sel.recv(&rx2)];
+    while next != [AMT, AMT] {
+        let oper = sel.select();
+        let (i, rx) = if oper.index() == opers[0] { (0, &rx1) } else { (1, &rx2) };
+        match oper.recv(rx) {
+            Ok(n) => {
+                assert_eq!(n, next[i]);
+                next[i] += 1;
+            }
+            // The channel is drained and disconnected, stop selecting it.
+            Err(RecvError) => sel.remove(opers[i]),
+        }
+    }
+
+    t1.join().unwrap();
+    t2.join().unwrap();
+}
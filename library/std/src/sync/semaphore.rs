@@ -0,0 +1,326 @@
+#[cfg(test)]
+mod tests;
+
+use crate::fmt;
+use crate::sys_common::semaphore as sys;
+use crate::time::{Duration, Instant};
+
+/// A counting semaphore, which limits how many threads can use a resource at
+/// the same time.
+///
+/// A semaphore holds a number of permits. Threads acquire permits with
+/// [`acquire`] or [`acquire_many`], which block until enough permits are
+/// available, and return them by dropping the [`SemaphorePermit`] guard.
+///
+/// The semaphore does not guarantee in which order blocked threads acquire
+/// their permits once they become available. In particular, a thread waiting
+/// for many permits may keep waiting while threads that need fewer permits
+/// acquire them.
+///
+/// [`acquire`]: Semaphore::acquire
+/// [`acquire_many`]: Semaphore::acquire_many
+///
+/// # Examples
+///
+/// ```
+/// #![feature(semaphore)]
+/// use std::sync::{Arc, Semaphore};
+/// use std::thread;
+///
+/// // At most two threads run the job at the same time.
+/// let semaphore = Arc::new(Semaphore::new(2));
+/// let handles: Vec<_> = (0..8)
+///     .map(|i| {
+///         let semaphore = Arc::clone(&semaphore);
+///         thread::spawn(move || {
+///             let _permit = semaphore.acquire();
+///             println!("running job {i}");
+///         })
+///     })
+///     .collect();
+///
+/// for handle in handles {
+///     handle.join().unwrap();
+/// }
+/// ```
+#[unstable(feature = "semaphore", issue = "none")]
+pub struct Semaphore {
+    inner: sys::Semaphore,
+}
+
+/// An RAII guard holding permits of a [`Semaphore`], which are returned to
+/// it when the guard is dropped.
+///
+/// This structure is created by the [`acquire`] and [`try_acquire`] methods
+/// on [`Semaphore`] and their variants.
+///
+/// [`acquire`]: Semaphore::acquire
+/// [`try_acquire`]: Semaphore::try_acquire
+#[unstable(feature = "semaphore", issue = "none")]
+#[must_use = "if unused the permits will immediately be released"]
+#[clippy::has_significant_drop]
+pub struct SemaphorePermit<'a> {
+    semaphore: &'a Semaphore,
+    permits: usize,
+}
+
+impl Semaphore {
+    /// Creates a new semaphore with the given number of available permits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(semaphore)]
+    /// use std::sync::Semaphore;
+    ///
+    /// let semaphore = Semaphore::new(4);
+    /// assert_eq!(semaphore.available_permits(), 4);
+    /// ```
+    #[unstable(feature = "semaphore", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn new(permits: usize) -> Semaphore {
+        Semaphore { inner: sys::Semaphore::new(permits) }
+    }
+
+    /// Acquires a permit, blocking the current thread until one is available.
+    ///
+    /// The permit is returned to the semaphore when the returned guard is
+    /// dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(semaphore)]
+    /// use std::sync::Semaphore;
+    ///
+    /// let semaphore = Semaphore::new(1);
+    /// let permit = semaphore.acquire();
+    /// assert_eq!(semaphore.available_permits(), 0);
+    /// drop(permit);
+    /// assert_eq!(semaphore.available_permits(), 1);
+    /// ```
+    #[unstable(feature = "semaphore", issue = "none")]
+    pub fn acquire(&self) -> SemaphorePermit<'_> {
+        self.acquire_many(1)
+    }
+
+    /// Acquires `n` permits at once, blocking the current thread until that
+    /// many are available.
+    ///
+    /// The permits are only taken once all of them are available, so that
+    /// two threads acquiring several permits can't deadlock by each holding
+    /// some of them. This blocks forever if the semaphore never holds `n`
+    /// permits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(semaphore)]
+    /// use std::sync::Semaphore;
+    ///
+    /// let semaphore = Semaphore::new(3);
+    /// let permit = semaphore.acquire_many(2);
+    /// assert_eq!(permit.permits(), 2);
+    /// assert_eq!(semaphore.available_permits(), 1);
+    /// ```
+    #[unstable(feature = "semaphore", issue = "none")]
+    pub fn acquire_many(&self, n: usize) -> SemaphorePermit<'_> {
+        self.inner.acquire(n, None);
+        SemaphorePermit { semaphore: self, permits: n }
+    }
+
+    /// Attempts to acquire a permit without blocking.
+    ///
+    /// Returns `None` if no permit is available.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(semaphore)]
+    /// use std::sync::Semaphore;
+    ///
+    /// let semaphore = Semaphore::new(1);
+    /// let permit = semaphore.try_acquire().unwrap();
+    /// assert!(semaphore.try_acquire().is_none());
+    /// ```
+    #[unstable(feature = "semaphore", issue = "none")]
+    pub fn try_acquire(&self) -> Option<SemaphorePermit<'_>> {
+        self.try_acquire_many(1)
+    }
+
+    /// Attempts to acquire `n` permits at once without blocking.
+    ///
+    /// Returns `None` if fewer than `n` permits are available, in which case
+    /// none of them are taken.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(semaphore)]
+    /// use std::sync::Semaphore;
+    ///
+    /// let semaphore = Semaphore::new(2);
+    /// assert!(semaphore.try_acquire_many(3).is_none());
+    /// assert!(semaphore.try_acquire_many(2).is_some());
+    /// ```
+    #[unstable(feature = "semaphore", issue = "none")]
+    pub fn try_acquire_many(&self, n: usize) -> Option<SemaphorePermit<'_>> {
+        self.inner.try_acquire(n).then(|| SemaphorePermit { semaphore: self, permits: n })
+    }
+
+    /// Acquires a permit, blocking the current thread for at most `dur`.
+    ///
+    /// Returns `None` if no permit became available before the timeout
+    /// elapsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(semaphore)]
+    /// use std::sync::Semaphore;
+    /// use std::time::Duration;
+    ///
+    /// let semaphore = Semaphore::new(1);
+    /// let permit = semaphore.acquire_timeout(Duration::from_millis(10)).unwrap();
+    /// assert!(semaphore.acquire_timeout(Duration::from_millis(10)).is_none());
+    /// ```
+    #[unstable(feature = "semaphore", issue = "none")]
+    pub fn acquire_timeout(&self, dur: Duration) -> Option<SemaphorePermit<'_>> {
+        self.acquire_many_timeout(1, dur)
+    }
+
+    /// Acquires `n` permits at once, blocking the current thread for at most
+    /// `dur`.
+    ///
+    /// Returns `None` if fewer than `n` permits were available when the
+    /// timeout elapsed, in which case none of them are taken.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(semaphore)]
+    /// use std::sync::Semaphore;
+    /// use std::time::Duration;
+    ///
+    /// let semaphore = Semaphore::new(2);
+    /// assert!(semaphore.acquire_many_timeout(3, Duration::from_millis(10)).is_none());
+    /// assert_eq!(semaphore.available_permits(), 2);
+    /// ```
+    #[unstable(feature = "semaphore", issue = "none")]
+    pub fn acquire_many_timeout(&self, n: usize, dur: Duration) -> Option<SemaphorePermit<'_>> {
+        // A timeout too large to be represented waits forever.
+        let deadline = Instant::now().checked_add(dur);
+        self.inner.acquire(n, deadline).then(|| SemaphorePermit { semaphore: self, permits: n })
+    }
+
+    /// Adds `n` permits to the semaphore, waking up the threads waiting for
+    /// them.
+    ///
+    /// This can be used to raise the limit of the semaphore, or together
+    /// with [`SemaphorePermit::forget`] to release permits from another
+    /// thread than the one that acquired them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of available permits overflows a `usize`, in
+    /// which case none of the `n` permits are added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(semaphore)]
+    /// use std::sync::Semaphore;
+    ///
+    /// let semaphore = Semaphore::new(0);
+    /// semaphore.add_permits(2);
+    /// assert_eq!(semaphore.available_permits(), 2);
+    /// ```
+    #[unstable(feature = "semaphore", issue = "none")]
+    pub fn add_permits(&self, n: usize) {
+        self.inner.release(n);
+    }
+
+    /// Returns the number of permits that are currently available.
+    ///
+    /// As other threads may acquire or release permits concurrently, the
+    /// returned value may be out of date as soon as this returns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(semaphore)]
+    /// use std::sync::Semaphore;
+    ///
+    /// let semaphore = Semaphore::new(2);
+    /// let _permit = semaphore.acquire();
+    /// assert_eq!(semaphore.available_permits(), 1);
+    /// ```
+    #[unstable(feature = "semaphore", issue = "none")]
+    #[must_use]
+    pub fn available_permits(&self) -> usize {
+        self.inner.available()
+    }
+}
+
+#[unstable(feature = "semaphore", issue = "none")]
+impl fmt::Debug for Semaphore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Semaphore").field("available_permits", &self.available_permits()).finish()
+    }
+}
+
+impl SemaphorePermit<'_> {
+    /// Returns the number of permits held by this guard.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(semaphore)]
+    /// use std::sync::Semaphore;
+    ///
+    /// let semaphore = Semaphore::new(4);
+    /// assert_eq!(semaphore.acquire_many(3).permits(), 3);
+    /// ```
+    #[unstable(feature = "semaphore", issue = "none")]
+    #[must_use]
+    pub fn permits(&self) -> usize {
+        self.permits
+    }
+
+    /// Consumes the guard without returning its permits to the semaphore.
+    ///
+    /// The permits can be returned later with [`Semaphore::add_permits`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(semaphore)]
+    /// use std::sync::Semaphore;
+    ///
+    /// let semaphore = Semaphore::new(2);
+    /// semaphore.acquire().forget();
+    /// assert_eq!(semaphore.available_permits(), 1);
+    /// ```
+    #[unstable(feature = "semaphore", issue = "none")]
+    pub fn forget(mut self) {
+        self.permits = 0;
+    }
+}
+
+#[unstable(feature = "semaphore", issue = "none")]
+impl Drop for SemaphorePermit<'_> {
+    #[inline]
+    fn drop(&mut self) {
+        if self.permits > 0 {
+            self.semaphore.add_permits(self.permits);
+        }
+    }
+}
+
+#[unstable(feature = "semaphore", issue = "none")]
+impl fmt::Debug for SemaphorePermit<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SemaphorePermit").field("permits", &self.permits).finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,116 @@
+use crate::sync::atomic::{AtomicUsize, Ordering};
+use crate::sync::mpsc::channel;
+use crate::sync::{Arc, Semaphore};
+use crate::thread;
+use crate::time::Duration;
+
+#[test]
+fn smoke() {
+    let s = Semaphore::new(2);
+    let a = s.acquire();
+    let b = s.try_acquire().unwrap();
+    assert!(s.try_acquire().is_none());
+    drop(a);
+    assert_eq!(s.available_permits(), 1);
+    drop(b);
+    assert_eq!(s.available_permits(), 2);
+}
+
+#[test]
+fn many() {
+    let s = Semaphore::new(3);
+    let a = s.acquire_many(2);
+    assert_eq!(a.permits(), 2);
+    assert!(s.try_acquire_many(2).is_none());
+    assert_eq!(s.available_permits(), 1);
+    assert_eq!(s.try_acquire_many(0).unwrap().permits(), 0);
+    drop(a);
+    assert!(s.try_acquire_many(3).is_some());
+}
+
+#[test]
+fn forget_and_add() {
+    let s = Semaphore::new(1);
+    s.acquire().forget();
+    assert_eq!(s.available_permits(), 0);
+    s.add_permits(2);
+    assert_eq!(s.available_permits(), 2);
+}
+
+#[test]
+fn timeout() {
+    let s = Semaphore::new(1);
+    let _p = s.acquire();
+    assert!(s.acquire_timeout(Duration::from_millis(10)).is_none());
+    assert!(s.acquire_many_timeout(2, Duration::ZERO).is_none());
+    assert_eq!(s.available_permits(), 0);
+}
+
+#[test]
+#[cfg_attr(target_os = "emscripten", ignore)]
+fn timeout_wakeup() {
+    let s = Arc::new(Semaphore::new(0));
+    let s2 = s.clone();
+    let t = thread::spawn(move || s2.acquire_many_timeout(2, Duration::from_secs(60)).is_some());
+    s.add_permits(1);
+    s.add_permits(1);
+    assert!(t.join().unwrap());
+    assert_eq!(s.available_permits(), 2);
+}
+
+#[test]
+#[cfg_attr(target_os = "emscripten", ignore)]
+fn limits_concurrency() {
+    const N: usize = 10;
+    const LIMIT: usize = 3;
+
+    let s = Arc::new(Semaphore::new(LIMIT));
+    let running = Arc::new(AtomicUsize::new(0));
+    let (tx, rx) = channel();
+    for _ in 0..N {
+        let (s, running, tx) = (s.clone(), running.clone(), tx.clone());
+        thread::spawn(move || {
+            let _p = s.acquire();
+            let n = running.fetch_add(1, Ordering::SeqCst) + 1;
+            tx.send(n).unwrap();
+            thread::sleep(Duration::from_millis(1));
+            running.fetch_sub(1, Ordering::SeqCst);
+        });
+    }
+    drop(tx);
+    assert_eq!(rx.iter().filter(|&n| n > LIMIT).count(), 0);
+    assert_eq!(s.available_permits(), LIMIT);
+}
+
+#[test]
+#[cfg_attr(target_os = "emscripten", ignore)]
+fn wakes_all_needed_waiters() {
+    let s = Arc::new(Semaphore::new(0));
+    let threads: Vec<_> = [3, 1, 2, 1, 1, 1]
+        .into_iter()
+        .map(|n| {
+            let s = s.clone();
+            thread::spawn(move || s.acquire_many(n).forget())
+        })
+        .collect();
+    s.add_permits(4);
+    for _ in 0..5 {
+        s.add_permits(1);
+    }
+    for t in threads {
+        t.join().unwrap();
+    }
+    assert_eq!(s.available_permits(), 0);
+}
+
+#[test]
+fn overflow() {
+    let s = Arc::new(Semaphore::new(usize::MAX));
+    let p = s.acquire();
+    let s2 = s.clone();
+    assert!(thread::spawn(move || s2.add_permits(2)).join().is_err());
+    assert_eq!(s.available_permits(), usize::MAX - 1);
+    drop(p);
+    assert_eq!(s.available_permits(), usize::MAX);
+    assert!(s.acquire_timeout(Duration::ZERO).is_some());
+}
@@ -0,0 +1,17 @@
+//! Anonymous pipes for `io::pipe`.
+//!
+//! Unlike the pipes set up by `Stdio::piped`, whose end in this process may be opened for
+//! asynchronous I/O, both ends of these pipes can be handed to any process.
+
+cfg_if::cfg_if! {
+    if #[cfg(unix)] {
+        mod unix;
+        pub use unix::{pipe, AnonPipe};
+    } else if #[cfg(windows)] {
+        mod windows;
+        pub use windows::{pipe, AnonPipe};
+    } else {
+        mod unsupported;
+        pub use unsupported::{pipe, AnonPipe};
+    }
+}
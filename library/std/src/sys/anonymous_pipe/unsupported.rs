@@ -0,0 +1,23 @@
+use crate::io::{self, PipeReader, PipeWriter};
+use crate::process::Stdio;
+
+pub use crate::sys::pipe::AnonPipe;
+
+#[inline]
+pub fn pipe() -> io::Result<(AnonPipe, AnonPipe)> {
+    Err(io::const_io_error!(io::ErrorKind::Unsupported, "operation not supported on this platform"))
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl From<PipeReader> for Stdio {
+    fn from(pipe: PipeReader) -> Self {
+        pipe.0.diverge()
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl From<PipeWriter> for Stdio {
+    fn from(pipe: PipeWriter) -> Self {
+        pipe.0.diverge()
+    }
+}
@@ -5,6 +5,8 @@
 
 mod personality;
 
+pub mod anonymous_pipe;
+
 // FIXME(117276): remove this, move feature implementations into individual
 //                submodules.
 pub use pal::*;
@@ -111,6 +111,14 @@
 
 pub struct File(FileDesc);
 
+/// The operations of `File::flock`.
+#[derive(Copy, Clone)]
+enum LockOperation {
+    Exclusive,
+    Shared,
+    Unlock,
+}
+
 // FIXME: This should be available on Linux with all `target_env`.
 // But currently only glibc exposes `statx` fn and structs.
 // We don't want to import unverified raw C structs here directly.
@@ -1226,6 +1234,75 @@ pub fn truncate(&self, size: u64) -> io::Result<()> {
         cvt_r(|| unsafe { ftruncate64(self.as_raw_fd(), size) }).map(drop)
     }
 
+    pub fn lock(&self) -> io::Result<()> {
+        self.flock(LockOperation::Exclusive, true)
+    }
+
+    pub fn lock_shared(&self) -> io::Result<()> {
+        self.flock(LockOperation::Shared, true)
+    }
+
+    pub fn try_lock(&self) -> io::Result<()> {
+        self.flock(LockOperation::Exclusive, false)
+    }
+
+    pub fn try_lock_shared(&self) -> io::Result<()> {
+        self.flock(LockOperation::Shared, false)
+    }
+
+    pub fn unlock(&self) -> io::Result<()> {
+        self.flock(LockOperation::Unlock, true)
+    }
+
+    // `flock` locks are attached to the open file description, like the locks of `LockFileEx`
+    // on Windows, whereas `fcntl` locks belong to the process, and are released when any of its
+    // descriptors for the file is closed. Platforms without `flock` don't support locking.
+    #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "fuchsia",
+        target_os = "ios",
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "tvos",
+        target_os = "watchos",
+    ))]
+    fn flock(&self, operation: LockOperation, blocking: bool) -> io::Result<()> {
+        let mut operation = match operation {
+            LockOperation::Exclusive => libc::LOCK_EX,
+            LockOperation::Shared => libc::LOCK_SH,
+            LockOperation::Unlock => libc::LOCK_UN,
+        };
+        if !blocking {
+            // A contended lock fails with `EWOULDBLOCK`, which maps to `ErrorKind::WouldBlock`.
+            operation |= libc::LOCK_NB;
+        }
+        cvt_r(|| unsafe { libc::flock(self.as_raw_fd(), operation) }).map(drop)
+    }
+
+    #[cfg(not(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "fuchsia",
+        target_os = "ios",
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "tvos",
+        target_os = "watchos",
+    )))]
+    fn flock(&self, _operation: LockOperation, _blocking: bool) -> io::Result<()> {
+        Err(io::const_io_error!(
+            io::ErrorKind::Unsupported,
+            "file locking is not supported on this platform",
+        ))
+    }
+
     pub fn read(&self, buf: &mut [u8]) -> io::Result<usize> {
         self.0.read(buf)
     }
@@ -1950,6 +2027,36 @@ pub fn chroot(dir: &Path) -> io::Result<()> {
 
 pub use remove_dir_impl::remove_dir_all;
 
+// Directory handles need `openat()` and the other `*at()` functions.
+#[cfg(not(any(
+    target_os = "redox",
+    target_os = "espidf",
+    target_os = "horizon",
+    target_os = "vita",
+    target_os = "nto",
+    miri
+)))]
+mod dir;
+
+#[cfg(any(
+    target_os = "redox",
+    target_os = "espidf",
+    target_os = "horizon",
+    target_os = "vita",
+    target_os = "nto",
+    miri
+))]
+pub use crate::sys_common::fs::DirHandle;
+#[cfg(not(any(
+    target_os = "redox",
+    target_os = "espidf",
+    target_os = "horizon",
+    target_os = "vita",
+    target_os = "nto",
+    miri
+)))]
+pub use dir::DirHandle;
+
 // Fallback for REDOX, ESP-ID, Horizon, Vita and Miri
 #[cfg(any(
     target_os = "redox",
@@ -0,0 +1,297 @@
+//! Directory handles, which resolve paths relative to an open directory with the `*at()`
+//! functions.
+//!
+//! When symlinks may be followed and paths may leave the directory, the kernel resolves the paths
+//! as usual. Otherwise, the paths are resolved one component at a time, by opening each
+//! directory with `O_NOFOLLOW`, and reading the symlinks with `readlinkat()` to resolve their
+//! target in place.
+
+use super::{stat64, Dir, File, FileAttr, InnerReadDir, OpenOptions, ReadDir};
+use crate::collections::VecDeque;
+use crate::ffi::{CStr, OsStr};
+use crate::fmt;
+use crate::io;
+use crate::mem;
+use crate::os::unix::ffi::OsStrExt;
+use crate::os::unix::io::{AsRawFd, FromRawFd, IntoRawFd, OwnedFd, RawFd};
+use crate::path::{Component, Path, PathBuf};
+use crate::sys::common::small_c_string::{run_path_with_cstr, run_with_cstr};
+use crate::sys::fd::FileDesc;
+use crate::sys::{cvt, cvt_r};
+use crate::sys_common::fs::ResolveOptions;
+
+use libc::c_int;
+
+#[cfg(any(
+    all(target_os = "linux", not(target_env = "musl")),
+    target_os = "emscripten",
+    target_os = "android",
+    target_os = "hurd"
+))]
+use super::fstatat64;
+#[cfg(not(any(
+    all(target_os = "linux", not(target_env = "musl")),
+    target_os = "emscripten",
+    target_os = "android",
+    target_os = "hurd"
+)))]
+use libc::fstatat as fstatat64;
+#[cfg(not(all(target_os = "linux", target_env = "gnu")))]
+use libc::openat;
+#[cfg(all(target_os = "linux", target_env = "gnu"))]
+use libc::openat64 as openat;
+
+/// The maximum number of symlinks followed while resolving a path, like on Linux.
+const MAX_SYMLINKS: usize = 40;
+
+const ESCAPE_ERROR: io::Error =
+    io::const_io_error!(io::ErrorKind::PermissionDenied, "the path escapes the directory",);
+
+pub struct DirHandle {
+    fd: OwnedFd,
+    /// The path the directory was opened with, which is the root of the paths of its entries.
+    root: PathBuf,
+    resolve: ResolveOptions,
+}
+
+/// A component of a path that is being resolved.
+enum Step {
+    Root,
+    Parent,
+    Name(Vec<u8>),
+}
+
+impl DirHandle {
+    pub fn open(path: &Path, resolve: ResolveOptions) -> io::Result<DirHandle> {
+        let fd = run_path_with_cstr(path, |path| open_dir_at(libc::AT_FDCWD, path, 0))?;
+        Ok(DirHandle { fd, root: path.to_path_buf(), resolve })
+    }
+
+    pub fn open_file(&self, path: &Path, opts: &OpenOptions) -> io::Result<File> {
+        let flags = libc::O_CLOEXEC
+            | opts.get_access_mode()?
+            | opts.get_creation_mode()?
+            | (opts.custom_flags as c_int & !libc::O_ACCMODE)
+            | self.nofollow(libc::O_NOFOLLOW);
+        self.at(path, true, |dirfd, name| {
+            // See `File::open_c` for the type of the mode.
+            let fd = cvt_r(|| unsafe { openat(dirfd, name.as_ptr(), flags, opts.mode as c_int) })?;
+            Ok(File(unsafe { FileDesc::from_raw_fd(fd) }))
+        })
+    }
+
+    pub fn open_dir(&self, path: &Path) -> io::Result<DirHandle> {
+        let flags = self.nofollow(libc::O_NOFOLLOW);
+        let fd = self.at(path, true, |dirfd, name| open_dir_at(dirfd, name, flags))?;
+        Ok(DirHandle { fd, root: self.root.join(path), resolve: self.resolve })
+    }
+
+    pub fn create_dir(&self, path: &Path) -> io::Result<()> {
+        self.at(path, false, |dirfd, name| {
+            cvt(unsafe { libc::mkdirat(dirfd, name.as_ptr(), 0o777) }).map(drop)
+        })
+    }
+
+    pub fn remove_dir(&self, path: &Path) -> io::Result<()> {
+        self.at(path, false, |dirfd, name| {
+            cvt(unsafe { libc::unlinkat(dirfd, name.as_ptr(), libc::AT_REMOVEDIR) }).map(drop)
+        })
+    }
+
+    pub fn remove_file(&self, path: &Path) -> io::Result<()> {
+        self.at(path, false, |dirfd, name| {
+            cvt(unsafe { libc::unlinkat(dirfd, name.as_ptr(), 0) }).map(drop)
+        })
+    }
+
+    pub fn metadata(&self, path: &Path) -> io::Result<FileAttr> {
+        let flags = self.nofollow(libc::AT_SYMLINK_NOFOLLOW);
+        self.at(path, true, |dirfd, name| stat_at(dirfd, name, flags))
+    }
+
+    pub fn symlink_metadata(&self, path: &Path) -> io::Result<FileAttr> {
+        self.at(path, false, |dirfd, name| stat_at(dirfd, name, libc::AT_SYMLINK_NOFOLLOW))
+    }
+
+    pub fn rename(&self, from: &Path, to_dir: &DirHandle, to: &Path) -> io::Result<()> {
+        self.at(from, false, |from_dirfd, from| {
+            to_dir.at(to, false, |to_dirfd, to| {
+                cvt(unsafe { libc::renameat(from_dirfd, from.as_ptr(), to_dirfd, to.as_ptr()) })
+                    .map(drop)
+            })
+        })
+    }
+
+    pub fn read_dir(&self) -> io::Result<ReadDir> {
+        // Open the directory again, as the stream and its offset would be shared with a
+        // duplicated descriptor.
+        let fd = open_dir_at(self.fd.as_raw_fd(), c".", 0)?;
+        let ptr = unsafe { libc::fdopendir(fd.as_raw_fd()) };
+        if ptr.is_null() {
+            return Err(io::Error::last_os_error());
+        }
+        // The descriptor is closed by `closedir()` from now on.
+        let _ = fd.into_raw_fd();
+        Ok(ReadDir::new(InnerReadDir { dirp: Dir(ptr), root: self.root.clone() }))
+    }
+
+    /// Whether the paths are resolved one component at a time, instead of by the kernel.
+    fn is_restricted(&self) -> bool {
+        !self.resolve.follow_symlinks || self.resolve.beneath
+    }
+
+    /// Returns `flag` if the paths are resolved one component at a time, as the symlinks that
+    /// may be followed are resolved already.
+    fn nofollow(&self, flag: c_int) -> c_int {
+        if self.is_restricted() { flag } else { 0 }
+    }
+
+    /// Resolve `path`, and call `f` with the descriptor of the directory that contains its last
+    /// component, and the name of this component.
+    ///
+    /// If `follow_last` is set, and the paths are resolved one component at a time, a symlink
+    /// in the last component is resolved as well.
+    fn at<T>(
+        &self,
+        path: &Path,
+        follow_last: bool,
+        f: impl FnOnce(RawFd, &CStr) -> io::Result<T>,
+    ) -> io::Result<T> {
+        if !self.is_restricted() || path.as_os_str().is_empty() {
+            return run_path_with_cstr(path, |path| f(self.fd.as_raw_fd(), path));
+        }
+        let (parent, name) = self.resolve(path, follow_last)?;
+        let dirfd = parent.as_ref().map_or(self.fd.as_raw_fd(), |fd| fd.as_raw_fd());
+        run_with_cstr(&name, |name| f(dirfd, name))
+    }
+
+    fn resolve(&self, path: &Path, follow_last: bool) -> io::Result<(Option<OwnedFd>, Vec<u8>)> {
+        // The directories opened so far, the last one being the current directory. The handle
+        // itself is the current directory when the stack is empty.
+        let mut stack: Vec<OwnedFd> = Vec::new();
+        let mut steps: VecDeque<Step> = path_steps(path).collect();
+        let mut links = 0;
+        while let Some(step) = steps.pop_front() {
+            let dirfd = stack.last().map_or(self.fd.as_raw_fd(), |fd| fd.as_raw_fd());
+            let name = match step {
+                Step::Root if self.resolve.beneath => return Err(ESCAPE_ERROR),
+                Step::Root => {
+                    stack.push(open_dir_at(libc::AT_FDCWD, c"/", 0)?);
+                    continue;
+                }
+                Step::Parent if self.resolve.beneath => {
+                    // Going back to the directory we came from can't leave the handle, even if
+                    // a directory is moved in the meantime.
+                    if stack.pop().is_none() {
+                        return Err(ESCAPE_ERROR);
+                    }
+                    continue;
+                }
+                Step::Parent => {
+                    stack.push(open_dir_at(dirfd, c"..", 0)?);
+                    continue;
+                }
+                Step::Name(name) => name,
+            };
+
+            let target = if steps.is_empty() {
+                if !follow_last || !self.resolve.follow_symlinks {
+                    return Ok((stack.pop(), name));
+                }
+                // The last component may not exist yet, in which case it's created by the
+                // caller, without following any symlink.
+                match run_with_cstr(&name, |name| readlink_at(dirfd, name)) {
+                    Ok(target) => target,
+                    Err(_) => return Ok((stack.pop(), name)),
+                }
+            } else {
+                let err =
+                    match run_with_cstr(&name, |name| open_dir_at(dirfd, name, libc::O_NOFOLLOW)) {
+                        Ok(fd) => {
+                            stack.push(fd);
+                            continue;
+                        }
+                        Err(err) => err,
+                    };
+                // Opening a symlink with `O_NOFOLLOW` fails with an error that depends on the
+                // platform, so check whether the component is a symlink separately.
+                if !self.resolve.follow_symlinks {
+                    return Err(err);
+                }
+                match run_with_cstr(&name, |name| readlink_at(dirfd, name)) {
+                    Ok(target) => target,
+                    Err(_) => return Err(err),
+                }
+            };
+
+            links += 1;
+            if links > MAX_SYMLINKS {
+                return Err(io::Error::from_raw_os_error(libc::ELOOP));
+            }
+            let target: Vec<Step> = path_steps(Path::new(OsStr::from_bytes(&target))).collect();
+            if target.is_empty() {
+                return Err(io::Error::from_raw_os_error(libc::ENOENT));
+            }
+            for step in target.into_iter().rev() {
+                steps.push_front(step);
+            }
+        }
+        Ok((stack.pop(), b".".to_vec()))
+    }
+}
+
+impl fmt::Debug for DirHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Dir").field("fd", &self.fd.as_raw_fd()).field("path", &self.root).finish()
+    }
+}
+
+fn path_steps(path: &Path) -> impl Iterator<Item = Step> + '_ {
+    path.components().filter_map(|component| match component {
+        Component::Prefix(_) | Component::RootDir => Some(Step::Root),
+        Component::CurDir => None,
+        Component::ParentDir => Some(Step::Parent),
+        Component::Normal(name) => Some(Step::Name(name.as_bytes().to_vec())),
+    })
+}
+
+fn open_dir_at(dirfd: RawFd, name: &CStr, flags: c_int) -> io::Result<OwnedFd> {
+    let fd = cvt_r(|| unsafe {
+        openat(dirfd, name.as_ptr(), libc::O_CLOEXEC | libc::O_RDONLY | libc::O_DIRECTORY | flags)
+    })?;
+    Ok(unsafe { OwnedFd::from_raw_fd(fd) })
+}
+
+fn stat_at(dirfd: RawFd, name: &CStr, flags: c_int) -> io::Result<FileAttr> {
+    cfg_has_statx! {
+        if let Some(ret) = unsafe { super::try_statx(
+            dirfd,
+            name.as_ptr(),
+            flags | libc::AT_STATX_SYNC_AS_STAT,
+            libc::STATX_ALL,
+        ) } {
+            return ret;
+        }
+    }
+
+    let mut stat: stat64 = unsafe { mem::zeroed() };
+    cvt(unsafe { fstatat64(dirfd, name.as_ptr(), &mut stat, flags) })?;
+    Ok(FileAttr::from_stat64(stat))
+}
+
+fn readlink_at(dirfd: RawFd, name: &CStr) -> io::Result<Vec<u8>> {
+    let mut buf = Vec::with_capacity(256);
+    loop {
+        let len = cvt(unsafe {
+            libc::readlinkat(dirfd, name.as_ptr(), buf.as_mut_ptr() as *mut _, buf.capacity())
+        })? as usize;
+        unsafe {
+            buf.set_len(len);
+        }
+        if len != buf.capacity() {
+            return Ok(buf);
+        }
+        // The target may have been truncated, so try again with a larger buffer.
+        buf.reserve(1);
+    }
+}
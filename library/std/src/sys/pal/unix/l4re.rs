@@ -521,6 +521,154 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         }
     }
 
+    pub struct TcpSocketBuilder {
+        inner: Socket,
+    }
+
+    impl TcpSocketBuilder {
+        pub fn new(_: &SocketAddr) -> io::Result<TcpSocketBuilder> {
+            unimpl!();
+        }
+
+        pub fn set_reuseaddr(&self, _: bool) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn reuseaddr(&self) -> io::Result<bool> {
+            unimpl!();
+        }
+
+        pub fn set_reuseport(&self, _: bool) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn reuseport(&self) -> io::Result<bool> {
+            unimpl!();
+        }
+
+        pub fn set_keepalive(&self, _: bool) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn keepalive(&self) -> io::Result<bool> {
+            unimpl!();
+        }
+
+        pub fn set_keepalive_idle(&self, _: Duration) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn keepalive_idle(&self) -> io::Result<Duration> {
+            unimpl!();
+        }
+
+        pub fn set_keepalive_interval(&self, _: Duration) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn keepalive_interval(&self) -> io::Result<Duration> {
+            unimpl!();
+        }
+
+        pub fn set_keepalive_retries(&self, _: u32) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn keepalive_retries(&self) -> io::Result<u32> {
+            unimpl!();
+        }
+
+        pub fn set_send_buffer_size(&self, _: u32) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn send_buffer_size(&self) -> io::Result<u32> {
+            unimpl!();
+        }
+
+        pub fn set_recv_buffer_size(&self, _: u32) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn recv_buffer_size(&self) -> io::Result<u32> {
+            unimpl!();
+        }
+
+        pub fn bind(&self, _: &SocketAddr) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn socket_addr(&self) -> io::Result<SocketAddr> {
+            unimpl!();
+        }
+
+        pub fn connect(self, _: &SocketAddr) -> io::Result<TcpStream> {
+            unimpl!();
+        }
+
+        pub fn listen(self, _: u32) -> io::Result<TcpListener> {
+            unimpl!();
+        }
+    }
+
+    impl fmt::Debug for TcpSocketBuilder {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "No networking support available on L4Re.")
+        }
+    }
+
+    pub struct UdpSocketBuilder {
+        inner: Socket,
+    }
+
+    impl UdpSocketBuilder {
+        pub fn new(_: &SocketAddr) -> io::Result<UdpSocketBuilder> {
+            unimpl!();
+        }
+
+        pub fn set_reuseaddr(&self, _: bool) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn reuseaddr(&self) -> io::Result<bool> {
+            unimpl!();
+        }
+
+        pub fn set_reuseport(&self, _: bool) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn reuseport(&self) -> io::Result<bool> {
+            unimpl!();
+        }
+
+        pub fn set_send_buffer_size(&self, _: u32) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn send_buffer_size(&self) -> io::Result<u32> {
+            unimpl!();
+        }
+
+        pub fn set_recv_buffer_size(&self, _: u32) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn recv_buffer_size(&self) -> io::Result<u32> {
+            unimpl!();
+        }
+
+        pub fn bind(self, _: &SocketAddr) -> io::Result<UdpSocket> {
+            unimpl!();
+        }
+    }
+
+    impl fmt::Debug for UdpSocketBuilder {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "No networking support available on L4Re.")
+        }
+    }
+
     pub struct LookupHost {
         original: *mut libc::addrinfo,
         cur: *mut libc::addrinfo,
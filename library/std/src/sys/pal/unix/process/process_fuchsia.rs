@@ -6,6 +6,7 @@
 
 use crate::sys::process::process_common::*;
 use crate::sys::process::zircon::{zx_handle_t, Handle};
+use crate::time::Duration;
 
 use libc::{c_int, size_t};
 
@@ -233,6 +234,24 @@ pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
         }
         Ok(Some(ExitStatus(proc_info.return_code)))
     }
+
+    pub fn wait_timeout(&mut self, timeout: Duration) -> io::Result<Option<ExitStatus>> {
+        crate::sys_common::process::wait_timeout_by_polling(self, timeout)
+    }
+
+    pub fn send_signal(&self, _signal: c_int) -> io::Result<()> {
+        Err(io::const_io_error!(
+            io::ErrorKind::Unsupported,
+            "signals are not supported on this platform",
+        ))
+    }
+
+    pub fn send_signal_group(&self, _signal: c_int) -> io::Result<()> {
+        Err(io::const_io_error!(
+            io::ErrorKind::Unsupported,
+            "process groups are not supported on this platform",
+        ))
+    }
 }
 
 #[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
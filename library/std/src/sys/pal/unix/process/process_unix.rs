@@ -5,6 +5,7 @@
 use crate::sys;
 use crate::sys::cvt;
 use crate::sys::process::process_common::*;
+use crate::time::Duration;
 use core::ffi::NonZero_c_int;
 
 #[cfg(target_os = "linux")]
@@ -41,7 +42,6 @@
     if #[cfg(all(target_os = "nto", target_env = "nto71"))] {
         use crate::thread;
         use libc::{c_char, posix_spawn_file_actions_t, posix_spawnattr_t};
-        use crate::time::Duration;
         use crate::sync::LazyLock;
         // Get smallest amount of time we can sleep.
         // Return a common value if it cannot be determined.
@@ -348,8 +348,14 @@ unsafe fn do_exec(
             cvt(libc::chdir(cwd.as_ptr()))?;
         }
 
+        if self.get_setsid() {
+            cvt(libc::setsid())?;
+        }
         if let Some(pgroup) = self.get_pgroup() {
-            cvt(libc::setpgid(0, pgroup))?;
+            // A session leader leads its own process group already, and can't join another one.
+            if !(self.get_setsid() && pgroup == 0) {
+                cvt(libc::setpgid(0, pgroup))?;
+            }
         }
 
         // emscripten has no signal support.
@@ -467,6 +473,7 @@ fn posix_spawn(
             || !self.get_closures().is_empty()
             || self.get_groups().is_some()
             || self.get_create_pidfd()
+            || self.get_setsid()
         {
             return Ok(None);
         }
@@ -815,6 +822,10 @@ pub fn id(&self) -> u32 {
     }
 
     pub fn kill(&mut self) -> io::Result<()> {
+        self.send_signal(libc::SIGKILL)
+    }
+
+    pub fn send_signal(&self, signal: c_int) -> io::Result<()> {
         // If we've already waited on this process then the pid can be recycled
         // and used for another process, and we probably shouldn't be killing
         // random processes, so return Ok because the process has exited already.
@@ -828,14 +839,21 @@ pub fn kill(&mut self) -> io::Result<()> {
                 libc::syscall(
                     libc::SYS_pidfd_send_signal,
                     pid_fd.as_raw_fd(),
-                    libc::SIGKILL,
+                    signal,
                     crate::ptr::null::<()>(),
                     0,
                 )
             })
             .map(drop);
         }
-        cvt(unsafe { libc::kill(self.pid, libc::SIGKILL) }).map(drop)
+        cvt(unsafe { libc::kill(self.pid, signal) }).map(drop)
+    }
+
+    pub fn send_signal_group(&self, signal: c_int) -> io::Result<()> {
+        // The ID of a process group stays in use while the group has members, even after its
+        // leader was reaped, so this can still reach the rest of the group. If the child doesn't
+        // lead a group, there is no group with its ID and this fails with `ESRCH`.
+        cvt(unsafe { libc::killpg(self.pid, signal) }).map(drop)
     }
 
     pub fn wait(&mut self) -> io::Result<ExitStatus> {
@@ -892,6 +910,56 @@ pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
             Ok(Some(ExitStatus::new(status)))
         }
     }
+
+    #[cfg(target_os = "linux")]
+    pub fn wait_timeout(&mut self, timeout: Duration) -> io::Result<Option<ExitStatus>> {
+        use crate::os::unix::io::{FromRawFd, OwnedFd, RawFd};
+        use crate::time::Instant;
+
+        if let Some(status) = self.try_wait()? {
+            return Ok(Some(status));
+        }
+        // A pidfd becomes readable when the process exits, so it can be polled with a timeout.
+        // The child isn't reaped yet, so its pid can't be reused to open a pidfd for another
+        // process if the child doesn't have one already.
+        let mut _opened = None;
+        let pidfd: RawFd = match self.pidfd.as_ref() {
+            Some(pid_fd) => pid_fd.as_raw_fd(),
+            None => match cvt(unsafe { libc::syscall(libc::SYS_pidfd_open, self.pid, 0) }) {
+                Ok(fd) => _opened.insert(unsafe { OwnedFd::from_raw_fd(fd as RawFd) }).as_raw_fd(),
+                Err(_) => {
+                    return crate::sys_common::process::wait_timeout_by_polling(self, timeout)
+                }
+            },
+        };
+        let deadline = Instant::now().checked_add(timeout);
+        loop {
+            let timeout = match deadline {
+                Some(deadline) => {
+                    let remaining = deadline.saturating_duration_since(Instant::now());
+                    if remaining.is_zero() {
+                        return Ok(None);
+                    }
+                    remaining.as_nanos().div_ceil(1_000_000).min(c_int::MAX as u128) as c_int
+                }
+                None => -1,
+            };
+            let mut fd = libc::pollfd { fd: pidfd, events: libc::POLLIN, revents: 0 };
+            if let Err(err) = cvt(unsafe { libc::poll(&mut fd, 1, timeout) }) {
+                if err.kind() != ErrorKind::Interrupted {
+                    return Err(err);
+                }
+            }
+            if let Some(status) = self.try_wait()? {
+                return Ok(Some(status));
+            }
+        }
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn wait_timeout(&mut self, timeout: Duration) -> io::Result<Option<ExitStatus>> {
+        crate::sys_common::process::wait_timeout_by_polling(self, timeout)
+    }
 }
 
 /// Unix exit statuses
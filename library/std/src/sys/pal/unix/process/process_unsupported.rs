@@ -3,6 +3,7 @@
 use crate::num::NonZeroI32;
 use crate::sys::pal::unix::unsupported::*;
 use crate::sys::process::process_common::*;
+use crate::time::Duration;
 use core::ffi::NonZero_c_int;
 
 use libc::{c_int, pid_t};
@@ -53,6 +54,18 @@ pub fn wait(&mut self) -> io::Result<ExitStatus> {
     pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
         unsupported()
     }
+
+    pub fn wait_timeout(&mut self, _timeout: Duration) -> io::Result<Option<ExitStatus>> {
+        unsupported()
+    }
+
+    pub fn send_signal(&self, _signal: c_int) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn send_signal_group(&self, _signal: c_int) -> io::Result<()> {
+        unsupported()
+    }
 }
 
 mod wait_status;
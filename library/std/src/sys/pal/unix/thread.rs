@@ -5,6 +5,7 @@
 use crate::num::NonZeroUsize;
 use crate::ptr;
 use crate::sys::{os, stack_overflow};
+use crate::sys_common::thread::ThreadAttributes;
 use crate::time::Duration;
 
 #[cfg(all(target_os = "linux", target_env = "gnu"))]
@@ -290,6 +291,91 @@ fn drop(&mut self) {
     }
 }
 
+pub fn set_current_attributes(attrs: &ThreadAttributes) -> io::Result<()> {
+    if let Some(cpus) = &attrs.affinity {
+        set_current_affinity(cpus)?;
+    }
+    if attrs.sched_policy.is_some() || attrs.sched_priority.is_some() {
+        set_current_sched(attrs.sched_policy, attrs.sched_priority)?;
+    }
+    Ok(())
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(any(target_os = "android", target_os = "linux"))] {
+        use crate::sys::cvt;
+
+        fn set_current_affinity(cpus: &[usize]) -> io::Result<()> {
+            let mut set: libc::cpu_set_t = unsafe { mem::zeroed() };
+            for &cpu in cpus {
+                if cpu >= libc::CPU_SETSIZE as usize {
+                    return Err(io::const_io_error!(
+                        io::ErrorKind::InvalidInput,
+                        "the CPU index is out of range",
+                    ));
+                }
+                unsafe { libc::CPU_SET(cpu, &mut set) };
+            }
+            cvt(unsafe { libc::sched_setaffinity(0, mem::size_of::<libc::cpu_set_t>(), &set) })?;
+            Ok(())
+        }
+
+        #[cfg(target_os = "linux")]
+        pub fn current_affinity() -> io::Result<Vec<usize>> {
+            let mut set: libc::cpu_set_t = unsafe { mem::zeroed() };
+            let size = mem::size_of::<libc::cpu_set_t>();
+            cvt(unsafe { libc::sched_getaffinity(0, size, &mut set) })?;
+            Ok((0..libc::CPU_SETSIZE as usize)
+                .filter(|&cpu| unsafe { libc::CPU_ISSET(cpu, &set) })
+                .collect())
+        }
+    } else {
+        fn set_current_affinity(_cpus: &[usize]) -> io::Result<()> {
+            Err(io::const_io_error!(
+                io::ErrorKind::Unsupported,
+                "setting the CPU affinity of a thread is not supported on this platform",
+            ))
+        }
+    }
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(target_os = "linux")] {
+        fn set_current_sched(policy: Option<i32>, priority: Option<i32>) -> io::Result<()> {
+            let policy = match policy {
+                Some(policy) => policy,
+                None => current_sched()?.0,
+            };
+            let mut param: libc::sched_param = unsafe { mem::zeroed() };
+            // The lowest priority is 0 for the normal policies, and 1 for the real-time ones.
+            param.sched_priority =
+                priority.unwrap_or_else(|| unsafe { libc::sched_get_priority_min(policy) });
+            match unsafe { libc::pthread_setschedparam(libc::pthread_self(), policy, &param) } {
+                0 => Ok(()),
+                err => Err(io::Error::from_raw_os_error(err)),
+            }
+        }
+
+        /// Returns the scheduling policy and priority of the current thread.
+        pub fn current_sched() -> io::Result<(i32, i32)> {
+            let mut policy = 0;
+            let mut param: libc::sched_param = unsafe { mem::zeroed() };
+            let thread = unsafe { libc::pthread_self() };
+            match unsafe { libc::pthread_getschedparam(thread, &mut policy, &mut param) } {
+                0 => Ok((policy, param.sched_priority)),
+                err => Err(io::Error::from_raw_os_error(err)),
+            }
+        }
+    } else {
+        fn set_current_sched(_policy: Option<i32>, _priority: Option<i32>) -> io::Result<()> {
+            Err(io::const_io_error!(
+                io::ErrorKind::Unsupported,
+                "scheduling policies are not supported on this platform",
+            ))
+        }
+    }
+}
+
 #[cfg(any(
     target_os = "linux",
     target_os = "macos",
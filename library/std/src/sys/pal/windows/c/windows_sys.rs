@@ -117,6 +117,15 @@ pub fn CreateNamedPipeW(
     ) -> HANDLE;
 }
 #[link(name = "kernel32")]
+extern "system" {
+    pub fn CreatePipe(
+        hreadpipe: *mut HANDLE,
+        hwritepipe: *mut HANDLE,
+        lppipeattributes: *const SECURITY_ATTRIBUTES,
+        nsize: u32,
+    ) -> BOOL;
+}
+#[link(name = "kernel32")]
 extern "system" {
     pub fn CreateProcessW(
         lpapplicationname: PCWSTR,
@@ -394,6 +403,17 @@ pub fn InitializeProcThreadAttributeList(
     ) -> BOOL;
 }
 #[link(name = "kernel32")]
+extern "system" {
+    pub fn LockFileEx(
+        hfile: HANDLE,
+        dwflags: LOCK_FILE_FLAGS,
+        dwreserved: u32,
+        nnumberofbytestolocklow: u32,
+        nnumberofbytestolockhigh: u32,
+        lpoverlapped: *mut OVERLAPPED,
+    ) -> BOOL;
+}
+#[link(name = "kernel32")]
 extern "system" {
     pub fn MoveFileExW(
         lpexistingfilename: PCWSTR,
@@ -513,6 +533,10 @@ pub fn SetFileTime(
     pub fn SetLastError(dwerrcode: WIN32_ERROR) -> ();
 }
 #[link(name = "kernel32")]
+extern "system" {
+    pub fn SetThreadAffinityMask(hthread: HANDLE, dwthreadaffinitymask: usize) -> usize;
+}
+#[link(name = "kernel32")]
 extern "system" {
     pub fn SetThreadStackGuarantee(stacksizeinbytes: *mut u32) -> BOOL;
 }
@@ -577,6 +601,16 @@ pub fn SleepConditionVariableSRW(
     pub fn TryAcquireSRWLockShared(srwlock: *mut SRWLOCK) -> BOOLEAN;
 }
 #[link(name = "kernel32")]
+extern "system" {
+    pub fn UnlockFile(
+        hfile: HANDLE,
+        dwfileoffsetlow: u32,
+        dwfileoffsethigh: u32,
+        nnumberofbytestounlocklow: u32,
+        nnumberofbytestounlockhigh: u32,
+    ) -> BOOL;
+}
+#[link(name = "kernel32")]
 extern "system" {
     pub fn UpdateProcThreadAttribute(
         lpattributelist: LPPROC_THREAD_ATTRIBUTE_LIST,
@@ -3649,6 +3683,9 @@ fn clone(&self) -> Self {
         *self
     }
 }
+pub const LOCKFILE_EXCLUSIVE_LOCK: LOCK_FILE_FLAGS = 2u32;
+pub const LOCKFILE_FAIL_IMMEDIATELY: LOCK_FILE_FLAGS = 1u32;
+pub type LOCK_FILE_FLAGS = u32;
 pub type LPOVERLAPPED_COMPLETION_ROUTINE = ::core::option::Option<
     unsafe extern "system" fn(
         dwerrorcode: u32,
@@ -3884,8 +3921,12 @@ fn clone(&self) -> Self {
 pub const SOL_SOCKET: i32 = 65535i32;
 pub const SO_BROADCAST: i32 = 32i32;
 pub const SO_ERROR: i32 = 4103i32;
+pub const SO_KEEPALIVE: i32 = 8i32;
 pub const SO_LINGER: i32 = 128i32;
+pub const SO_RCVBUF: i32 = 4098i32;
 pub const SO_RCVTIMEO: i32 = 4102i32;
+pub const SO_REUSEADDR: i32 = 4i32;
+pub const SO_SNDBUF: i32 = 4097i32;
 pub const SO_SNDTIMEO: i32 = 4101i32;
 pub const SPECIFIC_RIGHTS_ALL: FILE_ACCESS_RIGHTS = 65535u32;
 #[repr(C)]
@@ -4014,6 +4055,9 @@ fn clone(&self) -> Self {
         *self
     }
 }
+pub const TCP_KEEPCNT: i32 = 16i32;
+pub const TCP_KEEPIDLE: i32 = 3i32;
+pub const TCP_KEEPINTVL: i32 = 17i32;
 pub const TCP_NODELAY: i32 = 1i32;
 pub const THREAD_CREATE_RUN_IMMEDIATELY: THREAD_CREATION_FLAGS = 0u32;
 pub const THREAD_CREATE_SUSPENDED: THREAD_CREATION_FLAGS = 4u32;
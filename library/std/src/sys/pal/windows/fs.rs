@@ -19,6 +19,8 @@
 use super::path::maybe_verbatim;
 use super::{api, to_u16s, IoResult};
 
+pub use crate::sys_common::fs::DirHandle;
+
 pub struct File {
     handle: Handle,
 }
@@ -345,6 +347,68 @@ pub fn truncate(&self, size: u64) -> io::Result<()> {
         api::set_file_information_by_handle(self.handle.as_raw_handle(), &info).io_result()
     }
 
+    pub fn lock(&self) -> io::Result<()> {
+        self.lock_file(c::LOCKFILE_EXCLUSIVE_LOCK)
+    }
+
+    pub fn lock_shared(&self) -> io::Result<()> {
+        self.lock_file(0)
+    }
+
+    pub fn try_lock(&self) -> io::Result<()> {
+        self.lock_file(c::LOCKFILE_EXCLUSIVE_LOCK | c::LOCKFILE_FAIL_IMMEDIATELY)
+    }
+
+    pub fn try_lock_shared(&self) -> io::Result<()> {
+        self.lock_file(c::LOCKFILE_FAIL_IMMEDIATELY)
+    }
+
+    /// Lock the whole file, including any data that is later appended to it.
+    fn lock_file(&self, flags: c::LOCK_FILE_FLAGS) -> io::Result<()> {
+        let result = cvt(unsafe {
+            let mut overlapped: c::OVERLAPPED = mem::zeroed();
+            c::LockFileEx(
+                self.handle.as_raw_handle(),
+                flags,
+                0,
+                u32::MAX,
+                u32::MAX,
+                &mut overlapped,
+            )
+        });
+        match result {
+            Ok(_) => Ok(()),
+            // Handles opened for asynchronous I/O report a contended lock with
+            // `ERROR_IO_PENDING` instead of `ERROR_LOCK_VIOLATION`.
+            Err(err)
+                if flags & c::LOCKFILE_FAIL_IMMEDIATELY != 0
+                    && (err.raw_os_error() == Some(c::ERROR_LOCK_VIOLATION as i32)
+                        || err.raw_os_error() == Some(c::ERROR_IO_PENDING as i32)) =>
+            {
+                Err(io::const_io_error!(
+                    io::ErrorKind::WouldBlock,
+                    "the file is locked by another handle",
+                ))
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn unlock(&self) -> io::Result<()> {
+        // A handle may hold both an exclusive and a shared lock on the file, in which case the
+        // first unlock releases the exclusive lock, and the second one the shared lock.
+        let unlock =
+            || cvt(unsafe { c::UnlockFile(self.handle.as_raw_handle(), 0, 0, u32::MAX, u32::MAX) });
+        for _ in 0..2 {
+            match unlock() {
+                Ok(_) => {}
+                Err(err) if err.raw_os_error() == Some(c::ERROR_NOT_LOCKED as i32) => break,
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(())
+    }
+
     #[cfg(not(target_vendor = "uwp"))]
     pub fn file_attr(&self) -> io::Result<FileAttr> {
         unsafe {
@@ -7,6 +7,7 @@
 use crate::sys::c;
 use crate::sys::handle::Handle;
 use crate::sys::stack_overflow;
+use crate::sys_common::thread::ThreadAttributes;
 use crate::sys_common::FromInner;
 use crate::time::Duration;
 
@@ -110,6 +111,32 @@ pub fn into_handle(self) -> Handle {
     }
 }
 
+pub fn set_current_attributes(attrs: &ThreadAttributes) -> io::Result<()> {
+    if attrs.sched_policy.is_some() || attrs.sched_priority.is_some() {
+        return Err(io::const_io_error!(
+            io::ErrorKind::Unsupported,
+            "scheduling policies are not supported on this platform",
+        ));
+    }
+    if let Some(cpus) = &attrs.affinity {
+        // Only the CPUs of the first processor group can be selected this way.
+        let mut mask: usize = 0;
+        for &cpu in cpus {
+            if cpu >= usize::BITS as usize {
+                return Err(io::const_io_error!(
+                    io::ErrorKind::InvalidInput,
+                    "the CPU index is out of range",
+                ));
+            }
+            mask |= 1 << cpu;
+        }
+        if unsafe { c::SetThreadAffinityMask(c::GetCurrentThread(), mask) } == 0 {
+            return Err(io::Error::last_os_error());
+        }
+    }
+    Ok(())
+}
+
 pub fn available_parallelism() -> io::Result<NonZeroUsize> {
     let res = unsafe {
         let mut sysinfo: c::SYSTEM_INFO = crate::mem::zeroed();
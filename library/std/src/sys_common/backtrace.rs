@@ -176,33 +176,39 @@ pub fn __rust_end_short_backtrace<F, T>(f: F) -> T
     result
 }
 
-/// Prints the filename of the backtrace frame.
-///
-/// See also `output`.
-pub fn output_filename(
-    fmt: &mut fmt::Formatter<'_>,
-    bows: BytesOrWideString<'_>,
-    print_fmt: PrintFmt,
-    cwd: Option<&PathBuf>,
-) -> fmt::Result {
-    let file: Cow<'_, Path> = match bows {
+/// Converts the filename of a backtrace symbol to a path, if it can be
+/// represented on this platform.
+pub fn filename_path(bows: BytesOrWideString<'_>) -> Option<Cow<'_, Path>> {
+    match bows {
         #[cfg(unix)]
         BytesOrWideString::Bytes(bytes) => {
             use crate::os::unix::prelude::*;
-            Path::new(crate::ffi::OsStr::from_bytes(bytes)).into()
+            Some(Path::new(crate::ffi::OsStr::from_bytes(bytes)).into())
         }
         #[cfg(not(unix))]
         BytesOrWideString::Bytes(bytes) => {
-            Path::new(crate::str::from_utf8(bytes).unwrap_or("<unknown>")).into()
+            crate::str::from_utf8(bytes).ok().map(|s| Path::new(s).into())
         }
         #[cfg(windows)]
         BytesOrWideString::Wide(wide) => {
             use crate::os::windows::prelude::*;
-            Cow::Owned(crate::ffi::OsString::from_wide(wide).into())
+            Some(Cow::Owned(crate::ffi::OsString::from_wide(wide).into()))
         }
         #[cfg(not(windows))]
-        BytesOrWideString::Wide(_wide) => Path::new("<unknown>").into(),
-    };
+        BytesOrWideString::Wide(_wide) => None,
+    }
+}
+
+/// Prints the filename of the backtrace frame.
+///
+/// See also `output`.
+pub fn output_filename(
+    fmt: &mut fmt::Formatter<'_>,
+    bows: BytesOrWideString<'_>,
+    print_fmt: PrintFmt,
+    cwd: Option<&PathBuf>,
+) -> fmt::Result {
+    let file = filename_path(bows).unwrap_or_else(|| Path::new("<unknown>").into());
     if print_fmt == PrintFmt::Short && file.is_absolute() {
         if let Some(cwd) = cwd {
             if let Ok(stripped) = file.strip_prefix(&cwd) {
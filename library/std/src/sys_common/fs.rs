@@ -1,8 +1,10 @@
 #![allow(dead_code)] // not used on all platforms
 
+use crate::fmt;
 use crate::fs;
 use crate::io::{self, Error, ErrorKind};
 use crate::path::Path;
+use crate::sys::fs as fs_imp;
 
 pub(crate) const NOT_FILE_ERROR: Error = io::const_io_error!(
     ErrorKind::InvalidInput,
@@ -49,3 +51,66 @@ pub fn try_exists(path: &Path) -> io::Result<bool> {
         Err(error) => Err(error),
     }
 }
+
+/// How the paths relative to a directory handle are resolved.
+#[derive(Copy, Clone, Debug)]
+pub struct ResolveOptions {
+    /// Whether symlinks are followed.
+    pub follow_symlinks: bool,
+    /// Whether paths must stay within the directory.
+    pub beneath: bool,
+}
+
+/// The directory handle of the platforms that can't resolve paths relative to a directory.
+pub struct DirHandle(!);
+
+impl DirHandle {
+    pub fn open(_path: &Path, _resolve: ResolveOptions) -> io::Result<DirHandle> {
+        Err(io::const_io_error!(
+            ErrorKind::Unsupported,
+            "directory handles are not supported on this platform",
+        ))
+    }
+
+    pub fn open_file(&self, _path: &Path, _opts: &fs_imp::OpenOptions) -> io::Result<fs_imp::File> {
+        self.0
+    }
+
+    pub fn open_dir(&self, _path: &Path) -> io::Result<DirHandle> {
+        self.0
+    }
+
+    pub fn create_dir(&self, _path: &Path) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn remove_dir(&self, _path: &Path) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn remove_file(&self, _path: &Path) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn metadata(&self, _path: &Path) -> io::Result<fs_imp::FileAttr> {
+        self.0
+    }
+
+    pub fn symlink_metadata(&self, _path: &Path) -> io::Result<fs_imp::FileAttr> {
+        self.0
+    }
+
+    pub fn rename(&self, _from: &Path, _to_dir: &DirHandle, _to: &Path) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn read_dir(&self) -> io::Result<fs_imp::ReadDir> {
+        self.0
+    }
+}
+
+impl fmt::Debug for DirHandle {
+    fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0
+    }
+}
@@ -27,6 +27,7 @@
 pub mod memchr;
 pub mod once;
 pub mod process;
+pub mod semaphore;
 pub mod thread;
 pub mod thread_info;
 pub mod thread_local_dtor;
@@ -55,6 +55,43 @@
     }
 }
 
+cfg_if::cfg_if! {
+    if #[cfg(any(
+        target_os = "linux", target_os = "android",
+        target_os = "hurd", target_os = "fuchsia",
+        target_os = "dragonfly", target_os = "freebsd",
+        target_os = "openbsd", target_os = "netbsd",
+        target_os = "ios", target_os = "tvos", target_os = "macos", target_os = "watchos",
+        target_os = "haiku", target_os = "nto"))] {
+        const SO_REUSEPORT: Option<c_int> = Some(libc::SO_REUSEPORT);
+    } else {
+        const SO_REUSEPORT: Option<c_int> = None;
+    }
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(any(
+        target_os = "linux", target_os = "android",
+        target_os = "hurd", target_os = "fuchsia",
+        target_os = "dragonfly", target_os = "freebsd",
+        target_os = "netbsd", target_os = "illumos",
+        target_os = "solaris", windows))] {
+        const TCP_KEEPIDLE: Option<c_int> = Some(c::TCP_KEEPIDLE);
+        const TCP_KEEPINTVL: Option<c_int> = Some(c::TCP_KEEPINTVL);
+        const TCP_KEEPCNT: Option<c_int> = Some(c::TCP_KEEPCNT);
+    } else if #[cfg(any(
+        target_os = "ios", target_os = "tvos", target_os = "macos", target_os = "watchos"))] {
+        // Apple platforms set the idle time with `TCP_KEEPALIVE`.
+        const TCP_KEEPIDLE: Option<c_int> = Some(c::TCP_KEEPALIVE);
+        const TCP_KEEPINTVL: Option<c_int> = Some(c::TCP_KEEPINTVL);
+        const TCP_KEEPCNT: Option<c_int> = Some(c::TCP_KEEPCNT);
+    } else {
+        const TCP_KEEPIDLE: Option<c_int> = None;
+        const TCP_KEEPINTVL: Option<c_int> = None;
+        const TCP_KEEPCNT: Option<c_int> = None;
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // sockaddr and misc bindings
 ////////////////////////////////////////////////////////////////////////////////
@@ -716,6 +753,234 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////
+// Unbound sockets
+////////////////////////////////////////////////////////////////////////////////
+
+fn bind(sock: &Socket, addr: &SocketAddr) -> io::Result<()> {
+    let (addr, len) = addr.into_inner();
+    cvt(unsafe { c::bind(sock.as_raw(), addr.as_ptr(), len as _) })?;
+    Ok(())
+}
+
+fn set_bool_option(sock: &Socket, option_name: c_int, value: bool) -> io::Result<()> {
+    setsockopt(sock, c::SOL_SOCKET, option_name, value as c_int)
+}
+
+fn bool_option(sock: &Socket, option_name: c_int) -> io::Result<bool> {
+    let raw: c_int = getsockopt(sock, c::SOL_SOCKET, option_name)?;
+    Ok(raw != 0)
+}
+
+fn reuseport_option() -> io::Result<c_int> {
+    SO_REUSEPORT.ok_or(io::const_io_error!(
+        ErrorKind::Unsupported,
+        "SO_REUSEPORT is not supported on this platform",
+    ))
+}
+
+fn keepalive_option(option_name: Option<c_int>) -> io::Result<c_int> {
+    option_name.ok_or(io::const_io_error!(
+        ErrorKind::Unsupported,
+        "the keepalive parameters are not supported on this platform",
+    ))
+}
+
+fn set_keepalive_time(sock: &Socket, option_name: Option<c_int>, dur: Duration) -> io::Result<()> {
+    let option_name = keepalive_option(option_name)?;
+    if dur.as_secs() == 0 {
+        return Err(io::const_io_error!(
+            ErrorKind::InvalidInput,
+            "cannot set a keepalive time shorter than a second",
+        ));
+    }
+    let secs = cmp::min(dur.as_secs(), c_int::MAX as u64) as c_int;
+    setsockopt(sock, c::IPPROTO_TCP, option_name, secs)
+}
+
+fn keepalive_time(sock: &Socket, option_name: Option<c_int>) -> io::Result<Duration> {
+    let raw: c_int = getsockopt(sock, c::IPPROTO_TCP, keepalive_option(option_name)?)?;
+    Ok(Duration::from_secs(raw as u64))
+}
+
+fn set_buffer_size(sock: &Socket, option_name: c_int, size: u32) -> io::Result<()> {
+    let size = cmp::min(size, c_int::MAX as u32) as c_int;
+    setsockopt(sock, c::SOL_SOCKET, option_name, size)
+}
+
+fn buffer_size(sock: &Socket, option_name: c_int) -> io::Result<u32> {
+    let raw: c_int = getsockopt(sock, c::SOL_SOCKET, option_name)?;
+    Ok(raw as u32)
+}
+
+pub struct TcpSocketBuilder {
+    inner: Socket,
+}
+
+impl TcpSocketBuilder {
+    pub fn new(addr: &SocketAddr) -> io::Result<TcpSocketBuilder> {
+        init();
+
+        let sock = Socket::new(addr, c::SOCK_STREAM)?;
+        Ok(TcpSocketBuilder { inner: sock })
+    }
+
+    pub fn set_reuseaddr(&self, reuseaddr: bool) -> io::Result<()> {
+        set_bool_option(&self.inner, c::SO_REUSEADDR, reuseaddr)
+    }
+
+    pub fn reuseaddr(&self) -> io::Result<bool> {
+        bool_option(&self.inner, c::SO_REUSEADDR)
+    }
+
+    pub fn set_reuseport(&self, reuseport: bool) -> io::Result<()> {
+        set_bool_option(&self.inner, reuseport_option()?, reuseport)
+    }
+
+    pub fn reuseport(&self) -> io::Result<bool> {
+        bool_option(&self.inner, reuseport_option()?)
+    }
+
+    pub fn set_keepalive(&self, keepalive: bool) -> io::Result<()> {
+        set_bool_option(&self.inner, c::SO_KEEPALIVE, keepalive)
+    }
+
+    pub fn keepalive(&self) -> io::Result<bool> {
+        bool_option(&self.inner, c::SO_KEEPALIVE)
+    }
+
+    pub fn set_keepalive_idle(&self, idle: Duration) -> io::Result<()> {
+        set_keepalive_time(&self.inner, TCP_KEEPIDLE, idle)
+    }
+
+    pub fn keepalive_idle(&self) -> io::Result<Duration> {
+        keepalive_time(&self.inner, TCP_KEEPIDLE)
+    }
+
+    pub fn set_keepalive_interval(&self, interval: Duration) -> io::Result<()> {
+        set_keepalive_time(&self.inner, TCP_KEEPINTVL, interval)
+    }
+
+    pub fn keepalive_interval(&self) -> io::Result<Duration> {
+        keepalive_time(&self.inner, TCP_KEEPINTVL)
+    }
+
+    pub fn set_keepalive_retries(&self, retries: u32) -> io::Result<()> {
+        let retries = cmp::min(retries, c_int::MAX as u32) as c_int;
+        setsockopt(&self.inner, c::IPPROTO_TCP, keepalive_option(TCP_KEEPCNT)?, retries)
+    }
+
+    pub fn keepalive_retries(&self) -> io::Result<u32> {
+        let raw: c_int = getsockopt(&self.inner, c::IPPROTO_TCP, keepalive_option(TCP_KEEPCNT)?)?;
+        Ok(raw as u32)
+    }
+
+    pub fn set_send_buffer_size(&self, size: u32) -> io::Result<()> {
+        set_buffer_size(&self.inner, c::SO_SNDBUF, size)
+    }
+
+    pub fn send_buffer_size(&self) -> io::Result<u32> {
+        buffer_size(&self.inner, c::SO_SNDBUF)
+    }
+
+    pub fn set_recv_buffer_size(&self, size: u32) -> io::Result<()> {
+        set_buffer_size(&self.inner, c::SO_RCVBUF, size)
+    }
+
+    pub fn recv_buffer_size(&self) -> io::Result<u32> {
+        buffer_size(&self.inner, c::SO_RCVBUF)
+    }
+
+    pub fn bind(&self, addr: &SocketAddr) -> io::Result<()> {
+        bind(&self.inner, addr)
+    }
+
+    pub fn socket_addr(&self) -> io::Result<SocketAddr> {
+        sockname(|buf, len| unsafe { c::getsockname(self.inner.as_raw(), buf, len) })
+    }
+
+    pub fn connect(self, addr: &SocketAddr) -> io::Result<TcpStream> {
+        self.inner.connect(addr)?;
+        Ok(TcpStream { inner: self.inner })
+    }
+
+    pub fn listen(self, backlog: u32) -> io::Result<TcpListener> {
+        let backlog = cmp::min(backlog, c_int::MAX as u32) as c_int;
+        cvt(unsafe { c::listen(self.inner.as_raw(), backlog) })?;
+        Ok(TcpListener { inner: self.inner })
+    }
+}
+
+impl fmt::Debug for TcpSocketBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut res = f.debug_struct("TcpSocketBuilder");
+
+        if let Ok(addr) = self.socket_addr() {
+            res.field("addr", &addr);
+        }
+
+        let name = if cfg!(windows) { "socket" } else { "fd" };
+        res.field(name, &self.inner.as_raw()).finish()
+    }
+}
+
+pub struct UdpSocketBuilder {
+    inner: Socket,
+}
+
+impl UdpSocketBuilder {
+    pub fn new(addr: &SocketAddr) -> io::Result<UdpSocketBuilder> {
+        init();
+
+        let sock = Socket::new(addr, c::SOCK_DGRAM)?;
+        Ok(UdpSocketBuilder { inner: sock })
+    }
+
+    pub fn set_reuseaddr(&self, reuseaddr: bool) -> io::Result<()> {
+        set_bool_option(&self.inner, c::SO_REUSEADDR, reuseaddr)
+    }
+
+    pub fn reuseaddr(&self) -> io::Result<bool> {
+        bool_option(&self.inner, c::SO_REUSEADDR)
+    }
+
+    pub fn set_reuseport(&self, reuseport: bool) -> io::Result<()> {
+        set_bool_option(&self.inner, reuseport_option()?, reuseport)
+    }
+
+    pub fn reuseport(&self) -> io::Result<bool> {
+        bool_option(&self.inner, reuseport_option()?)
+    }
+
+    pub fn set_send_buffer_size(&self, size: u32) -> io::Result<()> {
+        set_buffer_size(&self.inner, c::SO_SNDBUF, size)
+    }
+
+    pub fn send_buffer_size(&self) -> io::Result<u32> {
+        buffer_size(&self.inner, c::SO_SNDBUF)
+    }
+
+    pub fn set_recv_buffer_size(&self, size: u32) -> io::Result<()> {
+        set_buffer_size(&self.inner, c::SO_RCVBUF, size)
+    }
+
+    pub fn recv_buffer_size(&self) -> io::Result<u32> {
+        buffer_size(&self.inner, c::SO_RCVBUF)
+    }
+
+    pub fn bind(self, addr: &SocketAddr) -> io::Result<UdpSocket> {
+        bind(&self.inner, addr)?;
+        Ok(UdpSocket { inner: self.inner })
+    }
+}
+
+impl fmt::Debug for UdpSocketBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = if cfg!(windows) { "socket" } else { "fd" };
+        f.debug_struct("UdpSocketBuilder").field(name, &self.inner.as_raw()).finish()
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Converting SocketAddr to libc representation
 ////////////////////////////////////////////////////////////////////////////////
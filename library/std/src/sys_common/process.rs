@@ -8,6 +8,8 @@
 use crate::io;
 use crate::sys::pipe::read2;
 use crate::sys::process::{EnvKey, ExitStatus, Process, StdioPipes};
+use crate::thread;
+use crate::time::{Duration, Instant};
 
 // Stores a set of changes to an environment
 #[derive(Clone)]
@@ -159,3 +161,29 @@ pub fn wait_with_output(
     let status = process.wait()?;
     Ok((status, stdout, stderr))
 }
+
+/// Waits for `process` to exit for at most `timeout`, by polling it with `try_wait`, on platforms
+/// that can't wait for a process with a timeout.
+pub fn wait_timeout_by_polling(
+    process: &mut Process,
+    timeout: Duration,
+) -> io::Result<Option<ExitStatus>> {
+    const MAX_DELAY: Duration = Duration::from_millis(50);
+
+    let deadline = Instant::now().checked_add(timeout);
+    let mut delay = Duration::from_millis(1);
+    loop {
+        if let Some(status) = process.try_wait()? {
+            return Ok(Some(status));
+        }
+        let remaining = match deadline {
+            Some(deadline) => deadline.saturating_duration_since(Instant::now()),
+            None => MAX_DELAY,
+        };
+        if remaining.is_zero() {
+            return Ok(None);
+        }
+        thread::sleep(delay.min(remaining));
+        delay = (delay * 2).min(MAX_DELAY);
+    }
+}
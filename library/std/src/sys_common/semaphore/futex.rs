@@ -0,0 +1,113 @@
+//! A semaphore for the platforms with futexes, waiting on a counter that is
+//! incremented when permits are released.
+
+use crate::sync::atomic::{
+    AtomicU32, AtomicUsize,
+    Ordering::{Acquire, Relaxed, SeqCst},
+};
+use crate::sys::futex::{futex_wait, futex_wake, futex_wake_all};
+use crate::time::Instant;
+
+pub struct Semaphore {
+    /// The number of available permits.
+    permits: AtomicUsize,
+    /// The number of threads waiting for permits.
+    waiters: AtomicUsize,
+    /// The number of those threads that wait for more than one permit.
+    many_waiters: AtomicUsize,
+    /// Incremented every time permits are released, for the waiting threads to
+    /// wait on.
+    futex: AtomicU32,
+}
+
+// Notes about memory ordering:
+//
+// A waiting thread registers itself in `waiters` before checking `permits`,
+// and a releasing thread updates `permits` before checking `waiters`. All of
+// these accesses are SeqCst, so either the waiting thread sees the released
+// permits, or the releasing thread sees the waiting thread and wakes it up.
+// As the waiting thread reads `futex` before checking `permits`, and the
+// releasing thread increments it after updating `permits`, the waiting thread
+// doesn't go to sleep if it missed the permits but raced with the wake up.
+impl Semaphore {
+    #[inline]
+    pub const fn new(permits: usize) -> Self {
+        Self {
+            permits: AtomicUsize::new(permits),
+            waiters: AtomicUsize::new(0),
+            many_waiters: AtomicUsize::new(0),
+            futex: AtomicU32::new(0),
+        }
+    }
+
+    #[inline]
+    pub fn available(&self) -> usize {
+        self.permits.load(Relaxed)
+    }
+
+    #[inline]
+    pub fn try_acquire(&self, n: usize) -> bool {
+        self.permits.fetch_update(SeqCst, SeqCst, |permits| permits.checked_sub(n)).is_ok()
+    }
+
+    /// Waits until `n` permits can be acquired, or until `deadline`. Returns
+    /// whether they were acquired.
+    pub fn acquire(&self, n: usize, deadline: Option<Instant>) -> bool {
+        if self.try_acquire(n) {
+            return true;
+        }
+
+        self.waiters.fetch_add(1, SeqCst);
+        if n > 1 {
+            self.many_waiters.fetch_add(1, SeqCst);
+        }
+        let acquired = loop {
+            let futex_value = self.futex.load(Acquire);
+            if self.try_acquire(n) {
+                break true;
+            }
+            let timeout = match deadline {
+                Some(deadline) => match deadline.checked_duration_since(Instant::now()) {
+                    Some(timeout) => Some(timeout),
+                    None => break false,
+                },
+                None => None,
+            };
+            // Spurious wake ups, and wake ups for permits that another thread
+            // acquired first, are handled by the loop.
+            futex_wait(&self.futex, futex_value, timeout);
+        };
+        if n > 1 {
+            self.many_waiters.fetch_sub(1, Relaxed);
+        }
+        self.waiters.fetch_sub(1, Relaxed);
+        acquired
+    }
+
+    /// Releases `n` permits.
+    ///
+    /// Panics without releasing them if the number of permits overflows.
+    pub fn release(&self, n: usize) {
+        if self.permits.fetch_update(SeqCst, SeqCst, |permits| permits.checked_add(n)).is_err() {
+            panic!("overflow in the permits of a semaphore");
+        }
+        self.futex.fetch_add(1, SeqCst);
+
+        let waiters = self.waiters.load(SeqCst);
+        if waiters == 0 {
+            return;
+        }
+        if self.many_waiters.load(SeqCst) > 0 {
+            // A thread waiting for several permits may not be able to use the
+            // ones released here while other threads could, so they all have
+            // to check.
+            futex_wake_all(&self.futex);
+        } else {
+            // Every waiting thread needs a single permit, so there is no point
+            // in waking up more threads than there are permits.
+            for _ in 0..n.min(waiters) {
+                futex_wake(&self.futex);
+            }
+        }
+    }
+}
@@ -0,0 +1,115 @@
+//! A semaphore for the platforms without futexes, waiting on a condition
+//! variable.
+
+use crate::sync::atomic::{
+    AtomicUsize,
+    Ordering::{Relaxed, SeqCst},
+};
+use crate::sync::{Condvar, Mutex, PoisonError};
+use crate::time::Instant;
+
+pub struct Semaphore {
+    /// The number of available permits.
+    permits: AtomicUsize,
+    /// The number of threads waiting for permits. Only modified with `lock`
+    /// held.
+    waiters: AtomicUsize,
+    /// The number of those threads that wait for more than one permit.
+    many_waiters: AtomicUsize,
+    /// Never held while running code that can panic, but a poisoned lock is
+    /// still accepted.
+    lock: Mutex<()>,
+    cvar: Condvar,
+}
+
+// The permits are acquired and released outside of the lock, so that this
+// never blocks when no thread is waiting. A waiting thread registers itself
+// in `waiters` before checking `permits`, and a releasing thread updates
+// `permits` before checking `waiters`. All of these accesses are SeqCst, so
+// either the waiting thread sees the released permits, or the releasing
+// thread sees the waiting thread, and takes the lock to wake it up.
+impl Semaphore {
+    #[inline]
+    pub const fn new(permits: usize) -> Self {
+        Self {
+            permits: AtomicUsize::new(permits),
+            waiters: AtomicUsize::new(0),
+            many_waiters: AtomicUsize::new(0),
+            lock: Mutex::new(()),
+            cvar: Condvar::new(),
+        }
+    }
+
+    #[inline]
+    pub fn available(&self) -> usize {
+        self.permits.load(Relaxed)
+    }
+
+    #[inline]
+    pub fn try_acquire(&self, n: usize) -> bool {
+        self.permits.fetch_update(SeqCst, SeqCst, |permits| permits.checked_sub(n)).is_ok()
+    }
+
+    /// Waits until `n` permits can be acquired, or until `deadline`. Returns
+    /// whether they were acquired.
+    pub fn acquire(&self, n: usize, deadline: Option<Instant>) -> bool {
+        if self.try_acquire(n) {
+            return true;
+        }
+
+        let mut guard = self.lock.lock().unwrap_or_else(PoisonError::into_inner);
+        self.waiters.fetch_add(1, SeqCst);
+        if n > 1 {
+            self.many_waiters.fetch_add(1, SeqCst);
+        }
+        let acquired = loop {
+            if self.try_acquire(n) {
+                break true;
+            }
+            guard = match deadline {
+                Some(deadline) => match deadline.checked_duration_since(Instant::now()) {
+                    Some(timeout) => {
+                        let result = self.cvar.wait_timeout(guard, timeout);
+                        result.unwrap_or_else(PoisonError::into_inner).0
+                    }
+                    None => break false,
+                },
+                None => self.cvar.wait(guard).unwrap_or_else(PoisonError::into_inner),
+            };
+        };
+        if n > 1 {
+            self.many_waiters.fetch_sub(1, Relaxed);
+        }
+        self.waiters.fetch_sub(1, Relaxed);
+        drop(guard);
+        acquired
+    }
+
+    /// Releases `n` permits.
+    ///
+    /// Panics without releasing them if the number of permits overflows.
+    pub fn release(&self, n: usize) {
+        if self.permits.fetch_update(SeqCst, SeqCst, |permits| permits.checked_add(n)).is_err() {
+            panic!("overflow in the permits of a semaphore");
+        }
+        if self.waiters.load(SeqCst) == 0 {
+            return;
+        }
+
+        // Taking the lock makes sure that the waiting threads that missed the
+        // permits are waiting on the condition variable.
+        let _guard = self.lock.lock().unwrap_or_else(PoisonError::into_inner);
+        if self.many_waiters.load(Relaxed) > 0 {
+            // A thread waiting for several permits may not be able to use the
+            // ones released here while other threads could, so they all have
+            // to check.
+            self.cvar.notify_all();
+        } else {
+            // Every waiting thread needs a single permit, so there is no point
+            // in waking up more threads than there are permits.
+            for _ in 0..n.min(self.waiters.load(Relaxed)) {
+                self.cvar.notify_one();
+            }
+        }
+    }
+}
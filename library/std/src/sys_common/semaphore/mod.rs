@@ -0,0 +1,22 @@
+// The semaphore keeps its permits in an atomic counter, so that acquiring and
+// releasing them doesn't block unless a thread has to wait. Waiting threads
+// sleep on a futex where available, and on a condition variable otherwise.
+
+cfg_if::cfg_if! {
+    if #[cfg(any(
+        target_os = "linux",
+        target_os = "android",
+        all(target_arch = "wasm32", target_feature = "atomics"),
+        target_os = "freebsd",
+        target_os = "openbsd",
+        target_os = "dragonfly",
+        target_os = "fuchsia",
+        target_os = "hermit",
+    ))] {
+        mod futex;
+        pub use futex::Semaphore;
+    } else {
+        mod generic;
+        pub use generic::Semaphore;
+    }
+}
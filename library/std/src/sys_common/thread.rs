@@ -1,5 +1,7 @@
 use crate::env;
+use crate::io;
 use crate::sync::atomic::{self, Ordering};
+use crate::sync::mpsc;
 use crate::sys::thread as imp;
 
 pub fn min_stack() -> usize {
@@ -16,3 +18,65 @@ pub fn min_stack() -> usize {
     MIN.store(amt + 1, Ordering::Relaxed);
     amt
 }
+
+/// The settings of a new thread that it applies to itself before running any code.
+#[derive(Clone, Debug, Default)]
+pub struct ThreadAttributes {
+    /// The indices of the CPUs the thread may run on.
+    pub affinity: Option<Vec<usize>>,
+    /// The raw scheduling policy and priority, which are only set on Linux.
+    pub sched_policy: Option<i32>,
+    pub sched_priority: Option<i32>,
+}
+
+impl ThreadAttributes {
+    fn is_default(&self) -> bool {
+        self.affinity.is_none() && self.sched_policy.is_none() && self.sched_priority.is_none()
+    }
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(any(unix, windows))] {
+        use imp::set_current_attributes;
+    } else {
+        fn set_current_attributes(_attrs: &ThreadAttributes) -> io::Result<()> {
+            Err(io::const_io_error!(
+                io::ErrorKind::Unsupported,
+                "thread attributes are not supported on this platform",
+            ))
+        }
+    }
+}
+
+/// Spawns a thread that runs `main` once it applied `attrs` to itself.
+///
+/// If the attributes can't be applied, `main` is dropped without running and the error is
+/// returned, which is why this waits until the thread has applied them.
+// unsafe: see thread::Builder::spawn_unchecked for safety requirements
+pub unsafe fn spawn(
+    stack: usize,
+    attrs: ThreadAttributes,
+    main: Box<dyn FnOnce()>,
+) -> io::Result<imp::Thread> {
+    if attrs.is_default() {
+        return imp::Thread::new(stack, main);
+    }
+
+    let (tx, rx) = mpsc::sync_channel(1);
+    let main = Box::new(move || match set_current_attributes(&attrs) {
+        Ok(()) => {
+            let _ = tx.send(Ok(()));
+            main();
+        }
+        Err(err) => {
+            drop(main);
+            let _ = tx.send(Err(err));
+        }
+    });
+    let thread = imp::Thread::new(stack, main)?;
+    if let Ok(Err(err)) = rx.recv() {
+        thread.join();
+        return Err(err);
+    }
+    Ok(thread)
+}
@@ -177,7 +177,7 @@
 use crate::sys_common::thread;
 use crate::sys_common::thread_info;
 use crate::sys_common::thread_parking::Parker;
-use crate::sys_common::{AsInner, IntoInner};
+use crate::sys_common::{AsInner, AsInnerMut, IntoInner};
 use crate::time::{Duration, Instant};
 
 #[stable(feature = "scoped_threads", since = "1.63.0")]
@@ -220,10 +220,11 @@ pub mod local_impl {
 ///
 /// Methods can be chained on it in order to configure it.
 ///
-/// The two configurations available are:
+/// The configurations available are:
 ///
 /// - [`name`]: specifies an [associated name for the thread][naming-threads]
 /// - [`stack_size`]: specifies the [desired stack size for the thread][stack-size]
+/// - [`affinity`]: specifies the CPUs the thread may run on
 ///
 /// The [`spawn`] method will take ownership of the builder and create an
 /// [`io::Result`] to the thread handle with the given configuration.
@@ -251,6 +252,7 @@ pub mod local_impl {
 ///
 /// [`stack_size`]: Builder::stack_size
 /// [`name`]: Builder::name
+/// [`affinity`]: Builder::affinity
 /// [`spawn`]: Builder::spawn
 /// [`thread::spawn`]: spawn
 /// [`io::Result`]: crate::io::Result
@@ -265,6 +267,8 @@ pub struct Builder {
     name: Option<String>,
     // The size of the stack for the spawned thread in bytes
     stack_size: Option<usize>,
+    // The settings the spawned thread applies to itself before running any code
+    attrs: thread::ThreadAttributes,
 }
 
 impl Builder {
@@ -288,7 +292,7 @@ impl Builder {
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn new() -> Builder {
-        Builder { name: None, stack_size: None }
+        Builder { name: None, stack_size: None, attrs: thread::ThreadAttributes::default() }
     }
 
     /// Names the thread-to-be. Currently the name is used for identification
@@ -344,6 +348,48 @@ pub fn stack_size(mut self, size: usize) -> Builder {
         self
     }
 
+    /// Sets the CPUs the new thread may run on, by their indices.
+    ///
+    /// The indices range from 0 to the number of CPUs of the system, and the
+    /// thread is restricted to the given CPUs before it runs any code, which
+    /// keeps it from migrating to other CPUs. When this is set, [`spawn`] waits
+    /// until the new thread has applied it.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This currently corresponds to the `sched_setaffinity` function on Linux
+    /// and Android, and to the `SetThreadAffinityMask` function on Windows,
+    /// which only supports the CPUs of the first processor group, that is, the
+    /// indices below 64. On other platforms, [`spawn`] fails with an error of
+    /// the kind [`io::ErrorKind::Unsupported`].
+    /// Note that this [may change in the future][changes].
+    ///
+    /// [changes]: io#platform-specific-behavior
+    /// [`spawn`]: Builder::spawn
+    ///
+    /// # Errors
+    ///
+    /// [`spawn`] fails if none of the CPUs can be used by the process, or if
+    /// an index is out of the supported range.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(thread_affinity)]
+    /// use std::thread;
+    ///
+    /// let handler = thread::Builder::new().affinity(&[2, 3]).spawn(|| {
+    ///     // thread code, which only runs on the CPUs 2 and 3
+    /// }).unwrap();
+    ///
+    /// handler.join().unwrap();
+    /// ```
+    #[unstable(feature = "thread_affinity", issue = "none")]
+    pub fn affinity(mut self, cpus: &[usize]) -> Builder {
+        self.attrs.affinity = Some(cpus.to_vec());
+        self
+    }
+
     /// Spawns a new thread by taking ownership of the `Builder`, and returns an
     /// [`io::Result`] to its [`JoinHandle`].
     ///
@@ -468,7 +514,7 @@ unsafe fn spawn_unchecked_<'a, 'scope, F, T>(
         T: Send + 'a,
         'scope: 'a,
     {
-        let Builder { name, stack_size } = self;
+        let Builder { name, stack_size, attrs } = self;
 
         let stack_size = stack_size.unwrap_or_else(thread::min_stack);
 
@@ -564,13 +610,24 @@ fn drop(&mut self) {
             // Similarly, the `sys` implementation must guarantee that no references to the closure
             // exist after the thread has terminated, which is signaled by `Thread::join`
             // returning.
-            native: unsafe { imp::Thread::new(stack_size, main)? },
+            native: unsafe { thread::spawn(stack_size, attrs, main)? },
             thread: my_thread,
             packet: my_packet,
         })
     }
 }
 
+impl AsInnerMut<thread::ThreadAttributes> for Builder {
+    #[inline]
+    fn as_inner_mut(&mut self) -> &mut thread::ThreadAttributes {
+        &mut self.attrs
+    }
+}
+
+/// Allows extension traits within `std`.
+#[unstable(feature = "sealed", issue = "none")]
+impl crate::sealed::Sealed for Builder {}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Free functions
 ////////////////////////////////////////////////////////////////////////////////
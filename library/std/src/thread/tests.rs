@@ -404,3 +404,43 @@ fn scope_join_race() {
         });
     }
 }
+
+#[test]
+#[cfg(any(target_os = "linux", target_os = "android", windows))]
+fn test_affinity_out_of_range() {
+    let err = Builder::new().affinity(&[usize::MAX]).spawn(|| panic!()).unwrap_err();
+    assert_eq!(err.kind(), crate::io::ErrorKind::InvalidInput);
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_affinity() {
+    use crate::os::linux::thread::affinity;
+
+    let cpus = affinity().unwrap();
+    let cpu = *cpus.last().unwrap();
+    let handle = Builder::new().affinity(&[cpu]).spawn(|| affinity().unwrap()).unwrap();
+    assert_eq!(handle.join().unwrap(), [cpu]);
+    // The affinity of the spawning thread is left alone.
+    assert_eq!(affinity().unwrap(), cpus);
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_sched_policy() {
+    use crate::os::linux::thread::{sched_policy, sched_priority, BuilderExt, SchedPolicy};
+
+    let handle = Builder::new()
+        .sched_policy(SchedPolicy::Batch)
+        .spawn(|| (sched_policy().unwrap(), sched_priority().unwrap()))
+        .unwrap();
+    assert_eq!(handle.join().unwrap(), (SchedPolicy::Batch, 0));
+
+    // A time-sharing policy only accepts the priority 0.
+    let err = Builder::new()
+        .sched_policy(SchedPolicy::Other)
+        .sched_priority(1)
+        .spawn(|| panic!())
+        .unwrap_err();
+    assert_eq!(err.kind(), crate::io::ErrorKind::InvalidInput);
+}
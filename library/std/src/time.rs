@@ -34,6 +34,8 @@
 #[cfg(test)]
 mod tests;
 
+mod civil;
+
 use crate::error::Error;
 use crate::fmt;
 use crate::ops::{Add, AddAssign, Sub, SubAssign};
@@ -46,6 +48,9 @@
 #[stable(feature = "duration_checked_float", since = "1.66.0")]
 pub use core::time::TryFromFloatSecsError;
 
+#[unstable(feature = "utc_date_time", issue = "none")]
+pub use self::civil::{ParseUtcDateTimeError, UtcDateTime};
+
 /// A measurement of a monotonically nondecreasing clock.
 /// Opaque and useful only with [`Duration`].
 ///
@@ -587,6 +592,36 @@ pub fn checked_add(&self, duration: Duration) -> Option<SystemTime> {
     pub fn checked_sub(&self, duration: Duration) -> Option<SystemTime> {
         self.0.checked_sub_duration(&duration).map(SystemTime)
     }
+
+    /// Breaks down this system time into a date and time in UTC, in the
+    /// proleptic Gregorian calendar.
+    ///
+    /// See [`UtcDateTime`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(utc_date_time)]
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// let time = UNIX_EPOCH - Duration::from_millis(1500);
+    /// assert_eq!(time.to_utc().to_string(), "1969-12-31T23:59:58.500Z");
+    /// ```
+    #[unstable(feature = "utc_date_time", issue = "none")]
+    #[must_use]
+    pub fn to_utc(&self) -> UtcDateTime {
+        let (secs, nanos) = match self.duration_since(UNIX_EPOCH) {
+            Ok(d) => (d.as_secs() as i128, d.subsec_nanos()),
+            Err(e) => {
+                let d = e.duration();
+                match d.subsec_nanos() {
+                    0 => (-(d.as_secs() as i128), 0),
+                    nanos => (-(d.as_secs() as i128) - 1, 1_000_000_000 - nanos),
+                }
+            }
+        };
+        UtcDateTime::from_unix_time(secs, nanos).expect("the year of a `SystemTime` fits in an i64")
+    }
 }
 
 #[stable(feature = "time2", since = "1.8.0")]
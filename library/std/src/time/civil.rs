@@ -0,0 +1,624 @@
+//! Conversion between system times and UTC dates and times in the proleptic
+//! Gregorian calendar.
+//!
+//! The conversion of days since the Unix epoch to calendar dates and back uses
+//! the algorithms described in <https://howardhinnant.github.io/date_algorithms.html>,
+//! which shift the start of the year to March so that leap days come last.
+
+use super::{Duration, SystemTime, UNIX_EPOCH};
+use crate::error::Error;
+use crate::fmt::{self, Write};
+use crate::str::FromStr;
+
+const SECS_PER_DAY: i128 = 86_400;
+const NANOS_PER_SEC: u32 = 1_000_000_000;
+
+/// The number of days in 400 years, after which the calendar repeats itself.
+const DAYS_PER_ERA: i128 = 146_097;
+
+/// The number of days from 0000-03-01, the start of an era, to 1970-01-01.
+const UNIX_EPOCH_DAYS: i128 = 719_468;
+
+/// A date and time in Coordinated Universal Time (UTC), broken down into the
+/// fields of the proleptic Gregorian calendar.
+///
+/// A `UtcDateTime` is obtained from a [`SystemTime`] with [`SystemTime::to_utc`],
+/// and converted back with [`to_system_time`]. Like [`SystemTime`], it
+/// ignores leap seconds: every day has exactly 86 400 seconds. Time zones are
+/// not supported, other than the fixed UTC offsets of the strings that are
+/// parsed.
+///
+/// Its [`Display`] implementation formats it as an [RFC 3339] timestamp,
+/// which is also an ISO 8601 one, and it can be parsed from one with
+/// [`FromStr`].
+///
+/// The years before year 1 follow the astronomical year numbering of ISO 8601:
+/// year 0 is 1 BC, year -1 is 2 BC, and so on.
+///
+/// [`to_system_time`]: UtcDateTime::to_system_time
+/// [`Display`]: fmt::Display
+/// [RFC 3339]: https://www.rfc-editor.org/rfc/rfc3339
+///
+/// # Examples
+///
+/// ```
+/// #![feature(utc_date_time)]
+/// use std::time::{Duration, UtcDateTime, UNIX_EPOCH};
+///
+/// let time = UNIX_EPOCH + Duration::new(951_782_400, 250_000_000);
+/// let utc = time.to_utc();
+/// assert_eq!((utc.year(), utc.month(), utc.day()), (2000, 2, 29));
+/// assert_eq!(utc.to_string(), "2000-02-29T00:00:00.250Z");
+///
+/// let parsed: UtcDateTime = "2000-02-29T01:00:00.25+01:00".parse().unwrap();
+/// assert_eq!(parsed.to_system_time(), Some(time));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[unstable(feature = "utc_date_time", issue = "none")]
+pub struct UtcDateTime {
+    // The order of the fields makes the derived `Ord` chronological.
+    year: i64,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    nanosecond: u32,
+}
+
+/// An error which can be returned when parsing a [`UtcDateTime`].
+///
+/// This error is used as the error type for the [`FromStr`] implementation
+/// for [`UtcDateTime`].
+///
+/// # Examples
+///
+/// ```
+/// #![feature(utc_date_time)]
+/// use std::time::UtcDateTime;
+///
+/// assert!("2023-02-29T00:00:00Z".parse::<UtcDateTime>().is_err());
+/// assert!("2023-02-28 24:00:00Z".parse::<UtcDateTime>().is_err());
+/// assert!("2023-02-28T00:00:00".parse::<UtcDateTime>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[unstable(feature = "utc_date_time", issue = "none")]
+pub struct ParseUtcDateTimeError {
+    kind: ParseErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ParseErrorKind {
+    /// The string doesn't have the syntax of an RFC 3339 timestamp.
+    Syntax,
+    /// A field is out of range, or the date and time can't be represented.
+    Range,
+}
+
+impl UtcDateTime {
+    /// Creates a date and time from its fields.
+    ///
+    /// Returns `None` if a field is out of range: `month` must be between 1
+    /// and 12, `day` between 1 and the number of days in the month, `hour`
+    /// lower than 24, `minute` and `second` lower than 60, and `nanosecond`
+    /// lower than 1 000 000 000.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(utc_date_time)]
+    /// use std::time::UtcDateTime;
+    ///
+    /// let date = UtcDateTime::new(2024, 2, 29, 12, 30, 0, 0).unwrap();
+    /// assert_eq!(date.to_string(), "2024-02-29T12:30:00Z");
+    ///
+    /// assert!(UtcDateTime::new(2023, 2, 29, 12, 30, 0, 0).is_none());
+    /// assert!(UtcDateTime::new(2024, 2, 29, 12, 60, 0, 0).is_none());
+    /// ```
+    #[unstable(feature = "utc_date_time", issue = "none")]
+    #[must_use]
+    pub fn new(
+        year: i64,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        nanosecond: u32,
+    ) -> Option<UtcDateTime> {
+        if !(1..=12).contains(&month)
+            || !(1..=Self::days_in_month(year, month)).contains(&day)
+            || hour >= 24
+            || minute >= 60
+            || second >= 60
+            || nanosecond >= NANOS_PER_SEC
+        {
+            return None;
+        }
+        Some(UtcDateTime { year, month, day, hour, minute, second, nanosecond })
+    }
+
+    /// Returns the year, which is 0 for 1 BC and negative before that.
+    #[unstable(feature = "utc_date_time", issue = "none")]
+    #[must_use]
+    pub fn year(&self) -> i64 {
+        self.year
+    }
+
+    /// Returns the month, from 1 for January to 12 for December.
+    #[unstable(feature = "utc_date_time", issue = "none")]
+    #[must_use]
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Returns the day of the month, starting at 1.
+    #[unstable(feature = "utc_date_time", issue = "none")]
+    #[must_use]
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Returns the hour, from 0 to 23.
+    #[unstable(feature = "utc_date_time", issue = "none")]
+    #[must_use]
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    /// Returns the minute, from 0 to 59.
+    #[unstable(feature = "utc_date_time", issue = "none")]
+    #[must_use]
+    pub fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    /// Returns the second, from 0 to 59.
+    #[unstable(feature = "utc_date_time", issue = "none")]
+    #[must_use]
+    pub fn second(&self) -> u8 {
+        self.second
+    }
+
+    /// Returns the fractional part of the second, in nanoseconds.
+    #[unstable(feature = "utc_date_time", issue = "none")]
+    #[must_use]
+    pub fn nanosecond(&self) -> u32 {
+        self.nanosecond
+    }
+
+    /// Returns whether `year` is a leap year in the proleptic Gregorian calendar,
+    /// that is whether it is divisible by 4 but not by 100, or by 400.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(utc_date_time)]
+    /// use std::time::UtcDateTime;
+    ///
+    /// assert!(UtcDateTime::is_leap_year(2024));
+    /// assert!(!UtcDateTime::is_leap_year(1900));
+    /// assert!(UtcDateTime::is_leap_year(2000));
+    /// assert!(UtcDateTime::is_leap_year(-4));
+    /// ```
+    #[unstable(feature = "utc_date_time", issue = "none")]
+    #[must_use]
+    pub const fn is_leap_year(year: i64) -> bool {
+        year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+    }
+
+    /// Returns the number of days in the given month of `year`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `month` isn't between 1 and 12.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(utc_date_time)]
+    /// use std::time::UtcDateTime;
+    ///
+    /// assert_eq!(UtcDateTime::days_in_month(2023, 2), 28);
+    /// assert_eq!(UtcDateTime::days_in_month(2024, 2), 29);
+    /// assert_eq!(UtcDateTime::days_in_month(2024, 4), 30);
+    /// ```
+    #[unstable(feature = "utc_date_time", issue = "none")]
+    #[must_use]
+    pub const fn days_in_month(year: i64, month: u8) -> u8 {
+        match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if Self::is_leap_year(year) => 29,
+            2 => 28,
+            _ => panic!("invalid month"),
+        }
+    }
+
+    /// Returns the day of the year, from 1 for January 1st to 365, or 366 in
+    /// leap years, for December 31st.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(utc_date_time)]
+    /// use std::time::UtcDateTime;
+    ///
+    /// let date = UtcDateTime::new(2024, 3, 1, 0, 0, 0, 0).unwrap();
+    /// assert_eq!(date.day_of_year(), 61);
+    /// ```
+    #[unstable(feature = "utc_date_time", issue = "none")]
+    #[must_use]
+    pub fn day_of_year(&self) -> u16 {
+        (days_from_civil(self.year, self.month, self.day) - days_from_civil(self.year, 1, 1) + 1)
+            as u16
+    }
+
+    /// Returns the day of the week, numbered as in ISO 8601 from 1 for Monday
+    /// to 7 for Sunday.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(utc_date_time)]
+    /// use std::time::UtcDateTime;
+    ///
+    /// // The Unix epoch was a Thursday.
+    /// let date = UtcDateTime::new(1970, 1, 1, 0, 0, 0, 0).unwrap();
+    /// assert_eq!(date.weekday(), 4);
+    /// ```
+    #[unstable(feature = "utc_date_time", issue = "none")]
+    #[must_use]
+    pub fn weekday(&self) -> u8 {
+        ((days_from_civil(self.year, self.month, self.day) + 3).rem_euclid(7) + 1) as u8
+    }
+
+    /// Adds a number of days to the date, keeping the time of the day.
+    ///
+    /// Returns `None` if the year of the result overflows an `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(utc_date_time)]
+    /// use std::time::UtcDateTime;
+    ///
+    /// let date = UtcDateTime::new(2023, 12, 31, 18, 0, 0, 0).unwrap();
+    /// let next = date.checked_add_days(1).unwrap();
+    /// assert_eq!(next.to_string(), "2024-01-01T18:00:00Z");
+    /// assert_eq!(next.checked_add_days(-365).unwrap().to_string(), "2023-01-01T18:00:00Z");
+    /// ```
+    #[unstable(feature = "utc_date_time", issue = "none")]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn checked_add_days(self, days: i64) -> Option<UtcDateTime> {
+        let (year, month, day) =
+            civil_from_days(days_from_civil(self.year, self.month, self.day) + days as i128)?;
+        Some(UtcDateTime { year, month, day, ..self })
+    }
+
+    /// Adds a number of months to the date, keeping the time of the day.
+    ///
+    /// If the day of the month doesn't exist in the resulting month, it is
+    /// clamped to the last day of that month.
+    ///
+    /// Returns `None` if the year of the result overflows an `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(utc_date_time)]
+    /// use std::time::UtcDateTime;
+    ///
+    /// let date = UtcDateTime::new(2024, 1, 31, 0, 0, 0, 0).unwrap();
+    /// assert_eq!(date.checked_add_months(1).unwrap().to_string(), "2024-02-29T00:00:00Z");
+    /// assert_eq!(date.checked_add_months(-2).unwrap().to_string(), "2023-11-30T00:00:00Z");
+    /// ```
+    #[unstable(feature = "utc_date_time", issue = "none")]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn checked_add_months(self, months: i64) -> Option<UtcDateTime> {
+        let months = self.year as i128 * 12 + (self.month - 1) as i128 + months as i128;
+        let year = i64::try_from(months.div_euclid(12)).ok()?;
+        let month = months.rem_euclid(12) as u8 + 1;
+        let day = self.day.min(Self::days_in_month(year, month));
+        Some(UtcDateTime { year, month, day, ..self })
+    }
+
+    /// Converts the date and time to a [`SystemTime`].
+    ///
+    /// Returns `None` if the date and time is outside the range of
+    /// [`SystemTime`], which depends on the platform.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(utc_date_time)]
+    /// use std::time::{Duration, UtcDateTime, UNIX_EPOCH};
+    ///
+    /// let date = UtcDateTime::new(1970, 1, 2, 0, 0, 1, 0).unwrap();
+    /// assert_eq!(date.to_system_time(), Some(UNIX_EPOCH + Duration::from_secs(86_401)));
+    /// ```
+    #[unstable(feature = "utc_date_time", issue = "none")]
+    #[must_use]
+    pub fn to_system_time(&self) -> Option<SystemTime> {
+        let secs = self.unix_seconds();
+        let nanos = Duration::from_nanos(self.nanosecond.into());
+        if secs >= 0 {
+            UNIX_EPOCH
+                .checked_add(Duration::from_secs(u64::try_from(secs).ok()?))?
+                .checked_add(nanos)
+        } else {
+            UNIX_EPOCH
+                .checked_sub(Duration::from_secs(u64::try_from(-secs).ok()?))?
+                .checked_add(nanos)
+        }
+    }
+
+    /// Returns the whole number of seconds since the Unix epoch, which is
+    /// negative before it.
+    fn unix_seconds(&self) -> i128 {
+        days_from_civil(self.year, self.month, self.day) * SECS_PER_DAY
+            + self.hour as i128 * 3600
+            + self.minute as i128 * 60
+            + self.second as i128
+    }
+
+    /// Creates a date and time from the whole number of seconds since the Unix
+    /// epoch and the nanoseconds after them.
+    ///
+    /// Returns `None` if the year overflows an `i64`.
+    pub(super) fn from_unix_time(secs: i128, nanosecond: u32) -> Option<UtcDateTime> {
+        let (year, month, day) = civil_from_days(secs.div_euclid(SECS_PER_DAY))?;
+        let secs = secs.rem_euclid(SECS_PER_DAY) as u32;
+        let (hour, minute, second) =
+            ((secs / 3600) as u8, (secs / 60 % 60) as u8, (secs % 60) as u8);
+        Some(UtcDateTime { year, month, day, hour, minute, second, nanosecond })
+    }
+}
+
+/// Returns the number of days from 1970-01-01 to the given date.
+fn days_from_civil(year: i64, month: u8, day: u8) -> i128 {
+    let year = year as i128 - (month <= 2) as i128;
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month = month as i128;
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i128 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * DAYS_PER_ERA + day_of_era - UNIX_EPOCH_DAYS
+}
+
+/// Returns the date that is the given number of days after 1970-01-01, or
+/// `None` if its year overflows an `i64`.
+fn civil_from_days(days: i128) -> Option<(i64, u8, u8)> {
+    let days = days + UNIX_EPOCH_DAYS;
+    let era = days.div_euclid(DAYS_PER_ERA);
+    let day_of_era = days.rem_euclid(DAYS_PER_ERA);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u8;
+    let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 } as u8;
+    let year = era * 400 + year_of_era + (month <= 2) as i128;
+    Some((i64::try_from(year).ok()?, month, day))
+}
+
+impl UtcDateTime {
+    /// Writes the RFC 3339 timestamp of `Display`, with `precision` digits of
+    /// fractional seconds if set.
+    fn write_timestamp(&self, f: &mut impl fmt::Write, precision: Option<usize>) -> fmt::Result {
+        if (0..=9999).contains(&self.year) {
+            write!(f, "{:04}", self.year)?;
+        } else {
+            write!(f, "{:+05}", self.year)?;
+        }
+        write!(
+            f,
+            "-{:02}-{:02}T{:02}:{:02}:{:02}",
+            self.month, self.day, self.hour, self.minute, self.second
+        )?;
+        let digits = match precision {
+            Some(precision) => precision.min(9),
+            None if self.nanosecond == 0 => 0,
+            None if self.nanosecond % 1_000_000 == 0 => 3,
+            None if self.nanosecond % 1_000 == 0 => 6,
+            None => 9,
+        };
+        if digits > 0 {
+            let fraction = self.nanosecond / 10u32.pow(9 - digits as u32);
+            write!(f, ".{fraction:0digits$}")?;
+        }
+        f.write_str("Z")
+    }
+}
+
+#[unstable(feature = "utc_date_time", issue = "none")]
+impl fmt::Display for UtcDateTime {
+    /// Formats the date and time as an RFC 3339 timestamp in UTC, such as
+    /// `2024-02-29T12:30:00.5Z`.
+    ///
+    /// The fractional seconds are written with 3, 6 or 9 digits, or left out
+    /// if they are zero. A precision, as in `{:.3}`, sets the number of digits
+    /// instead, up to 9, truncating the extra ones. A width, as in `{:>30}`,
+    /// pads the whole timestamp with the fill character and alignment.
+    ///
+    /// The years outside of the range 0 to 9999, which RFC 3339 doesn't
+    /// support, are written with a sign and at least four digits, as in the
+    /// expanded representation of ISO 8601.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f.precision();
+        let Some(width) = f.width() else {
+            return self.write_timestamp(f, precision);
+        };
+
+        // The precision doesn't truncate the timestamp, so `Formatter::pad` can't be used.
+        let mut timestamp = String::new();
+        self.write_timestamp(&mut timestamp, precision)?;
+        let padding = width.saturating_sub(timestamp.len());
+        let (before, after) = match f.align() {
+            Some(fmt::Alignment::Right) => (padding, 0),
+            Some(fmt::Alignment::Center) => (padding / 2, (padding + 1) / 2),
+            Some(fmt::Alignment::Left) | None => (0, padding),
+        };
+        let fill = f.fill();
+        for _ in 0..before {
+            f.write_char(fill)?;
+        }
+        f.write_str(&timestamp)?;
+        for _ in 0..after {
+            f.write_char(fill)?;
+        }
+        Ok(())
+    }
+}
+
+#[unstable(feature = "utc_date_time", issue = "none")]
+impl FromStr for UtcDateTime {
+    type Err = ParseUtcDateTimeError;
+
+    /// Parses an RFC 3339 timestamp, such as `2024-02-29T12:30:00.5Z` or
+    /// `2024-02-29 13:30:00.5+01:00`.
+    ///
+    /// The timestamp is converted to UTC according to its offset. The `T`
+    /// separating the date from the time may also be a lowercase `t` or a
+    /// space, and the `Z` of UTC a lowercase `z`. The fractional seconds can
+    /// have any number of digits, but the ones after the ninth are ignored.
+    /// The years outside of the range 0 to 9999 are accepted with a sign and
+    /// at least four digits, as written by the [`Display`] implementation.
+    ///
+    /// Leap seconds aren't supported, so the seconds must be lower than 60.
+    ///
+    /// [`Display`]: fmt::Display
+    fn from_str(s: &str) -> Result<UtcDateTime, ParseUtcDateTimeError> {
+        let mut parser = Parser { s: s.as_bytes() };
+        let year = parser.year()?;
+        parser.expect(b"-")?;
+        let month = parser.two_digits()?;
+        parser.expect(b"-")?;
+        let day = parser.two_digits()?;
+        parser.expect(b"Tt ")?;
+        let hour = parser.two_digits()?;
+        parser.expect(b":")?;
+        let minute = parser.two_digits()?;
+        parser.expect(b":")?;
+        let second = parser.two_digits()?;
+        let nanosecond = parser.fraction()?;
+        let offset = parser.offset()?;
+        if !parser.s.is_empty() {
+            return Err(ParseUtcDateTimeError { kind: ParseErrorKind::Syntax });
+        }
+
+        let range_error = ParseUtcDateTimeError { kind: ParseErrorKind::Range };
+        let local = UtcDateTime::new(year, month, day, hour, minute, second, nanosecond)
+            .ok_or_else(|| range_error.clone())?;
+        if offset == 0 {
+            return Ok(local);
+        }
+        UtcDateTime::from_unix_time(local.unix_seconds() - offset as i128, nanosecond)
+            .ok_or(range_error)
+    }
+}
+
+/// A parser for the fields of an RFC 3339 timestamp.
+struct Parser<'a> {
+    s: &'a [u8],
+}
+
+impl Parser<'_> {
+    fn syntax_error<T>() -> Result<T, ParseUtcDateTimeError> {
+        Err(ParseUtcDateTimeError { kind: ParseErrorKind::Syntax })
+    }
+
+    /// Consumes one of the characters in `chars`, and returns it.
+    fn expect(&mut self, chars: &[u8]) -> Result<u8, ParseUtcDateTimeError> {
+        match self.s.split_first() {
+            Some((&c, rest)) if chars.contains(&c) => {
+                self.s = rest;
+                Ok(c)
+            }
+            _ => Self::syntax_error(),
+        }
+    }
+
+    /// Consumes the leading ASCII digits, possibly none.
+    fn digits(&mut self) -> &[u8] {
+        let len = self.s.iter().take_while(|c| c.is_ascii_digit()).count();
+        let (digits, rest) = self.s.split_at(len);
+        self.s = rest;
+        digits
+    }
+
+    fn two_digits(&mut self) -> Result<u8, ParseUtcDateTimeError> {
+        match self.s {
+            [a @ b'0'..=b'9', b @ b'0'..=b'9', rest @ ..] => {
+                self.s = rest;
+                Ok((a - b'0') * 10 + (b - b'0'))
+            }
+            _ => Self::syntax_error(),
+        }
+    }
+
+    /// Parses a year of four digits, or of at least four digits after a sign.
+    fn year(&mut self) -> Result<i64, ParseUtcDateTimeError> {
+        let sign = self.expect(b"+-").ok();
+        let digits = self.digits();
+        if digits.len() < 4 || (digits.len() > 4 && sign.is_none()) {
+            return Self::syntax_error();
+        }
+        // Negative years are accumulated as such, as `i64::MIN` has no positive counterpart.
+        let sign = if sign == Some(b'-') { -1 } else { 1 };
+        let mut year: i64 = 0;
+        for &digit in digits {
+            year = year
+                .checked_mul(10)
+                .and_then(|year| year.checked_add(sign * i64::from(digit - b'0')))
+                .ok_or(ParseUtcDateTimeError { kind: ParseErrorKind::Range })?;
+        }
+        Ok(year)
+    }
+
+    /// Parses the optional fractional seconds, in nanoseconds.
+    fn fraction(&mut self) -> Result<u32, ParseUtcDateTimeError> {
+        if self.expect(b".").is_err() {
+            return Ok(0);
+        }
+        let digits = self.digits();
+        if digits.is_empty() {
+            return Self::syntax_error();
+        }
+        let mut nanosecond = 0;
+        for i in 0..9 {
+            nanosecond = nanosecond * 10 + digits.get(i).map_or(0, |digit| (digit - b'0') as u32);
+        }
+        Ok(nanosecond)
+    }
+
+    /// Parses the offset from UTC, in seconds.
+    fn offset(&mut self) -> Result<i32, ParseUtcDateTimeError> {
+        let sign = self.expect(b"Zz+-")?;
+        if sign == b'Z' || sign == b'z' {
+            return Ok(0);
+        }
+        let hours = self.two_digits()?;
+        self.expect(b":")?;
+        let minutes = self.two_digits()?;
+        if hours >= 24 || minutes >= 60 {
+            return Err(ParseUtcDateTimeError { kind: ParseErrorKind::Range });
+        }
+        let offset = hours as i32 * 3600 + minutes as i32 * 60;
+        Ok(if sign == b'-' { -offset } else { offset })
+    }
+}
+
+#[unstable(feature = "utc_date_time", issue = "none")]
+impl fmt::Display for ParseUtcDateTimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self.kind {
+            ParseErrorKind::Syntax => "invalid RFC 3339 date and time syntax",
+            ParseErrorKind::Range => "date and time out of range",
+        })
+    }
+}
+
+#[unstable(feature = "utc_date_time", issue = "none")]
+impl Error for ParseUtcDateTimeError {}
@@ -1,4 +1,4 @@
-use super::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use super::{Duration, Instant, SystemTime, UtcDateTime, UNIX_EPOCH};
 use core::fmt::Debug;
 #[cfg(not(target_arch = "wasm32"))]
 use test::{black_box, Bencher};
@@ -228,6 +228,148 @@ fn check<T: Eq + Copy + Debug>(start: Option<T>, op: impl Fn(&T, Duration) -> Op
     check(instant.checked_add(Duration::from_secs(i64::MAX as _)), Instant::checked_sub);
 }
 
+#[test]
+fn utc_round_trip() {
+    let times = [
+        UNIX_EPOCH,
+        UNIX_EPOCH + Duration::new(951_782_400, 250_000_000),
+        UNIX_EPOCH - Duration::from_nanos(1),
+        UNIX_EPOCH - Duration::new(86_400 * 365, 999_999_999),
+        UNIX_EPOCH + Duration::from_secs(253_402_300_799),
+    ];
+    for time in times {
+        let utc = time.to_utc();
+        assert_eq!(utc.to_system_time(), Some(time));
+        assert_eq!(utc.to_string().parse::<UtcDateTime>(), Ok(utc));
+    }
+
+    let utc = (UNIX_EPOCH - Duration::from_nanos(1)).to_utc();
+    assert_eq!(utc, UtcDateTime::new(1969, 12, 31, 23, 59, 59, 999_999_999).unwrap());
+    assert_eq!(
+        (UNIX_EPOCH + Duration::from_secs(253_402_300_799)).to_utc().to_string(),
+        "9999-12-31T23:59:59Z"
+    );
+}
+
+#[test]
+fn utc_calendar() {
+    assert!(UtcDateTime::is_leap_year(2000));
+    assert!(UtcDateTime::is_leap_year(0));
+    assert!(UtcDateTime::is_leap_year(-400));
+    assert!(!UtcDateTime::is_leap_year(2100));
+    assert!(!UtcDateTime::is_leap_year(-1));
+    assert_eq!(UtcDateTime::days_in_month(1900, 2), 28);
+    assert_eq!(UtcDateTime::days_in_month(2000, 2), 29);
+    assert_eq!(UtcDateTime::days_in_month(2000, 12), 31);
+    assert!(UtcDateTime::new(2000, 0, 1, 0, 0, 0, 0).is_none());
+    assert!(UtcDateTime::new(2000, 4, 31, 0, 0, 0, 0).is_none());
+    assert!(UtcDateTime::new(2000, 1, 1, 24, 0, 0, 0).is_none());
+    assert!(UtcDateTime::new(2000, 1, 1, 0, 0, 60, 0).is_none());
+    assert!(UtcDateTime::new(2000, 1, 1, 0, 0, 0, 1_000_000_000).is_none());
+
+    // Walk day by day across several 400-year cycles, around year 0.
+    let mut date = UtcDateTime::new(-1000, 1, 1, 12, 0, 0, 0).unwrap();
+    let mut weekday = date.weekday();
+    let days = if cfg!(miri) { 1_000 } else { 800_000 };
+    for _ in 0..days {
+        let next = date.checked_add_days(1).unwrap();
+        if date.day() < UtcDateTime::days_in_month(date.year(), date.month()) {
+            assert_eq!(
+                (next.year(), next.month(), next.day()),
+                (date.year(), date.month(), date.day() + 1)
+            );
+            assert_eq!(next.day_of_year(), date.day_of_year() + 1);
+        } else if date.month() < 12 {
+            assert_eq!((next.year(), next.month(), next.day()), (date.year(), date.month() + 1, 1));
+            assert_eq!(next.day_of_year(), date.day_of_year() + 1);
+        } else {
+            assert_eq!((next.year(), next.month(), next.day()), (date.year() + 1, 1, 1));
+            assert_eq!(next.day_of_year(), 1);
+        }
+        weekday = weekday % 7 + 1;
+        assert_eq!(next.weekday(), weekday);
+        date = next;
+    }
+
+    // 2000-01-01 was a Saturday.
+    assert_eq!(UtcDateTime::new(2000, 1, 1, 0, 0, 0, 0).unwrap().weekday(), 6);
+    assert_eq!(UtcDateTime::new(2000, 12, 31, 0, 0, 0, 0).unwrap().day_of_year(), 366);
+
+    let date = UtcDateTime::new(2023, 3, 31, 8, 0, 0, 0).unwrap();
+    assert_eq!(date.checked_add_months(-1).unwrap().to_string(), "2023-02-28T08:00:00Z");
+    assert_eq!(date.checked_add_months(11).unwrap().to_string(), "2024-02-29T08:00:00Z");
+    assert_eq!(date.checked_add_months(-27).unwrap().to_string(), "2020-12-31T08:00:00Z");
+
+    let max = UtcDateTime::new(i64::MAX, 12, 31, 23, 59, 59, 0).unwrap();
+    assert!(max.checked_add_days(1).is_none());
+    assert!(max.checked_add_months(1).is_none());
+    assert!(max.to_system_time().is_none());
+}
+
+#[test]
+fn utc_display() {
+    let date = UtcDateTime::new(2024, 2, 29, 1, 2, 3, 0).unwrap();
+    assert_eq!(date.to_string(), "2024-02-29T01:02:03Z");
+    assert_eq!(format!("{date:.3}"), "2024-02-29T01:02:03.000Z");
+    assert_eq!(format!("{date:>24}"), "    2024-02-29T01:02:03Z");
+    assert_eq!(format!("{date:*^25.0}"), "**2024-02-29T01:02:03Z***");
+    assert_eq!(format!("{date:10}"), "2024-02-29T01:02:03Z");
+
+    let date = UtcDateTime::new(2024, 2, 29, 1, 2, 3, 120_450_000).unwrap();
+    assert_eq!(date.to_string(), "2024-02-29T01:02:03.120450Z");
+    assert_eq!(format!("{date:.0}"), "2024-02-29T01:02:03Z");
+    assert_eq!(format!("{date:.4}"), "2024-02-29T01:02:03.1204Z");
+    assert_eq!(format!("{date:.12}"), "2024-02-29T01:02:03.120450000Z");
+
+    let date = UtcDateTime::new(0, 1, 1, 0, 0, 0, 1).unwrap();
+    assert_eq!(date.to_string(), "0000-01-01T00:00:00.000000001Z");
+    let date = UtcDateTime::new(-1, 1, 1, 0, 0, 0, 0).unwrap();
+    assert_eq!(date.to_string(), "-0001-01-01T00:00:00Z");
+    let date = UtcDateTime::new(10_000, 1, 1, 0, 0, 0, 0).unwrap();
+    assert_eq!(date.to_string(), "+10000-01-01T00:00:00Z");
+}
+
+#[test]
+fn utc_parse() {
+    #[track_caller]
+    fn check(s: &str, expected: &str) {
+        assert_eq!(s.parse::<UtcDateTime>().unwrap().to_string(), expected);
+    }
+
+    check("2024-02-29T01:02:03Z", "2024-02-29T01:02:03Z");
+    check("2024-02-29t01:02:03.5z", "2024-02-29T01:02:03.500Z");
+    check("2024-02-29 01:02:03.1234567899Z", "2024-02-29T01:02:03.123456789Z");
+    check("2024-03-01T01:02:03+02:30", "2024-02-29T22:32:03Z");
+    check("1999-12-31T23:30:00-00:45", "2000-01-01T00:15:00Z");
+    check("+0001-01-01T00:00:00Z", "0001-01-01T00:00:00Z");
+    check("-0001-01-01T00:00:00Z", "-0001-01-01T00:00:00Z");
+    check("+123456-01-01T00:00:00Z", "+123456-01-01T00:00:00Z");
+    check(&format!("{}-01-01T00:00:00Z", i64::MIN), &format!("{}-01-01T00:00:00Z", i64::MIN));
+
+    let errors = [
+        "",
+        "2024-02-29",
+        "2024-02-29T01:02:03",
+        "2024-02-29T01:02:03.Z",
+        "2024-02-29T01:02:03Z ",
+        "2024-2-29T01:02:03Z",
+        "2024-02-29_01:02:03Z",
+        "12024-02-29T01:02:03Z",
+        "2024-02-29T01:02:03+0100",
+        "2023-02-29T01:02:03Z",
+        "2024-02-29T24:00:00Z",
+        "2016-12-31T23:59:60Z",
+        "2024-02-29T01:02:03+24:00",
+        "+99999999999999999999-01-01T00:00:00Z",
+        "-9223372036854775809-01-01T00:00:00Z",
+    ];
+    for s in errors {
+        assert!(s.parse::<UtcDateTime>().is_err(), "{s:?}");
+    }
+    let max = format!("+{}-12-31T23:59:59-01:00", i64::MAX);
+    assert!(max.parse::<UtcDateTime>().is_err());
+}
+
 macro_rules! bench_instant_threaded {
     ($bench_name:ident, $thread_count:expr) => {
         #[bench]
@@ -2,6 +2,7 @@
 
 use std::env;
 use std::path::PathBuf;
+use std::time::Duration;
 
 use super::options::{ColorConfig, Options, OutputFormat, RunIgnored};
 use super::time::TestTimeOptions;
@@ -26,6 +27,13 @@ pub struct TestOpts {
     pub test_threads: Option<usize>,
     pub skip: Vec<String>,
     pub time_options: Option<TestTimeOptions>,
+    /// The time after which tests that don't specify their own timeout are killed.
+    pub test_timeout: Option<Duration>,
+    /// The index of the shard of tests to run, and the number of shards the tests are split
+    /// into.
+    pub shard: Option<(usize, usize)>,
+    /// The number of times failed tests are run again before being reported as failed.
+    pub retries: usize,
     /// Stop at first failing test.
     /// May run a few more tests due to threading, but will
     /// abort as soon as possible.
@@ -148,6 +156,33 @@ fn optgroups() -> getopts::Options {
             "shuffle-seed",
             "Run tests in random order; seed the random number generator with SEED",
             "SEED",
+        )
+        .optopt(
+            "",
+            "test-timeout",
+            "Kill tests that are still running after SECS seconds, and report them as failed.
+            Only tests that can be run in a subprocess are killed, which excludes the tests
+            run with --force-run-in-process.",
+            "SECS",
+        )
+        .optopt(
+            "",
+            "shard-index",
+            "Only run the tests of the shard INDEX, out of the ones given by --shard-count",
+            "INDEX",
+        )
+        .optopt(
+            "",
+            "shard-count",
+            "Split the tests into COUNT shards, and only run the one given by --shard-index",
+            "COUNT",
+        )
+        .optopt(
+            "",
+            "retries",
+            "Run failed tests again up to N times, and report the ones that eventually pass
+            as flaky. Tests that are not known at compile time are not run again.",
+            "N",
         );
     opts
 }
@@ -261,6 +296,9 @@ fn parse_opts_impl(matches: getopts::Matches) -> OptRes {
     let time_options = get_time_options(&matches, allow_unstable)?;
     let shuffle = get_shuffle(&matches, allow_unstable)?;
     let shuffle_seed = get_shuffle_seed(&matches, allow_unstable)?;
+    let test_timeout = get_test_timeout(&matches, allow_unstable)?;
+    let shard = get_shard(&matches, allow_unstable)?;
+    let retries = get_retries(&matches, allow_unstable)?;
 
     let include_ignored = matches.opt_present("include-ignored");
     let quiet = matches.opt_present("quiet");
@@ -299,6 +337,9 @@ fn parse_opts_impl(matches: getopts::Matches) -> OptRes {
         test_threads,
         skip,
         time_options,
+        test_timeout,
+        shard,
+        retries,
         options,
         fail_fast: false,
     };
@@ -375,6 +416,85 @@ fn get_shuffle_seed(matches: &getopts::Matches, allow_unstable: bool) -> OptPart
     Ok(shuffle_seed)
 }
 
+fn get_test_timeout(
+    matches: &getopts::Matches,
+    allow_unstable: bool,
+) -> OptPartRes<Option<Duration>> {
+    let test_timeout = match unstable_optopt!(matches, allow_unstable, "test-timeout") {
+        Some(secs_str) => match secs_str.parse::<u64>() {
+            Ok(0) => return Err("argument for --test-timeout must not be 0".to_string()),
+            Ok(secs) => Some(Duration::from_secs(secs)),
+            Err(e) => {
+                return Err(format!(
+                    "argument for --test-timeout must be a number of seconds \
+                     (error: {e})"
+                ));
+            }
+        },
+        None => None,
+    };
+
+    Ok(test_timeout)
+}
+
+fn get_shard(
+    matches: &getopts::Matches,
+    allow_unstable: bool,
+) -> OptPartRes<Option<(usize, usize)>> {
+    let index = unstable_optopt!(matches, allow_unstable, "shard-index");
+    let count = unstable_optopt!(matches, allow_unstable, "shard-count");
+    let (index, count) = match (index, count) {
+        (Some(index), Some(count)) => (index, count),
+        (None, None) => return Ok(None),
+        _ => return Err("--shard-index and --shard-count must be used together".to_string()),
+    };
+
+    let count = match count.parse::<usize>() {
+        Ok(0) => return Err("argument for --shard-count must not be 0".to_string()),
+        Ok(count) => count,
+        Err(e) => {
+            return Err(format!(
+                "argument for --shard-count must be a number > 0 \
+                 (error: {e})"
+            ));
+        }
+    };
+    let index = match index.parse::<usize>() {
+        Ok(index) if index < count => index,
+        Ok(index) => {
+            return Err(format!(
+                "argument for --shard-index must be less than the number of shards, \
+                 {count}, but it is {index}"
+            ));
+        }
+        Err(e) => {
+            return Err(format!(
+                "argument for --shard-index must be a number \
+                 (error: {e})"
+            ));
+        }
+    };
+
+    Ok(Some((index, count)))
+}
+
+fn get_retries(matches: &getopts::Matches, allow_unstable: bool) -> OptPartRes<usize> {
+    let retries = match unstable_optopt!(matches, allow_unstable, "retries") {
+        Some(n_str) => match n_str.parse::<usize>() {
+            Ok(n) => n,
+            Err(e) => {
+                return Err(format!(
+                    "argument for --retries must be a number \
+                     (error: {e})"
+                ));
+            }
+        },
+        None => 0,
+    };
+
+    Ok(retries)
+}
+
 fn get_test_threads(matches: &getopts::Matches) -> OptPartRes<Option<usize>> {
     let test_threads = match matches.opt_str("test-threads") {
         Some(n_str) => match n_str.parse::<usize>() {
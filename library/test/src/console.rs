@@ -95,6 +95,8 @@ pub struct ConsoleTestState {
     pub not_failures: Vec<(TestDesc, Vec<u8>)>,
     pub ignores: Vec<(TestDesc, Vec<u8>)>,
     pub time_failures: Vec<(TestDesc, Vec<u8>)>,
+    pub flaky: Vec<(TestDesc, Vec<u8>)>,
+    pub retries: usize,
     pub options: Options,
 }
 
@@ -119,6 +121,8 @@ pub fn new(opts: &TestOpts) -> io::Result<ConsoleTestState> {
             not_failures: Vec::new(),
             ignores: Vec::new(),
             time_failures: Vec::new(),
+            flaky: Vec::new(),
+            retries: opts.retries,
             options: opts.options,
         })
     }
@@ -161,6 +165,8 @@ pub fn write_log_result(
                     }
                     TestResult::TrBench(ref bs) => fmt_bench_samples(bs),
                     TestResult::TrTimedFail => "failed (time limit exceeded)".to_owned(),
+                    TestResult::TrTimedOut => "failed (timed out)".to_owned(),
+                    TestResult::TrFlaky(retries) => format!("ok (flaky, retries: {retries})"),
                 },
                 name,
             )
@@ -227,6 +233,10 @@ fn handle_test_result(st: &mut ConsoleTestState, completed_test: CompletedTest)
             st.passed += 1;
             st.not_failures.push((test, stdout));
         }
+        TestResult::TrFlaky(_) => {
+            st.passed += 1;
+            st.flaky.push((test, stdout));
+        }
         TestResult::TrIgnored => {
             st.ignored += 1;
             st.ignores.push((test, stdout));
@@ -239,7 +249,7 @@ fn handle_test_result(st: &mut ConsoleTestState, completed_test: CompletedTest)
             );
             st.measured += 1
         }
-        TestResult::TrFailed => {
+        TestResult::TrFailed | TestResult::TrTimedOut => {
             st.failed += 1;
             st.failures.push((test, stdout));
         }
@@ -23,6 +23,8 @@
 #![feature(process_exitcode_internals)]
 #![feature(panic_can_unwind)]
 #![feature(test)]
+#![feature(child_wait_timeout)]
+#![cfg_attr(unix, feature(unix_child_signal))]
 #![allow(internal_features)]
 
 // Public reexports
@@ -44,7 +46,7 @@ pub mod test {
         helpers::metrics::{Metric, MetricMap},
         options::{Options, RunIgnored, RunStrategy, ShouldPanic},
         run_test, test_main, test_main_static,
-        test_result::{TestResult, TrFailed, TrFailedMsg, TrIgnored, TrOk},
+        test_result::{TestResult, TrFailed, TrFailedMsg, TrFlaky, TrIgnored, TrOk},
         time::{TestExecTime, TestTimeOptions},
         types::{
             DynTestFn, DynTestName, StaticBenchFn, StaticTestFn, StaticTestName, TestDesc,
@@ -56,8 +58,8 @@ pub mod test {
 use std::{
     collections::VecDeque,
     env, io,
-    io::prelude::Write,
-    mem::ManuallyDrop,
+    io::prelude::{Read, Write},
+    mem::{self, ManuallyDrop},
     panic::{self, catch_unwind, AssertUnwindSafe, PanicInfo},
     process::{self, Command, Termination},
     sync::mpsc::{channel, Sender},
@@ -100,17 +102,25 @@ pub mod test {
 // The default console test runner. It accepts the command line
 // arguments and a vector of test_descs.
 pub fn test_main(args: &[String], tests: Vec<TestDescAndFn>, options: Option<Options>) {
-    let mut opts = match cli::parse_opts(args) {
-        Some(Ok(o)) => o,
+    let Some(mut opts) = parse_opts_or_exit(args) else { return };
+    if let Some(options) = options {
+        opts.options = options;
+    }
+    run_with_opts(opts, tests)
+}
+
+fn parse_opts_or_exit(args: &[String]) -> Option<TestOpts> {
+    match cli::parse_opts(args) {
+        Some(Ok(o)) => Some(o),
         Some(Err(msg)) => {
             eprintln!("error: {msg}");
             process::exit(ERROR_EXIT_CODE);
         }
-        None => return,
-    };
-    if let Some(options) = options {
-        opts.options = options;
+        None => None,
     }
+}
+
+fn run_with_opts(opts: TestOpts, tests: Vec<TestDescAndFn>) {
     if opts.list {
         if let Err(e) = console::list_tests_console(&opts, tests) {
             eprintln!("error: io error when listing tests: {e:?}");
@@ -157,9 +167,14 @@ pub fn test_main(args: &[String], tests: Vec<TestDescAndFn>, options: Option<Opt
 /// This is the entry point for the main function generated by `rustc --test`
 /// when panic=unwind.
 pub fn test_main_static(tests: &[&TestDescAndFn]) {
+    run_secondary_test_if_requested(tests);
+
     let args = env::args().collect::<Vec<_>>();
+    let Some(mut opts) = parse_opts_or_exit(&args) else { return };
+    // Keep the options given on the command line, e.g., `--show-output`.
+    opts.options.secondary_invocation = true;
     let owned_tests: Vec<_> = tests.iter().map(make_owned_test).collect();
-    test_main(&args, owned_tests, None)
+    run_with_opts(opts, owned_tests)
 }
 
 /// A variant optimized for invocation with a static test vector.
@@ -171,8 +186,17 @@ pub fn test_main_static(tests: &[&TestDescAndFn]) {
 /// This is the entry point for the main function generated by `rustc --test`
 /// when panic=abort.
 pub fn test_main_static_abort(tests: &[&TestDescAndFn]) {
-    // If we're being run in SpawnedSecondary mode, run the test here. run_test
-    // will then exit the process.
+    run_secondary_test_if_requested(tests);
+
+    let args = env::args().collect::<Vec<_>>();
+    let owned_tests: Vec<_> = tests.iter().map(make_owned_test).collect();
+    let options = Options::new().panic_abort(true).secondary_invocation(true);
+    test_main(&args, owned_tests, Some(options))
+}
+
+/// If we're being run in SpawnedSecondary mode, run the requested test here.
+/// run_test_in_spawned_subprocess will then exit the process.
+fn run_secondary_test_if_requested(tests: &[&TestDescAndFn]) {
     if let Ok(name) = env::var(SECONDARY_TEST_INVOKER_VAR) {
         env::remove_var(SECONDARY_TEST_INVOKER_VAR);
 
@@ -201,10 +225,6 @@ pub fn test_main_static_abort(tests: &[&TestDescAndFn]) {
             }
         }
     }
-
-    let args = env::args().collect::<Vec<_>>();
-    let owned_tests: Vec<_> = tests.iter().map(make_owned_test).collect();
-    test_main(&args, owned_tests, Some(Options::new().panic_abort(true)))
 }
 
 /// Clones static values for putting into a dynamic vector, which test_main()
@@ -219,6 +239,16 @@ fn make_owned_test(test: &&TestDescAndFn) -> TestDescAndFn {
     }
 }
 
+/// Clones a test to run it again, which is only possible for static tests.
+fn clone_static_test(test: &TestDescAndFn) -> Option<TestDescAndFn> {
+    let testfn = match test.testfn {
+        StaticTestFn(f) => StaticTestFn(f),
+        StaticBenchAsTestFn(f) => StaticBenchAsTestFn(f),
+        _ => return None,
+    };
+    Some(TestDescAndFn { desc: test.desc.clone(), testfn })
+}
+
 /// Invoked when unit tests terminate. Returns `Result::Err` if the test is
 /// considered a failure. By default, invokes `report()` and checks for a `0`
 /// result.
@@ -294,6 +324,59 @@ struct TimeoutEntry {
         timeout: Instant,
     }
 
+    type RetryMap<T> = HashMap<TestId, T, BuildHasherDefault<DefaultHasher>>;
+
+    // Keeps track of the tests that are run again after failing.
+    struct Retries {
+        max_retries: usize,
+        /// Copies of the running tests that can be run again.
+        copies: RetryMap<TestDescAndFn>,
+        /// The number of failed attempts of the tests that are being run again, and the output
+        /// of their last failed attempt.
+        failed_attempts: RetryMap<(usize, Vec<u8>)>,
+    }
+
+    impl Retries {
+        fn is_retry(&self, id: TestId) -> bool {
+            self.failed_attempts.contains_key(&id)
+        }
+
+        fn start(&mut self, id: TestId, test: &TestDescAndFn) {
+            if self.max_retries > 0 {
+                if let Some(copy) = clone_static_test(test) {
+                    self.copies.insert(id, copy);
+                }
+            }
+        }
+
+        /// Returns the test to run again if it failed, and has retries left. Otherwise, updates
+        /// the result of the tests that passed after failing.
+        fn complete(&mut self, completed_test: &mut CompletedTest) -> Option<TestDescAndFn> {
+            let id = completed_test.id;
+            let copy = self.copies.remove(&id);
+            let (attempts, failed_output) = self.failed_attempts.remove(&id).unwrap_or_default();
+            match completed_test.result {
+                TrFailed | TrFailedMsg(_) | TrTimedFail | TrTimedOut
+                    if attempts < self.max_retries =>
+                {
+                    let copy = copy?;
+                    let mut stdout = mem::take(&mut completed_test.stdout);
+                    if let TrFailedMsg(msg) = &completed_test.result {
+                        stdout.extend_from_slice(format!("note: {msg}").as_bytes());
+                    }
+                    self.failed_attempts.insert(id, (attempts + 1, stdout));
+                    Some(copy)
+                }
+                TrOk if attempts > 0 => {
+                    completed_test.result = TrFlaky(attempts);
+                    completed_test.stdout = failed_output;
+                    None
+                }
+                _ => None,
+            }
+        }
+    }
+
     let tests_len = tests.len();
 
     let mut filtered = FilteredTests { tests: Vec::new(), benches: Vec::new(), next_id: 0 };
@@ -345,6 +428,11 @@ struct TimeoutEntry {
     };
 
     let mut running_tests: TestMap = HashMap::default();
+    let mut retries = Retries {
+        max_retries: opts.retries,
+        copies: HashMap::default(),
+        failed_attempts: HashMap::default(),
+    };
     let mut timeout_queue: VecDeque<TimeoutEntry> = VecDeque::new();
 
     fn get_timed_out_tests(
@@ -375,16 +463,24 @@ fn calc_timeout(timeout_queue: &VecDeque<TimeoutEntry>) -> Option<Duration> {
     if concurrency == 1 {
         while !remaining.is_empty() {
             let (id, test) = remaining.pop_front().unwrap();
-            let event = TestEvent::TeWait(test.desc.clone());
-            notify_about_test_event(event)?;
+            if !retries.is_retry(id) {
+                let event = TestEvent::TeWait(test.desc.clone());
+                notify_about_test_event(event)?;
+            }
+            retries.start(id, &test);
             let join_handle = run_test(opts, !opts.run_tests, id, test, run_strategy, tx.clone());
             // Wait for the test to complete.
             let mut completed_test = rx.recv().unwrap();
             RunningTest { join_handle }.join(&mut completed_test);
 
+            if let Some(test) = retries.complete(&mut completed_test) {
+                remaining.push_front((id, test));
+                continue;
+            }
+
             let fail_fast = match completed_test.result {
-                TrIgnored | TrOk | TrBench(_) => false,
-                TrFailed | TrFailedMsg(_) | TrTimedFail => opts.fail_fast,
+                TrIgnored | TrOk | TrBench(_) | TrFlaky(_) => false,
+                TrFailed | TrFailedMsg(_) | TrTimedFail | TrTimedOut => opts.fail_fast,
             };
 
             let event = TestEvent::TeResult(completed_test);
@@ -401,8 +497,11 @@ fn calc_timeout(timeout_queue: &VecDeque<TimeoutEntry>) -> Option<Duration> {
                 let timeout = time::get_default_test_timeout();
                 let desc = test.desc.clone();
 
-                let event = TestEvent::TeWait(desc.clone());
-                notify_about_test_event(event)?; //here no pad
+                if !retries.is_retry(id) {
+                    let event = TestEvent::TeWait(desc.clone());
+                    notify_about_test_event(event)?; //here no pad
+                }
+                retries.start(id, &test);
                 let join_handle =
                     run_test(opts, !opts.run_tests, id, test, run_strategy, tx.clone());
                 running_tests.insert(id, RunningTest { join_handle });
@@ -437,15 +536,20 @@ fn calc_timeout(timeout_queue: &VecDeque<TimeoutEntry>) -> Option<Duration> {
             let mut completed_test = res.unwrap();
             let running_test = running_tests.remove(&completed_test.id).unwrap();
             running_test.join(&mut completed_test);
+            pending -= 1;
+
+            if let Some(test) = retries.complete(&mut completed_test) {
+                remaining.push_front((completed_test.id, test));
+                continue;
+            }
 
             let fail_fast = match completed_test.result {
-                TrIgnored | TrOk | TrBench(_) => false,
-                TrFailed | TrFailedMsg(_) | TrTimedFail => opts.fail_fast,
+                TrIgnored | TrOk | TrBench(_) | TrFlaky(_) => false,
+                TrFailed | TrFailedMsg(_) | TrTimedFail | TrTimedOut => opts.fail_fast,
             };
 
             let event = TestEvent::TeResult(completed_test);
             notify_about_test_event(event)?;
-            pending -= 1;
 
             if fail_fast {
                 // Prevent remaining test threads from panicking
@@ -510,6 +614,16 @@ pub fn filter_tests(opts: &TestOpts, tests: Vec<TestDescAndFn>) -> Vec<TestDescA
         RunIgnored::No => {}
     }
 
+    // Only keep the tests of the requested shard
+    if let Some((index, count)) = opts.shard {
+        filtered = filtered
+            .into_iter()
+            .enumerate()
+            .filter(|(i, _)| i % count == index)
+            .map(|(_, test)| test)
+            .collect();
+    }
+
     filtered
 }
 
@@ -558,6 +672,24 @@ pub fn run_test(
                 };
             }
 
+            // Tests with a timeout are run in a subprocess, so they can be killed. This requires
+            // the test binary to support running a single test in a subprocess, and rules out
+            // dynamic tests.
+            let timeout = desc.timeout.or(opts.test_timeout);
+            let supports_processes = !cfg!(target_family = "wasm");
+            let strategy = match strategy {
+                RunStrategy::InProcess
+                    if timeout.is_some()
+                        && supports_processes
+                        && opts.options.secondary_invocation
+                        && !opts.force_run_in_process
+                        && !runnable_test.is_dynamic() =>
+                {
+                    RunStrategy::SpawnPrimary
+                }
+                strategy => strategy,
+            };
+
             let name = desc.name.clone();
             let nocapture = opts.nocapture;
             let time_options = opts.time_options;
@@ -581,6 +713,7 @@ pub fn run_test(
                     monitor_ch,
                     time_options,
                     bench_benchmarks,
+                    timeout,
                 ),
             };
 
@@ -679,6 +812,7 @@ fn spawn_test_subprocess(
     monitor_ch: Sender<CompletedTest>,
     time_opts: Option<time::TestTimeOptions>,
     bench_benchmarks: bool,
+    timeout: Option<Duration>,
 ) {
     let (result, test_output, exec_time) = (|| {
         let args = env::args().collect::<Vec<_>>();
@@ -692,10 +826,17 @@ fn spawn_test_subprocess(
         if nocapture {
             command.stdout(process::Stdio::inherit());
             command.stderr(process::Stdio::inherit());
+        } else {
+            command.stdout(process::Stdio::piped());
+            command.stderr(process::Stdio::piped());
         }
 
         let start = report_time.then(Instant::now);
-        let output = match command.output() {
+        let output = match timeout {
+            Some(timeout) => output_with_timeout(&mut command, timeout),
+            None => command.output().map(|out| (Some(out.status), out.stdout, out.stderr)),
+        };
+        let (status, stdout, stderr) = match output {
             Ok(out) => out,
             Err(e) => {
                 let err = format!("Failed to spawn {} as child for test: {:?}", args[0], e);
@@ -707,11 +848,23 @@ fn spawn_test_subprocess(
             TestExecTime(duration)
         });
 
-        let std::process::Output { stdout, stderr, status } = output;
         let mut test_output = stdout;
         formatters::write_stderr_delimiter(&mut test_output, &desc.name);
         test_output.extend_from_slice(&stderr);
 
+        let Some(status) = status else {
+            if !test_output.ends_with(b"\n") {
+                test_output.push(b'\n');
+            }
+            let timeout = timeout.unwrap();
+            write!(
+                &mut test_output,
+                "note: test did not finish within {timeout:?}, and was killed"
+            )
+            .unwrap();
+            return (TrTimedOut, test_output, exec_time);
+        };
+
         let result = match (|| -> Result<TestResult, String> {
             let exit_code = get_exit_code(status)?;
             Ok(get_result_from_exit_code(&desc, exit_code, &time_opts, &exec_time))
@@ -730,6 +883,87 @@ fn spawn_test_subprocess(
     monitor_ch.send(message).unwrap();
 }
 
+/// Like `Command::output`, but kills the process if it doesn't exit within `timeout`, in which case
+/// the returned status is `None`, and the output is the one written until then.
+///
+/// Where possible, the process is spawned in a process group of its own, and the whole group is
+/// killed, so that the subprocesses of a hung test don't keep its output pipes open. Otherwise,
+/// only the process itself is killed, and the output is not read any further after that.
+fn output_with_timeout(
+    command: &mut Command,
+    timeout: Duration,
+) -> io::Result<(Option<process::ExitStatus>, Vec<u8>, Vec<u8>)> {
+    struct Reader {
+        output: Arc<Mutex<Vec<u8>>>,
+        thread: Option<thread::JoinHandle<()>>,
+    }
+
+    impl Reader {
+        fn new<R: Read + Send + 'static>(pipe: Option<R>) -> Reader {
+            let output = Arc::new(Mutex::new(Vec::new()));
+            let thread = pipe.map(|mut pipe| {
+                let output = output.clone();
+                thread::spawn(move || {
+                    let mut buf = [0; 4096];
+                    loop {
+                        match pipe.read(&mut buf) {
+                            Ok(0) => break,
+                            Ok(n) => output.lock().unwrap().extend_from_slice(&buf[..n]),
+                            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                            Err(_) => break,
+                        }
+                    }
+                })
+            });
+            Reader { output, thread }
+        }
+
+        /// Returns the output read so far, after reading to the end of the pipe if `wait`.
+        fn finish(self, wait: bool) -> Vec<u8> {
+            if let (true, Some(thread)) = (wait, self.thread) {
+                let _ = thread.join();
+            }
+            mem::take(&mut *self.output.lock().unwrap_or_else(|e| e.into_inner()))
+        }
+    }
+
+    #[cfg(unix)]
+    std::os::unix::process::CommandExt::process_group(command, 0);
+    let mut child = command.stdin(process::Stdio::null()).spawn()?;
+    let stdout = Reader::new(child.stdout.take());
+    let stderr = Reader::new(child.stderr.take());
+
+    let status = child.wait_timeout(timeout)?;
+    // Whether the output pipes are closed once the process has been waited on.
+    let mut closed = true;
+    if status.is_none() {
+        closed = kill_process_group(&child);
+        if !closed {
+            // The process may have exited in the meantime, in which case killing it fails.
+            let _ = child.kill();
+        }
+        child.wait()?;
+    }
+
+    Ok((status, stdout.finish(closed), stderr.finish(closed)))
+}
+
+/// Kills all the processes in the process group led by `child`, and returns whether that
+/// succeeded.
+fn kill_process_group(child: &process::Child) -> bool {
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ChildExt;
+        // SIGKILL
+        child.signal_group(9).is_ok()
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = child;
+        false
+    }
+}
+
 fn run_test_in_spawned_subprocess(desc: TestDesc, runnable_test: RunnableTest) -> ! {
     let builtin_panic_hook = panic::take_hook();
     let record_result = Arc::new(move |panic_info: Option<&'_ PanicInfo<'_>>| {
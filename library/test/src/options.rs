@@ -65,11 +65,14 @@ pub enum RunStrategy {
 pub struct Options {
     pub display_output: bool,
     pub panic_abort: bool,
+    /// Whether the test binary runs a single test when the runner invokes it as a subprocess,
+    /// which is the case for the ones generated by `rustc --test`.
+    pub secondary_invocation: bool,
 }
 
 impl Options {
     pub fn new() -> Options {
-        Options { display_output: false, panic_abort: false }
+        Options { display_output: false, panic_abort: false, secondary_invocation: false }
     }
 
     pub fn display_output(mut self, display_output: bool) -> Options {
@@ -81,4 +84,9 @@ pub fn panic_abort(mut self, panic_abort: bool) -> Options {
         self.panic_abort = panic_abort;
         self
     }
+
+    pub fn secondary_invocation(mut self, secondary_invocation: bool) -> Options {
+        self.secondary_invocation = secondary_invocation;
+        self
+    }
 }
@@ -21,6 +21,10 @@ pub enum TestResult {
     TrIgnored,
     TrBench(BenchSamples),
     TrTimedFail,
+    /// The test didn't finish within its timeout, and was killed.
+    TrTimedOut,
+    /// The test failed the given number of times, and then passed when it was run again.
+    TrFlaky(usize),
 }
 
 /// Creates a `TestResult` depending on the raw result of test execution
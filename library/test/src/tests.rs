@@ -33,6 +33,9 @@ fn new() -> TestOpts {
             test_threads: None,
             skip: vec![],
             time_options: None,
+            test_timeout: None,
+            shard: None,
+            retries: 0,
             options: Options::new(),
             fail_fast: false,
         }
@@ -55,6 +58,7 @@ fn one_ignored_one_unignored_test() -> Vec<TestDescAndFn> {
                 compile_fail: false,
                 no_run: false,
                 test_type: TestType::Unknown,
+                timeout: None,
             },
             testfn: DynTestFn(Box::new(move || Ok(()))),
         },
@@ -72,6 +76,7 @@ fn one_ignored_one_unignored_test() -> Vec<TestDescAndFn> {
                 compile_fail: false,
                 no_run: false,
                 test_type: TestType::Unknown,
+                timeout: None,
             },
             testfn: DynTestFn(Box::new(move || Ok(()))),
         },
@@ -97,6 +102,7 @@ fn f() -> Result<(), String> {
             compile_fail: false,
             no_run: false,
             test_type: TestType::Unknown,
+            timeout: None,
         },
         testfn: DynTestFn(Box::new(f)),
     };
@@ -106,6 +112,71 @@ fn f() -> Result<(), String> {
     assert_ne!(result, TrOk);
 }
 
+#[test]
+fn dynamic_tests_with_timeout_run_in_process() {
+    fn f() -> Result<(), String> {
+        Ok(())
+    }
+    let desc = TestDescAndFn {
+        desc: TestDesc {
+            name: StaticTestName("whatever"),
+            ignore: false,
+            ignore_message: None,
+            source_file: "",
+            start_line: 0,
+            start_col: 0,
+            end_line: 0,
+            end_col: 0,
+            should_panic: ShouldPanic::No,
+            compile_fail: false,
+            no_run: false,
+            test_type: TestType::Unknown,
+            timeout: Some(Duration::from_secs(60)),
+        },
+        testfn: DynTestFn(Box::new(f)),
+    };
+    let test_opts =
+        TestOpts { options: Options::new().secondary_invocation(true), ..TestOpts::new() };
+    let (tx, rx) = channel();
+    run_test(&test_opts, false, TestId(0), desc, RunStrategy::InProcess, tx);
+    let result = rx.recv().unwrap().result;
+    assert_eq!(result, TrOk);
+}
+
+#[test]
+#[cfg(unix)]
+fn output_with_timeout_kills_the_process() {
+    let mut command = Command::new("sh");
+    command.args(["-c", "echo before; exec sleep 60"]);
+    command.stdout(process::Stdio::piped()).stderr(process::Stdio::piped());
+    let (status, stdout, _) =
+        output_with_timeout(&mut command, Duration::from_millis(500)).unwrap();
+    assert_eq!(status, None);
+    assert_eq!(stdout, b"before\n");
+
+    let mut command = Command::new("sh");
+    command.args(["-c", "echo done"]);
+    command.stdout(process::Stdio::piped()).stderr(process::Stdio::piped());
+    let (status, stdout, _) = output_with_timeout(&mut command, Duration::from_secs(60)).unwrap();
+    assert!(status.unwrap().success());
+    assert_eq!(stdout, b"done\n");
+}
+
+#[test]
+#[cfg(unix)]
+fn output_with_timeout_kills_the_subprocesses() {
+    // The `sleep` subprocess inherits the output pipes, which would stay open if it outlived `sh`.
+    let mut command = Command::new("sh");
+    command.args(["-c", "echo before; sleep 60 & wait"]);
+    command.stdout(process::Stdio::piped()).stderr(process::Stdio::piped());
+    let start = Instant::now();
+    let (status, stdout, _) =
+        output_with_timeout(&mut command, Duration::from_millis(500)).unwrap();
+    assert!(start.elapsed() < Duration::from_secs(30));
+    assert_eq!(status, None);
+    assert_eq!(stdout, b"before\n");
+}
+
 #[test]
 pub fn ignored_tests_result_in_ignored() {
     fn f() -> Result<(), String> {
@@ -125,6 +196,7 @@ fn f() -> Result<(), String> {
             compile_fail: false,
             no_run: false,
             test_type: TestType::Unknown,
+            timeout: None,
         },
         testfn: DynTestFn(Box::new(f)),
     };
@@ -156,6 +228,7 @@ fn f() -> Result<(), String> {
             compile_fail: false,
             no_run: false,
             test_type: TestType::Unknown,
+            timeout: None,
         },
         testfn: DynTestFn(Box::new(f)),
     };
@@ -187,6 +260,7 @@ fn f() -> Result<(), String> {
             compile_fail: false,
             no_run: false,
             test_type: TestType::Unknown,
+            timeout: None,
         },
         testfn: DynTestFn(Box::new(f)),
     };
@@ -223,6 +297,7 @@ fn f() -> Result<(), String> {
             compile_fail: false,
             no_run: false,
             test_type: TestType::Unknown,
+            timeout: None,
         },
         testfn: DynTestFn(Box::new(f)),
     };
@@ -263,6 +338,7 @@ fn f() -> Result<(), String> {
             compile_fail: false,
             no_run: false,
             test_type: TestType::Unknown,
+            timeout: None,
         },
         testfn: DynTestFn(Box::new(f)),
     };
@@ -297,6 +373,7 @@ fn f() -> Result<(), String> {
                 compile_fail: false,
                 no_run: false,
                 test_type: TestType::Unknown,
+                timeout: None,
             },
             testfn: DynTestFn(Box::new(f)),
         };
@@ -330,6 +407,7 @@ fn f() -> Result<(), String> {
             compile_fail: false,
             no_run: false,
             test_type: TestType::Unknown,
+            timeout: None,
         },
         testfn: DynTestFn(Box::new(f)),
     };
@@ -372,6 +450,7 @@ fn f() -> Result<(), String> {
             compile_fail: false,
             no_run: false,
             test_type,
+            timeout: None,
         },
         testfn: DynTestFn(Box::new(f)),
     };
@@ -416,6 +495,7 @@ fn typed_test_desc(test_type: TestType) -> TestDesc {
         compile_fail: false,
         no_run: false,
         test_type,
+        timeout: None,
     }
 }
 
@@ -478,6 +558,46 @@ fn parse_include_ignored_flag() {
     assert_eq!(opts.run_ignored, RunIgnored::Yes);
 }
 
+#[test]
+fn parse_test_timeout_option() {
+    let args = vec![
+        "progname".to_string(),
+        "-Zunstable-options".to_string(),
+        "--test-timeout=5".to_string(),
+    ];
+    let opts = parse_opts(&args).unwrap().unwrap();
+    assert_eq!(opts.test_timeout, Some(Duration::from_secs(5)));
+
+    let args = vec![
+        "progname".to_string(),
+        "-Zunstable-options".to_string(),
+        "--test-timeout=0".to_string(),
+    ];
+    assert!(parse_opts(&args).unwrap().is_err());
+
+    // The option is unstable.
+    let args = vec!["progname".to_string(), "--test-timeout=5".to_string()];
+    assert!(parse_opts(&args).unwrap().is_err());
+}
+
+#[test]
+fn parse_shard_and_retries_options() {
+    let args = |extra: &[&str]| {
+        let mut args = vec!["progname".to_string(), "-Zunstable-options".to_string()];
+        args.extend(extra.iter().map(|arg| arg.to_string()));
+        args
+    };
+    let opts = parse_opts(&args(&["--shard-index=1", "--shard-count=3", "--retries=2"]));
+    let opts = opts.unwrap().unwrap();
+    assert_eq!(opts.shard, Some((1, 3)));
+    assert_eq!(opts.retries, 2);
+
+    assert!(parse_opts(&args(&["--shard-index=1"])).unwrap().is_err());
+    assert!(parse_opts(&args(&["--shard-index=3", "--shard-count=3"])).unwrap().is_err());
+    assert!(parse_opts(&args(&["--shard-index=0", "--shard-count=0"])).unwrap().is_err());
+    assert!(parse_opts(&args(&["--retries=many"])).unwrap().is_err());
+}
+
 #[test]
 pub fn filter_for_ignored_option() {
     // When we run ignored tests the test filter should filter out all the
@@ -533,6 +653,7 @@ pub fn exclude_should_panic_option() {
             compile_fail: false,
             no_run: false,
             test_type: TestType::Unknown,
+            timeout: None,
         },
         testfn: DynTestFn(Box::new(move || Ok(()))),
     });
@@ -562,6 +683,7 @@ fn tests() -> Vec<TestDescAndFn> {
                     compile_fail: false,
                     no_run: false,
                     test_type: TestType::Unknown,
+                    timeout: None,
                 },
                 testfn: DynTestFn(Box::new(move || Ok(()))),
             })
@@ -658,6 +780,7 @@ fn testfn() -> Result<(), String> {
                 compile_fail: false,
                 no_run: false,
                 test_type: TestType::Unknown,
+                timeout: None,
             },
             testfn: DynTestFn(Box::new(testfn)),
         };
@@ -666,6 +789,24 @@ fn testfn() -> Result<(), String> {
     tests
 }
 
+#[test]
+pub fn filter_tests_by_shard() {
+    let count = 3;
+    let mut names = Vec::new();
+    for index in 0..count {
+        let opts = TestOpts { shard: Some((index, count)), ..TestOpts::new() };
+        let shard = filter_tests(&opts, sample_tests());
+        assert!(shard.len() >= sample_tests().len() / count);
+        names.extend(shard.into_iter().map(|test| test.desc.name.to_string()));
+    }
+    // Every test is in exactly one shard.
+    let mut expected: Vec<_> =
+        sample_tests().into_iter().map(|test| test.desc.name.to_string()).collect();
+    names.sort();
+    expected.sort();
+    assert_eq!(names, expected);
+}
+
 #[test]
 pub fn shuffle_tests() {
     let mut opts = TestOpts::new();
@@ -792,6 +933,7 @@ fn f(_: &mut Bencher) -> Result<(), String> {
         compile_fail: false,
         no_run: false,
         test_type: TestType::Unknown,
+        timeout: None,
     };
 
     crate::bench::benchmark(TestId(0), desc, tx, true, f);
@@ -820,6 +962,7 @@ fn f(b: &mut Bencher) -> Result<(), String> {
         compile_fail: false,
         no_run: false,
         test_type: TestType::Unknown,
+        timeout: None,
     };
 
     crate::bench::benchmark(TestId(0), desc, tx, true, f);
@@ -841,6 +984,7 @@ fn should_sort_failures_before_printing_them() {
         compile_fail: false,
         no_run: false,
         test_type: TestType::Unknown,
+        timeout: None,
     };
 
     let test_b = TestDesc {
@@ -856,6 +1000,7 @@ fn should_sort_failures_before_printing_them() {
         compile_fail: false,
         no_run: false,
         test_type: TestType::Unknown,
+        timeout: None,
     };
 
     let mut out = PrettyFormatter::new(OutputLocation::Raw(Vec::new()), false, 10, false, None);
@@ -875,6 +1020,8 @@ fn should_sort_failures_before_printing_them() {
         not_failures: Vec::new(),
         ignores: Vec::new(),
         time_failures: Vec::new(),
+        flaky: Vec::new(),
+        retries: 0,
     };
 
     out.write_failures(&st).unwrap();
@@ -888,6 +1035,71 @@ fn should_sort_failures_before_printing_them() {
     assert!(apos < bpos);
 }
 
+#[test]
+fn failed_tests_are_retried() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static FLAKY_RUNS: AtomicUsize = AtomicUsize::new(0);
+    static FAILING_RUNS: AtomicUsize = AtomicUsize::new(0);
+
+    fn flaky() -> Result<(), String> {
+        match FLAKY_RUNS.fetch_add(1, Ordering::SeqCst) {
+            0 => Err("first run".to_string()),
+            _ => Ok(()),
+        }
+    }
+    fn failing() -> Result<(), String> {
+        FAILING_RUNS.fetch_add(1, Ordering::SeqCst);
+        Err("always fails".to_string())
+    }
+    fn dynamic() -> Result<(), String> {
+        Err("always fails".to_string())
+    }
+
+    let desc = |name| TestDesc {
+        name: StaticTestName(name),
+        ignore: false,
+        ignore_message: None,
+        source_file: "",
+        start_line: 0,
+        start_col: 0,
+        end_line: 0,
+        end_col: 0,
+        should_panic: ShouldPanic::No,
+        compile_fail: false,
+        no_run: false,
+        test_type: TestType::Unknown,
+        timeout: None,
+    };
+    let tests = vec![
+        TestDescAndFn { desc: desc("flaky"), testfn: StaticTestFn(flaky) },
+        TestDescAndFn { desc: desc("failing"), testfn: StaticTestFn(failing) },
+        TestDescAndFn { desc: desc("dynamic"), testfn: DynTestFn(Box::new(dynamic)) },
+    ];
+
+    let mut results = Vec::new();
+    let notify = |event: TestEvent| {
+        if let TestEvent::TeResult(completed_test) = event {
+            results.push((completed_test.desc.name.to_string(), completed_test.result));
+        }
+        Ok(())
+    };
+    let opts = TestOpts { run_tests: true, retries: 2, test_threads: Some(1), ..TestOpts::new() };
+    run_tests(&opts, tests, notify).unwrap();
+
+    results.sort_by(|a, b| a.0.cmp(&b.0));
+    assert_eq!(
+        results,
+        [
+            ("dynamic".to_string(), TrFailed),
+            ("failing".to_string(), TrFailed),
+            ("flaky".to_string(), TrFlaky(1)),
+        ]
+    );
+    assert_eq!(FLAKY_RUNS.load(Ordering::SeqCst), 2);
+    assert_eq!(FAILING_RUNS.load(Ordering::SeqCst), 3);
+}
+
 #[test]
 #[cfg(not(target_os = "emscripten"))]
 fn test_dyn_bench_returning_err_fails_when_run_as_test() {
@@ -908,6 +1120,7 @@ fn f(_: &mut Bencher) -> Result<(), String> {
             compile_fail: false,
             no_run: false,
             test_type: TestType::Unknown,
+            timeout: None,
         },
         testfn: DynBenchFn(Box::new(f)),
     };
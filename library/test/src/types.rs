@@ -3,6 +3,7 @@
 use std::borrow::Cow;
 use std::fmt;
 use std::sync::mpsc::Sender;
+use std::time::Duration;
 
 use super::__rust_begin_short_backtrace;
 use super::bench::Bencher;
@@ -208,6 +209,9 @@ pub struct TestDesc {
     pub compile_fail: bool,
     pub no_run: bool,
     pub test_type: TestType,
+    /// The time after which the test is killed and reported as failed, which overrides the one
+    /// given with `--test-timeout`.
+    pub timeout: Option<Duration>,
 }
 
 impl TestDesc {
@@ -1060,6 +1060,7 @@ fn add_test(&mut self, test: String, config: LangString, line: usize) {
                 compile_fail: config.compile_fail,
                 no_run,
                 test_type: test::TestType::DocTest,
+                timeout: None,
             },
             testfn: test::DynTestFn(Box::new(move || {
                 let report_unused_externs = |uext| {
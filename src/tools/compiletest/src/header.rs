@@ -1012,6 +1012,8 @@ macro_rules! decision {
         compile_fail: false,
         no_run: false,
         test_type: test::TestType::Unknown,
+        #[cfg(not(bootstrap))]
+        timeout: None,
     }
 }
 
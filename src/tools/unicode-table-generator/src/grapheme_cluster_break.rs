@@ -0,0 +1,92 @@
+use crate::{encode_runs, fmt_list, UnicodeData};
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Write},
+    ops::RangeInclusive,
+};
+
+/// The values of the Grapheme_Cluster_Break property, next to the names of
+/// the variants that represent them.
+static VALUES: &[(&str, &str)] = &[
+    ("Other", "Other"),
+    ("CR", "Cr"),
+    ("LF", "Lf"),
+    ("Control", "Control"),
+    ("Extend", "Extend"),
+    ("ZWJ", "Zwj"),
+    ("Regional_Indicator", "RegionalIndicator"),
+    ("Prepend", "Prepend"),
+    ("SpacingMark", "SpacingMark"),
+    ("L", "L"),
+    ("V", "V"),
+    ("T", "T"),
+    ("LV", "Lv"),
+    ("LVT", "Lvt"),
+];
+
+/// The Hangul syllables alternate between LV and LVT, so they are looked up
+/// algorithmically instead of being stored in the table.
+const HANGUL_SYLLABLES: RangeInclusive<u32> = 0xAC00..=0xD7A3;
+
+pub(crate) fn generate_grapheme_cluster_break(data: &UnicodeData) -> String {
+    let mut values = BTreeMap::new();
+    for (&codepoint, value) in &data.grapheme_cluster_break {
+        if HANGUL_SYLLABLES.contains(&codepoint) {
+            let expected =
+                if (codepoint - HANGUL_SYLLABLES.start()) % 28 == 0 { "LV" } else { "LVT" };
+            assert_eq!(value, expected, "unexpected value of U+{codepoint:04X}");
+            continue;
+        }
+        let index = VALUES
+            .iter()
+            .position(|(name, _)| name == value)
+            .unwrap_or_else(|| panic!("unknown Grapheme_Cluster_Break value {value:?}"));
+        values.insert(codepoint, u8::try_from(index).unwrap());
+    }
+    let runs = encode_runs(&values);
+
+    let mut file = String::new();
+    file.push_str("#[derive(Clone, Copy, Debug, PartialEq, Eq)]\n");
+    file.push_str("pub enum GraphemeClusterBreak {\n");
+    for (_, variant) in VALUES {
+        writeln!(file, "    {variant},").unwrap();
+    }
+    file.push_str("}\n\n");
+    file.push_str(HEADER.trim_start());
+    file.push('\n');
+    write!(
+        file,
+        "static VALUES: [GraphemeClusterBreak; {}] = [{}];",
+        VALUES.len(),
+        fmt_list(VALUES.iter().map(|&(_, variant)| Variant(variant)))
+    )
+    .unwrap();
+    file.push_str("\n\n");
+    write!(
+        file,
+        "static GRAPHEME_CLUSTER_BREAK_RUNS: [u32; {}] = [{}];",
+        runs.len(),
+        fmt_list(runs)
+    )
+    .unwrap();
+    file
+}
+
+struct Variant(&'static str);
+
+impl fmt::Debug for Variant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+static HEADER: &str = r"
+use GraphemeClusterBreak::*;
+
+pub fn lookup(c: char) -> GraphemeClusterBreak {
+    match c as u32 {
+        c @ 0xAC00..=0xD7A3 => if (c - 0xAC00) % 28 == 0 { Lv } else { Lvt },
+        c => VALUES[super::run_search(c, &GRAPHEME_CLUSTER_BREAK_RUNS) as usize],
+    }
+}
+";
@@ -71,12 +71,14 @@
 //! index of that offset is utilized as the answer to whether we're in the set
 //! or not.
 
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::ops::Range;
 use ucd_parse::Codepoints;
 
 mod cascading_map;
 mod case_mapping;
+mod grapheme_cluster_break;
+mod normalization;
 mod raw_emitter;
 mod skiplist;
 mod unicode_download;
@@ -90,6 +92,7 @@
     "Cased",
     "Case_Ignorable",
     "Grapheme_Extend",
+    "Extended_Pictographic",
     "White_Space",
     "Cc",
     "N",
@@ -99,6 +102,18 @@ struct UnicodeData {
     ranges: Vec<(&'static str, Vec<Range<u32>>)>,
     to_upper: BTreeMap<u32, (u32, u32, u32)>,
     to_lower: BTreeMap<u32, (u32, u32, u32)>,
+    grapheme_cluster_break: BTreeMap<u32, String>,
+    combining_classes: BTreeMap<u32, u8>,
+    canonical_decompositions: BTreeMap<u32, Vec<u32>>,
+    compatibility_decompositions: BTreeMap<u32, Vec<u32>>,
+    composition_exclusions: BTreeSet<u32>,
+}
+
+fn codepoint_range(codepoints: Codepoints) -> Range<u32> {
+    match codepoints {
+        Codepoints::Single(c) => c.value()..c.value() + 1,
+        Codepoints::Range(c) => c.start.value()..c.end.value() + 1,
+    }
 }
 
 fn to_mapping(origin: u32, codepoints: Vec<ucd_parse::Codepoint>) -> Option<(u32, u32, u32)> {
@@ -141,12 +156,57 @@ fn load_data() -> UnicodeData {
             properties.entry(*name).or_insert_with(Vec::new).push(row.codepoints);
         }
     }
+    for row in ucd_parse::parse::<_, ucd_parse::EmojiProperty>(&UNICODE_DIRECTORY).unwrap() {
+        if let Some(name) = PROPERTIES.iter().find(|prop| **prop == row.property.as_str()) {
+            properties.entry(*name).or_insert_with(Vec::new).push(row.codepoints);
+        }
+    }
+
+    let mut grapheme_cluster_break = BTreeMap::new();
+    for row in ucd_parse::parse::<_, ucd_parse::GraphemeClusterBreak>(&UNICODE_DIRECTORY).unwrap() {
+        for codepoint in codepoint_range(row.codepoints) {
+            grapheme_cluster_break.insert(codepoint, row.value.clone());
+        }
+    }
+
+    let unicode_data = ucd_parse::parse::<_, ucd_parse::UnicodeData>(&UNICODE_DIRECTORY).unwrap();
+
+    // The ranges of codepoints in UnicodeData.txt have neither a combining class
+    // nor a decomposition, so there's no need to expand them here.
+    let mut combining_classes = BTreeMap::new();
+    let mut canonical_decompositions = BTreeMap::new();
+    let mut compatibility_decompositions = BTreeMap::new();
+    for row in &unicode_data {
+        let codepoint = row.codepoint.value();
+        if row.canonical_combining_class != 0 {
+            combining_classes.insert(codepoint, row.canonical_combining_class);
+        }
+        // Codepoints without a decomposition are mapped to themselves.
+        let mapping = row.decomposition.mapping();
+        if mapping != [row.codepoint] {
+            let mapping = mapping.iter().map(|c| c.value()).collect::<Vec<_>>();
+            if row.decomposition.tag.is_none() {
+                canonical_decompositions.insert(codepoint, mapping);
+            } else {
+                compatibility_decompositions.insert(codepoint, mapping);
+            }
+        }
+    }
+
+    let composition_exclusions = std::fs::read_to_string(
+        std::path::Path::new(UNICODE_DIRECTORY).join("CompositionExclusions.txt"),
+    )
+    .unwrap()
+    .lines()
+    .filter_map(|line| {
+        let codepoint = line.split('#').next().unwrap().trim();
+        (!codepoint.is_empty()).then(|| u32::from_str_radix(codepoint, 16).unwrap())
+    })
+    .collect();
 
     let mut to_lower = BTreeMap::new();
     let mut to_upper = BTreeMap::new();
-    for row in ucd_parse::UnicodeDataExpander::new(
-        ucd_parse::parse::<_, ucd_parse::UnicodeData>(&UNICODE_DIRECTORY).unwrap(),
-    ) {
+    for row in ucd_parse::UnicodeDataExpander::new(unicode_data) {
         let general_category = if ["Nd", "Nl", "No"].contains(&row.general_category.as_str()) {
             "N"
         } else {
@@ -214,7 +274,16 @@ fn load_data() -> UnicodeData {
 
     let mut properties = properties.into_iter().collect::<Vec<_>>();
     properties.sort_by_key(|p| p.0);
-    UnicodeData { ranges: properties, to_lower, to_upper }
+    UnicodeData {
+        ranges: properties,
+        to_lower,
+        to_upper,
+        grapheme_cluster_break,
+        combining_classes,
+        canonical_decompositions,
+        compatibility_decompositions,
+        composition_exclusions,
+    }
 }
 
 fn main() {
@@ -280,6 +349,14 @@ fn main() {
     table_file.push('\n');
 
     modules.push((String::from("conversions"), case_mapping::generate_case_mapping(&unicode_data)));
+    modules.push((
+        String::from("grapheme_cluster_break"),
+        grapheme_cluster_break::generate_grapheme_cluster_break(&unicode_data),
+    ));
+    modules.push((
+        String::from("normalization"),
+        normalization::generate_normalization(&unicode_data),
+    ));
 
     for (name, contents) in modules {
         table_file.push_str("#[rustfmt::skip]\n");
@@ -336,6 +413,29 @@ fn fmt_list<V: std::fmt::Debug>(values: impl IntoIterator<Item = V>) -> String {
     out
 }
 
+/// Encodes the values of the codepoints as runs of consecutive codepoints with
+/// the same value, for `run_search`. Each run is encoded as its first codepoint
+/// shifted left by 8 bits, next to the value. Codepoints that are missing from
+/// `values` have the value 0.
+fn encode_runs(values: &BTreeMap<u32, u8>) -> Vec<u32> {
+    let mut runs: Vec<u32> = Vec::new();
+    let mut push = |start: u32, value: u8| {
+        if runs.last().map_or(true, |&run| run as u8 != value) {
+            runs.push(start << 8 | value as u32);
+        }
+    };
+    let mut next = 0;
+    for (&codepoint, &value) in values {
+        if codepoint != next {
+            push(next, 0);
+        }
+        push(codepoint, value);
+        next = codepoint + 1;
+    }
+    push(next, 0);
+    runs
+}
+
 fn generate_tests(data_path: &str, ranges: &[(&str, Vec<Range<u32>>)]) -> String {
     let mut s = String::new();
     s.push_str("#![allow(incomplete_features, unused)]\n");
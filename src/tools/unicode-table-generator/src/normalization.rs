@@ -0,0 +1,172 @@
+use crate::case_mapping::CharEscape;
+use crate::{encode_runs, fmt_list, UnicodeData};
+use std::{
+    char,
+    collections::{BTreeMap, HashMap},
+    fmt::Write,
+};
+
+pub(crate) fn generate_normalization(data: &UnicodeData) -> String {
+    let mut canonical = BTreeMap::new();
+    let mut compatibility = BTreeMap::new();
+    for &codepoint in
+        data.canonical_decompositions.keys().chain(data.compatibility_decompositions.keys())
+    {
+        let canonical_mapping = full_decomposition(data, codepoint, false);
+        let compatibility_mapping = full_decomposition(data, codepoint, true);
+        if compatibility_mapping != canonical_mapping {
+            compatibility.insert(codepoint, compatibility_mapping);
+        }
+        if canonical_mapping != [codepoint] {
+            canonical.insert(codepoint, canonical_mapping);
+        }
+    }
+
+    // The primary composites are the canonical decompositions into two
+    // characters, except for the composition exclusions and the decompositions
+    // of or into a non-starter.
+    let combining_class = |codepoint| data.combining_classes.get(&codepoint).copied().unwrap_or(0);
+    let mut compositions = Vec::new();
+    for (&codepoint, mapping) in &data.canonical_decompositions {
+        if let &[a, b] = &mapping[..] {
+            if !data.composition_exclusions.contains(&codepoint)
+                && combining_class(codepoint) == 0
+                && combining_class(a) == 0
+            {
+                compositions.push((a, b, codepoint));
+            }
+        }
+    }
+    compositions.sort();
+
+    let mut file = String::new();
+    file.push_str(HEADER.trim_start());
+    file.push('\n');
+    let runs = encode_runs(&data.combining_classes);
+    write!(file, "static COMBINING_CLASS_RUNS: [u32; {}] = [{}];", runs.len(), fmt_list(runs))
+        .unwrap();
+    file.push_str("\n\n");
+    file.push_str(&generate_tables("CANONICAL", &canonical));
+    file.push_str("\n\n");
+    file.push_str(&generate_tables("COMPATIBILITY", &compatibility));
+    file.push_str("\n\n");
+    write!(
+        file,
+        "static COMPOSITION_TABLE: &[(char, char, char)] = &[{}];",
+        fmt_list(compositions.into_iter().map(|(a, b, c)| (escape(a), escape(b), escape(c))))
+    )
+    .unwrap();
+    file
+}
+
+/// Recursively applies the decomposition mappings to `codepoint`, including the
+/// compatibility mappings if `compatible` is true.
+fn full_decomposition(data: &UnicodeData, codepoint: u32, compatible: bool) -> Vec<u32> {
+    let mapping = compatible
+        .then(|| data.compatibility_decompositions.get(&codepoint))
+        .flatten()
+        .or_else(|| data.canonical_decompositions.get(&codepoint));
+    match mapping {
+        Some(mapping) => {
+            mapping.iter().flat_map(|&c| full_decomposition(data, c, compatible)).collect()
+        }
+        None => vec![codepoint],
+    }
+}
+
+/// Emits the decompositions as a table that maps each codepoint to the offset
+/// and length of its decomposition in a shared list of characters, where the
+/// same decompositions are only stored once.
+fn generate_tables(kind: &str, decompositions: &BTreeMap<u32, Vec<u32>>) -> String {
+    let mut chars = Vec::new();
+    let mut offsets = HashMap::new();
+    let mut table = Vec::with_capacity(decompositions.len());
+    for (&codepoint, mapping) in decompositions {
+        let offset = *offsets.entry(mapping).or_insert_with(|| {
+            chars.extend(mapping.iter().map(|&c| escape(c)));
+            chars.len() - mapping.len()
+        });
+        let len = u8::try_from(mapping.len()).unwrap();
+        table.push((escape(codepoint), u32::try_from(offset).unwrap() << 8 | len as u32));
+    }
+
+    let mut tables = String::new();
+    write!(tables, "static {kind}_TABLE: &[(char, u32)] = &[{}];", fmt_list(table)).unwrap();
+    tables.push_str("\n\n");
+    write!(tables, "static {kind}_CHARS: &[char] = &[{}];", fmt_list(chars)).unwrap();
+    tables
+}
+
+fn escape(codepoint: u32) -> CharEscape {
+    CharEscape(char::from_u32(codepoint).unwrap())
+}
+
+static HEADER: &str = r"
+const S_BASE: u32 = 0xAC00;
+const L_BASE: u32 = 0x1100;
+const V_BASE: u32 = 0x1161;
+const T_BASE: u32 = 0x11A7;
+const L_COUNT: u32 = 19;
+const V_COUNT: u32 = 21;
+const T_COUNT: u32 = 28;
+const N_COUNT: u32 = V_COUNT * T_COUNT;
+const S_COUNT: u32 = L_COUNT * N_COUNT;
+
+pub fn canonical_combining_class(c: char) -> u8 {
+    if c.is_ascii() { 0 } else { super::run_search(c as u32, &COMBINING_CLASS_RUNS) }
+}
+
+pub fn decompose(c: char, compatible: bool, mut emit: impl FnMut(char)) {
+    if c.is_ascii() {
+        return emit(c);
+    }
+    let s_index = (c as u32).wrapping_sub(S_BASE);
+    if s_index < S_COUNT {
+        // SAFETY: The Hangul syllables decompose into valid chars
+        unsafe {
+            emit(char::from_u32_unchecked(L_BASE + s_index / N_COUNT));
+            emit(char::from_u32_unchecked(V_BASE + (s_index % N_COUNT) / T_COUNT));
+            if s_index % T_COUNT != 0 {
+                emit(char::from_u32_unchecked(T_BASE + s_index % T_COUNT));
+            }
+        }
+        return;
+    }
+    let mut chars = None;
+    if compatible {
+        chars = decomposition(COMPATIBILITY_TABLE, COMPATIBILITY_CHARS, c);
+    }
+    match chars.or_else(|| decomposition(CANONICAL_TABLE, CANONICAL_CHARS, c)) {
+        Some(chars) => chars.iter().copied().for_each(emit),
+        None => emit(c),
+    }
+}
+
+fn decomposition(
+    table: &'static [(char, u32)],
+    chars: &'static [char],
+    c: char,
+) -> Option<&'static [char]> {
+    let i = table.binary_search_by(|&(key, _)| key.cmp(&c)).ok()?;
+    let offset = (table[i].1 >> 8) as usize;
+    let len = (table[i].1 & 0xff) as usize;
+    chars.get(offset..offset + len)
+}
+
+pub fn compose(a: char, b: char) -> Option<char> {
+    let l_index = (a as u32).wrapping_sub(L_BASE);
+    let v_index = (b as u32).wrapping_sub(V_BASE);
+    if l_index < L_COUNT && v_index < V_COUNT {
+        return char::from_u32(S_BASE + (l_index * V_COUNT + v_index) * T_COUNT);
+    }
+    let s_index = (a as u32).wrapping_sub(S_BASE);
+    let t_index = (b as u32).wrapping_sub(T_BASE);
+    if s_index < S_COUNT && s_index % T_COUNT == 0 && 0 < t_index && t_index < T_COUNT {
+        return char::from_u32(a as u32 + t_index);
+    }
+    COMPOSITION_TABLE
+        .binary_search_by(|&(x, y, _)| (x, y).cmp(&(a, b)))
+        .ok()
+        .map(|i| COMPOSITION_TABLE[i].2)
+}
+";
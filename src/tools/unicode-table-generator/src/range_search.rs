@@ -96,3 +96,11 @@ fn skip_search<const SOR: usize, const OFFSETS: usize>(
     }
     offset_idx % 2 == 1
 }
+
+#[inline]
+fn run_search<const N: usize>(needle: u32, runs: &[u32; N]) -> u8 {
+    // The first run starts at zero, so there always is a run that contains the
+    // needle, and `idx` can't be zero.
+    let idx = runs.partition_point(|run| run >> 8 <= needle);
+    runs[idx - 1] as u8
+}
@@ -0,0 +1,23 @@
+use std::num::NonZeroU32;
+
+pub struct Pair<T> {
+    pub a: T,
+    pub b: u32,
+}
+
+pub enum Choice {
+    A(u8),
+    B(u64),
+}
+
+pub fn pair(a: u8) -> Pair<u8> {
+    Pair { a, b: 0 }
+}
+
+pub fn choice(b: bool) -> Choice {
+    if b { Choice::A(1) } else { Choice::B(2) }
+}
+
+pub fn maybe_nonzero(x: u32) -> Option<NonZeroU32> {
+    NonZeroU32::new(x)
+}
@@ -0,0 +1,10 @@
+pub fn unchanged() -> u32 {
+    1
+}
+
+pub fn changed() -> u32 {
+    #[cfg(changed)]
+    return 2;
+    #[cfg(not(changed))]
+    return 3;
+}
@@ -0,0 +1,14 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static FAILED: AtomicBool = AtomicBool::new(false);
+
+#[test]
+fn a() {
+    // Should fail the first time, and pass when run again
+    assert!(FAILED.swap(true, Ordering::Relaxed));
+}
+
+#[test]
+fn b() {
+    // Should pass
+}
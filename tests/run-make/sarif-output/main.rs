@@ -0,0 +1,5 @@
+use std::collections::HashMap;
+
+fn main() {
+    let _x: u32 = "not a number";
+}
@@ -0,0 +1,33 @@
+#!/usr/bin/env python
+
+import sys
+import json
+
+# The whole output is a single SARIF log on one line.
+lines = sys.stdin.read().splitlines()
+assert len(lines) == 1, lines
+log = json.loads(lines[0])
+
+assert log["version"] == "2.1.0"
+assert len(log["runs"]) == 1
+run = log["runs"][0]
+assert run["tool"]["driver"]["name"] == "rustc"
+
+rules = [rule["id"] for rule in run["tool"]["driver"]["rules"]]
+results = {result.get("ruleId"): result for result in run["results"]}
+
+warning = results["unused_imports"]
+assert warning["level"] == "warning"
+assert warning["message"]["text"].startswith("unused import: `std::collections::HashMap`")
+assert rules[warning["ruleIndex"]] == "unused_imports"
+region = warning["locations"][0]["physicalLocation"]["region"]
+assert region["startLine"] == 1
+assert region["snippet"]["text"] == "std::collections::HashMap"
+
+error = results["E0308"]
+assert error["level"] == "error"
+assert error["message"]["text"].startswith("mismatched types")
+assert rules[error["ruleIndex"]] == "E0308"
+location = error["locations"][0]["physicalLocation"]
+assert location["artifactLocation"]["uri"].endswith("main.rs")
+assert location["region"]["startLine"] == 4
@@ -0,0 +1,3 @@
+pub fn profiled(x: u32) -> u32 {
+    x + 1
+}
@@ -0,0 +1,13 @@
+pub fn old() {
+    let unused = 1;
+}
+
+#[cfg(not(fixed))]
+pub fn fixed_later() {
+    let also_unused = 2;
+}
+
+#[cfg(new_warning)]
+pub fn new() {
+    let unused = 3;
+}
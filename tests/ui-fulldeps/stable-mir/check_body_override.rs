@@ -0,0 +1,183 @@
+// run-pass
+//! Test that users are able to replace the optimized MIR of an item, and that the new body
+//! goes through the MIR validator.
+
+// ignore-stage1
+// ignore-cross-compile
+// ignore-remote
+// ignore-windows-gnu mingw has troubles with linking https://github.com/rust-lang/rust/pull/116837
+// edition: 2021
+
+#![feature(rustc_private)]
+#![feature(assert_matches)]
+
+extern crate rustc_driver;
+extern crate rustc_interface;
+extern crate rustc_smir;
+extern crate stable_mir;
+
+use rustc_driver::{Callbacks, Compilation, RunCompiler};
+use rustc_interface::{interface, Queries};
+use rustc_smir::rustc_internal;
+use stable_mir::mir::visit::Location;
+use stable_mir::mir::{
+    Body, Constant, MirVisitor, MutMirVisitor, Mutability, Operand, Place, Rvalue, SourceInfo,
+    Statement, StatementKind, VarDebugInfo, VarDebugInfoContents, RETURN_LOCAL,
+};
+use stable_mir::ty::{Const, ConstantKind, RigidTy, Span, Ty, TyKind, UintTy};
+use stable_mir::{CrateDef, CrateItem};
+use std::assert_matches::assert_matches;
+use std::io::Write;
+
+const CRATE_NAME: &str = "input";
+
+/// The body override installed for the test crate.
+fn instrument(item: CrateItem, mut body: Body) -> Option<Body> {
+    let span = body.span;
+    match item.trimmed_name().as_str() {
+        "instrumented" => {
+            ReplaceU32Consts.visit_body(&mut body);
+            let flag = body.new_local(Ty::bool_ty(), span, Mutability::Not);
+            body.insert_statement(0, 0, assign(flag, Const::from_bool(true), span));
+            body.var_debug_info.push(VarDebugInfo {
+                name: "flag".to_string(),
+                source_info: SourceInfo { span, scope: 0 },
+                composite: None,
+                value: VarDebugInfoContents::Place(Place::from(flag)),
+                argument_index: None,
+            });
+            Some(body)
+        }
+        "broken" => {
+            // The return place has type `u32`.
+            body.insert_statement(0, 0, assign(RETURN_LOCAL, Const::from_bool(true), span));
+            Some(body)
+        }
+        _ => None,
+    }
+}
+
+fn assign(local: usize, value: Const, span: Span) -> Statement {
+    let value = Operand::Constant(Constant { span, user_ty: None, literal: value });
+    Statement { kind: StatementKind::Assign(Place::from(local), Rvalue::Use(value)), span }
+}
+
+fn is_u32(ty: Ty) -> bool {
+    ty.kind() == TyKind::RigidTy(RigidTy::Uint(UintTy::U32))
+}
+
+/// Replace every `u32` constant operand by `42`.
+struct ReplaceU32Consts;
+
+impl MutMirVisitor for ReplaceU32Consts {
+    fn visit_constant(&mut self, constant: &mut Constant, location: Location) {
+        if is_u32(constant.ty()) {
+            constant.literal = Const::try_from_uint(42, UintTy::U32).unwrap();
+        }
+        self.super_constant(constant, location)
+    }
+}
+
+/// Collect the values of the `u32` constant operands.
+#[derive(Default)]
+struct CollectU32Consts(Vec<u128>);
+
+impl MirVisitor for CollectU32Consts {
+    fn visit_constant(&mut self, constant: &Constant, location: Location) {
+        if is_u32(constant.ty()) {
+            let ConstantKind::Allocated(alloc) = constant.literal.kind() else {
+                panic!("Unexpected constant: {constant:?}")
+            };
+            self.0.push(alloc.read_uint().unwrap());
+        }
+        self.super_constant(constant, location)
+    }
+}
+
+fn check_bodies() {
+    let items = stable_mir::all_local_items();
+    let find = |name: &str| items.iter().find(|item| item.trimmed_name() == name).unwrap();
+
+    let body = find("instrumented").body();
+    let flag = body.locals().len() - 1;
+    assert_eq!(body.locals()[flag].ty, Ty::bool_ty());
+    assert_matches!(
+        &body.blocks[0].statements[0].kind,
+        StatementKind::Assign(place, _) if place.local == flag
+    );
+    let mut consts = CollectU32Consts::default();
+    consts.visit_body(&body);
+    assert_eq!(consts.0, [42]);
+    let debug_info = |name: &str| body.var_debug_info.iter().find(|info| info.name == name);
+    assert_eq!(debug_info("flag").unwrap().local(), Some(flag));
+    assert_eq!(debug_info("x").unwrap().argument_index, Some(1));
+
+    // The invalid override is rejected, and the original body is kept.
+    let body = find("broken").body();
+    let mut consts = CollectU32Consts::default();
+    consts.visit_body(&body);
+    assert_eq!(consts.0, [0]);
+    assert!(!body.blocks[0].statements.iter().any(|stmt| matches!(
+        &stmt.kind,
+        StatementKind::Assign(_, Rvalue::Use(Operand::Constant(constant)))
+            if constant.ty() == Ty::bool_ty()
+    )));
+}
+
+struct Instrument {
+    checked: bool,
+}
+
+impl Callbacks for Instrument {
+    fn config(&mut self, config: &mut interface::Config) {
+        rustc_internal::override_optimized_mir(config, instrument);
+    }
+
+    fn after_analysis<'tcx>(
+        &mut self,
+        _compiler: &interface::Compiler,
+        queries: &'tcx Queries<'tcx>,
+    ) -> Compilation {
+        queries.global_ctxt().unwrap().enter(|tcx| {
+            rustc_internal::run(tcx, check_bodies).unwrap();
+            assert!(tcx.dcx().has_errors().is_some(), "The invalid override wasn't reported");
+        });
+        self.checked = true;
+        Compilation::Stop
+    }
+}
+
+/// This test will generate and analyze a dummy crate using the stable mir.
+/// For that, it will first write the dummy crate into a file.
+/// Then it will run the compiler with a body override installed.
+fn main() {
+    let path = "body_override_input.rs";
+    generate_input(&path).unwrap();
+    let args = vec![
+        "rustc".to_string(),
+        "--crate-type=lib".to_string(),
+        "--crate-name".to_string(),
+        CRATE_NAME.to_string(),
+        path.to_string(),
+    ];
+    let mut callbacks = Instrument { checked: false };
+    let _ = rustc_driver::catch_fatal_errors(|| RunCompiler::new(&args, &mut callbacks).run());
+    assert!(callbacks.checked);
+}
+
+fn generate_input(path: &str) -> std::io::Result<()> {
+    let mut file = std::fs::File::create(path)?;
+    write!(
+        file,
+        r#"
+    pub fn instrumented(x: u32) -> u32 {{
+        x.wrapping_add(1)
+    }}
+
+    pub fn broken() -> u32 {{
+        0
+    }}
+    "#
+    )?;
+    Ok(())
+}
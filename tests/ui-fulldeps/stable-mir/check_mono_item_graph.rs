@@ -0,0 +1,141 @@
+// run-pass
+//! Test that users are able to retrieve the mono item graph of the local crate.
+
+// ignore-stage1
+// ignore-cross-compile
+// ignore-remote
+// ignore-windows-gnu mingw has troubles with linking https://github.com/rust-lang/rust/pull/116837
+// edition: 2021
+
+#![feature(rustc_private)]
+#![feature(assert_matches)]
+#![feature(control_flow_enum)]
+
+extern crate rustc_middle;
+#[macro_use]
+extern crate rustc_smir;
+extern crate rustc_driver;
+extern crate rustc_interface;
+extern crate stable_mir;
+
+use rustc_middle::ty::TyCtxt;
+use rustc_smir::rustc_internal;
+use stable_mir::mir::mono::{MonoItem, MonoItemGraph, UseKind};
+use std::io::Write;
+use std::ops::ControlFlow;
+
+const CRATE_NAME: &str = "input";
+
+/// This function uses the Stable MIR APIs to get information about the test crate.
+fn test_stable_mir(_tcx: TyCtxt<'_>) -> ControlFlow<()> {
+    let graph = stable_mir::mono_item_graph();
+
+    let entry = find_item(&graph, |name| name == "entry").unwrap();
+    let uses = |kind: UseKind| {
+        graph
+            .used_by(entry)
+            .filter(move |usage| usage.kind == kind)
+            .map(|usage| item_name(&graph.items[usage.used]))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(uses(UseKind::Call), ["direct"]);
+    assert_eq!(uses(UseKind::VtableMethod), ["<Noisy as Greet>::greet"]);
+
+    let fn_ptrs = uses(UseKind::FnPtr);
+    assert_eq!(fn_ptrs.len(), 2, "Unexpected fn pointers: {fn_ptrs:?}");
+    assert!(fn_ptrs.iter().any(|name| name == "reified"));
+    assert!(fn_ptrs.iter().any(|name| name.starts_with("entry::{closure")));
+
+    let drop_glue = uses(UseKind::DropGlue);
+    assert_eq!(drop_glue.len(), 1, "Unexpected drop glue: {drop_glue:?}");
+    assert!(drop_glue[0].contains("drop_in_place::<Noisy>"));
+
+    // The drop glue calls the user defined destructor.
+    let glue = graph
+        .used_by(entry)
+        .find(|usage| usage.kind == UseKind::DropGlue)
+        .map(|usage| usage.used)
+        .unwrap();
+    assert!(graph.users_of(glue).any(|usage| usage.user == entry));
+    assert!(graph.used_by(glue).any(|usage| {
+        usage.kind == UseKind::Call && item_name(&graph.items[usage.used]).ends_with("Drop>::drop")
+    }));
+
+    // Every item ends up in at least one codegen unit.
+    for (index, item) in graph.items.iter().enumerate() {
+        assert!(graph.codegen_units_of(index).next().is_some(), "No CGU for {item:?}");
+    }
+    ControlFlow::Continue(())
+}
+
+fn item_name(item: &MonoItem) -> String {
+    match item {
+        MonoItem::Fn(instance) => instance.name(),
+        MonoItem::Static(def) => stable_mir::CrateDef::name(def),
+        MonoItem::GlobalAsm(asm) => asm.to_string(),
+    }
+}
+
+fn find_item(graph: &MonoItemGraph, pred: impl Fn(&str) -> bool) -> Option<usize> {
+    graph.items.iter().position(|item| pred(&item_name(item)))
+}
+
+/// This test will generate and analyze a dummy crate using the stable mir.
+/// For that, it will first write the dummy crate into a file.
+/// Then it will create a `StableMir` using custom arguments and then
+/// it will run the compiler.
+fn main() {
+    let path = "mono_item_graph_input.rs";
+    generate_input(&path).unwrap();
+    let args = vec![
+        "rustc".to_string(),
+        "-Cpanic=abort".to_string(),
+        "--crate-type=lib".to_string(),
+        "--crate-name".to_string(),
+        CRATE_NAME.to_string(),
+        path.to_string(),
+    ];
+    run!(args, tcx, test_stable_mir(tcx)).unwrap();
+}
+
+fn generate_input(path: &str) -> std::io::Result<()> {
+    let mut file = std::fs::File::create(path)?;
+    write!(
+        file,
+        r#"
+    pub struct Noisy;
+
+    impl Drop for Noisy {{
+        fn drop(&mut self) {{}}
+    }}
+
+    pub trait Greet {{
+        fn greet(&self) -> u32;
+    }}
+
+    impl Greet for Noisy {{
+        fn greet(&self) -> u32 {{
+            1
+        }}
+    }}
+
+    fn direct() -> u32 {{
+        2
+    }}
+
+    fn reified() -> u32 {{
+        3
+    }}
+
+    pub fn entry() -> u32 {{
+        let noisy = Noisy;
+        let greet: &dyn Greet = &noisy;
+        let f: fn() -> u32 = reified;
+        let c: fn() -> u32 = || 4;
+        direct() + greet.greet() + f() + c()
+    }}
+    "#
+    )?;
+    Ok(())
+}
@@ -0,0 +1,138 @@
+// run-pass
+//! Test that StableMIR values can be exported with serde, and inspected after the compiler is gone.
+
+// ignore-stage1
+// ignore-cross-compile
+// ignore-remote
+// ignore-windows-gnu mingw has troubles with linking https://github.com/rust-lang/rust/pull/116837
+
+#![feature(rustc_private)]
+#![feature(assert_matches)]
+
+extern crate rustc_middle;
+#[macro_use]
+extern crate rustc_smir;
+extern crate rustc_driver;
+extern crate rustc_interface;
+extern crate serde_json;
+extern crate stable_mir;
+
+use rustc_middle::ty::TyCtxt;
+use rustc_smir::rustc_internal;
+use stable_mir::abi::{FieldsShape, FnAbi};
+use stable_mir::export::Export;
+use stable_mir::mir::mono::Instance;
+use stable_mir::mir::{Body, Operand, TerminatorKind};
+use stable_mir::ty::{RigidTy, TyKind, UintTy};
+use stable_mir::{CrateDef, CrateItems, ItemKind};
+use std::assert_matches::assert_matches;
+use std::convert::TryFrom;
+use std::io::Write;
+use std::ops::ControlFlow;
+
+const CRATE_NAME: &str = "input";
+
+/// This function uses the Stable MIR APIs to export the bodies of the test crate, as well as the
+/// ABI of `add`, while the compiler is running.
+fn test_stable_mir(_tcx: TyCtxt<'_>) -> ControlFlow<(), (String, String)> {
+    let items = stable_mir::all_local_items();
+    let add = *get_item(&items, (ItemKind::Fn, "add")).unwrap();
+    let caller = *get_item(&items, (ItemKind::Fn, "caller")).unwrap();
+
+    let bodies = Export::new(vec![add.body(), caller.body()]);
+    let fn_abi = Export::new(Instance::try_from(add).unwrap().fn_abi().unwrap());
+    ControlFlow::Continue((
+        serde_json::to_string(&bodies).unwrap(),
+        serde_json::to_string(&fn_abi).unwrap(),
+    ))
+}
+
+/// Check the exported bodies of `add` and `caller` without a compiler.
+fn check_bodies(json: &str) {
+    let bodies: Export<Vec<Body>> = serde_json::from_str(json).unwrap();
+    let [add, caller] = &bodies.value[..] else { panic!("Expected two bodies") };
+
+    let ret_ty = add.ret_local().ty;
+    assert_matches!(bodies.ty_kind(ret_ty), Some(TyKind::RigidTy(RigidTy::Uint(UintTy::U32))));
+    for arg in add.arg_locals() {
+        assert_eq!(bodies.ty_kind(arg.ty), bodies.ty_kind(ret_ty));
+    }
+
+    let TerminatorKind::Call { func: Operand::Constant(callee), .. } =
+        &caller.blocks[0].terminator.kind
+    else {
+        panic!("Expected a call to `add`")
+    };
+    let Some(TyKind::RigidTy(RigidTy::FnDef(def, _))) = bodies.ty_kind(callee.literal.ty()) else {
+        panic!("Expected a function definition")
+    };
+    let def_info = bodies.def_info(def.def_id()).unwrap();
+    assert_eq!(def_info.name, "input::add");
+    assert_eq!(def_info.trimmed_name, "add");
+    assert_eq!(def_info.krate.name, CRATE_NAME);
+
+    // The exported value can be serialized again, without a compiler.
+    let json = serde_json::to_string(&bodies).unwrap();
+    let bodies: Export<Vec<Body>> = serde_json::from_str(&json).unwrap();
+    assert_matches!(
+        bodies.ty_kind(bodies.value[0].ret_local().ty),
+        Some(TyKind::RigidTy(RigidTy::Uint(UintTy::U32)))
+    );
+}
+
+/// Check the exported ABI of `add` without a compiler.
+fn check_fn_abi(json: &str) {
+    let fn_abi: Export<FnAbi> = serde_json::from_str(json).unwrap();
+    assert_eq!(fn_abi.value.args.len(), 2);
+
+    let shape = fn_abi.layout_shape(fn_abi.value.ret.layout).unwrap();
+    assert!(shape.is_sized());
+    assert_eq!(shape.size, 4);
+    assert_matches!(shape.fields, FieldsShape::Primitive);
+    for arg in &fn_abi.value.args {
+        assert_eq!(fn_abi.layout_shape(arg.layout), Some(shape));
+    }
+}
+
+fn get_item<'a>(
+    items: &'a CrateItems,
+    item: (ItemKind, &str),
+) -> Option<&'a stable_mir::CrateItem> {
+    items.iter().find(|crate_item| (item.0 == crate_item.kind()) && crate_item.name() == item.1)
+}
+
+/// This test will generate and analyze a dummy crate using the stable mir.
+/// For that, it will first write the dummy crate into a file.
+/// Then it will create a `StableMir` using custom arguments and then
+/// it will run the compiler, and check the exported values once it is done.
+fn main() {
+    let path = "serde_export_input.rs";
+    generate_input(&path).unwrap();
+    let args = vec![
+        "rustc".to_string(),
+        "--crate-type=lib".to_string(),
+        "--crate-name".to_string(),
+        CRATE_NAME.to_string(),
+        path.to_string(),
+    ];
+    let (bodies, fn_abi) = run!(args, tcx, test_stable_mir(tcx)).unwrap();
+    check_bodies(&bodies);
+    check_fn_abi(&fn_abi);
+}
+
+fn generate_input(path: &str) -> std::io::Result<()> {
+    let mut file = std::fs::File::create(path)?;
+    write!(
+        file,
+        r#"
+        pub fn add(a: u32, b: u32) -> u32 {{
+            a + b
+        }}
+
+        pub fn caller() -> u32 {{
+            add(1, 2)
+        }}
+        "#
+    )?;
+    Ok(())
+}
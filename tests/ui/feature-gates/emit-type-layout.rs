@@ -0,0 +1,3 @@
+// compile-flags: --emit=type-layout
+
+fn main() {}
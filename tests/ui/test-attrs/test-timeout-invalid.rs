@@ -0,0 +1,15 @@
+// compile-flags: --test
+
+#![feature(test_timeout)]
+
+#[test]
+#[test_timeout = "0"] //~ ERROR the timeout of a test must be a positive number of seconds
+fn zero() {}
+
+#[test]
+#[test_timeout = "1.5"] //~ ERROR the timeout of a test must be a positive number of seconds
+fn fractional() {}
+
+#[test]
+#[test_timeout = "10"]
+fn valid() {}
@@ -0,0 +1,30 @@
+// no-prefer-dynamic
+// compile-flags: --test
+// run-flags: --test-threads=1
+// run-fail
+// check-run-results
+// normalize-stdout-test "finished in \d+\.\d+s" -> "finished in $$TIME"
+
+// ignore-wasm no subprocess support
+// ignore-emscripten no subprocess support
+// ignore-sgx no subprocess support
+
+// Tests that tests that exceed their timeout are killed, and that their output is kept.
+
+#![cfg(test)]
+#![feature(test_timeout)]
+
+use std::io::Write;
+use std::time::Duration;
+
+#[test]
+#[test_timeout = "60"]
+fn it_finishes() {}
+
+#[test]
+#[test_timeout = "1"]
+fn it_hangs() {
+    println!("before hanging");
+    writeln!(std::io::stderr(), "still before hanging").unwrap();
+    std::thread::sleep(Duration::from_secs(600));
+}